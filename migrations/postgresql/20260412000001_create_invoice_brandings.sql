@@ -0,0 +1,13 @@
+-- Per-company invoice branding (logo, colors, fonts, default texts, custom template)
+CREATE TABLE invoice_brandings (
+    company_id UUID PRIMARY KEY REFERENCES companies(id) ON DELETE CASCADE,
+    logo_path TEXT,
+    accent_color VARCHAR(7) NOT NULL,
+    font VARCHAR(50) NOT NULL,
+    default_notes TEXT,
+    footer_text TEXT,
+    signature_text TEXT,
+    custom_template TEXT,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
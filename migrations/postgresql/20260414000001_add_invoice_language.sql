@@ -0,0 +1,3 @@
+-- Invoice document language: customers carry a default, invoices store the chosen one
+ALTER TABLE customers ADD COLUMN default_language VARCHAR(5) NOT NULL DEFAULT 'en';
+ALTER TABLE invoices ADD COLUMN language VARCHAR(5) NOT NULL DEFAULT 'en';
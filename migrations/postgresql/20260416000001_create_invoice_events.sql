@@ -0,0 +1,16 @@
+-- Append-only audit log of invoice activity. invoice_id has no foreign key so
+-- the history outlives deleted invoices; rows are never updated or deleted.
+CREATE TABLE invoice_events (
+    id UUID PRIMARY KEY,
+    invoice_id UUID NOT NULL,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    invoice_number TEXT NOT NULL,
+    user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    kind VARCHAR(32) NOT NULL,
+    changes TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_invoice_events_invoice_id ON invoice_events(invoice_id, created_at DESC);
+
+COMMENT ON COLUMN invoice_events.changes IS 'JSON array of {field, before, after}';
@@ -0,0 +1,15 @@
+-- Tamper-evident SHA-256 hash chain over issued invoices, one chain per company.
+-- Each link hashes the previous link's hash plus the canonical invoice payload.
+-- invoice_id has no foreign key so deleting a sealed invoice shows up as a
+-- broken link instead of silently dropping it from the chain.
+CREATE TABLE invoice_chain_links (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    invoice_id UUID NOT NULL,
+    sequence BIGINT NOT NULL,
+    previous_hash VARCHAR(64),
+    hash VARCHAR(64) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT invoice_chain_links_company_sequence_unique UNIQUE (company_id, sequence),
+    CONSTRAINT invoice_chain_links_invoice_unique UNIQUE (invoice_id)
+);
@@ -0,0 +1,45 @@
+-- Double-entry general ledger: per-company chart of accounts and journal.
+-- Journal entries are append-only; corrections are posted as reversing entries.
+CREATE TABLE ledger_accounts (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    code VARCHAR(8) NOT NULL,
+    name TEXT NOT NULL,
+    account_type VARCHAR(20) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT ledger_accounts_company_code_unique UNIQUE (company_id, code)
+);
+
+-- source_id points at the invoice / received invoice an automatic entry was
+-- posted for; no foreign key so the books survive document deletion.
+CREATE TABLE journal_entries (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    entry_date DATE NOT NULL,
+    description TEXT NOT NULL,
+    source VARCHAR(50) NOT NULL,
+    source_id UUID,
+    reversal_of UUID REFERENCES journal_entries(id),
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    CONSTRAINT journal_entries_reversal_unique UNIQUE (reversal_of)
+);
+
+CREATE INDEX idx_journal_entries_company_date ON journal_entries(company_id, entry_date);
+CREATE INDEX idx_journal_entries_source ON journal_entries(company_id, source, source_id);
+
+CREATE TABLE journal_lines (
+    id UUID PRIMARY KEY,
+    entry_id UUID NOT NULL REFERENCES journal_entries(id) ON DELETE CASCADE,
+    account_id UUID NOT NULL REFERENCES ledger_accounts(id),
+    debit DECIMAL(15,2) NOT NULL DEFAULT 0,
+    credit DECIMAL(15,2) NOT NULL DEFAULT 0,
+    description TEXT,
+    line_order INTEGER NOT NULL,
+    CONSTRAINT journal_lines_one_side CHECK (
+        debit >= 0 AND credit >= 0 AND (debit = 0) <> (credit = 0)
+    )
+);
+
+CREATE INDEX idx_journal_lines_entry_id ON journal_lines(entry_id);
+CREATE INDEX idx_journal_lines_account_id ON journal_lines(account_id);
@@ -0,0 +1,27 @@
+-- Per-company categories for bank transactions that have no invoice
+CREATE TABLE IF NOT EXISTS transaction_categories (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT transaction_categories_company_name_unique UNIQUE (company_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_transaction_categories_company_id ON transaction_categories(company_id);
+
+ALTER TABLE bank_transactions ADD COLUMN IF NOT EXISTS category_id UUID REFERENCES transaction_categories(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_bank_transactions_category ON bank_transactions(category_id) WHERE category_id IS NOT NULL;
+
+-- Categories learned from manual categorisations, keyed on a normalized
+-- counterparty account, registry code or name per transaction direction
+CREATE TABLE IF NOT EXISTS transaction_category_suggestions (
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    direction TEXT NOT NULL,
+    match_field TEXT NOT NULL,
+    match_value TEXT NOT NULL,
+    category_id UUID NOT NULL REFERENCES transaction_categories(id) ON DELETE CASCADE,
+    times_used INTEGER NOT NULL DEFAULT 1,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (company_id, direction, match_field, match_value)
+);
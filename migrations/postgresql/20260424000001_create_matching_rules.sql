@@ -0,0 +1,24 @@
+-- User-defined rules evaluated in position order when a bank statement is
+-- imported. Rules assigning a category go away with the category.
+CREATE TABLE IF NOT EXISTS matching_rules (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    counterparty_iban TEXT,
+    description_pattern TEXT,
+    min_amount DECIMAL(12,2),
+    max_amount DECIMAL(12,2),
+    direction TEXT,
+    action TEXT NOT NULL,
+    category_id UUID REFERENCES transaction_categories(id) ON DELETE CASCADE,
+    vendor_name TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_matching_rules_company_position ON matching_rules(company_id, position);
+
+-- Rule that fired on import and the outcomes a rule can set
+ALTER TABLE bank_transactions ADD COLUMN IF NOT EXISTS rule_id UUID REFERENCES matching_rules(id) ON DELETE SET NULL;
+ALTER TABLE bank_transactions ADD COLUMN IF NOT EXISTS ignored BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE bank_transactions ADD COLUMN IF NOT EXISTS needs_review BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,30 @@
+-- Parts of bank transactions settling issued or received invoices. A payment
+-- can settle several invoices and an invoice can be paid in several transfers.
+CREATE TABLE IF NOT EXISTS match_allocations (
+    id UUID PRIMARY KEY,
+    transaction_id UUID NOT NULL REFERENCES bank_transactions(id) ON DELETE CASCADE,
+    invoice_id UUID REFERENCES invoices(id) ON DELETE CASCADE,
+    received_invoice_id UUID REFERENCES received_invoices(id) ON DELETE CASCADE,
+    amount DECIMAL(12,2) NOT NULL CHECK (amount > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT match_allocation_one_document CHECK (
+        (invoice_id IS NOT NULL) <> (received_invoice_id IS NOT NULL)
+    )
+);
+
+CREATE INDEX IF NOT EXISTS idx_match_allocations_transaction_id ON match_allocations(transaction_id);
+CREATE INDEX IF NOT EXISTS idx_match_allocations_invoice_id ON match_allocations(invoice_id) WHERE invoice_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_match_allocations_received_invoice_id ON match_allocations(received_invoice_id) WHERE received_invoice_id IS NOT NULL;
+
+-- Existing matches settle their document with the whole transaction
+INSERT INTO match_allocations (id, transaction_id, invoice_id, received_invoice_id, amount, created_at)
+SELECT gen_random_uuid(), id, matched_invoice_id, matched_received_invoice_id, ABS(amount), created_at
+FROM bank_transactions
+WHERE (matched_invoice_id IS NOT NULL OR matched_received_invoice_id IS NOT NULL)
+  AND amount <> 0;
+
+ALTER TABLE bank_transactions DROP CONSTRAINT IF EXISTS bank_tx_at_most_one_match;
+DROP INDEX IF EXISTS idx_bank_transactions_matched_invoice;
+DROP INDEX IF EXISTS idx_bank_transactions_matched_received;
+ALTER TABLE bank_transactions DROP COLUMN IF EXISTS matched_invoice_id;
+ALTER TABLE bank_transactions DROP COLUMN IF EXISTS matched_received_invoice_id;
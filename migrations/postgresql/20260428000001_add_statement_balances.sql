@@ -0,0 +1,13 @@
+-- Balances and period from the statement's balance rows, and rows left out
+-- because an overlapping statement of the same account already had them
+ALTER TABLE monthly_reports ADD COLUMN IF NOT EXISTS opening_balance DECIMAL(12,2);
+ALTER TABLE monthly_reports ADD COLUMN IF NOT EXISTS closing_balance DECIMAL(12,2);
+ALTER TABLE monthly_reports ADD COLUMN IF NOT EXISTS period_start DATE;
+ALTER TABLE monthly_reports ADD COLUMN IF NOT EXISTS period_end DATE;
+ALTER TABLE monthly_reports ADD COLUMN IF NOT EXISTS duplicate_count INTEGER NOT NULL DEFAULT 0;
+
+-- Bank's identifiers for each statement row
+ALTER TABLE bank_transactions ADD COLUMN IF NOT EXISTS document_number TEXT;
+ALTER TABLE bank_transactions ADD COLUMN IF NOT EXISTS archive_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_bank_transactions_archive_id ON bank_transactions(archive_id) WHERE archive_id IS NOT NULL;
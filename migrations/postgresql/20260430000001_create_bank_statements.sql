@@ -0,0 +1,43 @@
+-- A monthly report aggregates one statement per company bank account
+CREATE TABLE IF NOT EXISTS bank_statements (
+    id UUID PRIMARY KEY,
+    report_id UUID NOT NULL REFERENCES monthly_reports(id) ON DELETE CASCADE,
+    bank_account_id UUID REFERENCES bank_accounts(id) ON DELETE SET NULL,
+    bank_account_iban TEXT NOT NULL,
+    total_incoming DECIMAL(12,2) NOT NULL DEFAULT 0,
+    total_outgoing DECIMAL(12,2) NOT NULL DEFAULT 0,
+    transaction_count INTEGER NOT NULL DEFAULT 0,
+    opening_balance DECIMAL(12,2),
+    closing_balance DECIMAL(12,2),
+    period_start DATE NOT NULL,
+    period_end DATE NOT NULL,
+    duplicate_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT bank_statements_report_iban_unique UNIQUE (report_id, bank_account_iban)
+);
+
+CREATE INDEX IF NOT EXISTS idx_bank_statements_report_id ON bank_statements(report_id);
+CREATE INDEX IF NOT EXISTS idx_bank_statements_iban ON bank_statements(bank_account_iban);
+
+-- Existing reports hold a single statement; it reuses the report's ID
+INSERT INTO bank_statements (id, report_id, bank_account_id, bank_account_iban, total_incoming, total_outgoing, transaction_count, opening_balance, closing_balance, period_start, period_end, duplicate_count, created_at)
+SELECT r.id, r.id,
+    (SELECT b.id FROM bank_accounts b WHERE b.company_id = r.company_id AND b.iban = r.bank_account_iban LIMIT 1),
+    r.bank_account_iban, r.total_incoming, r.total_outgoing, r.transaction_count,
+    r.opening_balance, r.closing_balance,
+    COALESCE(r.period_start, make_date(r.year, r.month, 1)),
+    COALESCE(r.period_end, (make_date(r.year, r.month, 1) + INTERVAL '1 month' - INTERVAL '1 day')::DATE),
+    r.duplicate_count, r.created_at
+FROM monthly_reports r
+WHERE r.bank_account_iban IS NOT NULL AND r.bank_account_iban <> '';
+
+ALTER TABLE bank_transactions ADD COLUMN IF NOT EXISTS statement_id UUID REFERENCES bank_statements(id) ON DELETE CASCADE;
+UPDATE bank_transactions SET statement_id = report_id WHERE report_id IN (SELECT id FROM bank_statements);
+CREATE INDEX IF NOT EXISTS idx_bank_transactions_statement_id ON bank_transactions(statement_id);
+
+ALTER TABLE monthly_reports DROP COLUMN IF EXISTS bank_account_iban;
+ALTER TABLE monthly_reports DROP COLUMN IF EXISTS opening_balance;
+ALTER TABLE monthly_reports DROP COLUMN IF EXISTS closing_balance;
+ALTER TABLE monthly_reports DROP COLUMN IF EXISTS period_start;
+ALTER TABLE monthly_reports DROP COLUMN IF EXISTS period_end;
+ALTER TABLE monthly_reports DROP COLUMN IF EXISTS duplicate_count;
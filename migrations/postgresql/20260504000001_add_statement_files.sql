@@ -0,0 +1,3 @@
+-- Uploaded statement file, kept so it can go into the report archive
+ALTER TABLE bank_statements ADD COLUMN file_path TEXT;
+ALTER TABLE bank_statements ADD COLUMN file_name TEXT;
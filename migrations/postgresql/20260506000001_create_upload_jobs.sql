@@ -0,0 +1,28 @@
+-- Outbox of files to push to a company's storage provider. One row per file:
+-- queueing the same path again replaces it. owner_id is the report or the
+-- invoice, depending on target.
+CREATE TABLE upload_jobs (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    target VARCHAR(32) NOT NULL,
+    owner_id UUID NOT NULL,
+    path TEXT NOT NULL,
+    folder_id TEXT NOT NULL,
+    replace_file_id TEXT,
+    local_path TEXT NOT NULL,
+    mime_type VARCHAR(255) NOT NULL,
+    content_hash VARCHAR(64),
+    requested_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    last_error TEXT,
+    cloud_id TEXT,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL,
+    UNIQUE (target, owner_id, path)
+);
+
+CREATE INDEX idx_upload_jobs_due ON upload_jobs(target, status, next_attempt_at);
+
+COMMENT ON COLUMN upload_jobs.status IS 'pending (retried with exponential backoff), succeeded or failed (gave up)';
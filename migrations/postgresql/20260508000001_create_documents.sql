@@ -0,0 +1,31 @@
+-- Uploaded and generated files. A company stores each content once, keyed by
+-- its SHA-256; entities reference documents through document_links.
+CREATE TABLE documents (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    sha256 VARCHAR(64) NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    mime_type VARCHAR(255) NOT NULL,
+    original_name TEXT NOT NULL,
+    storage_key TEXT NOT NULL,
+    uploaded_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    integrity_status VARCHAR(16) NOT NULL DEFAULT 'ok',
+    checked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL,
+    UNIQUE (company_id, sha256)
+);
+
+-- One document per owner
+CREATE TABLE document_links (
+    owner_type VARCHAR(32) NOT NULL,
+    owner_id UUID NOT NULL,
+    document_id UUID NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (owner_type, owner_id)
+);
+
+CREATE INDEX idx_document_links_document ON document_links(document_id);
+
+COMMENT ON COLUMN documents.storage_key IS 'Path of the file below the document store root';
+COMMENT ON COLUMN documents.integrity_status IS 'ok, missing or corrupted, as found by the last integrity check';
+COMMENT ON COLUMN document_links.owner_type IS 'invoice_pdf, received_invoice or transaction_receipt';
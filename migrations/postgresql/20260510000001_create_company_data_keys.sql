@@ -0,0 +1,8 @@
+-- Per-company data keys for encrypting stored documents at rest
+CREATE TABLE company_data_keys (
+    company_id UUID PRIMARY KEY REFERENCES companies(id) ON DELETE CASCADE,
+    wrapped_key TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+COMMENT ON COLUMN company_data_keys.wrapped_key IS 'Random data key, encrypted with the master key';
@@ -0,0 +1,27 @@
+-- Per-company retention period of source documents. Companies without a row
+-- keep them for the default 7 years.
+CREATE TABLE retention_policies (
+    company_id UUID PRIMARY KEY REFERENCES companies(id) ON DELETE CASCADE,
+    retention_years INTEGER NOT NULL,
+    updated_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+-- Append-only log of deletions refused by the retention policy
+CREATE TABLE retention_blocked_deletions (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    record_type VARCHAR(32) NOT NULL,
+    record_id UUID NOT NULL,
+    label TEXT NOT NULL,
+    user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    retained_until DATE NOT NULL,
+    attempted_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_retention_blocked_deletions_company
+    ON retention_blocked_deletions(company_id, attempted_at);
+
+COMMENT ON COLUMN retention_policies.retention_years IS 'Years kept after the end of the document''s year';
+COMMENT ON COLUMN retention_blocked_deletions.record_type IS 'issued_invoice, received_invoice or report';
+COMMENT ON COLUMN retention_blocked_deletions.record_id IS 'ID of the record; not a foreign key so the entry outlives it';
@@ -0,0 +1,9 @@
+-- Drive sync status of uploaded files, set by the reconciliation job. NULL
+-- until the file is checked after its upload.
+ALTER TABLE upload_jobs ADD COLUMN IF NOT EXISTS sync_status VARCHAR(16);
+ALTER TABLE upload_jobs ADD COLUMN IF NOT EXISTS synced_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_upload_jobs_company_status ON upload_jobs(company_id, status);
+
+COMMENT ON COLUMN upload_jobs.sync_status IS 'in_sync, moved, renamed or missing (queued again for upload)';
+COMMENT ON COLUMN upload_jobs.synced_at IS 'When the uploaded file was last checked in Drive';
@@ -0,0 +1,28 @@
+-- Received invoices tracked as payables: the VAT included in the amount, when
+-- and how to pay, and whether they are paid.
+ALTER TABLE received_invoices ADD COLUMN IF NOT EXISTS vat_amount DECIMAL(12,2) NOT NULL DEFAULT 0;
+ALTER TABLE received_invoices ADD COLUMN IF NOT EXISTS due_date DATE;
+ALTER TABLE received_invoices ADD COLUMN IF NOT EXISTS vendor_iban TEXT;
+ALTER TABLE received_invoices ADD COLUMN IF NOT EXISTS payment_reference TEXT;
+ALTER TABLE received_invoices ADD COLUMN IF NOT EXISTS status VARCHAR(16) NOT NULL DEFAULT 'unpaid';
+ALTER TABLE received_invoices ADD COLUMN IF NOT EXISTS paid_on DATE;
+
+CREATE INDEX IF NOT EXISTS idx_received_invoices_company_status ON received_invoices(company_id, status);
+
+-- Invoices already settled by debit transactions are paid
+UPDATE received_invoices r
+SET status = 'paid', paid_on = paid.last_date
+FROM (
+    SELECT a.received_invoice_id, SUM(a.amount) AS total, MAX(t.date) AS last_date
+    FROM match_allocations a
+    JOIN bank_transactions t ON t.id = a.transaction_id
+    WHERE a.received_invoice_id IS NOT NULL AND t.direction = 'debit'
+    GROUP BY a.received_invoice_id
+) paid
+WHERE paid.received_invoice_id = r.id
+  AND r.amount <> 0
+  AND paid.total >= ABS(r.amount);
+
+COMMENT ON COLUMN received_invoices.vat_amount IS 'Input VAT included in amount';
+COMMENT ON COLUMN received_invoices.status IS 'unpaid, scheduled, paid or disputed';
+COMMENT ON COLUMN received_invoices.paid_on IS 'Date of the settling bank transaction, NULL when marked paid by hand';
@@ -0,0 +1,41 @@
+-- Per-company vendor directory. Received invoices and outgoing bank
+-- transactions link to a vendor; vendors are unique by registry code.
+CREATE TABLE IF NOT EXISTS vendors (
+    id UUID PRIMARY KEY,
+    company_id UUID NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    registry_code VARCHAR(50),
+    vat_number VARCHAR(50),
+    ibans JSONB NOT NULL DEFAULT '[]'::jsonb,
+    default_category_id UUID REFERENCES transaction_categories(id) ON DELETE SET NULL,
+    default_vat_rate DECIMAL(5,2),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_vendors_company_id ON vendors(company_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_vendors_company_registry_code ON vendors(company_id, registry_code) WHERE registry_code IS NOT NULL;
+
+ALTER TABLE received_invoices ADD COLUMN IF NOT EXISTS vendor_id UUID REFERENCES vendors(id) ON DELETE SET NULL;
+ALTER TABLE bank_transactions ADD COLUMN IF NOT EXISTS vendor_id UUID REFERENCES vendors(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_received_invoices_vendor_id ON received_invoices(vendor_id) WHERE vendor_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_bank_transactions_vendor_id ON bank_transactions(vendor_id) WHERE vendor_id IS NOT NULL;
+
+-- One vendor per case-insensitive vendor name already on received invoices
+INSERT INTO vendors (id, company_id, name, created_at, updated_at)
+SELECT gen_random_uuid(), company_id, MIN(TRIM(vendor_name)), MIN(created_at), MIN(created_at)
+FROM received_invoices
+WHERE TRIM(vendor_name) <> ''
+GROUP BY company_id, LOWER(TRIM(vendor_name));
+
+UPDATE received_invoices r
+SET vendor_id = v.id
+FROM vendors v
+WHERE r.vendor_id IS NULL
+  AND v.company_id = r.company_id
+  AND LOWER(v.name) = LOWER(TRIM(r.vendor_name));
+
+COMMENT ON TABLE vendors IS 'Vendors of received invoices and outgoing payments';
+COMMENT ON COLUMN vendors.ibans IS 'JSON array of known vendor IBANs';
+COMMENT ON COLUMN vendors.default_vat_rate IS 'VAT rate in percent assumed for invoices without a VAT amount';
@@ -0,0 +1,17 @@
+-- Upload jobs are identified by what the file documents (file_key) rather
+-- than by its name, which moves when another file takes it: two same-day
+-- payments to the same counterparty used to replace each other's job. A push
+-- claims a job by setting it in_progress until next_attempt_at, so the
+-- background worker and a request never upload the same file twice.
+ALTER TABLE upload_jobs ADD COLUMN file_key TEXT;
+
+-- An invoice has a single PDF; earlier report files keep their path as key
+UPDATE upload_jobs
+SET file_key = CASE WHEN target = 'invoice_pdf' THEN 'pdf' ELSE path END;
+
+ALTER TABLE upload_jobs ALTER COLUMN file_key SET NOT NULL;
+ALTER TABLE upload_jobs DROP CONSTRAINT upload_jobs_target_owner_id_path_key;
+ALTER TABLE upload_jobs ADD CONSTRAINT upload_jobs_target_owner_id_file_key_key
+    UNIQUE (target, owner_id, file_key);
+
+COMMENT ON COLUMN upload_jobs.status IS 'pending (retried with exponential backoff), in_progress (claimed by a push until next_attempt_at), succeeded or failed (gave up)';
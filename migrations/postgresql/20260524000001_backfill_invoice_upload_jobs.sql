@@ -0,0 +1,30 @@
+-- Invoice PDFs uploaded to Drive before the upload outbox have a Drive file
+-- ID on the invoice but no upload job, so the Drive reconciliation never
+-- checked them. Record the ones with a local PDF to upload again as uploaded
+-- jobs.
+INSERT INTO upload_jobs (id, company_id, target, owner_id, file_key, path, folder_id, local_path, mime_type, status, attempts, next_attempt_at, cloud_id, created_at, updated_at)
+SELECT
+    gen_random_uuid(),
+    invoices.company_id,
+    'invoice_pdf',
+    invoices.id,
+    'pdf',
+    invoices.invoice_number,
+    COALESCE(companies.google_drive_folder_id, ''),
+    invoices.pdf_path,
+    'application/pdf',
+    'succeeded',
+    1,
+    NOW(),
+    invoices.pdf_drive_file_id,
+    NOW(),
+    NOW()
+FROM invoices
+JOIN companies ON companies.id = invoices.company_id
+WHERE invoices.pdf_drive_file_id IS NOT NULL
+  AND invoices.pdf_path IS NOT NULL
+  AND invoices.pdf_drive_file_id <> ''
+  AND NOT EXISTS (
+    SELECT 1 FROM upload_jobs
+    WHERE upload_jobs.target = 'invoice_pdf' AND upload_jobs.owner_id = invoices.id
+  );
@@ -0,0 +1,12 @@
+-- Deleting an invoice used to delete its allocations with it, leaving the
+-- reports' matched counts, ledger payments and payable statuses out of date.
+-- A matched invoice now has to be unmatched before it can be deleted.
+ALTER TABLE match_allocations
+    DROP CONSTRAINT IF EXISTS match_allocations_invoice_id_fkey,
+    ADD CONSTRAINT match_allocations_invoice_id_fkey
+        FOREIGN KEY (invoice_id) REFERENCES invoices(id) ON DELETE RESTRICT;
+
+ALTER TABLE match_allocations
+    DROP CONSTRAINT IF EXISTS match_allocations_received_invoice_id_fkey,
+    ADD CONSTRAINT match_allocations_received_invoice_id_fkey
+        FOREIGN KEY (received_invoice_id) REFERENCES received_invoices(id) ON DELETE RESTRICT;
@@ -0,0 +1,13 @@
+-- Per-company invoice branding (logo, colors, fonts, default texts, custom template)
+CREATE TABLE invoice_brandings (
+    company_id TEXT PRIMARY KEY NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    logo_path TEXT,
+    accent_color TEXT NOT NULL,
+    font TEXT NOT NULL,
+    default_notes TEXT,
+    footer_text TEXT,
+    signature_text TEXT,
+    custom_template TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,3 @@
+-- Invoice document language: customers carry a default, invoices store the chosen one
+ALTER TABLE customers ADD COLUMN default_language TEXT NOT NULL DEFAULT 'en';
+ALTER TABLE invoices ADD COLUMN language TEXT NOT NULL DEFAULT 'en';
@@ -0,0 +1,15 @@
+-- Append-only audit log of invoice activity. invoice_id has no foreign key so
+-- the history outlives deleted invoices; rows are never updated or deleted.
+-- changes holds a JSON array of {field, before, after}.
+CREATE TABLE invoice_events (
+    id TEXT PRIMARY KEY NOT NULL,
+    invoice_id TEXT NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    invoice_number TEXT NOT NULL,
+    user_id TEXT REFERENCES users(id) ON DELETE SET NULL,
+    kind TEXT NOT NULL,
+    changes TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_invoice_events_invoice_id ON invoice_events(invoice_id, created_at DESC);
@@ -0,0 +1,14 @@
+-- Tamper-evident SHA-256 hash chain over issued invoices, one chain per company.
+-- Each link hashes the previous link's hash plus the canonical invoice payload.
+-- invoice_id has no foreign key so deleting a sealed invoice shows up as a
+-- broken link instead of silently dropping it from the chain.
+CREATE TABLE invoice_chain_links (
+    id TEXT PRIMARY KEY NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    invoice_id TEXT NOT NULL UNIQUE,
+    sequence INTEGER NOT NULL,
+    previous_hash TEXT,
+    hash TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (company_id, sequence)
+);
@@ -0,0 +1,42 @@
+-- Double-entry general ledger: per-company chart of accounts and journal.
+-- Journal entries are append-only; corrections are posted as reversing entries.
+CREATE TABLE ledger_accounts (
+    id TEXT PRIMARY KEY NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    code TEXT NOT NULL,
+    name TEXT NOT NULL,
+    account_type TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (company_id, code)
+);
+
+-- source_id points at the invoice / received invoice an automatic entry was
+-- posted for; no foreign key so the books survive document deletion.
+CREATE TABLE journal_entries (
+    id TEXT PRIMARY KEY NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    entry_date TEXT NOT NULL,
+    description TEXT NOT NULL,
+    source TEXT NOT NULL,
+    source_id TEXT,
+    reversal_of TEXT UNIQUE REFERENCES journal_entries(id),
+    created_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_journal_entries_company_date ON journal_entries(company_id, entry_date);
+CREATE INDEX idx_journal_entries_source ON journal_entries(company_id, source, source_id);
+
+-- Amounts are stored as decimal strings like the rest of the SQLite schema
+CREATE TABLE journal_lines (
+    id TEXT PRIMARY KEY NOT NULL,
+    entry_id TEXT NOT NULL REFERENCES journal_entries(id) ON DELETE CASCADE,
+    account_id TEXT NOT NULL REFERENCES ledger_accounts(id),
+    debit TEXT NOT NULL DEFAULT '0',
+    credit TEXT NOT NULL DEFAULT '0',
+    description TEXT,
+    line_order INTEGER NOT NULL
+);
+
+CREATE INDEX idx_journal_lines_entry_id ON journal_lines(entry_id);
+CREATE INDEX idx_journal_lines_account_id ON journal_lines(account_id);
@@ -0,0 +1,27 @@
+-- Per-company categories for bank transactions that have no invoice
+CREATE TABLE IF NOT EXISTS transaction_categories (
+    id TEXT PRIMARY KEY NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    CONSTRAINT transaction_categories_company_name_unique UNIQUE (company_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_transaction_categories_company_id ON transaction_categories(company_id);
+
+ALTER TABLE bank_transactions ADD COLUMN category_id TEXT REFERENCES transaction_categories(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_bank_transactions_category ON bank_transactions(category_id) WHERE category_id IS NOT NULL;
+
+-- Categories learned from manual categorisations, keyed on a normalized
+-- counterparty account, registry code or name per transaction direction
+CREATE TABLE IF NOT EXISTS transaction_category_suggestions (
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    direction TEXT NOT NULL,
+    match_field TEXT NOT NULL,
+    match_value TEXT NOT NULL,
+    category_id TEXT NOT NULL REFERENCES transaction_categories(id) ON DELETE CASCADE,
+    times_used INTEGER NOT NULL DEFAULT 1,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (company_id, direction, match_field, match_value)
+);
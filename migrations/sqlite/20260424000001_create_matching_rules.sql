@@ -0,0 +1,24 @@
+-- User-defined rules evaluated in position order when a bank statement is
+-- imported. Rules assigning a category go away with the category.
+CREATE TABLE IF NOT EXISTS matching_rules (
+    id TEXT PRIMARY KEY NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    counterparty_iban TEXT,
+    description_pattern TEXT,
+    min_amount TEXT,
+    max_amount TEXT,
+    direction TEXT,
+    action TEXT NOT NULL,
+    category_id TEXT REFERENCES transaction_categories(id) ON DELETE CASCADE,
+    vendor_name TEXT,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_matching_rules_company_position ON matching_rules(company_id, position);
+
+-- Rule that fired on import and the outcomes a rule can set
+ALTER TABLE bank_transactions ADD COLUMN rule_id TEXT REFERENCES matching_rules(id) ON DELETE SET NULL;
+ALTER TABLE bank_transactions ADD COLUMN ignored INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE bank_transactions ADD COLUMN needs_review INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,40 @@
+-- Parts of bank transactions settling issued or received invoices. A payment
+-- can settle several invoices and an invoice can be paid in several transfers.
+CREATE TABLE IF NOT EXISTS match_allocations (
+    id TEXT PRIMARY KEY NOT NULL,
+    transaction_id TEXT NOT NULL REFERENCES bank_transactions(id) ON DELETE CASCADE,
+    invoice_id TEXT REFERENCES invoices(id) ON DELETE CASCADE,
+    received_invoice_id TEXT REFERENCES received_invoices(id) ON DELETE CASCADE,
+    amount TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    CONSTRAINT match_allocation_one_document CHECK (
+        (invoice_id IS NOT NULL) <> (received_invoice_id IS NOT NULL)
+    )
+);
+
+CREATE INDEX IF NOT EXISTS idx_match_allocations_transaction_id ON match_allocations(transaction_id);
+CREATE INDEX IF NOT EXISTS idx_match_allocations_invoice_id ON match_allocations(invoice_id) WHERE invoice_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_match_allocations_received_invoice_id ON match_allocations(received_invoice_id) WHERE received_invoice_id IS NOT NULL;
+
+-- Existing matches settle their document with the whole transaction
+INSERT INTO match_allocations (id, transaction_id, invoice_id, received_invoice_id, amount, created_at)
+SELECT
+    lower(hex(randomblob(4))) || '-' || lower(hex(randomblob(2))) || '-4' ||
+        substr(lower(hex(randomblob(2))), 2) || '-' ||
+        substr('89ab', 1 + (abs(random()) % 4), 1) || substr(lower(hex(randomblob(2))), 2) || '-' ||
+        lower(hex(randomblob(6))),
+    id,
+    matched_invoice_id,
+    matched_received_invoice_id,
+    CASE WHEN amount LIKE '-%' THEN substr(amount, 2) ELSE amount END,
+    created_at
+FROM bank_transactions
+WHERE (matched_invoice_id IS NOT NULL OR matched_received_invoice_id IS NOT NULL)
+  AND CAST(amount AS REAL) <> 0;
+
+-- matched_invoice_id and matched_received_invoice_id are no longer used. SQLite
+-- cannot drop columns that foreign keys and CHECK constraints refer to, so
+-- they stay, always NULL for new transactions.
+DROP INDEX IF EXISTS idx_bank_transactions_matched_invoice;
+DROP INDEX IF EXISTS idx_bank_transactions_matched_received;
+UPDATE bank_transactions SET matched_invoice_id = NULL, matched_received_invoice_id = NULL;
@@ -0,0 +1,13 @@
+-- Balances and period from the statement's balance rows, and rows left out
+-- because an overlapping statement of the same account already had them
+ALTER TABLE monthly_reports ADD COLUMN opening_balance TEXT;
+ALTER TABLE monthly_reports ADD COLUMN closing_balance TEXT;
+ALTER TABLE monthly_reports ADD COLUMN period_start TEXT;
+ALTER TABLE monthly_reports ADD COLUMN period_end TEXT;
+ALTER TABLE monthly_reports ADD COLUMN duplicate_count INTEGER NOT NULL DEFAULT 0;
+
+-- Bank's identifiers for each statement row
+ALTER TABLE bank_transactions ADD COLUMN document_number TEXT;
+ALTER TABLE bank_transactions ADD COLUMN archive_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_bank_transactions_archive_id ON bank_transactions(archive_id) WHERE archive_id IS NOT NULL;
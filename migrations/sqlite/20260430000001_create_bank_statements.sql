@@ -0,0 +1,43 @@
+-- A monthly report aggregates one statement per company bank account
+CREATE TABLE IF NOT EXISTS bank_statements (
+    id TEXT PRIMARY KEY NOT NULL,
+    report_id TEXT NOT NULL REFERENCES monthly_reports(id) ON DELETE CASCADE,
+    bank_account_id TEXT REFERENCES bank_accounts(id) ON DELETE SET NULL,
+    bank_account_iban TEXT NOT NULL,
+    total_incoming TEXT NOT NULL DEFAULT '0',
+    total_outgoing TEXT NOT NULL DEFAULT '0',
+    transaction_count INTEGER NOT NULL DEFAULT 0,
+    opening_balance TEXT,
+    closing_balance TEXT,
+    period_start TEXT NOT NULL,
+    period_end TEXT NOT NULL,
+    duplicate_count INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    CONSTRAINT bank_statements_report_iban_unique UNIQUE (report_id, bank_account_iban)
+);
+
+CREATE INDEX IF NOT EXISTS idx_bank_statements_report_id ON bank_statements(report_id);
+CREATE INDEX IF NOT EXISTS idx_bank_statements_iban ON bank_statements(bank_account_iban);
+
+-- Existing reports hold a single statement; it reuses the report's ID
+INSERT INTO bank_statements (id, report_id, bank_account_id, bank_account_iban, total_incoming, total_outgoing, transaction_count, opening_balance, closing_balance, period_start, period_end, duplicate_count, created_at)
+SELECT r.id, r.id,
+    (SELECT b.id FROM bank_accounts b WHERE b.company_id = r.company_id AND b.iban = r.bank_account_iban LIMIT 1),
+    r.bank_account_iban, r.total_incoming, r.total_outgoing, r.transaction_count,
+    r.opening_balance, r.closing_balance,
+    COALESCE(r.period_start, printf('%04d-%02d-01', r.year, r.month)),
+    COALESCE(r.period_end, date(printf('%04d-%02d-01', r.year, r.month), '+1 month', '-1 day')),
+    r.duplicate_count, r.created_at
+FROM monthly_reports r
+WHERE r.bank_account_iban IS NOT NULL AND r.bank_account_iban <> '';
+
+ALTER TABLE bank_transactions ADD COLUMN statement_id TEXT REFERENCES bank_statements(id) ON DELETE CASCADE;
+UPDATE bank_transactions SET statement_id = report_id WHERE report_id IN (SELECT id FROM bank_statements);
+CREATE INDEX IF NOT EXISTS idx_bank_transactions_statement_id ON bank_transactions(statement_id);
+
+ALTER TABLE monthly_reports DROP COLUMN bank_account_iban;
+ALTER TABLE monthly_reports DROP COLUMN opening_balance;
+ALTER TABLE monthly_reports DROP COLUMN closing_balance;
+ALTER TABLE monthly_reports DROP COLUMN period_start;
+ALTER TABLE monthly_reports DROP COLUMN period_end;
+ALTER TABLE monthly_reports DROP COLUMN duplicate_count;
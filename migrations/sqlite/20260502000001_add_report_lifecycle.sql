@@ -0,0 +1,33 @@
+-- Number of times a report was generated; reports generated before versioning
+-- count as version 1
+ALTER TABLE monthly_reports ADD COLUMN version INTEGER NOT NULL DEFAULT 0;
+UPDATE monthly_reports SET version = 1 WHERE status = 'generated';
+
+-- Append-only audit log of report lifecycle transitions. report_id has no
+-- foreign key so the history outlives deleted reports; rows are never updated
+-- or deleted.
+CREATE TABLE report_events (
+    id TEXT PRIMARY KEY NOT NULL,
+    report_id TEXT NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    user_id TEXT REFERENCES users(id) ON DELETE SET NULL,
+    kind TEXT NOT NULL,
+    version INTEGER NOT NULL,
+    reason TEXT,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_report_events_report_id ON report_events(report_id, created_at DESC);
+
+-- Folders and files uploaded to cloud storage for a report, keyed by their
+-- path below the report's month folder. content_hash is the SHA-256 of a
+-- file's content and NULL for folders.
+CREATE TABLE report_uploads (
+    report_id TEXT NOT NULL REFERENCES monthly_reports(id) ON DELETE CASCADE,
+    path TEXT NOT NULL,
+    cloud_id TEXT NOT NULL,
+    content_hash TEXT,
+    version INTEGER NOT NULL,
+    uploaded_at TEXT NOT NULL,
+    PRIMARY KEY (report_id, path)
+);
@@ -0,0 +1,27 @@
+-- Outbox of files to push to a company's storage provider. One row per file:
+-- queueing the same path again replaces it. owner_id is the report or the
+-- invoice, depending on target. Pending jobs are retried with exponential
+-- backoff until they succeed or fail for good.
+CREATE TABLE upload_jobs (
+    id TEXT PRIMARY KEY NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    target TEXT NOT NULL,
+    owner_id TEXT NOT NULL,
+    path TEXT NOT NULL,
+    folder_id TEXT NOT NULL,
+    replace_file_id TEXT,
+    local_path TEXT NOT NULL,
+    mime_type TEXT NOT NULL,
+    content_hash TEXT,
+    requested_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    status TEXT NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TEXT NOT NULL,
+    last_error TEXT,
+    cloud_id TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    UNIQUE (target, owner_id, path)
+);
+
+CREATE INDEX idx_upload_jobs_due ON upload_jobs(target, status, next_attempt_at);
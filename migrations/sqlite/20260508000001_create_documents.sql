@@ -0,0 +1,29 @@
+-- Uploaded and generated files. A company stores each content once, keyed by
+-- its SHA-256; entities reference documents through document_links.
+-- integrity_status is set by the periodic integrity check.
+CREATE TABLE documents (
+    id TEXT PRIMARY KEY NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    sha256 TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    mime_type TEXT NOT NULL,
+    original_name TEXT NOT NULL,
+    storage_key TEXT NOT NULL,
+    uploaded_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    integrity_status TEXT NOT NULL DEFAULT 'ok',
+    checked_at TEXT,
+    created_at TEXT NOT NULL,
+    UNIQUE (company_id, sha256)
+);
+
+-- One document per owner: owner_type is invoice_pdf, received_invoice or
+-- transaction_receipt, owner_id the ID of that entity.
+CREATE TABLE document_links (
+    owner_type TEXT NOT NULL,
+    owner_id TEXT NOT NULL,
+    document_id TEXT NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (owner_type, owner_id)
+);
+
+CREATE INDEX idx_document_links_document ON document_links(document_id);
@@ -0,0 +1,7 @@
+-- Per-company data keys for encrypting stored documents at rest. wrapped_key
+-- is the random data key, encrypted with the instance's master key.
+CREATE TABLE company_data_keys (
+    company_id TEXT PRIMARY KEY NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    wrapped_key TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
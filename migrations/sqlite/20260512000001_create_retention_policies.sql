@@ -0,0 +1,25 @@
+-- Per-company retention period of source documents. Companies without a row
+-- keep them for the default 7 years.
+CREATE TABLE retention_policies (
+    company_id TEXT PRIMARY KEY NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    retention_years INTEGER NOT NULL,
+    updated_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- Append-only log of deletions refused by the retention policy. record_type
+-- is issued_invoice, received_invoice or report; record_id is not a foreign
+-- key so the entry outlives the record.
+CREATE TABLE retention_blocked_deletions (
+    id TEXT PRIMARY KEY NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    record_type TEXT NOT NULL,
+    record_id TEXT NOT NULL,
+    label TEXT NOT NULL,
+    user_id TEXT REFERENCES users(id) ON DELETE SET NULL,
+    retained_until TEXT NOT NULL,
+    attempted_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_retention_blocked_deletions_company
+    ON retention_blocked_deletions(company_id, attempted_at);
@@ -0,0 +1,7 @@
+-- Drive sync status of uploaded files, set by the reconciliation job: in_sync,
+-- moved, renamed or missing (queued again for upload). NULL until the file
+-- is checked after its upload.
+ALTER TABLE upload_jobs ADD COLUMN sync_status TEXT;
+ALTER TABLE upload_jobs ADD COLUMN synced_at TEXT;
+
+CREATE INDEX idx_upload_jobs_company_status ON upload_jobs(company_id, status);
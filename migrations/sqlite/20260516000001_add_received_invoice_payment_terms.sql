@@ -0,0 +1,29 @@
+-- Received invoices tracked as payables: the VAT included in the amount, when
+-- and how to pay, and whether they are paid. Status is unpaid, scheduled,
+-- paid or disputed; paid_on is the date of the settling bank transaction,
+-- NULL when the invoice was marked paid by hand.
+ALTER TABLE received_invoices ADD COLUMN vat_amount TEXT NOT NULL DEFAULT '0';
+ALTER TABLE received_invoices ADD COLUMN due_date TEXT;
+ALTER TABLE received_invoices ADD COLUMN vendor_iban TEXT;
+ALTER TABLE received_invoices ADD COLUMN payment_reference TEXT;
+ALTER TABLE received_invoices ADD COLUMN status TEXT NOT NULL DEFAULT 'unpaid';
+ALTER TABLE received_invoices ADD COLUMN paid_on TEXT;
+
+CREATE INDEX idx_received_invoices_company_status ON received_invoices(company_id, status);
+
+-- Invoices already settled by debit transactions are paid
+UPDATE received_invoices
+SET status = 'paid',
+    paid_on = (
+        SELECT MAX(t.date)
+        FROM match_allocations a
+        JOIN bank_transactions t ON t.id = a.transaction_id
+        WHERE a.received_invoice_id = received_invoices.id AND t.direction = 'debit'
+    )
+WHERE CAST(amount AS REAL) <> 0
+  AND (
+    SELECT COALESCE(SUM(CAST(a.amount AS REAL)), 0)
+    FROM match_allocations a
+    JOIN bank_transactions t ON t.id = a.transaction_id
+    WHERE a.received_invoice_id = received_invoices.id AND t.direction = 'debit'
+  ) >= ABS(CAST(amount AS REAL));
@@ -0,0 +1,46 @@
+-- Per-company vendor directory. Received invoices and outgoing bank
+-- transactions link to a vendor; vendors are unique by registry code.
+CREATE TABLE IF NOT EXISTS vendors (
+    id TEXT PRIMARY KEY NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    registry_code TEXT,
+    vat_number TEXT,
+    ibans TEXT NOT NULL DEFAULT '[]',
+    default_category_id TEXT REFERENCES transaction_categories(id) ON DELETE SET NULL,
+    default_vat_rate TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_vendors_company_id ON vendors(company_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_vendors_company_registry_code ON vendors(company_id, registry_code) WHERE registry_code IS NOT NULL;
+
+ALTER TABLE received_invoices ADD COLUMN vendor_id TEXT REFERENCES vendors(id) ON DELETE SET NULL;
+ALTER TABLE bank_transactions ADD COLUMN vendor_id TEXT REFERENCES vendors(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_received_invoices_vendor_id ON received_invoices(vendor_id) WHERE vendor_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_bank_transactions_vendor_id ON bank_transactions(vendor_id) WHERE vendor_id IS NOT NULL;
+
+-- One vendor per case-insensitive vendor name already on received invoices
+INSERT INTO vendors (id, company_id, name, created_at, updated_at)
+SELECT
+    lower(hex(randomblob(4))) || '-' || lower(hex(randomblob(2))) || '-4' ||
+        substr(lower(hex(randomblob(2))), 2) || '-' ||
+        substr('89ab', 1 + (abs(random()) % 4), 1) || substr(lower(hex(randomblob(2))), 2) || '-' ||
+        lower(hex(randomblob(6))),
+    company_id,
+    MIN(trim(vendor_name)),
+    MIN(created_at),
+    MIN(created_at)
+FROM received_invoices
+WHERE trim(vendor_name) <> ''
+GROUP BY company_id, lower(trim(vendor_name));
+
+UPDATE received_invoices
+SET vendor_id = (
+    SELECT v.id FROM vendors v
+    WHERE v.company_id = received_invoices.company_id
+      AND lower(v.name) = lower(trim(received_invoices.vendor_name))
+)
+WHERE vendor_id IS NULL;
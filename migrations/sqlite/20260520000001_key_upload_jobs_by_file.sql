@@ -0,0 +1,43 @@
+-- Upload jobs are identified by what the file documents (file_key) rather
+-- than by its name, which moves when another file takes it: two same-day
+-- payments to the same counterparty used to replace each other's job. A push
+-- claims a job by setting it in_progress until next_attempt_at, so the
+-- background worker and a request never upload the same file twice.
+-- SQLite can't drop a table constraint, so the table is rebuilt.
+CREATE TABLE upload_jobs_new (
+    id TEXT PRIMARY KEY NOT NULL,
+    company_id TEXT NOT NULL REFERENCES companies(id) ON DELETE CASCADE,
+    target TEXT NOT NULL,
+    owner_id TEXT NOT NULL,
+    file_key TEXT NOT NULL,
+    path TEXT NOT NULL,
+    folder_id TEXT NOT NULL,
+    replace_file_id TEXT,
+    local_path TEXT NOT NULL,
+    mime_type TEXT NOT NULL,
+    content_hash TEXT,
+    requested_by TEXT REFERENCES users(id) ON DELETE SET NULL,
+    status TEXT NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TEXT NOT NULL,
+    last_error TEXT,
+    cloud_id TEXT,
+    sync_status TEXT,
+    synced_at TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    UNIQUE (target, owner_id, file_key)
+);
+
+-- An invoice has a single PDF; earlier report files keep their path as key
+INSERT INTO upload_jobs_new (id, company_id, target, owner_id, file_key, path, folder_id, replace_file_id, local_path, mime_type, content_hash, requested_by, status, attempts, next_attempt_at, last_error, cloud_id, sync_status, synced_at, created_at, updated_at)
+SELECT id, company_id, target, owner_id,
+       CASE WHEN target = 'invoice_pdf' THEN 'pdf' ELSE path END,
+       path, folder_id, replace_file_id, local_path, mime_type, content_hash, requested_by, status, attempts, next_attempt_at, last_error, cloud_id, sync_status, synced_at, created_at, updated_at
+FROM upload_jobs;
+
+DROP TABLE upload_jobs;
+ALTER TABLE upload_jobs_new RENAME TO upload_jobs;
+
+CREATE INDEX idx_upload_jobs_due ON upload_jobs(target, status, next_attempt_at);
+CREATE INDEX idx_upload_jobs_company_status ON upload_jobs(company_id, status);
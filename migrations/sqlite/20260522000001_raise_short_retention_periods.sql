@@ -0,0 +1,3 @@
+-- Retention periods below the legal minimum of 7 years can no longer be set;
+-- raise the ones saved before that check
+UPDATE retention_policies SET retention_years = 7 WHERE retention_years < 7;
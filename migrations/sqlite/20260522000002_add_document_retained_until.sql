@@ -0,0 +1,6 @@
+-- Set when a document is replaced while its owner is still under retention:
+-- the superseded file is kept, unlinked, until this day has passed and then
+-- removed by the purge job
+ALTER TABLE documents ADD COLUMN retained_until TEXT;
+
+CREATE INDEX idx_documents_retained_until ON documents(retained_until);
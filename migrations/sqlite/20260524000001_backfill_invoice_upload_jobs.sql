@@ -0,0 +1,39 @@
+-- Invoice PDFs uploaded to Drive before the upload outbox have a Drive file
+-- ID on the invoice but no upload job, so the Drive reconciliation never
+-- checked them. Record the ones with a local PDF to upload again as uploaded
+-- jobs.
+INSERT INTO upload_jobs (id, company_id, target, owner_id, file_key, path, folder_id, replace_file_id, local_path, mime_type, content_hash, requested_by, status, attempts, next_attempt_at, last_error, cloud_id, sync_status, synced_at, created_at, updated_at)
+SELECT
+    lower(hex(randomblob(4))) || '-' || lower(hex(randomblob(2))) || '-4' ||
+        substr(lower(hex(randomblob(2))), 2) || '-' ||
+        substr('89ab', 1 + (abs(random()) % 4), 1) || substr(lower(hex(randomblob(2))), 2) || '-' ||
+        lower(hex(randomblob(6))),
+    invoices.company_id,
+    'invoice_pdf',
+    invoices.id,
+    'pdf',
+    invoices.invoice_number,
+    COALESCE(companies.google_drive_folder_id, ''),
+    NULL,
+    invoices.pdf_path,
+    'application/pdf',
+    NULL,
+    NULL,
+    'succeeded',
+    1,
+    strftime('%Y-%m-%dT%H:%M:%fZ', 'now'),
+    NULL,
+    invoices.pdf_drive_file_id,
+    NULL,
+    NULL,
+    strftime('%Y-%m-%dT%H:%M:%fZ', 'now'),
+    strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+FROM invoices
+JOIN companies ON companies.id = invoices.company_id
+WHERE invoices.pdf_drive_file_id IS NOT NULL
+  AND invoices.pdf_path IS NOT NULL
+  AND invoices.pdf_drive_file_id <> ''
+  AND NOT EXISTS (
+    SELECT 1 FROM upload_jobs
+    WHERE upload_jobs.target = 'invoice_pdf' AND upload_jobs.owner_id = invoices.id
+  );
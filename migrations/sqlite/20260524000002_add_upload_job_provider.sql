@@ -0,0 +1,11 @@
+-- Storage provider a job's file was queued for (google_drive, s3, webdav,
+-- local or none), so the Drive reconciliation only checks files that went to
+-- Drive. Earlier jobs are taken to have gone to the company's current
+-- provider.
+ALTER TABLE upload_jobs ADD COLUMN provider TEXT NOT NULL DEFAULT 'none';
+
+UPDATE upload_jobs
+SET provider = COALESCE(
+    (SELECT lower(companies.storage_provider) FROM companies WHERE companies.id = upload_jobs.company_id),
+    'none'
+);
@@ -0,0 +1,3 @@
+-- Whether a job uploads its file or removes an earlier upload, e.g. a report
+-- file that a regeneration no longer includes
+ALTER TABLE upload_jobs ADD COLUMN action TEXT NOT NULL DEFAULT 'upload';
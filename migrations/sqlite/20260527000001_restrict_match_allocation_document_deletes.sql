@@ -0,0 +1,26 @@
+-- Deleting an invoice used to delete its allocations with it, leaving the
+-- reports' matched counts, ledger payments and payable statuses out of date.
+-- A matched invoice now has to be unmatched before it can be deleted.
+-- SQLite can't change a foreign key, so the table is rebuilt.
+CREATE TABLE match_allocations_new (
+    id TEXT PRIMARY KEY NOT NULL,
+    transaction_id TEXT NOT NULL REFERENCES bank_transactions(id) ON DELETE CASCADE,
+    invoice_id TEXT REFERENCES invoices(id) ON DELETE RESTRICT,
+    received_invoice_id TEXT REFERENCES received_invoices(id) ON DELETE RESTRICT,
+    amount TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    CONSTRAINT match_allocation_one_document CHECK (
+        (invoice_id IS NOT NULL) <> (received_invoice_id IS NOT NULL)
+    )
+);
+
+INSERT INTO match_allocations_new (id, transaction_id, invoice_id, received_invoice_id, amount, created_at)
+SELECT id, transaction_id, invoice_id, received_invoice_id, amount, created_at
+FROM match_allocations;
+
+DROP TABLE match_allocations;
+ALTER TABLE match_allocations_new RENAME TO match_allocations;
+
+CREATE INDEX idx_match_allocations_transaction_id ON match_allocations(transaction_id);
+CREATE INDEX idx_match_allocations_invoice_id ON match_allocations(invoice_id) WHERE invoice_id IS NOT NULL;
+CREATE INDEX idx_match_allocations_received_invoice_id ON match_allocations(received_invoice_id) WHERE received_invoice_id IS NOT NULL;
@@ -0,0 +1,16 @@
+#!/bin/bash
+
+# Downloads the OECD SAF-T 2.00 schema the SAF-T writer tests validate against.
+#
+#   SAFT_SCHEMA_URL     where to download oecd-saf-t-2.00.xsd from
+#   SAFT_SCHEMA_SHA256  expected checksum of the file
+
+set -euo pipefail
+
+: "${SAFT_SCHEMA_URL:?Set SAFT_SCHEMA_URL to the OECD SAF-T 2.00 schema URL}"
+: "${SAFT_SCHEMA_SHA256:?Set SAFT_SCHEMA_SHA256 to the schema's checksum}"
+
+TARGET="$(dirname "$0")/../schemas/saft/oecd-saf-t-2.00.xsd"
+
+curl --fail --silent --show-error --location "$SAFT_SCHEMA_URL" --output "$TARGET"
+echo "$SAFT_SCHEMA_SHA256  $TARGET" | sha256sum --check
@@ -4,12 +4,19 @@ use actix_web::{
   http::{StatusCode, header::ContentType},
 };
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fmt;
 
 use crate::domain::auth::errors::{AuthError, RepositoryError};
 use crate::domain::company::CompanyError;
+use crate::domain::document::DocumentError;
+use crate::domain::financials::FinancialsError;
 use crate::domain::invoice::InvoiceError;
+use crate::domain::ledger::LedgerError;
 use crate::domain::report::ReportError;
+use crate::domain::retention::RetentionError;
+use crate::domain::saft::SaftError;
+use crate::domain::storage::StorageError;
 
 use super::dtos::ErrorResponse;
 
@@ -20,6 +27,9 @@ pub enum ApiError {
   /// Validation error (400 Bad Request)
   Validation(String),
 
+  /// Validation errors keyed by input field (400 Bad Request)
+  FieldValidation(BTreeMap<String, String>),
+
   /// Authentication error (401 Unauthorized or 403 Forbidden)
   Auth(AuthErrorKind),
 
@@ -59,6 +69,11 @@ impl fmt::Display for ApiError {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       ApiError::Validation(msg) => write!(f, "Validation error: {}", msg),
+      ApiError::FieldValidation(fields) => write!(
+        f,
+        "Validation error in fields: {}",
+        fields.keys().cloned().collect::<Vec<_>>().join(", ")
+      ),
       ApiError::Auth(kind) => write!(f, "Authentication error: {:?}", kind),
       ApiError::Internal(msg) => write!(f, "Internal error: {}", msg),
     }
@@ -68,7 +83,7 @@ impl fmt::Display for ApiError {
 impl ResponseError for ApiError {
   fn status_code(&self) -> StatusCode {
     match self {
-      ApiError::Validation(_) => StatusCode::BAD_REQUEST,
+      ApiError::Validation(_) | ApiError::FieldValidation(_) => StatusCode::BAD_REQUEST,
       ApiError::Auth(kind) => match kind {
         AuthErrorKind::InvalidCredentials => StatusCode::UNAUTHORIZED,
         AuthErrorKind::InvalidSession => StatusCode::UNAUTHORIZED,
@@ -87,6 +102,11 @@ impl ResponseError for ApiError {
     let status = self.status_code();
     let (error_type, message, details) = match self {
       ApiError::Validation(msg) => ("validation_error", msg.clone(), None),
+      ApiError::FieldValidation(fields) => (
+        "validation_error",
+        "Please correct the highlighted fields".to_string(),
+        serde_json::to_value(fields).ok(),
+      ),
       ApiError::Auth(kind) => {
         let (err_type, msg) = match kind {
           AuthErrorKind::InvalidCredentials => (
@@ -242,6 +262,12 @@ impl From<InvoiceError> for ApiError {
       InvoiceError::NoLineItems => {
         ApiError::Validation("At least one line item is required".to_string())
       }
+      InvoiceError::InvalidFields(errors) => ApiError::FieldValidation(
+        errors
+          .into_iter()
+          .map(|error| (error.field, error.message))
+          .collect(),
+      ),
       InvoiceError::InvalidLineItemOrder => {
         ApiError::Validation("Invalid line item order".to_string())
       }
@@ -250,6 +276,9 @@ impl From<InvoiceError> for ApiError {
         ApiError::Validation(format!("A template with name '{}' already exists", name))
       }
       InvoiceError::CannotDeleteInvoice(msg) => ApiError::Validation(msg),
+      InvoiceError::ChainConflict => {
+        ApiError::Internal("Could not seal the invoice, please try again".to_string())
+      }
       InvoiceError::PdfGenerationFailed(msg) => ApiError::Internal(msg),
       InvoiceError::CloudStorageUploadFailed(msg) => ApiError::Internal(msg),
       InvoiceError::CloudStorageAuthFailed(msg) => ApiError::Internal(msg),
@@ -269,17 +298,43 @@ impl From<ReportError> for ApiError {
       ReportError::ReceivedInvoiceNotFound => {
         ApiError::Validation("Received invoice not found".to_string())
       }
+      ReportError::VendorNotFound => ApiError::Validation("Vendor not found".to_string()),
+      ReportError::DuplicateVendor => {
+        ApiError::Validation("A vendor with this registry code already exists".to_string())
+      }
+      ReportError::CategoryNotFound => ApiError::Validation("Category not found".to_string()),
+      ReportError::DuplicateCategory => {
+        ApiError::Validation("A category with this name already exists".to_string())
+      }
+      ReportError::CategoryInUse => ApiError::Validation(
+        "Category is used by transactions; clear it from them first".to_string(),
+      ),
+      ReportError::RuleNotFound => ApiError::Validation("Matching rule not found".to_string()),
       ReportError::DuplicateReport => {
         ApiError::Validation("A report for this month already exists".to_string())
       }
+      ReportError::DuplicateStatement => ApiError::Validation(
+        "A statement of this bank account is already imported for this month".to_string(),
+      ),
       ReportError::AlreadyMatched => {
         ApiError::Validation("Transaction is already matched".to_string())
       }
       ReportError::NotMatched => ApiError::Validation("Transaction is not matched".to_string()),
+      ReportError::ReceivedInvoiceMatched => ApiError::Validation(
+        "Received invoice is matched to bank transactions; unmatch them first".to_string(),
+      ),
       ReportError::DirectionMismatch => {
         ApiError::Validation("Direction mismatch for matching".to_string())
       }
       ReportError::NotDraft => ApiError::Validation("Report must be in draft status".to_string()),
+      ReportError::NotGenerated => {
+        ApiError::Validation("Report has not been generated yet".to_string())
+      }
+      ReportError::Generated => {
+        ApiError::Validation("Report is generated; reopen it to make changes".to_string())
+      }
+      ReportError::Locked => ApiError::Validation("Report is signed off and locked".to_string()),
+      ReportError::InsufficientPermissions => ApiError::Auth(AuthErrorKind::Forbidden),
       ReportError::NoMatchedTransactions => {
         ApiError::Validation("No matched transactions to generate".to_string())
       }
@@ -287,11 +342,94 @@ impl From<ReportError> for ApiError {
       ReportError::Validation(msg) => ApiError::Validation(msg),
       ReportError::CloudStorage(msg) => ApiError::Internal(msg),
       ReportError::FileError(msg) => ApiError::Internal(msg),
+      ReportError::Ledger(msg) => ApiError::Internal(msg),
       ReportError::Repository(e) => ApiError::Internal(format!("Repository error: {}", e)),
     }
   }
 }
 
+/// Convert LedgerError to ApiError
+impl From<LedgerError> for ApiError {
+  fn from(error: LedgerError) -> Self {
+    match error {
+      LedgerError::AccountNotFound => ApiError::Validation("Account not found".to_string()),
+      LedgerError::DuplicateAccountCode(code) => {
+        ApiError::Validation(format!("Account code {} is already in use", code))
+      }
+      LedgerError::EntryNotFound => ApiError::Validation("Journal entry not found".to_string()),
+      LedgerError::Unbalanced { debit, credit } => ApiError::Validation(format!(
+        "Debits ({}) and credits ({}) must be equal",
+        debit, credit
+      )),
+      LedgerError::AlreadyReversed => {
+        ApiError::Validation("Journal entry has already been reversed".to_string())
+      }
+      LedgerError::Validation(msg) => ApiError::Validation(msg),
+      LedgerError::Repository(e) => ApiError::Internal(format!("Repository error: {}", e)),
+    }
+  }
+}
+
+impl From<SaftError> for ApiError {
+  fn from(error: SaftError) -> Self {
+    match error {
+      SaftError::InvalidPeriod(msg) => ApiError::Validation(msg),
+      SaftError::CompanyNotFound => ApiError::Validation("Company not found".to_string()),
+      SaftError::DataAccess(e) => ApiError::Internal(format!("SAF-T export failed: {}", e)),
+      SaftError::Serialization(e) => ApiError::Internal(format!("SAF-T export failed: {}", e)),
+    }
+  }
+}
+
+impl From<FinancialsError> for ApiError {
+  fn from(error: FinancialsError) -> Self {
+    match error {
+      FinancialsError::InvalidPeriod(msg) | FinancialsError::Validation(msg) => {
+        ApiError::Validation(msg)
+      }
+      FinancialsError::DataAccess(e) => ApiError::Internal(format!("Statement failed: {}", e)),
+      FinancialsError::Export(e) => ApiError::Internal(format!("Statement export failed: {}", e)),
+    }
+  }
+}
+
+impl From<StorageError> for ApiError {
+  fn from(error: StorageError) -> Self {
+    match error {
+      StorageError::JobNotFound => ApiError::Validation("Upload not found".to_string()),
+      StorageError::Validation(msg) => ApiError::Validation(msg),
+      StorageError::UploadFailed(msg) => ApiError::Internal(msg),
+      StorageError::Repository(e) => ApiError::Internal(format!("Upload queue failed: {}", e)),
+    }
+  }
+}
+
+impl From<DocumentError> for ApiError {
+  fn from(error: DocumentError) -> Self {
+    match error {
+      DocumentError::NotFound => ApiError::Validation("Document not found".to_string()),
+      DocumentError::Validation(msg) => ApiError::Validation(msg),
+      DocumentError::FileError(msg) => ApiError::Internal(msg),
+      DocumentError::Encryption(msg) => ApiError::Internal(msg),
+      DocumentError::Repository(e) => ApiError::Internal(format!("Document store failed: {}", e)),
+    }
+  }
+}
+
+impl From<RetentionError> for ApiError {
+  fn from(error: RetentionError) -> Self {
+    match error {
+      RetentionError::Retained { .. } => ApiError::Validation(error.to_string()),
+      RetentionError::NotMember => ApiError::Auth(AuthErrorKind::InvalidSession),
+      RetentionError::InsufficientPermissions => ApiError::Auth(AuthErrorKind::Forbidden),
+      RetentionError::Validation(msg) => ApiError::Validation(msg),
+      RetentionError::Repository(e) => {
+        ApiError::Internal(format!("Retention policy failed: {}", e))
+      }
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -337,6 +475,9 @@ mod tests {
     let err: ApiError = ReportError::ReceivedInvoiceNotFound.into();
     assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
 
+    let err: ApiError = ReportError::DuplicateVendor.into();
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
     let err: ApiError = ReportError::DuplicateReport.into();
     assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
 
@@ -352,6 +493,18 @@ mod tests {
     let err: ApiError = ReportError::NotDraft.into();
     assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
 
+    let err: ApiError = ReportError::NotGenerated.into();
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+    let err: ApiError = ReportError::Generated.into();
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+    let err: ApiError = ReportError::Locked.into();
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+    let err: ApiError = ReportError::InsufficientPermissions.into();
+    assert_eq!(err.status_code(), StatusCode::FORBIDDEN);
+
     let err: ApiError = ReportError::NoMatchedTransactions.into();
     assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
 
@@ -369,4 +522,44 @@ mod tests {
     let err: ApiError = ReportError::FileError("io error".to_string()).into();
     assert_eq!(err.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
   }
+
+  #[test]
+  fn test_storage_error_conversion() {
+    let err: ApiError = StorageError::JobNotFound.into();
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+    let err: ApiError = StorageError::Validation("not failed".to_string()).into();
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+    let err: ApiError = StorageError::UploadFailed("timeout".to_string()).into();
+    assert_eq!(err.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+  }
+
+  #[test]
+  fn test_document_error_conversion() {
+    let err: ApiError = DocumentError::NotFound.into();
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+    let err: ApiError = DocumentError::Validation("File is empty".to_string()).into();
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+    let err: ApiError = DocumentError::FileError("disk full".to_string()).into();
+    assert_eq!(err.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let err: ApiError = DocumentError::Encryption("Decryption failed".to_string()).into();
+    assert_eq!(err.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+  }
+
+  #[test]
+  fn test_retention_error_conversion() {
+    let err: ApiError = RetentionError::Retained {
+      label: "Invoice INV-0001".to_string(),
+      until: chrono::NaiveDate::from_ymd_opt(2032, 12, 31).unwrap(),
+    }
+    .into();
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+
+    let err: ApiError = RetentionError::InsufficientPermissions.into();
+    assert_eq!(err.status_code(), StatusCode::FORBIDDEN);
+  }
 }
@@ -1,4 +1,6 @@
+use actix_multipart::Multipart;
 use actix_web::{HttpMessage, HttpRequest, HttpResponse, web};
+use futures_util::StreamExt;
 use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -7,12 +9,25 @@ use crate::adapters::http::errors::ApiError;
 use crate::adapters::http::templates::TemplateEngine;
 use crate::application::company::{
   CompanyAddressData, ConnectGoogleDriveUseCase, DisconnectGoogleDriveCommand,
-  DisconnectGoogleDriveUseCase, GetCompanyDetailsCommand, GetCompanyDetailsUseCase,
-  InitiateOAuthCommand, TestDriveConnectionCommand, TestDriveConnectionUseCase,
-  UpdateCompanyProfileCommand, UpdateCompanyProfileUseCase, UpdateStorageConfigCommand,
-  UpdateStorageConfigUseCase,
+  DisconnectGoogleDriveUseCase, GetCompanyDetailsCommand, GetCompanyDetailsResponse,
+  GetCompanyDetailsUseCase, InitiateOAuthCommand, ListDriveFoldersCommand, ListDriveFoldersUseCase,
+  TestDriveConnectionCommand, TestDriveConnectionUseCase, UpdateCompanyProfileCommand,
+  UpdateCompanyProfileUseCase, UpdateStorageConfigCommand, UpdateStorageConfigUseCase,
+};
+use crate::application::invoice::{
+  GetInvoiceBrandingCommand, GetInvoiceBrandingUseCase, InvoiceBrandingDto,
+  PreviewInvoiceBrandingUseCase, UpdateInvoiceBrandingCommand, UpdateInvoiceBrandingUseCase,
+};
+use crate::application::retention::{
+  GetRetentionSettingsCommand, GetRetentionSettingsUseCase, UpdateRetentionPolicyCommand,
+  UpdateRetentionPolicyUseCase,
 };
 use crate::domain::auth::entities::User;
+use crate::domain::company::StorageConfig;
+use crate::domain::invoice::InvoiceFont;
+
+/// Maximum accepted size of an invoice logo upload
+const MAX_LOGO_SIZE: usize = 2 * 1024 * 1024;
 
 /// Helper function to extract authenticated user from request
 fn get_user(req: &HttpRequest) -> Result<User, ApiError> {
@@ -30,6 +45,46 @@ fn get_user(req: &HttpRequest) -> Result<User, ApiError> {
   }
 }
 
+/// Build the context shared by all tabs of the company settings page
+fn settings_page_context(
+  user: &User,
+  company_details: &GetCompanyDetailsResponse,
+  branding: &InvoiceBrandingDto,
+) -> tera::Context {
+  let fonts: Vec<(&str, &str)> = InvoiceFont::all()
+    .iter()
+    .map(|font| (font.as_str(), font.css_family()))
+    .collect();
+
+  let mut context = tera::Context::new();
+  context.insert("user", user);
+  context.insert("company", company_details);
+  context.insert("branding", branding);
+  context.insert("fonts", &fonts);
+  context.insert("current_page", "settings");
+
+  // Prefill the S3 and WebDAV forms; secrets are never sent back
+  match company_details
+    .storage_config
+    .as_deref()
+    .and_then(|json| serde_json::from_str::<StorageConfig>(json).ok())
+  {
+    Some(StorageConfig::S3(mut s3_config)) => {
+      s3_config.secret_access_key.clear();
+      context.insert("s3_config", &s3_config);
+    }
+    Some(StorageConfig::WebDav(mut webdav_config)) => {
+      webdav_config.app_password.clear();
+      context.insert("webdav_config", &webdav_config);
+    }
+    Some(StorageConfig::Local(local_config)) => {
+      context.insert("local_config", &local_config);
+    }
+    _ => {}
+  }
+  context
+}
+
 /// GET /companies/:id/settings - Company settings page
 pub async fn company_settings_page(
   req: HttpRequest,
@@ -37,6 +92,8 @@ pub async fn company_settings_page(
   query: web::Query<std::collections::HashMap<String, String>>,
   templates: web::Data<TemplateEngine>,
   get_company_details: web::Data<Arc<GetCompanyDetailsUseCase>>,
+  get_invoice_branding: web::Data<Arc<GetInvoiceBrandingUseCase>>,
+  get_retention_settings: web::Data<Arc<GetRetentionSettingsUseCase>>,
 ) -> Result<HttpResponse, ApiError> {
   let user = get_user(&req)?;
   let company_id = path.into_inner();
@@ -49,10 +106,22 @@ pub async fn company_settings_page(
     })
     .await?;
 
-  let mut context = tera::Context::new();
-  context.insert("user", &user);
-  context.insert("company", &company_details);
-  context.insert("current_page", "settings");
+  let branding = get_invoice_branding
+    .execute(GetInvoiceBrandingCommand {
+      user_id: user.id,
+      company_id,
+    })
+    .await?;
+
+  let retention = get_retention_settings
+    .execute(GetRetentionSettingsCommand {
+      user_id: user.id,
+      company_id,
+    })
+    .await?;
+
+  let mut context = settings_page_context(&user, &company_details, &branding);
+  context.insert("retention", &retention);
 
   // Check for success parameter
   if let Some(success) = query.get("success") {
@@ -71,11 +140,18 @@ pub struct UpdateStorageConfigForm {
   pub storage_provider: String,
   pub google_drive_key: Option<String>,
   pub google_drive_folder_id: Option<String>,
+  pub reports_folder_id: Option<String>,
   pub s3_bucket: Option<String>,
   pub s3_region: Option<String>,
   pub s3_access_key: Option<String>,
   pub s3_secret_key: Option<String>,
   pub s3_prefix: Option<String>,
+  pub s3_endpoint: Option<String>,
+  pub webdav_url: Option<String>,
+  pub webdav_username: Option<String>,
+  pub webdav_app_password: Option<String>,
+  pub webdav_root_folder: Option<String>,
+  pub local_directory: Option<String>,
 }
 
 /// POST /companies/:id/settings/storage - Update storage configuration
@@ -94,6 +170,14 @@ pub async fn update_storage_config(
     form.storage_provider
   );
 
+  let filled = |value: &Option<String>| {
+    value
+      .as_deref()
+      .map(str::trim)
+      .filter(|value| !value.is_empty())
+      .map(str::to_string)
+  };
+
   // Build storage config JSON based on provider
   let storage_config_json = match form.storage_provider.as_str() {
     "google_drive" => {
@@ -108,28 +192,59 @@ pub async fn update_storage_config(
     }
     "s3" => {
       if let (Some(bucket), Some(region), Some(access_key), Some(secret_key)) = (
-        &form.s3_bucket,
-        &form.s3_region,
-        &form.s3_access_key,
-        &form.s3_secret_key,
+        filled(&form.s3_bucket),
+        filled(&form.s3_region),
+        filled(&form.s3_access_key),
+        filled(&form.s3_secret_key),
+      ) {
+        let mut config = serde_json::json!({
+          "provider": "s3",
+          "bucket": bucket,
+          "region": region,
+          "access_key_id": access_key,
+          "secret_access_key": secret_key,
+          "prefix": filled(&form.s3_prefix).unwrap_or_default()
+        });
+        // Only set for S3-compatible services (MinIO, Backblaze B2, ...)
+        if let Some(endpoint) = filled(&form.s3_endpoint) {
+          config["endpoint"] = serde_json::Value::String(endpoint);
+        }
+        Some(config.to_string())
+      } else {
+        return Err(ApiError::Validation(
+          "S3 bucket, region, and credentials are required".to_string(),
+        ));
+      }
+    }
+    "webdav" => {
+      if let (Some(url), Some(username), Some(app_password)) = (
+        filled(&form.webdav_url),
+        filled(&form.webdav_username),
+        filled(&form.webdav_app_password),
       ) {
         Some(
           serde_json::json!({
-            "provider": "s3",
-            "bucket": bucket,
-            "region": region,
-            "access_key_id": access_key,
-            "secret_access_key": secret_key,
-            "prefix": form.s3_prefix.as_deref().unwrap_or("")
+            "provider": "webdav",
+            "url": url,
+            "username": username,
+            "app_password": app_password,
+            "root_folder": filled(&form.webdav_root_folder).unwrap_or_default()
           })
           .to_string(),
         )
       } else {
         return Err(ApiError::Validation(
-          "S3 bucket, region, and credentials are required".to_string(),
+          "WebDAV URL, username, and app password are required".to_string(),
         ));
       }
     }
+    "local" => Some(
+      serde_json::json!({
+        "provider": "local",
+        "directory": filled(&form.local_directory).unwrap_or_default()
+      })
+      .to_string(),
+    ),
     "none" => None,
     _ => {
       return Err(ApiError::Validation(format!(
@@ -147,6 +262,7 @@ pub async fn update_storage_config(
       storage_provider: form.storage_provider.clone(),
       storage_config_json,
       google_drive_folder_id: form.google_drive_folder_id.clone(),
+      reports_folder_id: form.reports_folder_id.clone(),
     })
     .await?;
 
@@ -334,7 +450,43 @@ pub async fn disconnect_drive(
   )
 }
 
-/// POST /companies/:id/drive/test - Test Google Drive connection
+#[derive(Debug, Deserialize)]
+pub struct UpdateRetentionPolicyForm {
+  pub retention_years: u32,
+}
+
+/// POST /companies/:id/settings/retention - Update the retention policy
+pub async fn update_retention_policy(
+  req: HttpRequest,
+  path: web::Path<Uuid>,
+  form: web::Form<UpdateRetentionPolicyForm>,
+  use_case: web::Data<Arc<UpdateRetentionPolicyUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_id = path.into_inner();
+
+  use_case
+    .execute(UpdateRetentionPolicyCommand {
+      user_id: user.id,
+      company_id,
+      retention_years: form.retention_years,
+    })
+    .await?;
+
+  Ok(
+    HttpResponse::SeeOther()
+      .insert_header((
+        "Location",
+        format!(
+          "/companies/{}/settings?tab=retention&success=retention_updated",
+          company_id
+        ),
+      ))
+      .finish(),
+  )
+}
+
+/// POST /companies/:id/drive/test - Test Google Drive or WebDAV connection
 pub async fn test_drive_connection(
   path: web::Path<Uuid>,
   use_case: web::Data<Arc<TestDriveConnectionUseCase>>,
@@ -352,3 +504,309 @@ pub async fn test_drive_connection(
     "message": response.message,
   })))
 }
+
+/// Settings inputs the Drive folder picker can fill in
+const FOLDER_PICKER_TARGETS: [&str; 2] = ["google_drive_folder_id", "reports_folder_id"];
+
+#[derive(Debug, Deserialize)]
+pub struct DriveFoldersQuery {
+  /// Folder or shared drive to open; My Drive when missing
+  pub parent: Option<String>,
+  /// Name of the input the picked folder ID goes into
+  pub target: String,
+}
+
+/// GET /companies/:id/drive/folders - Folder picker for the storage settings
+pub async fn drive_folder_picker(
+  req: HttpRequest,
+  path: web::Path<Uuid>,
+  query: web::Query<DriveFoldersQuery>,
+  templates: web::Data<TemplateEngine>,
+  use_case: web::Data<Arc<ListDriveFoldersUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_id = path.into_inner();
+  let query = query.into_inner();
+
+  if !FOLDER_PICKER_TARGETS.contains(&query.target.as_str()) {
+    return Err(ApiError::Validation(format!(
+      "Unknown folder picker target: {}",
+      query.target
+    )));
+  }
+
+  let response = use_case
+    .execute(ListDriveFoldersCommand {
+      company_id,
+      user_id: user.id,
+      parent_id: query.parent.filter(|parent| !parent.trim().is_empty()),
+    })
+    .await?;
+
+  let mut context = tera::Context::new();
+  context.insert("company_id", &company_id.to_string());
+  context.insert("target", &query.target);
+  context.insert("picker", &response);
+
+  let html = templates
+    .render("partials/drive_folder_picker.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+/// Invoice branding fields submitted as multipart form (logo upload included)
+#[derive(Debug, Default)]
+struct InvoiceBrandingForm {
+  accent_color: String,
+  font: String,
+  default_notes: Option<String>,
+  footer_text: Option<String>,
+  signature_text: Option<String>,
+  custom_template: Option<String>,
+  remove_logo: bool,
+  logo: Option<(Vec<u8>, String)>,
+}
+
+impl InvoiceBrandingForm {
+  async fn from_multipart(mut payload: Multipart) -> Result<Self, ApiError> {
+    let mut form = Self::default();
+
+    while let Some(item) = payload.next().await {
+      let mut field = item.map_err(|e| ApiError::Validation(format!("Upload error: {}", e)))?;
+      let field_name = field.name().map(|s| s.to_string()).unwrap_or_default();
+      let file_ext = field
+        .content_disposition()
+        .and_then(|cd| cd.get_filename())
+        .and_then(|filename| filename.rsplit('.').next())
+        .map(|ext| ext.to_lowercase());
+
+      let mut bytes = Vec::new();
+      while let Some(chunk) = field.next().await {
+        let data = chunk.map_err(|e| ApiError::Validation(format!("Upload error: {}", e)))?;
+        if field_name == "logo" && bytes.len() + data.len() > MAX_LOGO_SIZE {
+          return Err(ApiError::Validation(
+            "Logo cannot be larger than 2 MB".to_string(),
+          ));
+        }
+        bytes.extend_from_slice(&data);
+      }
+
+      let text = || String::from_utf8_lossy(&bytes).to_string();
+      match field_name.as_str() {
+        "accent_color" => form.accent_color = text(),
+        "font" => form.font = text(),
+        "default_notes" => form.default_notes = Some(text()),
+        "footer_text" => form.footer_text = Some(text()),
+        "signature_text" => form.signature_text = Some(text()),
+        "custom_template" => form.custom_template = Some(text()),
+        "remove_logo" => form.remove_logo = true,
+        "logo" if !bytes.is_empty() => {
+          let ext = file_ext.unwrap_or_default();
+          if !matches!(ext.as_str(), "png" | "jpg" | "jpeg" | "gif") {
+            return Err(ApiError::Validation(
+              "Logo must be a PNG, JPEG or GIF image".to_string(),
+            ));
+          }
+          form.logo = Some((bytes, ext));
+        }
+        _ => {}
+      }
+    }
+
+    Ok(form)
+  }
+
+  fn into_command(
+    self,
+    user_id: Uuid,
+    company_id: Uuid,
+    logo_path: Option<String>,
+  ) -> UpdateInvoiceBrandingCommand {
+    UpdateInvoiceBrandingCommand {
+      user_id,
+      company_id,
+      accent_color: self.accent_color,
+      font: self.font,
+      default_notes: self.default_notes,
+      footer_text: self.footer_text,
+      signature_text: self.signature_text,
+      custom_template: self.custom_template,
+      logo_path,
+      remove_logo: self.remove_logo,
+    }
+  }
+}
+
+/// Tera wraps the useful message (e.g. syntax error location) in the error source chain
+fn template_error_message(error: &tera::Error) -> String {
+  let mut message = error.to_string();
+  let mut source = std::error::Error::source(error);
+  while let Some(inner) = source {
+    message.push_str(": ");
+    message.push_str(&inner.to_string());
+    source = inner.source();
+  }
+  message
+}
+
+/// POST /companies/:id/settings/branding - Save invoice branding
+pub async fn update_invoice_branding(
+  req: HttpRequest,
+  path: web::Path<Uuid>,
+  payload: Multipart,
+  templates: web::Data<TemplateEngine>,
+  get_company_details: web::Data<Arc<GetCompanyDetailsUseCase>>,
+  preview_use_case: web::Data<Arc<PreviewInvoiceBrandingUseCase>>,
+  update_use_case: web::Data<Arc<UpdateInvoiceBrandingUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_id = path.into_inner();
+
+  let mut form = InvoiceBrandingForm::from_multipart(payload).await?;
+  let logo = form.logo.take();
+  let command = form.into_command(user.id, company_id, None);
+
+  // Validate the settings by rendering the sample invoice before anything is stored,
+  // so a broken custom template never reaches PDF generation
+  let validation = match preview_use_case.execute(command.clone()).await {
+    Ok(sample) => templates
+      .render_invoice_document(&sample)
+      .map(|_| ())
+      .map_err(|e| template_error_message(&e)),
+    Err(e) => Err(e.to_string()),
+  };
+
+  if let Err(error) = validation {
+    let company_details = get_company_details
+      .execute(GetCompanyDetailsCommand {
+        requester_id: user.id,
+        company_id,
+      })
+      .await?;
+
+    // Re-render with the submitted values so the user can fix them
+    let submitted = InvoiceBrandingDto {
+      logo_path: None,
+      accent_color: command.accent_color.clone(),
+      font: command.font.clone(),
+      font_family: String::new(),
+      default_notes: command.default_notes.clone(),
+      footer_text: command.footer_text.clone(),
+      signature_text: command.signature_text.clone(),
+      custom_template: command.custom_template.clone(),
+    };
+    let mut context = settings_page_context(&user, &company_details, &submitted);
+    context.insert("branding_error", &error);
+    context.insert("active_tab", "branding");
+
+    let html = templates
+      .render("pages/company_settings.html.tera", &context)
+      .map_err(|e| ApiError::Internal(format!("Template error: {}", e)))?;
+
+    return Ok(
+      HttpResponse::BadRequest()
+        .content_type("text/html")
+        .body(html),
+    );
+  }
+
+  // Save uploaded logo to disk
+  let logo_path = match logo {
+    Some((bytes, ext)) => Some(update_use_case.save_logo(company_id, &bytes, &ext).await?),
+    None => None,
+  };
+
+  update_use_case
+    .execute(UpdateInvoiceBrandingCommand {
+      logo_path,
+      ..command
+    })
+    .await?;
+
+  Ok(
+    HttpResponse::SeeOther()
+      .insert_header((
+        "Location",
+        format!(
+          "/companies/{}/settings?tab=branding&success=branding_updated",
+          company_id
+        ),
+      ))
+      .finish(),
+  )
+}
+
+/// POST /companies/:id/settings/branding/preview - Render a sample invoice with unsaved branding
+pub async fn preview_invoice_branding(
+  req: HttpRequest,
+  path: web::Path<Uuid>,
+  payload: Multipart,
+  templates: web::Data<TemplateEngine>,
+  preview_use_case: web::Data<Arc<PreviewInvoiceBrandingUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_id = path.into_inner();
+
+  let mut form = InvoiceBrandingForm::from_multipart(payload).await?;
+
+  // A logo picked but not yet saved is written to a temporary file for this render only
+  let preview_logo = match form.logo.take() {
+    Some((bytes, ext)) => {
+      let path =
+        std::env::temp_dir().join(format!("taxbyte-logo-preview-{}.{}", Uuid::new_v4(), ext));
+      tokio::fs::write(&path, &bytes)
+        .await
+        .map_err(|e| ApiError::Internal(format!("Failed to save logo: {}", e)))?;
+      Some(path)
+    }
+    None => None,
+  };
+
+  let command = form.into_command(
+    user.id,
+    company_id,
+    preview_logo
+      .as_ref()
+      .map(|p| p.to_string_lossy().to_string()),
+  );
+  let sample = preview_use_case.execute(command).await;
+
+  let rendered = match &sample {
+    Ok(sample) => templates
+      .render_invoice_document(sample)
+      .map_err(|e| template_error_message(&e)),
+    Err(e) => Err(e.to_string()),
+  };
+
+  if let Some(path) = preview_logo {
+    tokio::fs::remove_file(path).await.ok();
+  }
+
+  match rendered {
+    // Company templates are untrusted: the preview may not run scripts or share the app's origin
+    Ok(html) => Ok(
+      HttpResponse::Ok()
+        .content_type("text/html")
+        .insert_header(("Content-Security-Policy", "sandbox; script-src 'none'"))
+        .body(html),
+    ),
+    Err(error) => {
+      let mut context = tera::Context::new();
+      context.insert("error", &error);
+
+      let html = templates
+        .render(
+          "partials/invoice_branding_preview_error.html.tera",
+          &context,
+        )
+        .map_err(|e| ApiError::Internal(format!("Template error: {}", e)))?;
+
+      Ok(
+        HttpResponse::BadRequest()
+          .content_type("text/html")
+          .body(html),
+      )
+    }
+  }
+}
@@ -10,6 +10,15 @@ use crate::application::invoice::{
   ArchiveCustomerCommand, ArchiveCustomerUseCase, CreateCustomerCommand, CreateCustomerUseCase,
   ListCustomersCommand, ListCustomersUseCase, UpdateCustomerCommand, UpdateCustomerUseCase,
 };
+use crate::domain::invoice::InvoiceLanguage;
+
+/// Language options for customer and invoice forms as (code, native name)
+pub(crate) fn invoice_languages() -> Vec<(&'static str, &'static str)> {
+  InvoiceLanguage::all()
+    .iter()
+    .map(|language| (language.as_str(), language.native_name()))
+    .collect()
+}
 
 // GET /customers - List all customers
 pub async fn customers_page(
@@ -57,6 +66,7 @@ pub async fn customers_page(
   context.insert("active_company", &active_company);
   context.insert("company_id", &company_id.to_string());
   context.insert("current_page", "customers");
+  context.insert("languages", &invoice_languages());
 
   let html = templates
     .render("pages/customers.html.tera", &context)
@@ -73,6 +83,7 @@ pub struct CreateCustomerForm {
   state: Option<String>,
   postal_code: Option<String>,
   country: Option<String>,
+  default_language: Option<String>,
 }
 
 // POST /customers/create - Create a new customer
@@ -96,6 +107,7 @@ pub async fn create_customer_submit(
       state: form.state.clone(),
       postal_code: form.postal_code.clone(),
       country: form.country.clone(),
+      default_language: form.default_language.clone(),
     })
     .await
   {
@@ -108,6 +120,7 @@ pub async fn create_customer_submit(
       let mut context = tera::Context::new();
       context.insert("error", &e.to_string());
       context.insert("form", &*form);
+      context.insert("languages", &invoice_languages());
 
       let html = templates
         .render("partials/create_customer_form.html.tera", &context)
@@ -130,6 +143,7 @@ pub struct UpdateCustomerForm {
   state: Option<String>,
   postal_code: Option<String>,
   country: Option<String>,
+  default_language: Option<String>,
 }
 
 // POST /c/{company_id}/customers/{id}/edit - Update a customer
@@ -162,6 +176,7 @@ pub async fn update_customer_submit(
       state: form.state.clone(),
       postal_code: form.postal_code.clone(),
       country: form.country.clone(),
+      default_language: form.default_language.clone(),
     })
     .await
   {
@@ -0,0 +1,246 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use chrono::Datelike;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::adapters::http::{
+  errors::ApiError,
+  handlers::{get_company_context, get_user},
+  templates::TemplateEngine,
+};
+use crate::application::company::{GetUserCompaniesCommand, GetUserCompaniesUseCase};
+use crate::application::financials::{
+  ExportStatementCsvCommand, ExportStatementCsvUseCase, GetFinancialStatementCommand,
+  GetFinancialStatementUseCase,
+};
+use crate::domain::financials::{
+  HtmlPdfConverter, PeriodKind, StatementKind, StatementPeriod, TrendPoint,
+};
+
+/// Statement selection shared by the page and the exports. Defaults to the
+/// profit & loss of the current month.
+#[derive(Debug, Deserialize)]
+pub struct StatementQuery {
+  pub statement: Option<String>,
+  pub period: Option<String>,
+  pub year: Option<i32>,
+  pub month: Option<u32>,
+}
+
+impl StatementQuery {
+  fn resolve(&self) -> Result<(StatementKind, StatementPeriod), ApiError> {
+    let kind = match &self.statement {
+      Some(statement) => StatementKind::try_from(statement.as_str())?,
+      None => StatementKind::ProfitAndLoss,
+    };
+
+    let today = chrono::Utc::now().date_naive();
+    let year = self.year.unwrap_or(today.year());
+    let period = match self.period.as_deref() {
+      Some(period) => PeriodKind::try_from(period)?,
+      None => PeriodKind::Month,
+    };
+    let period = match period {
+      PeriodKind::Month => StatementPeriod::month(year, self.month.unwrap_or(today.month()))?,
+      PeriodKind::Year => StatementPeriod::year(year)?,
+    };
+
+    Ok((kind, period))
+  }
+}
+
+fn period_kind_str(kind: PeriodKind) -> &'static str {
+  match kind {
+    PeriodKind::Month => "month",
+    PeriodKind::Year => "year",
+  }
+}
+
+/// Query string selecting the same statement, for the export links
+fn query_string(kind: StatementKind, period: StatementPeriod) -> String {
+  format!(
+    "statement={}&period={}&year={}&month={}",
+    kind.as_str(),
+    period_kind_str(period.kind),
+    period.start.year(),
+    period.start.month()
+  )
+}
+
+/// Trend points with bar widths in percent of the largest amount
+fn chart_points(trend: &[TrendPoint]) -> Vec<serde_json::Value> {
+  let max = trend
+    .iter()
+    .map(|point| point.inflow.max(point.outflow))
+    .max()
+    .unwrap_or(Decimal::ZERO);
+  let percent = |amount: Decimal| {
+    if max.is_zero() {
+      Decimal::ZERO
+    } else {
+      (amount / max * Decimal::ONE_HUNDRED).round_dp(1)
+    }
+  };
+
+  trend
+    .iter()
+    .map(|point| {
+      serde_json::json!({
+        "label": point.label,
+        "inflow": point.inflow,
+        "outflow": point.outflow,
+        "net": point.net,
+        "inflow_pct": percent(point.inflow).to_string(),
+        "outflow_pct": percent(point.outflow).to_string(),
+      })
+    })
+    .collect()
+}
+
+// GET /financials - Profit & loss or cash-flow statement
+pub async fn statement_page(
+  req: HttpRequest,
+  query: web::Query<StatementQuery>,
+  templates: web::Data<TemplateEngine>,
+  get_statement_use_case: web::Data<Arc<GetFinancialStatementUseCase>>,
+  get_companies_use_case: web::Data<Arc<GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_id = get_company_context(&req)?.company_id;
+  let (kind, period) = query.resolve()?;
+
+  let companies_response = get_companies_use_case
+    .execute(GetUserCompaniesCommand { user_id: user.id })
+    .await?;
+
+  let active_company = companies_response
+    .companies
+    .iter()
+    .find(|c| c.company_id == company_id)
+    .map(|c| {
+      serde_json::json!({
+        "company_id": c.company_id,
+        "name": c.name,
+        "role": c.role,
+      })
+    });
+
+  let statement = get_statement_use_case
+    .execute(GetFinancialStatementCommand {
+      company_id,
+      kind,
+      period,
+    })
+    .await?;
+
+  let mut context = tera::Context::new();
+  context.insert("user", &user);
+  context.insert("companies", &companies_response.companies);
+  context.insert("active_company", &active_company);
+  context.insert("company_id", &company_id.to_string());
+  context.insert("current_page", "financials");
+  context.insert("statement", &statement);
+  context.insert("monthly", &(period.kind == PeriodKind::Month));
+  context.insert("chart", &chart_points(&statement.trend));
+  context.insert("selected_statement", kind.as_str());
+  context.insert("selected_period", period_kind_str(period.kind));
+  context.insert("selected_year", &period.start.year());
+  context.insert("selected_month", &period.start.month());
+  context.insert("query_string", &query_string(kind, period));
+
+  let html = templates
+    .render("pages/financials.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+// GET /financials/export.csv - Statement as CSV
+pub async fn export_csv(
+  req: HttpRequest,
+  query: web::Query<StatementQuery>,
+  export_csv_use_case: web::Data<Arc<ExportStatementCsvUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_id = get_company_context(&req)?.company_id;
+  let (kind, period) = query.resolve()?;
+
+  let response = export_csv_use_case
+    .execute(ExportStatementCsvCommand {
+      company_id,
+      kind,
+      period,
+    })
+    .await?;
+
+  Ok(download(response.file_name, "text/csv", response.content))
+}
+
+// GET /financials/export.pdf - Statement as PDF
+pub async fn export_pdf(
+  req: HttpRequest,
+  query: web::Query<StatementQuery>,
+  templates: web::Data<TemplateEngine>,
+  get_statement_use_case: web::Data<Arc<GetFinancialStatementUseCase>>,
+  get_companies_use_case: web::Data<Arc<GetUserCompaniesUseCase>>,
+  pdf_converter: web::Data<Arc<dyn HtmlPdfConverter>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_id = get_company_context(&req)?.company_id;
+  let (kind, period) = query.resolve()?;
+
+  let company_name = company_name(&get_companies_use_case, user.id, company_id).await?;
+  let statement = get_statement_use_case
+    .execute(GetFinancialStatementCommand {
+      company_id,
+      kind,
+      period,
+    })
+    .await?;
+
+  let mut context = tera::Context::new();
+  context.insert("company_name", &company_name);
+  context.insert("statement", &statement);
+  context.insert("monthly", &(period.kind == PeriodKind::Month));
+  context.insert("chart", &chart_points(&statement.trend));
+  context.insert("generated_on", &chrono::Utc::now().date_naive().to_string());
+
+  let html = templates
+    .render("partials/financial_statement_pdf.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+  let pdf = pdf_converter.convert(&html).await?;
+
+  let file_name = format!("{}_{}.pdf", kind.as_str(), period.label().replace('.', "-"));
+  Ok(download(file_name, "application/pdf", pdf))
+}
+
+async fn company_name(
+  get_companies_use_case: &GetUserCompaniesUseCase,
+  user_id: Uuid,
+  company_id: Uuid,
+) -> Result<String, ApiError> {
+  let companies_response = get_companies_use_case
+    .execute(GetUserCompaniesCommand { user_id })
+    .await?;
+
+  Ok(
+    companies_response
+      .companies
+      .into_iter()
+      .find(|c| c.company_id == company_id)
+      .map(|c| c.name)
+      .unwrap_or_default(),
+  )
+}
+
+fn download(file_name: String, content_type: &str, content: Vec<u8>) -> HttpResponse {
+  HttpResponse::Ok()
+    .content_type(content_type)
+    .insert_header((
+      "Content-Disposition",
+      format!("attachment; filename=\"{}\"", file_name),
+    ))
+    .body(content)
+}
@@ -7,7 +7,7 @@ use uuid::Uuid;
 
 use crate::adapters::http::{
   errors::ApiError,
-  handlers::{get_company_context, get_user},
+  handlers::{customers_web::invoice_languages, get_company_context, get_user},
   templates::TemplateEngine,
 };
 use crate::application::company::{GetBankAccountsCommand, GetBankAccountsUseCase};
@@ -18,11 +18,17 @@ use crate::application::invoice::{
   CreateInvoiceUseCase, CreateTemplateFromInvoiceCommand, CreateTemplateFromInvoiceUseCase,
   DeleteInvoiceCommand, DeleteInvoiceUseCase, GetInvoiceDetailsCommand, GetInvoiceDetailsUseCase,
   ListArchivedInvoicesCommand, ListArchivedInvoicesUseCase, ListCustomersCommand,
-  ListCustomersUseCase, ListInvoicesCommand, ListInvoicesUseCase, ListTemplatesCommand,
-  ListTemplatesUseCase, PermanentlyDeleteInvoiceCommand, PermanentlyDeleteInvoiceUseCase,
+  ListCustomersUseCase, ListInvoiceEventsCommand, ListInvoiceEventsUseCase, ListInvoicesCommand,
+  ListInvoicesUseCase, ListTemplatesCommand, ListTemplatesUseCase, PermanentlyDeleteInvoiceCommand,
+  PermanentlyDeleteInvoiceUseCase, RetryInvoiceUploadsCommand, RetryInvoiceUploadsUseCase,
   ReuploadInvoiceCommand, ReuploadInvoiceUseCase, UnarchiveInvoiceCommand, UnarchiveInvoiceUseCase,
+  UpdateInvoiceCommand, UpdateInvoiceUseCase,
+};
+use crate::application::storage::{
+  GetSyncStatusCommand, GetSyncStatusUseCase, ListPendingUploadsCommand, ListPendingUploadsUseCase,
 };
 use crate::domain::company::ports::ActiveBankAccountRepository;
+use crate::domain::storage::UploadTarget;
 
 // GET /invoices - List all invoices
 pub async fn invoices_page(
@@ -136,6 +142,7 @@ pub async fn invoice_create_page(
   context.insert("customers", &customers_response.customers);
   context.insert("bank_accounts", &bank_accounts_response.accounts);
   context.insert("active_bank_account_id", &active_bank_account_id);
+  context.insert("languages", &invoice_languages());
   context.insert("companies", &companies_response.companies);
   context.insert("active_company", &active_company);
   context.insert("company_id", &company_id.to_string());
@@ -166,6 +173,7 @@ pub struct CreateInvoiceForm {
   currency: String,
   line_items: Vec<CreateInvoiceFormLineItem>,
   bank_account_id: Option<Uuid>,
+  language: Option<String>,
 }
 
 // POST /invoices/create - Create a new invoice
@@ -199,6 +207,7 @@ pub async fn create_invoice_submit(
       invoice_date: form.invoice_date,
       payment_terms: form.payment_terms.clone(),
       currency: form.currency.clone(),
+      language: form.language.clone(),
       line_items,
     })
     .await?;
@@ -207,11 +216,15 @@ pub async fn create_invoice_submit(
 }
 
 // GET /invoices/{id} - Show invoice details
+#[allow(clippy::too_many_arguments)]
 pub async fn invoice_details_page(
   req: HttpRequest,
   path: web::Path<(Uuid, Uuid)>,
   templates: web::Data<TemplateEngine>,
   get_invoice_details_use_case: web::Data<Arc<GetInvoiceDetailsUseCase>>,
+  list_invoice_events_use_case: web::Data<Arc<ListInvoiceEventsUseCase>>,
+  list_uploads_use_case: web::Data<Arc<ListPendingUploadsUseCase>>,
+  get_sync_status_use_case: web::Data<Arc<GetSyncStatusUseCase>>,
   get_companies_use_case: web::Data<Arc<crate::application::company::GetUserCompaniesUseCase>>,
 ) -> Result<HttpResponse, ApiError> {
   let user = get_user(&req)?;
@@ -244,8 +257,36 @@ pub async fn invoice_details_page(
     })
     .await?;
 
+  let events_response = list_invoice_events_use_case
+    .execute(ListInvoiceEventsCommand {
+      user_id: user.id,
+      invoice_id,
+    })
+    .await?;
+
+  // The PDF while it is not uploaded to cloud storage yet
+  let pending_uploads = list_uploads_use_case
+    .execute(ListPendingUploadsCommand {
+      company_id,
+      target: UploadTarget::InvoicePdf,
+      owner_id: invoice_id,
+    })
+    .await?;
+
+  // How the uploaded PDF compare to Drive, as of the last reconciliation
+  let sync_status = get_sync_status_use_case
+    .execute(GetSyncStatusCommand {
+      company_id,
+      target: UploadTarget::InvoicePdf,
+      owner_id: invoice_id,
+    })
+    .await?;
+
   let mut context = tera::Context::new();
   context.insert("invoice", &response);
+  context.insert("events", &events_response.events);
+  context.insert("pending_uploads", &pending_uploads);
+  context.insert("sync_status", &sync_status);
   context.insert("user", &user);
   context.insert("companies", &companies_response.companies);
   context.insert("active_company", &active_company);
@@ -259,6 +300,203 @@ pub async fn invoice_details_page(
   Ok(HttpResponse::Ok().content_type("text/html").body(html))
 }
 
+// GET /c/{company_id}/invoices/{id}/edit - Show the edit form for a draft invoice
+pub async fn invoice_edit_page(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  templates: web::Data<TemplateEngine>,
+  get_invoice_details_use_case: web::Data<Arc<GetInvoiceDetailsUseCase>>,
+  list_customers_use_case: web::Data<Arc<ListCustomersUseCase>>,
+  get_bank_accounts_use_case: web::Data<Arc<GetBankAccountsUseCase>>,
+  get_companies_use_case: web::Data<Arc<crate::application::company::GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let (_company_id_from_path, invoice_id) = path.into_inner();
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+
+  let invoice = get_invoice_details_use_case
+    .execute(GetInvoiceDetailsCommand {
+      user_id: user.id,
+      invoice_id,
+    })
+    .await?;
+
+  // Only drafts can be edited; send everything else back to the details page
+  if invoice.status != "draft" {
+    return Ok(
+      HttpResponse::SeeOther()
+        .insert_header((
+          "Location",
+          format!("/c/{}/invoices/{}", company_id, invoice_id),
+        ))
+        .finish(),
+    );
+  }
+
+  // Fetch user's companies for the navbar selector
+  let companies_response = get_companies_use_case
+    .execute(crate::application::company::GetUserCompaniesCommand { user_id: user.id })
+    .await?;
+
+  // Find current company from the list for the selector
+  let active_company = companies_response
+    .companies
+    .iter()
+    .find(|c| c.company_id == company_id)
+    .map(|c| {
+      serde_json::json!({
+        "company_id": c.company_id,
+        "name": c.name,
+        "role": c.role,
+      })
+    });
+
+  let customers_response = list_customers_use_case
+    .execute(ListCustomersCommand {
+      user_id: user.id,
+      company_id,
+      include_archived: false,
+    })
+    .await?;
+
+  let bank_accounts_response = get_bank_accounts_use_case
+    .execute(GetBankAccountsCommand {
+      company_id,
+      requester_id: user.id,
+      include_archived: false,
+    })
+    .await?;
+
+  // Initial state for the Alpine form, in the shape the PUT endpoint expects
+  let invoice_form = serde_json::json!({
+    "customer_id": invoice.customer.id,
+    "bank_account_id": invoice.bank_account_id,
+    "invoice_date": invoice.invoice_date,
+    "payment_terms": invoice.payment_terms_code,
+    "language": invoice.language,
+    "line_items": invoice.line_items.iter().map(|item| serde_json::json!({
+      "description": item.description,
+      "quantity": item.quantity,
+      "unit_price": item.unit_price,
+      "vat_rate": item.vat_rate,
+    })).collect::<Vec<_>>(),
+  });
+
+  let mut context = tera::Context::new();
+  context.insert("invoice", &invoice);
+  context.insert("invoice_form", &invoice_form);
+  context.insert("customers", &customers_response.customers);
+  context.insert("bank_accounts", &bank_accounts_response.accounts);
+  context.insert("languages", &invoice_languages());
+  context.insert("companies", &companies_response.companies);
+  context.insert("active_company", &active_company);
+  context.insert("company_id", &company_id.to_string());
+  context.insert("current_page", "invoices");
+  context.insert("user", &user);
+
+  let html = templates
+    .render("pages/invoice_edit.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateInvoiceForm {
+  customer_id: Uuid,
+  bank_account_id: Option<Uuid>,
+  invoice_date: NaiveDate,
+  payment_terms: String,
+  language: Option<String>,
+  line_items: Vec<CreateInvoiceFormLineItem>,
+}
+
+impl UpdateInvoiceForm {
+  fn into_command(self, user_id: Uuid, invoice_id: Uuid) -> UpdateInvoiceCommand {
+    UpdateInvoiceCommand {
+      user_id,
+      invoice_id,
+      customer_id: self.customer_id,
+      bank_account_id: self.bank_account_id,
+      invoice_date: self.invoice_date,
+      payment_terms: self.payment_terms,
+      language: self.language,
+      line_items: self
+        .line_items
+        .into_iter()
+        .map(|item| CreateInvoiceLineItemDto {
+          description: item.description,
+          quantity: item.quantity,
+          unit_price: item.unit_price,
+          vat_rate: item.vat_rate,
+        })
+        .collect(),
+    }
+  }
+}
+
+// PUT /c/{company_id}/invoices/{id} - Update a draft invoice from the edit page
+pub async fn update_invoice_submit(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  form: web::Json<UpdateInvoiceForm>,
+  update_invoice_use_case: web::Data<Arc<UpdateInvoiceUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let (company_id, invoice_id) = path.into_inner();
+
+  // Verify the company_id from URL matches the context
+  if company_id != company_context.company_id {
+    return Err(ApiError::Auth(
+      crate::adapters::http::errors::AuthErrorKind::Forbidden,
+    ));
+  }
+
+  let response = update_invoice_use_case
+    .execute(form.into_inner().into_command(user.id, invoice_id))
+    .await?;
+
+  Ok(HttpResponse::Ok().json(response))
+}
+
+// PUT /api/v1/invoices/{id} - Update a draft invoice
+pub async fn update_invoice_api(
+  req: HttpRequest,
+  path: web::Path<Uuid>,
+  form: web::Json<UpdateInvoiceForm>,
+  update_invoice_use_case: web::Data<Arc<UpdateInvoiceUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let invoice_id = path.into_inner();
+
+  let response = update_invoice_use_case
+    .execute(form.into_inner().into_command(user.id, invoice_id))
+    .await?;
+
+  Ok(HttpResponse::Ok().json(response))
+}
+
+// GET /api/v1/invoices/{id}/events - Audit log of an invoice, newest first
+pub async fn list_invoice_events_api(
+  req: HttpRequest,
+  path: web::Path<Uuid>,
+  list_invoice_events_use_case: web::Data<Arc<ListInvoiceEventsUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let invoice_id = path.into_inner();
+
+  let response = list_invoice_events_use_case
+    .execute(ListInvoiceEventsCommand {
+      user_id: user.id,
+      invoice_id,
+    })
+    .await?;
+
+  Ok(HttpResponse::Ok().json(response))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ChangeStatusForm {
   status: String,
@@ -322,6 +560,33 @@ pub async fn reupload_invoice(
   )
 }
 
+// POST /invoices/{id}/uploads/retry - Retry the invoice's failed PDF upload
+pub async fn retry_invoice_uploads(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  retry_use_case: web::Data<Arc<RetryInvoiceUploadsUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let (_company_id_from_path, invoice_id) = path.into_inner();
+  let company_id = get_company_context(&req)?.company_id;
+
+  retry_use_case
+    .execute(RetryInvoiceUploadsCommand {
+      user_id: user.id,
+      invoice_id,
+    })
+    .await?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/invoices/{}", company_id, invoice_id),
+      ))
+      .finish(),
+  )
+}
+
 // DELETE /invoices/{id}/archive - Archive an invoice
 pub async fn archive_invoice(
   req: HttpRequest,
@@ -754,12 +1019,9 @@ pub async fn invoice_html_view(
     })
     .await?;
 
-  // Render the invoice PDF template
-  let mut context = tera::Context::new();
-  context.insert("invoice", &invoice_data);
-
+  // Render the invoice PDF template (company custom template when configured)
   let html = templates
-    .render("partials/invoice_pdf.html.tera", &context)
+    .render_invoice_document(&invoice_data)
     .map_err(|e| ApiError::Internal(format!("Template error: {}", e)))?;
 
   Ok(HttpResponse::Ok().content_type("text/html").body(html))
@@ -0,0 +1,293 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use chrono::{Datelike, Months, NaiveDate};
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::adapters::http::{
+  errors::ApiError,
+  handlers::{get_company_context, get_user},
+  templates::TemplateEngine,
+};
+use crate::application::company::{GetUserCompaniesCommand, GetUserCompaniesUseCase};
+use crate::application::ledger::{
+  CreateAccountCommand, CreateAccountUseCase, GetTrialBalanceCommand, GetTrialBalanceUseCase,
+  JournalLineInput, ListAccountsCommand, ListAccountsUseCase, ListJournalEntriesCommand,
+  ListJournalEntriesUseCase, PostJournalEntryCommand, PostJournalEntryUseCase,
+  ReverseJournalEntryCommand, ReverseJournalEntryUseCase,
+};
+use crate::application::saft::{ExportSaftCommand, ExportSaftUseCase};
+use crate::domain::ledger::AccountType;
+
+/// Base context shared by all ledger pages (navbar, company selector)
+async fn page_context(
+  req: &HttpRequest,
+  get_companies_use_case: &GetUserCompaniesUseCase,
+) -> Result<(tera::Context, Uuid), ApiError> {
+  let user = get_user(req)?;
+  let company_id = get_company_context(req)?.company_id;
+
+  let companies_response = get_companies_use_case
+    .execute(GetUserCompaniesCommand { user_id: user.id })
+    .await?;
+
+  let active_company = companies_response
+    .companies
+    .iter()
+    .find(|c| c.company_id == company_id)
+    .map(|c| {
+      serde_json::json!({
+        "company_id": c.company_id,
+        "name": c.name,
+        "role": c.role,
+      })
+    });
+
+  let mut context = tera::Context::new();
+  context.insert("user", &user);
+  context.insert("companies", &companies_response.companies);
+  context.insert("active_company", &active_company);
+  context.insert("company_id", &company_id.to_string());
+  context.insert("current_page", "ledger");
+
+  Ok((context, company_id))
+}
+
+fn render(
+  templates: &TemplateEngine,
+  template: &str,
+  context: &tera::Context,
+) -> Result<HttpResponse, ApiError> {
+  let html = templates
+    .render(template, context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+// GET /ledger - General journal
+pub async fn journal_page(
+  req: HttpRequest,
+  templates: web::Data<TemplateEngine>,
+  list_entries_use_case: web::Data<Arc<ListJournalEntriesUseCase>>,
+  get_companies_use_case: web::Data<Arc<GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let (mut context, company_id) = page_context(&req, &get_companies_use_case).await?;
+
+  let response = list_entries_use_case
+    .execute(ListJournalEntriesCommand { company_id })
+    .await?;
+
+  context.insert("entries", &response.entries);
+  render(&templates, "pages/ledger_journal.html.tera", &context)
+}
+
+// GET /ledger/entries/new - Manual journal entry form
+pub async fn create_entry_page(
+  req: HttpRequest,
+  templates: web::Data<TemplateEngine>,
+  list_accounts_use_case: web::Data<Arc<ListAccountsUseCase>>,
+  get_companies_use_case: web::Data<Arc<GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let (mut context, company_id) = page_context(&req, &get_companies_use_case).await?;
+
+  let response = list_accounts_use_case
+    .execute(ListAccountsCommand { company_id })
+    .await?;
+
+  context.insert("accounts", &response.accounts);
+  context.insert("today", &chrono::Utc::now().date_naive().to_string());
+  render(&templates, "pages/ledger_entry_create.html.tera", &context)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PostJournalEntryForm {
+  pub entry_date: NaiveDate,
+  pub description: String,
+  pub lines: Vec<JournalLineInput>,
+}
+
+// POST /ledger/entries - Post a manual journal entry
+pub async fn post_entry_submit(
+  req: HttpRequest,
+  form: web::Json<PostJournalEntryForm>,
+  post_entry_use_case: web::Data<Arc<PostJournalEntryUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_id = get_company_context(&req)?.company_id;
+  let form = form.into_inner();
+
+  let response = post_entry_use_case
+    .execute(PostJournalEntryCommand {
+      user_id: user.id,
+      company_id,
+      entry_date: form.entry_date,
+      description: form.description,
+      lines: form.lines,
+    })
+    .await?;
+
+  Ok(HttpResponse::Ok().json(response))
+}
+
+// POST /ledger/entries/{id}/reverse - Post the reversing entry
+pub async fn reverse_entry(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  reverse_use_case: web::Data<Arc<ReverseJournalEntryUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_id = get_company_context(&req)?.company_id;
+  let (_, entry_id) = path.into_inner();
+
+  reverse_use_case
+    .execute(ReverseJournalEntryCommand {
+      user_id: user.id,
+      company_id,
+      entry_id,
+    })
+    .await?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header(("HX-Redirect", format!("/c/{}/ledger", company_id)))
+      .finish(),
+  )
+}
+
+// GET /ledger/accounts - Chart of accounts
+pub async fn accounts_page(
+  req: HttpRequest,
+  templates: web::Data<TemplateEngine>,
+  list_accounts_use_case: web::Data<Arc<ListAccountsUseCase>>,
+  get_companies_use_case: web::Data<Arc<GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let (mut context, company_id) = page_context(&req, &get_companies_use_case).await?;
+
+  let response = list_accounts_use_case
+    .execute(ListAccountsCommand { company_id })
+    .await?;
+
+  let account_types: Vec<serde_json::Value> = AccountType::all()
+    .iter()
+    .map(|t| serde_json::json!({ "value": t.as_str(), "label": t.label() }))
+    .collect();
+
+  context.insert("accounts", &response.accounts);
+  context.insert("account_types", &account_types);
+  render(&templates, "pages/ledger_accounts.html.tera", &context)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateAccountForm {
+  pub code: String,
+  pub name: String,
+  pub account_type: String,
+}
+
+// POST /ledger/accounts - Add an account to the chart
+pub async fn create_account_submit(
+  req: HttpRequest,
+  form: web::Form<CreateAccountForm>,
+  create_account_use_case: web::Data<Arc<CreateAccountUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_id = get_company_context(&req)?.company_id;
+  let form = form.into_inner();
+
+  create_account_use_case
+    .execute(CreateAccountCommand {
+      company_id,
+      code: form.code,
+      name: form.name,
+      account_type: form.account_type,
+    })
+    .await?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header(("HX-Redirect", format!("/c/{}/ledger/accounts", company_id)))
+      .finish(),
+  )
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrialBalanceQuery {
+  pub as_of: Option<NaiveDate>,
+}
+
+// GET /ledger/trial-balance?as_of=YYYY-MM-DD - Trial balance
+pub async fn trial_balance_page(
+  req: HttpRequest,
+  query: web::Query<TrialBalanceQuery>,
+  templates: web::Data<TemplateEngine>,
+  trial_balance_use_case: web::Data<Arc<GetTrialBalanceUseCase>>,
+  get_companies_use_case: web::Data<Arc<GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let (mut context, company_id) = page_context(&req, &get_companies_use_case).await?;
+
+  let trial_balance = trial_balance_use_case
+    .execute(GetTrialBalanceCommand {
+      company_id,
+      as_of: query.as_of,
+    })
+    .await?;
+
+  context.insert("balanced", &trial_balance.is_balanced());
+  context.insert("trial_balance", &trial_balance);
+  render(&templates, "pages/ledger_trial_balance.html.tera", &context)
+}
+
+// GET /ledger/saft - SAF-T export form, defaulting to the previous month
+pub async fn saft_page(
+  req: HttpRequest,
+  templates: web::Data<TemplateEngine>,
+  get_companies_use_case: web::Data<Arc<GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let (mut context, _) = page_context(&req, &get_companies_use_case).await?;
+
+  let today = chrono::Utc::now().date_naive();
+  let this_month = today.with_day(1).unwrap_or(today);
+  let start = this_month
+    .checked_sub_months(Months::new(1))
+    .unwrap_or(this_month);
+  let end = this_month.pred_opt().unwrap_or(this_month);
+
+  context.insert("start", &start.to_string());
+  context.insert("end", &end.to_string());
+  render(&templates, "pages/saft_export.html.tera", &context)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SaftExportQuery {
+  pub start: NaiveDate,
+  pub end: NaiveDate,
+}
+
+// GET /ledger/saft/export?start=YYYY-MM-DD&end=YYYY-MM-DD - Download SAF-T XML
+pub async fn saft_export(
+  req: HttpRequest,
+  query: web::Query<SaftExportQuery>,
+  export_saft_use_case: web::Data<Arc<ExportSaftUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_id = get_company_context(&req)?.company_id;
+
+  let response = export_saft_use_case
+    .execute(ExportSaftCommand {
+      company_id,
+      start_date: query.start,
+      end_date: query.end,
+    })
+    .await?;
+
+  Ok(
+    HttpResponse::Ok()
+      .content_type("application/xml")
+      .insert_header((
+        "Content-Disposition",
+        format!("attachment; filename=\"{}\"", response.file_name),
+      ))
+      .body(response.content),
+  )
+}
@@ -6,7 +6,9 @@ pub mod company_settings;
 pub mod company_web;
 pub mod customers_web;
 pub mod dev_mock_oauth;
+pub mod financials_web;
 pub mod invoices_web;
+pub mod ledger_web;
 pub mod oauth_callback;
 pub mod pages;
 pub mod reports_web;
@@ -13,16 +13,40 @@ use crate::adapters::http::{
   handlers::{get_company_context, get_user},
   templates::TemplateEngine,
 };
+use crate::application::document::{ListDocumentsCommand, ListDocumentsUseCase};
 use crate::application::report::{
-  CreateEmptyReportCommand, CreateEmptyReportUseCase, DeleteReceivedInvoiceCommand,
-  DeleteReceivedInvoiceUseCase, DeleteReportCommand, DeleteReportUseCase, GetReportDetailsCommand,
-  GetReportDetailsUseCase, ImportBankStatementCommand, ImportBankStatementUseCase,
-  ListMonthlyReportsCommand, ListMonthlyReportsUseCase, ListReceivedInvoicesCommand,
-  ListReceivedInvoicesUseCase, MatchTransactionCommand, MatchTransactionUseCase,
-  UnmatchTransactionCommand, UnmatchTransactionUseCase, UploadReceiptCommand, UploadReceiptUseCase,
-  UploadReceivedInvoiceCommand, UploadReceivedInvoiceUseCase,
+  CategoriseTransactionCommand, CategoriseTransactionUseCase, ClearRuleOutcomeCommand,
+  ClearRuleOutcomeUseCase, CreateEmptyReportCommand, CreateEmptyReportUseCase,
+  CreateMatchingRuleCommand, CreateMatchingRuleUseCase, CreateTransactionCategoryCommand,
+  CreateTransactionCategoryUseCase, DeleteMatchingRuleCommand, DeleteMatchingRuleUseCase,
+  DeleteReceivedInvoiceCommand, DeleteReceivedInvoiceUseCase, DeleteReportCommand,
+  DeleteReportUseCase, DeleteTransactionCategoryCommand, DeleteTransactionCategoryUseCase,
+  ExportReportArchiveCommand, ExportReportArchiveUseCase, GetReportDetailsCommand,
+  GetReportDetailsUseCase, GetVendorDetailsCommand, GetVendorDetailsUseCase,
+  ImportBankStatementCommand, ImportBankStatementUseCase, ListMatchingRulesCommand,
+  ListMatchingRulesUseCase, ListMonthlyReportsCommand, ListMonthlyReportsUseCase,
+  ListReceivedInvoicesCommand, ListReceivedInvoicesUseCase, ListReportEventsCommand,
+  ListReportEventsUseCase, ListTransactionCategoriesCommand, ListTransactionCategoriesUseCase,
+  ListUpcomingBillsCommand, ListUpcomingBillsUseCase, ListVendorsCommand, ListVendorsUseCase,
+  LockReportCommand, LockReportUseCase, MatchTransactionCommand, MatchTransactionUseCase,
+  MergeVendorsCommand, MergeVendorsUseCase, MoveMatchingRuleCommand, MoveMatchingRuleUseCase,
+  ReopenReportCommand, ReopenReportUseCase, RetryReportUploadsCommand, RetryReportUploadsUseCase,
+  SaveVendorCommand, SaveVendorUseCase, TestMatchingRuleCommand, TestMatchingRuleUseCase,
+  UnmatchTransactionCommand, UnmatchTransactionUseCase, UpdatePayableCommand, UpdatePayableUseCase,
+  UploadReceiptCommand, UploadReceiptUseCase, UploadReceivedInvoiceCommand,
+  UploadReceivedInvoiceUseCase,
 };
+use crate::application::storage::{
+  GetSyncStatusCommand, GetSyncStatusUseCase, ListPendingUploadsCommand, ListPendingUploadsUseCase,
+};
+use crate::domain::company::CompanyRole;
+use crate::domain::financials::HtmlPdfConverter;
 use crate::domain::report::ports::InvoiceDataExtractor;
+use crate::domain::report::{
+  ArchiveEntry, PayableStatus, PaymentTerms, ReportError, RuleAction, RuleConditions,
+  TransactionDirection, VendorProfile,
+};
+use crate::domain::storage::UploadTarget;
 
 // GET /reports - List monthly reports
 pub async fn reports_page(
@@ -58,6 +82,7 @@ pub async fn reports_page(
 
   let mut context = tera::Context::new();
   context.insert("reports", &response.reports);
+  context.insert("gaps", &response.gaps);
   context.insert("user", &user);
   context.insert("companies", &companies_response.companies);
   context.insert("active_company", &active_company);
@@ -157,12 +182,19 @@ pub async fn import_bank_statement(
   let company_id = company_context.company_id;
 
   let mut csv_content: Option<Vec<u8>> = None;
+  let mut file_name: Option<String> = None;
   let mut month: Option<u32> = None;
   let mut year: Option<i32> = None;
 
   while let Some(item) = payload.next().await {
     let mut field = item.map_err(|e| ApiError::Validation(format!("Upload error: {}", e)))?;
     let field_name = field.name().map(|s| s.to_string()).unwrap_or_default();
+    if field_name == "csv_file" {
+      file_name = field
+        .content_disposition()
+        .and_then(|cd| cd.get_filename())
+        .map(|name| name.to_string());
+    }
 
     let mut bytes = Vec::new();
     while let Some(chunk) = field.next().await {
@@ -199,15 +231,34 @@ pub async fn import_bank_statement(
   let month = month.ok_or_else(|| ApiError::Validation("Month is required".to_string()))?;
   let year = year.ok_or_else(|| ApiError::Validation("Year is required".to_string()))?;
 
-  let result = import_use_case
+  // Keep the original statement file for the report archive
+  let statement_dir = format!("data/bank_statements/{}", company_id);
+  tokio::fs::create_dir_all(&statement_dir)
+    .await
+    .map_err(|e| ApiError::Internal(format!("Failed to create directory: {}", e)))?;
+
+  let statement_path = format!("{}/{}.csv", statement_dir, Uuid::new_v4());
+  tokio::fs::write(&statement_path, &csv_content)
+    .await
+    .map_err(|e| ApiError::Internal(format!("Failed to save statement: {}", e)))?;
+
+  let result = match import_use_case
     .execute(ImportBankStatementCommand {
       company_id,
       month,
       year,
       csv_content,
+      file_path: Some(statement_path.clone()),
+      file_name,
     })
     .await
-    .map_err(ApiError::from)?;
+  {
+    Ok(result) => result,
+    Err(e) => {
+      tokio::fs::remove_file(&statement_path).await.ok();
+      return Err(ApiError::from(e));
+    }
+  };
 
   Ok(
     HttpResponse::Ok()
@@ -220,6 +271,7 @@ pub async fn import_bank_statement(
 }
 
 // GET /reports/{id} - Report details page
+#[allow(clippy::too_many_arguments)]
 pub async fn report_details_page(
   req: HttpRequest,
   path: web::Path<(Uuid, Uuid)>,
@@ -228,6 +280,11 @@ pub async fn report_details_page(
   list_received_use_case: web::Data<Arc<ListReceivedInvoicesUseCase>>,
   get_companies_use_case: web::Data<Arc<crate::application::company::GetUserCompaniesUseCase>>,
   list_invoices_use_case: web::Data<Arc<crate::application::invoice::ListInvoicesUseCase>>,
+  list_categories_use_case: web::Data<Arc<ListTransactionCategoriesUseCase>>,
+  list_rules_use_case: web::Data<Arc<ListMatchingRulesUseCase>>,
+  list_events_use_case: web::Data<Arc<ListReportEventsUseCase>>,
+  list_uploads_use_case: web::Data<Arc<ListPendingUploadsUseCase>>,
+  get_sync_status_use_case: web::Data<Arc<GetSyncStatusUseCase>>,
 ) -> Result<HttpResponse, ApiError> {
   let user = get_user(&req)?;
   let company_context = get_company_context(&req)?;
@@ -259,6 +316,79 @@ pub async fn report_details_page(
     })
     .await?;
 
+  let categories = list_categories_use_case
+    .execute(ListTransactionCategoriesCommand { company_id })
+    .await
+    .map_err(ApiError::from)?;
+
+  let rules = list_rules_use_case
+    .execute(ListMatchingRulesCommand { company_id })
+    .await
+    .map_err(ApiError::from)?;
+
+  let events = list_events_use_case
+    .execute(ListReportEventsCommand { report_id })
+    .await
+    .map_err(ApiError::from)?;
+
+  // Files of the report not uploaded to cloud storage yet
+  let pending_uploads = list_uploads_use_case
+    .execute(ListPendingUploadsCommand {
+      company_id,
+      target: UploadTarget::ReportFile,
+      owner_id: report_id,
+    })
+    .await?;
+
+  // How the uploaded files compare to Drive, as of the last reconciliation
+  let sync_status = get_sync_status_use_case
+    .execute(GetSyncStatusCommand {
+      company_id,
+      target: UploadTarget::ReportFile,
+      owner_id: report_id,
+    })
+    .await?;
+
+  // Invoices that still have an amount left to allocate, for the match forms
+  let open_amount = |id: &Uuid, total: Decimal| {
+    total.abs()
+      - report
+        .allocated_by_document
+        .get(id)
+        .copied()
+        .unwrap_or_default()
+  };
+  let open_invoices: Vec<serde_json::Value> = invoices
+    .invoices
+    .iter()
+    .map(|inv| (inv, open_amount(&inv.id, inv.total)))
+    .filter(|(_, open)| *open > Decimal::ZERO)
+    .map(|(inv, open)| {
+      serde_json::json!({
+        "id": inv.id,
+        "invoice_number": inv.invoice_number,
+        "customer_name": inv.customer_name,
+        "total": inv.total,
+        "open": open,
+      })
+    })
+    .collect();
+  let open_received_invoices: Vec<serde_json::Value> = received
+    .invoices
+    .iter()
+    .map(|ri| (ri, open_amount(&ri.id, ri.amount)))
+    .filter(|(_, open)| *open > Decimal::ZERO)
+    .map(|(ri, open)| {
+      serde_json::json!({
+        "id": ri.id,
+        "vendor_name": ri.vendor_name,
+        "amount": ri.amount,
+        "currency": ri.currency,
+        "open": open,
+      })
+    })
+    .collect();
+
   let active_company = companies_response
     .companies
     .iter()
@@ -273,9 +403,23 @@ pub async fn report_details_page(
 
   let mut context = tera::Context::new();
   context.insert("report", &report);
+  context.insert(
+    "can_sign_off",
+    &matches!(
+      company_context.role,
+      CompanyRole::Owner | CompanyRole::Admin
+    ),
+  );
+  context.insert("categories", &categories.categories);
+  context.insert("rules", &rules.rules);
+  context.insert("events", &events.events);
+  context.insert("pending_uploads", &pending_uploads);
+  context.insert("sync_status", &sync_status);
   context.insert("transactions", &report.transactions);
   context.insert("received_invoices", &received.invoices);
   context.insert("invoices", &invoices.invoices);
+  context.insert("open_invoices", &open_invoices);
+  context.insert("open_received_invoices", &open_received_invoices);
   context.insert("user", &user);
   context.insert("companies", &companies_response.companies);
   context.insert("active_company", &active_company);
@@ -293,6 +437,8 @@ pub async fn report_details_page(
 pub struct MatchForm {
   pub invoice_id: Option<String>,
   pub received_invoice_id: Option<String>,
+  /// Part of the transaction to allocate; empty for as much as is open
+  pub amount: Option<String>,
 }
 
 // POST /reports/{id}/match/{tx_id} - Match transaction
@@ -323,11 +469,22 @@ pub async fn match_transaction(
     .transpose()
     .map_err(|_| ApiError::Validation("Invalid received invoice ID".to_string()))?;
 
+  let amount = form
+    .amount
+    .as_deref()
+    .map(str::trim)
+    .filter(|s| !s.is_empty())
+    .map(Decimal::from_str)
+    .transpose()
+    .map_err(|_| ApiError::Validation("Invalid amount".to_string()))?;
+
   match_use_case
     .execute(MatchTransactionCommand {
+      company_id,
       transaction_id: tx_id,
       invoice_id,
       received_invoice_id,
+      amount,
     })
     .await
     .map_err(ApiError::from)?;
@@ -370,13 +527,57 @@ pub async fn unmatch_transaction(
   )
 }
 
+#[derive(Debug, Deserialize)]
+pub struct CategoriseForm {
+  pub category_id: Option<String>,
+}
+
+// POST /reports/{id}/category/{tx_id} - Set or clear a transaction's category
+pub async fn categorise_transaction(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid, Uuid)>,
+  form: web::Form<CategoriseForm>,
+  categorise_use_case: web::Data<Arc<CategoriseTransactionUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, report_id, tx_id) = path.into_inner();
+
+  let category_id = form
+    .category_id
+    .as_ref()
+    .filter(|s| !s.is_empty())
+    .map(|s| Uuid::parse_str(s))
+    .transpose()
+    .map_err(|_| ApiError::Validation("Invalid category ID".to_string()))?;
+
+  categorise_use_case
+    .execute(CategoriseTransactionCommand {
+      company_id,
+      transaction_id: tx_id,
+      category_id,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/{}", company_id, report_id),
+      ))
+      .finish(),
+  )
+}
+
 // POST /reports/{id}/generate - Generate Drive report
 pub async fn generate_report(
   req: HttpRequest,
   path: web::Path<(Uuid, Uuid)>,
   generate_use_case: web::Data<Arc<crate::application::report::GenerateReportUseCase>>,
 ) -> Result<HttpResponse, ApiError> {
-  let _user = get_user(&req)?;
+  let user = get_user(&req)?;
   let company_context = get_company_context(&req)?;
   let company_id = company_context.company_id;
   let (_, report_id) = path.into_inner();
@@ -385,6 +586,183 @@ pub async fn generate_report(
     .execute(crate::application::report::GenerateReportCommand {
       report_id,
       company_id,
+      user_id: user.id,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/{}", company_id, report_id),
+      ))
+      .finish(),
+  )
+}
+
+// POST /reports/{id}/uploads/retry - Retry the report's failed uploads
+pub async fn retry_report_uploads(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  retry_use_case: web::Data<Arc<RetryReportUploadsUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_id = get_company_context(&req)?.company_id;
+  let (_, report_id) = path.into_inner();
+
+  retry_use_case
+    .execute(RetryReportUploadsCommand {
+      report_id,
+      company_id,
+      user_id: user.id,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/{}", company_id, report_id),
+      ))
+      .finish(),
+  )
+}
+
+// GET /reports/{id}/export.zip - Download the report with its statements,
+// matched PDFs, a transaction index and a summary page
+pub async fn export_report_archive(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  templates: web::Data<TemplateEngine>,
+  get_details_use_case: web::Data<Arc<GetReportDetailsUseCase>>,
+  get_companies_use_case: web::Data<Arc<crate::application::company::GetUserCompaniesUseCase>>,
+  pdf_converter: web::Data<Arc<dyn HtmlPdfConverter>>,
+  export_use_case: web::Data<Arc<ExportReportArchiveUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_id = get_company_context(&req)?.company_id;
+  let (_, report_id) = path.into_inner();
+
+  let report = get_details_use_case
+    .execute(GetReportDetailsCommand { report_id })
+    .await
+    .map_err(ApiError::from)?;
+  if report.company_id != company_id {
+    return Err(ApiError::from(ReportError::NotFound));
+  }
+
+  let companies_response = get_companies_use_case
+    .execute(crate::application::company::GetUserCompaniesCommand { user_id: user.id })
+    .await?;
+  let company_name = companies_response
+    .companies
+    .into_iter()
+    .find(|c| c.company_id == company_id)
+    .map(|c| c.name)
+    .unwrap_or_default();
+
+  let summary = export_use_case
+    .summary(report_id, company_id)
+    .await
+    .map_err(ApiError::from)?;
+  let open_items: Vec<_> = report
+    .transactions
+    .iter()
+    .filter(|tx| summary.open_transaction_ids.contains(&tx.id))
+    .collect();
+
+  let mut context = tera::Context::new();
+  context.insert("company_name", &company_name);
+  context.insert("report", &report);
+  context.insert("open_items", &open_items);
+  context.insert("matched_count", &summary.matched_count);
+  context.insert("partly_matched_count", &summary.partly_matched_count);
+  context.insert("resolved_count", &summary.resolved_count);
+  context.insert("generated_on", &chrono::Utc::now().date_naive().to_string());
+
+  let html = templates
+    .render("partials/report_cover_sheet_pdf.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+  let cover_sheet = pdf_converter.convert(&html).await?;
+
+  let archive = export_use_case
+    .execute(ExportReportArchiveCommand {
+      report_id,
+      company_id,
+      cover_sheet: ArchiveEntry {
+        path: "summary.pdf".to_string(),
+        content: cover_sheet,
+      },
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .content_type("application/zip")
+      .insert_header((
+        "Content-Disposition",
+        format!("attachment; filename=\"{}\"", archive.file_name),
+      ))
+      .body(archive.content),
+  )
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReopenReportForm {
+  pub reason: String,
+}
+
+// POST /reports/{id}/reopen - Reopen a generated report for changes
+pub async fn reopen_report(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  form: web::Form<ReopenReportForm>,
+  reopen_use_case: web::Data<Arc<ReopenReportUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, report_id) = path.into_inner();
+
+  reopen_use_case
+    .execute(ReopenReportCommand {
+      report_id,
+      company_id,
+      user_id: user.id,
+      reason: form.into_inner().reason,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/{}", company_id, report_id),
+      ))
+      .finish(),
+  )
+}
+
+// POST /reports/{id}/lock - Sign off a generated report and lock it
+pub async fn lock_report(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  lock_use_case: web::Data<Arc<LockReportUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, report_id) = path.into_inner();
+
+  lock_use_case
+    .execute(LockReportCommand {
+      report_id,
+      company_id,
+      user_id: user.id,
     })
     .await
     .map_err(ApiError::from)?;
@@ -405,13 +783,16 @@ pub async fn delete_report(
   path: web::Path<(Uuid, Uuid)>,
   delete_use_case: web::Data<Arc<DeleteReportUseCase>>,
 ) -> Result<HttpResponse, ApiError> {
-  let _user = get_user(&req)?;
+  let user = get_user(&req)?;
   let company_context = get_company_context(&req)?;
   let company_id = company_context.company_id;
   let (_, report_id) = path.into_inner();
 
   delete_use_case
-    .execute(DeleteReportCommand { report_id })
+    .execute(DeleteReportCommand {
+      report_id,
+      user_id: user.id,
+    })
     .await
     .map_err(ApiError::from)?;
 
@@ -456,6 +837,7 @@ pub async fn received_invoices_page(
 
   let mut context = tera::Context::new();
   context.insert("received_invoices", &response.invoices);
+  context.insert("statuses", &payable_statuses());
   context.insert("user", &user);
   context.insert("companies", &companies_response.companies);
   context.insert("active_company", &active_company);
@@ -475,44 +857,161 @@ pub async fn received_invoices_page(
   Ok(HttpResponse::Ok().content_type("text/html").body(html))
 }
 
-// POST /reports/received-invoices - Upload received invoice
-pub async fn upload_received_invoice(
+// GET /reports/received-invoices/upcoming - Bills to pay this week
+pub async fn upcoming_bills_page(
   req: HttpRequest,
-  mut payload: Multipart,
-  upload_use_case: web::Data<Arc<UploadReceivedInvoiceUseCase>>,
+  templates: web::Data<TemplateEngine>,
+  list_use_case: web::Data<Arc<ListUpcomingBillsUseCase>>,
+  get_companies_use_case: web::Data<Arc<crate::application::company::GetUserCompaniesUseCase>>,
 ) -> Result<HttpResponse, ApiError> {
-  let _user = get_user(&req)?;
+  let user = get_user(&req)?;
   let company_context = get_company_context(&req)?;
   let company_id = company_context.company_id;
 
-  let mut pdf_bytes: Option<Vec<u8>> = None;
-  let mut vendor_name = String::new();
-  let mut amount_str = String::new();
-  let mut currency = "EUR".to_string();
-  let mut invoice_date_str = String::new();
-  let mut invoice_number = String::new();
-  let mut notes = String::new();
+  let companies_response = get_companies_use_case
+    .execute(crate::application::company::GetUserCompaniesCommand { user_id: user.id })
+    .await?;
 
-  while let Some(item) = payload.next().await {
-    let mut field = item.map_err(|e| ApiError::Validation(format!("Upload error: {}", e)))?;
-    let field_name = field.name().map(|s| s.to_string()).unwrap_or_default();
+  let bills = list_use_case
+    .execute(ListUpcomingBillsCommand { company_id })
+    .await
+    .map_err(ApiError::from)?;
 
-    let mut bytes = Vec::new();
-    while let Some(chunk) = field.next().await {
-      let data = chunk.map_err(|e| ApiError::Validation(format!("Upload error: {}", e)))?;
-      bytes.extend_from_slice(&data);
-    }
+  let active_company = companies_response
+    .companies
+    .iter()
+    .find(|c| c.company_id == company_id)
+    .map(|c| {
+      serde_json::json!({
+        "company_id": c.company_id,
+        "name": c.name,
+        "role": c.role,
+      })
+    });
 
-    match field_name.as_str() {
-      "pdf_file" => pdf_bytes = Some(bytes),
-      "vendor_name" => vendor_name = String::from_utf8_lossy(&bytes).trim().to_string(),
-      "amount" => amount_str = String::from_utf8_lossy(&bytes).trim().to_string(),
-      "currency" => currency = String::from_utf8_lossy(&bytes).trim().to_string(),
-      "invoice_date" => invoice_date_str = String::from_utf8_lossy(&bytes).trim().to_string(),
-      "invoice_number" => invoice_number = String::from_utf8_lossy(&bytes).trim().to_string(),
-      "notes" => notes = String::from_utf8_lossy(&bytes).trim().to_string(),
-      _ => {}
-    }
+  let mut context = tera::Context::new();
+  context.insert("bills", &bills);
+  context.insert("user", &user);
+  context.insert("companies", &companies_response.companies);
+  context.insert("active_company", &active_company);
+  context.insert("company_id", &company_id.to_string());
+  context.insert("current_page", "reports");
+
+  let html = templates
+    .render("pages/upcoming_bills.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+// GET /reports/documents - Stored documents and their integrity
+pub async fn documents_page(
+  req: HttpRequest,
+  templates: web::Data<TemplateEngine>,
+  list_use_case: web::Data<Arc<ListDocumentsUseCase>>,
+  get_companies_use_case: web::Data<Arc<crate::application::company::GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+
+  let companies_response = get_companies_use_case
+    .execute(crate::application::company::GetUserCompaniesCommand { user_id: user.id })
+    .await?;
+
+  let documents = list_use_case
+    .execute(ListDocumentsCommand { company_id })
+    .await
+    .map_err(ApiError::from)?;
+  let problems = documents
+    .iter()
+    .filter(|d| d.integrity_status != "ok")
+    .count();
+
+  let active_company = companies_response
+    .companies
+    .iter()
+    .find(|c| c.company_id == company_id)
+    .map(|c| {
+      serde_json::json!({
+        "company_id": c.company_id,
+        "name": c.name,
+        "role": c.role,
+      })
+    });
+
+  let mut context = tera::Context::new();
+  context.insert("documents", &documents);
+  context.insert("problems", &problems);
+  context.insert("user", &user);
+  context.insert("companies", &companies_response.companies);
+  context.insert("active_company", &active_company);
+  context.insert("company_id", &company_id.to_string());
+  context.insert("current_page", "reports");
+
+  let html = templates
+    .render("pages/documents.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+// POST /reports/received-invoices - Upload received invoice
+pub async fn upload_received_invoice(
+  req: HttpRequest,
+  mut payload: Multipart,
+  upload_use_case: web::Data<Arc<UploadReceivedInvoiceUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+
+  let mut pdf_bytes: Option<Vec<u8>> = None;
+  let mut file_name = String::from("invoice.pdf");
+  let mut vendor_name = String::new();
+  let mut amount_str = String::new();
+  let mut currency = "EUR".to_string();
+  let mut invoice_date_str = String::new();
+  let mut invoice_number = String::new();
+  let mut notes = String::new();
+  let mut vat_amount = String::new();
+  let mut due_date = String::new();
+  let mut vendor_iban = String::new();
+  let mut payment_reference = String::new();
+
+  while let Some(item) = payload.next().await {
+    let mut field = item.map_err(|e| ApiError::Validation(format!("Upload error: {}", e)))?;
+    let field_name = field.name().map(|s| s.to_string()).unwrap_or_default();
+    if field_name == "pdf_file" {
+      if let Some(name) = field
+        .content_disposition()
+        .and_then(|cd| cd.get_filename())
+        .filter(|name| !name.is_empty())
+      {
+        file_name = name.to_string();
+      }
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = field.next().await {
+      let data = chunk.map_err(|e| ApiError::Validation(format!("Upload error: {}", e)))?;
+      bytes.extend_from_slice(&data);
+    }
+
+    match field_name.as_str() {
+      "pdf_file" => pdf_bytes = Some(bytes),
+      "vendor_name" => vendor_name = String::from_utf8_lossy(&bytes).trim().to_string(),
+      "amount" => amount_str = String::from_utf8_lossy(&bytes).trim().to_string(),
+      "currency" => currency = String::from_utf8_lossy(&bytes).trim().to_string(),
+      "invoice_date" => invoice_date_str = String::from_utf8_lossy(&bytes).trim().to_string(),
+      "invoice_number" => invoice_number = String::from_utf8_lossy(&bytes).trim().to_string(),
+      "notes" => notes = String::from_utf8_lossy(&bytes).trim().to_string(),
+      "vat_amount" => vat_amount = String::from_utf8_lossy(&bytes).trim().to_string(),
+      "due_date" => due_date = String::from_utf8_lossy(&bytes).trim().to_string(),
+      "vendor_iban" => vendor_iban = String::from_utf8_lossy(&bytes).trim().to_string(),
+      "payment_reference" => payment_reference = String::from_utf8_lossy(&bytes).trim().to_string(),
+      _ => {}
+    }
   }
 
   let pdf_bytes =
@@ -541,18 +1040,7 @@ pub async fn upload_received_invoice(
   };
 
   let inv_notes = if notes.is_empty() { None } else { Some(notes) };
-
-  // Save PDF to disk
-  let pdf_dir = format!("data/received_invoices/{}", company_id);
-  tokio::fs::create_dir_all(&pdf_dir)
-    .await
-    .map_err(|e| ApiError::Internal(format!("Failed to create directory: {}", e)))?;
-
-  let file_id = Uuid::new_v4();
-  let pdf_path = format!("{}/{}.pdf", pdf_dir, file_id);
-  tokio::fs::write(&pdf_path, &pdf_bytes)
-    .await
-    .map_err(|e| ApiError::Internal(format!("Failed to save PDF: {}", e)))?;
+  let terms = payment_terms(&vat_amount, &due_date, vendor_iban, payment_reference)?;
 
   upload_use_case
     .execute(UploadReceivedInvoiceCommand {
@@ -562,8 +1050,11 @@ pub async fn upload_received_invoice(
       currency,
       invoice_date,
       invoice_number: inv_number,
-      pdf_path,
+      pdf_content: pdf_bytes,
+      file_name,
       notes: inv_notes,
+      terms,
+      uploaded_by: user.id,
     })
     .await
     .map_err(ApiError::from)?;
@@ -585,26 +1076,25 @@ pub async fn upload_receipt(
   mut payload: Multipart,
   upload_receipt_use_case: web::Data<Arc<UploadReceiptUseCase>>,
 ) -> Result<HttpResponse, ApiError> {
-  let _user = get_user(&req)?;
+  let user = get_user(&req)?;
   let company_context = get_company_context(&req)?;
   let company_id = company_context.company_id;
   let (_, report_id, tx_id) = path.into_inner();
 
   let mut file_bytes: Option<Vec<u8>> = None;
-  let mut file_ext = String::from("bin");
+  let mut file_name = String::from("receipt");
 
   while let Some(item) = payload.next().await {
     let mut field = item.map_err(|e| ApiError::Validation(format!("Upload error: {}", e)))?;
     let field_name = field.name().map(|s| s.to_string()).unwrap_or_default();
 
     if field_name == "file" {
-      // Extract extension from filename
-      if let Some(cd) = field.content_disposition() {
-        if let Some(filename) = cd.get_filename() {
-          if let Some(ext) = filename.rsplit('.').next() {
-            file_ext = ext.to_lowercase();
-          }
-        }
+      if let Some(name) = field
+        .content_disposition()
+        .and_then(|cd| cd.get_filename())
+        .filter(|name| !name.is_empty())
+      {
+        file_name = name.to_string();
       }
 
       let mut bytes = Vec::new();
@@ -623,22 +1113,13 @@ pub async fn upload_receipt(
     return Err(ApiError::Validation("File is empty".to_string()));
   }
 
-  // Save file to disk
-  let receipt_dir = format!("data/receipts/{}", company_id);
-  tokio::fs::create_dir_all(&receipt_dir)
-    .await
-    .map_err(|e| ApiError::Internal(format!("Failed to create directory: {}", e)))?;
-
-  let file_id = Uuid::new_v4();
-  let receipt_path = format!("{}/{}.{}", receipt_dir, file_id, file_ext);
-  tokio::fs::write(&receipt_path, &file_bytes)
-    .await
-    .map_err(|e| ApiError::Internal(format!("Failed to save receipt: {}", e)))?;
-
   upload_receipt_use_case
     .execute(UploadReceiptCommand {
+      company_id,
       transaction_id: tx_id,
-      receipt_path,
+      content: file_bytes,
+      file_name,
+      uploaded_by: user.id,
     })
     .await
     .map_err(ApiError::from)?;
@@ -707,19 +1188,115 @@ pub async fn extract_invoice_data(
   Ok(HttpResponse::Ok().content_type("text/html").body(html))
 }
 
+/// Payment terms from the received invoice forms; blank fields are left out
+fn payment_terms(
+  vat_amount: &str,
+  due_date: &str,
+  vendor_iban: String,
+  payment_reference: String,
+) -> Result<PaymentTerms, ApiError> {
+  let vat_amount = match vat_amount.trim() {
+    "" => Decimal::ZERO,
+    vat => {
+      Decimal::from_str(vat).map_err(|_| ApiError::Validation("Invalid VAT amount".to_string()))?
+    }
+  };
+  let due_date = match due_date.trim() {
+    "" => None,
+    due => Some(
+      NaiveDate::parse_from_str(due, "%Y-%m-%d")
+        .map_err(|_| ApiError::Validation("Invalid due date".to_string()))?,
+    ),
+  };
+  Ok(PaymentTerms {
+    vat_amount,
+    due_date,
+    vendor_iban: Some(vendor_iban),
+    payment_reference: Some(payment_reference),
+  })
+}
+
+fn payable_statuses() -> Vec<serde_json::Value> {
+  PayableStatus::all()
+    .iter()
+    .map(|s| serde_json::json!({ "value": s.as_str(), "label": s.label() }))
+    .collect()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PayableForm {
+  #[serde(default)]
+  pub vat_amount: String,
+  #[serde(default)]
+  pub due_date: String,
+  #[serde(default)]
+  pub vendor_iban: String,
+  #[serde(default)]
+  pub payment_reference: String,
+  #[serde(default)]
+  pub status: String,
+}
+
+// POST /reports/received-invoices/{id} - Update payment terms and status
+pub async fn update_payable(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  form: web::Form<PayableForm>,
+  update_use_case: web::Data<Arc<UpdatePayableUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, id) = path.into_inner();
+  let form = form.into_inner();
+
+  let status = match form.status.as_str() {
+    "" => None,
+    status => Some(PayableStatus::try_from(status).map_err(ApiError::from)?),
+  };
+  let terms = payment_terms(
+    &form.vat_amount,
+    &form.due_date,
+    form.vendor_iban,
+    form.payment_reference,
+  )?;
+
+  update_use_case
+    .execute(UpdatePayableCommand {
+      id,
+      company_id,
+      terms,
+      status,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/received-invoices", company_id),
+      ))
+      .finish(),
+  )
+}
+
 // DELETE /reports/received-invoices/{id} - Delete received invoice
 pub async fn delete_received_invoice(
   req: HttpRequest,
   path: web::Path<(Uuid, Uuid)>,
   delete_use_case: web::Data<Arc<DeleteReceivedInvoiceUseCase>>,
 ) -> Result<HttpResponse, ApiError> {
-  let _user = get_user(&req)?;
+  let user = get_user(&req)?;
   let company_context = get_company_context(&req)?;
   let company_id = company_context.company_id;
   let (_, id) = path.into_inner();
 
   delete_use_case
-    .execute(DeleteReceivedInvoiceCommand { id })
+    .execute(DeleteReceivedInvoiceCommand {
+      id,
+      user_id: user.id,
+    })
     .await
     .map_err(ApiError::from)?;
 
@@ -732,3 +1309,649 @@ pub async fn delete_received_invoice(
       .finish(),
   )
 }
+
+// GET /reports/vendors - Vendor directory
+pub async fn vendors_page(
+  req: HttpRequest,
+  templates: web::Data<TemplateEngine>,
+  list_use_case: web::Data<Arc<ListVendorsUseCase>>,
+  get_companies_use_case: web::Data<Arc<crate::application::company::GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+
+  let companies_response = get_companies_use_case
+    .execute(crate::application::company::GetUserCompaniesCommand { user_id: user.id })
+    .await?;
+
+  let response = list_use_case
+    .execute(ListVendorsCommand { company_id })
+    .await
+    .map_err(ApiError::from)?;
+
+  let active_company = companies_response
+    .companies
+    .iter()
+    .find(|c| c.company_id == company_id)
+    .map(|c| {
+      serde_json::json!({
+        "company_id": c.company_id,
+        "name": c.name,
+        "role": c.role,
+      })
+    });
+
+  let mut context = tera::Context::new();
+  context.insert("vendors", &response.vendors);
+  context.insert("categories", &response.categories);
+  context.insert("user", &user);
+  context.insert("companies", &companies_response.companies);
+  context.insert("active_company", &active_company);
+  context.insert("company_id", &company_id.to_string());
+  context.insert("current_page", "reports");
+
+  let html = templates
+    .render("pages/vendors.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+// GET /reports/vendors/{id} - A vendor with its bills and payments
+pub async fn vendor_details_page(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  templates: web::Data<TemplateEngine>,
+  details_use_case: web::Data<Arc<GetVendorDetailsUseCase>>,
+  get_companies_use_case: web::Data<Arc<crate::application::company::GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, vendor_id) = path.into_inner();
+
+  let companies_response = get_companies_use_case
+    .execute(crate::application::company::GetUserCompaniesCommand { user_id: user.id })
+    .await?;
+
+  let details = details_use_case
+    .execute(GetVendorDetailsCommand {
+      company_id,
+      vendor_id,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  let active_company = companies_response
+    .companies
+    .iter()
+    .find(|c| c.company_id == company_id)
+    .map(|c| {
+      serde_json::json!({
+        "company_id": c.company_id,
+        "name": c.name,
+        "role": c.role,
+      })
+    });
+
+  let mut context = tera::Context::new();
+  context.insert("vendor", &details.vendor);
+  context.insert("invoices", &details.invoices);
+  context.insert("transactions", &details.transactions);
+  context.insert("paid_total", &details.paid_total);
+  context.insert("open_amount", &details.open_amount);
+  context.insert("categories", &details.categories);
+  context.insert("other_vendors", &details.other_vendors);
+  context.insert("user", &user);
+  context.insert("companies", &companies_response.companies);
+  context.insert("active_company", &active_company);
+  context.insert("company_id", &company_id.to_string());
+  context.insert("current_page", "reports");
+
+  let html = templates
+    .render("pages/vendor_details.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VendorForm {
+  pub name: String,
+  #[serde(default)]
+  pub registry_code: String,
+  #[serde(default)]
+  pub vat_number: String,
+  /// IBANs separated by commas or new lines
+  #[serde(default)]
+  pub ibans: String,
+  #[serde(default)]
+  pub default_category_id: String,
+  #[serde(default)]
+  pub default_vat_rate: String,
+}
+
+impl VendorForm {
+  fn into_profile(self) -> Result<VendorProfile, ApiError> {
+    let default_category_id = match self.default_category_id.trim() {
+      "" => None,
+      id => {
+        Some(Uuid::parse_str(id).map_err(|_| ApiError::Validation("Invalid category".to_string()))?)
+      }
+    };
+    let default_vat_rate = match self.default_vat_rate.trim() {
+      "" => None,
+      rate => Some(
+        Decimal::from_str(rate)
+          .map_err(|_| ApiError::Validation("Invalid VAT rate".to_string()))?,
+      ),
+    };
+    Ok(VendorProfile {
+      name: self.name,
+      registry_code: Some(self.registry_code),
+      vat_number: Some(self.vat_number),
+      ibans: self
+        .ibans
+        .split([',', '\n'])
+        .map(|iban| iban.trim().to_string())
+        .collect(),
+      default_category_id,
+      default_vat_rate,
+    })
+  }
+}
+
+// POST /reports/vendors - Add a vendor
+pub async fn create_vendor(
+  req: HttpRequest,
+  form: web::Form<VendorForm>,
+  save_use_case: web::Data<Arc<SaveVendorUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+
+  let vendor = save_use_case
+    .execute(SaveVendorCommand {
+      company_id,
+      vendor_id: None,
+      profile: form.into_inner().into_profile()?,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/vendors/{}", company_id, vendor.id),
+      ))
+      .finish(),
+  )
+}
+
+// POST /reports/vendors/{id} - Update a vendor
+pub async fn update_vendor(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  form: web::Form<VendorForm>,
+  save_use_case: web::Data<Arc<SaveVendorUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, vendor_id) = path.into_inner();
+
+  save_use_case
+    .execute(SaveVendorCommand {
+      company_id,
+      vendor_id: Some(vendor_id),
+      profile: form.into_inner().into_profile()?,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/vendors/{}", company_id, vendor_id),
+      ))
+      .finish(),
+  )
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MergeVendorForm {
+  pub into_id: Uuid,
+}
+
+// POST /reports/vendors/{id}/merge - Merge a duplicate vendor into another
+pub async fn merge_vendor(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  form: web::Form<MergeVendorForm>,
+  merge_use_case: web::Data<Arc<MergeVendorsUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, from_id) = path.into_inner();
+
+  let vendor = merge_use_case
+    .execute(MergeVendorsCommand {
+      company_id,
+      from_id,
+      into_id: form.into_inner().into_id,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/vendors/{}", company_id, vendor.id),
+      ))
+      .finish(),
+  )
+}
+
+// GET /reports/categories - Manage transaction categories
+pub async fn categories_page(
+  req: HttpRequest,
+  templates: web::Data<TemplateEngine>,
+  list_use_case: web::Data<Arc<ListTransactionCategoriesUseCase>>,
+  get_companies_use_case: web::Data<Arc<crate::application::company::GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+
+  let companies_response = get_companies_use_case
+    .execute(crate::application::company::GetUserCompaniesCommand { user_id: user.id })
+    .await?;
+
+  let response = list_use_case
+    .execute(ListTransactionCategoriesCommand { company_id })
+    .await
+    .map_err(ApiError::from)?;
+
+  let active_company = companies_response
+    .companies
+    .iter()
+    .find(|c| c.company_id == company_id)
+    .map(|c| {
+      serde_json::json!({
+        "company_id": c.company_id,
+        "name": c.name,
+        "role": c.role,
+      })
+    });
+
+  let mut context = tera::Context::new();
+  context.insert("categories", &response.categories);
+  context.insert("user", &user);
+  context.insert("companies", &companies_response.companies);
+  context.insert("active_company", &active_company);
+  context.insert("company_id", &company_id.to_string());
+  context.insert("current_page", "reports");
+
+  let html = templates
+    .render("pages/transaction_categories.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CategoryForm {
+  pub name: String,
+}
+
+// POST /reports/categories - Create transaction category
+pub async fn create_category(
+  req: HttpRequest,
+  form: web::Form<CategoryForm>,
+  create_use_case: web::Data<Arc<CreateTransactionCategoryUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+
+  create_use_case
+    .execute(CreateTransactionCategoryCommand {
+      company_id,
+      name: form.into_inner().name,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/categories", company_id),
+      ))
+      .finish(),
+  )
+}
+
+// DELETE /reports/categories/{category_id} - Delete transaction category
+pub async fn delete_category(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  delete_use_case: web::Data<Arc<DeleteTransactionCategoryUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, category_id) = path.into_inner();
+
+  delete_use_case
+    .execute(DeleteTransactionCategoryCommand {
+      company_id,
+      category_id,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/categories", company_id),
+      ))
+      .finish(),
+  )
+}
+
+// DELETE /reports/{id}/rule/{tx_id} - Clear what a matching rule did to a transaction
+pub async fn clear_rule_outcome(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid, Uuid)>,
+  clear_use_case: web::Data<Arc<ClearRuleOutcomeUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, report_id, tx_id) = path.into_inner();
+
+  clear_use_case
+    .execute(ClearRuleOutcomeCommand {
+      company_id,
+      transaction_id: tx_id,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header((
+        "HX-Redirect",
+        format!("/c/{}/reports/{}", company_id, report_id),
+      ))
+      .finish(),
+  )
+}
+
+// GET /reports/rules - Manage matching rules
+pub async fn rules_page(
+  req: HttpRequest,
+  templates: web::Data<TemplateEngine>,
+  list_rules_use_case: web::Data<Arc<ListMatchingRulesUseCase>>,
+  list_categories_use_case: web::Data<Arc<ListTransactionCategoriesUseCase>>,
+  list_reports_use_case: web::Data<Arc<ListMonthlyReportsUseCase>>,
+  get_companies_use_case: web::Data<Arc<crate::application::company::GetUserCompaniesUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+
+  let companies_response = get_companies_use_case
+    .execute(crate::application::company::GetUserCompaniesCommand { user_id: user.id })
+    .await?;
+
+  let rules = list_rules_use_case
+    .execute(ListMatchingRulesCommand { company_id })
+    .await
+    .map_err(ApiError::from)?;
+
+  let categories = list_categories_use_case
+    .execute(ListTransactionCategoriesCommand { company_id })
+    .await
+    .map_err(ApiError::from)?;
+
+  let reports = list_reports_use_case
+    .execute(ListMonthlyReportsCommand { company_id })
+    .await
+    .map_err(ApiError::from)?;
+
+  let active_company = companies_response
+    .companies
+    .iter()
+    .find(|c| c.company_id == company_id)
+    .map(|c| {
+      serde_json::json!({
+        "company_id": c.company_id,
+        "name": c.name,
+        "role": c.role,
+      })
+    });
+
+  let mut context = tera::Context::new();
+  context.insert("rules", &rules.rules);
+  context.insert("categories", &categories.categories);
+  context.insert("reports", &reports.reports);
+  context.insert("user", &user);
+  context.insert("companies", &companies_response.companies);
+  context.insert("active_company", &active_company);
+  context.insert("company_id", &company_id.to_string());
+  context.insert("current_page", "reports");
+
+  let html = templates
+    .render("pages/matching_rules.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MatchingRuleForm {
+  pub name: String,
+  pub counterparty_iban: Option<String>,
+  pub description_pattern: Option<String>,
+  pub min_amount: Option<String>,
+  pub max_amount: Option<String>,
+  pub direction: Option<String>,
+  pub action: String,
+  pub category_id: Option<String>,
+  pub vendor_name: Option<String>,
+  /// Past report to test the rule against
+  pub report_id: Option<String>,
+}
+
+impl MatchingRuleForm {
+  fn conditions(&self) -> Result<RuleConditions, ApiError> {
+    let amount = |value: &Option<String>, label: &str| {
+      value
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+          Decimal::from_str(s)
+            .map_err(|_| ApiError::Validation(format!("Invalid {} amount", label)))
+        })
+        .transpose()
+    };
+
+    Ok(RuleConditions {
+      counterparty_iban: self.counterparty_iban.clone(),
+      description_pattern: self.description_pattern.clone(),
+      min_amount: amount(&self.min_amount, "minimum")?,
+      max_amount: amount(&self.max_amount, "maximum")?,
+      direction: self
+        .direction
+        .as_deref()
+        .filter(|s| !s.is_empty())
+        .map(TransactionDirection::try_from)
+        .transpose()
+        .map_err(ApiError::from)?,
+    })
+  }
+
+  fn action(&self) -> Result<RuleAction, ApiError> {
+    let category_id = self
+      .category_id
+      .as_ref()
+      .filter(|s| !s.is_empty())
+      .map(|s| Uuid::parse_str(s))
+      .transpose()
+      .map_err(|_| ApiError::Validation("Invalid category ID".to_string()))?;
+
+    RuleAction::from_parts(&self.action, category_id, self.vendor_name.as_deref())
+      .map_err(ApiError::from)
+  }
+}
+
+// POST /reports/rules - Save a matching rule as the last one evaluated
+pub async fn create_rule(
+  req: HttpRequest,
+  form: web::Form<MatchingRuleForm>,
+  create_use_case: web::Data<Arc<CreateMatchingRuleUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let form = form.into_inner();
+
+  create_use_case
+    .execute(CreateMatchingRuleCommand {
+      company_id,
+      conditions: form.conditions()?,
+      action: form.action()?,
+      name: form.name,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header(("HX-Redirect", format!("/c/{}/reports/rules", company_id)))
+      .finish(),
+  )
+}
+
+// POST /reports/rules/test - Show what an unsaved rule does to a past report
+pub async fn test_rule(
+  req: HttpRequest,
+  form: web::Form<MatchingRuleForm>,
+  templates: web::Data<TemplateEngine>,
+  test_use_case: web::Data<Arc<TestMatchingRuleUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let form = form.into_inner();
+
+  let result = async {
+    let report_id = form
+      .report_id
+      .as_ref()
+      .filter(|s| !s.is_empty())
+      .ok_or_else(|| ApiError::Validation("Choose a report to test against".to_string()))
+      .and_then(|s| {
+        Uuid::parse_str(s).map_err(|_| ApiError::Validation("Invalid report ID".to_string()))
+      })?;
+
+    test_use_case
+      .execute(TestMatchingRuleCommand {
+        company_id,
+        report_id,
+        conditions: form.conditions()?,
+        action: form.action()?,
+        name: form.name.clone(),
+      })
+      .await
+      .map_err(ApiError::from)
+  }
+  .await;
+
+  // Validation problems are shown in place of the results
+  let mut context = tera::Context::new();
+  match result {
+    Ok(response) => {
+      context.insert("matches", &response.matches);
+      context.insert("fired_count", &response.fired_count);
+    }
+    Err(ApiError::Validation(message)) => context.insert("error", &message),
+    Err(e) => return Err(e),
+  }
+
+  let html = templates
+    .render("partials/rule_test_results.html.tera", &context)
+    .map_err(|e| ApiError::Internal(format!("Template error: {:?}", e)))?;
+
+  Ok(HttpResponse::Ok().content_type("text/html").body(html))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MoveRuleForm {
+  /// "up" to evaluate the rule earlier, anything else to evaluate it later
+  pub direction: String,
+}
+
+// POST /reports/rules/{rule_id}/move - Change a rule's place in the evaluation order
+pub async fn move_rule(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  form: web::Form<MoveRuleForm>,
+  move_use_case: web::Data<Arc<MoveMatchingRuleUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, rule_id) = path.into_inner();
+
+  move_use_case
+    .execute(MoveMatchingRuleCommand {
+      company_id,
+      rule_id,
+      earlier: form.direction == "up",
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header(("HX-Redirect", format!("/c/{}/reports/rules", company_id)))
+      .finish(),
+  )
+}
+
+// DELETE /reports/rules/{rule_id} - Delete a matching rule
+pub async fn delete_rule(
+  req: HttpRequest,
+  path: web::Path<(Uuid, Uuid)>,
+  delete_use_case: web::Data<Arc<DeleteMatchingRuleUseCase>>,
+) -> Result<HttpResponse, ApiError> {
+  let _user = get_user(&req)?;
+  let company_context = get_company_context(&req)?;
+  let company_id = company_context.company_id;
+  let (_, rule_id) = path.into_inner();
+
+  delete_use_case
+    .execute(DeleteMatchingRuleCommand {
+      company_id,
+      rule_id,
+    })
+    .await
+    .map_err(ApiError::from)?;
+
+  Ok(
+    HttpResponse::Ok()
+      .insert_header(("HX-Redirect", format!("/c/{}/reports/rules", company_id)))
+      .finish(),
+  )
+}
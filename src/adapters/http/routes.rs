@@ -9,26 +9,43 @@ use crate::application::company::{
   AddCompanyMemberUseCase, ArchiveBankAccountUseCase, ConnectGoogleDriveUseCase,
   CreateBankAccountUseCase, CreateCompanyUseCase, DisconnectGoogleDriveUseCase,
   GetBankAccountsUseCase, GetCompanyDetailsUseCase, GetUserCompaniesUseCase,
-  RemoveCompanyMemberUseCase, SetActiveBankAccountUseCase, SetActiveCompanyUseCase,
-  TestDriveConnectionUseCase, UpdateBankAccountUseCase, UpdateCompanyProfileUseCase,
-  UpdateStorageConfigUseCase,
+  ListDriveFoldersUseCase, RemoveCompanyMemberUseCase, SetActiveBankAccountUseCase,
+  SetActiveCompanyUseCase, TestDriveConnectionUseCase, UpdateBankAccountUseCase,
+  UpdateCompanyProfileUseCase, UpdateStorageConfigUseCase,
 };
+use crate::application::document::ListDocumentsUseCase;
+use crate::application::financials::{ExportStatementCsvUseCase, GetFinancialStatementUseCase};
 use crate::application::invoice::{
   ArchiveCustomerUseCase, ArchiveInvoiceUseCase, ChangeInvoiceStatusUseCase, CreateCustomerUseCase,
-  CreateInvoiceUseCase, GetInvoiceDetailsUseCase, ListCustomersUseCase, ListInvoicesUseCase,
-  ReuploadInvoiceUseCase, UpdateCustomerUseCase,
+  CreateInvoiceUseCase, GetInvoiceDetailsUseCase, ListCustomersUseCase, ListInvoiceEventsUseCase,
+  ListInvoicesUseCase, RetryInvoiceUploadsUseCase, ReuploadInvoiceUseCase, UpdateCustomerUseCase,
+  UpdateInvoiceUseCase,
+};
+use crate::application::ledger::{
+  CreateAccountUseCase, GetTrialBalanceUseCase, ListAccountsUseCase, ListJournalEntriesUseCase,
+  PostJournalEntryUseCase, ReverseJournalEntryUseCase,
 };
 use crate::application::report::{
-  CreateEmptyReportUseCase, DeleteReceivedInvoiceUseCase, DeleteReportUseCase,
-  GenerateReportUseCase, GetReportDetailsUseCase, ImportBankStatementUseCase,
-  ListMonthlyReportsUseCase, ListReceivedInvoicesUseCase, MatchTransactionUseCase,
-  UnmatchTransactionUseCase, UploadReceiptUseCase, UploadReceivedInvoiceUseCase,
+  CategoriseTransactionUseCase, ClearRuleOutcomeUseCase, CreateEmptyReportUseCase,
+  CreateMatchingRuleUseCase, CreateTransactionCategoryUseCase, DeleteMatchingRuleUseCase,
+  DeleteReceivedInvoiceUseCase, DeleteReportUseCase, DeleteTransactionCategoryUseCase,
+  ExportReportArchiveUseCase, GenerateReportUseCase, GetReportDetailsUseCase,
+  GetVendorDetailsUseCase, ImportBankStatementUseCase, ListMatchingRulesUseCase,
+  ListMonthlyReportsUseCase, ListReceivedInvoicesUseCase, ListReportEventsUseCase,
+  ListTransactionCategoriesUseCase, ListUpcomingBillsUseCase, ListVendorsUseCase,
+  LockReportUseCase, MatchTransactionUseCase, MergeVendorsUseCase, MoveMatchingRuleUseCase,
+  ReopenReportUseCase, RetryReportUploadsUseCase, SaveVendorUseCase, TestMatchingRuleUseCase,
+  UnmatchTransactionUseCase, UpdatePayableUseCase, UploadReceiptUseCase,
+  UploadReceivedInvoiceUseCase,
 };
+use crate::application::saft::ExportSaftUseCase;
+use crate::application::storage::{GetSyncStatusUseCase, ListPendingUploadsUseCase};
 use crate::domain::auth::ports::UserRepository;
 use crate::domain::auth::services::AuthService;
 use crate::domain::company::ports::{
   ActiveBankAccountRepository, ActiveCompanyRepository, CompanyMemberRepository,
 };
+use crate::domain::financials::HtmlPdfConverter;
 use crate::domain::report::ports::InvoiceDataExtractor;
 
 use super::errors::ApiError;
@@ -40,8 +57,8 @@ use super::handlers::company::{
   remove_company_member_handler, set_active_company_handler,
 };
 use super::handlers::{
-  bank_accounts, bank_accounts_web, company_settings, company_web, customers_web, get_user,
-  invoices_web, oauth_callback, pages, reports_web, web_auth,
+  bank_accounts, bank_accounts_web, company_settings, company_web, customers_web, financials_web,
+  get_user, invoices_web, ledger_web, oauth_callback, pages, reports_web, web_auth,
 };
 use super::middleware::{CompanyContextMiddleware, WebAuthMiddleware};
 use super::templates::TemplateEngine;
@@ -80,8 +97,11 @@ pub struct WebRouteDependencies {
   pub get_invoice_details_use_case: Arc<GetInvoiceDetailsUseCase>,
   pub change_invoice_status_use_case: Arc<ChangeInvoiceStatusUseCase>,
   pub reupload_invoice_use_case: Arc<ReuploadInvoiceUseCase>,
+  pub retry_invoice_uploads_use_case: Arc<RetryInvoiceUploadsUseCase>,
   pub archive_invoice_use_case: Arc<ArchiveInvoiceUseCase>,
   pub delete_invoice_use_case: Arc<crate::application::invoice::DeleteInvoiceUseCase>,
+  pub update_invoice_use_case: Arc<UpdateInvoiceUseCase>,
+  pub list_invoice_events_use_case: Arc<ListInvoiceEventsUseCase>,
   // Template use cases
   pub create_template_from_invoice_use_case:
     Arc<crate::application::invoice::CreateTemplateFromInvoiceUseCase>,
@@ -99,6 +119,18 @@ pub struct WebRouteDependencies {
   pub connect_google_drive_use_case: Arc<ConnectGoogleDriveUseCase>,
   pub disconnect_google_drive_use_case: Arc<DisconnectGoogleDriveUseCase>,
   pub test_drive_connection_use_case: Arc<TestDriveConnectionUseCase>,
+  pub list_drive_folders_use_case: Arc<ListDriveFoldersUseCase>,
+  // Invoice branding use cases
+  pub get_invoice_branding_use_case: Arc<crate::application::invoice::GetInvoiceBrandingUseCase>,
+  pub update_invoice_branding_use_case:
+    Arc<crate::application::invoice::UpdateInvoiceBrandingUseCase>,
+  pub preview_invoice_branding_use_case:
+    Arc<crate::application::invoice::PreviewInvoiceBrandingUseCase>,
+  // Retention use cases
+  pub get_retention_settings_use_case:
+    Arc<crate::application::retention::GetRetentionSettingsUseCase>,
+  pub update_retention_policy_use_case:
+    Arc<crate::application::retention::UpdateRetentionPolicyUseCase>,
   // Report use cases
   pub create_empty_report_use_case: Arc<CreateEmptyReportUseCase>,
   pub import_bank_statement_use_case: Arc<ImportBankStatementUseCase>,
@@ -106,13 +138,50 @@ pub struct WebRouteDependencies {
   pub get_report_details_use_case: Arc<GetReportDetailsUseCase>,
   pub upload_received_invoice_use_case: Arc<UploadReceivedInvoiceUseCase>,
   pub list_received_invoices_use_case: Arc<ListReceivedInvoicesUseCase>,
+  pub update_payable_use_case: Arc<UpdatePayableUseCase>,
+  pub list_upcoming_bills_use_case: Arc<ListUpcomingBillsUseCase>,
+  pub list_vendors_use_case: Arc<ListVendorsUseCase>,
+  pub get_vendor_details_use_case: Arc<GetVendorDetailsUseCase>,
+  pub save_vendor_use_case: Arc<SaveVendorUseCase>,
+  pub merge_vendors_use_case: Arc<MergeVendorsUseCase>,
   pub match_transaction_use_case: Arc<MatchTransactionUseCase>,
   pub unmatch_transaction_use_case: Arc<UnmatchTransactionUseCase>,
   pub generate_report_use_case: Arc<GenerateReportUseCase>,
+  pub export_report_archive_use_case: Arc<ExportReportArchiveUseCase>,
+  pub retry_report_uploads_use_case: Arc<RetryReportUploadsUseCase>,
   pub delete_report_use_case: Arc<DeleteReportUseCase>,
+  pub reopen_report_use_case: Arc<ReopenReportUseCase>,
+  pub lock_report_use_case: Arc<LockReportUseCase>,
+  pub list_report_events_use_case: Arc<ListReportEventsUseCase>,
   pub delete_received_invoice_use_case: Arc<DeleteReceivedInvoiceUseCase>,
   pub upload_receipt_use_case: Arc<UploadReceiptUseCase>,
+  pub list_transaction_categories_use_case: Arc<ListTransactionCategoriesUseCase>,
+  pub create_transaction_category_use_case: Arc<CreateTransactionCategoryUseCase>,
+  pub delete_transaction_category_use_case: Arc<DeleteTransactionCategoryUseCase>,
+  pub categorise_transaction_use_case: Arc<CategoriseTransactionUseCase>,
+  pub list_matching_rules_use_case: Arc<ListMatchingRulesUseCase>,
+  pub create_matching_rule_use_case: Arc<CreateMatchingRuleUseCase>,
+  pub test_matching_rule_use_case: Arc<TestMatchingRuleUseCase>,
+  pub move_matching_rule_use_case: Arc<MoveMatchingRuleUseCase>,
+  pub delete_matching_rule_use_case: Arc<DeleteMatchingRuleUseCase>,
+  pub clear_rule_outcome_use_case: Arc<ClearRuleOutcomeUseCase>,
   pub invoice_data_extractor: Arc<dyn InvoiceDataExtractor>,
+  pub list_documents_use_case: Arc<ListDocumentsUseCase>,
+  // Ledger use cases
+  pub list_accounts_use_case: Arc<ListAccountsUseCase>,
+  pub create_account_use_case: Arc<CreateAccountUseCase>,
+  pub post_journal_entry_use_case: Arc<PostJournalEntryUseCase>,
+  pub reverse_journal_entry_use_case: Arc<ReverseJournalEntryUseCase>,
+  pub list_journal_entries_use_case: Arc<ListJournalEntriesUseCase>,
+  pub get_trial_balance_use_case: Arc<GetTrialBalanceUseCase>,
+  pub export_saft_use_case: Arc<ExportSaftUseCase>,
+  // Financial statement use cases
+  pub get_financial_statement_use_case: Arc<GetFinancialStatementUseCase>,
+  pub export_statement_csv_use_case: Arc<ExportStatementCsvUseCase>,
+  pub html_pdf_converter: Arc<dyn HtmlPdfConverter>,
+  // Upload outbox
+  pub list_pending_uploads_use_case: Arc<ListPendingUploadsUseCase>,
+  pub get_sync_status_use_case: Arc<GetSyncStatusUseCase>,
 }
 
 /// Configure authentication routes
@@ -367,6 +436,12 @@ pub fn configure_web_routes(cfg: &mut web::ServiceConfig, deps: WebRouteDependen
       .app_data(web::Data::new(deps.connect_google_drive_use_case.clone()))
       .app_data(web::Data::new(deps.disconnect_google_drive_use_case))
       .app_data(web::Data::new(deps.test_drive_connection_use_case))
+      .app_data(web::Data::new(deps.list_drive_folders_use_case))
+      .app_data(web::Data::new(deps.get_invoice_branding_use_case))
+      .app_data(web::Data::new(deps.update_invoice_branding_use_case))
+      .app_data(web::Data::new(deps.preview_invoice_branding_use_case))
+      .app_data(web::Data::new(deps.get_retention_settings_use_case))
+      .app_data(web::Data::new(deps.update_retention_policy_use_case))
       .app_data(web::Data::new(deps.user_repo))
       .app_data(web::Data::new(deps.member_repo))
       .route("", web::get().to(company_web::companies_page))
@@ -394,6 +469,18 @@ pub fn configure_web_routes(cfg: &mut web::ServiceConfig, deps: WebRouteDependen
         "/{company_id}/settings/storage",
         web::post().to(company_settings::update_storage_config),
       )
+      .route(
+        "/{company_id}/settings/branding",
+        web::post().to(company_settings::update_invoice_branding),
+      )
+      .route(
+        "/{company_id}/settings/branding/preview",
+        web::post().to(company_settings::preview_invoice_branding),
+      )
+      .route(
+        "/{company_id}/settings/retention",
+        web::post().to(company_settings::update_retention_policy),
+      )
       // OAuth routes for Google Drive
       .route(
         "/{company_id}/drive/connect",
@@ -407,6 +494,10 @@ pub fn configure_web_routes(cfg: &mut web::ServiceConfig, deps: WebRouteDependen
         "/{company_id}/drive/test",
         web::post().to(company_settings::test_drive_connection),
       )
+      .route(
+        "/{company_id}/drive/folders",
+        web::get().to(company_settings::drive_folder_picker),
+      )
       .route(
         "/{company_id}/members",
         web::get().to(company_web::company_members_page),
@@ -518,8 +609,13 @@ pub fn configure_company_scoped_routes(cfg: &mut web::ServiceConfig, deps: &WebR
       .app_data(web::Data::new(deps.get_invoice_details_use_case.clone()))
       .app_data(web::Data::new(deps.change_invoice_status_use_case.clone()))
       .app_data(web::Data::new(deps.reupload_invoice_use_case.clone()))
+      .app_data(web::Data::new(deps.retry_invoice_uploads_use_case.clone()))
+      .app_data(web::Data::new(deps.list_pending_uploads_use_case.clone()))
+      .app_data(web::Data::new(deps.get_sync_status_use_case.clone()))
       .app_data(web::Data::new(deps.archive_invoice_use_case.clone()))
       .app_data(web::Data::new(deps.delete_invoice_use_case.clone()))
+      .app_data(web::Data::new(deps.update_invoice_use_case.clone()))
+      .app_data(web::Data::new(deps.list_invoice_events_use_case.clone()))
       .app_data(web::Data::new(deps.get_bank_accounts_use_case.clone()))
       .app_data(web::Data::new(deps.active_bank_account_repo.clone()))
       .route("/invoices", web::get().to(invoices_web::invoices_page))
@@ -568,6 +664,14 @@ pub fn configure_company_scoped_routes(cfg: &mut web::ServiceConfig, deps: &WebR
         "/invoices/{id}",
         web::get().to(invoices_web::invoice_details_page),
       )
+      .route(
+        "/invoices/{id}",
+        web::put().to(invoices_web::update_invoice_submit),
+      )
+      .route(
+        "/invoices/{id}/edit",
+        web::get().to(invoices_web::invoice_edit_page),
+      )
       .route(
         "/invoices/{id}/save-as-template",
         web::post().to(invoices_web::save_as_template),
@@ -580,6 +684,10 @@ pub fn configure_company_scoped_routes(cfg: &mut web::ServiceConfig, deps: &WebR
         "/invoices/{id}/reupload",
         web::post().to(invoices_web::reupload_invoice),
       )
+      .route(
+        "/invoices/{id}/uploads/retry",
+        web::post().to(invoices_web::retry_invoice_uploads),
+      )
       .route(
         "/invoices/{id}/archive",
         web::delete().to(invoices_web::archive_invoice),
@@ -638,15 +746,43 @@ pub fn configure_company_scoped_routes(cfg: &mut web::ServiceConfig, deps: &WebR
         deps.upload_received_invoice_use_case.clone(),
       ))
       .app_data(web::Data::new(deps.list_received_invoices_use_case.clone()))
+      .app_data(web::Data::new(deps.update_payable_use_case.clone()))
+      .app_data(web::Data::new(deps.list_upcoming_bills_use_case.clone()))
+      .app_data(web::Data::new(deps.list_vendors_use_case.clone()))
+      .app_data(web::Data::new(deps.get_vendor_details_use_case.clone()))
+      .app_data(web::Data::new(deps.save_vendor_use_case.clone()))
+      .app_data(web::Data::new(deps.merge_vendors_use_case.clone()))
       .app_data(web::Data::new(deps.match_transaction_use_case.clone()))
       .app_data(web::Data::new(deps.unmatch_transaction_use_case.clone()))
       .app_data(web::Data::new(deps.generate_report_use_case.clone()))
+      .app_data(web::Data::new(deps.export_report_archive_use_case.clone()))
+      .app_data(web::Data::new(deps.retry_report_uploads_use_case.clone()))
       .app_data(web::Data::new(deps.delete_report_use_case.clone()))
+      .app_data(web::Data::new(deps.reopen_report_use_case.clone()))
+      .app_data(web::Data::new(deps.lock_report_use_case.clone()))
+      .app_data(web::Data::new(deps.list_report_events_use_case.clone()))
       .app_data(web::Data::new(
         deps.delete_received_invoice_use_case.clone(),
       ))
       .app_data(web::Data::new(deps.upload_receipt_use_case.clone()))
+      .app_data(web::Data::new(
+        deps.list_transaction_categories_use_case.clone(),
+      ))
+      .app_data(web::Data::new(
+        deps.create_transaction_category_use_case.clone(),
+      ))
+      .app_data(web::Data::new(
+        deps.delete_transaction_category_use_case.clone(),
+      ))
+      .app_data(web::Data::new(deps.categorise_transaction_use_case.clone()))
+      .app_data(web::Data::new(deps.list_matching_rules_use_case.clone()))
+      .app_data(web::Data::new(deps.create_matching_rule_use_case.clone()))
+      .app_data(web::Data::new(deps.test_matching_rule_use_case.clone()))
+      .app_data(web::Data::new(deps.move_matching_rule_use_case.clone()))
+      .app_data(web::Data::new(deps.delete_matching_rule_use_case.clone()))
+      .app_data(web::Data::new(deps.clear_rule_outcome_use_case.clone()))
       .app_data(web::Data::new(deps.invoice_data_extractor.clone()))
+      .app_data(web::Data::new(deps.list_documents_use_case.clone()))
       .route("/reports", web::get().to(reports_web::reports_page))
       .route(
         "/reports/create",
@@ -664,6 +800,10 @@ pub fn configure_company_scoped_routes(cfg: &mut web::ServiceConfig, deps: &WebR
         "/reports/received-invoices",
         web::get().to(reports_web::received_invoices_page),
       )
+      .route(
+        "/reports/received-invoices/upcoming",
+        web::get().to(reports_web::upcoming_bills_page),
+      )
       .route(
         "/reports/received-invoices/extract",
         web::post().to(reports_web::extract_invoice_data),
@@ -672,10 +812,61 @@ pub fn configure_company_scoped_routes(cfg: &mut web::ServiceConfig, deps: &WebR
         "/reports/received-invoices",
         web::post().to(reports_web::upload_received_invoice),
       )
+      .route(
+        "/reports/received-invoices/{id}",
+        web::post().to(reports_web::update_payable),
+      )
       .route(
         "/reports/received-invoices/{id}",
         web::delete().to(reports_web::delete_received_invoice),
       )
+      .route("/reports/vendors", web::get().to(reports_web::vendors_page))
+      .route(
+        "/reports/vendors",
+        web::post().to(reports_web::create_vendor),
+      )
+      .route(
+        "/reports/vendors/{id}",
+        web::get().to(reports_web::vendor_details_page),
+      )
+      .route(
+        "/reports/vendors/{id}",
+        web::post().to(reports_web::update_vendor),
+      )
+      .route(
+        "/reports/vendors/{id}/merge",
+        web::post().to(reports_web::merge_vendor),
+      )
+      .route(
+        "/reports/documents",
+        web::get().to(reports_web::documents_page),
+      )
+      .route(
+        "/reports/categories",
+        web::get().to(reports_web::categories_page),
+      )
+      .route(
+        "/reports/categories",
+        web::post().to(reports_web::create_category),
+      )
+      .route(
+        "/reports/categories/{category_id}",
+        web::delete().to(reports_web::delete_category),
+      )
+      .route("/reports/rules", web::get().to(reports_web::rules_page))
+      .route("/reports/rules", web::post().to(reports_web::create_rule))
+      .route(
+        "/reports/rules/test",
+        web::post().to(reports_web::test_rule),
+      )
+      .route(
+        "/reports/rules/{rule_id}",
+        web::delete().to(reports_web::delete_rule),
+      )
+      .route(
+        "/reports/rules/{rule_id}/move",
+        web::post().to(reports_web::move_rule),
+      )
       .route(
         "/reports/{id}",
         web::get().to(reports_web::report_details_page),
@@ -688,6 +879,14 @@ pub fn configure_company_scoped_routes(cfg: &mut web::ServiceConfig, deps: &WebR
         "/reports/{id}/match/{tx_id}",
         web::delete().to(reports_web::unmatch_transaction),
       )
+      .route(
+        "/reports/{id}/category/{tx_id}",
+        web::post().to(reports_web::categorise_transaction),
+      )
+      .route(
+        "/reports/{id}/rule/{tx_id}",
+        web::delete().to(reports_web::clear_rule_outcome),
+      )
       .route(
         "/reports/{id}/receipt/{tx_id}",
         web::post().to(reports_web::upload_receipt),
@@ -696,9 +895,75 @@ pub fn configure_company_scoped_routes(cfg: &mut web::ServiceConfig, deps: &WebR
         "/reports/{id}/generate",
         web::post().to(reports_web::generate_report),
       )
+      .route(
+        "/reports/{id}/uploads/retry",
+        web::post().to(reports_web::retry_report_uploads),
+      )
+      .route(
+        "/reports/{id}/export.zip",
+        web::get().to(reports_web::export_report_archive),
+      )
+      .route(
+        "/reports/{id}/reopen",
+        web::post().to(reports_web::reopen_report),
+      )
+      .route(
+        "/reports/{id}/lock",
+        web::post().to(reports_web::lock_report),
+      )
       .route(
         "/reports/{id}",
         web::delete().to(reports_web::delete_report),
+      )
+      // Ledger
+      .app_data(web::Data::new(deps.list_accounts_use_case.clone()))
+      .app_data(web::Data::new(deps.create_account_use_case.clone()))
+      .app_data(web::Data::new(deps.post_journal_entry_use_case.clone()))
+      .app_data(web::Data::new(deps.reverse_journal_entry_use_case.clone()))
+      .app_data(web::Data::new(deps.list_journal_entries_use_case.clone()))
+      .app_data(web::Data::new(deps.get_trial_balance_use_case.clone()))
+      .app_data(web::Data::new(deps.export_saft_use_case.clone()))
+      .route("/ledger", web::get().to(ledger_web::journal_page))
+      .route(
+        "/ledger/entries/new",
+        web::get().to(ledger_web::create_entry_page),
+      )
+      .route(
+        "/ledger/entries",
+        web::post().to(ledger_web::post_entry_submit),
+      )
+      .route(
+        "/ledger/entries/{id}/reverse",
+        web::post().to(ledger_web::reverse_entry),
+      )
+      .route("/ledger/accounts", web::get().to(ledger_web::accounts_page))
+      .route(
+        "/ledger/accounts",
+        web::post().to(ledger_web::create_account_submit),
+      )
+      .route(
+        "/ledger/trial-balance",
+        web::get().to(ledger_web::trial_balance_page),
+      )
+      .route("/ledger/saft", web::get().to(ledger_web::saft_page))
+      .route(
+        "/ledger/saft/export",
+        web::get().to(ledger_web::saft_export),
+      )
+      // Financial statements
+      .app_data(web::Data::new(
+        deps.get_financial_statement_use_case.clone(),
+      ))
+      .app_data(web::Data::new(deps.export_statement_csv_use_case.clone()))
+      .app_data(web::Data::new(deps.html_pdf_converter.clone()))
+      .route("/financials", web::get().to(financials_web::statement_page))
+      .route(
+        "/financials/export.csv",
+        web::get().to(financials_web::export_csv),
+      )
+      .route(
+        "/financials/export.pdf",
+        web::get().to(financials_web::export_pdf),
       ),
   );
 }
@@ -825,8 +1090,11 @@ pub fn configure_customer_routes(
 /// - GET / - List all invoices for the active company
 /// - GET /create - Show invoice creation form
 /// - GET /{id} - Get invoice details
+/// - PUT /{id} - Update a draft invoice (header fields and line items)
+/// - GET /{id}/events - Audit log of the invoice, newest first
 /// - POST /{id}/status - Change invoice status
 /// - DELETE /{id}/archive - Archive an invoice
+#[allow(clippy::too_many_arguments)]
 pub fn configure_invoice_routes(
   cfg: &mut web::ServiceConfig,
   create_use_case: Arc<CreateInvoiceUseCase>,
@@ -835,6 +1103,8 @@ pub fn configure_invoice_routes(
   change_status_use_case: Arc<ChangeInvoiceStatusUseCase>,
   archive_use_case: Arc<ArchiveInvoiceUseCase>,
   list_customers_use_case: Arc<ListCustomersUseCase>,
+  update_use_case: Arc<UpdateInvoiceUseCase>,
+  list_events_use_case: Arc<ListInvoiceEventsUseCase>,
 ) {
   cfg
     .app_data(web::Data::new(create_use_case))
@@ -843,10 +1113,17 @@ pub fn configure_invoice_routes(
     .app_data(web::Data::new(change_status_use_case))
     .app_data(web::Data::new(archive_use_case))
     .app_data(web::Data::new(list_customers_use_case))
+    .app_data(web::Data::new(update_use_case))
+    .app_data(web::Data::new(list_events_use_case))
     .route("", web::get().to(invoices_web::invoices_page))
     .route("", web::post().to(invoices_web::create_invoice_submit))
     .route("/create", web::get().to(invoices_web::invoice_create_page))
     .route("/{id}", web::get().to(invoices_web::invoice_details_page))
+    .route("/{id}", web::put().to(invoices_web::update_invoice_api))
+    .route(
+      "/{id}/events",
+      web::get().to(invoices_web::list_invoice_events_api),
+    )
     .route(
       "/{id}/status",
       web::post().to(invoices_web::change_invoice_status),
@@ -1,8 +1,21 @@
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use chrono::NaiveDate;
+use regex::Regex;
 use rust_decimal::Decimal;
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 use tera::{Tera, Value, to_value, try_get_value};
 
+use crate::application::invoice::InvoiceDetailsResponse;
+use crate::domain::invoice::{Currency, InvoiceLanguage};
+
+/// Upper bound for `range()` calls in company-provided invoice templates
+const SANDBOX_MAX_RANGE: usize = 1000;
+
+/// Elements removed, with their content, from company-provided templates
+const SANDBOX_BLOCKED_ELEMENTS: &[&str] = &["script", "iframe", "object", "embed"];
+
 /// Template engine wrapper for rendering HTML templates
 #[derive(Clone)]
 pub struct TemplateEngine {
@@ -49,6 +62,159 @@ fn format_money(value: &Value, _args: &HashMap<String, Value>) -> tera::Result<V
   Ok(to_value(result)?)
 }
 
+/// Resolve the `lang` filter argument, defaulting to English
+fn language_arg(filter: &str, args: &HashMap<String, Value>) -> tera::Result<InvoiceLanguage> {
+  match args.get("lang") {
+    Some(val) => {
+      let code = try_get_value!(filter, "lang", String, val);
+      InvoiceLanguage::from_str(&code).map_err(|e| tera::Error::msg(e.to_string()))
+    }
+    None => Ok(InvoiceLanguage::default()),
+  }
+}
+
+/// Parse a filter value that is either a decimal string or a JSON number
+fn decimal_value(filter: &str, value: &Value) -> tera::Result<Decimal> {
+  let decimal_str = match value {
+    Value::Number(n) => n.to_string(),
+    _ => try_get_value!(filter, "value", String, value),
+  };
+  decimal_str
+    .parse()
+    .map_err(|e| tera::Error::msg(format!("Invalid decimal value: {}", e)))
+}
+
+/// Custom Tera filter to format a number with the separators of `lang`.
+/// Without `decimals` the value keeps its own significant decimal places.
+fn format_number(value: &Value, args: &HashMap<String, Value>) -> tera::Result<Value> {
+  let decimal = decimal_value("format_number", value)?;
+  let language = language_arg("format_number", args)?;
+  let decimals = match args.get("decimals") {
+    Some(val) => try_get_value!("format_number", "decimals", u32, val),
+    None => decimal.normalize().scale(),
+  };
+
+  Ok(to_value(language.format_number(decimal, decimals))?)
+}
+
+/// Custom Tera filter to format an amount in `currency` for `lang`,
+/// placing the currency symbol as the locale expects
+fn format_currency(value: &Value, args: &HashMap<String, Value>) -> tera::Result<Value> {
+  let decimal = decimal_value("format_currency", value)?;
+  let language = language_arg("format_currency", args)?;
+  let currency = match args.get("currency") {
+    Some(val) => {
+      let code = try_get_value!("format_currency", "currency", String, val);
+      Currency::from_str(&code).map_err(|e| tera::Error::msg(e.to_string()))?
+    }
+    None => {
+      return Err(tera::Error::msg(
+        "Filter `format_currency` requires `currency`",
+      ));
+    }
+  };
+
+  Ok(to_value(language.format_money(decimal, currency))?)
+}
+
+/// Custom Tera filter to format an ISO date (YYYY-MM-DD) for `lang`
+fn format_date(value: &Value, args: &HashMap<String, Value>) -> tera::Result<Value> {
+  let date_str = try_get_value!("format_date", "value", String, value);
+  let date = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
+    .map_err(|e| tera::Error::msg(format!("Invalid date value: {}", e)))?;
+  let language = language_arg("format_date", args)?;
+
+  Ok(to_value(language.format_date(date))?)
+}
+
+/// Disabled Tera function for sandboxed templates (e.g. `get_env`)
+fn disabled_function(_args: &HashMap<String, Value>) -> tera::Result<Value> {
+  Err(tera::Error::msg(
+    "This function is not available in invoice templates",
+  ))
+}
+
+/// `range()` replacement for sandboxed templates that caps the number of items
+fn bounded_range(args: &HashMap<String, Value>) -> tera::Result<Value> {
+  let arg = |name: &str, default: Option<usize>| -> tera::Result<usize> {
+    match args.get(name) {
+      Some(val) => tera::from_value::<usize>(val.clone())
+        .map_err(|_| tera::Error::msg(format!("Function `range` received invalid `{}`", name))),
+      None => {
+        default.ok_or_else(|| tera::Error::msg(format!("Function `range` requires `{}`", name)))
+      }
+    }
+  };
+
+  let start = arg("start", Some(0))?;
+  let end = arg("end", None)?;
+  let step_by = arg("step_by", Some(1))?.max(1);
+
+  if end.saturating_sub(start) / step_by > SANDBOX_MAX_RANGE {
+    return Err(tera::Error::msg(format!(
+      "Function `range` is limited to {} items in invoice templates",
+      SANDBOX_MAX_RANGE
+    )));
+  }
+
+  Ok(to_value((start..end).step_by(step_by).collect::<Vec<_>>())?)
+}
+
+/// Read the branding logo and embed it as a data URI, since wkhtmltopdf
+/// renders the invoice without access to authenticated routes
+/// Remove the blocked elements and `on*` event handler attributes from HTML,
+/// repeating until nothing changes so removals can't join up into new tags
+fn strip_active_content(html: &str) -> String {
+  let names = SANDBOX_BLOCKED_ELEMENTS.join("|");
+  let elements: Vec<Regex> = SANDBOX_BLOCKED_ELEMENTS
+    .iter()
+    .map(|name| Regex::new(&format!(r"(?is)<\s*{0}\b.*?<\s*/\s*{0}\s*>", name)).unwrap())
+    .collect();
+  let stray_tags = Regex::new(&format!(r"(?i)<\s*/?\s*({})\b[^>]*>", names)).unwrap();
+  let handlers =
+    Regex::new(r#"(?i)(<[^>]*?)[\s/]+on[a-z]+\s*=\s*("[^"]*"|'[^']*'|[^\s>]+)"#).unwrap();
+
+  let mut html = html.to_string();
+  loop {
+    let mut stripped = html.clone();
+    for element in &elements {
+      stripped = element.replace_all(&stripped, "").into_owned();
+    }
+    stripped = stray_tags.replace_all(&stripped, "").into_owned();
+    stripped = handlers.replace_all(&stripped, "$1").into_owned();
+    if stripped == html {
+      return html;
+    }
+    html = stripped;
+  }
+}
+
+fn logo_data_uri(logo_path: &str) -> Option<String> {
+  let mime = match logo_path.rsplit('.').next()?.to_lowercase().as_str() {
+    "png" => "image/png",
+    "jpg" | "jpeg" => "image/jpeg",
+    "gif" => "image/gif",
+    _ => return None,
+  };
+
+  match std::fs::read(logo_path) {
+    Ok(bytes) => Some(format!("data:{};base64,{}", mime, BASE64.encode(bytes))),
+    Err(e) => {
+      tracing::warn!("Failed to read invoice logo {}: {}", logo_path, e);
+      None
+    }
+  }
+}
+
+fn register_custom_filters(tera: &mut Tera) {
+  tera.register_filter("format_money", format_money);
+  tera.register_filter("zero_pad", zero_pad);
+  tera.register_filter("abs", abs_filter);
+  tera.register_filter("format_number", format_number);
+  tera.register_filter("format_currency", format_currency);
+  tera.register_filter("format_date", format_date);
+}
+
 impl TemplateEngine {
   /// Create a new template engine instance
   pub fn new() -> Result<Self, tera::Error> {
@@ -56,9 +222,7 @@ impl TemplateEngine {
     tera.autoescape_on(vec!["html.tera", ".html"]);
 
     // Register custom filters
-    tera.register_filter("format_money", format_money);
-    tera.register_filter("zero_pad", zero_pad);
-    tera.register_filter("abs", abs_filter);
+    register_custom_filters(&mut tera);
 
     Ok(Self {
       tera: Arc::new(tera),
@@ -70,6 +234,45 @@ impl TemplateEngine {
     self.tera.render(template, context)
   }
 
+  /// Render the invoice document (HTML used for the PDF), using the company's
+  /// custom template when one is configured
+  pub fn render_invoice_document(
+    &self,
+    invoice: &InvoiceDetailsResponse,
+  ) -> Result<String, tera::Error> {
+    let mut context = tera::Context::new();
+    context.insert("invoice", invoice);
+    context.insert(
+      "logo_data_uri",
+      &invoice
+        .branding
+        .logo_path
+        .as_deref()
+        .and_then(logo_data_uri),
+    );
+
+    match &invoice.branding.custom_template {
+      Some(source) => Self::render_sandboxed(source, &context),
+      None => self.render("partials/invoice_pdf.html.tera", &context),
+    }
+  }
+
+  /// Render company-provided template source in an isolated Tera instance.
+  /// Only the given context is reachable: no other templates, no environment
+  /// variables, output is autoescaped and loops over `range()` are bounded.
+  /// Scripts, frames, plugins and event handlers are stripped from the output.
+  pub fn render_sandboxed(source: &str, context: &tera::Context) -> Result<String, tera::Error> {
+    let mut tera = Tera::default();
+    tera.autoescape_on(vec![".html"]);
+    register_custom_filters(&mut tera);
+    tera.register_function("get_env", disabled_function);
+    tera.register_function("range", bounded_range);
+
+    tera.add_raw_template("custom_invoice.html", source)?;
+    let html = tera.render("custom_invoice.html", context)?;
+    Ok(strip_active_content(&html))
+  }
+
   /// Render a template with no context
   pub fn render_simple(&self, template: &str) -> Result<String, tera::Error> {
     self.tera.render(template, &tera::Context::new())
@@ -78,9 +281,85 @@ impl TemplateEngine {
 
 #[cfg(test)]
 mod tests {
+  use super::*;
+
   #[test]
   fn test_template_engine_creation() {
     // In test environment, templates might not exist
     // This test just ensures the structure compiles
   }
+
+  #[test]
+  fn test_render_sandboxed_escapes_and_formats() {
+    let mut context = tera::Context::new();
+    context.insert("name", "<b>ACME</b>");
+    context.insert("amount", "1234.5");
+
+    let html =
+      TemplateEngine::render_sandboxed("{{ name }} {{ amount | format_money }}", &context).unwrap();
+    assert_eq!(html, "&lt;b&gt;ACME&lt;&#x2F;b&gt; 1234.50");
+  }
+
+  #[test]
+  fn test_localized_filters() {
+    let mut context = tera::Context::new();
+    context.insert("amount", "1234.5");
+    context.insert("date", "2026-03-05");
+
+    let html = TemplateEngine::render_sandboxed(
+      "{{ amount | format_currency(currency=\"EUR\", lang=\"et\") }}|\
+       {{ amount | format_number(lang=\"en\", decimals=2) }}|\
+       {{ amount | format_number(lang=\"fi\") }}|\
+       {{ date | format_date(lang=\"ru\") }}",
+      &context,
+    )
+    .unwrap();
+    assert_eq!(
+      html,
+      "1\u{a0}234,50\u{a0}€|1,234.50|1\u{a0}234,5|05.03.2026"
+    );
+  }
+
+  #[test]
+  fn test_render_sandboxed_strips_active_content() {
+    let context = tera::Context::new();
+    let html = TemplateEngine::render_sandboxed(
+      "<h1 class=\"t\" onclick=\"steal()\">Invoice</h1>\
+       <SCRIPT src=x>fetch('/invoices')</script >\
+       <iframe src=\"http://127.0.0.1/invoices/1/html\"></iframe>\
+       <object data=\"file:///etc/passwd\">\
+       <img/src=x/onerror=alert(1)>\
+       <scr<script></script>ipt>alert(1)</script>",
+      &context,
+    )
+    .unwrap();
+
+    assert_eq!(html, "<h1 class=\"t\">Invoice</h1><img/src=x>alert(1)");
+  }
+
+  #[test]
+  fn test_render_sandboxed_blocks_env_and_includes() {
+    let context = tera::Context::new();
+    assert!(TemplateEngine::render_sandboxed("{{ get_env(name=\"HOME\") }}", &context).is_err());
+    assert!(
+      TemplateEngine::render_sandboxed("{% include \"layouts/base.html.tera\" %}", &context)
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn test_render_sandboxed_bounds_range() {
+    let context = tera::Context::new();
+    assert!(
+      TemplateEngine::render_sandboxed("{% for i in range(end=10) %}{{ i }}{% endfor %}", &context)
+        .is_ok()
+    );
+    assert!(
+      TemplateEngine::render_sandboxed(
+        "{% for i in range(end=100000000) %}{{ i }}{% endfor %}",
+        &context
+      )
+      .is_err()
+    );
+  }
 }
@@ -35,6 +35,7 @@ pub struct GetCompanyDetailsResponse {
   pub oauth_refresh_token: bool,
   pub oauth_connected_at: Option<DateTime<Utc>>,
   pub google_drive_folder_id: Option<String>,
+  pub reports_folder_id: Option<String>,
   pub role: String,
   pub can_edit: bool,
   pub created_at: DateTime<Utc>,
@@ -104,6 +105,7 @@ impl GetCompanyDetailsUseCase {
       oauth_refresh_token: company.oauth_refresh_token.is_some(),
       oauth_connected_at: company.oauth_connected_at,
       google_drive_folder_id: company.google_drive_folder_id,
+      reports_folder_id: company.reports_folder_id,
       role,
       can_edit,
       created_at: company.created_at,
@@ -0,0 +1,110 @@
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::company::{CompanyError, CompanyMemberRepository, CompanyRepository};
+use crate::infrastructure::cloud::{CloudStorageFactory, DriveFolder};
+use crate::infrastructure::config::Config;
+use crate::infrastructure::security::AesTokenEncryption;
+
+/// Command to list the folders to pick an upload folder from
+pub struct ListDriveFoldersCommand {
+  pub company_id: Uuid,
+  pub user_id: Uuid,
+  /// Folder or shared drive to list; My Drive when `None`
+  pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ListDriveFoldersResponse {
+  pub parent_id: Option<String>,
+  pub folders: Vec<DriveFolder>,
+  /// Shared drives, listed next to the folders of My Drive
+  pub shared_drives: Vec<DriveFolder>,
+  /// Why Drive couldn't be listed
+  pub error: Option<String>,
+}
+
+/// Use case for browsing the connected Google Drive, so owners and admins
+/// pick upload folders instead of pasting their IDs
+pub struct ListDriveFoldersUseCase {
+  company_repo: Arc<dyn CompanyRepository>,
+  member_repo: Arc<dyn CompanyMemberRepository>,
+  token_encryption: Arc<AesTokenEncryption>,
+  config: Arc<Config>,
+}
+
+impl ListDriveFoldersUseCase {
+  pub fn new(
+    company_repo: Arc<dyn CompanyRepository>,
+    member_repo: Arc<dyn CompanyMemberRepository>,
+    token_encryption: Arc<AesTokenEncryption>,
+    config: Arc<Config>,
+  ) -> Self {
+    Self {
+      company_repo,
+      member_repo,
+      token_encryption,
+      config,
+    }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListDriveFoldersCommand,
+  ) -> Result<ListDriveFoldersResponse, CompanyError> {
+    let member = self
+      .member_repo
+      .find_member(command.company_id, command.user_id)
+      .await?
+      .ok_or(CompanyError::NotMember)?;
+    if !member.can_manage_members() {
+      return Err(CompanyError::InsufficientPermissions);
+    }
+
+    let company = self
+      .company_repo
+      .find_by_id(command.company_id)
+      .await?
+      .ok_or(CompanyError::NotFound)?;
+
+    let mut response = ListDriveFoldersResponse {
+      parent_id: command.parent_id.clone(),
+      ..Default::default()
+    };
+    let drive_config = self.config.google_drive.as_ref();
+    let Some(api) = CloudStorageFactory::connected_drive_api(
+      &company,
+      &self.token_encryption,
+      drive_config.and_then(|c| c.oauth_client_id.as_deref()),
+      drive_config.and_then(|c| c.oauth_client_secret.as_deref()),
+    ) else {
+      response.error = Some("Google Drive is not connected".to_string());
+      return Ok(response);
+    };
+
+    let listed = match command.parent_id.as_deref() {
+      Some(parent_id) => api.folders(Some(parent_id)).await,
+      None => match api.shared_drives().await {
+        Ok(drives) => {
+          response.shared_drives = drives;
+          api.folders(None).await
+        }
+        Err(e) => Err(e),
+      },
+    };
+    match listed {
+      Ok(folders) => response.folders = folders,
+      Err(e) => {
+        tracing::warn!(
+          "Listing Drive folders of company {} failed: {}",
+          command.company_id,
+          e
+        );
+        response.error = Some("Could not list Google Drive folders".to_string());
+      }
+    }
+
+    Ok(response)
+  }
+}
@@ -7,6 +7,7 @@ pub mod disconnect_google_drive;
 pub mod get_bank_accounts;
 pub mod get_company_details;
 pub mod get_user_companies;
+pub mod list_drive_folders;
 pub mod remove_company_member;
 pub mod set_active_bank_account;
 pub mod set_active_company;
@@ -34,6 +35,9 @@ pub use get_company_details::{
 pub use get_user_companies::{
   CompanyListItem, GetUserCompaniesCommand, GetUserCompaniesResponse, GetUserCompaniesUseCase,
 };
+pub use list_drive_folders::{
+  ListDriveFoldersCommand, ListDriveFoldersResponse, ListDriveFoldersUseCase,
+};
 pub use remove_company_member::{RemoveCompanyMemberCommand, RemoveCompanyMemberUseCase};
 pub use set_active_bank_account::{SetActiveBankAccountCommand, SetActiveBankAccountUseCase};
 pub use set_active_company::{SetActiveCompanyCommand, SetActiveCompanyUseCase};
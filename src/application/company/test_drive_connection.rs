@@ -1,7 +1,9 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::domain::company::{CompanyError, CompanyRepository};
+use crate::domain::company::{CompanyError, CompanyRepository, StorageProvider};
+use crate::infrastructure::cloud::{CloudStorageFactory, WebDavStorageAdapter};
+use crate::infrastructure::security::AesTokenEncryption;
 
 /// Command to test Drive connection
 pub struct TestDriveConnectionCommand {
@@ -14,14 +16,21 @@ pub struct TestDriveConnectionResponse {
   pub message: String,
 }
 
-/// Use case for testing Google Drive connection
+/// Use case for testing the Google Drive or WebDAV connection
 pub struct TestDriveConnectionUseCase {
   company_repo: Arc<dyn CompanyRepository>,
+  token_encryption: Arc<AesTokenEncryption>,
 }
 
 impl TestDriveConnectionUseCase {
-  pub fn new(company_repo: Arc<dyn CompanyRepository>) -> Self {
-    Self { company_repo }
+  pub fn new(
+    company_repo: Arc<dyn CompanyRepository>,
+    token_encryption: Arc<AesTokenEncryption>,
+  ) -> Self {
+    Self {
+      company_repo,
+      token_encryption,
+    }
   }
 
   /// Test the OAuth connection
@@ -35,6 +44,15 @@ impl TestDriveConnectionUseCase {
       .await?
       .ok_or(CompanyError::NotFound)?;
 
+    let provider = company
+      .storage_provider
+      .as_ref()
+      .and_then(|p| p.parse::<StorageProvider>().ok())
+      .unwrap_or_default();
+    if provider == StorageProvider::WebDav {
+      return Ok(self.test_webdav(company.storage_config.as_ref()).await);
+    }
+
     // Check if OAuth is connected
     if !company.has_oauth_connection() {
       return Ok(TestDriveConnectionResponse {
@@ -58,4 +76,26 @@ impl TestDriveConnectionUseCase {
       message: "Connection is active and tokens are valid".to_string(),
     })
   }
+
+  /// Sign in to the WebDAV server with the stored app password
+  async fn test_webdav(&self, config_json: Option<&String>) -> TestDriveConnectionResponse {
+    let Some(settings) = CloudStorageFactory::webdav_settings(config_json, &self.token_encryption)
+    else {
+      return TestDriveConnectionResponse {
+        success: false,
+        message: "WebDAV is not configured".to_string(),
+      };
+    };
+
+    match WebDavStorageAdapter::new(settings).test_connection().await {
+      Ok(()) => TestDriveConnectionResponse {
+        success: true,
+        message: "Connected to the WebDAV server".to_string(),
+      },
+      Err(e) => TestDriveConnectionResponse {
+        success: false,
+        message: e,
+      },
+    }
+  }
 }
@@ -2,7 +2,10 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::domain::company::{CompanyError, CompanyService, StorageProvider};
+use crate::domain::company::{
+  CompanyError, CompanyService, LocalConfig, StorageConfig, StorageProvider, ValidationError,
+};
+use crate::infrastructure::security::AesTokenEncryption;
 
 #[derive(Debug, Deserialize)]
 pub struct UpdateStorageConfigCommand {
@@ -11,6 +14,8 @@ pub struct UpdateStorageConfigCommand {
   pub storage_provider: String,
   pub storage_config_json: Option<String>,
   pub google_drive_folder_id: Option<String>,
+  /// Folder monthly report folders are created in; kept when `None`
+  pub reports_folder_id: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -22,11 +27,18 @@ pub struct UpdateStorageConfigResponse {
 
 pub struct UpdateStorageConfigUseCase {
   company_service: Arc<CompanyService>,
+  token_encryption: Arc<AesTokenEncryption>,
 }
 
 impl UpdateStorageConfigUseCase {
-  pub fn new(company_service: Arc<CompanyService>) -> Self {
-    Self { company_service }
+  pub fn new(
+    company_service: Arc<CompanyService>,
+    token_encryption: Arc<AesTokenEncryption>,
+  ) -> Self {
+    Self {
+      company_service,
+      token_encryption,
+    }
   }
 
   pub async fn execute(
@@ -41,8 +53,17 @@ impl UpdateStorageConfigUseCase {
         CompanyError::Validation(crate::domain::company::ValidationError::InvalidFormat(e))
       })?;
 
+    // S3 and WebDAV credentials are stored encrypted
+    let storage_config_json = match provider {
+      StorageProvider::S3 | StorageProvider::WebDav => {
+        Some(self.secure_config(&provider, command.storage_config_json.as_deref())?)
+      }
+      StorageProvider::Local => Some(Self::local_config(command.storage_config_json.as_deref())?),
+      StorageProvider::None | StorageProvider::GoogleDrive => command.storage_config_json,
+    };
+
     // Validate storage config JSON if provided
-    if let Some(ref config_json) = command.storage_config_json {
+    if let Some(ref config_json) = storage_config_json {
       // Try to parse as JSON to validate format
       serde_json::from_str::<serde_json::Value>(config_json).map_err(|e| {
         CompanyError::Validation(crate::domain::company::ValidationError::InvalidFormat(
@@ -58,8 +79,9 @@ impl UpdateStorageConfigUseCase {
         command.company_id,
         command.user_id,
         Some(provider.as_str().to_string()),
-        command.storage_config_json,
+        storage_config_json,
         command.google_drive_folder_id,
+        command.reports_folder_id,
       )
       .await?;
 
@@ -71,4 +93,88 @@ impl UpdateStorageConfigUseCase {
       message: "Storage configuration updated successfully".to_string(),
     })
   }
+
+  /// Validate the configuration of a provider signed in to with a secret
+  /// (S3, WebDAV) and encrypt the secret
+  fn secure_config(
+    &self,
+    provider: &StorageProvider,
+    config_json: Option<&str>,
+  ) -> Result<String, CompanyError> {
+    let invalid = |msg: String| CompanyError::Validation(ValidationError::InvalidFormat(msg));
+    let blank = |values: &[&String]| values.iter().any(|value| value.trim().is_empty());
+
+    let config = match config_json.map(serde_json::from_str::<StorageConfig>) {
+      Some(Ok(config)) => config,
+      Some(Err(e)) => return Err(invalid(format!("Invalid storage configuration: {}", e))),
+      None => return Err(invalid("Storage configuration is required".to_string())),
+    };
+
+    let config = match (provider, config) {
+      (StorageProvider::S3, StorageConfig::S3(mut config)) => {
+        if blank(&[
+          &config.bucket,
+          &config.region,
+          &config.access_key_id,
+          &config.secret_access_key,
+        ]) {
+          return Err(invalid(
+            "S3 bucket, region, and credentials are required".to_string(),
+          ));
+        }
+        config.secret_access_key = self.encrypt(&config.secret_access_key)?;
+        StorageConfig::S3(config)
+      }
+      (StorageProvider::WebDav, StorageConfig::WebDav(mut config)) => {
+        if blank(&[&config.url, &config.username, &config.app_password]) {
+          return Err(invalid(
+            "WebDAV URL, username, and app password are required".to_string(),
+          ));
+        }
+        config.app_password = self.encrypt(&config.app_password)?;
+        StorageConfig::WebDav(config)
+      }
+      _ => {
+        return Err(invalid(format!(
+          "Storage configuration doesn't match provider {}",
+          provider.as_str()
+        )));
+      }
+    };
+
+    serde_json::to_string(&config)
+      .map_err(|e| invalid(format!("Invalid storage configuration: {}", e)))
+  }
+
+  /// Validate the directory of the local storage provider
+  fn local_config(config_json: Option<&str>) -> Result<String, CompanyError> {
+    let invalid = |msg: String| CompanyError::Validation(ValidationError::InvalidFormat(msg));
+
+    let config = match config_json.map(serde_json::from_str::<StorageConfig>) {
+      Some(Ok(StorageConfig::Local(config))) => config,
+      None => LocalConfig::default(),
+      Some(Ok(_)) => {
+        return Err(invalid(
+          "Storage configuration doesn't match provider local".to_string(),
+        ));
+      }
+      Some(Err(e)) => return Err(invalid(format!("Invalid storage configuration: {}", e))),
+    };
+    if !config.is_valid_directory() {
+      return Err(invalid(
+        "Local storage directory must be a relative path without '.' or '..' parts".to_string(),
+      ));
+    }
+
+    serde_json::to_string(&StorageConfig::Local(config))
+      .map_err(|e| invalid(format!("Invalid storage configuration: {}", e)))
+  }
+
+  fn encrypt(&self, secret: &str) -> Result<String, CompanyError> {
+    self.token_encryption.encrypt(secret).map_err(|e| {
+      CompanyError::Repository(crate::domain::auth::errors::RepositoryError::QueryFailed(
+        format!("Secret encryption failed: {}", e),
+      ))
+    })
+  }
 }
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::domain::document::{DocumentError, DocumentService, IntegrityReport};
+
+/// Re-hash all stored documents, flagging missing and corrupted files
+pub struct CheckDocumentIntegrityUseCase {
+  document_service: Arc<DocumentService>,
+}
+
+impl CheckDocumentIntegrityUseCase {
+  pub fn new(document_service: Arc<DocumentService>) -> Self {
+    Self { document_service }
+  }
+
+  pub async fn execute(&self) -> Result<IntegrityReport, DocumentError> {
+    let report = self.document_service.check_integrity().await?;
+
+    if report.missing > 0 || report.corrupted > 0 {
+      tracing::warn!(
+        "Document integrity check: {} checked, {} missing, {} corrupted",
+        report.checked,
+        report.missing,
+        report.corrupted
+      );
+    } else {
+      tracing::info!(
+        "Document integrity check: {} documents intact",
+        report.checked
+      );
+    }
+
+    Ok(report)
+  }
+}
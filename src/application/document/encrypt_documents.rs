@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::ports::CompanyRepository;
+use crate::domain::document::{DocumentError, DocumentService, EncryptionReport};
+use crate::domain::invoice::ports::InvoiceRepository;
+use crate::domain::report::ports::{
+  BankTransactionRepository, MonthlyReportRepository, ReceivedInvoiceRepository,
+};
+
+/// Encrypt the files stored before encryption at rest was turned on: the
+/// documents in the store and the invoice PDFs and receipts written before
+/// the document store existed. Files already encrypted are skipped, so it
+/// can be run again.
+pub struct EncryptDocumentsUseCase {
+  document_service: Arc<DocumentService>,
+  company_repo: Arc<dyn CompanyRepository>,
+  invoice_repo: Arc<dyn InvoiceRepository>,
+  received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
+  monthly_report_repo: Arc<dyn MonthlyReportRepository>,
+  bank_transaction_repo: Arc<dyn BankTransactionRepository>,
+}
+
+impl EncryptDocumentsUseCase {
+  pub fn new(
+    document_service: Arc<DocumentService>,
+    company_repo: Arc<dyn CompanyRepository>,
+    invoice_repo: Arc<dyn InvoiceRepository>,
+    received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
+    monthly_report_repo: Arc<dyn MonthlyReportRepository>,
+    bank_transaction_repo: Arc<dyn BankTransactionRepository>,
+  ) -> Self {
+    Self {
+      document_service,
+      company_repo,
+      invoice_repo,
+      received_invoice_repo,
+      monthly_report_repo,
+      bank_transaction_repo,
+    }
+  }
+
+  pub async fn execute(&self) -> Result<EncryptionReport, DocumentError> {
+    let mut report = self.document_service.encrypt_stored().await?;
+
+    for company in self.company_repo.find_all().await.map_err(query_failed)? {
+      let mut paths = Vec::new();
+      for invoice in self
+        .invoice_repo
+        .find_by_company_id(company.id)
+        .await
+        .map_err(query_failed)?
+      {
+        paths.extend(invoice.pdf_path);
+      }
+      for invoice in self
+        .received_invoice_repo
+        .find_by_company_id(company.id)
+        .await
+        .map_err(query_failed)?
+      {
+        paths.push(invoice.pdf_path);
+      }
+      for monthly_report in self
+        .monthly_report_repo
+        .find_by_company_id(company.id)
+        .await
+        .map_err(query_failed)?
+      {
+        for transaction in self
+          .bank_transaction_repo
+          .find_by_report_id(monthly_report.id)
+          .await
+          .map_err(query_failed)?
+        {
+          paths.extend(transaction.receipt_path);
+        }
+      }
+
+      // Paths into the document store were sealed above and are skipped
+      for path in paths {
+        if self
+          .document_service
+          .encrypt_file(company.id, &path)
+          .await?
+        {
+          report.files += 1;
+        }
+      }
+    }
+
+    tracing::info!(
+      "Encrypted {} stored documents and {} other files; {} documents were encrypted already",
+      report.documents,
+      report.files,
+      report.skipped
+    );
+    Ok(report)
+  }
+}
+
+fn query_failed(error: impl std::fmt::Display) -> DocumentError {
+  DocumentError::Repository(RepositoryError::QueryFailed(error.to_string()))
+}
@@ -0,0 +1,210 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::ports::CompanyRepository;
+use crate::domain::document::{
+  DocumentError, DocumentOwner, DocumentService, ImportReport, NewDocument, mime_type_for,
+};
+use crate::domain::invoice::ports::InvoiceRepository;
+use crate::domain::report::ports::{
+  BankTransactionRepository, MonthlyReportRepository, ReceivedInvoiceRepository,
+};
+
+/// Store the invoice PDFs, bills and receipts written before the document
+/// store existed as documents of their owners, and point the owners at the
+/// stored copies. The files are copied, not moved: uploads queued earlier
+/// may still read them. Owners already linked are skipped, so it can be run
+/// again.
+pub struct ImportLegacyFilesUseCase {
+  document_service: Arc<DocumentService>,
+  company_repo: Arc<dyn CompanyRepository>,
+  invoice_repo: Arc<dyn InvoiceRepository>,
+  received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
+  monthly_report_repo: Arc<dyn MonthlyReportRepository>,
+  bank_transaction_repo: Arc<dyn BankTransactionRepository>,
+}
+
+impl ImportLegacyFilesUseCase {
+  pub fn new(
+    document_service: Arc<DocumentService>,
+    company_repo: Arc<dyn CompanyRepository>,
+    invoice_repo: Arc<dyn InvoiceRepository>,
+    received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
+    monthly_report_repo: Arc<dyn MonthlyReportRepository>,
+    bank_transaction_repo: Arc<dyn BankTransactionRepository>,
+  ) -> Self {
+    Self {
+      document_service,
+      company_repo,
+      invoice_repo,
+      received_invoice_repo,
+      monthly_report_repo,
+      bank_transaction_repo,
+    }
+  }
+
+  pub async fn execute(&self) -> Result<ImportReport, DocumentError> {
+    let mut report = ImportReport::default();
+
+    for company in self.company_repo.find_all().await.map_err(query_failed)? {
+      for mut invoice in self
+        .invoice_repo
+        .find_by_company_id(company.id)
+        .await
+        .map_err(query_failed)?
+      {
+        let Some(path) = invoice.pdf_path.clone() else {
+          continue;
+        };
+        if let Some(stored) = self
+          .import(
+            &mut report,
+            company.id,
+            &path,
+            DocumentOwner::InvoicePdf,
+            invoice.id,
+          )
+          .await?
+        {
+          invoice.set_pdf_path(stored);
+          self
+            .invoice_repo
+            .update(invoice)
+            .await
+            .map_err(query_failed)?;
+        }
+      }
+
+      for mut invoice in self
+        .received_invoice_repo
+        .find_by_company_id(company.id)
+        .await
+        .map_err(query_failed)?
+      {
+        let path = invoice.pdf_path.clone();
+        if let Some(stored) = self
+          .import(
+            &mut report,
+            company.id,
+            &path,
+            DocumentOwner::ReceivedInvoice,
+            invoice.id,
+          )
+          .await?
+        {
+          invoice.pdf_path = stored;
+          self
+            .received_invoice_repo
+            .update(invoice)
+            .await
+            .map_err(query_failed)?;
+        }
+      }
+
+      for monthly_report in self
+        .monthly_report_repo
+        .find_by_company_id(company.id)
+        .await
+        .map_err(query_failed)?
+      {
+        for transaction in self
+          .bank_transaction_repo
+          .find_by_report_id(monthly_report.id)
+          .await
+          .map_err(query_failed)?
+        {
+          let Some(path) = transaction.receipt_path else {
+            continue;
+          };
+          if let Some(stored) = self
+            .import(
+              &mut report,
+              company.id,
+              &path,
+              DocumentOwner::TransactionReceipt,
+              transaction.id,
+            )
+            .await?
+          {
+            self
+              .bank_transaction_repo
+              .update_receipt_path(transaction.id, Some(stored))
+              .await
+              .map_err(query_failed)?;
+          }
+        }
+      }
+    }
+
+    tracing::info!(
+      "Imported {} files into the document store; {} files were missing",
+      report.imported,
+      report.missing
+    );
+    Ok(report)
+  }
+
+  /// Store the owner's file unless the owner has a document already.
+  /// Returns the path of the stored copy.
+  async fn import(
+    &self,
+    report: &mut ImportReport,
+    company_id: Uuid,
+    path: &str,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+  ) -> Result<Option<String>, DocumentError> {
+    if self
+      .document_service
+      .document_of(owner, owner_id)
+      .await?
+      .is_some()
+    {
+      return Ok(None);
+    }
+    if !tokio::fs::try_exists(path).await.unwrap_or(false) {
+      tracing::warn!(
+        "File {} of {} {} is missing",
+        path,
+        owner.as_str(),
+        owner_id
+      );
+      report.missing += 1;
+      return Ok(None);
+    }
+
+    // Files encrypted in place earlier are read in plain form
+    let content = self.document_service.read_file(path).await?;
+    if content.is_empty() {
+      tracing::warn!("File {} of {} {} is empty", path, owner.as_str(), owner_id);
+      report.missing += 1;
+      return Ok(None);
+    }
+    let original_name = std::path::Path::new(path)
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .unwrap_or_default();
+    let document = self
+      .document_service
+      .store(
+        NewDocument {
+          company_id,
+          content,
+          mime_type: mime_type_for(&original_name).to_string(),
+          original_name,
+          uploaded_by: None,
+          keep_replaced_until: None,
+        },
+        owner,
+        owner_id,
+      )
+      .await?;
+    report.imported += 1;
+    Ok(Some(self.document_service.path(&document)))
+  }
+}
+
+fn query_failed(error: impl std::fmt::Display) -> DocumentError {
+  DocumentError::Repository(RepositoryError::QueryFailed(error.to_string()))
+}
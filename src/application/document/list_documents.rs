@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::document::{Document, DocumentError, DocumentLink, DocumentService};
+
+#[derive(Debug)]
+pub struct ListDocumentsCommand {
+  pub company_id: Uuid,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DocumentLinkDto {
+  pub owner: String,
+  pub owner_label: String,
+  pub owner_id: Uuid,
+}
+
+impl From<DocumentLink> for DocumentLinkDto {
+  fn from(link: DocumentLink) -> Self {
+    Self {
+      owner: link.owner.as_str().to_string(),
+      owner_label: link.owner.label().to_string(),
+      owner_id: link.owner_id,
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DocumentDto {
+  pub id: Uuid,
+  pub original_name: String,
+  pub mime_type: String,
+  pub size_bytes: i64,
+  pub sha256: String,
+  pub integrity_status: String,
+  pub integrity_label: String,
+  pub checked_at: Option<DateTime<Utc>>,
+  pub uploaded_by: Option<Uuid>,
+  pub created_at: DateTime<Utc>,
+  pub links: Vec<DocumentLinkDto>,
+}
+
+impl DocumentDto {
+  fn new(document: Document, links: Vec<DocumentLink>) -> Self {
+    Self {
+      id: document.id,
+      original_name: document.original_name,
+      mime_type: document.mime_type,
+      size_bytes: document.size_bytes,
+      sha256: document.sha256,
+      integrity_status: document.integrity_status.as_str().to_string(),
+      integrity_label: document.integrity_status.label().to_string(),
+      checked_at: document.checked_at,
+      uploaded_by: document.uploaded_by,
+      created_at: document.created_at,
+      links: links.into_iter().map(DocumentLinkDto::from).collect(),
+    }
+  }
+}
+
+/// Documents of a company with what they are attached to, newest first
+pub struct ListDocumentsUseCase {
+  document_service: Arc<DocumentService>,
+}
+
+impl ListDocumentsUseCase {
+  pub fn new(document_service: Arc<DocumentService>) -> Self {
+    Self { document_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListDocumentsCommand,
+  ) -> Result<Vec<DocumentDto>, DocumentError> {
+    let documents = self.document_service.list(command.company_id).await?;
+
+    let mut dtos = Vec::with_capacity(documents.len());
+    for document in documents {
+      let links = self.document_service.links(document.id).await?;
+      dtos.push(DocumentDto::new(document, links));
+    }
+    Ok(dtos)
+  }
+}
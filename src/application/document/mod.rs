@@ -0,0 +1,11 @@
+mod check_document_integrity;
+mod encrypt_documents;
+mod import_legacy_files;
+mod list_documents;
+
+pub use check_document_integrity::CheckDocumentIntegrityUseCase;
+pub use encrypt_documents::EncryptDocumentsUseCase;
+pub use import_legacy_files::ImportLegacyFilesUseCase;
+pub use list_documents::{
+  DocumentDto, DocumentLinkDto, ListDocumentsCommand, ListDocumentsUseCase,
+};
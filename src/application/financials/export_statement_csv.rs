@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::financials::{
+  FinancialStatementService, FinancialsError, StatementCsvWriter, StatementKind, StatementPeriod,
+};
+
+#[derive(Debug)]
+pub struct ExportStatementCsvCommand {
+  pub company_id: Uuid,
+  pub kind: StatementKind,
+  pub period: StatementPeriod,
+}
+
+#[derive(Debug)]
+pub struct ExportStatementCsvResponse {
+  pub file_name: String,
+  pub content: Vec<u8>,
+}
+
+pub struct ExportStatementCsvUseCase {
+  statement_service: Arc<FinancialStatementService>,
+  csv_writer: Arc<dyn StatementCsvWriter>,
+}
+
+impl ExportStatementCsvUseCase {
+  pub fn new(
+    statement_service: Arc<FinancialStatementService>,
+    csv_writer: Arc<dyn StatementCsvWriter>,
+  ) -> Self {
+    Self {
+      statement_service,
+      csv_writer,
+    }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ExportStatementCsvCommand,
+  ) -> Result<ExportStatementCsvResponse, FinancialsError> {
+    let statement = self
+      .statement_service
+      .statement(command.company_id, command.kind, command.period)
+      .await?;
+    let content = self.csv_writer.write(&statement)?;
+
+    Ok(ExportStatementCsvResponse {
+      file_name: format!(
+        "{}_{}.csv",
+        command.kind.as_str(),
+        command.period.label().replace('.', "-")
+      ),
+      content,
+    })
+  }
+}
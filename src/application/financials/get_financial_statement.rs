@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::financials::{
+  FinancialStatement, FinancialStatementService, FinancialsError, StatementKind, StatementPeriod,
+};
+
+#[derive(Debug)]
+pub struct GetFinancialStatementCommand {
+  pub company_id: Uuid,
+  pub kind: StatementKind,
+  pub period: StatementPeriod,
+}
+
+pub struct GetFinancialStatementUseCase {
+  statement_service: Arc<FinancialStatementService>,
+}
+
+impl GetFinancialStatementUseCase {
+  pub fn new(statement_service: Arc<FinancialStatementService>) -> Self {
+    Self { statement_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: GetFinancialStatementCommand,
+  ) -> Result<FinancialStatement, FinancialsError> {
+    self
+      .statement_service
+      .statement(command.company_id, command.kind, command.period)
+      .await
+  }
+}
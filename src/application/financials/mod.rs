@@ -0,0 +1,7 @@
+mod export_statement_csv;
+mod get_financial_statement;
+
+pub use export_statement_csv::{
+  ExportStatementCsvCommand, ExportStatementCsvResponse, ExportStatementCsvUseCase,
+};
+pub use get_financial_statement::{GetFinancialStatementCommand, GetFinancialStatementUseCase};
@@ -1,19 +1,18 @@
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::company::ConnectGoogleDriveUseCase;
 use crate::application::invoice::get_invoice_details::{
   GetInvoiceDetailsCommand, GetInvoiceDetailsUseCase,
 };
-use crate::domain::company::CompanyRepository;
+use crate::application::invoice::pdf_uploads::InvoicePdfUploader;
+use crate::domain::document::{DocumentOwner, DocumentService, NewDocument};
 use crate::domain::invoice::errors::InvoiceError;
 use crate::domain::invoice::ports::PdfGenerator;
-use crate::domain::invoice::{InvoiceService, InvoiceStatus};
-use crate::infrastructure::cloud::CloudStorageFactory;
-use crate::infrastructure::config::Config;
-use crate::infrastructure::security::AesTokenEncryption;
+use crate::domain::invoice::{Invoice, InvoiceEventKind, InvoiceService, InvoiceStatus};
+use crate::domain::ledger::{JournalSource, LedgerError, LedgerService};
 
 #[derive(Debug, Deserialize)]
 pub struct ChangeInvoiceStatusCommand {
@@ -33,10 +32,9 @@ pub struct ChangeInvoiceStatusUseCase {
   invoice_service: Arc<InvoiceService>,
   pdf_generator: Arc<dyn PdfGenerator>,
   get_invoice_details: Arc<GetInvoiceDetailsUseCase>,
-  company_repo: Arc<dyn CompanyRepository>,
-  token_encryption: Arc<AesTokenEncryption>,
-  connect_google_drive: Arc<ConnectGoogleDriveUseCase>,
-  config: Arc<Config>,
+  pdf_uploader: Arc<InvoicePdfUploader>,
+  ledger_service: Arc<LedgerService>,
+  document_service: Arc<DocumentService>,
 }
 
 impl ChangeInvoiceStatusUseCase {
@@ -44,19 +42,17 @@ impl ChangeInvoiceStatusUseCase {
     invoice_service: Arc<InvoiceService>,
     pdf_generator: Arc<dyn PdfGenerator>,
     get_invoice_details: Arc<GetInvoiceDetailsUseCase>,
-    company_repo: Arc<dyn CompanyRepository>,
-    token_encryption: Arc<AesTokenEncryption>,
-    connect_google_drive: Arc<ConnectGoogleDriveUseCase>,
-    config: Arc<Config>,
+    pdf_uploader: Arc<InvoicePdfUploader>,
+    ledger_service: Arc<LedgerService>,
+    document_service: Arc<DocumentService>,
   ) -> Self {
     Self {
       invoice_service,
       pdf_generator,
       get_invoice_details,
-      company_repo,
-      token_encryption,
-      connect_google_drive,
-      config,
+      pdf_uploader,
+      ledger_service,
+      document_service,
     }
   }
 
@@ -66,8 +62,8 @@ impl ChangeInvoiceStatusUseCase {
   ) -> Result<ChangeInvoiceStatusResponse, InvoiceError> {
     let new_status = InvoiceStatus::from_str(&command.new_status)?;
 
-    // If changing to "Sent", generate PDF and upload to Drive first
-    let (pdf_path, drive_file_id) = if new_status == InvoiceStatus::Sent {
+    // If changing to "Sent", generate the PDF first
+    let pdf_path = if new_status == InvoiceStatus::Sent {
       // Get full invoice details for PDF generation
       let invoice_details = self
         .get_invoice_details
@@ -77,54 +73,14 @@ impl ChangeInvoiceStatusUseCase {
         })
         .await?;
 
-      // Generate PDF
-      let pdf_path = self
-        .pdf_generator
-        .generate_invoice_pdf(command.invoice_id, &invoice_details)
-        .await?;
-
-      // Fetch full company entity with OAuth tokens
-      let company = self
-        .company_repo
-        .find_by_id(invoice_details.company_id)
-        .await
-        .map_err(|e| {
-          InvoiceError::CloudStorageUploadFailed(format!("Failed to fetch company: {}", e))
-        })?
-        .ok_or_else(|| InvoiceError::CloudStorageUploadFailed("Company not found".to_string()))?;
-
-      // Extract OAuth credentials from config
-      let (oauth_client_id, oauth_client_secret) =
-        if let Some(ref drive_config) = self.config.google_drive {
-          (
-            drive_config.oauth_client_id.as_deref(),
-            drive_config.oauth_client_secret.as_deref(),
-          )
-        } else {
-          (None, None)
-        };
-
-      // Create cloud storage adapter with OAuth support
-      let cloud_storage = CloudStorageFactory::create_with_oauth(
-        company.storage_provider.as_ref(),
-        company.storage_config.as_ref(),
-        &company,
-        &self.token_encryption,
-        Some(&self.connect_google_drive),
-        oauth_client_id,
-        oauth_client_secret,
+      Some(
+        self
+          .pdf_generator
+          .generate_invoice_pdf(command.invoice_id, &invoice_details)
+          .await?,
       )
-      .await;
-
-      // Upload to cloud storage (use google_drive_folder_id or empty string for NoOp)
-      let folder_id = company.google_drive_folder_id.as_deref().unwrap_or("");
-      let file_id = cloud_storage
-        .upload_invoice_pdf(folder_id, &invoice_details.invoice_number, &pdf_path)
-        .await?;
-
-      (Some(pdf_path), Some(file_id))
     } else {
-      (None, None)
+      None
     };
 
     // Change invoice status
@@ -133,18 +89,102 @@ impl ChangeInvoiceStatusUseCase {
       .change_invoice_status(command.user_id, command.invoice_id, new_status)
       .await?;
 
-    // Store PDF path and Drive file ID if generated
-    if let Some(pdf_path) = &pdf_path {
+    // Store the PDF path and queue its upload; the Drive file ID is filled
+    // in once the upload goes through
+    if let Some(generated_path) = &pdf_path {
+      let pdf_path = self
+        .store_pdf(&invoice, generated_path, command.user_id)
+        .await?;
       invoice = self
         .invoice_service
-        .set_invoice_pdf_path(command.invoice_id, pdf_path.clone(), drive_file_id)
+        .set_invoice_pdf_path(
+          command.user_id,
+          command.invoice_id,
+          pdf_path.clone(),
+          invoice.pdf_drive_file_id.clone(),
+          InvoiceEventKind::PdfGenerated,
+        )
+        .await?;
+      self
+        .pdf_uploader
+        .upload(&invoice, &pdf_path, command.user_id)
         .await?;
     }
 
+    self.post_to_ledger(command.user_id, &invoice).await?;
+
     Ok(ChangeInvoiceStatusResponse {
       invoice_id: invoice.id,
       status: invoice.status.as_str().to_string(),
       pdf_path: invoice.pdf_path,
     })
   }
+
+  /// Move the generated PDF into the document store as the invoice's PDF
+  async fn store_pdf(
+    &self,
+    invoice: &Invoice,
+    generated_path: &str,
+    user_id: Uuid,
+  ) -> Result<String, InvoiceError> {
+    let document = self
+      .document_service
+      .import_file(
+        generated_path,
+        NewDocument {
+          company_id: invoice.company_id,
+          content: Vec::new(),
+          mime_type: "application/pdf".to_string(),
+          original_name: format!("{}.pdf", invoice.invoice_number),
+          uploaded_by: Some(user_id),
+          // Only a draft is sent, and a draft's PDF is not retained
+          keep_replaced_until: None,
+        },
+        DocumentOwner::InvoicePdf,
+        invoice.id,
+      )
+      .await?;
+    Ok(self.document_service.path(&document))
+  }
+
+  /// Book the status change: issuing posts the sale, payment the receipt of
+  /// the invoice total and cancellation reverses the sale. An invoice marked
+  /// paid without being sent first gets both entries.
+  async fn post_to_ledger(&self, user_id: Uuid, invoice: &Invoice) -> Result<(), InvoiceError> {
+    let to_invoice_error =
+      |e: LedgerError| InvoiceError::Internal(format!("Failed to post journal entry: {}", e));
+
+    match invoice.status {
+      InvoiceStatus::Sent | InvoiceStatus::Paid => {
+        let (_, line_items, _, _, _, totals) = self
+          .invoice_service
+          .get_invoice_with_details(user_id, invoice.id)
+          .await?;
+
+        self
+          .ledger_service
+          .record_invoice_issued(invoice, &line_items)
+          .await
+          .map_err(to_invoice_error)?;
+
+        if invoice.status == InvoiceStatus::Paid {
+          self
+            .ledger_service
+            .record_invoice_payment(invoice, totals.grand_total.amount, Utc::now().date_naive())
+            .await
+            .map_err(to_invoice_error)?;
+        }
+      }
+      InvoiceStatus::Cancelled => {
+        self
+          .ledger_service
+          .reverse_source(invoice.company_id, JournalSource::InvoiceIssued, invoice.id)
+          .await
+          .map_err(to_invoice_error)?;
+      }
+      InvoiceStatus::Draft | InvoiceStatus::Overdue => {}
+    }
+
+    Ok(())
+  }
 }
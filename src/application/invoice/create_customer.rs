@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::domain::invoice::{CustomerAddress, CustomerName, InvoiceError, InvoiceService};
+use crate::domain::invoice::{
+  CustomerAddress, CustomerName, InvoiceError, InvoiceLanguage, InvoiceService,
+};
 
 #[derive(Debug, Deserialize)]
 pub struct CreateCustomerCommand {
@@ -14,6 +17,7 @@ pub struct CreateCustomerCommand {
   pub state: Option<String>,
   pub postal_code: Option<String>,
   pub country: Option<String>,
+  pub default_language: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -36,6 +40,12 @@ impl CreateCustomerUseCase {
     command: CreateCustomerCommand,
   ) -> Result<CreateCustomerResponse, InvoiceError> {
     let name = CustomerName::new(command.name)?;
+    let default_language = command
+      .default_language
+      .as_deref()
+      .map(InvoiceLanguage::from_str)
+      .transpose()?
+      .unwrap_or_default();
 
     let address = if command.street.is_some()
       || command.city.is_some()
@@ -56,7 +66,13 @@ impl CreateCustomerUseCase {
 
     let customer = self
       .invoice_service
-      .create_customer(command.user_id, command.company_id, name, address)
+      .create_customer(
+        command.user_id,
+        command.company_id,
+        name,
+        address,
+        default_language,
+      )
       .await?;
 
     Ok(CreateCustomerResponse {
@@ -6,8 +6,8 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::invoice::{
-  Currency, InvoiceData, InvoiceError, InvoiceService, LineItemDescription, Money, PaymentTerms,
-  Quantity, VatRate,
+  Currency, InvoiceData, InvoiceError, InvoiceLanguage, InvoiceService, LineItemDescription, Money,
+  PaymentTerms, Quantity, VatRate,
 };
 
 #[derive(Debug, Deserialize)]
@@ -28,6 +28,8 @@ pub struct CreateInvoiceCommand {
   pub invoice_date: NaiveDate,
   pub payment_terms: String,
   pub currency: String,
+  /// Document language; None uses the customer's default language
+  pub language: Option<String>,
   pub line_items: Vec<CreateInvoiceLineItemDto>,
 }
 
@@ -53,6 +55,12 @@ impl CreateInvoiceUseCase {
   ) -> Result<CreateInvoiceResponse, InvoiceError> {
     let payment_terms = PaymentTerms::from_str(&command.payment_terms)?;
     let currency = Currency::from_str(&command.currency)?;
+    let language = command
+      .language
+      .as_deref()
+      .filter(|language| !language.is_empty())
+      .map(InvoiceLanguage::from_str)
+      .transpose()?;
 
     let line_items: Vec<_> = command
       .line_items
@@ -73,6 +81,7 @@ impl CreateInvoiceUseCase {
       invoice_date: command.invoice_date,
       payment_terms,
       currency,
+      language,
       line_items,
     };
 
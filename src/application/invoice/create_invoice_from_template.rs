@@ -64,6 +64,7 @@ impl CreateInvoiceFromTemplateUseCase {
       invoice_date: command.invoice_date,
       payment_terms: template.payment_terms.as_str(),
       currency: template.currency.as_str().to_string(),
+      language: None,
       line_items,
     };
 
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::invoice::{InvoiceBranding, InvoiceError, InvoiceService};
+
+#[derive(Debug, Deserialize)]
+pub struct GetInvoiceBrandingCommand {
+  pub user_id: Uuid,
+  pub company_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InvoiceBrandingDto {
+  pub logo_path: Option<String>,
+  pub accent_color: String,
+  pub font: String,
+  pub font_family: String,
+  pub default_notes: Option<String>,
+  pub footer_text: Option<String>,
+  pub signature_text: Option<String>,
+  pub custom_template: Option<String>,
+}
+
+impl From<InvoiceBranding> for InvoiceBrandingDto {
+  fn from(branding: InvoiceBranding) -> Self {
+    Self {
+      logo_path: branding.logo_path,
+      accent_color: branding.accent_color.value().to_string(),
+      font: branding.font.as_str().to_string(),
+      font_family: branding.font.css_family().to_string(),
+      default_notes: branding.default_notes.map(|t| t.value().to_string()),
+      footer_text: branding.footer_text.map(|t| t.value().to_string()),
+      signature_text: branding.signature_text.map(|t| t.value().to_string()),
+      custom_template: branding.custom_template.map(|t| t.value().to_string()),
+    }
+  }
+}
+
+pub struct GetInvoiceBrandingUseCase {
+  invoice_service: Arc<InvoiceService>,
+}
+
+impl GetInvoiceBrandingUseCase {
+  pub fn new(invoice_service: Arc<InvoiceService>) -> Self {
+    Self { invoice_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: GetInvoiceBrandingCommand,
+  ) -> Result<InvoiceBrandingDto, InvoiceError> {
+    let branding = self
+      .invoice_service
+      .get_invoice_branding(command.user_id, command.company_id)
+      .await?;
+
+    Ok(InvoiceBrandingDto::from(branding))
+  }
+}
@@ -4,7 +4,10 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use uuid::Uuid;
 
+use super::get_invoice_branding::InvoiceBrandingDto;
+use crate::domain::company::entities::Company;
 use crate::domain::invoice::InvoiceError;
+use crate::domain::invoice::InvoiceLabels;
 use crate::domain::invoice::InvoiceService;
 
 #[derive(Debug, Deserialize)]
@@ -63,6 +66,26 @@ pub struct CompanyDetailsDto {
   pub storage_config: Option<String>,
 }
 
+impl From<Company> for CompanyDetailsDto {
+  fn from(company: Company) -> Self {
+    Self {
+      id: company.id,
+      name: company.name,
+      email: company.email.map(|e| e.as_str().to_string()),
+      phone: company.phone.map(|p| p.as_str().to_string()),
+      street: company.address.as_ref().and_then(|a| a.street.clone()),
+      city: company.address.as_ref().and_then(|a| a.city.clone()),
+      state: company.address.as_ref().and_then(|a| a.state.clone()),
+      postal_code: company.address.as_ref().and_then(|a| a.postal_code.clone()),
+      country: company.address.as_ref().and_then(|a| a.country.clone()),
+      registry_code: company.registry_code.map(|r| r.as_str().to_string()),
+      vat_number: company.vat_number.map(|v| v.as_str().to_string()),
+      storage_provider: company.storage_provider,
+      storage_config: company.storage_config,
+    }
+  }
+}
+
 #[derive(Debug, Serialize)]
 pub struct BankAccountDetailsDto {
   pub id: Uuid,
@@ -84,11 +107,18 @@ pub struct InvoiceDetailsResponse {
   pub invoice_date: NaiveDate,
   pub due_date: NaiveDate,
   pub payment_terms: String,
+  /// Machine-readable payment terms (e.g. `net_30`) for edit forms
+  pub payment_terms_code: String,
+  /// Payment terms as printed on the document, in the invoice language
+  pub localized_payment_terms: String,
   pub currency: String,
+  pub language: String,
+  pub labels: &'static InvoiceLabels,
   pub status: String,
   pub pdf_path: Option<String>,
   pub line_items: Vec<InvoiceLineItemDto>,
   pub totals: InvoiceTotalsDto,
+  pub branding: InvoiceBrandingDto,
   pub created_at: DateTime<Utc>,
   pub updated_at: DateTime<Utc>,
 }
@@ -140,21 +170,12 @@ impl GetInvoiceDetailsUseCase {
       country: customer.address.as_ref().and_then(|a| a.country.clone()),
     };
 
-    let company_dto = CompanyDetailsDto {
-      id: company.id,
-      name: company.name,
-      email: company.email.map(|e| e.as_str().to_string()),
-      phone: company.phone.map(|p| p.as_str().to_string()),
-      street: company.address.as_ref().and_then(|a| a.street.clone()),
-      city: company.address.as_ref().and_then(|a| a.city.clone()),
-      state: company.address.as_ref().and_then(|a| a.state.clone()),
-      postal_code: company.address.as_ref().and_then(|a| a.postal_code.clone()),
-      country: company.address.as_ref().and_then(|a| a.country.clone()),
-      registry_code: company.registry_code.map(|r| r.as_str().to_string()),
-      vat_number: company.vat_number.map(|v| v.as_str().to_string()),
-      storage_provider: company.storage_provider,
-      storage_config: company.storage_config,
-    };
+    let branding = self
+      .invoice_service
+      .get_invoice_branding(command.user_id, invoice.company_id)
+      .await?;
+
+    let company_dto = CompanyDetailsDto::from(company);
 
     let bank_account_dto = bank_account.map(|account| BankAccountDetailsDto {
       id: account.id,
@@ -182,11 +203,16 @@ impl GetInvoiceDetailsUseCase {
       invoice_date: invoice.invoice_date,
       due_date: invoice.due_date,
       payment_terms: invoice.payment_terms.to_string(),
+      payment_terms_code: invoice.payment_terms.as_str(),
+      localized_payment_terms: invoice.language.payment_terms(&invoice.payment_terms),
       currency: invoice.currency.as_str().to_string(),
+      language: invoice.language.as_str().to_string(),
+      labels: invoice.language.labels(),
       status: invoice.status.as_str().to_string(),
       pdf_path: invoice.pdf_path,
       line_items: line_item_dtos,
       totals: totals_dto,
+      branding: InvoiceBrandingDto::from(branding),
       created_at: invoice.created_at,
       updated_at: invoice.updated_at,
     })
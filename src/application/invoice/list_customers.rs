@@ -22,6 +22,7 @@ pub struct CustomerDto {
   pub state: Option<String>,
   pub postal_code: Option<String>,
   pub country: Option<String>,
+  pub default_language: String,
   pub created_at: DateTime<Utc>,
   pub archived_at: Option<DateTime<Utc>>,
 }
@@ -63,6 +64,7 @@ impl ListCustomersUseCase {
         state: c.address.as_ref().and_then(|a| a.state.clone()),
         postal_code: c.address.as_ref().and_then(|a| a.postal_code.clone()),
         country: c.address.as_ref().and_then(|a| a.country.clone()),
+        default_language: c.default_language.as_str().to_string(),
         created_at: c.created_at,
         archived_at: c.archived_at,
       })
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::auth::ports::UserRepository;
+use crate::domain::invoice::{InvoiceError, InvoiceFieldChange, InvoiceService};
+
+#[derive(Debug, Deserialize)]
+pub struct ListInvoiceEventsCommand {
+  pub user_id: Uuid,
+  pub invoice_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InvoiceEventDto {
+  pub id: Uuid,
+  pub kind: String,
+  pub label: String,
+  pub user_id: Option<Uuid>,
+  /// Full name of the acting user; None for system actions
+  pub user_name: Option<String>,
+  pub changes: Vec<InvoiceFieldChange>,
+  pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListInvoiceEventsResponse {
+  pub invoice_id: Uuid,
+  pub events: Vec<InvoiceEventDto>,
+}
+
+pub struct ListInvoiceEventsUseCase {
+  invoice_service: Arc<InvoiceService>,
+  user_repo: Arc<dyn UserRepository>,
+}
+
+impl ListInvoiceEventsUseCase {
+  pub fn new(invoice_service: Arc<InvoiceService>, user_repo: Arc<dyn UserRepository>) -> Self {
+    Self {
+      invoice_service,
+      user_repo,
+    }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListInvoiceEventsCommand,
+  ) -> Result<ListInvoiceEventsResponse, InvoiceError> {
+    let events = self
+      .invoice_service
+      .list_invoice_events(command.user_id, command.invoice_id)
+      .await?;
+
+    let mut user_names: HashMap<Uuid, String> = HashMap::new();
+    for user_id in events.iter().filter_map(|event| event.user_id) {
+      if user_names.contains_key(&user_id) {
+        continue;
+      }
+      let user = self
+        .user_repo
+        .find_by_id(user_id)
+        .await
+        .map_err(|e| InvoiceError::Internal(format!("Failed to fetch user: {}", e)))?;
+      if let Some(user) = user {
+        user_names.insert(user_id, user.full_name);
+      }
+    }
+
+    let events = events
+      .into_iter()
+      .map(|event| InvoiceEventDto {
+        id: event.id,
+        kind: event.kind.as_str().to_string(),
+        label: event.kind.label().to_string(),
+        user_id: event.user_id,
+        user_name: event
+          .user_id
+          .and_then(|user_id| user_names.get(&user_id).cloned()),
+        changes: event.changes,
+        created_at: event.created_at,
+      })
+      .collect();
+
+    Ok(ListInvoiceEventsResponse {
+      invoice_id: command.invoice_id,
+      events,
+    })
+  }
+}
@@ -7,15 +7,24 @@ pub mod create_invoice;
 pub mod create_invoice_from_template;
 pub mod create_template_from_invoice;
 pub mod delete_invoice;
+pub mod get_invoice_branding;
 pub mod get_invoice_details;
 pub mod list_archived_invoices;
 pub mod list_customers;
+pub mod list_invoice_events;
 pub mod list_invoices;
 pub mod list_templates;
+pub mod pdf_uploads;
 pub mod permanently_delete_invoice;
+pub mod preview_invoice_branding;
+pub mod process_invoice_uploads;
+pub mod retry_invoice_uploads;
 pub mod reupload_invoice;
 pub mod unarchive_invoice;
 pub mod update_customer;
+pub mod update_invoice;
+pub mod update_invoice_branding;
+pub mod verify_invoice_chain;
 
 pub use archive_customer::{ArchiveCustomerCommand, ArchiveCustomerUseCase};
 pub use archive_invoice::{ArchiveInvoiceCommand, ArchiveInvoiceUseCase};
@@ -35,6 +44,9 @@ pub use create_template_from_invoice::{
   CreateTemplateFromInvoiceUseCase,
 };
 pub use delete_invoice::{DeleteInvoiceCommand, DeleteInvoiceUseCase};
+pub use get_invoice_branding::{
+  GetInvoiceBrandingCommand, GetInvoiceBrandingUseCase, InvoiceBrandingDto,
+};
 pub use get_invoice_details::{
   CustomerDetailsDto, GetInvoiceDetailsCommand, GetInvoiceDetailsUseCase, InvoiceDetailsResponse,
   InvoiceLineItemDto, InvoiceTotalsDto,
@@ -45,15 +57,25 @@ pub use list_archived_invoices::{
 pub use list_customers::{
   CustomerDto, ListCustomersCommand, ListCustomersResponse, ListCustomersUseCase,
 };
+pub use list_invoice_events::{
+  InvoiceEventDto, ListInvoiceEventsCommand, ListInvoiceEventsResponse, ListInvoiceEventsUseCase,
+};
 pub use list_invoices::{
   InvoiceListItemDto, ListInvoicesCommand, ListInvoicesResponse, ListInvoicesUseCase,
 };
 pub use list_templates::{
   ListTemplatesCommand, ListTemplatesResponse, ListTemplatesUseCase, TemplateListItem,
 };
+pub use pdf_uploads::InvoicePdfUploader;
 pub use permanently_delete_invoice::{
   PermanentlyDeleteInvoiceCommand, PermanentlyDeleteInvoiceUseCase,
 };
+pub use preview_invoice_branding::PreviewInvoiceBrandingUseCase;
+pub use process_invoice_uploads::ProcessInvoiceUploadsUseCase;
+pub use retry_invoice_uploads::{RetryInvoiceUploadsCommand, RetryInvoiceUploadsUseCase};
 pub use reupload_invoice::{ReuploadInvoiceCommand, ReuploadInvoiceUseCase};
 pub use unarchive_invoice::{UnarchiveInvoiceCommand, UnarchiveInvoiceUseCase};
 pub use update_customer::{UpdateCustomerCommand, UpdateCustomerResponse, UpdateCustomerUseCase};
+pub use update_invoice::{UpdateInvoiceCommand, UpdateInvoiceResponse, UpdateInvoiceUseCase};
+pub use update_invoice_branding::{UpdateInvoiceBrandingCommand, UpdateInvoiceBrandingUseCase};
+pub use verify_invoice_chain::{VerifyInvoiceChainCommand, VerifyInvoiceChainUseCase};
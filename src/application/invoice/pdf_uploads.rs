@@ -0,0 +1,216 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::company::ConnectGoogleDriveUseCase;
+use crate::domain::company::{CompanyRepository, StorageProvider};
+use crate::domain::document::DocumentService;
+use crate::domain::invoice::errors::InvoiceError;
+use crate::domain::invoice::{Invoice, InvoiceService};
+use crate::domain::storage::{UploadJob, UploadOutboxService, UploadTarget};
+use crate::infrastructure::cloud::CloudStorageFactory;
+use crate::infrastructure::config::Config;
+use crate::infrastructure::security::AesTokenEncryption;
+
+/// Queues invoice PDFs in the upload outbox and pushes them to the company's
+/// storage provider. A failed upload no longer blocks the invoice: it stays
+/// queued and is retried with backoff.
+pub struct InvoicePdfUploader {
+  invoice_service: Arc<InvoiceService>,
+  company_repo: Arc<dyn CompanyRepository>,
+  token_encryption: Arc<AesTokenEncryption>,
+  connect_google_drive: Arc<ConnectGoogleDriveUseCase>,
+  config: Arc<Config>,
+  outbox: Arc<UploadOutboxService>,
+  document_service: Arc<DocumentService>,
+}
+
+impl InvoicePdfUploader {
+  pub fn new(
+    invoice_service: Arc<InvoiceService>,
+    company_repo: Arc<dyn CompanyRepository>,
+    token_encryption: Arc<AesTokenEncryption>,
+    connect_google_drive: Arc<ConnectGoogleDriveUseCase>,
+    config: Arc<Config>,
+    outbox: Arc<UploadOutboxService>,
+    document_service: Arc<DocumentService>,
+  ) -> Self {
+    Self {
+      invoice_service,
+      company_repo,
+      token_encryption,
+      connect_google_drive,
+      config,
+      outbox,
+      document_service,
+    }
+  }
+
+  /// Queue the invoice's PDF for upload into the company's folder
+  pub async fn enqueue(
+    &self,
+    invoice: &Invoice,
+    pdf_path: &str,
+    user_id: Uuid,
+  ) -> Result<UploadJob, InvoiceError> {
+    let company = self
+      .company_repo
+      .find_by_id(invoice.company_id)
+      .await
+      .map_err(|e| {
+        InvoiceError::CloudStorageUploadFailed(format!("Failed to fetch company: {}", e))
+      })?
+      .ok_or_else(|| InvoiceError::CloudStorageUploadFailed("Company not found".to_string()))?;
+
+    let provider = company
+      .storage_provider
+      .as_ref()
+      .and_then(|p| p.parse::<StorageProvider>().ok())
+      .unwrap_or_default();
+    // Use google_drive_folder_id or empty string for NoOp
+    let folder_id = company.google_drive_folder_id.unwrap_or_default();
+    let mut job = UploadJob::new(
+      invoice.company_id,
+      UploadTarget::InvoicePdf,
+      invoice.id,
+      invoice.invoice_number.value().to_string(),
+      folder_id,
+      pdf_path.to_string(),
+      user_id,
+    );
+    // An invoice has a single PDF, whatever its number
+    job.file_key = "pdf".to_string();
+    job.provider = provider;
+    Ok(self.outbox.enqueue(job).await?)
+  }
+
+  /// Attempt the given jobs, remembering the Drive file of the ones that
+  /// went through and scheduling the others for another attempt. Jobs
+  /// another push has claimed meanwhile are skipped.
+  pub async fn push(&self, jobs: Vec<UploadJob>) -> Result<(), InvoiceError> {
+    for job in jobs {
+      let Some(job) = self.outbox.claim(job).await? else {
+        continue;
+      };
+      let Some(company) = self
+        .company_repo
+        .find_by_id(job.company_id)
+        .await
+        .map_err(|e| {
+          InvoiceError::CloudStorageUploadFailed(format!("Failed to fetch company: {}", e))
+        })?
+      else {
+        self.outbox.discard(job).await?;
+        continue;
+      };
+
+      // Extract OAuth credentials from config
+      let (oauth_client_id, oauth_client_secret) =
+        if let Some(ref drive_config) = self.config.google_drive {
+          (
+            drive_config.oauth_client_id.as_deref(),
+            drive_config.oauth_client_secret.as_deref(),
+          )
+        } else {
+          (None, None)
+        };
+
+      // Create cloud storage adapter with OAuth support
+      let cloud_storage = CloudStorageFactory::create_with_oauth(
+        company.storage_provider.as_ref(),
+        company.storage_config.as_ref(),
+        &company,
+        &self.token_encryption,
+        Some(&self.connect_google_drive),
+        oauth_client_id,
+        oauth_client_secret,
+        &self.config.local_storage.root_dir,
+      )
+      .await;
+
+      // Upload stored PDFs in plain form
+      let file = match self.document_service.plain_file(&job.local_path).await {
+        Ok(file) => file,
+        Err(e) => {
+          self.outbox.record_failure(job, e.to_string()).await?;
+          continue;
+        }
+      };
+      let result = cloud_storage
+        .upload_invoice_pdf(&job.folder_id, &job.path, file.path())
+        .await;
+      drop(file);
+
+      match result {
+        Ok(file_id) => {
+          let job = self.outbox.record_success(job, file_id.clone()).await?;
+          match self
+            .invoice_service
+            .set_pdf_drive_file_id(job.owner_id, file_id)
+            .await
+          {
+            // Deleted while its upload was queued
+            Ok(_) | Err(InvoiceError::InvoiceNotFound(_)) => {}
+            Err(e) => return Err(e),
+          }
+        }
+        Err(e) => {
+          self.outbox.record_failure(job, e.to_string()).await?;
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Queue the PDF and attempt it right away; a failure is left to the
+  /// outbox rather than returned
+  pub async fn upload(
+    &self,
+    invoice: &Invoice,
+    pdf_path: &str,
+    user_id: Uuid,
+  ) -> Result<(), InvoiceError> {
+    let job = self.enqueue(invoice, pdf_path, user_id).await?;
+    self.push(vec![job]).await
+  }
+
+  /// Queued jobs of an invoice not uploaded yet
+  pub async fn open_jobs(&self, invoice_id: Uuid) -> Result<Vec<UploadJob>, InvoiceError> {
+    Ok(
+      self
+        .outbox
+        .open_jobs(UploadTarget::InvoicePdf, invoice_id)
+        .await?,
+    )
+  }
+
+  /// Attempt the failed jobs of an invoice again right away. Returns the
+  /// number of files still not uploaded.
+  pub async fn retry(&self, invoice_id: Uuid) -> Result<usize, InvoiceError> {
+    self
+      .outbox
+      .retry_failed(UploadTarget::InvoicePdf, invoice_id)
+      .await?;
+    let now = chrono::Utc::now();
+    let due = self
+      .open_jobs(invoice_id)
+      .await?
+      .into_iter()
+      .filter(|job| job.is_due(now))
+      .collect();
+    self.push(due).await?;
+    Ok(self.open_jobs(invoice_id).await?.len())
+  }
+
+  /// Attempt due jobs of all companies, at most `limit` of them. Returns the
+  /// number attempted.
+  pub async fn push_due(&self, limit: i64) -> Result<usize, InvoiceError> {
+    let jobs = self
+      .outbox
+      .due_jobs(UploadTarget::InvoicePdf, limit)
+      .await?;
+    let attempted = jobs.len();
+    self.push(jobs).await?;
+    Ok(attempted)
+  }
+}
@@ -2,7 +2,9 @@ use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::domain::invoice::{InvoiceError, InvoiceService};
+use crate::domain::document::{DocumentOwner, DocumentService};
+use crate::domain::invoice::{InvoiceError, InvoiceService, InvoiceStatus};
+use crate::domain::retention::{DeletionAttempt, RetainedRecord, RetentionService};
 
 #[derive(Debug, Deserialize)]
 pub struct PermanentlyDeleteInvoiceCommand {
@@ -12,20 +14,56 @@ pub struct PermanentlyDeleteInvoiceCommand {
 
 pub struct PermanentlyDeleteInvoiceUseCase {
   invoice_service: Arc<InvoiceService>,
+  retention_service: Arc<RetentionService>,
+  document_service: Arc<DocumentService>,
 }
 
 impl PermanentlyDeleteInvoiceUseCase {
-  pub fn new(invoice_service: Arc<InvoiceService>) -> Self {
-    Self { invoice_service }
+  pub fn new(
+    invoice_service: Arc<InvoiceService>,
+    retention_service: Arc<RetentionService>,
+    document_service: Arc<DocumentService>,
+  ) -> Self {
+    Self {
+      invoice_service,
+      retention_service,
+      document_service,
+    }
   }
 
   pub async fn execute(
     &self,
     command: PermanentlyDeleteInvoiceCommand,
   ) -> Result<(), InvoiceError> {
+    let invoice = self
+      .invoice_service
+      .get_invoice(command.user_id, command.invoice_id)
+      .await?;
+
+    // Issued invoices are source documents; drafts never were
+    if invoice.status != InvoiceStatus::Draft {
+      self
+        .retention_service
+        .guard_deletion(DeletionAttempt {
+          company_id: invoice.company_id,
+          record: RetainedRecord::IssuedInvoice,
+          record_id: invoice.id,
+          label: format!("Invoice {}", invoice.invoice_number.value()),
+          document_date: invoice.invoice_date,
+          user_id: Some(command.user_id),
+        })
+        .await?;
+    }
+
     self
       .invoice_service
       .permanently_delete_invoice(command.user_id, command.invoice_id)
-      .await
+      .await?;
+    self
+      .document_service
+      .detach(DocumentOwner::InvoicePdf, invoice.id)
+      .await?;
+
+    Ok(())
   }
 }
@@ -0,0 +1,127 @@
+use chrono::{Duration, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::get_invoice_branding::InvoiceBrandingDto;
+use super::get_invoice_details::{
+  BankAccountDetailsDto, CompanyDetailsDto, CustomerDetailsDto, InvoiceDetailsResponse,
+  InvoiceLineItemDto, InvoiceTotalsDto,
+};
+use super::update_invoice_branding::UpdateInvoiceBrandingCommand;
+use crate::domain::invoice::{InvoiceError, InvoiceLanguage, InvoiceService, PaymentTerms};
+
+/// Builds a sample invoice rendered with unsaved branding settings,
+/// so a custom template can be checked before it is saved.
+pub struct PreviewInvoiceBrandingUseCase {
+  invoice_service: Arc<InvoiceService>,
+}
+
+impl PreviewInvoiceBrandingUseCase {
+  pub fn new(invoice_service: Arc<InvoiceService>) -> Self {
+    Self { invoice_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: UpdateInvoiceBrandingCommand,
+  ) -> Result<InvoiceDetailsResponse, InvoiceError> {
+    let data = command.to_branding_data()?;
+
+    let (company, branding) = self
+      .invoice_service
+      .preview_invoice_branding(command.user_id, command.company_id, data)
+      .await?;
+
+    Ok(sample_invoice(
+      CompanyDetailsDto::from(company),
+      InvoiceBrandingDto::from(branding),
+    ))
+  }
+}
+
+fn sample_line_item(
+  description: &str,
+  quantity: Decimal,
+  unit_price: Decimal,
+  line_order: i32,
+) -> InvoiceLineItemDto {
+  let vat_rate = dec!(24);
+  let subtotal = quantity * unit_price;
+  let vat_amount = (subtotal * vat_rate / dec!(100)).round_dp(2);
+  InvoiceLineItemDto {
+    id: Uuid::nil(),
+    description: description.to_string(),
+    quantity,
+    unit_price,
+    vat_rate,
+    currency: "EUR".to_string(),
+    line_order,
+    subtotal,
+    vat_amount,
+    total: subtotal + vat_amount,
+  }
+}
+
+fn sample_invoice(
+  company: CompanyDetailsDto,
+  branding: InvoiceBrandingDto,
+) -> InvoiceDetailsResponse {
+  let line_items = vec![
+    sample_line_item("Consulting services", dec!(10), dec!(85), 0),
+    sample_line_item("Software license", dec!(1), dec!(240), 1),
+  ];
+
+  let subtotal: Decimal = line_items.iter().map(|i| i.subtotal).sum();
+  let total_vat: Decimal = line_items.iter().map(|i| i.vat_amount).sum();
+
+  let now = Utc::now();
+  let invoice_date = now.date_naive();
+  let payment_terms = PaymentTerms::Net15;
+  let language = InvoiceLanguage::default();
+
+  InvoiceDetailsResponse {
+    id: Uuid::nil(),
+    company_id: company.id,
+    company,
+    customer: CustomerDetailsDto {
+      id: Uuid::nil(),
+      name: "Sample Customer OÜ".to_string(),
+      street: Some("Narva mnt 5".to_string()),
+      city: Some("Tallinn".to_string()),
+      state: None,
+      postal_code: Some("10117".to_string()),
+      country: Some("Estonia".to_string()),
+    },
+    bank_account_id: None,
+    bank_account: Some(BankAccountDetailsDto {
+      id: Uuid::nil(),
+      name: "Main account".to_string(),
+      iban: "EE382200221020145685".to_string(),
+      iban_formatted: "EE38 2200 2210 2014 5685".to_string(),
+      bank_details: Some("Swedbank AS\nSWIFT: HABAEE2X".to_string()),
+    }),
+    invoice_number: "PREVIEW-001".to_string(),
+    invoice_date,
+    due_date: invoice_date + Duration::days(payment_terms.days() as i64),
+    payment_terms: payment_terms.to_string(),
+    payment_terms_code: payment_terms.as_str(),
+    localized_payment_terms: language.payment_terms(&payment_terms),
+    currency: "EUR".to_string(),
+    language: language.as_str().to_string(),
+    labels: language.labels(),
+    status: "draft".to_string(),
+    pdf_path: None,
+    line_items,
+    totals: InvoiceTotalsDto {
+      subtotal,
+      total_vat,
+      grand_total: subtotal + total_vat,
+      currency: "EUR".to_string(),
+    },
+    branding,
+    created_at: now,
+    updated_at: now,
+  }
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::application::invoice::pdf_uploads::InvoicePdfUploader;
+use crate::domain::invoice::InvoiceError;
+
+/// Invoice PDFs attempted per run
+const BATCH_SIZE: i64 = 50;
+
+/// Background run over the upload outbox: attempts the invoice PDFs whose
+/// next attempt is due
+pub struct ProcessInvoiceUploadsUseCase {
+  pdf_uploader: Arc<InvoicePdfUploader>,
+}
+
+impl ProcessInvoiceUploadsUseCase {
+  pub fn new(pdf_uploader: Arc<InvoicePdfUploader>) -> Self {
+    Self { pdf_uploader }
+  }
+
+  /// Returns the number of files attempted
+  pub async fn execute(&self) -> Result<usize, InvoiceError> {
+    self.pdf_uploader.push_due(BATCH_SIZE).await
+  }
+}
@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::application::invoice::pdf_uploads::InvoicePdfUploader;
+use crate::domain::invoice::{InvoiceError, InvoiceService};
+
+#[derive(Debug, Deserialize)]
+pub struct RetryInvoiceUploadsCommand {
+  pub user_id: Uuid,
+  pub invoice_id: Uuid,
+}
+
+/// Attempt an invoice's failed PDF upload again right away
+pub struct RetryInvoiceUploadsUseCase {
+  invoice_service: Arc<InvoiceService>,
+  pdf_uploader: Arc<InvoicePdfUploader>,
+}
+
+impl RetryInvoiceUploadsUseCase {
+  pub fn new(invoice_service: Arc<InvoiceService>, pdf_uploader: Arc<InvoicePdfUploader>) -> Self {
+    Self {
+      invoice_service,
+      pdf_uploader,
+    }
+  }
+
+  /// Returns the number of files still not uploaded
+  pub async fn execute(&self, command: RetryInvoiceUploadsCommand) -> Result<usize, InvoiceError> {
+    // Verifies the user may access the invoice
+    let invoice = self
+      .invoice_service
+      .get_invoice(command.user_id, command.invoice_id)
+      .await?;
+
+    self.pdf_uploader.retry(invoice.id).await
+  }
+}
@@ -2,17 +2,15 @@ use serde::Serialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::application::company::ConnectGoogleDriveUseCase;
 use crate::application::invoice::get_invoice_details::{
   GetInvoiceDetailsCommand, GetInvoiceDetailsUseCase,
 };
-use crate::domain::company::CompanyRepository;
+use crate::application::invoice::pdf_uploads::InvoicePdfUploader;
+use crate::domain::document::{DocumentOwner, DocumentService, NewDocument};
 use crate::domain::invoice::errors::InvoiceError;
 use crate::domain::invoice::ports::PdfGenerator;
-use crate::domain::invoice::{InvoiceService, InvoiceStatus};
-use crate::infrastructure::cloud::CloudStorageFactory;
-use crate::infrastructure::config::Config;
-use crate::infrastructure::security::AesTokenEncryption;
+use crate::domain::invoice::{Invoice, InvoiceEventKind, InvoiceService, InvoiceStatus};
+use crate::domain::retention::RetentionService;
 
 pub struct ReuploadInvoiceCommand {
   pub user_id: Uuid,
@@ -29,10 +27,9 @@ pub struct ReuploadInvoiceUseCase {
   invoice_service: Arc<InvoiceService>,
   pdf_generator: Arc<dyn PdfGenerator>,
   get_invoice_details: Arc<GetInvoiceDetailsUseCase>,
-  company_repo: Arc<dyn CompanyRepository>,
-  token_encryption: Arc<AesTokenEncryption>,
-  connect_google_drive: Arc<ConnectGoogleDriveUseCase>,
-  config: Arc<Config>,
+  pdf_uploader: Arc<InvoicePdfUploader>,
+  document_service: Arc<DocumentService>,
+  retention_service: Arc<RetentionService>,
 }
 
 impl ReuploadInvoiceUseCase {
@@ -40,19 +37,17 @@ impl ReuploadInvoiceUseCase {
     invoice_service: Arc<InvoiceService>,
     pdf_generator: Arc<dyn PdfGenerator>,
     get_invoice_details: Arc<GetInvoiceDetailsUseCase>,
-    company_repo: Arc<dyn CompanyRepository>,
-    token_encryption: Arc<AesTokenEncryption>,
-    connect_google_drive: Arc<ConnectGoogleDriveUseCase>,
-    config: Arc<Config>,
+    pdf_uploader: Arc<InvoicePdfUploader>,
+    document_service: Arc<DocumentService>,
+    retention_service: Arc<RetentionService>,
   ) -> Self {
     Self {
       invoice_service,
       pdf_generator,
       get_invoice_details,
-      company_repo,
-      token_encryption,
-      connect_google_drive,
-      config,
+      pdf_uploader,
+      document_service,
+      retention_service,
     }
   }
 
@@ -84,54 +79,29 @@ impl ReuploadInvoiceUseCase {
       .await?;
 
     // Generate PDF
-    let pdf_path = self
+    let generated_path = self
       .pdf_generator
       .generate_invoice_pdf(command.invoice_id, &invoice_details)
       .await?;
-
-    // Fetch full company entity with OAuth tokens
-    let company = self
-      .company_repo
-      .find_by_id(invoice_details.company_id)
-      .await
-      .map_err(|e| {
-        InvoiceError::CloudStorageUploadFailed(format!("Failed to fetch company: {}", e))
-      })?
-      .ok_or_else(|| InvoiceError::CloudStorageUploadFailed("Company not found".to_string()))?;
-
-    // Extract OAuth credentials from config
-    let (oauth_client_id, oauth_client_secret) =
-      if let Some(ref drive_config) = self.config.google_drive {
-        (
-          drive_config.oauth_client_id.as_deref(),
-          drive_config.oauth_client_secret.as_deref(),
-        )
-      } else {
-        (None, None)
-      };
-
-    // Create cloud storage adapter with OAuth support
-    let cloud_storage = CloudStorageFactory::create_with_oauth(
-      company.storage_provider.as_ref(),
-      company.storage_config.as_ref(),
-      &company,
-      &self.token_encryption,
-      Some(&self.connect_google_drive),
-      oauth_client_id,
-      oauth_client_secret,
-    )
-    .await;
-
-    // Upload to cloud storage (use google_drive_folder_id or empty string for NoOp)
-    let folder_id = company.google_drive_folder_id.as_deref().unwrap_or("");
-    let file_id = cloud_storage
-      .upload_invoice_pdf(folder_id, &invoice_details.invoice_number, &pdf_path)
+    let pdf_path = self
+      .store_pdf(&invoice, &generated_path, command.user_id)
       .await?;
 
-    // Update stored PDF path and Drive file ID
+    // Update stored PDF path, keeping the Drive file until the new upload
+    // replaces it
     let updated_invoice = self
       .invoice_service
-      .set_invoice_pdf_path(command.invoice_id, pdf_path.clone(), Some(file_id))
+      .set_invoice_pdf_path(
+        command.user_id,
+        command.invoice_id,
+        pdf_path.clone(),
+        invoice.pdf_drive_file_id.clone(),
+        InvoiceEventKind::PdfReuploaded,
+      )
+      .await?;
+    self
+      .pdf_uploader
+      .upload(&updated_invoice, &pdf_path, command.user_id)
       .await?;
 
     Ok(ReuploadInvoiceResponse {
@@ -139,4 +109,35 @@ impl ReuploadInvoiceUseCase {
       pdf_path: updated_invoice.pdf_path,
     })
   }
+
+  /// Move the generated PDF into the document store as the invoice's PDF.
+  /// The PDF it replaces is kept for as long as the invoice is retained.
+  async fn store_pdf(
+    &self,
+    invoice: &Invoice,
+    generated_path: &str,
+    user_id: Uuid,
+  ) -> Result<String, InvoiceError> {
+    let keep_replaced_until = self
+      .retention_service
+      .retained_until(invoice.company_id, invoice.invoice_date)
+      .await?;
+    let document = self
+      .document_service
+      .import_file(
+        generated_path,
+        NewDocument {
+          company_id: invoice.company_id,
+          content: Vec::new(),
+          mime_type: "application/pdf".to_string(),
+          original_name: format!("{}.pdf", invoice.invoice_number),
+          uploaded_by: Some(user_id),
+          keep_replaced_until,
+        },
+        DocumentOwner::InvoicePdf,
+        invoice.id,
+      )
+      .await?;
+    Ok(self.document_service.path(&document))
+  }
 }
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::domain::invoice::{CustomerAddress, CustomerName, InvoiceError, InvoiceService};
+use crate::domain::invoice::{
+  CustomerAddress, CustomerName, InvoiceError, InvoiceLanguage, InvoiceService,
+};
 
 #[derive(Debug, Deserialize)]
 pub struct UpdateCustomerCommand {
@@ -14,6 +17,7 @@ pub struct UpdateCustomerCommand {
   pub state: Option<String>,
   pub postal_code: Option<String>,
   pub country: Option<String>,
+  pub default_language: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -36,6 +40,11 @@ impl UpdateCustomerUseCase {
     command: UpdateCustomerCommand,
   ) -> Result<UpdateCustomerResponse, InvoiceError> {
     let name = CustomerName::new(command.name)?;
+    let default_language = command
+      .default_language
+      .as_deref()
+      .map(InvoiceLanguage::from_str)
+      .transpose()?;
 
     let address = if command.street.is_some()
       || command.city.is_some()
@@ -56,7 +65,13 @@ impl UpdateCustomerUseCase {
 
     let customer = self
       .invoice_service
-      .update_customer(command.user_id, command.customer_id, name, address)
+      .update_customer(
+        command.user_id,
+        command.customer_id,
+        name,
+        address,
+        default_language,
+      )
       .await?;
 
     Ok(UpdateCustomerResponse {
@@ -0,0 +1,176 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::create_invoice::CreateInvoiceLineItemDto;
+use crate::domain::invoice::{
+  InvoiceError, InvoiceFieldError, InvoiceLanguage, InvoiceService, InvoiceUpdateData,
+  LineItemDescription, Money, PaymentTerms, Quantity, ValueObjectError, VatRate,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateInvoiceCommand {
+  pub user_id: Uuid,
+  pub invoice_id: Uuid,
+  pub customer_id: Uuid,
+  pub bank_account_id: Option<Uuid>,
+  pub invoice_date: NaiveDate,
+  pub payment_terms: String,
+  /// Document language; None keeps the current one
+  pub language: Option<String>,
+  /// Full line item set in display order; replaces the existing items
+  pub line_items: Vec<CreateInvoiceLineItemDto>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateInvoiceResponse {
+  pub invoice_id: Uuid,
+  pub invoice_number: String,
+  pub line_item_count: usize,
+  pub updated_at: DateTime<Utc>,
+}
+
+pub struct UpdateInvoiceUseCase {
+  invoice_service: Arc<InvoiceService>,
+}
+
+impl UpdateInvoiceUseCase {
+  pub fn new(invoice_service: Arc<InvoiceService>) -> Self {
+    Self { invoice_service }
+  }
+
+  /// Validates every field up front and reports all failures together as
+  /// `InvoiceError::InvalidFields`, so the edit form can mark each input
+  pub async fn execute(
+    &self,
+    command: UpdateInvoiceCommand,
+  ) -> Result<UpdateInvoiceResponse, InvoiceError> {
+    let invoice = self
+      .invoice_service
+      .get_invoice(command.user_id, command.invoice_id)
+      .await?;
+
+    if !invoice.is_editable() {
+      return Err(InvoiceError::CannotEditInvoice(
+        "Only draft invoices can be edited".to_string(),
+      ));
+    }
+
+    let mut errors = Vec::new();
+
+    let payment_terms = field_value(
+      &mut errors,
+      "payment_terms".to_string(),
+      PaymentTerms::from_str(&command.payment_terms),
+    );
+
+    let language = match command.language.as_deref() {
+      Some(language) if !language.is_empty() => field_value(
+        &mut errors,
+        "language".to_string(),
+        InvoiceLanguage::from_str(language),
+      ),
+      _ => None,
+    };
+
+    if command.line_items.is_empty() {
+      errors.push(InvoiceFieldError::new(
+        "line_items",
+        "At least one line item is required",
+      ));
+    }
+
+    let mut line_items = Vec::with_capacity(command.line_items.len());
+    for (i, item) in command.line_items.into_iter().enumerate() {
+      let field = |name: &str| format!("line_items.{}.{}", i, name);
+      let description = field_value(
+        &mut errors,
+        field("description"),
+        LineItemDescription::new(item.description),
+      );
+      let quantity = field_value(&mut errors, field("quantity"), Quantity::new(item.quantity));
+      let unit_price = field_value(
+        &mut errors,
+        field("unit_price"),
+        Money::new(item.unit_price, invoice.currency),
+      );
+      let vat_rate = field_value(&mut errors, field("vat_rate"), VatRate::new(item.vat_rate));
+
+      if let (Some(description), Some(quantity), Some(unit_price), Some(vat_rate)) =
+        (description, quantity, unit_price, vat_rate)
+      {
+        line_items.push((description, quantity, unit_price, vat_rate));
+      }
+    }
+
+    let Some(payment_terms) = payment_terms.filter(|_| errors.is_empty()) else {
+      return Err(InvoiceError::InvalidFields(errors));
+    };
+
+    let data = InvoiceUpdateData {
+      customer_id: command.customer_id,
+      bank_account_id: command.bank_account_id,
+      invoice_date: command.invoice_date,
+      payment_terms,
+      language,
+      line_items,
+    };
+
+    let (invoice, line_items) = self
+      .invoice_service
+      .update_invoice(command.user_id, command.invoice_id, data)
+      .await
+      .map_err(|e| match e {
+        InvoiceError::CustomerNotFound(_) => {
+          InvoiceError::InvalidFields(vec![InvoiceFieldError::new(
+            "customer_id",
+            "Customer not found",
+          )])
+        }
+        other => other,
+      })?;
+
+    Ok(UpdateInvoiceResponse {
+      invoice_id: invoice.id,
+      invoice_number: invoice.invoice_number.into_inner(),
+      line_item_count: line_items.len(),
+      updated_at: invoice.updated_at,
+    })
+  }
+}
+
+/// Keep a parsed value, or record its error against `field`
+fn field_value<T>(
+  errors: &mut Vec<InvoiceFieldError>,
+  field: String,
+  result: Result<T, ValueObjectError>,
+) -> Option<T> {
+  result
+    .map_err(|e| errors.push(InvoiceFieldError::new(field, e.to_string())))
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rust_decimal_macros::dec;
+
+  #[test]
+  fn test_field_value_records_error_against_field() {
+    let mut errors = Vec::new();
+
+    let ok = field_value(&mut errors, "quantity".to_string(), Quantity::new(dec!(2)));
+    let bad = field_value(
+      &mut errors,
+      "line_items.1.quantity".to_string(),
+      Quantity::new(dec!(-1)),
+    );
+
+    assert!(ok.is_some());
+    assert!(bad.is_none());
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].field, "line_items.1.quantity");
+  }
+}
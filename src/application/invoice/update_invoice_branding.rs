@@ -0,0 +1,131 @@
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::get_invoice_branding::InvoiceBrandingDto;
+use crate::domain::invoice::{
+  AccentColor, BrandingText, CustomInvoiceTemplate, InvoiceBranding, InvoiceBrandingData,
+  InvoiceError, InvoiceFont, InvoiceService,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateInvoiceBrandingCommand {
+  pub user_id: Uuid,
+  pub company_id: Uuid,
+  pub accent_color: String,
+  pub font: String,
+  pub default_notes: Option<String>,
+  pub footer_text: Option<String>,
+  pub signature_text: Option<String>,
+  pub custom_template: Option<String>,
+  /// Path of a newly uploaded logo file
+  pub logo_path: Option<String>,
+  #[serde(default)]
+  pub remove_logo: bool,
+}
+
+impl UpdateInvoiceBrandingCommand {
+  /// Validate the raw form values into domain branding data
+  pub(crate) fn to_branding_data(&self) -> Result<InvoiceBrandingData, InvoiceError> {
+    let text = |value: &Option<String>| -> Result<Option<BrandingText>, InvoiceError> {
+      Ok(BrandingText::new(value.clone().unwrap_or_default())?)
+    };
+
+    Ok(InvoiceBrandingData {
+      accent_color: AccentColor::new(self.accent_color.clone())?,
+      font: InvoiceFont::from_str(&self.font)?,
+      default_notes: text(&self.default_notes)?,
+      footer_text: text(&self.footer_text)?,
+      signature_text: text(&self.signature_text)?,
+      custom_template: CustomInvoiceTemplate::new(
+        self.custom_template.clone().unwrap_or_default(),
+      )?,
+      logo_path: self.logo_path.clone(),
+      remove_logo: self.remove_logo,
+    })
+  }
+}
+
+pub struct UpdateInvoiceBrandingUseCase {
+  invoice_service: Arc<InvoiceService>,
+  /// Directory the companies' logos are stored under, one folder per company
+  logo_dir: PathBuf,
+}
+
+impl UpdateInvoiceBrandingUseCase {
+  pub fn new(invoice_service: Arc<InvoiceService>, logo_dir: PathBuf) -> Self {
+    Self {
+      invoice_service,
+      logo_dir,
+    }
+  }
+
+  /// Store an uploaded logo for the company; returns the path to save in the branding
+  pub async fn save_logo(
+    &self,
+    company_id: Uuid,
+    content: &[u8],
+    extension: &str,
+  ) -> Result<String, InvoiceError> {
+    let company_dir = self.logo_dir.join(company_id.to_string());
+    tokio::fs::create_dir_all(&company_dir)
+      .await
+      .map_err(|e| InvoiceError::Internal(format!("Failed to create logo directory: {}", e)))?;
+
+    let path = company_dir.join(format!("logo-{}.{}", Uuid::new_v4(), extension));
+    tokio::fs::write(&path, content)
+      .await
+      .map_err(|e| InvoiceError::Internal(format!("Failed to save logo: {}", e)))?;
+    Ok(path.to_string_lossy().to_string())
+  }
+
+  pub async fn execute(
+    &self,
+    command: UpdateInvoiceBrandingCommand,
+  ) -> Result<InvoiceBrandingDto, InvoiceError> {
+    let saved = self.save(&command).await;
+    // A new logo that didn't make it into the branding is not kept
+    if let (Err(_), Some(new_logo)) = (&saved, &command.logo_path) {
+      remove_logo(new_logo).await;
+    }
+    let (previous_logo, branding) = saved?;
+
+    // The replaced or removed logo is no longer used
+    if let Some(previous_logo) =
+      previous_logo.filter(|previous| branding.logo_path.as_ref() != Some(previous))
+    {
+      remove_logo(&previous_logo).await;
+    }
+
+    Ok(InvoiceBrandingDto::from(branding))
+  }
+
+  /// Save the branding; returns the logo it had before along with it
+  async fn save(
+    &self,
+    command: &UpdateInvoiceBrandingCommand,
+  ) -> Result<(Option<String>, InvoiceBranding), InvoiceError> {
+    let data = command.to_branding_data()?;
+    let previous_logo = self
+      .invoice_service
+      .get_invoice_branding(command.user_id, command.company_id)
+      .await?
+      .logo_path;
+
+    let branding = self
+      .invoice_service
+      .update_invoice_branding(command.user_id, command.company_id, data)
+      .await?;
+    Ok((previous_logo, branding))
+  }
+}
+
+async fn remove_logo(path: &str) {
+  if let Err(e) = tokio::fs::remove_file(path).await {
+    if e.kind() != std::io::ErrorKind::NotFound {
+      tracing::warn!("Failed to delete logo file {}: {}", path, e);
+    }
+  }
+}
@@ -0,0 +1,32 @@
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::invoice::{InvoiceChainReport, InvoiceError, InvoiceService};
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyInvoiceChainCommand {
+  /// Nil for system verification (CLI), which skips the membership check
+  pub user_id: Uuid,
+  pub company_id: Uuid,
+}
+
+pub struct VerifyInvoiceChainUseCase {
+  invoice_service: Arc<InvoiceService>,
+}
+
+impl VerifyInvoiceChainUseCase {
+  pub fn new(invoice_service: Arc<InvoiceService>) -> Self {
+    Self { invoice_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: VerifyInvoiceChainCommand,
+  ) -> Result<InvoiceChainReport, InvoiceError> {
+    self
+      .invoice_service
+      .verify_invoice_chain(command.user_id, command.company_id)
+      .await
+  }
+}
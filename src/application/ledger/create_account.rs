@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::ledger::{
+  errors::LedgerError, services::LedgerService, value_objects::AccountType,
+};
+
+#[derive(Debug)]
+pub struct CreateAccountCommand {
+  pub company_id: Uuid,
+  pub code: String,
+  pub name: String,
+  pub account_type: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateAccountResponse {
+  pub account_id: Uuid,
+  pub code: String,
+}
+
+pub struct CreateAccountUseCase {
+  ledger_service: Arc<LedgerService>,
+}
+
+impl CreateAccountUseCase {
+  pub fn new(ledger_service: Arc<LedgerService>) -> Self {
+    Self { ledger_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: CreateAccountCommand,
+  ) -> Result<CreateAccountResponse, LedgerError> {
+    let account_type = AccountType::try_from(command.account_type.as_str())?;
+
+    let account = self
+      .ledger_service
+      .create_account(command.company_id, command.code, command.name, account_type)
+      .await?;
+
+    Ok(CreateAccountResponse {
+      account_id: account.id,
+      code: account.code,
+    })
+  }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::ledger::{entities::TrialBalance, errors::LedgerError, services::LedgerService};
+
+#[derive(Debug)]
+pub struct GetTrialBalanceCommand {
+  pub company_id: Uuid,
+  /// Include postings up to and including this date; None for all postings
+  pub as_of: Option<NaiveDate>,
+}
+
+pub struct GetTrialBalanceUseCase {
+  ledger_service: Arc<LedgerService>,
+}
+
+impl GetTrialBalanceUseCase {
+  pub fn new(ledger_service: Arc<LedgerService>) -> Self {
+    Self { ledger_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: GetTrialBalanceCommand,
+  ) -> Result<TrialBalance, LedgerError> {
+    self
+      .ledger_service
+      .trial_balance(command.company_id, command.as_of)
+      .await
+  }
+}
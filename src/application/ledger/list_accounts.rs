@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::ledger::{errors::LedgerError, services::LedgerService};
+
+#[derive(Debug)]
+pub struct ListAccountsCommand {
+  pub company_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountSummary {
+  pub id: Uuid,
+  pub code: String,
+  pub name: String,
+  pub account_type: String,
+  pub account_type_label: String,
+}
+
+#[derive(Debug)]
+pub struct ListAccountsResponse {
+  pub accounts: Vec<AccountSummary>,
+}
+
+pub struct ListAccountsUseCase {
+  ledger_service: Arc<LedgerService>,
+}
+
+impl ListAccountsUseCase {
+  pub fn new(ledger_service: Arc<LedgerService>) -> Self {
+    Self { ledger_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListAccountsCommand,
+  ) -> Result<ListAccountsResponse, LedgerError> {
+    let accounts = self
+      .ledger_service
+      .chart_of_accounts(command.company_id)
+      .await?;
+
+    let accounts = accounts
+      .into_iter()
+      .map(|a| AccountSummary {
+        id: a.id,
+        code: a.code,
+        name: a.name,
+        account_type: a.account_type.as_str().to_string(),
+        account_type_label: a.account_type.label().to_string(),
+      })
+      .collect();
+
+    Ok(ListAccountsResponse { accounts })
+  }
+}
@@ -0,0 +1,102 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::ledger::{errors::LedgerError, services::LedgerService};
+
+#[derive(Debug)]
+pub struct ListJournalEntriesCommand {
+  pub company_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JournalLineDetail {
+  pub account_code: String,
+  pub account_name: String,
+  pub debit: Decimal,
+  pub credit: Decimal,
+  pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JournalEntryDetail {
+  pub id: Uuid,
+  pub entry_date: NaiveDate,
+  pub description: String,
+  pub source: String,
+  pub source_label: String,
+  pub source_id: Option<Uuid>,
+  pub reversal_of: Option<Uuid>,
+  /// Whether a later entry reverses this one
+  pub reversed: bool,
+  pub total: Decimal,
+  pub created_at: DateTime<Utc>,
+  pub lines: Vec<JournalLineDetail>,
+}
+
+#[derive(Debug)]
+pub struct ListJournalEntriesResponse {
+  /// Newest first
+  pub entries: Vec<JournalEntryDetail>,
+}
+
+pub struct ListJournalEntriesUseCase {
+  ledger_service: Arc<LedgerService>,
+}
+
+impl ListJournalEntriesUseCase {
+  pub fn new(ledger_service: Arc<LedgerService>) -> Self {
+    Self { ledger_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListJournalEntriesCommand,
+  ) -> Result<ListJournalEntriesResponse, LedgerError> {
+    let accounts = self
+      .ledger_service
+      .chart_of_accounts(command.company_id)
+      .await?;
+    let accounts: HashMap<Uuid, _> = accounts.into_iter().map(|a| (a.id, a)).collect();
+
+    let entries = self.ledger_service.list_entries(command.company_id).await?;
+    let reversed: HashSet<Uuid> = entries.iter().filter_map(|e| e.reversal_of).collect();
+
+    let entries = entries
+      .into_iter()
+      .rev()
+      .map(|entry| JournalEntryDetail {
+        id: entry.id,
+        entry_date: entry.entry_date,
+        source: entry.source.as_str().to_string(),
+        source_label: entry.source.label().to_string(),
+        source_id: entry.source_id,
+        reversal_of: entry.reversal_of,
+        reversed: reversed.contains(&entry.id),
+        total: entry.total(),
+        created_at: entry.created_at,
+        lines: entry
+          .lines
+          .into_iter()
+          .map(|line| {
+            let account = accounts.get(&line.account_id);
+            JournalLineDetail {
+              account_code: account.map(|a| a.code.clone()).unwrap_or_default(),
+              account_name: account.map(|a| a.name.clone()).unwrap_or_default(),
+              debit: line.debit,
+              credit: line.credit,
+              description: line.description,
+            }
+          })
+          .collect(),
+        description: entry.description,
+      })
+      .collect();
+
+    Ok(ListJournalEntriesResponse { entries })
+  }
+}
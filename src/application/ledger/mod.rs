@@ -0,0 +1,20 @@
+mod create_account;
+mod get_trial_balance;
+mod list_accounts;
+mod list_journal_entries;
+mod post_journal_entry;
+mod reverse_journal_entry;
+
+pub use create_account::{CreateAccountCommand, CreateAccountResponse, CreateAccountUseCase};
+pub use get_trial_balance::{GetTrialBalanceCommand, GetTrialBalanceUseCase};
+pub use list_accounts::{
+  AccountSummary, ListAccountsCommand, ListAccountsResponse, ListAccountsUseCase,
+};
+pub use list_journal_entries::{
+  JournalEntryDetail, JournalLineDetail, ListJournalEntriesCommand, ListJournalEntriesResponse,
+  ListJournalEntriesUseCase,
+};
+pub use post_journal_entry::{
+  JournalLineInput, PostJournalEntryCommand, PostJournalEntryResponse, PostJournalEntryUseCase,
+};
+pub use reverse_journal_entry::{ReverseJournalEntryCommand, ReverseJournalEntryUseCase};
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::ledger::{
+  entities::NewJournalLine, errors::LedgerError, services::LedgerService,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct JournalLineInput {
+  pub account_id: Uuid,
+  #[serde(default)]
+  pub debit: Decimal,
+  #[serde(default)]
+  pub credit: Decimal,
+  pub description: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct PostJournalEntryCommand {
+  pub user_id: Uuid,
+  pub company_id: Uuid,
+  pub entry_date: NaiveDate,
+  pub description: String,
+  pub lines: Vec<JournalLineInput>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PostJournalEntryResponse {
+  pub entry_id: Uuid,
+  pub total: Decimal,
+}
+
+pub struct PostJournalEntryUseCase {
+  ledger_service: Arc<LedgerService>,
+}
+
+impl PostJournalEntryUseCase {
+  pub fn new(ledger_service: Arc<LedgerService>) -> Self {
+    Self { ledger_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: PostJournalEntryCommand,
+  ) -> Result<PostJournalEntryResponse, LedgerError> {
+    let lines = command
+      .lines
+      .into_iter()
+      .map(|line| NewJournalLine {
+        account_id: line.account_id,
+        debit: line.debit,
+        credit: line.credit,
+        description: line.description,
+      })
+      .collect();
+
+    let entry = self
+      .ledger_service
+      .post_manual_entry(
+        command.company_id,
+        command.user_id,
+        command.entry_date,
+        command.description,
+        lines,
+      )
+      .await?;
+
+    Ok(PostJournalEntryResponse {
+      entry_id: entry.id,
+      total: entry.total(),
+    })
+  }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::ledger::{errors::LedgerError, services::LedgerService};
+
+#[derive(Debug)]
+pub struct ReverseJournalEntryCommand {
+  pub user_id: Uuid,
+  pub company_id: Uuid,
+  pub entry_id: Uuid,
+}
+
+pub struct ReverseJournalEntryUseCase {
+  ledger_service: Arc<LedgerService>,
+}
+
+impl ReverseJournalEntryUseCase {
+  pub fn new(ledger_service: Arc<LedgerService>) -> Self {
+    Self { ledger_service }
+  }
+
+  /// Posts the reversing entry dated today
+  pub async fn execute(&self, command: ReverseJournalEntryCommand) -> Result<Uuid, LedgerError> {
+    let reversal = self
+      .ledger_service
+      .reverse_entry(
+        command.company_id,
+        command.user_id,
+        command.entry_id,
+        Utc::now().date_naive(),
+      )
+      .await?;
+
+    Ok(reversal.id)
+  }
+}
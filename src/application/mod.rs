@@ -6,5 +6,11 @@
 
 pub mod auth;
 pub mod company;
+pub mod document;
+pub mod financials;
 pub mod invoice;
+pub mod ledger;
 pub mod report;
+pub mod retention;
+pub mod saft;
+pub mod storage;
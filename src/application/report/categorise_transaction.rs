@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::report::{errors::ReportError, services::ReportService};
+
+#[derive(Debug)]
+pub struct CategoriseTransactionCommand {
+  pub company_id: Uuid,
+  pub transaction_id: Uuid,
+  /// None clears the category
+  pub category_id: Option<Uuid>,
+}
+
+pub struct CategoriseTransactionUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl CategoriseTransactionUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(&self, command: CategoriseTransactionCommand) -> Result<(), ReportError> {
+    self
+      .report_service
+      .categorise_transaction(
+        command.company_id,
+        command.transaction_id,
+        command.category_id,
+      )
+      .await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::report::{errors::ReportError, services::ReportService};
+
+#[derive(Debug)]
+pub struct ClearRuleOutcomeCommand {
+  pub company_id: Uuid,
+  pub transaction_id: Uuid,
+}
+
+pub struct ClearRuleOutcomeUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl ClearRuleOutcomeUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(&self, command: ClearRuleOutcomeCommand) -> Result<(), ReportError> {
+    self
+      .report_service
+      .clear_rule_outcome(command.company_id, command.transaction_id)
+      .await
+  }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::report::{
+  entities::{MatchingRule, RuleConditions},
+  errors::ReportError,
+  services::ReportService,
+  value_objects::RuleAction,
+};
+
+#[derive(Debug)]
+pub struct CreateMatchingRuleCommand {
+  pub company_id: Uuid,
+  pub name: String,
+  pub conditions: RuleConditions,
+  pub action: RuleAction,
+}
+
+pub struct CreateMatchingRuleUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl CreateMatchingRuleUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: CreateMatchingRuleCommand,
+  ) -> Result<MatchingRule, ReportError> {
+    self
+      .report_service
+      .create_rule(
+        command.company_id,
+        command.name,
+        command.conditions,
+        command.action,
+      )
+      .await
+  }
+}
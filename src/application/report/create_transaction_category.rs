@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::report::{
+  entities::TransactionCategory, errors::ReportError, services::ReportService,
+};
+
+#[derive(Debug)]
+pub struct CreateTransactionCategoryCommand {
+  pub company_id: Uuid,
+  pub name: String,
+}
+
+pub struct CreateTransactionCategoryUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl CreateTransactionCategoryUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: CreateTransactionCategoryCommand,
+  ) -> Result<TransactionCategory, ReportError> {
+    self
+      .report_service
+      .create_category(command.company_id, &command.name)
+      .await
+  }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::report::{errors::ReportError, services::ReportService};
+
+#[derive(Debug)]
+pub struct DeleteMatchingRuleCommand {
+  pub company_id: Uuid,
+  pub rule_id: Uuid,
+}
+
+pub struct DeleteMatchingRuleUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl DeleteMatchingRuleUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(&self, command: DeleteMatchingRuleCommand) -> Result<(), ReportError> {
+    self
+      .report_service
+      .delete_rule(command.company_id, command.rule_id)
+      .await
+  }
+}
@@ -1,31 +1,87 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::domain::document::{DocumentOwner, DocumentService};
+use crate::domain::ledger::{services::LedgerService, value_objects::JournalSource};
 use crate::domain::report::{errors::ReportError, services::ReportService};
+use crate::domain::retention::{DeletionAttempt, RetainedRecord, RetentionService};
 
 #[derive(Debug)]
 pub struct DeleteReceivedInvoiceCommand {
   pub id: Uuid,
+  pub user_id: Uuid,
 }
 
 pub struct DeleteReceivedInvoiceUseCase {
   report_service: Arc<ReportService>,
+  ledger_service: Arc<LedgerService>,
+  document_service: Arc<DocumentService>,
+  retention_service: Arc<RetentionService>,
 }
 
 impl DeleteReceivedInvoiceUseCase {
-  pub fn new(report_service: Arc<ReportService>) -> Self {
-    Self { report_service }
+  pub fn new(
+    report_service: Arc<ReportService>,
+    ledger_service: Arc<LedgerService>,
+    document_service: Arc<DocumentService>,
+    retention_service: Arc<RetentionService>,
+  ) -> Self {
+    Self {
+      report_service,
+      ledger_service,
+      document_service,
+      retention_service,
+    }
   }
 
   pub async fn execute(&self, command: DeleteReceivedInvoiceCommand) -> Result<(), ReportError> {
+    let invoice = self.report_service.get_received_invoice(command.id).await?;
+
+    // Bills without a date count from their upload
+    self
+      .retention_service
+      .guard_deletion(DeletionAttempt {
+        company_id: invoice.company_id,
+        record: RetainedRecord::ReceivedInvoice,
+        record_id: invoice.id,
+        label: match &invoice.invoice_number {
+          Some(number) => format!("Received invoice {} from {}", number, invoice.vendor_name),
+          None => format!("Received invoice from {}", invoice.vendor_name),
+        },
+        document_date: invoice
+          .invoice_date
+          .unwrap_or_else(|| invoice.created_at.date_naive()),
+        user_id: Some(command.user_id),
+      })
+      .await?;
+
     let pdf_path = self
       .report_service
       .delete_received_invoice(command.id)
       .await?;
 
-    // Delete the PDF file from disk
-    if let Err(e) = tokio::fs::remove_file(&pdf_path).await {
-      tracing::warn!("Failed to delete PDF file {}: {}", pdf_path, e);
+    // Reverse the bill and any payment booked against it
+    for source in [
+      JournalSource::ReceivedInvoice,
+      JournalSource::ReceivedInvoicePayment,
+    ] {
+      self
+        .ledger_service
+        .reverse_source(invoice.company_id, source, invoice.id)
+        .await
+        .map_err(|e| ReportError::Ledger(e.to_string()))?;
+    }
+
+    // The PDF goes with its last link; bills uploaded before the document
+    // store have a file of their own
+    let detached = self
+      .document_service
+      .detach(DocumentOwner::ReceivedInvoice, invoice.id)
+      .await?;
+    if !detached {
+      if let Err(e) = tokio::fs::remove_file(&pdf_path).await {
+        tracing::warn!("Failed to delete PDF file {}: {}", pdf_path, e);
+      }
     }
 
     Ok(())
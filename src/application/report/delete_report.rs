@@ -1,23 +1,89 @@
+use chrono::NaiveDate;
 use std::sync::Arc;
 use uuid::Uuid;
 
+use super::payments::sync_ledger_payments;
+use crate::domain::document::{DocumentOwner, DocumentService};
+use crate::domain::invoice::ports::InvoiceRepository;
+use crate::domain::ledger::services::LedgerService;
 use crate::domain::report::{errors::ReportError, services::ReportService};
+use crate::domain::retention::{DeletionAttempt, RetainedRecord, RetentionService};
 
 #[derive(Debug)]
 pub struct DeleteReportCommand {
   pub report_id: Uuid,
+  pub user_id: Uuid,
 }
 
 pub struct DeleteReportUseCase {
   report_service: Arc<ReportService>,
+  ledger_service: Arc<LedgerService>,
+  invoice_repo: Arc<dyn InvoiceRepository>,
+  document_service: Arc<DocumentService>,
+  retention_service: Arc<RetentionService>,
 }
 
 impl DeleteReportUseCase {
-  pub fn new(report_service: Arc<ReportService>) -> Self {
-    Self { report_service }
+  pub fn new(
+    report_service: Arc<ReportService>,
+    ledger_service: Arc<LedgerService>,
+    invoice_repo: Arc<dyn InvoiceRepository>,
+    document_service: Arc<DocumentService>,
+    retention_service: Arc<RetentionService>,
+  ) -> Self {
+    Self {
+      report_service,
+      ledger_service,
+      invoice_repo,
+      document_service,
+      retention_service,
+    }
   }
 
   pub async fn execute(&self, command: DeleteReportCommand) -> Result<(), ReportError> {
-    self.report_service.delete_report(command.report_id).await
+    let (report, transactions) = self
+      .report_service
+      .get_report_details(command.report_id)
+      .await?;
+
+    // A report without transactions holds no statement or receipts to keep
+    if !transactions.is_empty() {
+      if let Some(first_day) = NaiveDate::from_ymd_opt(report.year, report.month, 1) {
+        self
+          .retention_service
+          .guard_deletion(DeletionAttempt {
+            company_id: report.company_id,
+            record: RetainedRecord::Report,
+            record_id: report.id,
+            label: format!("Report {:02}.{}", report.month, report.year),
+            document_date: first_day,
+            user_id: Some(command.user_id),
+          })
+          .await?;
+      }
+    }
+
+    self.report_service.delete_report(command.report_id).await?;
+
+    for tx in transactions.iter().filter(|tx| tx.receipt_path.is_some()) {
+      self
+        .document_service
+        .detach(DocumentOwner::TransactionReceipt, tx.id)
+        .await?;
+    }
+
+    // Deleting the statement drops its allocations; reverse the payments they booked
+    let allocations: Vec<_> = transactions
+      .into_iter()
+      .flat_map(|tx| tx.allocations)
+      .collect();
+    sync_ledger_payments(
+      &self.report_service,
+      &self.ledger_service,
+      self.invoice_repo.as_ref(),
+      report.company_id,
+      &allocations,
+    )
+    .await
   }
 }
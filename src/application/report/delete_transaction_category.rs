@@ -0,0 +1,30 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::report::{errors::ReportError, services::ReportService};
+
+#[derive(Debug)]
+pub struct DeleteTransactionCategoryCommand {
+  pub company_id: Uuid,
+  pub category_id: Uuid,
+}
+
+pub struct DeleteTransactionCategoryUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl DeleteTransactionCategoryUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: DeleteTransactionCategoryCommand,
+  ) -> Result<(), ReportError> {
+    self
+      .report_service
+      .delete_category(command.company_id, command.category_id)
+      .await
+  }
+}
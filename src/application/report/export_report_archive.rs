@@ -0,0 +1,276 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use super::report_files::report_layout;
+use crate::domain::company::ports::BankAccountRepository;
+use crate::domain::document::DocumentService;
+use crate::domain::invoice::ports::InvoiceRepository;
+use crate::domain::report::{
+  entities::BankTransaction,
+  errors::ReportError,
+  ports::{ArchiveEntry, ReportArchiveWriter, ReportIndexRow, ReportIndexWriter},
+  services::ReportService,
+  value_objects::{ReportMonth, TransactionDirection},
+};
+
+#[derive(Debug)]
+pub struct ExportReportArchiveCommand {
+  pub report_id: Uuid,
+  pub company_id: Uuid,
+  /// Summary page put at the root of the archive, e.g. "summary.pdf"
+  pub cover_sheet: ArchiveEntry,
+}
+
+#[derive(Debug)]
+pub struct ExportReportArchiveResponse {
+  pub file_name: String,
+  pub content: Vec<u8>,
+}
+
+/// How the report's transactions are accounted for, for the cover sheet
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReportArchiveSummary {
+  /// Matched to invoices in full, or to a receipt
+  pub matched_count: usize,
+  /// Matched, categorised or ignored
+  pub resolved_count: usize,
+  /// Open transactions with part of their amount matched
+  pub partly_matched_count: usize,
+  /// Transactions still to be accounted for, in report order
+  pub open_transaction_ids: Vec<Uuid>,
+}
+
+impl ReportArchiveSummary {
+  pub fn of(transactions: &[BankTransaction]) -> Self {
+    let mut summary = Self::default();
+    for tx in transactions {
+      if tx.is_matched() {
+        summary.matched_count += 1;
+      }
+      if tx.is_resolved() {
+        summary.resolved_count += 1;
+        continue;
+      }
+      if !tx.allocations.is_empty() {
+        summary.partly_matched_count += 1;
+      }
+      summary.open_transaction_ids.push(tx.id);
+    }
+    summary
+  }
+}
+
+/// Packs a report into a ZIP laid out like its cloud storage folder, so it
+/// can be handed over without a Google Drive connection
+pub struct ExportReportArchiveUseCase {
+  report_service: Arc<ReportService>,
+  invoice_repo: Arc<dyn InvoiceRepository>,
+  bank_account_repo: Arc<dyn BankAccountRepository>,
+  index_writer: Arc<dyn ReportIndexWriter>,
+  archive_writer: Arc<dyn ReportArchiveWriter>,
+  document_service: Arc<DocumentService>,
+}
+
+impl ExportReportArchiveUseCase {
+  pub fn new(
+    report_service: Arc<ReportService>,
+    invoice_repo: Arc<dyn InvoiceRepository>,
+    bank_account_repo: Arc<dyn BankAccountRepository>,
+    index_writer: Arc<dyn ReportIndexWriter>,
+    archive_writer: Arc<dyn ReportArchiveWriter>,
+    document_service: Arc<DocumentService>,
+  ) -> Self {
+    Self {
+      report_service,
+      invoice_repo,
+      bank_account_repo,
+      index_writer,
+      archive_writer,
+      document_service,
+    }
+  }
+
+  /// Summary of the company's report for the archive's cover sheet
+  pub async fn summary(
+    &self,
+    report_id: Uuid,
+    company_id: Uuid,
+  ) -> Result<ReportArchiveSummary, ReportError> {
+    let (report, transactions) = self.report_service.get_report_details(report_id).await?;
+    if report.company_id != company_id {
+      return Err(ReportError::NotFound);
+    }
+    Ok(ReportArchiveSummary::of(&transactions))
+  }
+
+  pub async fn execute(
+    &self,
+    command: ExportReportArchiveCommand,
+  ) -> Result<ExportReportArchiveResponse, ReportError> {
+    let (report, transactions) = self
+      .report_service
+      .get_report_details(command.report_id)
+      .await?;
+    if report.company_id != command.company_id {
+      return Err(ReportError::NotFound);
+    }
+
+    let layout = report_layout(
+      &self.report_service,
+      self.invoice_repo.as_ref(),
+      self.bank_account_repo.as_ref(),
+      &report,
+      &transactions,
+    )
+    .await?;
+
+    let mut entries = vec![command.cover_sheet];
+
+    // The statements as they were uploaded, in their account's folder
+    for (statement, folder) in &layout.accounts {
+      if let Some(file_path) = &statement.file_path {
+        let file_name = statement.file_name.as_deref().unwrap_or("statement.csv");
+        entries.push(ArchiveEntry {
+          path: format!("{}/{}", folder, file_name),
+          content: self.document_service.read_file(file_path).await?,
+        });
+      }
+    }
+
+    // Invoice PDFs, in the same incoming/ and outcoming/ folders as on Drive
+    for file in layout.documents.iter().filter_map(|d| d.file.as_ref()) {
+      entries.push(ArchiveEntry {
+        path: file.path(),
+        content: self.document_service.read_file(&file.local_path).await?,
+      });
+    }
+
+    let categories: HashMap<Uuid, String> = self
+      .report_service
+      .list_categories(report.company_id)
+      .await?
+      .into_iter()
+      .map(|c| (c.id, c.name))
+      .collect();
+
+    let mut rows = Vec::new();
+    for tx in &transactions {
+      let row = ReportIndexRow {
+        date: tx.date,
+        account: layout.account_folder(tx.statement_id).to_string(),
+        counterparty: tx.counterparty_name.clone(),
+        description: tx.description.clone(),
+        amount: match tx.direction {
+          TransactionDirection::Credit => tx.amount.abs(),
+          TransactionDirection::Debit => -tx.amount.abs(),
+        },
+        currency: tx.currency.clone(),
+        status: status(tx).to_string(),
+        category: tx.category_id.and_then(|id| categories.get(&id).cloned()),
+        document: None,
+        allocated: None,
+        file: None,
+      };
+
+      let documents: Vec<_> = layout
+        .documents
+        .iter()
+        .filter(|d| d.transaction_id == tx.id)
+        .collect();
+      if documents.is_empty() {
+        rows.push(row);
+        continue;
+      }
+      for document in documents {
+        rows.push(ReportIndexRow {
+          document: Some(document.label.clone()),
+          allocated: document.allocated,
+          file: document.file.as_ref().map(|f| f.path()),
+          ..row.clone()
+        });
+      }
+    }
+    entries.push(ArchiveEntry {
+      path: "index.csv".to_string(),
+      content: self.index_writer.write(&rows)?,
+    });
+
+    let period = ReportMonth::new(report.month, report.year)?;
+    Ok(ExportReportArchiveResponse {
+      file_name: format!("report-{}.zip", period.folder_name()),
+      content: self.archive_writer.write(&entries)?,
+    })
+  }
+}
+
+/// How the transaction was accounted for, as shown in the index
+fn status(tx: &BankTransaction) -> &'static str {
+  if !tx.allocations.is_empty() {
+    if tx.is_fully_allocated() {
+      "Matched"
+    } else {
+      "Partly matched"
+    }
+  } else if tx.receipt_path.is_some() {
+    "Receipt"
+  } else if tx.category_id.is_some() {
+    "Categorised"
+  } else if tx.ignored {
+    "Ignored"
+  } else if tx.needs_review {
+    "Needs review"
+  } else {
+    "Unmatched"
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::NaiveDate;
+  use rust_decimal_macros::dec;
+
+  fn transaction(amount: rust_decimal::Decimal) -> BankTransaction {
+    BankTransaction::new(
+      Uuid::new_v4(),
+      1,
+      NaiveDate::from_ymd_opt(2026, 3, 10).unwrap(),
+      None,
+      None,
+      TransactionDirection::Debit,
+      amount,
+      None,
+      None,
+      "EUR".to_string(),
+      None,
+    )
+  }
+
+  #[test]
+  fn test_summary_counts_receipts_as_matched() {
+    let mut invoice = transaction(dec!(-100));
+    invoice
+      .allocate(None, Some(Uuid::new_v4()), dec!(100))
+      .unwrap();
+    let mut receipt = transaction(dec!(-12.50));
+    receipt.receipt_path = Some("receipt.pdf".to_string());
+    let mut categorised = transaction(dec!(-2));
+    categorised.category_id = Some(Uuid::new_v4());
+    let mut partly = transaction(dec!(-80));
+    partly
+      .allocate(None, Some(Uuid::new_v4()), dec!(30))
+      .unwrap();
+    let open = transaction(dec!(-5));
+
+    let summary =
+      ReportArchiveSummary::of(&[invoice, receipt, categorised, partly.clone(), open.clone()]);
+
+    assert_eq!(summary.matched_count, 2);
+    assert_eq!(summary.resolved_count, 3);
+    assert_eq!(summary.partly_matched_count, 1);
+    assert_eq!(summary.open_transaction_ids, [partly.id, open.id]);
+  }
+}
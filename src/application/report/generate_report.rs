@@ -1,26 +1,47 @@
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::domain::company::ports::CompanyRepository;
+use super::report_files::{NO_STATEMENT_FOLDER, report_layout};
+use super::uploads::{due_report_files, finish_generation, push_report_files};
+use crate::domain::company::StorageProvider;
+use crate::domain::company::ports::{BankAccountRepository, CompanyRepository};
+use crate::domain::document::DocumentService;
 use crate::domain::invoice::ports::InvoiceRepository;
 use crate::domain::report::{
+  entities::{MonthlyReport, ReportUpload},
   errors::ReportError,
-  ports::ReportCloudStorage,
+  ports::{ReportCloudStorage, ReportStorageResolver},
   services::ReportService,
-  value_objects::{ReportMonth, ReportStatus, TransactionDirection},
+  value_objects::{ReportMonth, ReportStatus},
 };
+use crate::domain::storage::{UploadAction, UploadJob, UploadOutboxService, UploadTarget};
 
 #[derive(Debug)]
 pub struct GenerateReportCommand {
   pub report_id: Uuid,
   pub company_id: Uuid,
+  pub user_id: Uuid,
+}
+
+#[derive(Debug)]
+pub struct GenerateReportResponse {
+  pub drive_folder_id: String,
+  /// Whether every file is uploaded and the report marked generated
+  pub generated: bool,
+  /// Files left in the upload outbox, to be retried
+  pub open_uploads: usize,
 }
 
 pub struct GenerateReportUseCase {
   report_service: Arc<ReportService>,
   company_repo: Arc<dyn CompanyRepository>,
   invoice_repo: Arc<dyn InvoiceRepository>,
-  cloud_storage: Arc<dyn ReportCloudStorage>,
+  bank_account_repo: Arc<dyn BankAccountRepository>,
+  storage_resolver: Arc<dyn ReportStorageResolver>,
+  outbox: Arc<UploadOutboxService>,
+  document_service: Arc<DocumentService>,
 }
 
 impl GenerateReportUseCase {
@@ -28,17 +49,26 @@ impl GenerateReportUseCase {
     report_service: Arc<ReportService>,
     company_repo: Arc<dyn CompanyRepository>,
     invoice_repo: Arc<dyn InvoiceRepository>,
-    cloud_storage: Arc<dyn ReportCloudStorage>,
+    bank_account_repo: Arc<dyn BankAccountRepository>,
+    storage_resolver: Arc<dyn ReportStorageResolver>,
+    outbox: Arc<UploadOutboxService>,
+    document_service: Arc<DocumentService>,
   ) -> Self {
     Self {
       report_service,
       company_repo,
       invoice_repo,
-      cloud_storage,
+      bank_account_repo,
+      storage_resolver,
+      outbox,
+      document_service,
     }
   }
 
-  pub async fn execute(&self, command: GenerateReportCommand) -> Result<String, ReportError> {
+  pub async fn execute(
+    &self,
+    command: GenerateReportCommand,
+  ) -> Result<GenerateReportResponse, ReportError> {
     let (report, transactions) = self
       .report_service
       .get_report_details(command.report_id)
@@ -48,8 +78,10 @@ impl GenerateReportUseCase {
       return Err(ReportError::NotDraft);
     }
 
-    let matched: Vec<_> = transactions.iter().filter(|t| t.is_matched()).collect();
-    if matched.is_empty() {
+    if !transactions
+      .iter()
+      .any(|t| t.is_matched() || !t.allocations.is_empty())
+    {
       return Err(ReportError::NoMatchedTransactions);
     }
 
@@ -67,76 +99,215 @@ impl GenerateReportUseCase {
       )
     })?;
 
+    let cloud_storage = self.storage_resolver.for_company(report.company_id).await?;
+    let provider = company
+      .storage_provider
+      .as_ref()
+      .and_then(|p| p.parse::<StorageProvider>().ok())
+      .unwrap_or_default();
+
+    // A regenerated report reuses the folders and unchanged files of its
+    // earlier versions
+    let mut uploads: HashMap<String, ReportUpload> = self
+      .report_service
+      .get_uploads(report.id)
+      .await?
+      .into_iter()
+      .map(|u| (u.path.clone(), u))
+      .collect();
+
     // Create MM.YYYY folder
-    let period = ReportMonth::new(report.month, report.year)?;
-    let month_folder_id = self
-      .cloud_storage
-      .create_folder(&reports_folder_id, &period.folder_name())
-      .await?;
+    let month_folder_id = match &report.drive_folder_id {
+      Some(folder_id) => folder_id.clone(),
+      None => {
+        let period = ReportMonth::new(report.month, report.year)?;
+        let folder_id = cloud_storage
+          .create_folder(&reports_folder_id, &period.folder_name())
+          .await?;
+        self
+          .report_service
+          .set_drive_folder(report.id, folder_id.clone())
+          .await?;
+        folder_id
+      }
+    };
 
-    // Create incoming/ and outcoming/ subfolders
-    let incoming_folder_id = self
-      .cloud_storage
-      .create_folder(&month_folder_id, "incoming")
-      .await?;
-    let outcoming_folder_id = self
-      .cloud_storage
-      .create_folder(&month_folder_id, "outcoming")
-      .await?;
+    // One folder per bank account, each with incoming/ and outcoming/
+    // subfolders
+    let layout = report_layout(
+      &self.report_service,
+      self.invoice_repo.as_ref(),
+      self.bank_account_repo.as_ref(),
+      &report,
+      &transactions,
+    )
+    .await?;
+    if layout.without_statement > 0 {
+      tracing::warn!(
+        "Report {}: {} transaction(s) on no statement filed under \"{}\"",
+        report.id,
+        layout.without_statement,
+        NO_STATEMENT_FOLDER
+      );
+    }
+    for folder_name in layout.account_folders() {
+      let account_folder_id = self
+        .folder(
+          cloud_storage.as_ref(),
+          &report,
+          &mut uploads,
+          &month_folder_id,
+          folder_name,
+        )
+        .await?;
+      for subfolder in ["incoming", "outcoming"] {
+        let path = format!("{}/{}", folder_name, subfolder);
+        self
+          .folder(
+            cloud_storage.as_ref(),
+            &report,
+            &mut uploads,
+            &account_folder_id,
+            &path,
+          )
+          .await?;
+      }
+    }
+
+    // Files queued by an earlier attempt that are no longer part of the
+    // report, and deletions of files that are part of it again
+    let files: Vec<_> = layout
+      .documents
+      .iter()
+      .filter_map(|d| d.file.as_ref())
+      .collect();
+    let paths: Vec<String> = files.iter().map(|f| f.path()).collect();
+    for job in self
+      .outbox
+      .open_jobs(UploadTarget::ReportFile, report.id)
+      .await?
+    {
+      let dropped = match job.action {
+        UploadAction::Upload => !files.iter().any(|f| f.key == job.file_key),
+        UploadAction::Delete => paths.contains(&job.path),
+      };
+      if dropped {
+        self.outbox.discard(job).await?;
+      }
+    }
+
+    // Files an earlier version uploaded that the report no longer has, e.g.
+    // a receipt unmatched after reopening, are removed from the storage
+    for upload in uploads
+      .values()
+      .filter(|u| u.content_hash.is_some() && !paths.contains(&u.path))
+    {
+      let mut job = UploadJob::deletion(
+        report.company_id,
+        UploadTarget::ReportFile,
+        report.id,
+        upload.path.clone(),
+        upload.cloud_id.clone(),
+        command.user_id,
+      );
+      job.provider = provider.clone();
+      self.outbox.enqueue(job).await?;
+    }
 
-    // Upload matched PDFs
-    for tx in &matched {
-      match tx.direction {
-        TransactionDirection::Credit => {
-          // Incoming money = issued invoice paid
-          if let Some(invoice_id) = tx.matched_invoice_id {
-            if let Ok(Some(invoice)) = self.invoice_repo.find_by_id(invoice_id).await {
-              if let Some(pdf_path) = &invoice.pdf_path {
-                let file_name = format!(
-                  "{} - {}.pdf",
-                  tx.date.format("%Y-%m-%d"),
-                  tx.counterparty_name.as_deref().unwrap_or("unknown")
-                );
-                let _ = self
-                  .cloud_storage
-                  .upload_file(&incoming_folder_id, &file_name, pdf_path, "application/pdf")
-                  .await;
-              }
-            }
-          }
-        }
-        TransactionDirection::Debit => {
-          // Outgoing money = received invoice (bill) paid
-          if let Some(received_id) = tx.matched_received_invoice_id {
-            if let Ok(invoice) = self.report_service.get_received_invoice(received_id).await {
-              let file_name = format!(
-                "{} - {}.pdf",
-                tx.date.format("%Y-%m-%d"),
-                tx.counterparty_name
-                  .as_deref()
-                  .unwrap_or(&invoice.vendor_name)
-              );
-              let _ = self
-                .cloud_storage
-                .upload_file(
-                  &outcoming_folder_id,
-                  &file_name,
-                  &invoice.pdf_path,
-                  "application/pdf",
-                )
-                .await;
-            }
-          }
-        }
+    // Queue the PDF of every allocated invoice, partly paid ones included,
+    // unless an earlier version already uploaded the same content
+    for file in files {
+      let path = file.path();
+      let content_hash = self
+        .document_service
+        .read_file(&file.local_path)
+        .await
+        .ok()
+        .map(|bytes| hex::encode(Sha256::digest(&bytes)));
+      let uploaded = uploads.get(&path);
+      if content_hash.is_some()
+        && uploaded.and_then(|u| u.content_hash.as_ref()) == content_hash.as_ref()
+      {
+        continue;
       }
+
+      let folder_id = uploads
+        .get(&file.folder)
+        .map(|folder| folder.cloud_id.clone())
+        .ok_or_else(|| {
+          ReportError::CloudStorage(format!("Folder {} was not created", file.folder))
+        })?;
+      let mut job = UploadJob::new(
+        report.company_id,
+        UploadTarget::ReportFile,
+        report.id,
+        path,
+        folder_id,
+        file.local_path.clone(),
+        command.user_id,
+      );
+      job.file_key = file.key.clone();
+      job.provider = provider.clone();
+      job.replace_file_id = uploaded.map(|u| u.cloud_id.clone());
+      job.content_hash = content_hash;
+      self.outbox.enqueue(job).await?;
+    }
+
+    // Upload right away; what fails stays queued and is retried with backoff.
+    // The report only counts as generated once every file is uploaded.
+    let jobs = due_report_files(&self.outbox, report.id).await?;
+    push_report_files(
+      &self.report_service,
+      &self.outbox,
+      &self.document_service,
+      cloud_storage.as_ref(),
+      &report,
+      jobs,
+    )
+    .await?;
+    let open_uploads = finish_generation(
+      &self.report_service,
+      &self.outbox,
+      report.id,
+      command.user_id,
+    )
+    .await?;
+
+    Ok(GenerateReportResponse {
+      drive_folder_id: month_folder_id,
+      generated: open_uploads == 0,
+      open_uploads,
+    })
+  }
+
+  /// Folder at `path` below the month folder, created the first time the
+  /// report is generated
+  async fn folder(
+    &self,
+    cloud_storage: &dyn ReportCloudStorage,
+    report: &MonthlyReport,
+    uploads: &mut HashMap<String, ReportUpload>,
+    parent_id: &str,
+    path: &str,
+  ) -> Result<String, ReportError> {
+    if let Some(upload) = uploads.get(path) {
+      return Ok(upload.cloud_id.clone());
     }
 
-    // Mark report as generated
-    self
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let folder_id = cloud_storage.create_folder(parent_id, name).await?;
+    let upload = self
       .report_service
-      .mark_generated(command.report_id, month_folder_id.clone())
+      .record_upload(ReportUpload::new(
+        report.id,
+        path.to_string(),
+        folder_id.clone(),
+        None,
+        report.version + 1,
+      ))
       .await?;
+    uploads.insert(upload.path.clone(), upload);
 
-    Ok(month_folder_id)
+    Ok(folder_id)
   }
 }
@@ -1,20 +1,52 @@
 use std::sync::Arc;
 
+use std::collections::HashMap;
+
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::Serialize;
 use uuid::Uuid;
 
-use crate::domain::report::{errors::ReportError, services::ReportService};
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::ports::BankAccountRepository;
+use crate::domain::report::{
+  entities::MatchAllocation, errors::ReportError, services::ReportService,
+};
 
 #[derive(Debug)]
 pub struct GetReportDetailsCommand {
   pub report_id: Uuid,
 }
 
+/// One account's statement with its subtotals
+#[derive(Debug, Clone, Serialize)]
+pub struct StatementDetail {
+  pub id: Uuid,
+  pub bank_account_id: Option<Uuid>,
+  /// Name of the registered bank account the statement is linked to
+  pub bank_account_name: Option<String>,
+  pub bank_account_iban: String,
+  pub total_incoming: Decimal,
+  pub total_outgoing: Decimal,
+  pub transaction_count: i32,
+  pub matched_count: i32,
+  pub opening_balance: Option<Decimal>,
+  pub closing_balance: Option<Decimal>,
+  /// Opening balance plus credits minus debits, less the closing balance
+  pub balance_difference: Option<Decimal>,
+  pub is_balanced: bool,
+  pub period_start: NaiveDate,
+  pub period_end: NaiveDate,
+  /// Rows skipped on import because an overlapping statement had them
+  pub duplicate_count: i32,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct TransactionDetail {
   pub id: Uuid,
+  pub statement_id: Option<Uuid>,
+  /// IBAN of the account whose statement the transaction is on
+  pub bank_account_iban: Option<String>,
   pub row_number: i32,
   pub date: NaiveDate,
   pub counterparty_name: Option<String>,
@@ -25,11 +57,20 @@ pub struct TransactionDetail {
   pub description: Option<String>,
   pub currency: String,
   pub registry_code: Option<String>,
-  pub matched_invoice_id: Option<Uuid>,
-  pub matched_received_invoice_id: Option<Uuid>,
+  pub allocations: Vec<MatchAllocation>,
+  pub allocated_amount: Decimal,
+  /// Part of the amount not yet allocated to an invoice
+  pub unallocated_amount: Decimal,
+  /// Allocated to invoices, but not for its full amount
+  pub is_partially_matched: bool,
   pub is_matched: bool,
   pub receipt_path: Option<String>,
   pub has_receipt: bool,
+  pub category_id: Option<Uuid>,
+  pub rule_id: Option<Uuid>,
+  pub ignored: bool,
+  pub needs_review: bool,
+  pub is_resolved: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -39,23 +80,36 @@ pub struct GetReportDetailsResponse {
   pub month: u32,
   pub year: i32,
   pub status: String,
-  pub bank_account_iban: Option<String>,
   pub total_incoming: Decimal,
   pub total_outgoing: Decimal,
   pub transaction_count: i32,
   pub matched_count: i32,
   pub drive_folder_id: Option<String>,
+  /// Number of times the report was generated
+  pub version: i32,
+  /// One statement per bank account, ordered by IBAN
+  pub statements: Vec<StatementDetail>,
   pub transactions: Vec<TransactionDetail>,
+  /// Amount allocated so far to each of the company's issued and received
+  /// invoices, across all reports, keyed by invoice ID
+  pub allocated_by_document: HashMap<Uuid, Decimal>,
   pub created_at: DateTime<Utc>,
 }
 
 pub struct GetReportDetailsUseCase {
   report_service: Arc<ReportService>,
+  bank_account_repo: Arc<dyn BankAccountRepository>,
 }
 
 impl GetReportDetailsUseCase {
-  pub fn new(report_service: Arc<ReportService>) -> Self {
-    Self { report_service }
+  pub fn new(
+    report_service: Arc<ReportService>,
+    bank_account_repo: Arc<dyn BankAccountRepository>,
+  ) -> Self {
+    Self {
+      report_service,
+      bank_account_repo,
+    }
   }
 
   pub async fn execute(
@@ -66,14 +120,28 @@ impl GetReportDetailsUseCase {
       .report_service
       .get_report_details(command.report_id)
       .await?;
+    let statements = self.report_service.get_statements(report.id).await?;
+    let ibans: HashMap<Uuid, &str> = statements
+      .iter()
+      .map(|s| (s.id, s.bank_account_iban.as_str()))
+      .collect();
 
     let tx_details: Vec<TransactionDetail> = transactions
       .into_iter()
       .map(|t| {
         let is_matched = t.is_matched();
         let has_receipt = t.receipt_path.is_some();
+        let is_resolved = t.is_resolved();
+        let allocated_amount = t.allocated_amount();
+        let unallocated_amount = t.unallocated_amount();
+        let is_partially_matched = !t.allocations.is_empty() && !t.is_fully_allocated();
         TransactionDetail {
           id: t.id,
+          statement_id: t.statement_id,
+          bank_account_iban: t
+            .statement_id
+            .and_then(|id| ibans.get(&id))
+            .map(|iban| iban.to_string()),
           row_number: t.row_number,
           date: t.date,
           counterparty_name: t.counterparty_name,
@@ -84,28 +152,74 @@ impl GetReportDetailsUseCase {
           description: t.description,
           currency: t.currency,
           registry_code: t.registry_code,
-          matched_invoice_id: t.matched_invoice_id,
-          matched_received_invoice_id: t.matched_received_invoice_id,
+          allocations: t.allocations,
+          allocated_amount,
+          unallocated_amount,
+          is_partially_matched,
           is_matched,
           receipt_path: t.receipt_path,
           has_receipt,
+          category_id: t.category_id,
+          rule_id: t.rule_id,
+          ignored: t.ignored,
+          needs_review: t.needs_review,
+          is_resolved,
         }
       })
       .collect();
 
+    let allocated_by_document = self
+      .report_service
+      .allocated_by_document(report.company_id)
+      .await?;
+
+    let bank_accounts = self
+      .bank_account_repo
+      .find_by_company_id(report.company_id, true)
+      .await
+      .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?;
+    let statement_details = statements
+      .iter()
+      .map(|s| StatementDetail {
+        id: s.id,
+        bank_account_id: s.bank_account_id,
+        bank_account_name: bank_accounts
+          .iter()
+          .find(|a| Some(a.id) == s.bank_account_id)
+          .map(|a| a.name.as_str().to_string()),
+        bank_account_iban: s.bank_account_iban.clone(),
+        total_incoming: s.total_incoming,
+        total_outgoing: s.total_outgoing,
+        transaction_count: s.transaction_count,
+        matched_count: tx_details
+          .iter()
+          .filter(|t| t.statement_id == Some(s.id) && t.is_resolved)
+          .count() as i32,
+        opening_balance: s.opening_balance,
+        closing_balance: s.closing_balance,
+        balance_difference: s.balance_difference(),
+        is_balanced: s.is_balanced(),
+        period_start: s.period_start,
+        period_end: s.period_end,
+        duplicate_count: s.duplicate_count,
+      })
+      .collect();
+
     Ok(GetReportDetailsResponse {
       id: report.id,
       company_id: report.company_id,
       month: report.month,
       year: report.year,
       status: report.status.as_str().to_string(),
-      bank_account_iban: report.bank_account_iban,
       total_incoming: report.total_incoming,
       total_outgoing: report.total_outgoing,
       transaction_count: report.transaction_count,
       matched_count: report.matched_count,
       drive_folder_id: report.drive_folder_id,
+      version: report.version,
+      statements: statement_details,
       transactions: tx_details,
+      allocated_by_document,
       created_at: report.created_at,
     })
   }
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::domain::report::{
+  entities::{BankTransaction, ReceivedInvoice, TransactionCategory, Vendor},
+  errors::ReportError,
+  services::ReportService,
+};
+
+#[derive(Debug)]
+pub struct GetVendorDetailsCommand {
+  pub company_id: Uuid,
+  pub vendor_id: Uuid,
+}
+
+#[derive(Debug)]
+pub struct GetVendorDetailsResponse {
+  pub vendor: Vendor,
+  pub invoices: Vec<ReceivedInvoice>,
+  pub transactions: Vec<BankTransaction>,
+  /// Paid to the vendor over all linked transactions
+  pub paid_total: Decimal,
+  /// Still to pay on the vendor's open bills
+  pub open_amount: Decimal,
+  pub categories: Vec<TransactionCategory>,
+  /// The company's other vendors, to merge this one into
+  pub other_vendors: Vec<Vendor>,
+}
+
+pub struct GetVendorDetailsUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl GetVendorDetailsUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: GetVendorDetailsCommand,
+  ) -> Result<GetVendorDetailsResponse, ReportError> {
+    let vendor = self
+      .report_service
+      .get_vendor(command.company_id, command.vendor_id)
+      .await?;
+    let invoices = self.report_service.vendor_invoices(vendor.id).await?;
+    let transactions = self.report_service.vendor_transactions(vendor.id).await?;
+    let categories = self
+      .report_service
+      .list_categories(command.company_id)
+      .await?;
+    let other_vendors = self
+      .report_service
+      .list_vendors(command.company_id)
+      .await?
+      .into_iter()
+      .filter(|v| v.id != vendor.id)
+      .collect();
+
+    let paid_total = transactions.iter().map(|t| t.amount.abs()).sum();
+    let open_amount = invoices
+      .iter()
+      .filter(|i| i.is_open())
+      .map(|i| i.amount)
+      .sum();
+
+    Ok(GetVendorDetailsResponse {
+      vendor,
+      invoices,
+      transactions,
+      paid_total,
+      open_amount,
+      categories,
+      other_vendors,
+    })
+  }
+}
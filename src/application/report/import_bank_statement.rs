@@ -4,6 +4,11 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
+use super::payments::sync_ledger_payments;
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::{ports::BankAccountRepository, value_objects::Iban};
+use crate::domain::invoice::ports::InvoiceRepository;
+use crate::domain::ledger::services::LedgerService;
 use crate::domain::report::{
   errors::ReportError, ports::BankStatementParser, services::ReportService,
   value_objects::ReportMonth,
@@ -15,6 +20,9 @@ pub struct ImportBankStatementCommand {
   pub month: u32,
   pub year: i32,
   pub csv_content: Vec<u8>,
+  /// Where the uploaded file was saved, and its original name
+  pub file_path: Option<String>,
+  pub file_name: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -30,13 +38,25 @@ pub struct ImportBankStatementResponse {
 
 pub struct ImportBankStatementUseCase {
   report_service: Arc<ReportService>,
+  ledger_service: Arc<LedgerService>,
+  invoice_repo: Arc<dyn InvoiceRepository>,
+  bank_account_repo: Arc<dyn BankAccountRepository>,
   parser: Arc<dyn BankStatementParser>,
 }
 
 impl ImportBankStatementUseCase {
-  pub fn new(report_service: Arc<ReportService>, parser: Arc<dyn BankStatementParser>) -> Self {
+  pub fn new(
+    report_service: Arc<ReportService>,
+    ledger_service: Arc<LedgerService>,
+    invoice_repo: Arc<dyn InvoiceRepository>,
+    bank_account_repo: Arc<dyn BankAccountRepository>,
+    parser: Arc<dyn BankStatementParser>,
+  ) -> Self {
     Self {
       report_service,
+      ledger_service,
+      invoice_repo,
+      bank_account_repo,
       parser,
     }
   }
@@ -47,13 +67,49 @@ impl ImportBankStatementUseCase {
   ) -> Result<ImportBankStatementResponse, ReportError> {
     let period = ReportMonth::new(command.month, command.year)?;
 
-    let transactions = self.parser.parse(&command.csv_content)?;
+    let statement = self.parser.parse(&command.csv_content)?;
+
+    // Link the statement to the company's bank account with its IBAN
+    let bank_account = match statement
+      .account_iban()
+      .and_then(|iban| Iban::new(iban).ok())
+    {
+      Some(iban) => self
+        .bank_account_repo
+        .find_by_iban(command.company_id, iban.as_str())
+        .await
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      None => None,
+    };
 
     let report = self
       .report_service
-      .import_bank_statement(command.company_id, period, transactions)
+      .import_bank_statement(
+        command.company_id,
+        period,
+        statement,
+        bank_account.map(|account| account.id),
+        command.file_path,
+        command.file_name,
+      )
       .await?;
 
+    // Book payments for vendor bills allocated automatically during import,
+    // including ones only partly paid by a transaction
+    let (_, transactions) = self.report_service.get_report_details(report.id).await?;
+    let allocations: Vec<_> = transactions
+      .into_iter()
+      .flat_map(|tx| tx.allocations)
+      .collect();
+    sync_ledger_payments(
+      &self.report_service,
+      &self.ledger_service,
+      self.invoice_repo.as_ref(),
+      report.company_id,
+      &allocations,
+    )
+    .await?;
+
     Ok(ImportBankStatementResponse {
       report_id: report.id,
       month: report.month,
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::report::{entities::MatchingRule, errors::ReportError, services::ReportService};
+
+#[derive(Debug)]
+pub struct ListMatchingRulesCommand {
+  pub company_id: Uuid,
+}
+
+#[derive(Debug)]
+pub struct ListMatchingRulesResponse {
+  /// In evaluation order
+  pub rules: Vec<MatchingRule>,
+}
+
+pub struct ListMatchingRulesUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl ListMatchingRulesUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListMatchingRulesCommand,
+  ) -> Result<ListMatchingRulesResponse, ReportError> {
+    let rules = self.report_service.list_rules(command.company_id).await?;
+
+    Ok(ListMatchingRulesResponse { rules })
+  }
+}
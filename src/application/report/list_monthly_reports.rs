@@ -5,7 +5,7 @@ use rust_decimal::Decimal;
 use serde::Serialize;
 use uuid::Uuid;
 
-use crate::domain::report::{errors::ReportError, services::ReportService};
+use crate::domain::report::{StatementGap, errors::ReportError, services::ReportService};
 
 #[derive(Debug)]
 pub struct ListMonthlyReportsCommand {
@@ -18,17 +18,26 @@ pub struct ReportSummary {
   pub month: u32,
   pub year: i32,
   pub status: String,
-  pub bank_account_iban: Option<String>,
+  /// Number of times the report was generated
+  pub version: i32,
+  /// Accounts with a statement in the report
+  pub bank_account_ibans: Vec<String>,
   pub total_incoming: Decimal,
   pub total_outgoing: Decimal,
   pub transaction_count: i32,
   pub matched_count: i32,
+  /// Whether any statement has both balances to reconcile against
+  pub balance_checked: bool,
+  /// Every statement with both balances adds up
+  pub is_balanced: bool,
   pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug)]
 pub struct ListMonthlyReportsResponse {
   pub reports: Vec<ReportSummary>,
+  /// Days missing between consecutive statements of the same account
+  pub gaps: Vec<StatementGap>,
 }
 
 pub struct ListMonthlyReportsUseCase {
@@ -49,22 +58,44 @@ impl ListMonthlyReportsUseCase {
       .get_company_reports(command.company_id)
       .await?;
 
+    let statements = self
+      .report_service
+      .get_company_statements(command.company_id)
+      .await?;
+    let gaps = StatementGap::find(&statements);
+
     let summaries = reports
       .into_iter()
-      .map(|r| ReportSummary {
-        id: r.id,
-        month: r.month,
-        year: r.year,
-        status: r.status.as_str().to_string(),
-        bank_account_iban: r.bank_account_iban,
-        total_incoming: r.total_incoming,
-        total_outgoing: r.total_outgoing,
-        transaction_count: r.transaction_count,
-        matched_count: r.matched_count,
-        created_at: r.created_at,
+      .map(|r| {
+        let statements: Vec<_> = statements.iter().filter(|s| s.report_id == r.id).collect();
+        let checked: Vec<_> = statements
+          .iter()
+          .filter(|s| s.balance_difference().is_some())
+          .collect();
+        ReportSummary {
+          id: r.id,
+          month: r.month,
+          year: r.year,
+          status: r.status.as_str().to_string(),
+          version: r.version,
+          bank_account_ibans: statements
+            .iter()
+            .map(|s| s.bank_account_iban.clone())
+            .collect(),
+          total_incoming: r.total_incoming,
+          total_outgoing: r.total_outgoing,
+          transaction_count: r.transaction_count,
+          matched_count: r.matched_count,
+          balance_checked: !checked.is_empty(),
+          is_balanced: !checked.is_empty() && checked.iter().all(|s| s.is_balanced()),
+          created_at: r.created_at,
+        }
       })
       .collect();
 
-    Ok(ListMonthlyReportsResponse { reports: summaries })
+    Ok(ListMonthlyReportsResponse {
+      reports: summaries,
+      gaps,
+    })
   }
 }
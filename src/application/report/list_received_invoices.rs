@@ -21,6 +21,16 @@ pub struct ReceivedInvoiceSummary {
   pub invoice_date: Option<NaiveDate>,
   pub invoice_number: Option<String>,
   pub notes: Option<String>,
+  pub net_amount: Decimal,
+  pub vat_amount: Decimal,
+  pub due_date: Option<NaiveDate>,
+  pub vendor_iban: Option<String>,
+  pub payment_reference: Option<String>,
+  pub status: String,
+  pub status_label: String,
+  pub paid_on: Option<NaiveDate>,
+  pub is_overdue: bool,
+  pub vendor_id: Option<Uuid>,
   pub created_at: DateTime<Utc>,
 }
 
@@ -47,9 +57,14 @@ impl ListReceivedInvoicesUseCase {
       .list_received_invoices(command.company_id)
       .await?;
 
+    let today = Utc::now().date_naive();
     let summaries = invoices
       .into_iter()
       .map(|i| ReceivedInvoiceSummary {
+        net_amount: i.net_amount(),
+        is_overdue: i.is_overdue(today),
+        status: i.status.as_str().to_string(),
+        status_label: i.status.label().to_string(),
         id: i.id,
         vendor_name: i.vendor_name,
         amount: i.amount,
@@ -57,6 +72,12 @@ impl ListReceivedInvoicesUseCase {
         invoice_date: i.invoice_date,
         invoice_number: i.invoice_number,
         notes: i.notes,
+        vat_amount: i.vat_amount,
+        due_date: i.due_date,
+        vendor_iban: i.vendor_iban,
+        payment_reference: i.payment_reference,
+        paid_on: i.paid_on,
+        vendor_id: i.vendor_id,
         created_at: i.created_at,
       })
       .collect();
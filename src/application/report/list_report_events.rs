@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::auth::ports::UserRepository;
+use crate::domain::report::{errors::ReportError, services::ReportService};
+
+#[derive(Debug)]
+pub struct ListReportEventsCommand {
+  pub report_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportEventDto {
+  pub id: Uuid,
+  pub kind: String,
+  pub label: String,
+  pub version: i32,
+  pub reason: Option<String>,
+  pub user_id: Option<Uuid>,
+  /// Full name of the acting user; None for system actions
+  pub user_name: Option<String>,
+  pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListReportEventsResponse {
+  pub report_id: Uuid,
+  pub events: Vec<ReportEventDto>,
+}
+
+pub struct ListReportEventsUseCase {
+  report_service: Arc<ReportService>,
+  user_repo: Arc<dyn UserRepository>,
+}
+
+impl ListReportEventsUseCase {
+  pub fn new(report_service: Arc<ReportService>, user_repo: Arc<dyn UserRepository>) -> Self {
+    Self {
+      report_service,
+      user_repo,
+    }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListReportEventsCommand,
+  ) -> Result<ListReportEventsResponse, ReportError> {
+    let events = self
+      .report_service
+      .get_report_events(command.report_id)
+      .await?;
+
+    let mut user_names: HashMap<Uuid, String> = HashMap::new();
+    for user_id in events.iter().filter_map(|event| event.user_id) {
+      if user_names.contains_key(&user_id) {
+        continue;
+      }
+      let user = self
+        .user_repo
+        .find_by_id(user_id)
+        .await
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?;
+      if let Some(user) = user {
+        user_names.insert(user_id, user.full_name);
+      }
+    }
+
+    let events = events
+      .into_iter()
+      .map(|event| ReportEventDto {
+        id: event.id,
+        kind: event.kind.as_str().to_string(),
+        label: event.kind.label().to_string(),
+        version: event.version,
+        reason: event.reason,
+        user_id: event.user_id,
+        user_name: event
+          .user_id
+          .and_then(|user_id| user_names.get(&user_id).cloned()),
+        created_at: event.created_at,
+      })
+      .collect();
+
+    Ok(ListReportEventsResponse {
+      report_id: command.report_id,
+      events,
+    })
+  }
+}
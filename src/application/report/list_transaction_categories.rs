@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::report::{
+  entities::TransactionCategory, errors::ReportError, services::ReportService,
+};
+
+#[derive(Debug)]
+pub struct ListTransactionCategoriesCommand {
+  pub company_id: Uuid,
+}
+
+#[derive(Debug)]
+pub struct ListTransactionCategoriesResponse {
+  pub categories: Vec<TransactionCategory>,
+}
+
+pub struct ListTransactionCategoriesUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl ListTransactionCategoriesUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListTransactionCategoriesCommand,
+  ) -> Result<ListTransactionCategoriesResponse, ReportError> {
+    let categories = self
+      .report_service
+      .list_categories(command.company_id)
+      .await?;
+
+    Ok(ListTransactionCategoriesResponse { categories })
+  }
+}
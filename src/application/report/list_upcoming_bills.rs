@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::{Datelike, Days, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::report::{
+  PayableStatus, ReceivedInvoice, errors::ReportError, services::ReportService,
+};
+
+#[derive(Debug)]
+pub struct ListUpcomingBillsCommand {
+  pub company_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BillSummary {
+  pub id: Uuid,
+  pub vendor_name: String,
+  pub invoice_number: Option<String>,
+  pub amount: Decimal,
+  pub currency: String,
+  pub due_date: Option<NaiveDate>,
+  pub vendor_iban: Option<String>,
+  pub payment_reference: Option<String>,
+  pub status: String,
+  pub status_label: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CurrencyTotal {
+  pub currency: String,
+  pub amount: Decimal,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListUpcomingBillsResponse {
+  pub today: NaiveDate,
+  /// Sunday of the current week
+  pub week_end: NaiveDate,
+  pub overdue: Vec<BillSummary>,
+  pub due_this_week: Vec<BillSummary>,
+  /// Overdue and due this week, per currency
+  pub totals: Vec<CurrencyTotal>,
+  /// Open bills without a due date
+  pub undated: Vec<BillSummary>,
+  /// On hold until the dispute with the vendor is settled
+  pub disputed: Vec<BillSummary>,
+}
+
+/// Received invoices to pay this week: overdue ones and those due by Sunday,
+/// whether or not the payment is already scheduled
+pub struct ListUpcomingBillsUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl ListUpcomingBillsUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListUpcomingBillsCommand,
+  ) -> Result<ListUpcomingBillsResponse, ReportError> {
+    let invoices = self
+      .report_service
+      .open_payables(command.company_id)
+      .await?;
+    Ok(upcoming_bills(invoices, Utc::now().date_naive()))
+  }
+}
+
+fn upcoming_bills(invoices: Vec<ReceivedInvoice>, today: NaiveDate) -> ListUpcomingBillsResponse {
+  let week_end = today + Days::new(6 - u64::from(today.weekday().num_days_from_monday()));
+  let mut response = ListUpcomingBillsResponse {
+    today,
+    week_end,
+    overdue: Vec::new(),
+    due_this_week: Vec::new(),
+    totals: Vec::new(),
+    undated: Vec::new(),
+    disputed: Vec::new(),
+  };
+
+  let mut totals: BTreeMap<String, Decimal> = BTreeMap::new();
+  for invoice in invoices.into_iter().filter(ReceivedInvoice::is_open) {
+    let due_now = match invoice.due_date {
+      _ if invoice.status == PayableStatus::Disputed => {
+        response.disputed.push(summary(invoice));
+        continue;
+      }
+      None => {
+        response.undated.push(summary(invoice));
+        continue;
+      }
+      Some(due) if due < today => &mut response.overdue,
+      Some(due) if due <= week_end => &mut response.due_this_week,
+      Some(_) => continue,
+    };
+    *totals.entry(invoice.currency.clone()).or_default() += invoice.amount.abs();
+    due_now.push(summary(invoice));
+  }
+  response.totals = totals
+    .into_iter()
+    .map(|(currency, amount)| CurrencyTotal { currency, amount })
+    .collect();
+
+  response
+}
+
+fn summary(invoice: ReceivedInvoice) -> BillSummary {
+  BillSummary {
+    id: invoice.id,
+    vendor_name: invoice.vendor_name,
+    invoice_number: invoice.invoice_number,
+    amount: invoice.amount,
+    currency: invoice.currency,
+    due_date: invoice.due_date,
+    vendor_iban: invoice.vendor_iban,
+    payment_reference: invoice.payment_reference,
+    status: invoice.status.as_str().to_string(),
+    status_label: invoice.status.label().to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rust_decimal_macros::dec;
+
+  fn bill(amount: Decimal, currency: &str, due_date: Option<NaiveDate>) -> ReceivedInvoice {
+    let mut invoice = ReceivedInvoice::new(
+      Uuid::new_v4(),
+      "Vendor Co".to_string(),
+      amount,
+      currency.to_string(),
+      None,
+      None,
+      String::new(),
+      None,
+    );
+    invoice.due_date = due_date;
+    invoice
+  }
+
+  #[test]
+  fn test_groups_open_bills_by_what_is_due_this_week() {
+    // A Wednesday; the week ends on Sunday the 25th
+    let today = NaiveDate::from_ymd_opt(2026, 10, 21).unwrap();
+    let date = |day| NaiveDate::from_ymd_opt(2026, 10, day);
+
+    let mut scheduled = bill(dec!(30.00), "EUR", date(25));
+    scheduled.set_status(PayableStatus::Scheduled);
+    let mut disputed = bill(dec!(40.00), "EUR", date(1));
+    disputed.set_status(PayableStatus::Disputed);
+    let mut paid = bill(dec!(50.00), "EUR", date(1));
+    paid.set_status(PayableStatus::Paid);
+    let invoices = vec![
+      bill(dec!(10.00), "EUR", date(20)),
+      bill(dec!(20.00), "USD", date(21)),
+      scheduled,
+      bill(dec!(60.00), "EUR", date(26)),
+      bill(dec!(70.00), "EUR", None),
+      disputed,
+      paid,
+    ];
+
+    let upcoming = upcoming_bills(invoices, today);
+    assert_eq!(upcoming.week_end, date(25).unwrap());
+    assert_eq!(upcoming.overdue.len(), 1);
+    assert_eq!(upcoming.due_this_week.len(), 2);
+    assert_eq!(upcoming.due_this_week[1].status, "scheduled");
+    assert_eq!(upcoming.undated.len(), 1);
+    assert_eq!(upcoming.disputed.len(), 1);
+    let totals: Vec<(&str, Decimal)> = upcoming
+      .totals
+      .iter()
+      .map(|t| (t.currency.as_str(), t.amount))
+      .collect();
+    assert_eq!(totals, vec![("EUR", dec!(40.00)), ("USD", dec!(20.00))]);
+
+    // On a Sunday the week ends today
+    let sunday = date(25).unwrap();
+    assert_eq!(upcoming_bills(Vec::new(), sunday).week_end, sunday);
+  }
+}
@@ -0,0 +1,96 @@
+use std::{collections::HashMap, sync::Arc};
+
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::report::{
+  entities::{TransactionCategory, Vendor},
+  errors::ReportError,
+  services::ReportService,
+};
+
+#[derive(Debug)]
+pub struct ListVendorsCommand {
+  pub company_id: Uuid,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VendorSummary {
+  pub vendor: Vendor,
+  pub category_name: Option<String>,
+  pub invoice_count: usize,
+  pub invoiced_total: Decimal,
+  /// Still to pay on the vendor's open bills
+  pub open_amount: Decimal,
+}
+
+#[derive(Debug)]
+pub struct ListVendorsResponse {
+  pub vendors: Vec<VendorSummary>,
+  pub categories: Vec<TransactionCategory>,
+}
+
+pub struct ListVendorsUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl ListVendorsUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListVendorsCommand,
+  ) -> Result<ListVendorsResponse, ReportError> {
+    let vendors = self.report_service.list_vendors(command.company_id).await?;
+    let categories = self
+      .report_service
+      .list_categories(command.company_id)
+      .await?;
+    let invoices = self
+      .report_service
+      .list_received_invoices(command.company_id)
+      .await?;
+
+    let mut totals: HashMap<Uuid, (usize, Decimal, Decimal)> = HashMap::new();
+    for invoice in &invoices {
+      let Some(vendor_id) = invoice.vendor_id else {
+        continue;
+      };
+      let entry = totals.entry(vendor_id).or_default();
+      entry.0 += 1;
+      entry.1 += invoice.amount;
+      if invoice.is_open() {
+        entry.2 += invoice.amount;
+      }
+    }
+
+    let vendors = vendors
+      .into_iter()
+      .map(|vendor| {
+        let (invoice_count, invoiced_total, open_amount) =
+          totals.get(&vendor.id).copied().unwrap_or_default();
+        let category_name = vendor.default_category_id.and_then(|id| {
+          categories
+            .iter()
+            .find(|c| c.id == id)
+            .map(|c| c.name.clone())
+        });
+        VendorSummary {
+          vendor,
+          category_name,
+          invoice_count,
+          invoiced_total,
+          open_amount,
+        }
+      })
+      .collect();
+
+    Ok(ListVendorsResponse {
+      vendors,
+      categories,
+    })
+  }
+}
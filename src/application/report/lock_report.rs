@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::ports::CompanyMemberRepository;
+use crate::domain::report::{errors::ReportError, services::ReportService};
+
+/// Sign off a generated report, locking it permanently. Only owners and
+/// admins can sign off.
+#[derive(Debug)]
+pub struct LockReportCommand {
+  pub report_id: Uuid,
+  pub company_id: Uuid,
+  pub user_id: Uuid,
+}
+
+pub struct LockReportUseCase {
+  report_service: Arc<ReportService>,
+  member_repo: Arc<dyn CompanyMemberRepository>,
+}
+
+impl LockReportUseCase {
+  pub fn new(
+    report_service: Arc<ReportService>,
+    member_repo: Arc<dyn CompanyMemberRepository>,
+  ) -> Self {
+    Self {
+      report_service,
+      member_repo,
+    }
+  }
+
+  pub async fn execute(&self, command: LockReportCommand) -> Result<(), ReportError> {
+    let member = self
+      .member_repo
+      .find_member(command.company_id, command.user_id)
+      .await
+      .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+    if !member.is_some_and(|member| member.can_manage_members()) {
+      return Err(ReportError::InsufficientPermissions);
+    }
+
+    self
+      .report_service
+      .lock_report(command.company_id, command.report_id, command.user_id)
+      .await?;
+
+    Ok(())
+  }
+}
@@ -1,32 +1,104 @@
+use rust_decimal::Decimal;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::domain::report::{errors::ReportError, services::ReportService};
+use super::payments::sync_ledger_payments;
+use crate::domain::invoice::entities::InvoiceTotals;
+use crate::domain::invoice::ports::{InvoiceLineItemRepository, InvoiceRepository};
+use crate::domain::invoice::value_objects::InvoiceStatus;
+use crate::domain::ledger::services::LedgerService;
+use crate::domain::report::{
+  errors::ReportError, services::ReportService, value_objects::MatchDocument,
+};
 
 #[derive(Debug)]
 pub struct MatchTransactionCommand {
+  pub company_id: Uuid,
   pub transaction_id: Uuid,
   pub invoice_id: Option<Uuid>,
   pub received_invoice_id: Option<Uuid>,
+  /// Part of the transaction to allocate; by default as much as is open on
+  /// both the transaction and the invoice
+  pub amount: Option<Decimal>,
 }
 
 pub struct MatchTransactionUseCase {
   report_service: Arc<ReportService>,
+  ledger_service: Arc<LedgerService>,
+  invoice_repo: Arc<dyn InvoiceRepository>,
+  line_item_repo: Arc<dyn InvoiceLineItemRepository>,
 }
 
 impl MatchTransactionUseCase {
-  pub fn new(report_service: Arc<ReportService>) -> Self {
-    Self { report_service }
+  pub fn new(
+    report_service: Arc<ReportService>,
+    ledger_service: Arc<LedgerService>,
+    invoice_repo: Arc<dyn InvoiceRepository>,
+    line_item_repo: Arc<dyn InvoiceLineItemRepository>,
+  ) -> Self {
+    Self {
+      report_service,
+      ledger_service,
+      invoice_repo,
+      line_item_repo,
+    }
   }
 
   pub async fn execute(&self, command: MatchTransactionCommand) -> Result<(), ReportError> {
-    self
+    let document = match (command.invoice_id, command.received_invoice_id) {
+      (Some(invoice_id), None) => {
+        let invoice = self
+          .invoice_repo
+          .find_by_id(invoice_id)
+          .await
+          .map_err(|e| ReportError::Ledger(format!("Failed to fetch invoice: {}", e)))?
+          .ok_or_else(|| ReportError::Validation("Invoice not found".to_string()))?;
+        // A draft isn't issued yet, and one that is matched couldn't be deleted
+        if invoice.status == InvoiceStatus::Draft {
+          return Err(ReportError::Validation(
+            "Draft invoices can't be matched; send the invoice first".to_string(),
+          ));
+        }
+        let line_items = self
+          .line_item_repo
+          .find_by_invoice_id(invoice.id)
+          .await
+          .map_err(|e| ReportError::Ledger(format!("Failed to fetch invoice lines: {}", e)))?;
+        MatchDocument::Invoice {
+          id: invoice.id,
+          company_id: invoice.company_id,
+          total: InvoiceTotals::calculate(&line_items, invoice.currency)
+            .grand_total
+            .amount,
+          currency: invoice.currency.as_str().to_string(),
+        }
+      }
+      (None, Some(received_invoice_id)) => MatchDocument::ReceivedInvoice(received_invoice_id),
+      _ => {
+        return Err(ReportError::Validation(
+          "Exactly one of invoice_id or received_invoice_id must be provided".to_string(),
+        ));
+      }
+    };
+
+    let (_, allocation) = self
       .report_service
       .match_transaction(
+        command.company_id,
         command.transaction_id,
-        command.invoice_id,
-        command.received_invoice_id,
+        document,
+        command.amount,
       )
-      .await
+      .await?;
+
+    // Book the payment against the matched document
+    sync_ledger_payments(
+      &self.report_service,
+      &self.ledger_service,
+      self.invoice_repo.as_ref(),
+      command.company_id,
+      &[allocation],
+    )
+    .await
   }
 }
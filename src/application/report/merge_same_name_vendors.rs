@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::ports::CompanyRepository;
+use crate::domain::report::{errors::ReportError, services::ReportService};
+
+/// Merge the vendors of every company whose names only differ in case or
+/// spacing. The migration creating the vendor directory grouped bills by
+/// vendor name in SQL, which compares names more strictly than vendor
+/// matching does; run once after upgrading, and again at will.
+pub struct MergeSameNameVendorsUseCase {
+  report_service: Arc<ReportService>,
+  company_repo: Arc<dyn CompanyRepository>,
+}
+
+impl MergeSameNameVendorsUseCase {
+  pub fn new(report_service: Arc<ReportService>, company_repo: Arc<dyn CompanyRepository>) -> Self {
+    Self {
+      report_service,
+      company_repo,
+    }
+  }
+
+  /// Returns the number of vendors merged
+  pub async fn execute(&self) -> Result<usize, ReportError> {
+    let companies = self
+      .company_repo
+      .find_all()
+      .await
+      .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?;
+
+    let mut merged = 0;
+    for company in companies {
+      merged += self
+        .report_service
+        .merge_same_name_vendors(company.id)
+        .await?;
+    }
+    Ok(merged)
+  }
+}
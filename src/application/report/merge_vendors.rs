@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::report::{entities::Vendor, errors::ReportError, services::ReportService};
+
+#[derive(Debug)]
+pub struct MergeVendorsCommand {
+  pub company_id: Uuid,
+  /// Duplicate vendor, deleted once merged
+  pub from_id: Uuid,
+  pub into_id: Uuid,
+}
+
+pub struct MergeVendorsUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl MergeVendorsUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(&self, command: MergeVendorsCommand) -> Result<Vendor, ReportError> {
+    self
+      .report_service
+      .merge_vendors(command.company_id, command.from_id, command.into_id)
+      .await
+  }
+}
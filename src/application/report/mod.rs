@@ -1,36 +1,108 @@
+mod categorise_transaction;
+mod clear_rule_outcome;
 mod create_empty_report;
+mod create_matching_rule;
+mod create_transaction_category;
+mod delete_matching_rule;
 mod delete_received_invoice;
 mod delete_report;
+mod delete_transaction_category;
+mod export_report_archive;
 mod generate_report;
 mod get_report_details;
+mod get_vendor_details;
 mod import_bank_statement;
+mod list_matching_rules;
 mod list_monthly_reports;
 mod list_received_invoices;
+mod list_report_events;
+mod list_transaction_categories;
+mod list_upcoming_bills;
+mod list_vendors;
+mod lock_report;
 mod match_transaction;
+mod merge_same_name_vendors;
+mod merge_vendors;
+mod move_matching_rule;
+mod payments;
+mod process_report_uploads;
+mod reopen_report;
+mod report_files;
+mod retry_report_uploads;
+mod save_vendor;
+mod test_matching_rule;
 mod unmatch_transaction;
+mod update_payable;
 mod upload_receipt;
 mod upload_received_invoice;
+mod uploads;
 
+pub use categorise_transaction::{CategoriseTransactionCommand, CategoriseTransactionUseCase};
+pub use clear_rule_outcome::{ClearRuleOutcomeCommand, ClearRuleOutcomeUseCase};
 pub use create_empty_report::{
   CreateEmptyReportCommand, CreateEmptyReportResponse, CreateEmptyReportUseCase,
 };
+pub use create_matching_rule::{CreateMatchingRuleCommand, CreateMatchingRuleUseCase};
+pub use create_transaction_category::{
+  CreateTransactionCategoryCommand, CreateTransactionCategoryUseCase,
+};
+pub use delete_matching_rule::{DeleteMatchingRuleCommand, DeleteMatchingRuleUseCase};
 pub use delete_received_invoice::{DeleteReceivedInvoiceCommand, DeleteReceivedInvoiceUseCase};
 pub use delete_report::{DeleteReportCommand, DeleteReportUseCase};
-pub use generate_report::{GenerateReportCommand, GenerateReportUseCase};
+pub use delete_transaction_category::{
+  DeleteTransactionCategoryCommand, DeleteTransactionCategoryUseCase,
+};
+pub use export_report_archive::{
+  ExportReportArchiveCommand, ExportReportArchiveResponse, ExportReportArchiveUseCase,
+  ReportArchiveSummary,
+};
+pub use generate_report::{GenerateReportCommand, GenerateReportResponse, GenerateReportUseCase};
 pub use get_report_details::{
-  GetReportDetailsCommand, GetReportDetailsResponse, GetReportDetailsUseCase, TransactionDetail,
+  GetReportDetailsCommand, GetReportDetailsResponse, GetReportDetailsUseCase, StatementDetail,
+  TransactionDetail,
+};
+pub use get_vendor_details::{
+  GetVendorDetailsCommand, GetVendorDetailsResponse, GetVendorDetailsUseCase,
 };
 pub use import_bank_statement::{
   ImportBankStatementCommand, ImportBankStatementResponse, ImportBankStatementUseCase,
 };
+pub use list_matching_rules::{
+  ListMatchingRulesCommand, ListMatchingRulesResponse, ListMatchingRulesUseCase,
+};
 pub use list_monthly_reports::{
   ListMonthlyReportsCommand, ListMonthlyReportsResponse, ListMonthlyReportsUseCase,
 };
 pub use list_received_invoices::{
   ListReceivedInvoicesCommand, ListReceivedInvoicesResponse, ListReceivedInvoicesUseCase,
 };
+pub use list_report_events::{
+  ListReportEventsCommand, ListReportEventsResponse, ListReportEventsUseCase, ReportEventDto,
+};
+pub use list_transaction_categories::{
+  ListTransactionCategoriesCommand, ListTransactionCategoriesResponse,
+  ListTransactionCategoriesUseCase,
+};
+pub use list_upcoming_bills::{
+  BillSummary, ListUpcomingBillsCommand, ListUpcomingBillsResponse, ListUpcomingBillsUseCase,
+};
+pub use list_vendors::{
+  ListVendorsCommand, ListVendorsResponse, ListVendorsUseCase, VendorSummary,
+};
+pub use lock_report::{LockReportCommand, LockReportUseCase};
 pub use match_transaction::{MatchTransactionCommand, MatchTransactionUseCase};
+pub use merge_same_name_vendors::MergeSameNameVendorsUseCase;
+pub use merge_vendors::{MergeVendorsCommand, MergeVendorsUseCase};
+pub use move_matching_rule::{MoveMatchingRuleCommand, MoveMatchingRuleUseCase};
+pub use process_report_uploads::ProcessReportUploadsUseCase;
+pub use reopen_report::{ReopenReportCommand, ReopenReportUseCase};
+pub use retry_report_uploads::{RetryReportUploadsCommand, RetryReportUploadsUseCase};
+pub use save_vendor::{SaveVendorCommand, SaveVendorUseCase};
+pub use test_matching_rule::{
+  RuleTestMatchDetail, TestMatchingRuleCommand, TestMatchingRuleResponse, TestMatchingRuleUseCase,
+};
 pub use unmatch_transaction::{UnmatchTransactionCommand, UnmatchTransactionUseCase};
+pub use update_payable::{UpdatePayableCommand, UpdatePayableUseCase};
 pub use upload_receipt::{UploadReceiptCommand, UploadReceiptUseCase};
 pub use upload_received_invoice::{
   UploadReceivedInvoiceCommand, UploadReceivedInvoiceResponse, UploadReceivedInvoiceUseCase,
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::report::{errors::ReportError, services::ReportService};
+
+#[derive(Debug)]
+pub struct MoveMatchingRuleCommand {
+  pub company_id: Uuid,
+  pub rule_id: Uuid,
+  /// Evaluate the rule before its predecessor instead of after its successor
+  pub earlier: bool,
+}
+
+pub struct MoveMatchingRuleUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl MoveMatchingRuleUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(&self, command: MoveMatchingRuleCommand) -> Result<(), ReportError> {
+    self
+      .report_service
+      .move_rule(command.company_id, command.rule_id, command.earlier)
+      .await
+  }
+}
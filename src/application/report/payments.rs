@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::domain::invoice::ports::InvoiceRepository;
+use crate::domain::ledger::services::LedgerService;
+use crate::domain::report::{
+  entities::MatchAllocation, errors::ReportError, services::ReportService,
+};
+
+/// Re-post the bank payments of every invoice the allocations were for, so the
+/// ledger follows the allocations after they are created or removed. Received
+/// invoices are marked paid, or unpaid again, along the way.
+pub(super) async fn sync_ledger_payments(
+  report_service: &ReportService,
+  ledger_service: &LedgerService,
+  invoice_repo: &dyn InvoiceRepository,
+  company_id: Uuid,
+  allocations: &[MatchAllocation],
+) -> Result<(), ReportError> {
+  let invoice_ids: HashSet<Uuid> = allocations.iter().filter_map(|a| a.invoice_id).collect();
+  for invoice_id in invoice_ids {
+    let invoice = invoice_repo
+      .find_by_id(invoice_id)
+      .await
+      .map_err(|e| ReportError::Ledger(format!("Failed to fetch invoice: {}", e)))?;
+    if let Some(invoice) = invoice.filter(|i| i.company_id == company_id) {
+      let payments = report_service.invoice_payments(invoice.id).await?;
+      ledger_service
+        .sync_invoice_payments(&invoice, &payments)
+        .await
+        .map_err(|e| ReportError::Ledger(e.to_string()))?;
+    }
+  }
+
+  let received_invoice_ids: HashSet<Uuid> = allocations
+    .iter()
+    .filter_map(|a| a.received_invoice_id)
+    .collect();
+  for received_invoice_id in received_invoice_ids {
+    let invoice = report_service
+      .get_received_invoice(received_invoice_id)
+      .await?;
+    if invoice.company_id == company_id {
+      let invoice = report_service.sync_payable_status(invoice.id).await?;
+      let payments = report_service.received_invoice_payments(invoice.id).await?;
+      ledger_service
+        .sync_received_invoice_payments(&invoice, &payments)
+        .await
+        .map_err(|e| ReportError::Ledger(e.to_string()))?;
+    }
+  }
+
+  Ok(())
+}
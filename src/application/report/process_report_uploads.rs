@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use super::uploads::{finish_generation, push_report_files};
+use crate::domain::document::DocumentService;
+use crate::domain::report::{
+  errors::ReportError, ports::ReportStorageResolver, services::ReportService,
+};
+use crate::domain::storage::{UploadJob, UploadOutboxService, UploadTarget};
+
+/// Report files attempted per run
+const BATCH_SIZE: i64 = 50;
+
+/// Background run over the upload outbox: attempts the report files whose
+/// next attempt is due and marks reports generated once all their files are
+/// uploaded
+pub struct ProcessReportUploadsUseCase {
+  report_service: Arc<ReportService>,
+  storage_resolver: Arc<dyn ReportStorageResolver>,
+  outbox: Arc<UploadOutboxService>,
+  document_service: Arc<DocumentService>,
+}
+
+impl ProcessReportUploadsUseCase {
+  pub fn new(
+    report_service: Arc<ReportService>,
+    storage_resolver: Arc<dyn ReportStorageResolver>,
+    outbox: Arc<UploadOutboxService>,
+    document_service: Arc<DocumentService>,
+  ) -> Self {
+    Self {
+      report_service,
+      storage_resolver,
+      outbox,
+      document_service,
+    }
+  }
+
+  /// Returns the number of files attempted
+  pub async fn execute(&self) -> Result<usize, ReportError> {
+    let jobs = self
+      .outbox
+      .due_jobs(UploadTarget::ReportFile, BATCH_SIZE)
+      .await?;
+    let attempted = jobs.len();
+
+    let mut by_report: BTreeMap<Uuid, Vec<UploadJob>> = BTreeMap::new();
+    for job in jobs {
+      by_report.entry(job.owner_id).or_default().push(job);
+    }
+
+    for (report_id, jobs) in by_report {
+      let report = match self.report_service.get_report(report_id).await {
+        Ok(report) => report,
+        // The report was deleted while its files were queued
+        Err(ReportError::NotFound) => {
+          for job in jobs {
+            self.outbox.discard(job).await?;
+          }
+          continue;
+        }
+        Err(e) => return Err(e),
+      };
+
+      let cloud_storage = self.storage_resolver.for_company(report.company_id).await?;
+      let user_id = jobs
+        .iter()
+        .find_map(|job| job.requested_by)
+        .unwrap_or_else(Uuid::nil);
+      push_report_files(
+        &self.report_service,
+        &self.outbox,
+        &self.document_service,
+        cloud_storage.as_ref(),
+        &report,
+        jobs,
+      )
+      .await?;
+      finish_generation(&self.report_service, &self.outbox, report.id, user_id).await?;
+    }
+
+    Ok(attempted)
+  }
+}
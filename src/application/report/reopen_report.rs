@@ -0,0 +1,36 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::report::{errors::ReportError, services::ReportService};
+
+#[derive(Debug)]
+pub struct ReopenReportCommand {
+  pub report_id: Uuid,
+  pub company_id: Uuid,
+  pub user_id: Uuid,
+  pub reason: String,
+}
+
+pub struct ReopenReportUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl ReopenReportUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(&self, command: ReopenReportCommand) -> Result<(), ReportError> {
+    self
+      .report_service
+      .reopen_report(
+        command.company_id,
+        command.report_id,
+        command.user_id,
+        &command.reason,
+      )
+      .await?;
+
+    Ok(())
+  }
+}
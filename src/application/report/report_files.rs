@@ -0,0 +1,369 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::domain::company::ports::BankAccountRepository;
+use crate::domain::document::file_extension;
+use crate::domain::invoice::ports::InvoiceRepository;
+use crate::domain::report::{
+  entities::{BankStatement, BankTransaction, MonthlyReport},
+  errors::ReportError,
+  services::ReportService,
+  value_objects::TransactionDirection,
+};
+
+/// Stands in for the account folder of transactions that are on none of the
+/// report's statements
+pub(super) const NO_STATEMENT_FOLDER: &str = "No statement";
+
+/// Folders and documents of a report, laid out the same way in cloud storage
+/// and in the downloadable archive
+pub(super) struct ReportLayout {
+  /// Each statement with its account's folder, e.g. "Main (EE12...)"
+  pub accounts: Vec<(BankStatement, String)>,
+  /// One per allocation and receipt, in transaction order
+  pub documents: Vec<ReportDocument>,
+  /// Transactions on none of the statements, filed under
+  /// [`NO_STATEMENT_FOLDER`]
+  pub without_statement: usize,
+}
+
+/// Issued or received invoice a transaction was matched to, or its receipt
+pub(super) struct ReportDocument {
+  pub transaction_id: Uuid,
+  /// Part of the transaction allocated to the invoice, `None` for a receipt
+  pub allocated: Option<Decimal>,
+  /// Invoice number, the vendor's name for a bill without one, or "Receipt"
+  pub label: String,
+  /// None when the invoice has no PDF
+  pub file: Option<ReportFile>,
+}
+
+/// File to put in an account's incoming/ or outcoming/ folder
+pub(super) struct ReportFile {
+  /// What the file documents, e.g. "<allocation id>/invoice", unlike its
+  /// name the same each time the report is generated
+  pub key: String,
+  /// e.g. "Main (EE12...)/incoming"
+  pub folder: String,
+  pub name: String,
+  pub local_path: String,
+}
+
+impl ReportFile {
+  pub fn path(&self) -> String {
+    format!("{}/{}", self.folder, self.name)
+  }
+}
+
+/// Counterparty or account name usable in a file or folder name: bank CSVs
+/// and account names may contain path separators and control characters
+pub(super) fn safe_name(name: &str) -> String {
+  let cleaned: String = name
+    .chars()
+    .map(|c| {
+      if c == '/' || c == '\\' || c.is_control() {
+        ' '
+      } else {
+        c
+      }
+    })
+    .collect();
+  let cleaned = cleaned
+    .split_whitespace()
+    .filter(|word| !word.chars().all(|c| c == '.'))
+    .collect::<Vec<_>>()
+    .join(" ");
+  match cleaned.as_str() {
+    "" => "unknown".to_string(),
+    _ => cleaned,
+  }
+}
+
+/// Gives the files of a report distinct names within each folder; the second
+/// "2026-03-05 - Telia.pdf" becomes "2026-03-05 - Telia (2).pdf"
+#[derive(Default)]
+pub(super) struct FileNamer {
+  used: HashSet<String>,
+}
+
+impl FileNamer {
+  pub fn name(
+    &mut self,
+    folder: &str,
+    date: NaiveDate,
+    counterparty: &str,
+    extension: &str,
+  ) -> String {
+    let stem = format!("{} - {}", date.format("%Y-%m-%d"), safe_name(counterparty));
+    let mut name = format!("{}.{}", stem, extension);
+    let mut number = 1;
+    while !self
+      .used
+      .insert(format!("{}/{}", folder, name).to_lowercase())
+    {
+      number += 1;
+      name = format!("{} ({}).{}", stem, number, extension);
+    }
+    name
+  }
+}
+
+impl ReportLayout {
+  /// Folder of the account whose statement the transaction is on
+  pub fn account_folder(&self, statement_id: Option<Uuid>) -> &str {
+    self
+      .accounts
+      .iter()
+      .find(|(statement, _)| Some(statement.id) == statement_id)
+      .map(|(_, folder)| folder.as_str())
+      .unwrap_or(NO_STATEMENT_FOLDER)
+  }
+
+  /// Every account folder in use, [`NO_STATEMENT_FOLDER`] included when a
+  /// transaction needs it
+  pub fn account_folders(&self) -> Vec<&str> {
+    let mut folders: Vec<&str> = self
+      .accounts
+      .iter()
+      .map(|(_, folder)| folder.as_str())
+      .collect();
+    if self.without_statement > 0 {
+      folders.push(NO_STATEMENT_FOLDER);
+    }
+    folders
+  }
+}
+
+pub(super) async fn report_layout(
+  report_service: &ReportService,
+  invoice_repo: &dyn InvoiceRepository,
+  bank_account_repo: &dyn BankAccountRepository,
+  report: &MonthlyReport,
+  transactions: &[BankTransaction],
+) -> Result<ReportLayout, ReportError> {
+  let statements = report_service.get_statements(report.id).await?;
+  let bank_accounts = bank_account_repo
+    .find_by_company_id(report.company_id, true)
+    .await
+    .map_err(|e| ReportError::CloudStorage(e.to_string()))?;
+  let accounts: Vec<(BankStatement, String)> = statements
+    .into_iter()
+    .map(|statement| {
+      let folder = match bank_accounts
+        .iter()
+        .find(|a| Some(a.id) == statement.bank_account_id)
+      {
+        Some(account) => safe_name(&format!(
+          "{} ({})",
+          account.name.as_str(),
+          statement.bank_account_iban
+        )),
+        None => safe_name(&statement.bank_account_iban),
+      };
+      (statement, folder)
+    })
+    .collect();
+  let folders: HashMap<Uuid, &str> = accounts
+    .iter()
+    .map(|(statement, folder)| (statement.id, folder.as_str()))
+    .collect();
+
+  let mut documents = Vec::new();
+  let mut names = FileNamer::default();
+  let mut without_statement = 0;
+  for tx in transactions {
+    let account_folder = match tx.statement_id.and_then(|id| folders.get(&id)) {
+      Some(folder) => *folder,
+      None => {
+        without_statement += 1;
+        NO_STATEMENT_FOLDER
+      }
+    };
+    for allocation in &tx.allocations {
+      // Incoming money = issued invoice paid
+      if let Some(invoice_id) = allocation.invoice_id {
+        if let Ok(Some(invoice)) = invoice_repo.find_by_id(invoice_id).await {
+          let file = invoice.pdf_path.as_ref().map(|pdf_path| {
+            let folder = format!("{}/incoming", account_folder);
+            let counterparty = tx.counterparty_name.as_deref().unwrap_or("unknown");
+            ReportFile {
+              key: format!("{}/invoice", allocation.id),
+              name: names.name(&folder, tx.date, counterparty, "pdf"),
+              folder,
+              local_path: pdf_path.clone(),
+            }
+          });
+          documents.push(ReportDocument {
+            transaction_id: tx.id,
+            allocated: Some(allocation.amount),
+            label: invoice.invoice_number.value().to_string(),
+            file,
+          });
+        }
+      }
+
+      // Outgoing money = received invoice (bill) paid
+      if let Some(received_id) = allocation.received_invoice_id {
+        if let Ok(invoice) = report_service.get_received_invoice(received_id).await {
+          let folder = format!("{}/outcoming", account_folder);
+          let counterparty = tx
+            .counterparty_name
+            .as_deref()
+            .unwrap_or(&invoice.vendor_name);
+          let file = Some(ReportFile {
+            key: format!("{}/received_invoice", allocation.id),
+            name: names.name(&folder, tx.date, counterparty, "pdf"),
+            folder,
+            local_path: invoice.pdf_path.clone(),
+          });
+          documents.push(ReportDocument {
+            transaction_id: tx.id,
+            allocated: Some(allocation.amount),
+            label: invoice
+              .invoice_number
+              .clone()
+              .unwrap_or_else(|| invoice.vendor_name.clone()),
+            file,
+          });
+        }
+      }
+    }
+
+    // A receipt goes with the payment it documents
+    if let Some(receipt_path) = &tx.receipt_path {
+      let subfolder = match tx.direction {
+        TransactionDirection::Credit => "incoming",
+        TransactionDirection::Debit => "outcoming",
+      };
+      let folder = format!("{}/{}", account_folder, subfolder);
+      let extension = file_extension(receipt_path).unwrap_or_else(|| "pdf".to_string());
+      let counterparty = tx.counterparty_name.as_deref().unwrap_or("unknown");
+      let file = Some(ReportFile {
+        key: format!("{}/receipt", tx.id),
+        name: names.name(&folder, tx.date, counterparty, &extension),
+        folder,
+        local_path: receipt_path.clone(),
+      });
+      documents.push(ReportDocument {
+        transaction_id: tx.id,
+        allocated: None,
+        label: "Receipt".to_string(),
+        file,
+      });
+    }
+  }
+
+  Ok(ReportLayout {
+    accounts,
+    documents,
+    without_statement,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::report::ports::ReportCloudStorage;
+  use crate::infrastructure::cloud::LocalStorageAdapter;
+
+  #[test]
+  fn test_same_day_payments_to_the_same_counterparty_get_distinct_names() {
+    let date = NaiveDate::from_ymd_opt(2026, 3, 5).unwrap();
+    let mut names = FileNamer::default();
+
+    let first = names.name("Main/outcoming", date, "Telia Eesti AS", "pdf");
+    let second = names.name("Main/outcoming", date, "Telia Eesti AS", "pdf");
+    let third = names.name("Main/outcoming", date, "TELIA EESTI AS", "pdf");
+    assert_eq!(first, "2026-03-05 - Telia Eesti AS.pdf");
+    assert_eq!(second, "2026-03-05 - Telia Eesti AS (2).pdf");
+    assert_eq!(third, "2026-03-05 - TELIA EESTI AS (3).pdf");
+
+    // Other folders have their own names
+    let other = names.name("Main/incoming", date, "Telia Eesti AS", "pdf");
+    assert_eq!(other, "2026-03-05 - Telia Eesti AS.pdf");
+  }
+
+  #[test]
+  fn test_transactions_without_statement_get_their_own_folder() {
+    let mut layout = ReportLayout {
+      accounts: Vec::new(),
+      documents: Vec::new(),
+      without_statement: 0,
+    };
+    assert!(layout.account_folders().is_empty());
+
+    layout.without_statement = 2;
+    assert_eq!(layout.account_folders(), vec![NO_STATEMENT_FOLDER]);
+    assert_eq!(layout.account_folder(None), NO_STATEMENT_FOLDER);
+    assert_eq!(
+      layout.account_folder(Some(Uuid::new_v4())),
+      NO_STATEMENT_FOLDER
+    );
+  }
+
+  #[test]
+  fn test_names_cannot_leave_their_folder() {
+    let date = NaiveDate::from_ymd_opt(2026, 3, 5).unwrap();
+    let mut names = FileNamer::default();
+
+    let name = names.name("Main/outcoming", date, "../../etc/passwd", "pdf");
+    assert_eq!(name, "2026-03-05 - etc passwd.pdf");
+    assert_eq!(safe_name("A/S Shop\\\nLtd\t"), "A S Shop Ltd");
+    assert_eq!(safe_name(" .. "), "unknown");
+  }
+
+  #[tokio::test]
+  async fn test_same_day_payments_end_up_as_separate_local_files() {
+    let dir = std::env::temp_dir().join(format!("report_files_test_{}", Uuid::new_v4()));
+    let storage = LocalStorageAdapter::new(dir.join("storage"));
+    std::fs::create_dir_all(&dir).unwrap();
+    let date = NaiveDate::from_ymd_opt(2026, 3, 5).unwrap();
+    let mut names = FileNamer::default();
+
+    // Two bills of the same vendor paid on the same day
+    let files: Vec<ReportFile> = [b"bill 1", b"bill 2"]
+      .iter()
+      .map(|content| {
+        let local_path = dir.join(format!("{}.pdf", Uuid::new_v4()));
+        std::fs::write(&local_path, content).unwrap();
+        let folder = "Main (EE12)/outcoming".to_string();
+        ReportFile {
+          key: format!("{}/received_invoice", Uuid::new_v4()),
+          name: names.name(&folder, date, "Telia Eesti AS", "pdf"),
+          folder,
+          local_path: local_path.to_string_lossy().to_string(),
+        }
+      })
+      .collect();
+
+    let month = storage.create_folder("reports", "03.2026").await.unwrap();
+    let account = storage.create_folder(&month, "Main (EE12)").await.unwrap();
+    let outcoming = storage.create_folder(&account, "outcoming").await.unwrap();
+    let mut stored = Vec::new();
+    for file in &files {
+      stored.push(
+        storage
+          .upload_file(&outcoming, &file.name, &file.local_path, "application/pdf")
+          .await
+          .unwrap(),
+      );
+    }
+
+    // Stored under the names the layout gave them, neither replacing the other
+    assert_eq!(
+      stored,
+      vec![
+        "reports/03.2026/Main (EE12)/outcoming/2026-03-05 - Telia Eesti AS.pdf",
+        "reports/03.2026/Main (EE12)/outcoming/2026-03-05 - Telia Eesti AS (2).pdf",
+      ]
+    );
+    assert_eq!(
+      std::fs::read(dir.join("storage").join(&stored[1])).unwrap(),
+      b"bill 2"
+    );
+    assert_eq!(storage.manifest().await.unwrap().len(), 2);
+  }
+}
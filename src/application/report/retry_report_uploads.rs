@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::uploads::{due_report_files, finish_generation, push_report_files};
+use crate::domain::document::DocumentService;
+use crate::domain::report::{
+  errors::ReportError, ports::ReportStorageResolver, services::ReportService,
+};
+use crate::domain::storage::{UploadOutboxService, UploadTarget};
+
+/// Attempt a report's failed uploads again right away, finishing its
+/// generation when they all go through
+#[derive(Debug)]
+pub struct RetryReportUploadsCommand {
+  pub report_id: Uuid,
+  pub company_id: Uuid,
+  pub user_id: Uuid,
+}
+
+pub struct RetryReportUploadsUseCase {
+  report_service: Arc<ReportService>,
+  storage_resolver: Arc<dyn ReportStorageResolver>,
+  outbox: Arc<UploadOutboxService>,
+  document_service: Arc<DocumentService>,
+}
+
+impl RetryReportUploadsUseCase {
+  pub fn new(
+    report_service: Arc<ReportService>,
+    storage_resolver: Arc<dyn ReportStorageResolver>,
+    outbox: Arc<UploadOutboxService>,
+    document_service: Arc<DocumentService>,
+  ) -> Self {
+    Self {
+      report_service,
+      storage_resolver,
+      outbox,
+      document_service,
+    }
+  }
+
+  /// Returns the number of files still not uploaded
+  pub async fn execute(&self, command: RetryReportUploadsCommand) -> Result<usize, ReportError> {
+    let report = self.report_service.get_report(command.report_id).await?;
+    if report.company_id != command.company_id {
+      return Err(ReportError::NotFound);
+    }
+
+    self
+      .outbox
+      .retry_failed(UploadTarget::ReportFile, report.id)
+      .await?;
+    let cloud_storage = self.storage_resolver.for_company(report.company_id).await?;
+    let jobs = due_report_files(&self.outbox, report.id).await?;
+    push_report_files(
+      &self.report_service,
+      &self.outbox,
+      &self.document_service,
+      cloud_storage.as_ref(),
+      &report,
+      jobs,
+    )
+    .await?;
+
+    finish_generation(
+      &self.report_service,
+      &self.outbox,
+      report.id,
+      command.user_id,
+    )
+    .await
+  }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::report::{
+  entities::{Vendor, VendorProfile},
+  errors::ReportError,
+  services::ReportService,
+};
+
+#[derive(Debug)]
+pub struct SaveVendorCommand {
+  pub company_id: Uuid,
+  /// Vendor to change, `None` to add one
+  pub vendor_id: Option<Uuid>,
+  pub profile: VendorProfile,
+}
+
+/// Add a vendor to the directory or change its details
+pub struct SaveVendorUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl SaveVendorUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(&self, command: SaveVendorCommand) -> Result<Vendor, ReportError> {
+    match command.vendor_id {
+      Some(id) => {
+        self
+          .report_service
+          .update_vendor(command.company_id, id, command.profile)
+          .await
+      }
+      None => {
+        self
+          .report_service
+          .create_vendor(command.company_id, command.profile)
+          .await
+      }
+    }
+  }
+}
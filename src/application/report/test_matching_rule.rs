@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::report::{
+  entities::RuleConditions, errors::ReportError, services::ReportService, value_objects::RuleAction,
+};
+
+/// Try an unsaved rule against the transactions of a past report
+#[derive(Debug)]
+pub struct TestMatchingRuleCommand {
+  pub company_id: Uuid,
+  pub report_id: Uuid,
+  pub name: String,
+  pub conditions: RuleConditions,
+  pub action: RuleAction,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleTestMatchDetail {
+  pub transaction_id: Uuid,
+  pub date: NaiveDate,
+  pub counterparty_name: Option<String>,
+  pub description: Option<String>,
+  pub direction: String,
+  pub amount: Decimal,
+  pub currency: String,
+  /// Name of an earlier rule that fires on the transaction instead
+  pub preempted_by: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TestMatchingRuleResponse {
+  pub matches: Vec<RuleTestMatchDetail>,
+  /// Transactions the rule would actually fire on
+  pub fired_count: usize,
+}
+
+pub struct TestMatchingRuleUseCase {
+  report_service: Arc<ReportService>,
+}
+
+impl TestMatchingRuleUseCase {
+  pub fn new(report_service: Arc<ReportService>) -> Self {
+    Self { report_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: TestMatchingRuleCommand,
+  ) -> Result<TestMatchingRuleResponse, ReportError> {
+    let rule = self
+      .report_service
+      .build_rule(
+        command.company_id,
+        command.name,
+        command.conditions,
+        command.action,
+      )
+      .await?;
+
+    let matches: Vec<RuleTestMatchDetail> = self
+      .report_service
+      .test_rule(command.company_id, rule, command.report_id)
+      .await?
+      .into_iter()
+      .map(|m| RuleTestMatchDetail {
+        transaction_id: m.transaction.id,
+        date: m.transaction.date,
+        counterparty_name: m.transaction.counterparty_name,
+        description: m.transaction.description,
+        direction: m.transaction.direction.as_str().to_string(),
+        amount: m.transaction.amount,
+        currency: m.transaction.currency,
+        preempted_by: m.preempted_by,
+      })
+      .collect();
+    let fired_count = matches.iter().filter(|m| m.preempted_by.is_none()).count();
+
+    Ok(TestMatchingRuleResponse {
+      matches,
+      fired_count,
+    })
+  }
+}
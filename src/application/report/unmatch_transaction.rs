@@ -1,6 +1,9 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
+use super::payments::sync_ledger_payments;
+use crate::domain::invoice::ports::InvoiceRepository;
+use crate::domain::ledger::services::LedgerService;
 use crate::domain::report::{errors::ReportError, services::ReportService};
 
 #[derive(Debug)]
@@ -10,17 +13,38 @@ pub struct UnmatchTransactionCommand {
 
 pub struct UnmatchTransactionUseCase {
   report_service: Arc<ReportService>,
+  ledger_service: Arc<LedgerService>,
+  invoice_repo: Arc<dyn InvoiceRepository>,
 }
 
 impl UnmatchTransactionUseCase {
-  pub fn new(report_service: Arc<ReportService>) -> Self {
-    Self { report_service }
+  pub fn new(
+    report_service: Arc<ReportService>,
+    ledger_service: Arc<LedgerService>,
+    invoice_repo: Arc<dyn InvoiceRepository>,
+  ) -> Self {
+    Self {
+      report_service,
+      ledger_service,
+      invoice_repo,
+    }
   }
 
   pub async fn execute(&self, command: UnmatchTransactionCommand) -> Result<(), ReportError> {
-    self
+    let tx = self
       .report_service
       .unmatch_transaction(command.transaction_id)
-      .await
+      .await?;
+    let report = self.report_service.get_report(tx.report_id).await?;
+
+    // The bank payment no longer settles its invoices, so reverse its postings
+    sync_ledger_payments(
+      &self.report_service,
+      &self.ledger_service,
+      self.invoice_repo.as_ref(),
+      report.company_id,
+      &tx.allocations,
+    )
+    .await
   }
 }
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::ledger::{services::LedgerService, value_objects::JournalSource};
+use crate::domain::report::{
+  PayableStatus, PaymentTerms, errors::ReportError, services::ReportService,
+};
+
+#[derive(Debug)]
+pub struct UpdatePayableCommand {
+  pub id: Uuid,
+  pub company_id: Uuid,
+  pub terms: PaymentTerms,
+  /// New status set by hand, `None` to keep the current one
+  pub status: Option<PayableStatus>,
+}
+
+/// Change a received invoice's payment terms or mark its status by hand
+pub struct UpdatePayableUseCase {
+  report_service: Arc<ReportService>,
+  ledger_service: Arc<LedgerService>,
+}
+
+impl UpdatePayableUseCase {
+  pub fn new(report_service: Arc<ReportService>, ledger_service: Arc<LedgerService>) -> Self {
+    Self {
+      report_service,
+      ledger_service,
+    }
+  }
+
+  pub async fn execute(&self, command: UpdatePayableCommand) -> Result<(), ReportError> {
+    let mut invoice = self.report_service.get_received_invoice(command.id).await?;
+    if invoice.company_id != command.company_id {
+      return Err(ReportError::ReceivedInvoiceNotFound);
+    }
+
+    let previous_vat = invoice.vat_amount;
+    invoice.set_payment_terms(command.terms)?;
+    if let Some(status) = command.status {
+      invoice.set_status(status);
+    }
+    let invoice = self.report_service.update_received_invoice(invoice).await?;
+
+    // The bill is booked with its VAT split out, so a new VAT amount means
+    // booking it again
+    if invoice.vat_amount != previous_vat {
+      self
+        .ledger_service
+        .reverse_source(
+          invoice.company_id,
+          JournalSource::ReceivedInvoice,
+          invoice.id,
+        )
+        .await
+        .map_err(|e| ReportError::Ledger(e.to_string()))?;
+      self
+        .ledger_service
+        .record_received_invoice(&invoice)
+        .await
+        .map_err(|e| ReportError::Ledger(e.to_string()))?;
+    }
+
+    Ok(())
+  }
+}
@@ -1,28 +1,82 @@
+use chrono::NaiveDate;
 use std::sync::Arc;
 
 use uuid::Uuid;
 
+use crate::domain::document::{DocumentOwner, DocumentService, NewDocument, mime_type_for};
 use crate::domain::report::{errors::ReportError, services::ReportService};
+use crate::domain::retention::RetentionService;
 
 #[derive(Debug)]
 pub struct UploadReceiptCommand {
+  pub company_id: Uuid,
   pub transaction_id: Uuid,
-  pub receipt_path: String,
+  pub content: Vec<u8>,
+  pub file_name: String,
+  pub uploaded_by: Uuid,
 }
 
 pub struct UploadReceiptUseCase {
   report_service: Arc<ReportService>,
+  document_service: Arc<DocumentService>,
+  retention_service: Arc<RetentionService>,
 }
 
 impl UploadReceiptUseCase {
-  pub fn new(report_service: Arc<ReportService>) -> Self {
-    Self { report_service }
+  pub fn new(
+    report_service: Arc<ReportService>,
+    document_service: Arc<DocumentService>,
+    retention_service: Arc<RetentionService>,
+  ) -> Self {
+    Self {
+      report_service,
+      document_service,
+      retention_service,
+    }
   }
 
   pub async fn execute(&self, command: UploadReceiptCommand) -> Result<(), ReportError> {
+    // Check before storing, so a refused upload leaves the old receipt alone
+    let report = self
+      .report_service
+      .receipt_report(command.transaction_id)
+      .await?;
+    if report.company_id != command.company_id {
+      return Err(ReportError::TransactionNotFound);
+    }
+    // A replaced receipt is kept as long as its report
+    let keep_replaced_until = match NaiveDate::from_ymd_opt(report.year, report.month, 1) {
+      Some(first_day) => {
+        self
+          .retention_service
+          .retained_until(report.company_id, first_day)
+          .await?
+      }
+      None => None,
+    };
+
+    let document = self
+      .document_service
+      .store(
+        NewDocument {
+          company_id: command.company_id,
+          content: command.content,
+          mime_type: mime_type_for(&command.file_name).to_string(),
+          original_name: command.file_name,
+          uploaded_by: Some(command.uploaded_by),
+          keep_replaced_until,
+        },
+        DocumentOwner::TransactionReceipt,
+        command.transaction_id,
+      )
+      .await?;
+
     self
       .report_service
-      .update_receipt_path(command.transaction_id, Some(command.receipt_path))
+      .update_receipt_path(
+        command.transaction_id,
+        Some(self.document_service.path(&document)),
+      )
       .await
   }
 }
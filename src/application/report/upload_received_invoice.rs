@@ -4,8 +4,11 @@ use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
+use crate::domain::document::{DocumentOwner, DocumentService, NewDocument};
+use crate::domain::ledger::services::LedgerService;
 use crate::domain::report::{
-  entities::ReceivedInvoice, errors::ReportError, services::ReportService,
+  PaymentTerms, VendorIdentity, entities::ReceivedInvoice, errors::ReportError,
+  services::ReportService,
 };
 
 #[derive(Debug)]
@@ -16,8 +19,11 @@ pub struct UploadReceivedInvoiceCommand {
   pub currency: String,
   pub invoice_date: Option<NaiveDate>,
   pub invoice_number: Option<String>,
-  pub pdf_path: String,
+  pub pdf_content: Vec<u8>,
+  pub file_name: String,
   pub notes: Option<String>,
+  pub terms: PaymentTerms,
+  pub uploaded_by: Uuid,
 }
 
 #[derive(Debug, Clone)]
@@ -30,11 +36,21 @@ pub struct UploadReceivedInvoiceResponse {
 
 pub struct UploadReceivedInvoiceUseCase {
   report_service: Arc<ReportService>,
+  ledger_service: Arc<LedgerService>,
+  document_service: Arc<DocumentService>,
 }
 
 impl UploadReceivedInvoiceUseCase {
-  pub fn new(report_service: Arc<ReportService>) -> Self {
-    Self { report_service }
+  pub fn new(
+    report_service: Arc<ReportService>,
+    ledger_service: Arc<LedgerService>,
+    document_service: Arc<DocumentService>,
+  ) -> Self {
+    Self {
+      report_service,
+      ledger_service,
+      document_service,
+    }
   }
 
   pub async fn execute(
@@ -47,18 +63,70 @@ impl UploadReceivedInvoiceUseCase {
       ));
     }
 
-    let invoice = ReceivedInvoice::new(
+    let mut invoice = ReceivedInvoice::new(
       command.company_id,
       command.vendor_name,
       command.amount,
       command.currency,
       command.invoice_date,
       command.invoice_number,
-      command.pdf_path,
+      String::new(),
       command.notes,
     );
+    invoice.set_payment_terms(command.terms)?;
+
+    // Bills of a vendor share its directory entry however its name is spelled.
+    // Without a VAT amount, the vendor's usual rate is assumed.
+    let identity = VendorIdentity {
+      name: invoice.vendor_name.clone(),
+      registry_code: None,
+      iban: invoice.vendor_iban.clone(),
+    };
+    if let Some(vendor) = self
+      .report_service
+      .identify_vendor(command.company_id, &identity, true)
+      .await?
+    {
+      invoice.vendor_id = Some(vendor.id);
+      if invoice.vat_amount.is_zero() {
+        invoice.vat_amount = vendor.vat_included_in(invoice.amount).unwrap_or_default();
+      }
+    }
+
+    let document = self
+      .document_service
+      .store(
+        NewDocument {
+          company_id: command.company_id,
+          content: command.pdf_content,
+          mime_type: "application/pdf".to_string(),
+          original_name: command.file_name,
+          uploaded_by: Some(command.uploaded_by),
+          keep_replaced_until: None,
+        },
+        DocumentOwner::ReceivedInvoice,
+        invoice.id,
+      )
+      .await?;
+    invoice.pdf_path = self.document_service.path(&document);
+    let invoice_id = invoice.id;
+
+    let created = match self.report_service.create_received_invoice(invoice).await {
+      Ok(created) => created,
+      Err(e) => {
+        let _ = self
+          .document_service
+          .detach(DocumentOwner::ReceivedInvoice, invoice_id)
+          .await;
+        return Err(e);
+      }
+    };
 
-    let created = self.report_service.create_received_invoice(invoice).await?;
+    self
+      .ledger_service
+      .record_received_invoice(&created)
+      .await
+      .map_err(|e| ReportError::Ledger(e.to_string()))?;
 
     Ok(UploadReceivedInvoiceResponse {
       id: created.id,
@@ -0,0 +1,127 @@
+use uuid::Uuid;
+
+use crate::domain::document::DocumentService;
+use crate::domain::report::{
+  entities::{MonthlyReport, ReportUpload},
+  errors::ReportError,
+  ports::ReportCloudStorage,
+  services::ReportService,
+  value_objects::ReportStatus,
+};
+use crate::domain::storage::{UploadAction, UploadJob, UploadOutboxService, UploadTarget};
+
+/// Attempt the report's upload jobs that are due. Each job is claimed first,
+/// so one the background worker is already uploading is skipped. A file that
+/// fails stays in the outbox for its next attempt; the ones that succeed are
+/// remembered as uploads of the report's next version. Encrypted files are
+/// uploaded in plain form. Files removed by a delete job are forgotten along
+/// with the job.
+pub(super) async fn push_report_files(
+  report_service: &ReportService,
+  outbox: &UploadOutboxService,
+  document_service: &DocumentService,
+  cloud_storage: &dyn ReportCloudStorage,
+  report: &MonthlyReport,
+  jobs: Vec<UploadJob>,
+) -> Result<(), ReportError> {
+  for job in jobs {
+    let Some(job) = outbox.claim(job).await? else {
+      continue;
+    };
+    if job.action == UploadAction::Delete {
+      let file_id = job.replace_file_id.clone().unwrap_or_default();
+      match cloud_storage.delete_file(&file_id).await {
+        Ok(()) => {
+          report_service.forget_upload(report.id, &job.path).await?;
+          outbox.discard(job).await?;
+        }
+        Err(e) => {
+          outbox.record_failure(job, e.to_string()).await?;
+        }
+      }
+      continue;
+    }
+
+    let file = match document_service.plain_file(&job.local_path).await {
+      Ok(file) => file,
+      Err(e) => {
+        outbox.record_failure(job, e.to_string()).await?;
+        continue;
+      }
+    };
+    let result = match &job.replace_file_id {
+      Some(file_id) => {
+        cloud_storage
+          .update_file(file_id, file.path(), &job.mime_type)
+          .await
+      }
+      None => {
+        cloud_storage
+          .upload_file(&job.folder_id, job.file_name(), file.path(), &job.mime_type)
+          .await
+      }
+    };
+    drop(file);
+
+    match result {
+      Ok(file_id) => {
+        let job = outbox.record_success(job, file_id.clone()).await?;
+        report_service
+          .record_upload(ReportUpload::new(
+            report.id,
+            job.path,
+            file_id,
+            job.content_hash,
+            report.version + 1,
+          ))
+          .await?;
+      }
+      Err(e) => {
+        outbox.record_failure(job, e.to_string()).await?;
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// Due upload jobs of one report
+pub(super) async fn due_report_files(
+  outbox: &UploadOutboxService,
+  report_id: Uuid,
+) -> Result<Vec<UploadJob>, ReportError> {
+  let now = chrono::Utc::now();
+  Ok(
+    outbox
+      .open_jobs(UploadTarget::ReportFile, report_id)
+      .await?
+      .into_iter()
+      .filter(|job| job.is_due(now))
+      .collect(),
+  )
+}
+
+/// Mark a report being generated as generated once none of its files is
+/// left to upload. Returns the number of files still open.
+pub(super) async fn finish_generation(
+  report_service: &ReportService,
+  outbox: &UploadOutboxService,
+  report_id: Uuid,
+  user_id: Uuid,
+) -> Result<usize, ReportError> {
+  let report = report_service.get_report(report_id).await?;
+  let open = outbox
+    .open_jobs(UploadTarget::ReportFile, report_id)
+    .await?
+    .len();
+
+  if open == 0 && report.status == ReportStatus::Draft {
+    if let Some(folder_id) = report.drive_folder_id {
+      report_service
+        .mark_generated(report_id, folder_id, user_id)
+        .await?;
+    }
+  }
+
+  Ok(open)
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::retention::{
+  BlockedDeletion, DEFAULT_RETENTION_YEARS, MAX_RETENTION_YEARS, MIN_RETENTION_YEARS,
+  RetentionError, RetentionService,
+};
+
+/// Blocked attempts shown on the settings page
+const BLOCKED_DELETIONS_SHOWN: i64 = 50;
+
+#[derive(Debug)]
+pub struct GetRetentionSettingsCommand {
+  pub user_id: Uuid,
+  pub company_id: Uuid,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlockedDeletionDto {
+  pub record: String,
+  pub record_label: String,
+  pub label: String,
+  pub user_id: Option<Uuid>,
+  pub retained_until: NaiveDate,
+  pub attempted_at: DateTime<Utc>,
+}
+
+impl From<BlockedDeletion> for BlockedDeletionDto {
+  fn from(attempt: BlockedDeletion) -> Self {
+    Self {
+      record: attempt.record.as_str().to_string(),
+      record_label: attempt.record.label().to_string(),
+      label: attempt.label,
+      user_id: attempt.user_id,
+      retained_until: attempt.retained_until,
+      attempted_at: attempt.attempted_at,
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RetentionSettingsDto {
+  pub retention_years: u32,
+  pub default_years: u32,
+  pub min_years: u32,
+  pub max_years: u32,
+  /// None while the company uses the default policy
+  pub updated_at: Option<DateTime<Utc>>,
+  pub blocked_deletions: Vec<BlockedDeletionDto>,
+}
+
+/// The company's retention policy with its latest blocked deletions
+pub struct GetRetentionSettingsUseCase {
+  retention_service: Arc<RetentionService>,
+}
+
+impl GetRetentionSettingsUseCase {
+  pub fn new(retention_service: Arc<RetentionService>) -> Self {
+    Self { retention_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: GetRetentionSettingsCommand,
+  ) -> Result<RetentionSettingsDto, RetentionError> {
+    let policy = self
+      .retention_service
+      .get_policy(command.user_id, command.company_id)
+      .await?;
+    let blocked_deletions = self
+      .retention_service
+      .blocked_deletions(command.user_id, command.company_id, BLOCKED_DELETIONS_SHOWN)
+      .await?;
+
+    Ok(RetentionSettingsDto {
+      retention_years: policy.retention_years,
+      default_years: DEFAULT_RETENTION_YEARS,
+      min_years: MIN_RETENTION_YEARS,
+      max_years: MAX_RETENTION_YEARS,
+      updated_at: policy.updated_by.map(|_| policy.updated_at),
+      blocked_deletions: blocked_deletions
+        .into_iter()
+        .map(BlockedDeletionDto::from)
+        .collect(),
+    })
+  }
+}
@@ -0,0 +1,10 @@
+mod get_retention_settings;
+mod purge_expired_records;
+mod update_retention_policy;
+
+pub use get_retention_settings::{
+  BlockedDeletionDto, GetRetentionSettingsCommand, GetRetentionSettingsUseCase,
+  RetentionSettingsDto,
+};
+pub use purge_expired_records::{PurgeExpiredRecordsResponse, PurgeExpiredRecordsUseCase};
+pub use update_retention_policy::{UpdateRetentionPolicyCommand, UpdateRetentionPolicyUseCase};
@@ -0,0 +1,199 @@
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::ports::CompanyRepository;
+use crate::domain::document::{DocumentOwner, DocumentService};
+use crate::domain::invoice::{InvoiceService, ports::InvoiceRepository};
+use crate::domain::report::services::ReportService;
+use crate::domain::retention::{RetentionError, RetentionService};
+
+/// Counts of a purge run
+#[derive(Debug, Default, Serialize)]
+pub struct PurgeExpiredRecordsResponse {
+  pub invoices: usize,
+  pub received_invoices: usize,
+  pub reports: usize,
+  /// Replaced invoice PDFs and receipts kept until their retention ended
+  pub replaced_documents: usize,
+}
+
+/// Remove the records of every company whose retention period has ended:
+/// archived invoices, received invoices and reports, with their documents,
+/// and the documents replaced while their owner was still retained
+///
+/// Invoices still in the invoice list are left alone; archiving them marks
+/// them as no longer needed. Reports go first, and invoices still matched to
+/// transactions of a retained report are kept with it. Ledger entries are not reversed: the books of
+/// the closed years stay as they were.
+pub struct PurgeExpiredRecordsUseCase {
+  retention_service: Arc<RetentionService>,
+  company_repo: Arc<dyn CompanyRepository>,
+  invoice_repo: Arc<dyn InvoiceRepository>,
+  invoice_service: Arc<InvoiceService>,
+  report_service: Arc<ReportService>,
+  document_service: Arc<DocumentService>,
+}
+
+impl PurgeExpiredRecordsUseCase {
+  pub fn new(
+    retention_service: Arc<RetentionService>,
+    company_repo: Arc<dyn CompanyRepository>,
+    invoice_repo: Arc<dyn InvoiceRepository>,
+    invoice_service: Arc<InvoiceService>,
+    report_service: Arc<ReportService>,
+    document_service: Arc<DocumentService>,
+  ) -> Self {
+    Self {
+      retention_service,
+      company_repo,
+      invoice_repo,
+      invoice_service,
+      report_service,
+      document_service,
+    }
+  }
+
+  pub async fn execute(&self) -> Result<PurgeExpiredRecordsResponse, RetentionError> {
+    let mut response = PurgeExpiredRecordsResponse::default();
+    let today = Utc::now().date_naive();
+
+    for company in self.company_repo.find_all().await? {
+      let policy = self.retention_service.policy(company.id).await?;
+
+      for report in self
+        .report_service
+        .get_company_reports(company.id)
+        .await
+        .map_err(failed)?
+        .into_iter()
+        .filter(|report| {
+          NaiveDate::from_ymd_opt(report.year, report.month, 1)
+            .is_some_and(|first_day| policy.is_expired(first_day, today))
+        })
+      {
+        let (_, transactions) = self
+          .report_service
+          .get_report_details(report.id)
+          .await
+          .map_err(failed)?;
+        self
+          .report_service
+          .purge_report(report.id)
+          .await
+          .map_err(failed)?;
+        for tx in transactions {
+          if tx.receipt_path.is_some() {
+            self
+              .remove_document(DocumentOwner::TransactionReceipt, tx.id, tx.receipt_path)
+              .await?;
+          }
+        }
+        response.reports += 1;
+      }
+
+      // Invoices paid by transactions of reports still retained stay until
+      // those reports are purged
+      let allocated = self
+        .report_service
+        .allocated_by_document(company.id)
+        .await
+        .map_err(failed)?;
+
+      for invoice in self
+        .invoice_repo
+        .find_archived_by_company_id(company.id)
+        .await
+        .map_err(failed)?
+        .into_iter()
+        .filter(|invoice| {
+          policy.is_expired(invoice.invoice_date, today) && !allocated.contains_key(&invoice.id)
+        })
+      {
+        let (invoice_id, pdf_path) = (invoice.id, invoice.pdf_path.clone());
+        self
+          .invoice_service
+          .purge_invoice(invoice)
+          .await
+          .map_err(failed)?;
+        self
+          .remove_document(DocumentOwner::InvoicePdf, invoice_id, pdf_path)
+          .await?;
+        response.invoices += 1;
+      }
+
+      for invoice in self
+        .report_service
+        .list_received_invoices(company.id)
+        .await
+        .map_err(failed)?
+        .into_iter()
+        .filter(|invoice| {
+          let date = invoice
+            .invoice_date
+            .unwrap_or_else(|| invoice.created_at.date_naive());
+          policy.is_expired(date, today) && !allocated.contains_key(&invoice.id)
+        })
+      {
+        let pdf_path = self
+          .report_service
+          .delete_received_invoice(invoice.id)
+          .await
+          .map_err(failed)?;
+        self
+          .remove_document(DocumentOwner::ReceivedInvoice, invoice.id, Some(pdf_path))
+          .await?;
+        response.received_invoices += 1;
+      }
+    }
+
+    response.replaced_documents = self
+      .document_service
+      .purge_released()
+      .await
+      .map_err(failed)?;
+
+    if response.invoices
+      + response.received_invoices
+      + response.reports
+      + response.replaced_documents
+      > 0
+    {
+      tracing::info!(
+        "Purged {} invoices, {} received invoices, {} reports and {} replaced documents past their retention period",
+        response.invoices,
+        response.received_invoices,
+        response.reports,
+        response.replaced_documents
+      );
+    }
+    Ok(response)
+  }
+
+  /// Detach the owner's document; files written before the document store
+  /// are removed on their own
+  async fn remove_document(
+    &self,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+    legacy_path: Option<String>,
+  ) -> Result<(), RetentionError> {
+    let detached = self
+      .document_service
+      .detach(owner, owner_id)
+      .await
+      .map_err(failed)?;
+    if let Some(path) = legacy_path.filter(|_| !detached) {
+      if let Err(e) = tokio::fs::remove_file(&path).await {
+        tracing::warn!("Failed to delete file {}: {}", path, e);
+      }
+    }
+    Ok(())
+  }
+}
+
+fn failed(error: impl std::fmt::Display) -> RetentionError {
+  RetentionError::Repository(RepositoryError::QueryFailed(error.to_string()))
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::retention::{RetentionError, RetentionPolicy, RetentionService};
+
+#[derive(Debug)]
+pub struct UpdateRetentionPolicyCommand {
+  pub user_id: Uuid,
+  pub company_id: Uuid,
+  pub retention_years: u32,
+}
+
+pub struct UpdateRetentionPolicyUseCase {
+  retention_service: Arc<RetentionService>,
+}
+
+impl UpdateRetentionPolicyUseCase {
+  pub fn new(retention_service: Arc<RetentionService>) -> Self {
+    Self { retention_service }
+  }
+
+  pub async fn execute(
+    &self,
+    command: UpdateRetentionPolicyCommand,
+  ) -> Result<RetentionPolicy, RetentionError> {
+    let policy = self
+      .retention_service
+      .update_policy(command.user_id, command.company_id, command.retention_years)
+      .await?;
+
+    tracing::info!(
+      "Retention period of company {} set to {} years by user {}",
+      command.company_id,
+      policy.retention_years,
+      command.user_id
+    );
+    Ok(policy)
+  }
+}
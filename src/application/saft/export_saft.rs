@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::domain::saft::{AuditFileWriter, SaftError, SaftPeriod, SaftService};
+
+#[derive(Debug)]
+pub struct ExportSaftCommand {
+  pub company_id: Uuid,
+  pub start_date: NaiveDate,
+  pub end_date: NaiveDate,
+}
+
+#[derive(Debug)]
+pub struct ExportSaftResponse {
+  pub file_name: String,
+  pub content: Vec<u8>,
+}
+
+pub struct ExportSaftUseCase {
+  saft_service: Arc<SaftService>,
+  writer: Arc<dyn AuditFileWriter>,
+}
+
+impl ExportSaftUseCase {
+  pub fn new(saft_service: Arc<SaftService>, writer: Arc<dyn AuditFileWriter>) -> Self {
+    Self {
+      saft_service,
+      writer,
+    }
+  }
+
+  pub async fn execute(&self, command: ExportSaftCommand) -> Result<ExportSaftResponse, SaftError> {
+    let period = SaftPeriod::new(command.start_date, command.end_date)?;
+    let audit_file = self
+      .saft_service
+      .build_audit_file(command.company_id, period)
+      .await?;
+    let content = self.writer.write(&audit_file)?;
+
+    let company = audit_file
+      .header
+      .company
+      .registration_number
+      .unwrap_or_else(|| command.company_id.simple().to_string());
+    let file_name = format!(
+      "SAF-T_{}_{}_{}.xml",
+      company,
+      period.start.format("%Y%m%d"),
+      period.end.format("%Y%m%d")
+    );
+
+    Ok(ExportSaftResponse { file_name, content })
+  }
+}
@@ -0,0 +1,3 @@
+mod export_saft;
+
+pub use export_saft::{ExportSaftCommand, ExportSaftResponse, ExportSaftUseCase};
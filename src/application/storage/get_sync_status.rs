@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::storage::{
+  StorageError, SyncStatus, UploadJob, UploadOutboxService, UploadStatus, UploadTarget,
+};
+
+#[derive(Debug)]
+pub struct GetSyncStatusCommand {
+  pub company_id: Uuid,
+  pub target: UploadTarget,
+  /// Report or invoice the files belong to
+  pub owner_id: Uuid,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SyncedFileDto {
+  pub path: String,
+  pub file_name: String,
+  pub cloud_id: Option<String>,
+  /// `None` until the first reconciliation after the upload
+  pub sync_status: Option<String>,
+  pub sync_label: String,
+  pub synced_at: Option<DateTime<Utc>>,
+}
+
+impl From<UploadJob> for SyncedFileDto {
+  fn from(job: UploadJob) -> Self {
+    Self {
+      file_name: job.file_name().to_string(),
+      path: job.path,
+      cloud_id: job.cloud_id,
+      sync_status: job.sync_status.map(|s| s.as_str().to_string()),
+      sync_label: job
+        .sync_status
+        .map_or("Not checked yet", |s| s.label())
+        .to_string(),
+      synced_at: job.synced_at,
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+pub struct GetSyncStatusResponse {
+  /// Worst status of the files: "missing", "drifted" or "in_sync"; "none"
+  /// until a reconciliation has checked any of them, as for files not
+  /// stored in Google Drive
+  pub status: String,
+  pub files: Vec<SyncedFileDto>,
+}
+
+/// How the files uploaded for a report or invoice compare to what is in
+/// Google Drive, as of the last reconciliation
+pub struct GetSyncStatusUseCase {
+  outbox: Arc<UploadOutboxService>,
+}
+
+impl GetSyncStatusUseCase {
+  pub fn new(outbox: Arc<UploadOutboxService>) -> Self {
+    Self { outbox }
+  }
+
+  pub async fn execute(
+    &self,
+    command: GetSyncStatusCommand,
+  ) -> Result<GetSyncStatusResponse, StorageError> {
+    // Uploaded files, and those found missing and being uploaded again
+    let jobs: Vec<UploadJob> = self
+      .outbox
+      .jobs(command.target, command.owner_id)
+      .await?
+      .into_iter()
+      .filter(|job| job.company_id == command.company_id)
+      .filter(|job| {
+        job.status == UploadStatus::Succeeded || job.sync_status == Some(SyncStatus::Missing)
+      })
+      .collect();
+
+    let status = if jobs.iter().all(|j| j.sync_status.is_none()) {
+      "none"
+    } else if jobs
+      .iter()
+      .any(|j| j.sync_status == Some(SyncStatus::Missing))
+    {
+      "missing"
+    } else if jobs
+      .iter()
+      .any(|j| j.sync_status.is_some_and(|s| s.is_drifted()))
+    {
+      "drifted"
+    } else {
+      "in_sync"
+    };
+
+    Ok(GetSyncStatusResponse {
+      status: status.to_string(),
+      files: jobs.into_iter().map(SyncedFileDto::from).collect(),
+    })
+  }
+}
@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::storage::{StorageError, UploadJob, UploadOutboxService, UploadTarget};
+
+#[derive(Debug)]
+pub struct ListPendingUploadsCommand {
+  pub company_id: Uuid,
+  pub target: UploadTarget,
+  /// Report or invoice the files belong to
+  pub owner_id: Uuid,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PendingUploadDto {
+  pub id: Uuid,
+  pub path: String,
+  pub file_name: String,
+  pub status: String,
+  pub status_label: String,
+  pub attempts: i32,
+  pub max_attempts: i32,
+  pub next_attempt_at: DateTime<Utc>,
+  pub last_error: Option<String>,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl From<UploadJob> for PendingUploadDto {
+  fn from(job: UploadJob) -> Self {
+    Self {
+      id: job.id,
+      file_name: job.file_name().to_string(),
+      status_label: job.status_label().to_string(),
+      path: job.path,
+      status: job.status.as_str().to_string(),
+      attempts: job.attempts,
+      max_attempts: UploadJob::MAX_ATTEMPTS,
+      next_attempt_at: job.next_attempt_at,
+      last_error: job.last_error,
+      updated_at: job.updated_at,
+    }
+  }
+}
+
+/// Files of a report or invoice still waiting to be uploaded, or given up on
+pub struct ListPendingUploadsUseCase {
+  outbox: Arc<UploadOutboxService>,
+}
+
+impl ListPendingUploadsUseCase {
+  pub fn new(outbox: Arc<UploadOutboxService>) -> Self {
+    Self { outbox }
+  }
+
+  pub async fn execute(
+    &self,
+    command: ListPendingUploadsCommand,
+  ) -> Result<Vec<PendingUploadDto>, StorageError> {
+    let jobs = self
+      .outbox
+      .open_jobs(command.target, command.owner_id)
+      .await?;
+
+    Ok(
+      jobs
+        .into_iter()
+        .filter(|job| job.company_id == command.company_id)
+        .map(PendingUploadDto::from)
+        .collect(),
+    )
+  }
+}
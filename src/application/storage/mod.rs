@@ -0,0 +1,11 @@
+mod get_sync_status;
+mod list_pending_uploads;
+mod reconcile_drive_files;
+
+pub use get_sync_status::{
+  GetSyncStatusCommand, GetSyncStatusResponse, GetSyncStatusUseCase, SyncedFileDto,
+};
+pub use list_pending_uploads::{
+  ListPendingUploadsCommand, ListPendingUploadsUseCase, PendingUploadDto,
+};
+pub use reconcile_drive_files::{ReconcileDriveFilesResponse, ReconcileDriveFilesUseCase};
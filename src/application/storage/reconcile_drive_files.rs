@@ -0,0 +1,351 @@
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::StorageProvider;
+use crate::domain::company::ports::CompanyRepository;
+use crate::domain::storage::{
+  DriveInspectorResolver, StorageError, SyncStatus, UploadOutboxService,
+};
+
+/// Counts of a reconciliation run
+#[derive(Debug, Default, Serialize)]
+pub struct ReconcileDriveFilesResponse {
+  pub checked: usize,
+  pub in_sync: usize,
+  /// Moved or renamed in Drive
+  pub drifted: usize,
+  /// Gone from Drive and queued again for upload
+  pub missing: usize,
+}
+
+/// Compare the files uploaded to each company's Google Drive with what is
+/// there now. Files moved or renamed in Drive are flagged but left where
+/// they are; deleted or trashed ones are queued again, and the upload
+/// outbox puts them back.
+pub struct ReconcileDriveFilesUseCase {
+  company_repo: Arc<dyn CompanyRepository>,
+  outbox: Arc<UploadOutboxService>,
+  drives: Arc<dyn DriveInspectorResolver>,
+}
+
+impl ReconcileDriveFilesUseCase {
+  pub fn new(
+    company_repo: Arc<dyn CompanyRepository>,
+    outbox: Arc<UploadOutboxService>,
+    drives: Arc<dyn DriveInspectorResolver>,
+  ) -> Self {
+    Self {
+      company_repo,
+      outbox,
+      drives,
+    }
+  }
+
+  /// Reconcile every company storing its files in Google Drive. A company
+  /// whose Drive can't be reached is skipped until the next run.
+  pub async fn execute(&self) -> Result<ReconcileDriveFilesResponse, StorageError> {
+    let companies = self
+      .company_repo
+      .find_all()
+      .await
+      .map_err(|e| StorageError::Repository(RepositoryError::QueryFailed(e.to_string())))?;
+
+    let mut total = ReconcileDriveFilesResponse::default();
+    for company in companies {
+      match self.reconcile_company(company.id).await {
+        Ok(counts) => {
+          total.checked += counts.checked;
+          total.in_sync += counts.in_sync;
+          total.drifted += counts.drifted;
+          total.missing += counts.missing;
+        }
+        Err(e) => {
+          tracing::warn!(
+            "Drive reconciliation of company {} failed: {}",
+            company.id,
+            e
+          );
+        }
+      }
+    }
+
+    Ok(total)
+  }
+
+  pub async fn reconcile_company(
+    &self,
+    company_id: Uuid,
+  ) -> Result<ReconcileDriveFilesResponse, StorageError> {
+    let mut counts = ReconcileDriveFilesResponse::default();
+    let Some(drive) = self.drives.for_company(company_id).await? else {
+      return Ok(counts);
+    };
+
+    // Files the company stored elsewhere before switching to Drive are not
+    // Drive's to check
+    for job in self
+      .outbox
+      .uploaded_jobs(company_id)
+      .await?
+      .into_iter()
+      .filter(|job| job.provider == StorageProvider::GoogleDrive)
+    {
+      let Some(file_id) = job.cloud_id.clone() else {
+        continue;
+      };
+      let remote = drive.inspect(&file_id).await?;
+      let status = job.sync_status_of(remote.as_ref());
+
+      counts.checked += 1;
+      match status {
+        SyncStatus::InSync => counts.in_sync += 1,
+        SyncStatus::Moved | SyncStatus::Renamed => counts.drifted += 1,
+        SyncStatus::Missing => counts.missing += 1,
+      }
+      self.outbox.record_sync(job, status).await?;
+    }
+
+    Ok(counts)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use async_trait::async_trait;
+  use chrono::{DateTime, Utc};
+  use std::sync::Mutex;
+
+  use crate::domain::company::{Company, CompanyError};
+  use crate::domain::storage::{
+    DriveFileInspector, UploadJob, UploadJobRepository, UploadStatus, UploadTarget,
+  };
+  use crate::infrastructure::cloud::{DriveEndpoints, fake_drive};
+
+  #[derive(Default)]
+  struct InMemoryJobs(Mutex<Vec<UploadJob>>);
+
+  #[async_trait]
+  impl UploadJobRepository for InMemoryJobs {
+    async fn create(&self, job: UploadJob) -> Result<UploadJob, StorageError> {
+      self.0.lock().unwrap().push(job.clone());
+      Ok(job)
+    }
+
+    async fn update(&self, job: UploadJob) -> Result<UploadJob, StorageError> {
+      let mut jobs = self.0.lock().unwrap();
+      let stored = jobs
+        .iter_mut()
+        .find(|j| j.id == job.id)
+        .ok_or(StorageError::JobNotFound)?;
+      *stored = job.clone();
+      Ok(job)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), StorageError> {
+      self.0.lock().unwrap().retain(|j| j.id != id);
+      Ok(())
+    }
+
+    async fn claim(
+      &self,
+      job: UploadJob,
+      now: DateTime<Utc>,
+    ) -> Result<Option<UploadJob>, StorageError> {
+      let mut jobs = self.0.lock().unwrap();
+      match jobs.iter_mut().find(|j| j.id == job.id && j.is_due(now)) {
+        Some(stored) => {
+          *stored = job.clone();
+          Ok(Some(job))
+        }
+        None => Ok(None),
+      }
+    }
+
+    async fn find_due(
+      &self,
+      target: UploadTarget,
+      now: DateTime<Utc>,
+      _limit: i64,
+    ) -> Result<Vec<UploadJob>, StorageError> {
+      let jobs = self.0.lock().unwrap();
+      Ok(
+        jobs
+          .iter()
+          .filter(|j| j.target == target && j.is_due(now))
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn find_by_owner(
+      &self,
+      target: UploadTarget,
+      owner_id: Uuid,
+    ) -> Result<Vec<UploadJob>, StorageError> {
+      let jobs = self.0.lock().unwrap();
+      Ok(
+        jobs
+          .iter()
+          .filter(|j| j.target == target && j.owner_id == owner_id)
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn find_uploaded(&self, company_id: Uuid) -> Result<Vec<UploadJob>, StorageError> {
+      let jobs = self.0.lock().unwrap();
+      Ok(
+        jobs
+          .iter()
+          .filter(|j| j.company_id == company_id && j.status == UploadStatus::Succeeded)
+          .cloned()
+          .collect(),
+      )
+    }
+  }
+
+  struct FakeDriveResolver(DriveEndpoints);
+
+  #[async_trait]
+  impl DriveInspectorResolver for FakeDriveResolver {
+    async fn for_company(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Option<Arc<dyn DriveFileInspector>>, StorageError> {
+      Ok(Some(Arc::new(fake_drive::api(self.0.clone()))))
+    }
+  }
+
+  struct NoCompanies;
+
+  #[async_trait]
+  impl CompanyRepository for NoCompanies {
+    async fn create(&self, company: Company) -> Result<Company, CompanyError> {
+      Ok(company)
+    }
+    async fn find_by_id(&self, _id: Uuid) -> Result<Option<Company>, CompanyError> {
+      Ok(None)
+    }
+    async fn find_all(&self) -> Result<Vec<Company>, CompanyError> {
+      Ok(Vec::new())
+    }
+    async fn update(&self, company: Company) -> Result<Company, CompanyError> {
+      Ok(company)
+    }
+    async fn delete(&self, _id: Uuid) -> Result<(), CompanyError> {
+      Ok(())
+    }
+    async fn update_oauth_tokens(
+      &self,
+      _company_id: &Uuid,
+      _access_token: String,
+      _refresh_token: String,
+      _expires_at: DateTime<Utc>,
+      _connected_by: Uuid,
+    ) -> Result<(), CompanyError> {
+      Ok(())
+    }
+    async fn clear_oauth_tokens(&self, _company_id: &Uuid) -> Result<(), CompanyError> {
+      Ok(())
+    }
+  }
+
+  fn uploaded(company_id: Uuid, path: &str, folder_id: &str, file_id: &str) -> UploadJob {
+    let mut job = UploadJob::new(
+      company_id,
+      UploadTarget::ReportFile,
+      Uuid::new_v4(),
+      path.to_string(),
+      folder_id.to_string(),
+      "data/documents/1.pdf".to_string(),
+      Uuid::nil(),
+    );
+    job.provider = StorageProvider::GoogleDrive;
+    job.succeed(file_id.to_string());
+    job
+  }
+
+  #[actix_web::test]
+  async fn test_flags_drift_and_queues_missing_files_again() {
+    let (endpoints, drive) = fake_drive::start();
+    let company_id = Uuid::new_v4();
+    let jobs = Arc::new(InMemoryJobs::default());
+    let (kept, renamed, moved, trashed, deleted) = {
+      let mut drive = drive.lock().unwrap();
+      let shared = drive.add_shared_drive("Accounting");
+      let folder = drive.add_folder("incoming", &shared);
+      let other = drive.add_folder("Archive", &shared);
+      let mut add = |name: &str| {
+        let file_id = drive.add_file(name, &folder, b"%PDF");
+        let job = uploaded(
+          company_id,
+          &format!("EE12/incoming/{}", name),
+          &folder,
+          &file_id,
+        );
+        jobs.0.lock().unwrap().push(job.clone());
+        job
+      };
+      let jobs = (
+        add("a.pdf"),
+        add("b.pdf"),
+        add("c.pdf"),
+        add("d.pdf"),
+        add("e.pdf"),
+      );
+      drive.rename(jobs.1.cloud_id.as_ref().unwrap(), "renamed.pdf");
+      drive.move_to(jobs.2.cloud_id.as_ref().unwrap(), &other);
+      drive.trash(jobs.3.cloud_id.as_ref().unwrap());
+      drive.delete(jobs.4.cloud_id.as_ref().unwrap());
+      jobs
+    };
+    // Uploaded to S3 before the company switched to Drive
+    let mut in_s3 = uploaded(
+      company_id,
+      "EE12/incoming/f.pdf",
+      "reports/03.2026",
+      "reports/03.2026/f.pdf",
+    );
+    in_s3.provider = StorageProvider::S3;
+    jobs.0.lock().unwrap().push(in_s3.clone());
+    let use_case = ReconcileDriveFilesUseCase::new(
+      Arc::new(NoCompanies),
+      Arc::new(UploadOutboxService::new(jobs.clone())),
+      Arc::new(FakeDriveResolver(endpoints)),
+    );
+
+    let counts = use_case.reconcile_company(company_id).await.unwrap();
+    assert_eq!(counts.checked, 5);
+    assert_eq!(counts.in_sync, 1);
+    assert_eq!(counts.drifted, 2);
+    assert_eq!(counts.missing, 2);
+
+    let stored = |id: Uuid| {
+      jobs
+        .0
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|j| j.id == id)
+        .cloned()
+        .unwrap()
+    };
+    assert_eq!(stored(kept.id).sync_status, Some(SyncStatus::InSync));
+    assert_eq!(stored(renamed.id).sync_status, Some(SyncStatus::Renamed));
+    assert_eq!(stored(moved.id).sync_status, Some(SyncStatus::Moved));
+    assert_eq!(stored(in_s3.id).sync_status, None);
+    for job in [trashed, deleted] {
+      let job = stored(job.id);
+      assert_eq!(job.sync_status, Some(SyncStatus::Missing));
+      assert!(job.is_due(Utc::now()));
+      assert_eq!(job.cloud_id, None);
+    }
+
+    // Queued files are not checked until they are uploaded again
+    let counts = use_case.reconcile_company(company_id).await.unwrap();
+    assert_eq!(counts.checked, 3);
+  }
+}
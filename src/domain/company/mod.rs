@@ -15,7 +15,9 @@ pub use ports::{
   CompanyMemberRepository, CompanyRepository,
 };
 pub use services::CompanyService;
-pub use storage_provider::{GoogleDriveConfig, StorageConfig, StorageProvider};
+pub use storage_provider::{
+  GoogleDriveConfig, LocalConfig, S3Config, StorageConfig, StorageProvider, WebDavConfig,
+};
 pub use value_objects::{
   BankAccountName, BankDetails, CompanyAddress, CompanyName, Iban, PhoneNumber, RegistryCode,
   VatNumber,
@@ -11,6 +11,8 @@ use super::{
 pub trait CompanyRepository: Send + Sync {
   async fn create(&self, company: Company) -> Result<Company, CompanyError>;
   async fn find_by_id(&self, id: Uuid) -> Result<Option<Company>, CompanyError>;
+  /// All companies of the instance, for maintenance commands
+  async fn find_all(&self) -> Result<Vec<Company>, CompanyError>;
   async fn update(&self, company: Company) -> Result<Company, CompanyError>;
   async fn delete(&self, id: Uuid) -> Result<(), CompanyError>;
 
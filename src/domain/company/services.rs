@@ -194,6 +194,8 @@ impl CompanyService {
   }
 
   /// Update company storage configuration (requires owner/admin permission)
+  ///
+  /// `reports_folder_id` is kept when `None` and cleared when blank.
   pub async fn update_storage_config(
     &self,
     company_id: Uuid,
@@ -201,6 +203,7 @@ impl CompanyService {
     storage_provider: Option<String>,
     storage_config: Option<String>,
     google_drive_folder_id: Option<String>,
+    reports_folder_id: Option<String>,
   ) -> Result<Company, CompanyError> {
     // Verify requester is member
     let member = self.verify_membership(company_id, requester_id).await?;
@@ -221,6 +224,11 @@ impl CompanyService {
     company.storage_provider = storage_provider;
     company.storage_config = storage_config;
     company.google_drive_folder_id = google_drive_folder_id;
+    if let Some(reports_folder_id) = reports_folder_id {
+      let reports_folder_id = reports_folder_id.trim();
+      company.reports_folder_id =
+        (!reports_folder_id.is_empty()).then(|| reports_folder_id.to_string());
+    }
     company.updated_at = chrono::Utc::now();
 
     // Save
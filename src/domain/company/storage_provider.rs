@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use uuid::Uuid;
 
 /// Storage provider type for cloud uploads
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -8,6 +10,10 @@ pub enum StorageProvider {
   #[default]
   None,
   GoogleDrive,
+  S3,
+  #[serde(rename = "webdav")]
+  WebDav,
+  Local,
 }
 
 impl StorageProvider {
@@ -15,6 +21,9 @@ impl StorageProvider {
     match self {
       StorageProvider::None => "none",
       StorageProvider::GoogleDrive => "google_drive",
+      StorageProvider::S3 => "s3",
+      StorageProvider::WebDav => "webdav",
+      StorageProvider::Local => "local",
     }
   }
 }
@@ -26,6 +35,9 @@ impl FromStr for StorageProvider {
     match s.to_lowercase().as_str() {
       "none" | "" => Ok(StorageProvider::None),
       "google_drive" => Ok(StorageProvider::GoogleDrive),
+      "s3" => Ok(StorageProvider::S3),
+      "webdav" => Ok(StorageProvider::WebDav),
+      "local" => Ok(StorageProvider::Local),
       _ => Err(format!("Unknown storage provider: {}", s)),
     }
   }
@@ -37,6 +49,10 @@ impl FromStr for StorageProvider {
 pub enum StorageConfig {
   None,
   GoogleDrive(GoogleDriveConfig),
+  S3(S3Config),
+  #[serde(rename = "webdav")]
+  WebDav(WebDavConfig),
+  Local(LocalConfig),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,3 +60,125 @@ pub struct GoogleDriveConfig {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub service_account_key: Option<String>, // Base64 encoded key or path (deprecated - use OAuth)
 }
+
+/// Bucket of an S3-compatible object store (AWS S3, MinIO, Backblaze B2,
+/// Hetzner). Folders are key prefixes below `prefix`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S3Config {
+  pub bucket: String,
+  pub region: String,
+  /// Base URL of an S3-compatible service, addressed path-style; AWS when
+  /// not set
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub endpoint: Option<String>,
+  #[serde(default)]
+  pub prefix: String,
+  pub access_key_id: String,
+  /// Encrypted with the application's token encryption key
+  pub secret_access_key: String,
+}
+
+/// WebDAV server such as Nextcloud or ownCloud. Folders are collections
+/// below `root_folder`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebDavConfig {
+  /// WebDAV URL of the user's files, e.g.
+  /// `https://cloud.example.com/remote.php/dav/files/alice`
+  pub url: String,
+  pub username: String,
+  /// App password, encrypted with the application's token encryption key
+  pub app_password: String,
+  #[serde(default)]
+  pub root_folder: String,
+}
+
+/// Directory on the server, inside the company's own directory below the
+/// configured local storage root
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LocalConfig {
+  /// Relative path below the company's directory; the directory itself when empty
+  #[serde(default)]
+  pub directory: String,
+}
+
+impl LocalConfig {
+  /// Whether the directory is empty or a plain relative path: no absolute,
+  /// empty, `.` or `..` components, drive letters or control characters
+  pub fn is_valid_directory(&self) -> bool {
+    let directory = self.directory.trim();
+    directory.is_empty()
+      || directory.split(['/', '\\']).all(|part| {
+        !part.is_empty()
+          && part != "."
+          && part != ".."
+          && !part.contains(':')
+          && !part.chars().any(char::is_control)
+      })
+  }
+
+  /// Where a company's files go: `<root>/<company ID>/<directory>`, so no
+  /// two companies ever share a directory. `None` for an invalid directory.
+  pub fn company_path(&self, root: &Path, company_id: Uuid) -> Option<PathBuf> {
+    if !self.is_valid_directory() {
+      return None;
+    }
+    let mut path = root.join(company_id.to_string());
+    for part in self
+      .directory
+      .trim()
+      .split(['/', '\\'])
+      .filter(|p| !p.is_empty())
+    {
+      path.push(part);
+    }
+    Some(path)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_local_directory_stays_below_the_root() {
+    let config = |directory: &str| LocalConfig {
+      directory: directory.to_string(),
+    };
+
+    assert!(config("").is_valid_directory());
+    assert!(config("acme/books").is_valid_directory());
+    assert!(!config("/etc").is_valid_directory());
+    assert!(!config("acme/../../etc").is_valid_directory());
+    assert!(!config("C:\\data").is_valid_directory());
+    assert!(!config(".").is_valid_directory());
+    assert!(!config("acme//books").is_valid_directory());
+    assert!(!config("acme/./books").is_valid_directory());
+  }
+
+  #[test]
+  fn test_companies_never_share_a_local_directory() {
+    let root = Path::new("/srv/taxbyte");
+    let a = Uuid::new_v4();
+    let b = Uuid::new_v4();
+    let config = |directory: String| LocalConfig { directory };
+
+    // Company A naming company B's directory stays inside its own
+    let path = config(b.to_string()).company_path(root, a).unwrap();
+    assert_eq!(path, root.join(a.to_string()).join(b.to_string()));
+    assert!(!path.starts_with(root.join(b.to_string())));
+
+    for directory in ["", "books", "acme/books", "  "] {
+      let pa = config(directory.to_string()).company_path(root, a).unwrap();
+      let pb = config(directory.to_string()).company_path(root, b).unwrap();
+      assert_ne!(pa, pb);
+      assert!(pa.starts_with(root.join(a.to_string())));
+      assert!(pb.starts_with(root.join(b.to_string())));
+    }
+    assert_eq!(
+      config(String::new()).company_path(root, a).unwrap(),
+      root.join(a.to_string())
+    );
+    assert!(config(".".to_string()).company_path(root, a).is_none());
+    assert!(config("../x".to_string()).company_path(root, a).is_none());
+  }
+}
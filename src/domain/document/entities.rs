@@ -0,0 +1,123 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::value_objects::{DocumentOwner, IntegrityStatus, file_extension};
+
+/// A stored file. Files are content-addressed: a company stores each
+/// content once, however many entities link to it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Document {
+  pub id: Uuid,
+  pub company_id: Uuid,
+  /// Hex SHA-256 of the content
+  pub sha256: String,
+  pub size_bytes: i64,
+  pub mime_type: String,
+  /// File name it was first uploaded or generated as
+  pub original_name: String,
+  /// Path of the file below the document store's root
+  pub storage_key: String,
+  pub uploaded_by: Option<Uuid>,
+  pub integrity_status: IntegrityStatus,
+  pub checked_at: Option<DateTime<Utc>>,
+  /// Set when the document was replaced while its owner was still under
+  /// retention: it is kept, even unlinked, until that day has passed
+  pub retained_until: Option<NaiveDate>,
+  pub created_at: DateTime<Utc>,
+}
+
+impl Document {
+  pub fn new(
+    company_id: Uuid,
+    sha256: String,
+    size_bytes: i64,
+    mime_type: String,
+    original_name: String,
+    uploaded_by: Option<Uuid>,
+  ) -> Self {
+    let id = Uuid::new_v4();
+    let storage_key = Self::storage_key_for(company_id, &sha256, id, &original_name);
+    Self {
+      id,
+      company_id,
+      sha256,
+      size_bytes,
+      mime_type,
+      original_name,
+      storage_key,
+      uploaded_by,
+      integrity_status: IntegrityStatus::Ok,
+      checked_at: None,
+      retained_until: None,
+      created_at: Utc::now(),
+    }
+  }
+
+  /// `<company>/<first two hex digits>/<sha256>-<id>.<ext>`, so no
+  /// directory grows too large. The ID keeps the content of a deleted
+  /// document apart from the same content stored again meanwhile.
+  pub fn storage_key_for(company_id: Uuid, sha256: &str, id: Uuid, original_name: &str) -> String {
+    let ext = file_extension(original_name).unwrap_or_else(|| "bin".to_string());
+    format!("{}/{}/{}-{}.{}", company_id, &sha256[..2], sha256, id, ext)
+  }
+}
+
+/// Link from an owning entity to its document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentLink {
+  pub document_id: Uuid,
+  pub owner: DocumentOwner,
+  pub owner_id: Uuid,
+  pub created_at: DateTime<Utc>,
+}
+
+impl DocumentLink {
+  pub fn new(document_id: Uuid, owner: DocumentOwner, owner_id: Uuid) -> Self {
+    Self {
+      document_id,
+      owner,
+      owner_id,
+      created_at: Utc::now(),
+    }
+  }
+}
+
+/// An owner's removed link
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unlinked {
+  /// Document the owner was linked to
+  pub document_id: Uuid,
+  /// That document when it was deleted along with its last link; its
+  /// content is left to remove
+  pub removed: Option<Document>,
+}
+
+/// Counts of an integrity check run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct IntegrityReport {
+  pub checked: usize,
+  pub missing: usize,
+  pub corrupted: usize,
+}
+
+/// Counts of an import of files stored before the document store existed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ImportReport {
+  /// Files now stored as documents of their owners
+  pub imported: usize,
+  /// Paths whose file no longer exists or is empty
+  pub missing: usize,
+}
+
+/// Counts of an encryption run over stored files
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct EncryptionReport {
+  /// Documents of the document store newly encrypted
+  pub documents: usize,
+  /// Files outside the document store, e.g. PDFs generated earlier, newly
+  /// encrypted in place
+  pub files: usize,
+  /// Files already encrypted
+  pub skipped: usize,
+}
@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+use crate::domain::auth::errors::RepositoryError;
+
+#[derive(Debug, Error)]
+pub enum DocumentError {
+  #[error("Document not found")]
+  NotFound,
+
+  #[error("Validation error: {0}")]
+  Validation(String),
+
+  #[error("File error: {0}")]
+  FileError(String),
+
+  #[error("Encryption error: {0}")]
+  Encryption(String),
+
+  #[error("Repository error: {0}")]
+  Repository(#[from] RepositoryError),
+}
+
+impl From<sqlx::Error> for DocumentError {
+  fn from(error: sqlx::Error) -> Self {
+    DocumentError::Repository(RepositoryError::from(error))
+  }
+}
@@ -0,0 +1,13 @@
+pub mod entities;
+pub mod errors;
+pub mod ports;
+pub mod services;
+pub mod value_objects;
+
+pub use entities::{
+  Document, DocumentLink, EncryptionReport, ImportReport, IntegrityReport, Unlinked,
+};
+pub use errors::DocumentError;
+pub use ports::{DataKeyRepository, DocumentBlobStore, DocumentCipher, DocumentRepository};
+pub use services::{DocumentService, NewDocument, PlainFile};
+pub use value_objects::{DocumentOwner, IntegrityStatus, file_extension, mime_type_for};
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+use super::{
+  entities::{Document, DocumentLink, Unlinked},
+  errors::DocumentError,
+  value_objects::{DocumentOwner, IntegrityStatus},
+};
+
+#[async_trait]
+pub trait DocumentRepository: Send + Sync {
+  /// Store a new document. When the company stored the same content
+  /// meanwhile, that document is returned instead and `document` dropped.
+  async fn create(&self, document: Document) -> Result<Document, DocumentError>;
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<Document>, DocumentError>;
+  async fn find_by_hash(
+    &self,
+    company_id: Uuid,
+    sha256: &str,
+  ) -> Result<Option<Document>, DocumentError>;
+  /// Documents of a company, newest first
+  async fn find_by_company(&self, company_id: Uuid) -> Result<Vec<Document>, DocumentError>;
+  /// Documents of all companies ordered by ID, starting after `after`
+  async fn find_page(
+    &self,
+    after: Option<Uuid>,
+    limit: i64,
+  ) -> Result<Vec<Document>, DocumentError>;
+  async fn update_integrity(
+    &self,
+    id: Uuid,
+    status: IntegrityStatus,
+    checked_at: DateTime<Utc>,
+  ) -> Result<(), DocumentError>;
+  /// Delete the document unless something links to it or it is retained
+  /// beyond `today`. Returns it when deleted; its content is left to remove.
+  async fn delete_if_unlinked(
+    &self,
+    id: Uuid,
+    today: NaiveDate,
+  ) -> Result<Option<Document>, DocumentError>;
+  /// Documents nothing links to any more whose retention ended before `today`
+  async fn find_released(&self, today: NaiveDate) -> Result<Vec<Document>, DocumentError>;
+
+  /// Link the owner to a document, replacing its earlier link, in one
+  /// transaction with releasing the document linked before: it is kept until
+  /// `keep_replaced_until` when given, and deleted as by
+  /// [`DocumentRepository::delete_if_unlinked`] otherwise. Returns the
+  /// deleted document. Fails with `NotFound` when the document to link was
+  /// deleted meanwhile.
+  async fn link(
+    &self,
+    link: DocumentLink,
+    keep_replaced_until: Option<NaiveDate>,
+    today: NaiveDate,
+  ) -> Result<Option<Document>, DocumentError>;
+  /// Remove the owner's link and, in the same transaction, delete its
+  /// document as by [`DocumentRepository::delete_if_unlinked`]
+  async fn unlink(
+    &self,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+    today: NaiveDate,
+  ) -> Result<Option<Unlinked>, DocumentError>;
+  async fn find_link(
+    &self,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+  ) -> Result<Option<DocumentLink>, DocumentError>;
+  async fn find_links(&self, document_id: Uuid) -> Result<Vec<DocumentLink>, DocumentError>;
+}
+
+/// Where the content of documents is kept
+#[async_trait]
+pub trait DocumentBlobStore: Send + Sync {
+  /// Write the content at `key`, replacing what is there
+  async fn write(&self, key: &str, content: &[u8]) -> Result<(), DocumentError>;
+  /// Content at `key`, `None` when there is none
+  async fn read(&self, key: &str) -> Result<Option<Vec<u8>>, DocumentError>;
+  async fn delete(&self, key: &str) -> Result<(), DocumentError>;
+  /// Local file path of `key`, for the code reading files by path
+  fn path(&self, key: &str) -> String;
+  /// Write a decrypted copy named after `name` where only the server can
+  /// read it, returning its path. The caller removes it when done.
+  async fn write_plain_copy(&self, name: &str, content: &[u8]) -> Result<String, DocumentError>;
+}
+
+/// Envelope encryption of document content: each company's files are
+/// encrypted with its own data key, which is itself wrapped by the instance's
+/// master key
+#[async_trait]
+pub trait DocumentCipher: Send + Sync {
+  /// Encrypt content with the company's data key, creating the key on first use
+  async fn seal(&self, company_id: Uuid, content: &[u8]) -> Result<Vec<u8>, DocumentError>;
+  /// Decrypt sealed content; content that is not sealed is returned as is
+  async fn open(&self, stored: Vec<u8>) -> Result<Vec<u8>, DocumentError>;
+  fn is_sealed(&self, stored: &[u8]) -> bool;
+}
+
+/// Data keys of companies, each stored wrapped by the master key
+#[async_trait]
+pub trait DataKeyRepository: Send + Sync {
+  async fn find(&self, company_id: Uuid) -> Result<Option<String>, DocumentError>;
+  /// Store the company's wrapped key unless it has one already. Returns the
+  /// key stored for the company.
+  async fn create_if_absent(
+    &self,
+    company_id: Uuid,
+    wrapped_key: String,
+  ) -> Result<String, DocumentError>;
+}
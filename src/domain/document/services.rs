@@ -0,0 +1,1000 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::{
+  entities::{Document, DocumentLink, EncryptionReport, IntegrityReport},
+  errors::DocumentError,
+  ports::{DocumentBlobStore, DocumentCipher, DocumentRepository},
+  value_objects::{DocumentOwner, IntegrityStatus},
+};
+
+/// Number of documents read per page when going through the whole store
+const PAGE_SIZE: i64 = 100;
+
+/// A file to store as a document
+pub struct NewDocument {
+  pub company_id: Uuid,
+  pub content: Vec<u8>,
+  pub mime_type: String,
+  pub original_name: String,
+  pub uploaded_by: Option<Uuid>,
+  /// Retention end of the owner: the document this one replaces is kept
+  /// until then instead of being removed. `None` when the owner is not
+  /// retained, e.g. a draft invoice.
+  pub keep_replaced_until: Option<NaiveDate>,
+}
+
+/// A file in plain form for code that reads files by path, such as the
+/// cloud storage adapters. A decrypted copy is removed again on drop.
+pub struct PlainFile {
+  path: String,
+  temporary: bool,
+}
+
+impl PlainFile {
+  pub fn path(&self) -> &str {
+    &self.path
+  }
+}
+
+impl Drop for PlainFile {
+  fn drop(&mut self) {
+    if self.temporary {
+      let _ = std::fs::remove_file(&self.path);
+    }
+  }
+}
+
+/// Stores uploaded and generated files once per company, keyed by their
+/// SHA-256, and links them to the entities they belong to
+///
+/// With encryption at rest, content is sealed with the company's data key
+/// before it is written. Files written before encryption was turned on stay
+/// readable: reads open sealed content and pass plain content through.
+pub struct DocumentService {
+  document_repo: Arc<dyn DocumentRepository>,
+  blob_store: Arc<dyn DocumentBlobStore>,
+  cipher: Arc<dyn DocumentCipher>,
+  encrypt_at_rest: bool,
+}
+
+impl DocumentService {
+  pub fn new(
+    document_repo: Arc<dyn DocumentRepository>,
+    blob_store: Arc<dyn DocumentBlobStore>,
+    cipher: Arc<dyn DocumentCipher>,
+    encrypt_at_rest: bool,
+  ) -> Self {
+    Self {
+      document_repo,
+      blob_store,
+      cipher,
+      encrypt_at_rest,
+    }
+  }
+
+  /// Store a file as the owner's document, replacing the owner's earlier
+  /// one. Content the company already has is not stored again.
+  pub async fn store(
+    &self,
+    file: NewDocument,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+  ) -> Result<Document, DocumentError> {
+    if file.content.is_empty() {
+      return Err(DocumentError::Validation("File is empty".to_string()));
+    }
+    let sha256 = hex::encode(Sha256::digest(&file.content));
+
+    let mut retried = false;
+    loop {
+      let document = self.find_or_create(&file, &sha256).await?;
+      let linked = self
+        .document_repo
+        .link(
+          DocumentLink::new(document.id, owner, owner_id),
+          file.keep_replaced_until,
+          Utc::now().date_naive(),
+        )
+        .await;
+      match linked {
+        Ok(removed) => {
+          if let Some(removed) = removed {
+            self.blob_store.delete(&removed.storage_key).await?;
+          }
+          return Ok(document);
+        }
+        // The document found was deleted before it could be linked: store
+        // the content again
+        Err(DocumentError::NotFound) if !retried => retried = true,
+        Err(e) => return Err(e),
+      }
+    }
+  }
+
+  /// The company's document with the file's content, stored now unless it
+  /// has one
+  async fn find_or_create(
+    &self,
+    file: &NewDocument,
+    sha256: &str,
+  ) -> Result<Document, DocumentError> {
+    match self
+      .document_repo
+      .find_by_hash(file.company_id, sha256)
+      .await?
+    {
+      Some(existing) => {
+        // Heal a copy the integrity check found missing or corrupted
+        if existing.integrity_status != IntegrityStatus::Ok {
+          let stored = self.stored_form(file.company_id, &file.content).await?;
+          self
+            .blob_store
+            .write(&existing.storage_key, &stored)
+            .await?;
+          self
+            .document_repo
+            .update_integrity(existing.id, IntegrityStatus::Ok, Utc::now())
+            .await?;
+        }
+        Ok(existing)
+      }
+      None => {
+        let document = Document::new(
+          file.company_id,
+          sha256.to_string(),
+          file.content.len() as i64,
+          file.mime_type.clone(),
+          file.original_name.clone(),
+          file.uploaded_by,
+        );
+        let stored = self.stored_form(file.company_id, &file.content).await?;
+        self
+          .blob_store
+          .write(&document.storage_key, &stored)
+          .await?;
+        let created = self.document_repo.create(document.clone()).await?;
+        // A concurrent upload of the same content got there first
+        if created.id != document.id {
+          self.blob_store.delete(&document.storage_key).await?;
+        }
+        Ok(created)
+      }
+    }
+  }
+
+  /// Move a file written elsewhere, e.g. a generated PDF, into the store as
+  /// the owner's document. The content is read from `local_path`; the
+  /// content of `file` is ignored.
+  pub async fn import_file(
+    &self,
+    local_path: &str,
+    file: NewDocument,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+  ) -> Result<Document, DocumentError> {
+    let content = tokio::fs::read(local_path)
+      .await
+      .map_err(|e| DocumentError::FileError(format!("Failed to read {}: {}", local_path, e)))?;
+    let document = self
+      .store(NewDocument { content, ..file }, owner, owner_id)
+      .await?;
+
+    if self.path(&document) != local_path {
+      if let Err(e) = tokio::fs::remove_file(local_path).await {
+        tracing::warn!("Failed to remove imported file {}: {}", local_path, e);
+      }
+    }
+    Ok(document)
+  }
+
+  /// Remove the owner's document link; the document goes once nothing links
+  /// to it. Returns whether the owner had a document.
+  pub async fn detach(&self, owner: DocumentOwner, owner_id: Uuid) -> Result<bool, DocumentError> {
+    let today = Utc::now().date_naive();
+    match self.document_repo.unlink(owner, owner_id, today).await? {
+      Some(unlinked) => {
+        if let Some(removed) = unlinked.removed {
+          self.blob_store.delete(&removed.storage_key).await?;
+        }
+        Ok(true)
+      }
+      None => Ok(false),
+    }
+  }
+
+  /// The document the owner is linked to
+  pub async fn document_of(
+    &self,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+  ) -> Result<Option<Uuid>, DocumentError> {
+    let link = self.document_repo.find_link(owner, owner_id).await?;
+    Ok(link.map(|link| link.document_id))
+  }
+
+  /// Local file path of a document. The file may be encrypted: read it
+  /// with [`DocumentService::read_file`] or [`DocumentService::plain_file`].
+  pub fn path(&self, document: &Document) -> String {
+    self.blob_store.path(&document.storage_key)
+  }
+
+  /// Plain content of a stored file, given its path
+  pub async fn read_file(&self, path: &str) -> Result<Vec<u8>, DocumentError> {
+    let stored = tokio::fs::read(path)
+      .await
+      .map_err(|e| DocumentError::FileError(format!("Failed to read {}: {}", path, e)))?;
+    self.cipher.open(stored).await
+  }
+
+  /// The file at `path` in plain form: the file itself unless it is
+  /// encrypted, a decrypted copy only the server can read otherwise. A
+  /// missing file is passed on as is, for the reader to report.
+  pub async fn plain_file(&self, path: &str) -> Result<PlainFile, DocumentError> {
+    let stored = match tokio::fs::read(path).await {
+      Ok(stored) if self.cipher.is_sealed(&stored) => stored,
+      _ => {
+        return Ok(PlainFile {
+          path: path.to_string(),
+          temporary: false,
+        });
+      }
+    };
+
+    let content = self.cipher.open(stored).await?;
+    let name = std::path::Path::new(path)
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .unwrap_or_default();
+    let copy = self.blob_store.write_plain_copy(&name, &content).await?;
+    Ok(PlainFile {
+      path: copy,
+      temporary: true,
+    })
+  }
+
+  /// Encrypt the stored documents that are not encrypted yet, e.g. the
+  /// ones written before encryption at rest was turned on
+  pub async fn encrypt_stored(&self) -> Result<EncryptionReport, DocumentError> {
+    let mut report = EncryptionReport::default();
+    let mut after = None;
+
+    loop {
+      let page = self.document_repo.find_page(after, PAGE_SIZE).await?;
+      let Some(last) = page.last() else {
+        break;
+      };
+      after = Some(last.id);
+
+      for document in page {
+        let Some(stored) = self.blob_store.read(&document.storage_key).await? else {
+          // Left for the integrity check to flag
+          continue;
+        };
+        if self.cipher.is_sealed(&stored) {
+          report.skipped += 1;
+          continue;
+        }
+        let sealed = self.cipher.seal(document.company_id, &stored).await?;
+        self
+          .blob_store
+          .write(&document.storage_key, &sealed)
+          .await?;
+        report.documents += 1;
+      }
+    }
+
+    Ok(report)
+  }
+
+  /// Encrypt a file outside the document store in place, e.g. a PDF
+  /// generated before the document store existed. Returns whether the file
+  /// was encrypted now; missing and already encrypted files are left alone.
+  pub async fn encrypt_file(&self, company_id: Uuid, path: &str) -> Result<bool, DocumentError> {
+    let stored = match tokio::fs::read(path).await {
+      Ok(stored) => stored,
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+      Err(e) => {
+        return Err(DocumentError::FileError(format!(
+          "Failed to read {}: {}",
+          path, e
+        )));
+      }
+    };
+    if self.cipher.is_sealed(&stored) {
+      return Ok(false);
+    }
+
+    let sealed = self.cipher.seal(company_id, &stored).await?;
+    let temp = format!("{}.{}.tmp", path, Uuid::new_v4());
+    let written = async {
+      tokio::fs::write(&temp, &sealed).await?;
+      tokio::fs::rename(&temp, path).await
+    }
+    .await;
+    if let Err(e) = written {
+      let _ = tokio::fs::remove_file(&temp).await;
+      return Err(DocumentError::FileError(format!(
+        "Failed to write {}: {}",
+        path, e
+      )));
+    }
+    Ok(true)
+  }
+
+  pub async fn list(&self, company_id: Uuid) -> Result<Vec<Document>, DocumentError> {
+    self.document_repo.find_by_company(company_id).await
+  }
+
+  pub async fn links(&self, document_id: Uuid) -> Result<Vec<DocumentLink>, DocumentError> {
+    self.document_repo.find_links(document_id).await
+  }
+
+  /// Re-hash every stored file and flag the ones that are missing or no
+  /// longer match their SHA-256
+  pub async fn check_integrity(&self) -> Result<IntegrityReport, DocumentError> {
+    let mut report = IntegrityReport::default();
+    let mut after = None;
+
+    loop {
+      let page = self.document_repo.find_page(after, PAGE_SIZE).await?;
+      let Some(last) = page.last() else {
+        break;
+      };
+      after = Some(last.id);
+
+      for document in page {
+        let status = match self.blob_store.read(&document.storage_key).await? {
+          None => IntegrityStatus::Missing,
+          // Content that no longer decrypts is as corrupted as a changed one
+          Some(stored) => match self.cipher.open(stored).await {
+            Ok(content) if hex::encode(Sha256::digest(&content)) == document.sha256 => {
+              IntegrityStatus::Ok
+            }
+            _ => IntegrityStatus::Corrupted,
+          },
+        };
+
+        match status {
+          IntegrityStatus::Ok => {}
+          IntegrityStatus::Missing => report.missing += 1,
+          IntegrityStatus::Corrupted => report.corrupted += 1,
+        }
+        if status != IntegrityStatus::Ok && status != document.integrity_status {
+          tracing::warn!(
+            "Document {} ({}) of company {} is {}",
+            document.id,
+            document.original_name,
+            document.company_id,
+            status.as_str()
+          );
+        }
+
+        self
+          .document_repo
+          .update_integrity(document.id, status, Utc::now())
+          .await?;
+        report.checked += 1;
+      }
+    }
+
+    Ok(report)
+  }
+
+  /// Content as written to the blob store
+  async fn stored_form(&self, company_id: Uuid, content: &[u8]) -> Result<Vec<u8>, DocumentError> {
+    if self.encrypt_at_rest {
+      self.cipher.seal(company_id, content).await
+    } else {
+      Ok(content.to_vec())
+    }
+  }
+
+  /// Remove documents replaced while their owner was retained, once their
+  /// retention has ended. Returns the number removed.
+  pub async fn purge_released(&self) -> Result<usize, DocumentError> {
+    let today = Utc::now().date_naive();
+    let released = self.document_repo.find_released(today).await?;
+    let count = released.len();
+    for document in released {
+      self.remove_if_unlinked(document.id).await?;
+    }
+    Ok(count)
+  }
+
+  /// Remove a document nothing links to, unless it is still retained
+  async fn remove_if_unlinked(&self, document_id: Uuid) -> Result<(), DocumentError> {
+    let today = Utc::now().date_naive();
+    if let Some(document) = self
+      .document_repo
+      .delete_if_unlinked(document_id, today)
+      .await?
+    {
+      self.blob_store.delete(&document.storage_key).await?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::document::entities::Unlinked;
+  use async_trait::async_trait;
+  use chrono::DateTime;
+  use std::collections::HashMap;
+  use std::sync::Mutex;
+
+  #[derive(Default)]
+  struct InMemoryDocuments {
+    documents: Mutex<Vec<Document>>,
+    links: Mutex<Vec<DocumentLink>>,
+  }
+
+  #[async_trait]
+  impl DocumentRepository for InMemoryDocuments {
+    async fn create(&self, document: Document) -> Result<Document, DocumentError> {
+      let mut documents = self.documents.lock().unwrap();
+      let same_content = documents
+        .iter()
+        .find(|d| d.company_id == document.company_id && d.sha256 == document.sha256);
+      if let Some(existing) = same_content {
+        return Ok(existing.clone());
+      }
+      documents.push(document.clone());
+      Ok(document)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Document>, DocumentError> {
+      let documents = self.documents.lock().unwrap();
+      Ok(documents.iter().find(|d| d.id == id).cloned())
+    }
+
+    async fn find_by_hash(
+      &self,
+      company_id: Uuid,
+      sha256: &str,
+    ) -> Result<Option<Document>, DocumentError> {
+      let documents = self.documents.lock().unwrap();
+      Ok(
+        documents
+          .iter()
+          .find(|d| d.company_id == company_id && d.sha256 == sha256)
+          .cloned(),
+      )
+    }
+
+    async fn find_by_company(&self, company_id: Uuid) -> Result<Vec<Document>, DocumentError> {
+      let documents = self.documents.lock().unwrap();
+      Ok(
+        documents
+          .iter()
+          .filter(|d| d.company_id == company_id)
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn find_page(
+      &self,
+      after: Option<Uuid>,
+      limit: i64,
+    ) -> Result<Vec<Document>, DocumentError> {
+      let mut documents = self.documents.lock().unwrap().clone();
+      documents.sort_by_key(|d| d.id);
+      Ok(
+        documents
+          .into_iter()
+          .filter(|d| after.is_none_or(|after| d.id > after))
+          .take(limit as usize)
+          .collect(),
+      )
+    }
+
+    async fn update_integrity(
+      &self,
+      id: Uuid,
+      status: IntegrityStatus,
+      checked_at: DateTime<Utc>,
+    ) -> Result<(), DocumentError> {
+      let mut documents = self.documents.lock().unwrap();
+      let document = documents.iter_mut().find(|d| d.id == id).unwrap();
+      document.integrity_status = status;
+      document.checked_at = Some(checked_at);
+      Ok(())
+    }
+
+    async fn delete_if_unlinked(
+      &self,
+      id: Uuid,
+      today: NaiveDate,
+    ) -> Result<Option<Document>, DocumentError> {
+      let mut documents = self.documents.lock().unwrap();
+      let links = self.links.lock().unwrap();
+      let index = documents.iter().position(|d| {
+        d.id == id
+          && !d.retained_until.is_some_and(|until| until >= today)
+          && !links.iter().any(|l| l.document_id == id)
+      });
+      Ok(index.map(|index| documents.remove(index)))
+    }
+
+    async fn find_released(&self, today: NaiveDate) -> Result<Vec<Document>, DocumentError> {
+      let documents = self.documents.lock().unwrap();
+      let links = self.links.lock().unwrap();
+      Ok(
+        documents
+          .iter()
+          .filter(|d| d.retained_until.is_some_and(|until| until < today))
+          .filter(|d| !links.iter().any(|l| l.document_id == d.id))
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn link(
+      &self,
+      link: DocumentLink,
+      keep_replaced_until: Option<NaiveDate>,
+      today: NaiveDate,
+    ) -> Result<Option<Document>, DocumentError> {
+      if self.find_by_id(link.document_id).await?.is_none() {
+        return Err(DocumentError::NotFound);
+      }
+      let previous = {
+        let mut links = self.links.lock().unwrap();
+        let index = links
+          .iter()
+          .position(|l| l.owner == link.owner && l.owner_id == link.owner_id);
+        let previous = index.map(|index| links.remove(index).document_id);
+        links.push(link.clone());
+        previous
+      };
+      match previous.filter(|id| *id != link.document_id) {
+        Some(previous) => {
+          if let Some(until) = keep_replaced_until {
+            let mut documents = self.documents.lock().unwrap();
+            let document = documents.iter_mut().find(|d| d.id == previous).unwrap();
+            document.retained_until = document.retained_until.max(Some(until));
+          }
+          self.delete_if_unlinked(previous, today).await
+        }
+        None => Ok(None),
+      }
+    }
+
+    async fn unlink(
+      &self,
+      owner: DocumentOwner,
+      owner_id: Uuid,
+      today: NaiveDate,
+    ) -> Result<Option<Unlinked>, DocumentError> {
+      let document_id = {
+        let mut links = self.links.lock().unwrap();
+        let index = links
+          .iter()
+          .position(|l| l.owner == owner && l.owner_id == owner_id);
+        index.map(|index| links.remove(index).document_id)
+      };
+      match document_id {
+        Some(document_id) => Ok(Some(Unlinked {
+          document_id,
+          removed: self.delete_if_unlinked(document_id, today).await?,
+        })),
+        None => Ok(None),
+      }
+    }
+
+    async fn find_link(
+      &self,
+      owner: DocumentOwner,
+      owner_id: Uuid,
+    ) -> Result<Option<DocumentLink>, DocumentError> {
+      let links = self.links.lock().unwrap();
+      Ok(
+        links
+          .iter()
+          .find(|l| l.owner == owner && l.owner_id == owner_id)
+          .cloned(),
+      )
+    }
+
+    async fn find_links(&self, document_id: Uuid) -> Result<Vec<DocumentLink>, DocumentError> {
+      let links = self.links.lock().unwrap();
+      Ok(
+        links
+          .iter()
+          .filter(|l| l.document_id == document_id)
+          .cloned()
+          .collect(),
+      )
+    }
+  }
+
+  #[derive(Default)]
+  struct InMemoryBlobs {
+    blobs: Mutex<HashMap<String, Vec<u8>>>,
+  }
+
+  #[async_trait]
+  impl DocumentBlobStore for InMemoryBlobs {
+    async fn write(&self, key: &str, content: &[u8]) -> Result<(), DocumentError> {
+      let mut blobs = self.blobs.lock().unwrap();
+      blobs.insert(key.to_string(), content.to_vec());
+      Ok(())
+    }
+
+    async fn read(&self, key: &str) -> Result<Option<Vec<u8>>, DocumentError> {
+      Ok(self.blobs.lock().unwrap().get(key).cloned())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DocumentError> {
+      self.blobs.lock().unwrap().remove(key);
+      Ok(())
+    }
+
+    fn path(&self, key: &str) -> String {
+      format!("/documents/{}", key)
+    }
+
+    async fn write_plain_copy(&self, name: &str, content: &[u8]) -> Result<String, DocumentError> {
+      let copy = std::env::temp_dir().join(format!("{}-{}", Uuid::new_v4(), name));
+      std::fs::write(&copy, content).unwrap();
+      Ok(copy.to_string_lossy().to_string())
+    }
+  }
+
+  /// Stand-in cipher: reverses the content behind a marker
+  struct ReversingCipher;
+
+  const SEALED: &[u8] = b"sealed:";
+
+  #[async_trait]
+  impl DocumentCipher for ReversingCipher {
+    async fn seal(&self, _company_id: Uuid, content: &[u8]) -> Result<Vec<u8>, DocumentError> {
+      Ok([SEALED, &content.iter().rev().copied().collect::<Vec<_>>()].concat())
+    }
+
+    async fn open(&self, stored: Vec<u8>) -> Result<Vec<u8>, DocumentError> {
+      match stored.strip_prefix(SEALED) {
+        Some(sealed) => Ok(sealed.iter().rev().copied().collect()),
+        None => Ok(stored),
+      }
+    }
+
+    fn is_sealed(&self, stored: &[u8]) -> bool {
+      stored.starts_with(SEALED)
+    }
+  }
+
+  fn service() -> (DocumentService, Arc<InMemoryDocuments>, Arc<InMemoryBlobs>) {
+    service_encrypting(false)
+  }
+
+  fn service_encrypting(
+    encrypt_at_rest: bool,
+  ) -> (DocumentService, Arc<InMemoryDocuments>, Arc<InMemoryBlobs>) {
+    let documents = Arc::new(InMemoryDocuments::default());
+    let blobs = Arc::new(InMemoryBlobs::default());
+    (
+      DocumentService::new(
+        documents.clone(),
+        blobs.clone(),
+        Arc::new(ReversingCipher),
+        encrypt_at_rest,
+      ),
+      documents,
+      blobs,
+    )
+  }
+
+  fn file(company_id: Uuid, content: &[u8], name: &str) -> NewDocument {
+    NewDocument {
+      company_id,
+      content: content.to_vec(),
+      mime_type: "application/pdf".to_string(),
+      original_name: name.to_string(),
+      uploaded_by: None,
+      keep_replaced_until: None,
+    }
+  }
+
+  #[tokio::test]
+  async fn test_identical_files_are_stored_once() {
+    let (service, documents, blobs) = service();
+    let company_id = Uuid::new_v4();
+    let (bill, receipt) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let first = service
+      .store(
+        file(company_id, b"%PDF bill", "bill.pdf"),
+        DocumentOwner::ReceivedInvoice,
+        bill,
+      )
+      .await
+      .unwrap();
+    let second = service
+      .store(
+        file(company_id, b"%PDF bill", "scan.pdf"),
+        DocumentOwner::TransactionReceipt,
+        receipt,
+      )
+      .await
+      .unwrap();
+
+    assert_eq!(first.id, second.id);
+    assert_eq!(first.original_name, "bill.pdf");
+    assert_eq!(first.size_bytes, 9);
+    assert_eq!(documents.documents.lock().unwrap().len(), 1);
+    assert_eq!(blobs.blobs.lock().unwrap().len(), 1);
+    assert_eq!(service.links(first.id).await.unwrap().len(), 2);
+    assert!(
+      service
+        .path(&first)
+        .starts_with(&format!("/documents/{}/", company_id))
+    );
+
+    // Another company stores its own copy
+    let other = service
+      .store(
+        file(Uuid::new_v4(), b"%PDF bill", "bill.pdf"),
+        DocumentOwner::ReceivedInvoice,
+        Uuid::new_v4(),
+      )
+      .await
+      .unwrap();
+    assert_ne!(other.id, first.id);
+  }
+
+  #[tokio::test]
+  async fn test_documents_go_with_their_last_link() {
+    let (service, documents, blobs) = service();
+    let company_id = Uuid::new_v4();
+    let (invoice, receipt) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let draft = service
+      .store(
+        file(company_id, b"%PDF draft", "INV-1.pdf"),
+        DocumentOwner::InvoicePdf,
+        invoice,
+      )
+      .await
+      .unwrap();
+    service
+      .store(
+        file(company_id, b"%PDF draft", "receipt.pdf"),
+        DocumentOwner::TransactionReceipt,
+        receipt,
+      )
+      .await
+      .unwrap();
+
+    // Replacing the invoice PDF keeps the draft, still linked to the receipt
+    service
+      .store(
+        file(company_id, b"%PDF sent", "INV-1.pdf"),
+        DocumentOwner::InvoicePdf,
+        invoice,
+      )
+      .await
+      .unwrap();
+    assert_eq!(documents.documents.lock().unwrap().len(), 2);
+
+    assert!(
+      service
+        .detach(DocumentOwner::TransactionReceipt, receipt)
+        .await
+        .unwrap()
+    );
+    assert!(
+      !service
+        .detach(DocumentOwner::TransactionReceipt, receipt)
+        .await
+        .unwrap()
+    );
+    assert_eq!(documents.documents.lock().unwrap().len(), 1);
+    assert!(!blobs.blobs.lock().unwrap().contains_key(&draft.storage_key));
+    assert_eq!(
+      service
+        .document_of(DocumentOwner::TransactionReceipt, receipt)
+        .await
+        .unwrap(),
+      None
+    );
+    assert!(
+      service
+        .document_of(DocumentOwner::InvoicePdf, invoice)
+        .await
+        .unwrap()
+        .is_some()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_replaced_documents_are_kept_while_retained() {
+    let (service, documents, blobs) = service();
+    let company_id = Uuid::new_v4();
+    let invoice = Uuid::new_v4();
+    let today = Utc::now().date_naive();
+
+    let sent = service
+      .store(
+        file(company_id, b"%PDF sent", "INV-1.pdf"),
+        DocumentOwner::InvoicePdf,
+        invoice,
+      )
+      .await
+      .unwrap();
+    service
+      .store(
+        NewDocument {
+          keep_replaced_until: Some(today),
+          ..file(company_id, b"%PDF reissued", "INV-1.pdf")
+        },
+        DocumentOwner::InvoicePdf,
+        invoice,
+      )
+      .await
+      .unwrap();
+
+    // The sent PDF stays, unlinked, until its retention ends
+    assert_eq!(documents.documents.lock().unwrap().len(), 2);
+    assert!(blobs.blobs.lock().unwrap().contains_key(&sent.storage_key));
+    assert_eq!(service.purge_released().await.unwrap(), 0);
+
+    documents
+      .documents
+      .lock()
+      .unwrap()
+      .iter_mut()
+      .find(|d| d.id == sent.id)
+      .unwrap()
+      .retained_until = today.pred_opt();
+    assert_eq!(service.purge_released().await.unwrap(), 1);
+    assert_eq!(documents.documents.lock().unwrap().len(), 1);
+    assert!(!blobs.blobs.lock().unwrap().contains_key(&sent.storage_key));
+  }
+
+  #[tokio::test]
+  async fn test_integrity_check_flags_missing_and_corrupted_files() {
+    let (service, documents, blobs) = service();
+    let company_id = Uuid::new_v4();
+    let mut stored = Vec::new();
+    for content in [b"%PDF one".as_slice(), b"%PDF two", b"%PDF three"] {
+      stored.push(
+        service
+          .store(
+            file(company_id, content, "file.pdf"),
+            DocumentOwner::ReceivedInvoice,
+            Uuid::new_v4(),
+          )
+          .await
+          .unwrap(),
+      );
+    }
+    blobs.blobs.lock().unwrap().remove(&stored[0].storage_key);
+    blobs
+      .blobs
+      .lock()
+      .unwrap()
+      .insert(stored[1].storage_key.clone(), b"tampered".to_vec());
+
+    let report = service.check_integrity().await.unwrap();
+
+    assert_eq!(
+      report,
+      IntegrityReport {
+        checked: 3,
+        missing: 1,
+        corrupted: 1,
+      }
+    );
+    let status = |id: Uuid| {
+      let documents = documents.documents.lock().unwrap();
+      documents
+        .iter()
+        .find(|d| d.id == id)
+        .unwrap()
+        .integrity_status
+    };
+    assert_eq!(status(stored[0].id), IntegrityStatus::Missing);
+    assert_eq!(status(stored[1].id), IntegrityStatus::Corrupted);
+    assert_eq!(status(stored[2].id), IntegrityStatus::Ok);
+
+    // Uploading the content again repairs the stored copy
+    service
+      .store(
+        file(company_id, b"%PDF one", "again.pdf"),
+        DocumentOwner::ReceivedInvoice,
+        Uuid::new_v4(),
+      )
+      .await
+      .unwrap();
+    assert_eq!(status(stored[0].id), IntegrityStatus::Ok);
+    assert!(
+      blobs
+        .blobs
+        .lock()
+        .unwrap()
+        .contains_key(&stored[0].storage_key)
+    );
+  }
+
+  #[tokio::test]
+  async fn test_encryption_at_rest() {
+    let (plain_service, documents, blobs) = service_encrypting(false);
+    let company_id = Uuid::new_v4();
+    let earlier = plain_service
+      .store(
+        file(company_id, b"%PDF earlier", "earlier.pdf"),
+        DocumentOwner::ReceivedInvoice,
+        Uuid::new_v4(),
+      )
+      .await
+      .unwrap();
+
+    // Turning encryption on seals new files; earlier ones stay readable
+    let service = DocumentService::new(
+      documents.clone(),
+      blobs.clone(),
+      Arc::new(ReversingCipher),
+      true,
+    );
+    let bill = service
+      .store(
+        file(company_id, b"%PDF bill", "bill.pdf"),
+        DocumentOwner::ReceivedInvoice,
+        Uuid::new_v4(),
+      )
+      .await
+      .unwrap();
+    let stored = |key: &str| blobs.blobs.lock().unwrap().get(key).cloned().unwrap();
+    assert!(stored(&bill.storage_key).starts_with(SEALED));
+    assert_eq!(stored(&earlier.storage_key), b"%PDF earlier".to_vec());
+
+    let report = service.check_integrity().await.unwrap();
+    assert_eq!((report.checked, report.corrupted), (2, 0));
+
+    let report = service.encrypt_stored().await.unwrap();
+    assert_eq!((report.documents, report.skipped), (1, 1));
+    assert!(stored(&earlier.storage_key).starts_with(SEALED));
+    let report = service.check_integrity().await.unwrap();
+    assert_eq!((report.checked, report.corrupted), (2, 0));
+  }
+
+  #[tokio::test]
+  async fn test_encrypted_files_are_read_in_plain_form() {
+    let (service, _, _) = service_encrypting(true);
+    let company_id = Uuid::new_v4();
+    let dir = std::env::temp_dir().join(format!("document_service_test_{}", Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("INV-1.pdf").to_string_lossy().to_string();
+    std::fs::write(&path, b"%PDF legacy").unwrap();
+
+    // Plain files are passed through as they are
+    let plain = service.plain_file(&path).await.unwrap();
+    assert_eq!(plain.path(), path);
+    drop(plain);
+
+    assert!(service.encrypt_file(company_id, &path).await.unwrap());
+    assert!(!service.encrypt_file(company_id, &path).await.unwrap());
+    assert!(std::fs::read(&path).unwrap().starts_with(SEALED));
+    assert_eq!(
+      service.read_file(&path).await.unwrap(),
+      b"%PDF legacy".to_vec()
+    );
+
+    let plain = service.plain_file(&path).await.unwrap();
+    let copy = plain.path().to_string();
+    assert_ne!(copy, path);
+    assert!(copy.ends_with("INV-1.pdf"));
+    assert_eq!(std::fs::read(&copy).unwrap(), b"%PDF legacy".to_vec());
+    drop(plain);
+    assert!(!std::path::Path::new(&copy).exists());
+
+    std::fs::remove_dir_all(dir).unwrap();
+  }
+}
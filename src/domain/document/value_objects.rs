@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use super::errors::DocumentError;
+
+/// Entity a document belongs to. Each owner has at most one document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DocumentOwner {
+  /// Generated PDF of an issued invoice
+  InvoicePdf,
+  /// PDF of a vendor bill
+  ReceivedInvoice,
+  /// Receipt attached to a bank transaction
+  TransactionReceipt,
+}
+
+impl DocumentOwner {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      DocumentOwner::InvoicePdf => "invoice_pdf",
+      DocumentOwner::ReceivedInvoice => "received_invoice",
+      DocumentOwner::TransactionReceipt => "transaction_receipt",
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      DocumentOwner::InvoicePdf => "Invoice PDF",
+      DocumentOwner::ReceivedInvoice => "Received invoice",
+      DocumentOwner::TransactionReceipt => "Receipt",
+    }
+  }
+}
+
+impl TryFrom<&str> for DocumentOwner {
+  type Error = DocumentError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "invoice_pdf" => Ok(DocumentOwner::InvoicePdf),
+      "received_invoice" => Ok(DocumentOwner::ReceivedInvoice),
+      "transaction_receipt" => Ok(DocumentOwner::TransactionReceipt),
+      _ => Err(DocumentError::Validation(format!(
+        "Invalid document owner: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+/// Outcome of the last integrity check of a stored file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntegrityStatus {
+  /// Present with the recorded SHA-256
+  Ok,
+  Missing,
+  /// Present but its content no longer matches the recorded SHA-256
+  Corrupted,
+}
+
+impl IntegrityStatus {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      IntegrityStatus::Ok => "ok",
+      IntegrityStatus::Missing => "missing",
+      IntegrityStatus::Corrupted => "corrupted",
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      IntegrityStatus::Ok => "OK",
+      IntegrityStatus::Missing => "Missing",
+      IntegrityStatus::Corrupted => "Corrupted",
+    }
+  }
+}
+
+impl TryFrom<&str> for IntegrityStatus {
+  type Error = DocumentError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "ok" => Ok(IntegrityStatus::Ok),
+      "missing" => Ok(IntegrityStatus::Missing),
+      "corrupted" => Ok(IntegrityStatus::Corrupted),
+      _ => Err(DocumentError::Validation(format!(
+        "Invalid integrity status: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+/// MIME type of a file judged by the extension of its name
+pub fn mime_type_for(file_name: &str) -> &'static str {
+  match file_extension(file_name).as_deref() {
+    Some("pdf") => "application/pdf",
+    Some("png") => "image/png",
+    Some("jpg") | Some("jpeg") => "image/jpeg",
+    Some("gif") => "image/gif",
+    Some("webp") => "image/webp",
+    Some("heic") => "image/heic",
+    Some("xml") => "application/xml",
+    Some("csv") => "text/csv",
+    Some("txt") => "text/plain",
+    _ => "application/octet-stream",
+  }
+}
+
+/// Lowercase extension of a file name, if it has a plausible one
+pub fn file_extension(file_name: &str) -> Option<String> {
+  let (stem, ext) = file_name.rsplit_once('.')?;
+  let valid = !stem.is_empty()
+    && (1..=8).contains(&ext.len())
+    && ext.chars().all(|c| c.is_ascii_alphanumeric());
+  valid.then(|| ext.to_ascii_lowercase())
+}
@@ -0,0 +1,310 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use super::value_objects::{StatementKind, StatementPeriod, StatementSectionKind};
+
+/// A dated amount from an invoice, received invoice or bank transaction,
+/// already assigned to a statement section and line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Posting {
+  pub date: NaiveDate,
+  pub section: StatementSectionKind,
+  /// Customer, vendor or counterparty the line is grouped by
+  pub label: String,
+  /// Positive amount; the section decides the sign
+  pub amount: Decimal,
+}
+
+/// An amount for the selected period next to the previous period and the
+/// same period a year earlier
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct Comparison {
+  pub current: Decimal,
+  pub previous: Decimal,
+  pub year_ago: Decimal,
+  /// Change against the previous period in percent; None when that was zero
+  pub previous_change: Option<Decimal>,
+  /// Change against a year earlier in percent; None when that was zero
+  pub year_ago_change: Option<Decimal>,
+}
+
+impl Comparison {
+  pub fn new(current: Decimal, previous: Decimal, year_ago: Decimal) -> Self {
+    Self {
+      current,
+      previous,
+      year_ago,
+      previous_change: percent_change(current, previous),
+      year_ago_change: percent_change(current, year_ago),
+    }
+  }
+
+  fn plus(self, other: Comparison) -> Self {
+    Self::new(
+      self.current + other.current,
+      self.previous + other.previous,
+      self.year_ago + other.year_ago,
+    )
+  }
+
+  fn minus(self, other: Comparison) -> Self {
+    Self::new(
+      self.current - other.current,
+      self.previous - other.previous,
+      self.year_ago - other.year_ago,
+    )
+  }
+}
+
+fn percent_change(current: Decimal, base: Decimal) -> Option<Decimal> {
+  if base.is_zero() {
+    return None;
+  }
+  let mut change = ((current - base) / base.abs() * Decimal::ONE_HUNDRED).round_dp(1);
+  change.rescale(1);
+  Some(change)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StatementLine {
+  pub label: String,
+  pub amounts: Comparison,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StatementSection {
+  pub kind: StatementSectionKind,
+  pub title: String,
+  pub inflow: bool,
+  pub lines: Vec<StatementLine>,
+  pub total: Comparison,
+}
+
+/// Inflow and outflow totals of one period, for the trend chart
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TrendPoint {
+  pub label: String,
+  pub inflow: Decimal,
+  pub outflow: Decimal,
+  pub net: Decimal,
+}
+
+/// Profit & loss or cash-flow statement for a period with comparison columns
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FinancialStatement {
+  pub kind: StatementKind,
+  pub title: String,
+  pub currency: String,
+  pub period: StatementPeriod,
+  pub period_label: String,
+  pub previous_label: String,
+  pub year_ago_label: String,
+  pub sections: Vec<StatementSection>,
+  pub result_label: String,
+  pub result: Comparison,
+  pub trend: Vec<TrendPoint>,
+  /// Documents left out because they are not in the statement currency
+  pub excluded_documents: usize,
+}
+
+impl FinancialStatement {
+  pub fn build(
+    kind: StatementKind,
+    period: StatementPeriod,
+    currency: &str,
+    postings: &[Posting],
+    excluded_documents: usize,
+  ) -> Self {
+    let previous = period.previous();
+    let year_ago = period.year_ago();
+
+    let sections: Vec<StatementSection> = kind
+      .sections()
+      .into_iter()
+      .map(|section| build_section(section, postings, period, previous, year_ago))
+      .collect();
+
+    let result = sections
+      .iter()
+      .fold(Comparison::default(), |result, section| {
+        if section.inflow {
+          result.plus(section.total)
+        } else {
+          result.minus(section.total)
+        }
+      });
+
+    let trend = period
+      .trend()
+      .into_iter()
+      .map(|trend_period| {
+        let (inflow, outflow) = postings
+          .iter()
+          .filter(|p| kind.sections().contains(&p.section) && trend_period.contains(p.date))
+          .fold((Decimal::ZERO, Decimal::ZERO), |(inflow, outflow), p| {
+            if p.section.is_inflow() {
+              (inflow + p.amount, outflow)
+            } else {
+              (inflow, outflow + p.amount)
+            }
+          });
+        TrendPoint {
+          label: trend_period.label(),
+          inflow,
+          outflow,
+          net: inflow - outflow,
+        }
+      })
+      .collect();
+
+    Self {
+      kind,
+      title: kind.label().to_string(),
+      currency: currency.to_string(),
+      period,
+      period_label: period.label(),
+      previous_label: previous.label(),
+      year_ago_label: year_ago.label(),
+      sections,
+      result_label: kind.result_label().to_string(),
+      result,
+      trend,
+      excluded_documents,
+    }
+  }
+}
+
+fn build_section(
+  section: StatementSectionKind,
+  postings: &[Posting],
+  period: StatementPeriod,
+  previous: StatementPeriod,
+  year_ago: StatementPeriod,
+) -> StatementSection {
+  // label -> (current, previous, year ago)
+  let mut by_label: BTreeMap<&str, (Decimal, Decimal, Decimal)> = BTreeMap::new();
+  for posting in postings.iter().filter(|p| p.section == section) {
+    let in_current = period.contains(posting.date);
+    let in_previous = previous.contains(posting.date);
+    let in_year_ago = year_ago.contains(posting.date);
+    if !(in_current || in_previous || in_year_ago) {
+      continue;
+    }
+
+    let amounts = by_label.entry(posting.label.as_str()).or_default();
+    if in_current {
+      amounts.0 += posting.amount;
+    }
+    if in_previous {
+      amounts.1 += posting.amount;
+    }
+    if in_year_ago {
+      amounts.2 += posting.amount;
+    }
+  }
+
+  let mut lines: Vec<StatementLine> = by_label
+    .into_iter()
+    .map(|(label, (current, prev, ago))| StatementLine {
+      label: label.to_string(),
+      amounts: Comparison::new(current, prev, ago),
+    })
+    .collect();
+  // Largest lines of the selected period first; BTreeMap keeps ties by label
+  lines.sort_by_key(|line| std::cmp::Reverse(line.amounts.current));
+
+  let total = lines.iter().fold(Comparison::default(), |total, line| {
+    total.plus(line.amounts)
+  });
+
+  StatementSection {
+    kind: section,
+    title: section.label().to_string(),
+    inflow: section.is_inflow(),
+    lines,
+    total,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rust_decimal_macros::dec;
+
+  fn posting(
+    year: i32,
+    month: u32,
+    section: StatementSectionKind,
+    label: &str,
+    amount: Decimal,
+  ) -> Posting {
+    Posting {
+      date: NaiveDate::from_ymd_opt(year, month, 15).unwrap(),
+      section,
+      label: label.to_string(),
+      amount,
+    }
+  }
+
+  #[test]
+  fn test_profit_and_loss_compares_previous_month_and_year_ago() {
+    let postings = vec![
+      posting(2026, 3, StatementSectionKind::Revenue, "Acme", dec!(1000)),
+      posting(2026, 3, StatementSectionKind::Revenue, "Globex", dec!(500)),
+      posting(2026, 2, StatementSectionKind::Revenue, "Acme", dec!(800)),
+      posting(2025, 3, StatementSectionKind::Revenue, "Acme", dec!(750)),
+      posting(
+        2026,
+        3,
+        StatementSectionKind::Purchases,
+        "Hosting",
+        dec!(200),
+      ),
+      posting(
+        2026,
+        2,
+        StatementSectionKind::OtherExpenses,
+        "Bank",
+        dec!(5),
+      ),
+      // Cash-flow section, ignored by the P&L
+      posting(
+        2026,
+        3,
+        StatementSectionKind::OtherReceipts,
+        "Acme",
+        dec!(999),
+      ),
+    ];
+    let period = StatementPeriod::month(2026, 3).unwrap();
+
+    let statement =
+      FinancialStatement::build(StatementKind::ProfitAndLoss, period, "EUR", &postings, 0);
+
+    let revenue = &statement.sections[0];
+    assert_eq!(revenue.lines[0].label, "Acme");
+    assert_eq!(revenue.lines[0].amounts.previous_change, Some(dec!(25.0)));
+    assert_eq!(revenue.total.current, dec!(1500));
+    assert_eq!(revenue.total.year_ago, dec!(750));
+    assert_eq!(revenue.total.year_ago_change, Some(dec!(100.0)));
+
+    assert_eq!(statement.result.current, dec!(1300));
+    assert_eq!(statement.result.previous, dec!(795));
+    assert_eq!(statement.previous_label, "02.2026");
+    assert_eq!(statement.year_ago_label, "03.2025");
+
+    assert_eq!(statement.trend.len(), 12);
+    let last = statement.trend.last().unwrap();
+    assert_eq!((last.inflow, last.outflow), (dec!(1500), dec!(200)));
+  }
+
+  #[test]
+  fn test_change_is_none_without_base() {
+    let comparison = Comparison::new(dec!(10), Decimal::ZERO, dec!(-20));
+    assert_eq!(comparison.previous_change, None);
+    assert_eq!(comparison.year_ago_change, Some(dec!(150.0)));
+  }
+}
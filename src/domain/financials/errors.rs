@@ -0,0 +1,31 @@
+use thiserror::Error;
+
+use crate::domain::invoice::errors::InvoiceError;
+use crate::domain::report::errors::ReportError;
+
+#[derive(Debug, Error)]
+pub enum FinancialsError {
+  #[error("Invalid period: {0}")]
+  InvalidPeriod(String),
+
+  #[error("Validation error: {0}")]
+  Validation(String),
+
+  #[error("Failed to load statement data: {0}")]
+  DataAccess(String),
+
+  #[error("Failed to export statement: {0}")]
+  Export(String),
+}
+
+impl From<InvoiceError> for FinancialsError {
+  fn from(error: InvoiceError) -> Self {
+    FinancialsError::DataAccess(error.to_string())
+  }
+}
+
+impl From<ReportError> for FinancialsError {
+  fn from(error: ReportError) -> Self {
+    FinancialsError::DataAccess(error.to_string())
+  }
+}
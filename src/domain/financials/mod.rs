@@ -0,0 +1,13 @@
+pub mod entities;
+pub mod errors;
+pub mod ports;
+pub mod services;
+pub mod value_objects;
+
+pub use entities::{
+  Comparison, FinancialStatement, Posting, StatementLine, StatementSection, TrendPoint,
+};
+pub use errors::FinancialsError;
+pub use ports::{HtmlPdfConverter, StatementCsvWriter};
+pub use services::{FinancialStatementService, FinancialStatementServiceDependencies};
+pub use value_objects::{PeriodKind, StatementKind, StatementPeriod, StatementSectionKind};
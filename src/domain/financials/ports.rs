@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use super::{entities::FinancialStatement, errors::FinancialsError};
+
+/// Serializes a statement as CSV
+pub trait StatementCsvWriter: Send + Sync {
+  fn write(&self, statement: &FinancialStatement) -> Result<Vec<u8>, FinancialsError>;
+}
+
+/// Prints an HTML page (e.g. a rendered statement) to PDF
+#[async_trait]
+pub trait HtmlPdfConverter: Send + Sync {
+  async fn convert(&self, html: &str) -> Result<Vec<u8>, FinancialsError>;
+}
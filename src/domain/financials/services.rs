@@ -0,0 +1,342 @@
+use std::sync::Arc;
+
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::{
+  entities::{FinancialStatement, Posting},
+  errors::FinancialsError,
+  value_objects::{StatementKind, StatementPeriod, StatementSectionKind},
+};
+use crate::domain::invoice::entities::{Customer, Invoice, InvoiceTotals};
+use crate::domain::invoice::ports::{
+  CustomerRepository, InvoiceLineItemRepository, InvoiceRepository,
+};
+use crate::domain::invoice::value_objects::InvoiceStatus;
+use crate::domain::ledger::services::LEDGER_CURRENCY;
+use crate::domain::report::entities::{BankTransaction, ReceivedInvoice, TransactionCategory};
+use crate::domain::report::ports::{
+  BankTransactionRepository, ReceivedInvoiceRepository, TransactionCategoryRepository,
+};
+use crate::domain::report::value_objects::TransactionDirection;
+
+/// Dependencies for FinancialStatementService
+pub struct FinancialStatementServiceDependencies {
+  pub customer_repo: Arc<dyn CustomerRepository>,
+  pub invoice_repo: Arc<dyn InvoiceRepository>,
+  pub line_item_repo: Arc<dyn InvoiceLineItemRepository>,
+  pub received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
+  pub bank_transaction_repo: Arc<dyn BankTransactionRepository>,
+  pub transaction_category_repo: Arc<dyn TransactionCategoryRepository>,
+}
+
+/// Builds profit & loss and cash-flow statements from source documents
+///
+/// P&L: issued invoices (net of VAT) are revenue, received invoices are
+/// purchases, and bank transactions not matched to either count as other
+/// income or expenses. Cash flow: every bank transaction, split by whether it
+/// settles an invoice. Categorised bank transactions are grouped by category
+/// rather than counterparty. Only documents in the ledger currency are included.
+pub struct FinancialStatementService {
+  customer_repo: Arc<dyn CustomerRepository>,
+  invoice_repo: Arc<dyn InvoiceRepository>,
+  line_item_repo: Arc<dyn InvoiceLineItemRepository>,
+  received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
+  bank_transaction_repo: Arc<dyn BankTransactionRepository>,
+  transaction_category_repo: Arc<dyn TransactionCategoryRepository>,
+}
+
+impl FinancialStatementService {
+  pub fn new(deps: FinancialStatementServiceDependencies) -> Self {
+    Self {
+      customer_repo: deps.customer_repo,
+      invoice_repo: deps.invoice_repo,
+      line_item_repo: deps.line_item_repo,
+      received_invoice_repo: deps.received_invoice_repo,
+      bank_transaction_repo: deps.bank_transaction_repo,
+      transaction_category_repo: deps.transaction_category_repo,
+    }
+  }
+
+  pub async fn statement(
+    &self,
+    company_id: Uuid,
+    kind: StatementKind,
+    period: StatementPeriod,
+  ) -> Result<FinancialStatement, FinancialsError> {
+    // Everything any column or the trend chart needs
+    let trend_start = period.trend()[0].start;
+    let range_start = trend_start.min(period.year_ago().start);
+    let range = StatementPeriod {
+      start: range_start,
+      ..period
+    };
+
+    let transactions = self
+      .bank_transaction_repo
+      .find_by_company_and_date_range(company_id, range.start, range.end)
+      .await?;
+    let categories = self
+      .transaction_category_repo
+      .find_by_company_id(company_id)
+      .await?;
+
+    let mut postings = Vec::new();
+    let mut excluded = 0;
+    // Only documents of the selected period are reported as left out
+    let mut exclude = |date| {
+      if period.contains(date) {
+        excluded += 1;
+      }
+    };
+
+    if kind == StatementKind::ProfitAndLoss {
+      let mut invoices = self.invoice_repo.find_by_company_id(company_id).await?;
+      invoices.extend(
+        self
+          .invoice_repo
+          .find_archived_by_company_id(company_id)
+          .await?,
+      );
+      let customers = self.customer_repo.find_by_company_id(company_id).await?;
+
+      for invoice in invoices
+        .iter()
+        .filter(|invoice| is_issued(invoice.status) && range.contains(invoice.invoice_date))
+      {
+        if !is_ledger_currency(invoice.currency.as_str()) {
+          exclude(invoice.invoice_date);
+          continue;
+        }
+        let line_items = self.line_item_repo.find_by_invoice_id(invoice.id).await?;
+        let net = InvoiceTotals::calculate(&line_items, invoice.currency)
+          .subtotal
+          .amount
+          .round_dp(2);
+        postings.push(revenue_posting(invoice, net, &customers));
+      }
+
+      let received_invoices = self
+        .received_invoice_repo
+        .find_by_company_id(company_id)
+        .await?;
+      for invoice in &received_invoices {
+        let date = received_invoice_date(invoice);
+        if !range.contains(date) {
+          continue;
+        }
+        if !is_ledger_currency(&invoice.currency) {
+          exclude(date);
+          continue;
+        }
+        postings.push(Posting {
+          date,
+          section: StatementSectionKind::Purchases,
+          label: invoice.vendor_name.clone(),
+          amount: invoice.net_amount().abs(),
+        });
+      }
+    }
+
+    for transaction in &transactions {
+      if !is_ledger_currency(&transaction.currency) {
+        exclude(transaction.date);
+        continue;
+      }
+      for (section, amount) in transaction_sections(kind, transaction) {
+        postings.push(Posting {
+          date: transaction.date,
+          section,
+          label: transaction_label(transaction, &categories),
+          amount,
+        });
+      }
+    }
+
+    Ok(FinancialStatement::build(
+      kind,
+      period,
+      LEDGER_CURRENCY,
+      &postings,
+      excluded,
+    ))
+  }
+}
+
+/// Drafts were never issued and cancelled invoices are void
+fn is_issued(status: InvoiceStatus) -> bool {
+  !matches!(status, InvoiceStatus::Draft | InvoiceStatus::Cancelled)
+}
+
+fn is_ledger_currency(currency: &str) -> bool {
+  currency.eq_ignore_ascii_case(LEDGER_CURRENCY)
+}
+
+fn revenue_posting(invoice: &Invoice, net: Decimal, customers: &[Customer]) -> Posting {
+  let label = customers
+    .iter()
+    .find(|customer| customer.id == invoice.customer_id)
+    .map(|customer| customer.name.value().to_string())
+    .unwrap_or_else(|| "Unknown customer".to_string());
+
+  Posting {
+    date: invoice.invoice_date,
+    section: StatementSectionKind::Revenue,
+    label,
+    amount: net,
+  }
+}
+
+/// Received invoices without a date are booked on the day they were added
+fn received_invoice_date(invoice: &ReceivedInvoice) -> chrono::NaiveDate {
+  invoice
+    .invoice_date
+    .unwrap_or_else(|| invoice.created_at.date_naive())
+}
+
+/// Where a bank transaction goes on the statement, split by amount. For the
+/// P&L, the parts allocated to invoices are already counted through the
+/// invoice and ignored transactions are left out.
+fn transaction_sections(
+  kind: StatementKind,
+  transaction: &BankTransaction,
+) -> Vec<(StatementSectionKind, Decimal)> {
+  let mut sections = match (kind, transaction.direction) {
+    // Ignored transactions, e.g. transfers between own accounts, are neither
+    // income nor expense; they still move cash
+    (StatementKind::ProfitAndLoss, _) if transaction.ignored => Vec::new(),
+    (StatementKind::ProfitAndLoss, TransactionDirection::Credit) => vec![(
+      StatementSectionKind::OtherIncome,
+      transaction.unallocated_amount(),
+    )],
+    (StatementKind::ProfitAndLoss, TransactionDirection::Debit) => vec![(
+      StatementSectionKind::OtherExpenses,
+      transaction.unallocated_amount(),
+    )],
+    (StatementKind::CashFlow, direction) => {
+      // Only a receipt for a sales invoice or a payment for a vendor bill
+      // settles an invoice; anything else, e.g. a refund, is other cash
+      let settled: Decimal = transaction
+        .allocations
+        .iter()
+        .filter(|allocation| match direction {
+          TransactionDirection::Credit => allocation.invoice_id.is_some(),
+          TransactionDirection::Debit => allocation.received_invoice_id.is_some(),
+        })
+        .map(|allocation| allocation.amount)
+        .sum();
+      let (settled_section, other_section) = match direction {
+        TransactionDirection::Credit => (
+          StatementSectionKind::CustomerReceipts,
+          StatementSectionKind::OtherReceipts,
+        ),
+        TransactionDirection::Debit => (
+          StatementSectionKind::SupplierPayments,
+          StatementSectionKind::OtherPayments,
+        ),
+      };
+      vec![
+        (settled_section, settled),
+        (other_section, transaction.amount.abs() - settled),
+      ]
+    }
+  };
+
+  sections.retain(|(_, amount)| !amount.is_zero());
+  sections
+}
+
+fn transaction_label(transaction: &BankTransaction, categories: &[TransactionCategory]) -> String {
+  if let Some(category) = categories
+    .iter()
+    .find(|category| Some(category.id) == transaction.category_id)
+  {
+    return category.name.clone();
+  }
+
+  transaction
+    .counterparty_name
+    .as_deref()
+    .or(transaction.description.as_deref())
+    .map(str::trim)
+    .filter(|label| !label.is_empty())
+    .unwrap_or("Unknown counterparty")
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::NaiveDate;
+  use rust_decimal_macros::dec;
+
+  fn transaction(direction: TransactionDirection) -> BankTransaction {
+    BankTransaction::new(
+      Uuid::new_v4(),
+      1,
+      NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+      Some("Acme OÜ".to_string()),
+      None,
+      direction,
+      dec!(100),
+      None,
+      None,
+      "EUR".to_string(),
+      None,
+    )
+  }
+
+  #[test]
+  fn test_transaction_sections() {
+    let mut incoming = transaction(TransactionDirection::Credit);
+    let outgoing = transaction(TransactionDirection::Debit);
+
+    assert_eq!(
+      transaction_sections(StatementKind::ProfitAndLoss, &incoming),
+      vec![(StatementSectionKind::OtherIncome, dec!(100))]
+    );
+    assert_eq!(
+      transaction_sections(StatementKind::CashFlow, &outgoing),
+      vec![(StatementSectionKind::OtherPayments, dec!(100))]
+    );
+
+    // Partly allocated: only the remainder is other income
+    incoming
+      .allocate(Some(Uuid::new_v4()), None, dec!(60))
+      .unwrap();
+    assert_eq!(
+      transaction_sections(StatementKind::ProfitAndLoss, &incoming),
+      vec![(StatementSectionKind::OtherIncome, dec!(40))]
+    );
+    assert_eq!(
+      transaction_sections(StatementKind::CashFlow, &incoming),
+      vec![
+        (StatementSectionKind::CustomerReceipts, dec!(60)),
+        (StatementSectionKind::OtherReceipts, dec!(40)),
+      ]
+    );
+
+    incoming
+      .allocate(Some(Uuid::new_v4()), None, dec!(40))
+      .unwrap();
+    assert!(transaction_sections(StatementKind::ProfitAndLoss, &incoming).is_empty());
+    assert_eq!(
+      transaction_sections(StatementKind::CashFlow, &incoming),
+      vec![(StatementSectionKind::CustomerReceipts, dec!(100))]
+    );
+    assert_eq!(transaction_label(&incoming, &[]), "Acme OÜ");
+
+    let fees = TransactionCategory::new(Uuid::new_v4(), "Bank fees".to_string());
+    let mut fee = transaction(TransactionDirection::Debit);
+    fee.category_id = Some(fees.id);
+    assert_eq!(transaction_label(&fee, &[fees]), "Bank fees");
+
+    let mut transfer = transaction(TransactionDirection::Debit);
+    transfer.ignored = true;
+    assert!(transaction_sections(StatementKind::ProfitAndLoss, &transfer).is_empty());
+    assert_eq!(
+      transaction_sections(StatementKind::CashFlow, &transfer),
+      vec![(StatementSectionKind::OtherPayments, dec!(100))]
+    );
+  }
+}
@@ -0,0 +1,277 @@
+use chrono::{Datelike, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::errors::FinancialsError;
+
+/// Which statement to build
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatementKind {
+  ProfitAndLoss,
+  CashFlow,
+}
+
+impl StatementKind {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      StatementKind::ProfitAndLoss => "profit_and_loss",
+      StatementKind::CashFlow => "cash_flow",
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      StatementKind::ProfitAndLoss => "Profit & Loss",
+      StatementKind::CashFlow => "Cash Flow",
+    }
+  }
+
+  /// Sections in display order: inflows first, then outflows
+  pub fn sections(&self) -> [StatementSectionKind; 4] {
+    match self {
+      StatementKind::ProfitAndLoss => [
+        StatementSectionKind::Revenue,
+        StatementSectionKind::OtherIncome,
+        StatementSectionKind::Purchases,
+        StatementSectionKind::OtherExpenses,
+      ],
+      StatementKind::CashFlow => [
+        StatementSectionKind::CustomerReceipts,
+        StatementSectionKind::OtherReceipts,
+        StatementSectionKind::SupplierPayments,
+        StatementSectionKind::OtherPayments,
+      ],
+    }
+  }
+
+  /// Label of the bottom line
+  pub fn result_label(&self) -> &'static str {
+    match self {
+      StatementKind::ProfitAndLoss => "Net profit",
+      StatementKind::CashFlow => "Net cash flow",
+    }
+  }
+}
+
+impl TryFrom<&str> for StatementKind {
+  type Error = FinancialsError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s.to_lowercase().replace('-', "_").as_str() {
+      "profit_and_loss" | "pnl" => Ok(StatementKind::ProfitAndLoss),
+      "cash_flow" => Ok(StatementKind::CashFlow),
+      _ => Err(FinancialsError::Validation(format!(
+        "Invalid statement: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+/// Line grouping within a statement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatementSectionKind {
+  /// Issued invoices, net of VAT, per customer
+  Revenue,
+  /// Incoming bank payments not matched to an invoice
+  OtherIncome,
+  /// Received invoices, per vendor
+  Purchases,
+  /// Outgoing bank payments not matched to a received invoice
+  OtherExpenses,
+  /// Incoming bank payments matched to an issued invoice
+  CustomerReceipts,
+  /// Other incoming bank payments
+  OtherReceipts,
+  /// Outgoing bank payments matched to a received invoice
+  SupplierPayments,
+  /// Other outgoing bank payments
+  OtherPayments,
+}
+
+impl StatementSectionKind {
+  pub fn label(&self) -> &'static str {
+    match self {
+      StatementSectionKind::Revenue => "Revenue",
+      StatementSectionKind::OtherIncome => "Other income",
+      StatementSectionKind::Purchases => "Purchases",
+      StatementSectionKind::OtherExpenses => "Other expenses",
+      StatementSectionKind::CustomerReceipts => "Receipts from customers",
+      StatementSectionKind::OtherReceipts => "Other receipts",
+      StatementSectionKind::SupplierPayments => "Payments to suppliers",
+      StatementSectionKind::OtherPayments => "Other payments",
+    }
+  }
+
+  /// Whether the section adds to the bottom line
+  pub fn is_inflow(&self) -> bool {
+    matches!(
+      self,
+      StatementSectionKind::Revenue
+        | StatementSectionKind::OtherIncome
+        | StatementSectionKind::CustomerReceipts
+        | StatementSectionKind::OtherReceipts
+    )
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PeriodKind {
+  Month,
+  Year,
+}
+
+impl TryFrom<&str> for PeriodKind {
+  type Error = FinancialsError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s.to_lowercase().as_str() {
+      "month" => Ok(PeriodKind::Month),
+      "year" => Ok(PeriodKind::Year),
+      _ => Err(FinancialsError::Validation(format!(
+        "Invalid period: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+/// A calendar month or year a statement column covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct StatementPeriod {
+  pub kind: PeriodKind,
+  pub start: NaiveDate,
+  pub end: NaiveDate,
+}
+
+impl StatementPeriod {
+  pub fn month(year: i32, month: u32) -> Result<Self, FinancialsError> {
+    let start = NaiveDate::from_ymd_opt(year, month, 1).ok_or_else(|| {
+      FinancialsError::InvalidPeriod(format!("{:02}.{} is not a valid month", month, year))
+    })?;
+    Ok(Self::month_starting(start))
+  }
+
+  pub fn year(year: i32) -> Result<Self, FinancialsError> {
+    let start = NaiveDate::from_ymd_opt(year, 1, 1)
+      .ok_or_else(|| FinancialsError::InvalidPeriod(format!("{} is not a valid year", year)))?;
+    let end = NaiveDate::from_ymd_opt(year, 12, 31)
+      .ok_or_else(|| FinancialsError::InvalidPeriod(format!("{} is not a valid year", year)))?;
+    Ok(Self {
+      kind: PeriodKind::Year,
+      start,
+      end,
+    })
+  }
+
+  fn month_starting(start: NaiveDate) -> Self {
+    let next = start + Months::new(1);
+    Self {
+      kind: PeriodKind::Month,
+      start,
+      end: next.pred_opt().unwrap_or(start),
+    }
+  }
+
+  pub fn contains(&self, date: NaiveDate) -> bool {
+    self.start <= date && date <= self.end
+  }
+
+  /// The period right before this one (previous month or previous year)
+  pub fn previous(&self) -> Self {
+    self.shifted_back(match self.kind {
+      PeriodKind::Month => 1,
+      PeriodKind::Year => 12,
+    })
+  }
+
+  /// The same period one year earlier
+  pub fn year_ago(&self) -> Self {
+    self.shifted_back(12)
+  }
+
+  /// Periods ending with this one, oldest first: twelve months or five years
+  pub fn trend(&self) -> Vec<Self> {
+    let count = match self.kind {
+      PeriodKind::Month => 12,
+      PeriodKind::Year => 5,
+    };
+    let mut periods = vec![*self];
+    for _ in 1..count {
+      let previous = periods[periods.len() - 1].previous();
+      periods.push(previous);
+    }
+    periods.reverse();
+    periods
+  }
+
+  /// "03.2026" for a month, "2026" for a year
+  pub fn label(&self) -> String {
+    match self.kind {
+      PeriodKind::Month => format!("{:02}.{}", self.start.month(), self.start.year()),
+      PeriodKind::Year => self.start.year().to_string(),
+    }
+  }
+
+  fn shifted_back(&self, months: u32) -> Self {
+    let start = self.start - Months::new(months);
+    match self.kind {
+      PeriodKind::Month => Self::month_starting(start),
+      PeriodKind::Year => Self {
+        kind: PeriodKind::Year,
+        start,
+        end: self.end - Months::new(months),
+      },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+  }
+
+  #[test]
+  fn test_month_comparisons() {
+    let march = StatementPeriod::month(2024, 3).unwrap();
+    assert_eq!(march.end, date(2024, 3, 31));
+    assert_eq!(march.label(), "03.2024");
+
+    let february = march.previous();
+    assert_eq!(
+      (february.start, february.end),
+      (date(2024, 2, 1), date(2024, 2, 29))
+    );
+
+    let january = StatementPeriod::month(2024, 1).unwrap().previous();
+    assert_eq!(january.label(), "12.2023");
+
+    let year_ago = march.year_ago();
+    assert_eq!(
+      (year_ago.start, year_ago.end),
+      (date(2023, 3, 1), date(2023, 3, 31))
+    );
+
+    assert!(StatementPeriod::month(2024, 13).is_err());
+  }
+
+  #[test]
+  fn test_trend_ends_with_period() {
+    let trend = StatementPeriod::month(2024, 3).unwrap().trend();
+    assert_eq!(trend.len(), 12);
+    assert_eq!(trend[0].label(), "04.2023");
+    assert_eq!(trend[11].label(), "03.2024");
+
+    let years = StatementPeriod::year(2024).unwrap().trend();
+    assert_eq!(
+      years.iter().map(|p| p.label()).collect::<Vec<_>>(),
+      vec!["2020", "2021", "2022", "2023", "2024"]
+    );
+    assert_eq!(years[0].end, date(2020, 12, 31));
+  }
+}
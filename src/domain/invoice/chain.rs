@@ -0,0 +1,228 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::entities::{Invoice, InvoiceLineItem};
+
+/// Canonical form of an issued invoice. Only fields that must not change after
+/// the invoice leaves Draft are included; status, PDF location and archive
+/// flags legitimately change later. Field order is fixed by declaration order.
+#[derive(Serialize)]
+struct CanonicalInvoice<'a> {
+  id: Uuid,
+  company_id: Uuid,
+  customer_id: Uuid,
+  bank_account_id: Option<Uuid>,
+  invoice_number: &'a str,
+  invoice_date: NaiveDate,
+  due_date: NaiveDate,
+  payment_terms: String,
+  currency: &'a str,
+  language: &'a str,
+  line_items: Vec<CanonicalLineItem<'a>>,
+}
+
+#[derive(Serialize)]
+struct CanonicalLineItem<'a> {
+  line_order: i32,
+  description: &'a str,
+  quantity: String,
+  unit_price: String,
+  vat_rate: String,
+}
+
+/// Canonical JSON of the invoice and its lines, independent of the scale
+/// decimals come back from the database with
+pub fn canonical_invoice_payload(invoice: &Invoice, line_items: &[InvoiceLineItem]) -> String {
+  let mut items: Vec<&InvoiceLineItem> = line_items.iter().collect();
+  items.sort_by_key(|item| item.line_order);
+
+  let canonical = CanonicalInvoice {
+    id: invoice.id,
+    company_id: invoice.company_id,
+    customer_id: invoice.customer_id,
+    bank_account_id: invoice.bank_account_id,
+    invoice_number: invoice.invoice_number.value(),
+    invoice_date: invoice.invoice_date,
+    due_date: invoice.due_date,
+    payment_terms: invoice.payment_terms.as_str(),
+    currency: invoice.currency.as_str(),
+    language: invoice.language.as_str(),
+    line_items: items
+      .into_iter()
+      .map(|item| CanonicalLineItem {
+        line_order: item.line_order,
+        description: item.description.value(),
+        quantity: item.quantity.value().normalize().to_string(),
+        unit_price: item.unit_price.amount.normalize().to_string(),
+        vat_rate: item.vat_rate.value().normalize().to_string(),
+      })
+      .collect(),
+  };
+
+  serde_json::to_string(&canonical).expect("canonical invoice serializes to JSON")
+}
+
+/// SHA-256 (hex) over the previous link's hash followed by the payload
+pub fn chain_hash(previous_hash: Option<&str>, payload: &str) -> String {
+  let mut hasher = Sha256::new();
+  hasher.update(previous_hash.unwrap_or_default().as_bytes());
+  hasher.update(payload.as_bytes());
+  hex::encode(hasher.finalize())
+}
+
+// Invoice Chain Link - Seal of one issued invoice, chained per company
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvoiceChainLink {
+  pub id: Uuid,
+  pub company_id: Uuid,
+  pub invoice_id: Uuid,
+  /// Position in the company's chain, starting at 1
+  pub sequence: i64,
+  pub previous_hash: Option<String>,
+  pub hash: String,
+  pub created_at: DateTime<Utc>,
+}
+
+impl InvoiceChainLink {
+  /// Seal the invoice as the link following `previous` (None starts the chain)
+  pub fn seal(
+    invoice: &Invoice,
+    line_items: &[InvoiceLineItem],
+    previous: Option<&InvoiceChainLink>,
+  ) -> Self {
+    let previous_hash = previous.map(|link| link.hash.clone());
+    let payload = canonical_invoice_payload(invoice, line_items);
+
+    Self {
+      id: Uuid::new_v4(),
+      company_id: invoice.company_id,
+      invoice_id: invoice.id,
+      sequence: previous.map_or(1, |link| link.sequence + 1),
+      hash: chain_hash(previous_hash.as_deref(), &payload),
+      previous_hash,
+      created_at: Utc::now(),
+    }
+  }
+
+  /// Whether the invoice still hashes to the sealed value
+  pub fn matches(&self, invoice: &Invoice, line_items: &[InvoiceLineItem]) -> bool {
+    let payload = canonical_invoice_payload(invoice, line_items);
+    chain_hash(self.previous_hash.as_deref(), &payload) == self.hash
+  }
+}
+
+/// Why a link failed verification
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum ChainBreak {
+  /// A link is missing or out of order
+  SequenceGap { expected: i64 },
+  /// The link does not point at the hash of the link before it
+  PreviousHashMismatch,
+  /// The sealed invoice no longer exists
+  InvoiceMissing,
+  /// The invoice or its lines changed after sealing
+  ContentMismatch,
+}
+
+impl ChainBreak {
+  pub fn description(&self) -> String {
+    match self {
+      ChainBreak::SequenceGap { expected } => {
+        format!("expected sequence {}, link missing or reordered", expected)
+      }
+      ChainBreak::PreviousHashMismatch => "previous hash does not match preceding link".to_string(),
+      ChainBreak::InvoiceMissing => "sealed invoice no longer exists".to_string(),
+      ChainBreak::ContentMismatch => "invoice content changed after it was issued".to_string(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BrokenChainLink {
+  pub sequence: i64,
+  pub invoice_id: Uuid,
+  pub invoice_number: Option<String>,
+  #[serde(flatten)]
+  pub problem: ChainBreak,
+}
+
+/// Result of walking a company's invoice chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvoiceChainReport {
+  pub company_id: Uuid,
+  pub links_checked: usize,
+  pub broken_links: Vec<BrokenChainLink>,
+}
+
+impl InvoiceChainReport {
+  pub fn is_intact(&self) -> bool {
+    self.broken_links.is_empty()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::invoice::value_objects::{
+    Currency, InvoiceNumber, LineItemDescription, Money, PaymentTerms, Quantity, VatRate,
+  };
+  use rust_decimal_macros::dec;
+
+  fn invoice_with_line(
+    number: &str,
+    quantity: rust_decimal::Decimal,
+  ) -> (Invoice, InvoiceLineItem) {
+    let invoice = Invoice::new(
+      Uuid::new_v4(),
+      Uuid::new_v4(),
+      None,
+      InvoiceNumber::new(number.to_string()).unwrap(),
+      NaiveDate::from_ymd_opt(2026, 4, 1).unwrap(),
+      PaymentTerms::Net15,
+      Currency::EUR,
+    );
+    let item = InvoiceLineItem::new(
+      invoice.id,
+      LineItemDescription::new("Consulting".to_string()).unwrap(),
+      Quantity::new(quantity).unwrap(),
+      Money::new(dec!(100), Currency::EUR).unwrap(),
+      VatRate::new(dec!(24)).unwrap(),
+      1,
+    );
+    (invoice, item)
+  }
+
+  #[test]
+  fn test_seal_chains_to_previous_link() {
+    let (first, first_item) = invoice_with_line("INV-001", dec!(1));
+    let (second, second_item) = invoice_with_line("INV-002", dec!(2));
+
+    let head = InvoiceChainLink::seal(&first, std::slice::from_ref(&first_item), None);
+    let next = InvoiceChainLink::seal(&second, std::slice::from_ref(&second_item), Some(&head));
+
+    assert_eq!(head.sequence, 1);
+    assert_eq!(head.previous_hash, None);
+    assert_eq!(next.sequence, 2);
+    assert_eq!(next.previous_hash.as_deref(), Some(head.hash.as_str()));
+    assert_eq!(next.hash.len(), 64);
+  }
+
+  #[test]
+  fn test_matches_ignores_status_and_decimal_scale() {
+    let (mut invoice, mut item) = invoice_with_line("INV-001", dec!(2));
+    let link = InvoiceChainLink::seal(&invoice, std::slice::from_ref(&item), None);
+
+    invoice
+      .change_status(crate::domain::invoice::InvoiceStatus::Sent)
+      .unwrap();
+    invoice.set_pdf_path("/tmp/inv.pdf".to_string());
+    item.quantity = Quantity::new(dec!(2.00)).unwrap();
+    assert!(link.matches(&invoice, std::slice::from_ref(&item)));
+
+    item.quantity = Quantity::new(dec!(3)).unwrap();
+    assert!(!link.matches(&invoice, std::slice::from_ref(&item)));
+  }
+}
@@ -3,9 +3,11 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::errors::InvoiceEntityError;
+use super::localization::InvoiceLanguage;
 use super::value_objects::{
-  Currency, CustomerAddress, CustomerName, InvoiceNumber, InvoiceStatus, LineItemDescription,
-  Money, PaymentTerms, Quantity, TemplateName, VatRate,
+  AccentColor, BrandingText, Currency, CustomInvoiceTemplate, CustomerAddress, CustomerName,
+  InvoiceEventKind, InvoiceFont, InvoiceNumber, InvoiceStatus, LineItemDescription, Money,
+  PaymentTerms, Quantity, TemplateName, VatRate,
 };
 
 // Customer - Reusable client information
@@ -15,6 +17,7 @@ pub struct Customer {
   pub company_id: Uuid,
   pub name: CustomerName,
   pub address: Option<CustomerAddress>,
+  pub default_language: InvoiceLanguage,
   pub created_at: DateTime<Utc>,
   pub updated_at: DateTime<Utc>,
   pub archived_at: Option<DateTime<Utc>>,
@@ -28,6 +31,7 @@ impl Customer {
       company_id,
       name,
       address,
+      default_language: InvoiceLanguage::default(),
       created_at: now,
       updated_at: now,
       archived_at: None,
@@ -40,6 +44,11 @@ impl Customer {
     self.updated_at = Utc::now();
   }
 
+  pub fn set_default_language(&mut self, language: InvoiceLanguage) {
+    self.default_language = language;
+    self.updated_at = Utc::now();
+  }
+
   pub fn archive(&mut self) {
     self.archived_at = Some(Utc::now());
   }
@@ -61,6 +70,7 @@ pub struct Invoice {
   pub due_date: NaiveDate,
   pub payment_terms: PaymentTerms,
   pub currency: Currency,
+  pub language: InvoiceLanguage,
   pub status: InvoiceStatus,
   pub pdf_path: Option<String>,
   pub pdf_drive_file_id: Option<String>,
@@ -92,6 +102,7 @@ impl Invoice {
       due_date,
       payment_terms,
       currency,
+      language: InvoiceLanguage::default(),
       status: InvoiceStatus::Draft,
       pdf_path: None,
       pdf_drive_file_id: None,
@@ -124,6 +135,23 @@ impl Invoice {
     Ok(())
   }
 
+  pub fn with_language(mut self, language: InvoiceLanguage) -> Self {
+    self.language = language;
+    self
+  }
+
+  pub fn change_language(&mut self, language: InvoiceLanguage) -> Result<(), InvoiceEntityError> {
+    if !self.status.is_editable() {
+      return Err(InvoiceEntityError::CannotEditNonDraftInvoice {
+        current_status: self.status,
+      });
+    }
+
+    self.language = language;
+    self.updated_at = Utc::now();
+    Ok(())
+  }
+
   pub fn change_status(&mut self, new_status: InvoiceStatus) -> Result<(), InvoiceEntityError> {
     if !self.status.can_transition_to(new_status) {
       return Err(InvoiceEntityError::InvalidStatusTransition {
@@ -342,6 +370,195 @@ impl InvoiceTemplateLineItem {
   }
 }
 
+// Invoice Branding - Per-company look and default texts of invoice documents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvoiceBranding {
+  pub company_id: Uuid,
+  pub logo_path: Option<String>,
+  pub accent_color: AccentColor,
+  pub font: InvoiceFont,
+  pub default_notes: Option<BrandingText>,
+  pub footer_text: Option<BrandingText>,
+  pub signature_text: Option<BrandingText>,
+  pub custom_template: Option<CustomInvoiceTemplate>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl InvoiceBranding {
+  /// Default branding used until the company saves its own
+  pub fn new(company_id: Uuid) -> Self {
+    let now = Utc::now();
+    Self {
+      company_id,
+      logo_path: None,
+      accent_color: AccentColor::default(),
+      font: InvoiceFont::default(),
+      default_notes: None,
+      footer_text: None,
+      signature_text: None,
+      custom_template: None,
+      created_at: now,
+      updated_at: now,
+    }
+  }
+
+  pub fn update(
+    &mut self,
+    accent_color: AccentColor,
+    font: InvoiceFont,
+    default_notes: Option<BrandingText>,
+    footer_text: Option<BrandingText>,
+    signature_text: Option<BrandingText>,
+    custom_template: Option<CustomInvoiceTemplate>,
+  ) {
+    self.accent_color = accent_color;
+    self.font = font;
+    self.default_notes = default_notes;
+    self.footer_text = footer_text;
+    self.signature_text = signature_text;
+    self.custom_template = custom_template;
+    self.updated_at = Utc::now();
+  }
+
+  pub fn set_logo_path(&mut self, logo_path: Option<String>) {
+    self.logo_path = logo_path;
+    self.updated_at = Utc::now();
+  }
+}
+
+// Invoice Field Change - One field's value before and after an audit event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvoiceFieldChange {
+  pub field: String,
+  pub before: Option<String>,
+  pub after: Option<String>,
+}
+
+// Invoice Snapshot - Flattened field values of an invoice, used to diff audit events
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InvoiceSnapshot {
+  fields: Vec<(String, String)>,
+}
+
+impl InvoiceSnapshot {
+  /// Header fields of the invoice; absent optional values are left out
+  pub fn capture(invoice: &Invoice) -> Self {
+    let mut fields = vec![
+      ("invoice_number", invoice.invoice_number.value().to_string()),
+      ("customer_id", invoice.customer_id.to_string()),
+      ("invoice_date", invoice.invoice_date.to_string()),
+      ("due_date", invoice.due_date.to_string()),
+      ("payment_terms", invoice.payment_terms.as_str()),
+      ("currency", invoice.currency.as_str().to_string()),
+      ("language", invoice.language.as_str().to_string()),
+      ("status", invoice.status.as_str().to_string()),
+    ];
+    if let Some(bank_account_id) = invoice.bank_account_id {
+      fields.push(("bank_account_id", bank_account_id.to_string()));
+    }
+    if let Some(pdf_path) = &invoice.pdf_path {
+      fields.push(("pdf_path", pdf_path.clone()));
+    }
+    if let Some(file_id) = &invoice.pdf_drive_file_id {
+      fields.push(("pdf_drive_file_id", file_id.clone()));
+    }
+    if let Some(archived_at) = invoice.archived_at {
+      fields.push(("archived_at", archived_at.to_rfc3339()));
+    }
+
+    Self {
+      fields: fields
+        .into_iter()
+        .map(|(field, value)| (field.to_string(), value))
+        .collect(),
+    }
+  }
+
+  /// Adds one `line_items.N` entry per line, numbered by position
+  pub fn with_line_items(mut self, line_items: &[InvoiceLineItem]) -> Self {
+    for (i, item) in line_items.iter().enumerate() {
+      self.fields.push((
+        format!("line_items.{}", i + 1),
+        format!(
+          "{} | {} x {} | VAT {}%",
+          item.description.value(),
+          item.quantity.value().normalize(),
+          item.unit_price,
+          item.vat_rate.value().normalize()
+        ),
+      ));
+    }
+    self
+  }
+
+  fn get(&self, field: &str) -> Option<&String> {
+    self
+      .fields
+      .iter()
+      .find(|(name, _)| name == field)
+      .map(|(_, value)| value)
+  }
+
+  /// Fields whose value differs between the two snapshots, in field order
+  pub fn diff(&self, after: &InvoiceSnapshot) -> Vec<InvoiceFieldChange> {
+    let changed = self
+      .fields
+      .iter()
+      .filter(|(field, value)| after.get(field) != Some(value))
+      .map(|(field, value)| InvoiceFieldChange {
+        field: field.clone(),
+        before: Some(value.clone()),
+        after: after.get(field).cloned(),
+      });
+    let added = after
+      .fields
+      .iter()
+      .filter(|(field, _)| self.get(field).is_none())
+      .map(|(field, value)| InvoiceFieldChange {
+        field: field.clone(),
+        before: None,
+        after: Some(value.clone()),
+      });
+
+    changed.chain(added).collect()
+  }
+}
+
+// Invoice Event - Append-only audit log entry; kept after the invoice is deleted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvoiceEvent {
+  pub id: Uuid,
+  pub invoice_id: Uuid,
+  pub company_id: Uuid,
+  pub invoice_number: String,
+  /// None for system actions (e.g. the overdue job)
+  pub user_id: Option<Uuid>,
+  pub kind: InvoiceEventKind,
+  pub changes: Vec<InvoiceFieldChange>,
+  pub created_at: DateTime<Utc>,
+}
+
+impl InvoiceEvent {
+  pub fn new(
+    invoice: &Invoice,
+    user_id: Uuid,
+    kind: InvoiceEventKind,
+    changes: Vec<InvoiceFieldChange>,
+  ) -> Self {
+    Self {
+      id: Uuid::new_v4(),
+      invoice_id: invoice.id,
+      company_id: invoice.company_id,
+      invoice_number: invoice.invoice_number.value().to_string(),
+      user_id: (!user_id.is_nil()).then_some(user_id),
+      kind,
+      changes,
+      created_at: Utc::now(),
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -440,6 +657,26 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_invoice_language_only_changes_when_draft() {
+    let mut invoice = Invoice::new(
+      Uuid::new_v4(),
+      Uuid::new_v4(),
+      None,
+      InvoiceNumber::new("INV-001".to_string()).unwrap(),
+      NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+      PaymentTerms::Net30,
+      Currency::EUR,
+    )
+    .with_language(InvoiceLanguage::Et);
+    assert_eq!(invoice.language, InvoiceLanguage::Et);
+
+    assert!(invoice.change_language(InvoiceLanguage::Fi).is_ok());
+    invoice.change_status(InvoiceStatus::Sent).unwrap();
+    assert!(invoice.change_language(InvoiceLanguage::Ru).is_err());
+    assert_eq!(invoice.language, InvoiceLanguage::Fi);
+  }
+
   #[test]
   fn test_line_item_calculations() {
     let line_item = InvoiceLineItem::new(
@@ -502,4 +739,94 @@ mod tests {
     invoice.change_status(InvoiceStatus::Sent).unwrap();
     assert!(invoice.is_overdue(current_date)); // Past due date
   }
+
+  #[test]
+  fn test_invoice_branding_defaults_and_update() {
+    let company_id = Uuid::new_v4();
+    let mut branding = InvoiceBranding::new(company_id);
+    assert_eq!(branding.company_id, company_id);
+    assert_eq!(branding.accent_color, AccentColor::default());
+    assert_eq!(branding.font, InvoiceFont::Arial);
+    assert!(branding.custom_template.is_none());
+
+    branding.update(
+      AccentColor::new("#2563eb".to_string()).unwrap(),
+      InvoiceFont::Georgia,
+      BrandingText::new("Thank you for your business".to_string()).unwrap(),
+      None,
+      BrandingText::new("Jane Doe, CEO".to_string()).unwrap(),
+      None,
+    );
+    branding.set_logo_path(Some("data/branding/logo.png".to_string()));
+
+    assert_eq!(branding.accent_color.value(), "#2563eb");
+    assert_eq!(branding.font, InvoiceFont::Georgia);
+    assert_eq!(
+      branding.default_notes.as_ref().map(|n| n.value()),
+      Some("Thank you for your business")
+    );
+    assert!(branding.footer_text.is_none());
+    assert_eq!(
+      branding.logo_path.as_deref(),
+      Some("data/branding/logo.png")
+    );
+  }
+
+  #[test]
+  fn test_invoice_snapshot_diff() {
+    let mut invoice = Invoice::new(
+      Uuid::new_v4(),
+      Uuid::new_v4(),
+      None,
+      InvoiceNumber::new("INV-001".to_string()).unwrap(),
+      NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+      PaymentTerms::Net30,
+      Currency::EUR,
+    );
+    let item = InvoiceLineItem::new(
+      invoice.id,
+      LineItemDescription::new("Consulting".to_string()).unwrap(),
+      Quantity::new(dec!(2)).unwrap(),
+      Money::new(dec!(100), Currency::EUR).unwrap(),
+      VatRate::new(dec!(24)).unwrap(),
+      1,
+    );
+    let before = InvoiceSnapshot::capture(&invoice).with_line_items(std::slice::from_ref(&item));
+
+    invoice.change_status(InvoiceStatus::Sent).unwrap();
+    invoice.set_pdf_path("/tmp/inv.pdf".to_string());
+    let after = InvoiceSnapshot::capture(&invoice);
+
+    let changes = before.diff(&after);
+    assert_eq!(
+      changes,
+      vec![
+        InvoiceFieldChange {
+          field: "status".to_string(),
+          before: Some("draft".to_string()),
+          after: Some("sent".to_string()),
+        },
+        InvoiceFieldChange {
+          field: "line_items.1".to_string(),
+          before: Some("Consulting | 2 x €100.00 | VAT 24%".to_string()),
+          after: None,
+        },
+        InvoiceFieldChange {
+          field: "pdf_path".to_string(),
+          before: None,
+          after: Some("/tmp/inv.pdf".to_string()),
+        },
+      ]
+    );
+    assert!(after.diff(&after).is_empty());
+
+    let event = InvoiceEvent::new(
+      &invoice,
+      Uuid::nil(),
+      InvoiceEventKind::StatusChanged,
+      changes,
+    );
+    assert_eq!(event.user_id, None);
+    assert_eq!(event.invoice_number, "INV-001");
+  }
 }
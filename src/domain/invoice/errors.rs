@@ -1,4 +1,8 @@
 use super::value_objects::{InvoiceStatus, ValueObjectError};
+use crate::domain::document::DocumentError;
+use crate::domain::retention::RetentionError;
+use crate::domain::storage::StorageError;
+use serde::Serialize;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -15,6 +19,23 @@ pub enum InvoiceEntityError {
   },
 }
 
+/// Validation failure tied to one input field (e.g. `line_items.2.quantity`)
+/// so forms can show it next to the offending input
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvoiceFieldError {
+  pub field: String,
+  pub message: String,
+}
+
+impl InvoiceFieldError {
+  pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+    Self {
+      field: field.into(),
+      message: message.into(),
+    }
+  }
+}
+
 #[derive(Debug, Error)]
 pub enum InvoiceError {
   #[error("Validation error: {0}")]
@@ -53,6 +74,9 @@ pub enum InvoiceError {
   #[error("No line items provided")]
   NoLineItems,
 
+  #[error("Invalid fields: {}", .0.iter().map(|e| e.field.as_str()).collect::<Vec<_>>().join(", "))]
+  InvalidFields(Vec<InvoiceFieldError>),
+
   #[error("Invalid line item order")]
   InvalidLineItemOrder,
 
@@ -65,6 +89,9 @@ pub enum InvoiceError {
   #[error("Cannot delete invoice: {0}")]
   CannotDeleteInvoice(String),
 
+  #[error("Invoice chain conflict: another invoice was sealed concurrently")]
+  ChainConflict,
+
   #[error("PDF generation failed: {0}")]
   PdfGenerationFailed(String),
 
@@ -83,3 +110,31 @@ pub enum InvoiceError {
   #[error("Internal error: {0}")]
   Internal(String),
 }
+
+impl From<DocumentError> for InvoiceError {
+  fn from(error: DocumentError) -> Self {
+    match error {
+      DocumentError::Repository(e) => InvoiceError::Repository(e.to_string()),
+      other => InvoiceError::Internal(other.to_string()),
+    }
+  }
+}
+
+impl From<RetentionError> for InvoiceError {
+  fn from(error: RetentionError) -> Self {
+    match error {
+      RetentionError::Retained { .. } => InvoiceError::CannotDeleteInvoice(error.to_string()),
+      RetentionError::NotMember | RetentionError::InsufficientPermissions => {
+        InvoiceError::PermissionDenied(error.to_string())
+      }
+      RetentionError::Repository(e) => InvoiceError::Repository(e.to_string()),
+      RetentionError::Validation(message) => InvoiceError::Internal(message),
+    }
+  }
+}
+
+impl From<StorageError> for InvoiceError {
+  fn from(error: StorageError) -> Self {
+    InvoiceError::CloudStorageUploadFailed(error.to_string())
+  }
+}
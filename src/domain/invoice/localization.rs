@@ -0,0 +1,348 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use super::value_objects::{Currency, PaymentTerms, ValueObjectError};
+
+/// Non-breaking space used as the thousands separator in et/fi/ru
+const NBSP: char = '\u{a0}';
+
+// Invoice Language - language the invoice document is rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InvoiceLanguage {
+  #[default]
+  En,
+  Et,
+  Fi,
+  Ru,
+}
+
+/// Static labels printed on the invoice document
+#[derive(Debug, Clone, Serialize)]
+pub struct InvoiceLabels {
+  pub invoice_title: &'static str,
+  pub invoice_number: &'static str,
+  pub invoice_date: &'static str,
+  pub due_date: &'static str,
+  pub issued_to: &'static str,
+  pub description: &'static str,
+  pub quantity: &'static str,
+  pub unit_price: &'static str,
+  pub vat_percent: &'static str,
+  pub line_total: &'static str,
+  pub subtotal: &'static str,
+  pub total_vat: &'static str,
+  pub grand_total: &'static str,
+  pub payment_details: &'static str,
+  pub bank_details: &'static str,
+  pub iban: &'static str,
+  pub payment_terms: &'static str,
+  pub email: &'static str,
+  pub phone: &'static str,
+  pub vat_number: &'static str,
+  pub registry_code: &'static str,
+  pub notes: &'static str,
+}
+
+const EN_LABELS: InvoiceLabels = InvoiceLabels {
+  invoice_title: "INVOICE",
+  invoice_number: "Invoice",
+  invoice_date: "Date",
+  due_date: "Due Date",
+  issued_to: "Issued To",
+  description: "Description",
+  quantity: "Qty",
+  unit_price: "Price",
+  vat_percent: "VAT %",
+  line_total: "Total",
+  subtotal: "Subtotal",
+  total_vat: "Total VAT",
+  grand_total: "Grand Total",
+  payment_details: "Payment Details",
+  bank_details: "Bank Details",
+  iban: "IBAN",
+  payment_terms: "Payment Terms",
+  email: "Email",
+  phone: "Phone",
+  vat_number: "VAT No.",
+  registry_code: "Reg. code",
+  notes: "Notes",
+};
+
+const ET_LABELS: InvoiceLabels = InvoiceLabels {
+  invoice_title: "ARVE",
+  invoice_number: "Arve nr",
+  invoice_date: "Kuupäev",
+  due_date: "Maksetähtaeg",
+  issued_to: "Maksja",
+  description: "Kirjeldus",
+  quantity: "Kogus",
+  unit_price: "Hind",
+  vat_percent: "KM %",
+  line_total: "Summa",
+  subtotal: "Summa km-ta",
+  total_vat: "Käibemaks",
+  grand_total: "Kokku tasuda",
+  payment_details: "Makseandmed",
+  bank_details: "Panga andmed",
+  iban: "IBAN",
+  payment_terms: "Maksetingimused",
+  email: "E-post",
+  phone: "Telefon",
+  vat_number: "KMKR nr",
+  registry_code: "Registrikood",
+  notes: "Märkused",
+};
+
+const FI_LABELS: InvoiceLabels = InvoiceLabels {
+  invoice_title: "LASKU",
+  invoice_number: "Laskun numero",
+  invoice_date: "Päivämäärä",
+  due_date: "Eräpäivä",
+  issued_to: "Vastaanottaja",
+  description: "Kuvaus",
+  quantity: "Määrä",
+  unit_price: "Hinta",
+  vat_percent: "ALV %",
+  line_total: "Yhteensä",
+  subtotal: "Veroton summa",
+  total_vat: "ALV yhteensä",
+  grand_total: "Maksettava yhteensä",
+  payment_details: "Maksutiedot",
+  bank_details: "Pankkitiedot",
+  iban: "IBAN",
+  payment_terms: "Maksuehdot",
+  email: "Sähköposti",
+  phone: "Puhelin",
+  vat_number: "ALV-tunnus",
+  registry_code: "Y-tunnus",
+  notes: "Lisätiedot",
+};
+
+const RU_LABELS: InvoiceLabels = InvoiceLabels {
+  invoice_title: "СЧЁТ",
+  invoice_number: "Счёт №",
+  invoice_date: "Дата",
+  due_date: "Срок оплаты",
+  issued_to: "Плательщик",
+  description: "Описание",
+  quantity: "Кол-во",
+  unit_price: "Цена",
+  vat_percent: "НДС %",
+  line_total: "Сумма",
+  subtotal: "Сумма без НДС",
+  total_vat: "НДС",
+  grand_total: "Итого к оплате",
+  payment_details: "Платёжные реквизиты",
+  bank_details: "Банковские реквизиты",
+  iban: "IBAN",
+  payment_terms: "Условия оплаты",
+  email: "Эл. почта",
+  phone: "Телефон",
+  vat_number: "Номер НДС",
+  registry_code: "Рег. код",
+  notes: "Примечания",
+};
+
+impl InvoiceLanguage {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      InvoiceLanguage::En => "en",
+      InvoiceLanguage::Et => "et",
+      InvoiceLanguage::Fi => "fi",
+      InvoiceLanguage::Ru => "ru",
+    }
+  }
+
+  /// Name of the language in the language itself, for selects
+  pub fn native_name(&self) -> &'static str {
+    match self {
+      InvoiceLanguage::En => "English",
+      InvoiceLanguage::Et => "Eesti",
+      InvoiceLanguage::Fi => "Suomi",
+      InvoiceLanguage::Ru => "Русский",
+    }
+  }
+
+  pub fn all() -> &'static [InvoiceLanguage] {
+    &[
+      InvoiceLanguage::En,
+      InvoiceLanguage::Et,
+      InvoiceLanguage::Fi,
+      InvoiceLanguage::Ru,
+    ]
+  }
+
+  pub fn labels(&self) -> &'static InvoiceLabels {
+    match self {
+      InvoiceLanguage::En => &EN_LABELS,
+      InvoiceLanguage::Et => &ET_LABELS,
+      InvoiceLanguage::Fi => &FI_LABELS,
+      InvoiceLanguage::Ru => &RU_LABELS,
+    }
+  }
+
+  fn separators(&self) -> (char, char) {
+    match self {
+      InvoiceLanguage::En => (',', '.'),
+      InvoiceLanguage::Et | InvoiceLanguage::Fi | InvoiceLanguage::Ru => (NBSP, ','),
+    }
+  }
+
+  /// Format a number with the locale's thousands and decimal separators,
+  /// rounded to the given number of decimal places
+  pub fn format_number(&self, amount: Decimal, decimals: u32) -> String {
+    let (thousands_sep, decimal_sep) = self.separators();
+    let rounded = amount.round_dp(decimals);
+    let formatted = format!("{:.*}", decimals as usize, rounded.abs());
+    let (int_part, frac_part) = match formatted.split_once('.') {
+      Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+      None => (formatted.as_str(), None),
+    };
+
+    let mut result = String::new();
+    if rounded.is_sign_negative() && !rounded.is_zero() {
+      result.push('-');
+    }
+    for (i, digit) in int_part.chars().enumerate() {
+      if i > 0 && (int_part.len() - i) % 3 == 0 {
+        result.push(thousands_sep);
+      }
+      result.push(digit);
+    }
+    if let Some(frac_part) = frac_part {
+      result.push(decimal_sep);
+      result.push_str(frac_part);
+    }
+    result
+  }
+
+  /// Format an amount with the currency symbol placed as the locale expects
+  /// (prefix in English, suffix otherwise)
+  pub fn format_money(&self, amount: Decimal, currency: Currency) -> String {
+    let number = self.format_number(amount, 2);
+    match self {
+      InvoiceLanguage::En => match number.strip_prefix('-') {
+        Some(abs) => format!("-{}{}", currency.symbol(), abs),
+        None => format!("{}{}", currency.symbol(), number),
+      },
+      InvoiceLanguage::Et | InvoiceLanguage::Fi | InvoiceLanguage::Ru => {
+        format!("{}{}{}", number, NBSP, currency.symbol())
+      }
+    }
+  }
+
+  pub fn format_date(&self, date: NaiveDate) -> String {
+    let pattern = match self {
+      InvoiceLanguage::En => "%b %-d, %Y",
+      InvoiceLanguage::Et | InvoiceLanguage::Ru => "%d.%m.%Y",
+      InvoiceLanguage::Fi => "%-d.%-m.%Y",
+    };
+    date.format(pattern).to_string()
+  }
+
+  pub fn payment_terms(&self, terms: &PaymentTerms) -> String {
+    let days = terms.days();
+    match (self, terms) {
+      (InvoiceLanguage::En, _) => terms.to_string(),
+      (InvoiceLanguage::Et, PaymentTerms::DueOnReceipt) => "Tasuda kohe".to_string(),
+      (InvoiceLanguage::Et, _) => format!("{} päeva", days),
+      (InvoiceLanguage::Fi, PaymentTerms::DueOnReceipt) => "Maksetaan heti".to_string(),
+      (InvoiceLanguage::Fi, _) => format!("{} päivää netto", days),
+      (InvoiceLanguage::Ru, PaymentTerms::DueOnReceipt) => "Оплата при получении".to_string(),
+      (InvoiceLanguage::Ru, _) => format!("Оплата в течение {} дн.", days),
+    }
+  }
+}
+
+impl FromStr for InvoiceLanguage {
+  type Err = ValueObjectError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim().to_lowercase().as_str() {
+      "en" => Ok(InvoiceLanguage::En),
+      "et" => Ok(InvoiceLanguage::Et),
+      "fi" => Ok(InvoiceLanguage::Fi),
+      "ru" => Ok(InvoiceLanguage::Ru),
+      _ => Err(ValueObjectError::InvalidLanguage(format!(
+        "Unsupported invoice language: {}",
+        s
+      ))),
+    }
+  }
+}
+
+impl fmt::Display for InvoiceLanguage {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rust_decimal_macros::dec;
+
+  #[test]
+  fn test_language_from_str() {
+    assert_eq!(
+      InvoiceLanguage::from_str("ET").unwrap(),
+      InvoiceLanguage::Et
+    );
+    assert_eq!(
+      InvoiceLanguage::from_str("ru").unwrap(),
+      InvoiceLanguage::Ru
+    );
+    assert!(InvoiceLanguage::from_str("de").is_err());
+  }
+
+  #[test]
+  fn test_format_number() {
+    assert_eq!(
+      InvoiceLanguage::En.format_number(dec!(1234567.5), 2),
+      "1,234,567.50"
+    );
+    assert_eq!(
+      InvoiceLanguage::Et.format_number(dec!(1234.5), 2),
+      "1\u{a0}234,50"
+    );
+    assert_eq!(
+      InvoiceLanguage::Fi.format_number(dec!(-999.999), 2),
+      "-1\u{a0}000,00"
+    );
+    assert_eq!(InvoiceLanguage::Ru.format_number(dec!(24), 0), "24");
+  }
+
+  #[test]
+  fn test_format_money_symbol_placement() {
+    assert_eq!(
+      InvoiceLanguage::En.format_money(dec!(-1500), Currency::EUR),
+      "-€1,500.00"
+    );
+    assert_eq!(
+      InvoiceLanguage::Et.format_money(dec!(1500), Currency::EUR),
+      "1\u{a0}500,00\u{a0}€"
+    );
+  }
+
+  #[test]
+  fn test_format_date_and_payment_terms() {
+    let date = NaiveDate::from_ymd_opt(2026, 3, 5).unwrap();
+    assert_eq!(InvoiceLanguage::En.format_date(date), "Mar 5, 2026");
+    assert_eq!(InvoiceLanguage::Et.format_date(date), "05.03.2026");
+    assert_eq!(InvoiceLanguage::Fi.format_date(date), "5.3.2026");
+
+    assert_eq!(
+      InvoiceLanguage::En.payment_terms(&PaymentTerms::Net15),
+      "Net 15"
+    );
+    assert_eq!(
+      InvoiceLanguage::Et.payment_terms(&PaymentTerms::Custom(7)),
+      "7 päeva"
+    );
+    assert_eq!(InvoiceLanguage::Ru.labels().grand_total, "Итого к оплате");
+  }
+}
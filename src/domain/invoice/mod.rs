@@ -1,19 +1,28 @@
+pub mod chain;
 pub mod entities;
 pub mod errors;
+pub mod localization;
 pub mod ports;
 pub mod services;
 pub mod value_objects;
 
+pub use chain::{BrokenChainLink, ChainBreak, InvoiceChainLink, InvoiceChainReport};
 pub use entities::{
-  Customer, Invoice, InvoiceLineItem, InvoiceTemplate, InvoiceTemplateLineItem, InvoiceTotals,
+  Customer, Invoice, InvoiceBranding, InvoiceEvent, InvoiceFieldChange, InvoiceLineItem,
+  InvoiceSnapshot, InvoiceTemplate, InvoiceTemplateLineItem, InvoiceTotals,
 };
-pub use errors::InvoiceError;
+pub use errors::{InvoiceError, InvoiceFieldError};
+pub use localization::{InvoiceLabels, InvoiceLanguage};
 pub use ports::{
-  CustomerRepository, InvoiceLineItemRepository, InvoiceRepository,
-  InvoiceTemplateLineItemRepository, InvoiceTemplateRepository,
+  CustomerRepository, InvoiceBrandingRepository, InvoiceChainRepository, InvoiceEventRepository,
+  InvoiceLineItemRepository, InvoiceRepository, InvoiceTemplateLineItemRepository,
+  InvoiceTemplateRepository, InvoiceUnitOfWork, InvoiceUnitOfWorkFactory,
+};
+pub use services::{
+  InvoiceBrandingData, InvoiceData, InvoiceService, InvoiceServiceDependencies, InvoiceUpdateData,
 };
-pub use services::{InvoiceData, InvoiceService, InvoiceServiceDependencies, InvoiceUpdateData};
 pub use value_objects::{
-  Currency, CustomerAddress, CustomerName, InvoiceNumber, InvoiceStatus, LineItemDescription,
-  Money, PaymentTerms, Quantity, TemplateName, ValueObjectError, VatRate,
+  AccentColor, BrandingText, Currency, CustomInvoiceTemplate, CustomerAddress, CustomerName,
+  InvoiceEventKind, InvoiceFont, InvoiceNumber, InvoiceStatus, LineItemDescription, Money,
+  PaymentTerms, Quantity, TemplateName, ValueObjectError, VatRate,
 };
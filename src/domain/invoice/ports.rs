@@ -1,9 +1,12 @@
 use async_trait::async_trait;
 use chrono::NaiveDate;
+use std::any::Any;
 use uuid::Uuid;
 
+use super::chain::InvoiceChainLink;
 use super::entities::{
-  Customer, Invoice, InvoiceLineItem, InvoiceTemplate, InvoiceTemplateLineItem,
+  Customer, Invoice, InvoiceBranding, InvoiceEvent, InvoiceLineItem, InvoiceTemplate,
+  InvoiceTemplateLineItem,
 };
 use super::errors::InvoiceError;
 use super::value_objects::InvoiceStatus;
@@ -53,6 +56,26 @@ pub trait InvoiceRepository: Send + Sync {
     company_id: Uuid,
   ) -> Result<Vec<Invoice>, InvoiceError>;
   async fn delete(&self, id: Uuid) -> Result<(), InvoiceError>;
+  /// Save the invoice's status as part of the unit of work
+  async fn update_status(
+    &self,
+    unit_of_work: &mut dyn InvoiceUnitOfWork,
+    invoice: &Invoice,
+  ) -> Result<(), InvoiceError>;
+}
+
+/// An open database transaction the invoice repositories write through;
+/// none of the writes are kept unless it is committed
+#[async_trait]
+pub trait InvoiceUnitOfWork: Send {
+  /// The adapter's own transaction, for the repositories of the same adapter
+  fn transaction(&mut self) -> &mut (dyn Any + Send);
+  async fn commit(self: Box<Self>) -> Result<(), InvoiceError>;
+}
+
+#[async_trait]
+pub trait InvoiceUnitOfWorkFactory: Send + Sync {
+  async fn begin(&self) -> Result<Box<dyn InvoiceUnitOfWork>, InvoiceError>;
 }
 
 #[async_trait]
@@ -93,6 +116,43 @@ pub trait InvoiceTemplateRepository: Send + Sync {
   ) -> Result<bool, InvoiceError>;
 }
 
+#[async_trait]
+pub trait InvoiceBrandingRepository: Send + Sync {
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Option<InvoiceBranding>, InvoiceError>;
+  /// Insert or replace the branding of the company
+  async fn save(&self, branding: InvoiceBranding) -> Result<InvoiceBranding, InvoiceError>;
+}
+
+/// Append-only store of invoice audit events; there is no update or delete
+#[async_trait]
+pub trait InvoiceEventRepository: Send + Sync {
+  async fn append(&self, event: InvoiceEvent) -> Result<InvoiceEvent, InvoiceError>;
+  /// Events of one invoice, newest first
+  async fn find_by_invoice_id(&self, invoice_id: Uuid) -> Result<Vec<InvoiceEvent>, InvoiceError>;
+}
+
+/// Hash chain over issued invoices; links are append-only
+#[async_trait]
+pub trait InvoiceChainRepository: Send + Sync {
+  /// Store the link as part of the unit of work. Fails with
+  /// `InvoiceError::ChainConflict` if the sequence number or the invoice is
+  /// already taken
+  async fn append(
+    &self,
+    unit_of_work: &mut dyn InvoiceUnitOfWork,
+    link: InvoiceChainLink,
+  ) -> Result<InvoiceChainLink, InvoiceError>;
+  async fn find_latest(&self, company_id: Uuid) -> Result<Option<InvoiceChainLink>, InvoiceError>;
+  /// All links of the company in sequence order
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<InvoiceChainLink>, InvoiceError>;
+}
+
 #[async_trait]
 pub trait InvoiceTemplateLineItemRepository: Send + Sync {
   async fn create_many(
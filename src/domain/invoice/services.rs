@@ -7,17 +7,22 @@ use crate::domain::company::ports::{
   BankAccountRepository, CompanyMemberRepository, CompanyRepository,
 };
 
+use super::chain::{BrokenChainLink, ChainBreak, InvoiceChainLink, InvoiceChainReport};
 use super::entities::{
-  Customer, Invoice, InvoiceLineItem, InvoiceTemplate, InvoiceTemplateLineItem, InvoiceTotals,
+  Customer, Invoice, InvoiceBranding, InvoiceEvent, InvoiceFieldChange, InvoiceLineItem,
+  InvoiceSnapshot, InvoiceTemplate, InvoiceTemplateLineItem, InvoiceTotals,
 };
 use super::errors::InvoiceError;
+use super::localization::InvoiceLanguage;
 use super::ports::{
-  CustomerRepository, InvoiceLineItemRepository, InvoiceRepository,
-  InvoiceTemplateLineItemRepository, InvoiceTemplateRepository,
+  CustomerRepository, InvoiceBrandingRepository, InvoiceChainRepository, InvoiceEventRepository,
+  InvoiceLineItemRepository, InvoiceRepository, InvoiceTemplateLineItemRepository,
+  InvoiceTemplateRepository, InvoiceUnitOfWorkFactory,
 };
 use super::value_objects::{
-  Currency, CustomerAddress, CustomerName, InvoiceNumber, InvoiceStatus, LineItemDescription,
-  Money, PaymentTerms, Quantity, TemplateName, VatRate,
+  AccentColor, BrandingText, Currency, CustomInvoiceTemplate, CustomerAddress, CustomerName,
+  InvoiceEventKind, InvoiceFont, InvoiceNumber, InvoiceStatus, LineItemDescription, Money,
+  PaymentTerms, Quantity, TemplateName, VatRate,
 };
 
 /// Invoice creation data
@@ -28,6 +33,8 @@ pub struct InvoiceData {
   pub invoice_date: NaiveDate,
   pub payment_terms: PaymentTerms,
   pub currency: Currency,
+  /// Document language; None uses the customer's default language
+  pub language: Option<InvoiceLanguage>,
   pub line_items: Vec<(LineItemDescription, Quantity, Money, VatRate)>,
 }
 
@@ -37,9 +44,24 @@ pub struct InvoiceUpdateData {
   pub bank_account_id: Option<Uuid>,
   pub invoice_date: NaiveDate,
   pub payment_terms: PaymentTerms,
+  /// New document language; None keeps the current one
+  pub language: Option<InvoiceLanguage>,
   pub line_items: Vec<(LineItemDescription, Quantity, Money, VatRate)>,
 }
 
+/// Invoice branding data submitted from company settings
+pub struct InvoiceBrandingData {
+  pub accent_color: AccentColor,
+  pub font: InvoiceFont,
+  pub default_notes: Option<BrandingText>,
+  pub footer_text: Option<BrandingText>,
+  pub signature_text: Option<BrandingText>,
+  pub custom_template: Option<CustomInvoiceTemplate>,
+  /// Newly uploaded logo; None keeps the current one
+  pub logo_path: Option<String>,
+  pub remove_logo: bool,
+}
+
 /// Dependencies for InvoiceService
 pub struct InvoiceServiceDependencies {
   pub invoice_repo: Arc<dyn InvoiceRepository>,
@@ -50,6 +72,10 @@ pub struct InvoiceServiceDependencies {
   pub bank_account_repo: Arc<dyn BankAccountRepository>,
   pub template_repo: Arc<dyn InvoiceTemplateRepository>,
   pub template_line_item_repo: Arc<dyn InvoiceTemplateLineItemRepository>,
+  pub branding_repo: Arc<dyn InvoiceBrandingRepository>,
+  pub event_repo: Arc<dyn InvoiceEventRepository>,
+  pub chain_repo: Arc<dyn InvoiceChainRepository>,
+  pub unit_of_work: Arc<dyn InvoiceUnitOfWorkFactory>,
 }
 
 pub struct InvoiceService {
@@ -61,6 +87,10 @@ pub struct InvoiceService {
   bank_account_repo: Arc<dyn BankAccountRepository>,
   template_repo: Arc<dyn InvoiceTemplateRepository>,
   template_line_item_repo: Arc<dyn InvoiceTemplateLineItemRepository>,
+  branding_repo: Arc<dyn InvoiceBrandingRepository>,
+  event_repo: Arc<dyn InvoiceEventRepository>,
+  chain_repo: Arc<dyn InvoiceChainRepository>,
+  unit_of_work: Arc<dyn InvoiceUnitOfWorkFactory>,
 }
 
 impl InvoiceService {
@@ -74,6 +104,10 @@ impl InvoiceService {
       bank_account_repo: deps.bank_account_repo,
       template_repo: deps.template_repo,
       template_line_item_repo: deps.template_line_item_repo,
+      branding_repo: deps.branding_repo,
+      event_repo: deps.event_repo,
+      chain_repo: deps.chain_repo,
+      unit_of_work: deps.unit_of_work,
     }
   }
 
@@ -84,6 +118,7 @@ impl InvoiceService {
     company_id: Uuid,
     name: CustomerName,
     address: Option<CustomerAddress>,
+    default_language: InvoiceLanguage,
   ) -> Result<Customer, InvoiceError> {
     // Verify user is company member
     self.verify_company_membership(user_id, company_id).await?;
@@ -97,7 +132,8 @@ impl InvoiceService {
       return Err(InvoiceError::CustomerNameAlreadyExists);
     }
 
-    let customer = Customer::new(company_id, name, address);
+    let mut customer = Customer::new(company_id, name, address);
+    customer.set_default_language(default_language);
     self.customer_repo.create(customer).await
   }
 
@@ -107,6 +143,7 @@ impl InvoiceService {
     customer_id: Uuid,
     name: CustomerName,
     address: Option<CustomerAddress>,
+    default_language: Option<InvoiceLanguage>,
   ) -> Result<Customer, InvoiceError> {
     let mut customer = self
       .customer_repo
@@ -129,6 +166,9 @@ impl InvoiceService {
     }
 
     customer.update(name, address);
+    if let Some(language) = default_language {
+      customer.set_default_language(language);
+    }
     self.customer_repo.update(customer).await
   }
 
@@ -241,7 +281,8 @@ impl InvoiceService {
       data.invoice_date,
       data.payment_terms,
       data.currency,
-    );
+    )
+    .with_language(data.language.unwrap_or(customer.default_language));
 
     let created_invoice = self.invoice_repo.create(invoice).await?;
 
@@ -264,6 +305,16 @@ impl InvoiceService {
 
     let created_line_items = self.line_item_repo.create_many(line_items_entities).await?;
 
+    let snapshot = InvoiceSnapshot::capture(&created_invoice).with_line_items(&created_line_items);
+    self
+      .record_event(
+        &created_invoice,
+        user_id,
+        InvoiceEventKind::Created,
+        InvoiceSnapshot::default().diff(&snapshot),
+      )
+      .await?;
+
     Ok((created_invoice, created_line_items))
   }
 
@@ -319,6 +370,9 @@ impl InvoiceService {
       }
     }
 
+    let old_line_items = self.line_item_repo.find_by_invoice_id(invoice_id).await?;
+    let before = InvoiceSnapshot::capture(&invoice).with_line_items(&old_line_items);
+
     // Update invoice
     invoice.update(
       data.customer_id,
@@ -326,6 +380,9 @@ impl InvoiceService {
       data.invoice_date,
       data.payment_terms,
     )?;
+    if let Some(language) = data.language {
+      invoice.change_language(language)?;
+    }
 
     let updated_invoice = self.invoice_repo.update(invoice).await?;
 
@@ -350,6 +407,16 @@ impl InvoiceService {
 
     let created_line_items = self.line_item_repo.create_many(line_items_entities).await?;
 
+    let after = InvoiceSnapshot::capture(&updated_invoice).with_line_items(&created_line_items);
+    self
+      .record_event(
+        &updated_invoice,
+        user_id,
+        InvoiceEventKind::Updated,
+        before.diff(&after),
+      )
+      .await?;
+
     Ok((updated_invoice, created_line_items))
   }
 
@@ -370,9 +437,114 @@ impl InvoiceService {
       .verify_company_membership(user_id, invoice.company_id)
       .await?;
 
+    let before = InvoiceSnapshot::capture(&invoice);
+    // Once issued, the content is sealed into the company's hash chain; a
+    // draft that is cancelled was never issued
+    let issues = invoice.status == InvoiceStatus::Draft
+      && matches!(new_status, InvoiceStatus::Sent | InvoiceStatus::Paid);
     invoice.change_status(new_status)?;
 
-    self.invoice_repo.update(invoice).await
+    let updated = if issues {
+      self.seal_invoice(&invoice).await?;
+      invoice
+    } else {
+      self.invoice_repo.update(invoice).await?
+    };
+    self
+      .record_event(
+        &updated,
+        user_id,
+        InvoiceEventKind::StatusChanged,
+        before.diff(&InvoiceSnapshot::capture(&updated)),
+      )
+      .await?;
+
+    Ok(updated)
+  }
+
+  /// Append the invoice to its company's hash chain, saving its new status
+  /// along with the link in one unit of work. Retries when another invoice of
+  /// the same company was sealed at the same moment.
+  async fn seal_invoice(&self, invoice: &Invoice) -> Result<InvoiceChainLink, InvoiceError> {
+    const MAX_ATTEMPTS: usize = 3;
+
+    let line_items = self.line_item_repo.find_by_invoice_id(invoice.id).await?;
+    for _ in 0..MAX_ATTEMPTS {
+      let previous = self.chain_repo.find_latest(invoice.company_id).await?;
+      let link = InvoiceChainLink::seal(invoice, &line_items, previous.as_ref());
+
+      // Dropping the unit of work uncommitted discards the status update too
+      let mut unit_of_work = self.unit_of_work.begin().await?;
+      self
+        .invoice_repo
+        .update_status(unit_of_work.as_mut(), invoice)
+        .await?;
+      match self.chain_repo.append(unit_of_work.as_mut(), link).await {
+        Err(InvoiceError::ChainConflict) => continue,
+        Err(e) => return Err(e),
+        Ok(link) => {
+          unit_of_work.commit().await?;
+          return Ok(link);
+        }
+      }
+    }
+
+    Err(InvoiceError::ChainConflict)
+  }
+
+  /// Walk the company's invoice hash chain and report every broken link.
+  /// A nil user skips the membership check (system/CLI verification).
+  pub async fn verify_invoice_chain(
+    &self,
+    user_id: Uuid,
+    company_id: Uuid,
+  ) -> Result<InvoiceChainReport, InvoiceError> {
+    if !user_id.is_nil() {
+      self.verify_company_membership(user_id, company_id).await?;
+    }
+
+    let links = self.chain_repo.find_by_company_id(company_id).await?;
+    let mut broken_links = Vec::new();
+    let mut previous: Option<&InvoiceChainLink> = None;
+
+    for link in &links {
+      let invoice = self.invoice_repo.find_by_id(link.invoice_id).await?;
+      let mut report = |problem: ChainBreak| {
+        broken_links.push(BrokenChainLink {
+          sequence: link.sequence,
+          invoice_id: link.invoice_id,
+          invoice_number: invoice
+            .as_ref()
+            .map(|invoice| invoice.invoice_number.value().to_string()),
+          problem,
+        })
+      };
+
+      let expected = previous.map_or(1, |prev| prev.sequence + 1);
+      if link.sequence != expected {
+        report(ChainBreak::SequenceGap { expected });
+      }
+      if link.previous_hash.as_deref() != previous.map(|prev| prev.hash.as_str()) {
+        report(ChainBreak::PreviousHashMismatch);
+      }
+      match &invoice {
+        None => report(ChainBreak::InvoiceMissing),
+        Some(invoice) => {
+          let line_items = self.line_item_repo.find_by_invoice_id(invoice.id).await?;
+          if !link.matches(invoice, &line_items) {
+            report(ChainBreak::ContentMismatch);
+          }
+        }
+      }
+
+      previous = Some(link);
+    }
+
+    Ok(InvoiceChainReport {
+      company_id,
+      links_checked: links.len(),
+      broken_links,
+    })
   }
 
   pub async fn archive_invoice(&self, user_id: Uuid, invoice_id: Uuid) -> Result<(), InvoiceError> {
@@ -387,8 +559,17 @@ impl InvoiceService {
       .verify_company_membership(user_id, invoice.company_id)
       .await?;
 
+    let before = InvoiceSnapshot::capture(&invoice);
     invoice.archive();
-    self.invoice_repo.update(invoice).await?;
+    let updated = self.invoice_repo.update(invoice).await?;
+    self
+      .record_event(
+        &updated,
+        user_id,
+        InvoiceEventKind::Archived,
+        before.diff(&InvoiceSnapshot::capture(&updated)),
+      )
+      .await?;
     Ok(())
   }
 
@@ -412,8 +593,7 @@ impl InvoiceService {
     }
 
     // Delete the invoice (line items will be deleted by the repository via CASCADE)
-    self.invoice_repo.delete(invoice_id).await?;
-    Ok(())
+    self.delete_with_event(user_id, invoice).await
   }
 
   pub async fn get_invoice(
@@ -575,8 +755,17 @@ impl InvoiceService {
       ));
     }
 
+    let before = InvoiceSnapshot::capture(&invoice);
     invoice.unarchive();
-    self.invoice_repo.update(invoice).await?;
+    let updated = self.invoice_repo.update(invoice).await?;
+    self
+      .record_event(
+        &updated,
+        user_id,
+        InvoiceEventKind::Unarchived,
+        before.diff(&InvoiceSnapshot::capture(&updated)),
+      )
+      .await?;
     Ok(())
   }
 
@@ -601,15 +790,41 @@ impl InvoiceService {
       ));
     }
 
-    self.invoice_repo.delete(invoice_id).await?;
-    Ok(())
+    self.delete_with_event(user_id, invoice).await
+  }
+
+  /// Delete an invoice whose retention period has ended, on behalf of the
+  /// purge job rather than a member
+  pub async fn purge_invoice(&self, invoice: Invoice) -> Result<(), InvoiceError> {
+    // Nil user: deleted by the system
+    self.delete_with_event(Uuid::nil(), invoice).await
   }
 
+  /// Delete an invoice, recording its last state in the audit log first
+  async fn delete_with_event(&self, user_id: Uuid, invoice: Invoice) -> Result<(), InvoiceError> {
+    let line_items = self.line_item_repo.find_by_invoice_id(invoice.id).await?;
+    let before = InvoiceSnapshot::capture(&invoice).with_line_items(&line_items);
+    self
+      .record_event(
+        &invoice,
+        user_id,
+        InvoiceEventKind::Deleted,
+        before.diff(&InvoiceSnapshot::default()),
+      )
+      .await?;
+
+    self.invoice_repo.delete(invoice.id).await
+  }
+
+  /// Store a freshly generated PDF; `kind` tells the audit log whether it
+  /// was generated on send or re-uploaded
   pub async fn set_invoice_pdf_path(
     &self,
+    user_id: Uuid,
     invoice_id: Uuid,
     pdf_path: String,
     drive_file_id: Option<String>,
+    kind: InvoiceEventKind,
   ) -> Result<Invoice, InvoiceError> {
     let mut invoice = self
       .invoice_repo
@@ -617,11 +832,77 @@ impl InvoiceService {
       .await?
       .ok_or(InvoiceError::InvoiceNotFound(invoice_id))?;
 
+    let before = InvoiceSnapshot::capture(&invoice);
     invoice.set_pdf_path(pdf_path);
     invoice.pdf_drive_file_id = drive_file_id;
+    let updated = self.invoice_repo.update(invoice).await?;
+
+    // A re-upload may produce identical values, so always note the file
+    let mut changes = before.diff(&InvoiceSnapshot::capture(&updated));
+    if changes.is_empty() {
+      changes.push(InvoiceFieldChange {
+        field: "pdf_path".to_string(),
+        before: updated.pdf_path.clone(),
+        after: updated.pdf_path.clone(),
+      });
+    }
+    self.record_event(&updated, user_id, kind, changes).await?;
+
+    Ok(updated)
+  }
+
+  /// Remember where the invoice's PDF ended up once its queued upload
+  /// succeeded; the file itself was already recorded with its event
+  pub async fn set_pdf_drive_file_id(
+    &self,
+    invoice_id: Uuid,
+    drive_file_id: String,
+  ) -> Result<Invoice, InvoiceError> {
+    let mut invoice = self
+      .invoice_repo
+      .find_by_id(invoice_id)
+      .await?
+      .ok_or(InvoiceError::InvoiceNotFound(invoice_id))?;
+
+    invoice.pdf_drive_file_id = Some(drive_file_id);
     self.invoice_repo.update(invoice).await
   }
 
+  /// Audit log of an invoice, newest first. Works for deleted invoices too,
+  /// using the company recorded on the events for the membership check.
+  pub async fn list_invoice_events(
+    &self,
+    user_id: Uuid,
+    invoice_id: Uuid,
+  ) -> Result<Vec<InvoiceEvent>, InvoiceError> {
+    let events = self.event_repo.find_by_invoice_id(invoice_id).await?;
+
+    let company_id = match self.invoice_repo.find_by_id(invoice_id).await? {
+      Some(invoice) => invoice.company_id,
+      None => events
+        .first()
+        .map(|event| event.company_id)
+        .ok_or(InvoiceError::InvoiceNotFound(invoice_id))?,
+    };
+    self.verify_company_membership(user_id, company_id).await?;
+
+    Ok(events)
+  }
+
+  async fn record_event(
+    &self,
+    invoice: &Invoice,
+    user_id: Uuid,
+    kind: InvoiceEventKind,
+    changes: Vec<InvoiceFieldChange>,
+  ) -> Result<(), InvoiceError> {
+    self
+      .event_repo
+      .append(InvoiceEvent::new(invoice, user_id, kind, changes))
+      .await?;
+    Ok(())
+  }
+
   pub async fn mark_overdue_invoices(
     &self,
     company_id: Uuid,
@@ -635,8 +916,18 @@ impl InvoiceService {
     let mut updated_invoices = Vec::new();
     for mut invoice in overdue_invoices {
       if invoice.is_overdue(current_date) {
+        let before = InvoiceSnapshot::capture(&invoice);
         invoice.change_status(InvoiceStatus::Overdue)?;
         let updated = self.invoice_repo.update(invoice).await?;
+        // Nil user: the status change was made by the system, not a member
+        self
+          .record_event(
+            &updated,
+            Uuid::nil(),
+            InvoiceEventKind::StatusChanged,
+            before.diff(&InvoiceSnapshot::capture(&updated)),
+          )
+          .await?;
         updated_invoices.push(updated);
       }
     }
@@ -775,6 +1066,87 @@ impl InvoiceService {
   }
 
   // Helper methods
+  // Branding operations
+  pub async fn get_invoice_branding(
+    &self,
+    user_id: Uuid,
+    company_id: Uuid,
+  ) -> Result<InvoiceBranding, InvoiceError> {
+    // Skip membership check for system user (nil UUID), see get_invoice
+    if !user_id.is_nil() {
+      self.verify_company_membership(user_id, company_id).await?;
+    }
+
+    Ok(
+      self
+        .branding_repo
+        .find_by_company_id(company_id)
+        .await?
+        .unwrap_or_else(|| InvoiceBranding::new(company_id)),
+    )
+  }
+
+  pub async fn update_invoice_branding(
+    &self,
+    user_id: Uuid,
+    company_id: Uuid,
+    data: InvoiceBrandingData,
+  ) -> Result<InvoiceBranding, InvoiceError> {
+    let branding = self.apply_branding(user_id, company_id, data).await?;
+    self.branding_repo.save(branding).await
+  }
+
+  /// Apply branding data without saving, for rendering a preview
+  pub async fn preview_invoice_branding(
+    &self,
+    user_id: Uuid,
+    company_id: Uuid,
+    data: InvoiceBrandingData,
+  ) -> Result<(Company, InvoiceBranding), InvoiceError> {
+    let branding = self.apply_branding(user_id, company_id, data).await?;
+
+    let company = self
+      .company_repo
+      .find_by_id(company_id)
+      .await
+      .map_err(|e| InvoiceError::Internal(format!("Failed to fetch company: {}", e)))?
+      .ok_or_else(|| InvoiceError::Internal(format!("Company {} not found", company_id)))?;
+
+    Ok((company, branding))
+  }
+
+  async fn apply_branding(
+    &self,
+    user_id: Uuid,
+    company_id: Uuid,
+    data: InvoiceBrandingData,
+  ) -> Result<InvoiceBranding, InvoiceError> {
+    self.verify_company_membership(user_id, company_id).await?;
+
+    let mut branding = self
+      .branding_repo
+      .find_by_company_id(company_id)
+      .await?
+      .unwrap_or_else(|| InvoiceBranding::new(company_id));
+
+    branding.update(
+      data.accent_color,
+      data.font,
+      data.default_notes,
+      data.footer_text,
+      data.signature_text,
+      data.custom_template,
+    );
+
+    if data.logo_path.is_some() {
+      branding.set_logo_path(data.logo_path);
+    } else if data.remove_logo {
+      branding.set_logo_path(None);
+    }
+
+    Ok(branding)
+  }
+
   async fn verify_company_membership(
     &self,
     user_id: Uuid,
@@ -798,6 +1170,531 @@ impl InvoiceService {
 
 #[cfg(test)]
 mod tests {
-  // Tests would require mocking repositories, which is beyond scope of this implementation
-  // In production, consider using mockall crate for repository mocks
+  use super::*;
+  use crate::domain::company::entities::{CompanyMember, CompanyRole};
+  use crate::domain::company::errors::CompanyError;
+  use crate::domain::invoice::ports::InvoiceUnitOfWork;
+  use async_trait::async_trait;
+  use chrono::DateTime;
+  use rust_decimal::Decimal;
+  use rust_decimal_macros::dec;
+  use std::sync::Mutex;
+
+  /// Invoices, their line items and events, customers and members; what
+  /// editing an invoice reads and writes
+  #[derive(Default)]
+  struct InMemoryInvoices {
+    invoices: Mutex<Vec<Invoice>>,
+    line_items: Mutex<Vec<InvoiceLineItem>>,
+    customers: Mutex<Vec<Customer>>,
+    members: Mutex<Vec<CompanyMember>>,
+    events: Mutex<Vec<InvoiceEvent>>,
+  }
+
+  #[async_trait]
+  impl InvoiceRepository for InMemoryInvoices {
+    async fn create(&self, invoice: Invoice) -> Result<Invoice, InvoiceError> {
+      self.invoices.lock().unwrap().push(invoice.clone());
+      Ok(invoice)
+    }
+
+    async fn update(&self, invoice: Invoice) -> Result<Invoice, InvoiceError> {
+      let mut invoices = self.invoices.lock().unwrap();
+      if let Some(stored) = invoices.iter_mut().find(|i| i.id == invoice.id) {
+        *stored = invoice.clone();
+      }
+      Ok(invoice)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Invoice>, InvoiceError> {
+      let invoices = self.invoices.lock().unwrap();
+      Ok(invoices.iter().find(|i| i.id == id).cloned())
+    }
+
+    async fn find_by_company_id(&self, _company_id: Uuid) -> Result<Vec<Invoice>, InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn find_by_company_and_status(
+      &self,
+      _company_id: Uuid,
+      _status: InvoiceStatus,
+    ) -> Result<Vec<Invoice>, InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn find_by_company_and_customer(
+      &self,
+      _company_id: Uuid,
+      _customer_id: Uuid,
+    ) -> Result<Vec<Invoice>, InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn find_overdue(
+      &self,
+      _company_id: Uuid,
+      _current_date: NaiveDate,
+    ) -> Result<Vec<Invoice>, InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn find_archived_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<Invoice>, InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn delete(&self, _id: Uuid) -> Result<(), InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn update_status(
+      &self,
+      _unit_of_work: &mut dyn InvoiceUnitOfWork,
+      _invoice: &Invoice,
+    ) -> Result<(), InvoiceError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl InvoiceLineItemRepository for InMemoryInvoices {
+    async fn create(&self, line_item: InvoiceLineItem) -> Result<InvoiceLineItem, InvoiceError> {
+      self.line_items.lock().unwrap().push(line_item.clone());
+      Ok(line_item)
+    }
+
+    async fn create_many(
+      &self,
+      line_items: Vec<InvoiceLineItem>,
+    ) -> Result<Vec<InvoiceLineItem>, InvoiceError> {
+      self.line_items.lock().unwrap().extend(line_items.clone());
+      Ok(line_items)
+    }
+
+    async fn update(&self, _line_item: InvoiceLineItem) -> Result<InvoiceLineItem, InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn delete(&self, _id: Uuid) -> Result<(), InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn delete_by_invoice_id(&self, invoice_id: Uuid) -> Result<(), InvoiceError> {
+      let mut line_items = self.line_items.lock().unwrap();
+      line_items.retain(|item| item.invoice_id != invoice_id);
+      Ok(())
+    }
+
+    async fn find_by_id(&self, _id: Uuid) -> Result<Option<InvoiceLineItem>, InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn find_by_invoice_id(
+      &self,
+      invoice_id: Uuid,
+    ) -> Result<Vec<InvoiceLineItem>, InvoiceError> {
+      let line_items = self.line_items.lock().unwrap();
+      Ok(
+        line_items
+          .iter()
+          .filter(|item| item.invoice_id == invoice_id)
+          .cloned()
+          .collect(),
+      )
+    }
+  }
+
+  #[async_trait]
+  impl CustomerRepository for InMemoryInvoices {
+    async fn create(&self, customer: Customer) -> Result<Customer, InvoiceError> {
+      self.customers.lock().unwrap().push(customer.clone());
+      Ok(customer)
+    }
+
+    async fn update(&self, _customer: Customer) -> Result<Customer, InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Customer>, InvoiceError> {
+      let customers = self.customers.lock().unwrap();
+      Ok(customers.iter().find(|c| c.id == id).cloned())
+    }
+
+    async fn find_by_company_id(&self, _company_id: Uuid) -> Result<Vec<Customer>, InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn find_active_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<Customer>, InvoiceError> {
+      unimplemented!()
+    }
+
+    async fn exists_by_name(
+      &self,
+      _company_id: Uuid,
+      _name: &str,
+      _exclude_id: Option<Uuid>,
+    ) -> Result<bool, InvoiceError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl CompanyMemberRepository for InMemoryInvoices {
+    async fn add_member(&self, member: CompanyMember) -> Result<CompanyMember, CompanyError> {
+      self.members.lock().unwrap().push(member.clone());
+      Ok(member)
+    }
+
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<CompanyMember>, CompanyError> {
+      unimplemented!()
+    }
+
+    async fn find_by_user_id(&self, _user_id: Uuid) -> Result<Vec<CompanyMember>, CompanyError> {
+      unimplemented!()
+    }
+
+    async fn find_member(
+      &self,
+      company_id: Uuid,
+      user_id: Uuid,
+    ) -> Result<Option<CompanyMember>, CompanyError> {
+      let members = self.members.lock().unwrap();
+      Ok(
+        members
+          .iter()
+          .find(|m| m.company_id == company_id && m.user_id == user_id)
+          .cloned(),
+      )
+    }
+
+    async fn remove_member(&self, _company_id: Uuid, _user_id: Uuid) -> Result<(), CompanyError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl InvoiceEventRepository for InMemoryInvoices {
+    async fn append(&self, event: InvoiceEvent) -> Result<InvoiceEvent, InvoiceError> {
+      self.events.lock().unwrap().push(event.clone());
+      Ok(event)
+    }
+
+    async fn find_by_invoice_id(
+      &self,
+      _invoice_id: Uuid,
+    ) -> Result<Vec<InvoiceEvent>, InvoiceError> {
+      unimplemented!()
+    }
+  }
+
+  /// Stands in for the repositories editing an invoice doesn't touch
+  struct Unused;
+
+  #[async_trait]
+  impl CompanyRepository for Unused {
+    async fn create(&self, _company: Company) -> Result<Company, CompanyError> {
+      unimplemented!()
+    }
+    async fn find_by_id(&self, _id: Uuid) -> Result<Option<Company>, CompanyError> {
+      unimplemented!()
+    }
+    async fn find_all(&self) -> Result<Vec<Company>, CompanyError> {
+      unimplemented!()
+    }
+    async fn update(&self, _company: Company) -> Result<Company, CompanyError> {
+      unimplemented!()
+    }
+    async fn delete(&self, _id: Uuid) -> Result<(), CompanyError> {
+      unimplemented!()
+    }
+    async fn update_oauth_tokens(
+      &self,
+      _company_id: &Uuid,
+      _encrypted_access_token: String,
+      _encrypted_refresh_token: String,
+      _expires_at: DateTime<Utc>,
+      _connected_by: Uuid,
+    ) -> Result<(), CompanyError> {
+      unimplemented!()
+    }
+    async fn clear_oauth_tokens(&self, _company_id: &Uuid) -> Result<(), CompanyError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl BankAccountRepository for Unused {
+    async fn create(&self, _account: BankAccount) -> Result<BankAccount, CompanyError> {
+      unimplemented!()
+    }
+    async fn find_by_id(&self, _id: Uuid) -> Result<Option<BankAccount>, CompanyError> {
+      unimplemented!()
+    }
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+      _include_archived: bool,
+    ) -> Result<Vec<BankAccount>, CompanyError> {
+      unimplemented!()
+    }
+    async fn find_by_iban(
+      &self,
+      _company_id: Uuid,
+      _iban: &str,
+    ) -> Result<Option<BankAccount>, CompanyError> {
+      unimplemented!()
+    }
+    async fn update(&self, _account: BankAccount) -> Result<BankAccount, CompanyError> {
+      unimplemented!()
+    }
+    async fn archive(&self, _id: Uuid) -> Result<(), CompanyError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl InvoiceTemplateRepository for Unused {
+    async fn create(&self, _template: InvoiceTemplate) -> Result<InvoiceTemplate, InvoiceError> {
+      unimplemented!()
+    }
+    async fn update(&self, _template: InvoiceTemplate) -> Result<InvoiceTemplate, InvoiceError> {
+      unimplemented!()
+    }
+    async fn find_by_id(&self, _id: Uuid) -> Result<Option<InvoiceTemplate>, InvoiceError> {
+      unimplemented!()
+    }
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<InvoiceTemplate>, InvoiceError> {
+      unimplemented!()
+    }
+    async fn find_active_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<InvoiceTemplate>, InvoiceError> {
+      unimplemented!()
+    }
+    async fn exists_by_name(
+      &self,
+      _company_id: Uuid,
+      _name: &str,
+      _exclude_id: Option<Uuid>,
+    ) -> Result<bool, InvoiceError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl InvoiceTemplateLineItemRepository for Unused {
+    async fn create_many(
+      &self,
+      _items: Vec<InvoiceTemplateLineItem>,
+    ) -> Result<Vec<InvoiceTemplateLineItem>, InvoiceError> {
+      unimplemented!()
+    }
+    async fn find_by_template_id(
+      &self,
+      _template_id: Uuid,
+    ) -> Result<Vec<InvoiceTemplateLineItem>, InvoiceError> {
+      unimplemented!()
+    }
+    async fn delete_by_template_id(&self, _template_id: Uuid) -> Result<(), InvoiceError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl InvoiceBrandingRepository for Unused {
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Option<InvoiceBranding>, InvoiceError> {
+      unimplemented!()
+    }
+    async fn save(&self, _branding: InvoiceBranding) -> Result<InvoiceBranding, InvoiceError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl InvoiceChainRepository for Unused {
+    async fn append(
+      &self,
+      _unit_of_work: &mut dyn InvoiceUnitOfWork,
+      _link: InvoiceChainLink,
+    ) -> Result<InvoiceChainLink, InvoiceError> {
+      unimplemented!()
+    }
+    async fn find_latest(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Option<InvoiceChainLink>, InvoiceError> {
+      unimplemented!()
+    }
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<InvoiceChainLink>, InvoiceError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl InvoiceUnitOfWorkFactory for Unused {
+    async fn begin(&self) -> Result<Box<dyn InvoiceUnitOfWork>, InvoiceError> {
+      unimplemented!()
+    }
+  }
+
+  fn service(store: &Arc<InMemoryInvoices>) -> InvoiceService {
+    let unused = Arc::new(Unused);
+    InvoiceService::new(InvoiceServiceDependencies {
+      invoice_repo: store.clone(),
+      line_item_repo: store.clone(),
+      customer_repo: store.clone(),
+      company_member_repo: store.clone(),
+      company_repo: unused.clone(),
+      bank_account_repo: unused.clone(),
+      template_repo: unused.clone(),
+      template_line_item_repo: unused.clone(),
+      branding_repo: unused.clone(),
+      event_repo: store.clone(),
+      chain_repo: unused.clone(),
+      unit_of_work: unused,
+    })
+  }
+
+  fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+  }
+
+  fn line(
+    description: &str,
+    quantity: Decimal,
+    unit_price: Decimal,
+    vat_rate: Decimal,
+  ) -> (LineItemDescription, Quantity, Money, VatRate) {
+    (
+      LineItemDescription::new(description.to_string()).unwrap(),
+      Quantity::new(quantity).unwrap(),
+      Money::new(unit_price, Currency::EUR).unwrap(),
+      VatRate::new(vat_rate).unwrap(),
+    )
+  }
+
+  /// A member's company with a customer and a draft invoice of one line;
+  /// returns the member, customer and invoice
+  fn draft_invoice(store: &InMemoryInvoices) -> (Uuid, Customer, Invoice) {
+    let company_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let customer = Customer::new(
+      company_id,
+      CustomerName::new("Acme".to_string()).unwrap(),
+      None,
+    );
+    let invoice = Invoice::new(
+      company_id,
+      customer.id,
+      None,
+      InvoiceNumber::new("INV-001".to_string()).unwrap(),
+      date(1),
+      PaymentTerms::Net30,
+      Currency::EUR,
+    );
+    let (description, quantity, unit_price, vat_rate) =
+      line("Consulting", dec!(1), dec!(100), dec!(22));
+    let item = InvoiceLineItem::new(invoice.id, description, quantity, unit_price, vat_rate, 1);
+
+    store.members.lock().unwrap().push(CompanyMember::new(
+      company_id,
+      user_id,
+      CompanyRole::Member,
+    ));
+    store.customers.lock().unwrap().push(customer.clone());
+    store.invoices.lock().unwrap().push(invoice.clone());
+    store.line_items.lock().unwrap().push(item);
+    (user_id, customer, invoice)
+  }
+
+  fn update_data(customer_id: Uuid) -> InvoiceUpdateData {
+    InvoiceUpdateData {
+      customer_id,
+      bank_account_id: None,
+      invoice_date: date(5),
+      payment_terms: PaymentTerms::Net15,
+      language: None,
+      line_items: vec![
+        line("Design", dec!(2), dec!(50), dec!(22)),
+        line("Hosting", dec!(3), dec!(10), dec!(0)),
+      ],
+    }
+  }
+
+  #[tokio::test]
+  async fn test_update_replaces_line_items_and_recomputes_totals() {
+    let store = Arc::new(InMemoryInvoices::default());
+    let (user_id, customer, invoice) = draft_invoice(&store);
+
+    let (updated, line_items) = service(&store)
+      .update_invoice(user_id, invoice.id, update_data(customer.id))
+      .await
+      .unwrap();
+
+    assert_eq!(updated.invoice_date, date(5));
+    assert_eq!(updated.due_date, date(20));
+    let stored = store.line_items.lock().unwrap().clone();
+    assert_eq!(stored.len(), 2);
+    assert_eq!(
+      stored
+        .iter()
+        .map(|item| (item.description.value().to_string(), item.line_order))
+        .collect::<Vec<_>>(),
+      [("Design".to_string(), 1), ("Hosting".to_string(), 2)]
+    );
+
+    let totals = InvoiceTotals::calculate(&line_items, Currency::EUR);
+    assert_eq!(totals.subtotal.amount, dec!(130));
+    assert_eq!(totals.total_vat.amount, dec!(22));
+    assert_eq!(totals.grand_total.amount, dec!(152));
+    assert_eq!(store.events.lock().unwrap().len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_update_refuses_invoices_that_are_not_drafts() {
+    let store = Arc::new(InMemoryInvoices::default());
+    let (user_id, customer, invoice) = draft_invoice(&store);
+    store.invoices.lock().unwrap()[0].status = InvoiceStatus::Sent;
+
+    let result = service(&store)
+      .update_invoice(user_id, invoice.id, update_data(customer.id))
+      .await;
+
+    assert!(matches!(result, Err(InvoiceError::CannotEditInvoice(_))));
+    assert_eq!(store.line_items.lock().unwrap().len(), 1);
+    assert!(store.events.lock().unwrap().is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_update_rejects_non_members() {
+    let store = Arc::new(InMemoryInvoices::default());
+    let (_, customer, invoice) = draft_invoice(&store);
+
+    let result = service(&store)
+      .update_invoice(Uuid::new_v4(), invoice.id, update_data(customer.id))
+      .await;
+
+    assert!(matches!(result, Err(InvoiceError::PermissionDenied(_))));
+    assert_eq!(store.line_items.lock().unwrap().len(), 1);
+    assert_eq!(store.invoices.lock().unwrap()[0].invoice_date, date(1));
+  }
 }
@@ -23,6 +23,18 @@ pub enum ValueObjectError {
   InvalidPaymentTerms(String),
   #[error("Invalid template name: {0}")]
   InvalidTemplateName(String),
+  #[error("Invalid accent color: {0}")]
+  InvalidAccentColor(String),
+  #[error("Invalid font: {0}")]
+  InvalidFont(String),
+  #[error("Invalid branding text: {0}")]
+  InvalidBrandingText(String),
+  #[error("Invalid invoice template: {0}")]
+  InvalidInvoiceTemplate(String),
+  #[error("Invalid invoice language: {0}")]
+  InvalidLanguage(String),
+  #[error("Invalid invoice event kind: {0}")]
+  InvalidEventKind(String),
 }
 
 // Invoice Number - User-editable text field
@@ -545,6 +557,224 @@ impl CustomerAddress {
   }
 }
 
+// Accent Color - Hex color used for invoice headings and borders
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccentColor(String);
+
+impl AccentColor {
+  pub fn new(value: String) -> Result<Self, ValueObjectError> {
+    let trimmed = value.trim();
+    let hex = trimmed.strip_prefix('#').ok_or_else(|| {
+      ValueObjectError::InvalidAccentColor("Accent color must start with #".to_string())
+    })?;
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+      return Err(ValueObjectError::InvalidAccentColor(
+        "Accent color must be a 6-digit hex value like #1f2937".to_string(),
+      ));
+    }
+    Ok(Self(format!("#{}", hex.to_lowercase())))
+  }
+
+  pub fn value(&self) -> &str {
+    &self.0
+  }
+}
+
+impl Default for AccentColor {
+  fn default() -> Self {
+    Self("#000000".to_string())
+  }
+}
+
+// Invoice Font - Fonts available to wkhtmltopdf on the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceFont {
+  #[default]
+  Arial,
+  Helvetica,
+  Verdana,
+  Georgia,
+  TimesNewRoman,
+  CourierNew,
+}
+
+impl InvoiceFont {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      InvoiceFont::Arial => "arial",
+      InvoiceFont::Helvetica => "helvetica",
+      InvoiceFont::Verdana => "verdana",
+      InvoiceFont::Georgia => "georgia",
+      InvoiceFont::TimesNewRoman => "times_new_roman",
+      InvoiceFont::CourierNew => "courier_new",
+    }
+  }
+
+  /// CSS font-family stack used when rendering the invoice
+  pub fn css_family(&self) -> &'static str {
+    match self {
+      InvoiceFont::Arial => "Arial, sans-serif",
+      InvoiceFont::Helvetica => "Helvetica, Arial, sans-serif",
+      InvoiceFont::Verdana => "Verdana, sans-serif",
+      InvoiceFont::Georgia => "Georgia, serif",
+      InvoiceFont::TimesNewRoman => "'Times New Roman', Times, serif",
+      InvoiceFont::CourierNew => "'Courier New', Courier, monospace",
+    }
+  }
+
+  pub fn all() -> &'static [InvoiceFont] {
+    &[
+      InvoiceFont::Arial,
+      InvoiceFont::Helvetica,
+      InvoiceFont::Verdana,
+      InvoiceFont::Georgia,
+      InvoiceFont::TimesNewRoman,
+      InvoiceFont::CourierNew,
+    ]
+  }
+}
+
+impl FromStr for InvoiceFont {
+  type Err = ValueObjectError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    InvoiceFont::all()
+      .iter()
+      .find(|font| font.as_str() == s)
+      .copied()
+      .ok_or_else(|| ValueObjectError::InvalidFont(s.to_string()))
+  }
+}
+
+// Branding Text - Notes, footer and signature printed on invoices
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrandingText(String);
+
+impl BrandingText {
+  const MAX_LENGTH: usize = 2000;
+
+  /// Returns None for blank input so optional form fields can be cleared
+  pub fn new(value: String) -> Result<Option<Self>, ValueObjectError> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+      return Ok(None);
+    }
+    if trimmed.chars().count() > Self::MAX_LENGTH {
+      return Err(ValueObjectError::InvalidBrandingText(format!(
+        "Text cannot exceed {} characters",
+        Self::MAX_LENGTH
+      )));
+    }
+    Ok(Some(Self(trimmed.to_string())))
+  }
+
+  pub fn value(&self) -> &str {
+    &self.0
+  }
+}
+
+// Custom Invoice Template - Tera source overriding the global invoice layout
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomInvoiceTemplate(String);
+
+impl CustomInvoiceTemplate {
+  const MAX_LENGTH: usize = 100_000;
+
+  /// Returns None for blank input so the global template is used
+  pub fn new(value: String) -> Result<Option<Self>, ValueObjectError> {
+    if value.trim().is_empty() {
+      return Ok(None);
+    }
+    if value.len() > Self::MAX_LENGTH {
+      return Err(ValueObjectError::InvalidInvoiceTemplate(format!(
+        "Template cannot exceed {} bytes",
+        Self::MAX_LENGTH
+      )));
+    }
+    Ok(Some(Self(value)))
+  }
+
+  pub fn value(&self) -> &str {
+    &self.0
+  }
+}
+
+// Invoice Event Kind - What happened to an invoice in its audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum InvoiceEventKind {
+  Created,
+  Updated,
+  StatusChanged,
+  PdfGenerated,
+  PdfReuploaded,
+  Archived,
+  Unarchived,
+  Deleted,
+}
+
+impl InvoiceEventKind {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      InvoiceEventKind::Created => "created",
+      InvoiceEventKind::Updated => "updated",
+      InvoiceEventKind::StatusChanged => "status_changed",
+      InvoiceEventKind::PdfGenerated => "pdf_generated",
+      InvoiceEventKind::PdfReuploaded => "pdf_reuploaded",
+      InvoiceEventKind::Archived => "archived",
+      InvoiceEventKind::Unarchived => "unarchived",
+      InvoiceEventKind::Deleted => "deleted",
+    }
+  }
+
+  /// Human-readable label for the activity timeline
+  pub fn label(&self) -> &'static str {
+    match self {
+      InvoiceEventKind::Created => "Invoice created",
+      InvoiceEventKind::Updated => "Draft edited",
+      InvoiceEventKind::StatusChanged => "Status changed",
+      InvoiceEventKind::PdfGenerated => "PDF generated",
+      InvoiceEventKind::PdfReuploaded => "PDF re-uploaded",
+      InvoiceEventKind::Archived => "Archived",
+      InvoiceEventKind::Unarchived => "Restored from archive",
+      InvoiceEventKind::Deleted => "Deleted",
+    }
+  }
+
+  pub fn all() -> &'static [InvoiceEventKind] {
+    &[
+      InvoiceEventKind::Created,
+      InvoiceEventKind::Updated,
+      InvoiceEventKind::StatusChanged,
+      InvoiceEventKind::PdfGenerated,
+      InvoiceEventKind::PdfReuploaded,
+      InvoiceEventKind::Archived,
+      InvoiceEventKind::Unarchived,
+      InvoiceEventKind::Deleted,
+    ]
+  }
+}
+
+impl fmt::Display for InvoiceEventKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
+
+impl FromStr for InvoiceEventKind {
+  type Err = ValueObjectError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    InvoiceEventKind::all()
+      .iter()
+      .find(|kind| kind.as_str() == s)
+      .copied()
+      .ok_or_else(|| ValueObjectError::InvalidEventKind(s.to_string()))
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -647,4 +877,64 @@ mod tests {
     assert!(formatted.contains("Copenhagen"));
     assert!(formatted.contains("Denmark"));
   }
+
+  #[test]
+  fn test_accent_color() {
+    assert_eq!(
+      AccentColor::new("#1F2937".to_string()).unwrap().value(),
+      "#1f2937"
+    );
+    assert!(AccentColor::new("1f2937".to_string()).is_err());
+    assert!(AccentColor::new("#fff".to_string()).is_err());
+    assert!(AccentColor::new("#zzzzzz".to_string()).is_err());
+    assert_eq!(AccentColor::default().value(), "#000000");
+  }
+
+  #[test]
+  fn test_invoice_font() {
+    assert_eq!(
+      InvoiceFont::from_str("times_new_roman").unwrap(),
+      InvoiceFont::TimesNewRoman
+    );
+    assert!(InvoiceFont::from_str("comic_sans").is_err());
+    for font in InvoiceFont::all() {
+      assert_eq!(InvoiceFont::from_str(font.as_str()).unwrap(), *font);
+    }
+  }
+
+  #[test]
+  fn test_branding_text() {
+    assert!(BrandingText::new("   ".to_string()).unwrap().is_none());
+    assert_eq!(
+      BrandingText::new(" Thank you! ".to_string())
+        .unwrap()
+        .unwrap()
+        .value(),
+      "Thank you!"
+    );
+    assert!(BrandingText::new("x".repeat(2001)).is_err());
+  }
+
+  #[test]
+  fn test_custom_invoice_template() {
+    assert!(
+      CustomInvoiceTemplate::new("".to_string())
+        .unwrap()
+        .is_none()
+    );
+    assert!(
+      CustomInvoiceTemplate::new("<h1>{{ invoice.invoice_number }}</h1>".to_string())
+        .unwrap()
+        .is_some()
+    );
+    assert!(CustomInvoiceTemplate::new("x".repeat(100_001)).is_err());
+  }
+
+  #[test]
+  fn test_invoice_event_kind_round_trip() {
+    for kind in InvoiceEventKind::all() {
+      assert_eq!(InvoiceEventKind::from_str(kind.as_str()).unwrap(), *kind);
+    }
+    assert!(InvoiceEventKind::from_str("paid").is_err());
+  }
 }
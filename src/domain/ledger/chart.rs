@@ -0,0 +1,132 @@
+use uuid::Uuid;
+
+use super::entities::Account;
+use super::value_objects::AccountType;
+
+/// Default chart of accounts seeded for every company. Codes and grouping
+/// follow the Estonian standard chart of accounts for small companies:
+/// 1xxx assets, 20xx-28xx liabilities, 29xx equity, 3xxx revenue and
+/// 4xxx-5xxx expenses and financial items.
+pub const STANDARD_CHART: &[(&str, &str, AccountType)] = &[
+  ("1000", "Cash on hand", AccountType::Asset),
+  ("1020", "Bank accounts", AccountType::Asset),
+  (
+    "1100",
+    "Short-term financial investments",
+    AccountType::Asset,
+  ),
+  ("1200", "Accounts receivable", AccountType::Asset),
+  ("1210", "Doubtful receivables", AccountType::Asset),
+  ("1300", "Other receivables", AccountType::Asset),
+  ("1400", "Prepaid taxes", AccountType::Asset),
+  ("1410", "Input VAT", AccountType::Asset),
+  ("1500", "Prepayments to suppliers", AccountType::Asset),
+  ("1600", "Inventories", AccountType::Asset),
+  (
+    "1700",
+    "Long-term financial investments",
+    AccountType::Asset,
+  ),
+  ("1800", "Property, plant and equipment", AccountType::Asset),
+  ("1890", "Accumulated depreciation", AccountType::Asset),
+  ("1900", "Intangible assets", AccountType::Asset),
+  ("2000", "Short-term loans", AccountType::Liability),
+  ("2100", "Accounts payable", AccountType::Liability),
+  ("2200", "Payables to employees", AccountType::Liability),
+  ("2300", "Tax liabilities", AccountType::Liability),
+  ("2310", "VAT payable", AccountType::Liability),
+  ("2400", "Other payables", AccountType::Liability),
+  ("2500", "Prepayments from customers", AccountType::Liability),
+  ("2600", "Short-term provisions", AccountType::Liability),
+  ("2700", "Long-term loans", AccountType::Liability),
+  ("2900", "Share capital", AccountType::Equity),
+  ("2910", "Share premium", AccountType::Equity),
+  ("2920", "Statutory reserve capital", AccountType::Equity),
+  ("2930", "Retained earnings", AccountType::Equity),
+  ("2940", "Profit for the financial year", AccountType::Equity),
+  ("3000", "Sales revenue", AccountType::Revenue),
+  ("3100", "Other operating income", AccountType::Revenue),
+  (
+    "4000",
+    "Goods, materials and services",
+    AccountType::Expense,
+  ),
+  (
+    "4100",
+    "Miscellaneous operating expenses",
+    AccountType::Expense,
+  ),
+  ("4200", "Labour expenses", AccountType::Expense),
+  ("4300", "Depreciation", AccountType::Expense),
+  ("4400", "Other operating expenses", AccountType::Expense),
+  ("5000", "Financial income", AccountType::Revenue),
+  ("5100", "Financial expenses", AccountType::Expense),
+  ("5200", "Income tax", AccountType::Expense),
+];
+
+/// Accounts the automatic postings rely on; all of them are part of
+/// [`STANDARD_CHART`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SystemAccount {
+  Bank,
+  Receivables,
+  InputVat,
+  Payables,
+  VatPayable,
+  SalesRevenue,
+  PurchasedServices,
+}
+
+impl SystemAccount {
+  pub fn code(&self) -> &'static str {
+    match self {
+      SystemAccount::Bank => "1020",
+      SystemAccount::Receivables => "1200",
+      SystemAccount::InputVat => "1410",
+      SystemAccount::Payables => "2100",
+      SystemAccount::VatPayable => "2310",
+      SystemAccount::SalesRevenue => "3000",
+      SystemAccount::PurchasedServices => "4000",
+    }
+  }
+}
+
+/// Fresh accounts for a company, one per [`STANDARD_CHART`] row
+pub fn standard_chart_for(company_id: Uuid) -> Vec<Account> {
+  STANDARD_CHART
+    .iter()
+    .map(|(code, name, account_type)| {
+      Account::new(
+        company_id,
+        code.to_string(),
+        name.to_string(),
+        *account_type,
+      )
+      .expect("standard chart accounts are valid")
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashSet;
+
+  #[test]
+  fn test_standard_chart_codes_unique_and_cover_system_accounts() {
+    let codes: HashSet<&str> = STANDARD_CHART.iter().map(|(code, _, _)| *code).collect();
+    assert_eq!(codes.len(), STANDARD_CHART.len());
+
+    for account in [
+      SystemAccount::Bank,
+      SystemAccount::Receivables,
+      SystemAccount::InputVat,
+      SystemAccount::Payables,
+      SystemAccount::VatPayable,
+      SystemAccount::SalesRevenue,
+      SystemAccount::PurchasedServices,
+    ] {
+      assert!(codes.contains(account.code()), "{:?} missing", account);
+    }
+  }
+}
@@ -0,0 +1,393 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use super::errors::LedgerError;
+use super::value_objects::{AccountType, JournalSource};
+
+/// Account in a company's chart of accounts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Account {
+  pub id: Uuid,
+  pub company_id: Uuid,
+  pub code: String,
+  pub name: String,
+  pub account_type: AccountType,
+  pub created_at: DateTime<Utc>,
+}
+
+impl Account {
+  pub fn new(
+    company_id: Uuid,
+    code: String,
+    name: String,
+    account_type: AccountType,
+  ) -> Result<Self, LedgerError> {
+    let code = code.trim().to_string();
+    if !(3..=8).contains(&code.len()) || !code.chars().all(|c| c.is_ascii_digit()) {
+      return Err(LedgerError::Validation(
+        "Account code must be 3-8 digits".to_string(),
+      ));
+    }
+
+    let name = name.trim().to_string();
+    if name.is_empty() {
+      return Err(LedgerError::Validation(
+        "Account name is required".to_string(),
+      ));
+    }
+
+    Ok(Self {
+      id: Uuid::new_v4(),
+      company_id,
+      code,
+      name,
+      account_type,
+      created_at: Utc::now(),
+    })
+  }
+}
+
+/// One debit or credit line of a journal entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalLine {
+  pub id: Uuid,
+  pub entry_id: Uuid,
+  pub account_id: Uuid,
+  pub debit: Decimal,
+  pub credit: Decimal,
+  pub description: Option<String>,
+  pub line_order: i32,
+}
+
+/// Line of a journal entry that has not been posted yet
+#[derive(Debug, Clone)]
+pub struct NewJournalLine {
+  pub account_id: Uuid,
+  pub debit: Decimal,
+  pub credit: Decimal,
+  pub description: Option<String>,
+}
+
+impl NewJournalLine {
+  pub fn debit(account_id: Uuid, amount: Decimal) -> Self {
+    Self {
+      account_id,
+      debit: amount,
+      credit: Decimal::ZERO,
+      description: None,
+    }
+  }
+
+  pub fn credit(account_id: Uuid, amount: Decimal) -> Self {
+    Self {
+      account_id,
+      debit: Decimal::ZERO,
+      credit: amount,
+      description: None,
+    }
+  }
+}
+
+/// Balanced double-entry posting. Entries are never edited or deleted;
+/// mistakes are corrected with a reversing entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+  pub id: Uuid,
+  pub company_id: Uuid,
+  pub entry_date: NaiveDate,
+  pub description: String,
+  pub source: JournalSource,
+  /// Document the entry was posted for; None for manual entries
+  pub source_id: Option<Uuid>,
+  /// Entry this one reverses
+  pub reversal_of: Option<Uuid>,
+  /// None for automatic postings
+  pub created_by: Option<Uuid>,
+  pub created_at: DateTime<Utc>,
+  pub lines: Vec<JournalLine>,
+}
+
+impl JournalEntry {
+  /// Validate and build an entry. A nil `created_by` marks a system posting.
+  pub fn new(
+    company_id: Uuid,
+    entry_date: NaiveDate,
+    description: String,
+    source: JournalSource,
+    source_id: Option<Uuid>,
+    created_by: Option<Uuid>,
+    lines: Vec<NewJournalLine>,
+  ) -> Result<Self, LedgerError> {
+    let description = description.trim().to_string();
+    if description.is_empty() {
+      return Err(LedgerError::Validation(
+        "Entry description is required".to_string(),
+      ));
+    }
+
+    if lines.len() < 2 {
+      return Err(LedgerError::Validation(
+        "A journal entry needs at least two lines".to_string(),
+      ));
+    }
+
+    let mut debit = Decimal::ZERO;
+    let mut credit = Decimal::ZERO;
+    for (index, line) in lines.iter().enumerate() {
+      if line.debit.is_sign_negative() || line.credit.is_sign_negative() {
+        return Err(LedgerError::Validation(format!(
+          "Line {}: amounts cannot be negative",
+          index + 1
+        )));
+      }
+      if line.debit.is_zero() == line.credit.is_zero() {
+        return Err(LedgerError::Validation(format!(
+          "Line {}: enter either a debit or a credit amount",
+          index + 1
+        )));
+      }
+      if line.debit.scale() > 2 || line.credit.scale() > 2 {
+        return Err(LedgerError::Validation(format!(
+          "Line {}: amounts can have at most two decimals",
+          index + 1
+        )));
+      }
+      debit += line.debit;
+      credit += line.credit;
+    }
+
+    if debit != credit {
+      return Err(LedgerError::Unbalanced { debit, credit });
+    }
+
+    let id = Uuid::new_v4();
+    let lines = lines
+      .into_iter()
+      .enumerate()
+      .map(|(index, line)| JournalLine {
+        id: Uuid::new_v4(),
+        entry_id: id,
+        account_id: line.account_id,
+        debit: line.debit,
+        credit: line.credit,
+        description: line
+          .description
+          .map(|d| d.trim().to_string())
+          .filter(|d| !d.is_empty()),
+        line_order: index as i32,
+      })
+      .collect();
+
+    Ok(Self {
+      id,
+      company_id,
+      entry_date,
+      description,
+      source,
+      source_id,
+      reversal_of: None,
+      created_by: created_by.filter(|user_id| !user_id.is_nil()),
+      created_at: Utc::now(),
+      lines,
+    })
+  }
+
+  /// Entry that cancels this one out, posted on `entry_date`
+  pub fn reversal(&self, entry_date: NaiveDate, created_by: Option<Uuid>) -> Self {
+    let id = Uuid::new_v4();
+    Self {
+      id,
+      company_id: self.company_id,
+      entry_date,
+      description: format!("Reversal: {}", self.description),
+      source: self.source,
+      source_id: self.source_id,
+      reversal_of: Some(self.id),
+      created_by: created_by.filter(|user_id| !user_id.is_nil()),
+      created_at: Utc::now(),
+      lines: self
+        .lines
+        .iter()
+        .map(|line| JournalLine {
+          id: Uuid::new_v4(),
+          entry_id: id,
+          account_id: line.account_id,
+          debit: line.credit,
+          credit: line.debit,
+          description: line.description.clone(),
+          line_order: line.line_order,
+        })
+        .collect(),
+    }
+  }
+
+  pub fn total(&self) -> Decimal {
+    self.lines.iter().map(|line| line.debit).sum()
+  }
+}
+
+/// Entries that are neither reversals nor reversed by another entry in `entries`
+pub fn unreversed_entries(entries: &[JournalEntry]) -> Vec<&JournalEntry> {
+  let reversed: HashSet<Uuid> = entries.iter().filter_map(|e| e.reversal_of).collect();
+  entries
+    .iter()
+    .filter(|e| e.reversal_of.is_none() && !reversed.contains(&e.id))
+    .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TrialBalanceRow {
+  pub account_id: Uuid,
+  pub code: String,
+  pub name: String,
+  pub account_type: AccountType,
+  pub total_debit: Decimal,
+  pub total_credit: Decimal,
+  /// Net balance on the debit side (zero if the account has a credit balance)
+  pub debit_balance: Decimal,
+  /// Net balance on the credit side (zero if the account has a debit balance)
+  pub credit_balance: Decimal,
+}
+
+/// Per-account totals of all postings up to a date
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TrialBalance {
+  pub as_of: Option<NaiveDate>,
+  pub rows: Vec<TrialBalanceRow>,
+  pub total_debit: Decimal,
+  pub total_credit: Decimal,
+}
+
+impl TrialBalance {
+  /// Sum `entries` per account; accounts without postings are left out
+  pub fn compute(accounts: &[Account], entries: &[JournalEntry], as_of: Option<NaiveDate>) -> Self {
+    let mut totals: HashMap<Uuid, (Decimal, Decimal)> = HashMap::new();
+    for line in entries
+      .iter()
+      .filter(|entry| as_of.map_or(true, |date| entry.entry_date <= date))
+      .flat_map(|entry| entry.lines.iter())
+    {
+      let total = totals
+        .entry(line.account_id)
+        .or_insert((Decimal::ZERO, Decimal::ZERO));
+      total.0 += line.debit;
+      total.1 += line.credit;
+    }
+
+    let mut rows: Vec<TrialBalanceRow> = accounts
+      .iter()
+      .filter_map(|account| {
+        let (total_debit, total_credit) = *totals.get(&account.id)?;
+        let net = total_debit - total_credit;
+        Some(TrialBalanceRow {
+          account_id: account.id,
+          code: account.code.clone(),
+          name: account.name.clone(),
+          account_type: account.account_type,
+          total_debit,
+          total_credit,
+          debit_balance: net.max(Decimal::ZERO),
+          credit_balance: (-net).max(Decimal::ZERO),
+        })
+      })
+      .collect();
+    rows.sort_by(|a, b| a.code.cmp(&b.code));
+
+    Self {
+      as_of,
+      total_debit: rows.iter().map(|row| row.debit_balance).sum(),
+      total_credit: rows.iter().map(|row| row.credit_balance).sum(),
+      rows,
+    }
+  }
+
+  pub fn is_balanced(&self) -> bool {
+    self.total_debit == self.total_credit
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rust_decimal_macros::dec;
+
+  fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, 4, day).unwrap()
+  }
+
+  fn account(company_id: Uuid, code: &str, account_type: AccountType) -> Account {
+    Account::new(company_id, code.to_string(), code.to_string(), account_type).unwrap()
+  }
+
+  #[test]
+  fn test_journal_entry_rejects_unbalanced_lines() {
+    let result = JournalEntry::new(
+      Uuid::new_v4(),
+      date(1),
+      "Office rent".to_string(),
+      JournalSource::Manual,
+      None,
+      None,
+      vec![
+        NewJournalLine::debit(Uuid::new_v4(), dec!(100.00)),
+        NewJournalLine::credit(Uuid::new_v4(), dec!(90.00)),
+      ],
+    );
+    assert!(matches!(result, Err(LedgerError::Unbalanced { .. })));
+
+    let result = JournalEntry::new(
+      Uuid::new_v4(),
+      date(1),
+      "Office rent".to_string(),
+      JournalSource::Manual,
+      None,
+      None,
+      vec![NewJournalLine {
+        account_id: Uuid::new_v4(),
+        debit: dec!(10),
+        credit: dec!(10),
+        description: None,
+      }],
+    );
+    assert!(matches!(result, Err(LedgerError::Validation(_))));
+  }
+
+  #[test]
+  fn test_reversal_swaps_sides_and_cancels_out() {
+    let company_id = Uuid::new_v4();
+    let bank = account(company_id, "1020", AccountType::Asset);
+    let revenue = account(company_id, "3000", AccountType::Revenue);
+    let entry = JournalEntry::new(
+      company_id,
+      date(1),
+      "Cash sale".to_string(),
+      JournalSource::Manual,
+      None,
+      Some(Uuid::nil()),
+      vec![
+        NewJournalLine::debit(bank.id, dec!(50.00)),
+        NewJournalLine::credit(revenue.id, dec!(50.00)),
+      ],
+    )
+    .unwrap();
+    assert_eq!(entry.created_by, None);
+
+    let reversal = entry.reversal(date(2), None);
+    assert_eq!(reversal.reversal_of, Some(entry.id));
+    assert_eq!(reversal.lines[0].credit, dec!(50.00));
+    assert!(unreversed_entries(&[entry.clone(), reversal.clone()]).is_empty());
+
+    let accounts = [bank, revenue];
+    let before = TrialBalance::compute(&accounts, std::slice::from_ref(&entry), Some(date(1)));
+    assert_eq!(before.total_debit, dec!(50.00));
+    assert!(before.is_balanced());
+
+    let after = TrialBalance::compute(&accounts, &[entry, reversal], None);
+    assert!(after.is_balanced());
+    assert_eq!(after.total_debit, Decimal::ZERO);
+    assert_eq!(after.rows[0].total_credit, dec!(50.00));
+  }
+}
@@ -0,0 +1,34 @@
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::domain::auth::errors::RepositoryError;
+
+#[derive(Debug, Error)]
+pub enum LedgerError {
+  #[error("Account not found")]
+  AccountNotFound,
+
+  #[error("Account code {0} is already in use")]
+  DuplicateAccountCode(String),
+
+  #[error("Journal entry not found")]
+  EntryNotFound,
+
+  #[error("Journal entry is not balanced: debits {debit}, credits {credit}")]
+  Unbalanced { debit: Decimal, credit: Decimal },
+
+  #[error("Journal entry has already been reversed")]
+  AlreadyReversed,
+
+  #[error("Validation error: {0}")]
+  Validation(String),
+
+  #[error("Repository error: {0}")]
+  Repository(#[from] RepositoryError),
+}
+
+impl From<sqlx::Error> for LedgerError {
+  fn from(error: sqlx::Error) -> Self {
+    LedgerError::Repository(RepositoryError::from(error))
+  }
+}
@@ -0,0 +1,15 @@
+pub mod chart;
+pub mod entities;
+pub mod errors;
+pub mod ports;
+pub mod services;
+pub mod value_objects;
+
+pub use chart::{STANDARD_CHART, SystemAccount};
+pub use entities::{
+  Account, JournalEntry, JournalLine, NewJournalLine, TrialBalance, TrialBalanceRow,
+};
+pub use errors::LedgerError;
+pub use ports::{AccountRepository, JournalEntryRepository};
+pub use services::LedgerService;
+pub use value_objects::{AccountType, JournalSource};
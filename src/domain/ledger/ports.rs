@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{
+  entities::{Account, JournalEntry},
+  errors::LedgerError,
+  value_objects::JournalSource,
+};
+
+#[async_trait]
+pub trait AccountRepository: Send + Sync {
+  /// Fails with `DuplicateAccountCode` if the company already uses the code
+  async fn create(&self, account: Account) -> Result<Account, LedgerError>;
+  /// Insert accounts, silently skipping codes the company already has
+  async fn create_many(&self, accounts: Vec<Account>) -> Result<(), LedgerError>;
+  /// Ordered by code
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<Account>, LedgerError>;
+}
+
+#[async_trait]
+pub trait JournalEntryRepository: Send + Sync {
+  /// Store the entry and its lines atomically. Fails with `AlreadyReversed`
+  /// if another entry already reverses the same entry.
+  async fn create(&self, entry: JournalEntry) -> Result<JournalEntry, LedgerError>;
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<JournalEntry>, LedgerError>;
+  /// Whether another entry reverses the entry
+  async fn is_reversed(&self, id: Uuid) -> Result<bool, LedgerError>;
+  /// Ordered by entry date, then creation time
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<JournalEntry>, LedgerError>;
+  /// Entries (including reversals) posted for one source document
+  async fn find_by_source(
+    &self,
+    company_id: Uuid,
+    source: JournalSource,
+    source_id: Uuid,
+  ) -> Result<Vec<JournalEntry>, LedgerError>;
+}
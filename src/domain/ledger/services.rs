@@ -0,0 +1,614 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use super::{
+  chart::{SystemAccount, standard_chart_for},
+  entities::{Account, JournalEntry, NewJournalLine, TrialBalance, unreversed_entries},
+  errors::LedgerError,
+  ports::{AccountRepository, JournalEntryRepository},
+  value_objects::{AccountType, JournalSource},
+};
+use crate::domain::invoice::entities::{Invoice, InvoiceLineItem, InvoiceTotals};
+use crate::domain::report::entities::ReceivedInvoice;
+
+/// The books are kept in euro; documents in other currencies are not posted
+/// automatically and have to be booked by hand at the day's rate.
+pub const LEDGER_CURRENCY: &str = "EUR";
+
+pub struct LedgerService {
+  account_repo: Arc<dyn AccountRepository>,
+  entry_repo: Arc<dyn JournalEntryRepository>,
+}
+
+impl LedgerService {
+  pub fn new(
+    account_repo: Arc<dyn AccountRepository>,
+    entry_repo: Arc<dyn JournalEntryRepository>,
+  ) -> Self {
+    Self {
+      account_repo,
+      entry_repo,
+    }
+  }
+
+  // -- Chart of accounts --
+
+  /// The company's accounts, seeding the standard chart on first use
+  pub async fn chart_of_accounts(&self, company_id: Uuid) -> Result<Vec<Account>, LedgerError> {
+    let accounts = self.account_repo.find_by_company_id(company_id).await?;
+    if !accounts.is_empty() {
+      return Ok(accounts);
+    }
+
+    self
+      .account_repo
+      .create_many(standard_chart_for(company_id))
+      .await?;
+    self.account_repo.find_by_company_id(company_id).await
+  }
+
+  pub async fn create_account(
+    &self,
+    company_id: Uuid,
+    code: String,
+    name: String,
+    account_type: AccountType,
+  ) -> Result<Account, LedgerError> {
+    // Seed first so a custom account cannot take a standard code
+    self.chart_of_accounts(company_id).await?;
+
+    let account = Account::new(company_id, code, name, account_type)?;
+    self.account_repo.create(account).await
+  }
+
+  // -- Journal --
+
+  /// Post a manual entry; every line must use one of the company's accounts
+  pub async fn post_manual_entry(
+    &self,
+    company_id: Uuid,
+    user_id: Uuid,
+    entry_date: NaiveDate,
+    description: String,
+    lines: Vec<NewJournalLine>,
+  ) -> Result<JournalEntry, LedgerError> {
+    let accounts = self.chart_of_accounts(company_id).await?;
+    if lines
+      .iter()
+      .any(|line| !accounts.iter().any(|account| account.id == line.account_id))
+    {
+      return Err(LedgerError::AccountNotFound);
+    }
+
+    let entry = JournalEntry::new(
+      company_id,
+      entry_date,
+      description,
+      JournalSource::Manual,
+      None,
+      Some(user_id),
+      lines,
+    )?;
+    self.entry_repo.create(entry).await
+  }
+
+  /// Post the reversing entry for `entry_id`
+  pub async fn reverse_entry(
+    &self,
+    company_id: Uuid,
+    user_id: Uuid,
+    entry_id: Uuid,
+    entry_date: NaiveDate,
+  ) -> Result<JournalEntry, LedgerError> {
+    let entry = self
+      .entry_repo
+      .find_by_id(entry_id)
+      .await?
+      .filter(|entry| entry.company_id == company_id)
+      .ok_or(LedgerError::EntryNotFound)?;
+
+    if entry.reversal_of.is_some() {
+      return Err(LedgerError::Validation(
+        "A reversing entry cannot be reversed".to_string(),
+      ));
+    }
+    if self.entry_repo.is_reversed(entry.id).await? {
+      return Err(LedgerError::AlreadyReversed);
+    }
+
+    self
+      .entry_repo
+      .create(entry.reversal(entry_date, Some(user_id)))
+      .await
+  }
+
+  pub async fn list_entries(&self, company_id: Uuid) -> Result<Vec<JournalEntry>, LedgerError> {
+    self.entry_repo.find_by_company_id(company_id).await
+  }
+
+  pub async fn trial_balance(
+    &self,
+    company_id: Uuid,
+    as_of: Option<NaiveDate>,
+  ) -> Result<TrialBalance, LedgerError> {
+    let accounts = self.chart_of_accounts(company_id).await?;
+    let entries = self.entry_repo.find_by_company_id(company_id).await?;
+    Ok(TrialBalance::compute(&accounts, &entries, as_of))
+  }
+
+  // -- Automatic postings --
+  //
+  // Each source document gets at most one live entry per source kind, so
+  // calling these again (e.g. an invoice marked paid after its bank payment
+  // was matched) is a no-op. They return the posted entry, if any. Bank
+  // payments are the exception: a document paid in several transfers gets
+  // one entry per transfer, kept in sync with its allocations.
+
+  /// Invoice sent: receivable against sales revenue and output VAT
+  pub async fn record_invoice_issued(
+    &self,
+    invoice: &Invoice,
+    line_items: &[InvoiceLineItem],
+  ) -> Result<Option<JournalEntry>, LedgerError> {
+    if !self.is_ledger_currency(invoice.currency.as_str(), invoice.id) {
+      return Ok(None);
+    }
+
+    let totals = InvoiceTotals::calculate(line_items, invoice.currency);
+    let revenue = totals.subtotal.amount.round_dp(2);
+    let vat = totals.total_vat.amount.round_dp(2);
+    if (revenue + vat).is_zero() {
+      return Ok(None);
+    }
+
+    let accounts = self.system_accounts(invoice.company_id).await?;
+    let mut lines = vec![
+      NewJournalLine::debit(accounts[&SystemAccount::Receivables], revenue + vat),
+      NewJournalLine::credit(accounts[&SystemAccount::SalesRevenue], revenue),
+    ];
+    if !vat.is_zero() {
+      lines.push(NewJournalLine::credit(
+        accounts[&SystemAccount::VatPayable],
+        vat,
+      ));
+    }
+
+    self
+      .post_once(
+        invoice.company_id,
+        invoice.invoice_date,
+        format!("Invoice {} issued", invoice.invoice_number.value()),
+        JournalSource::InvoiceIssued,
+        invoice.id,
+        lines,
+      )
+      .await
+  }
+
+  /// Invoice paid: bank against receivable
+  pub async fn record_invoice_payment(
+    &self,
+    invoice: &Invoice,
+    amount: Decimal,
+    paid_on: NaiveDate,
+  ) -> Result<Option<JournalEntry>, LedgerError> {
+    if !self.is_ledger_currency(invoice.currency.as_str(), invoice.id) {
+      return Ok(None);
+    }
+
+    let amount = amount.abs().round_dp(2);
+    if amount.is_zero() {
+      return Ok(None);
+    }
+
+    let accounts = self.system_accounts(invoice.company_id).await?;
+    self
+      .post_once(
+        invoice.company_id,
+        paid_on,
+        format!("Payment for invoice {}", invoice.invoice_number.value()),
+        JournalSource::InvoicePayment,
+        invoice.id,
+        vec![
+          NewJournalLine::debit(accounts[&SystemAccount::Bank], amount),
+          NewJournalLine::credit(accounts[&SystemAccount::Receivables], amount),
+        ],
+      )
+      .await
+  }
+
+  /// Vendor bill recorded: net expense and input VAT against payables
+  pub async fn record_received_invoice(
+    &self,
+    invoice: &ReceivedInvoice,
+  ) -> Result<Option<JournalEntry>, LedgerError> {
+    if !self.is_ledger_currency(&invoice.currency, invoice.id) {
+      return Ok(None);
+    }
+
+    let amount = invoice.amount.abs().round_dp(2);
+    if amount.is_zero() {
+      return Ok(None);
+    }
+    let vat = invoice.vat_amount.abs().round_dp(2);
+
+    let accounts = self.system_accounts(invoice.company_id).await?;
+    let mut lines = Vec::new();
+    if amount > vat {
+      lines.push(NewJournalLine::debit(
+        accounts[&SystemAccount::PurchasedServices],
+        amount - vat,
+      ));
+    }
+    if !vat.is_zero() {
+      lines.push(NewJournalLine::debit(
+        accounts[&SystemAccount::InputVat],
+        vat,
+      ));
+    }
+    lines.push(NewJournalLine::credit(
+      accounts[&SystemAccount::Payables],
+      amount,
+    ));
+
+    self
+      .post_once(
+        invoice.company_id,
+        invoice
+          .invoice_date
+          .unwrap_or_else(|| invoice.created_at.date_naive()),
+        received_invoice_description("Received invoice", invoice),
+        JournalSource::ReceivedInvoice,
+        invoice.id,
+        lines,
+      )
+      .await
+  }
+
+  /// Bring an issued invoice's payment entries in line with the bank
+  /// transactions allocated to it, one entry per dated payment: bank against
+  /// receivable. Entries for payments that no longer exist are reversed.
+  pub async fn sync_invoice_payments(
+    &self,
+    invoice: &Invoice,
+    payments: &[(NaiveDate, Decimal)],
+  ) -> Result<(), LedgerError> {
+    if !self.is_ledger_currency(invoice.currency.as_str(), invoice.id) {
+      return Ok(());
+    }
+
+    let accounts = self.system_accounts(invoice.company_id).await?;
+    let (bank, receivables) = (
+      accounts[&SystemAccount::Bank],
+      accounts[&SystemAccount::Receivables],
+    );
+    self
+      .sync_payments(
+        invoice.company_id,
+        JournalSource::InvoicePayment,
+        invoice.id,
+        format!("Payment for invoice {}", invoice.invoice_number.value()),
+        payments,
+        |amount| {
+          vec![
+            NewJournalLine::debit(bank, amount),
+            NewJournalLine::credit(receivables, amount),
+          ]
+        },
+      )
+      .await
+  }
+
+  /// Bring a vendor bill's payment entries in line with the bank transactions
+  /// allocated to it, one entry per dated payment: payables against bank
+  pub async fn sync_received_invoice_payments(
+    &self,
+    invoice: &ReceivedInvoice,
+    payments: &[(NaiveDate, Decimal)],
+  ) -> Result<(), LedgerError> {
+    if !self.is_ledger_currency(&invoice.currency, invoice.id) {
+      return Ok(());
+    }
+
+    let accounts = self.system_accounts(invoice.company_id).await?;
+    let (payables, bank) = (
+      accounts[&SystemAccount::Payables],
+      accounts[&SystemAccount::Bank],
+    );
+    self
+      .sync_payments(
+        invoice.company_id,
+        JournalSource::ReceivedInvoicePayment,
+        invoice.id,
+        received_invoice_description("Payment for received invoice", invoice),
+        payments,
+        |amount| {
+          vec![
+            NewJournalLine::debit(payables, amount),
+            NewJournalLine::credit(bank, amount),
+          ]
+        },
+      )
+      .await
+  }
+
+  /// Reverse the live automatic entries posted for a document, e.g. when a
+  /// bank match is undone or an invoice is cancelled
+  pub async fn reverse_source(
+    &self,
+    company_id: Uuid,
+    source: JournalSource,
+    source_id: Uuid,
+  ) -> Result<(), LedgerError> {
+    let entries = self
+      .entry_repo
+      .find_by_source(company_id, source, source_id)
+      .await?;
+
+    let today = Utc::now().date_naive();
+    for entry in unreversed_entries(&entries) {
+      self.entry_repo.create(entry.reversal(today, None)).await?;
+    }
+
+    Ok(())
+  }
+
+  fn is_ledger_currency(&self, currency: &str, document_id: Uuid) -> bool {
+    if currency.eq_ignore_ascii_case(LEDGER_CURRENCY) {
+      return true;
+    }
+    tracing::warn!(
+      "Skipping automatic journal entry for document {} in {}; only {} is posted automatically",
+      document_id,
+      currency,
+      LEDGER_CURRENCY
+    );
+    false
+  }
+
+  async fn system_accounts(
+    &self,
+    company_id: Uuid,
+  ) -> Result<HashMap<SystemAccount, Uuid>, LedgerError> {
+    let accounts = self.chart_of_accounts(company_id).await?;
+
+    let mut resolved = HashMap::new();
+    for system_account in [
+      SystemAccount::Bank,
+      SystemAccount::Receivables,
+      SystemAccount::InputVat,
+      SystemAccount::Payables,
+      SystemAccount::VatPayable,
+      SystemAccount::SalesRevenue,
+      SystemAccount::PurchasedServices,
+    ] {
+      let account = accounts
+        .iter()
+        .find(|account| account.code == system_account.code())
+        .ok_or(LedgerError::AccountNotFound)?;
+      resolved.insert(system_account, account.id);
+    }
+
+    Ok(resolved)
+  }
+
+  /// Keep the live entries of a document's payments that still exist, reverse
+  /// the rest and post the payments that have no entry yet. Entries are told
+  /// apart by date and amount.
+  async fn sync_payments(
+    &self,
+    company_id: Uuid,
+    source: JournalSource,
+    source_id: Uuid,
+    description: String,
+    payments: &[(NaiveDate, Decimal)],
+    lines: impl Fn(Decimal) -> Vec<NewJournalLine>,
+  ) -> Result<(), LedgerError> {
+    let mut missing: Vec<(NaiveDate, Decimal)> = payments
+      .iter()
+      .map(|(date, amount)| (*date, amount.abs().round_dp(2)))
+      .filter(|(_, amount)| !amount.is_zero())
+      .collect();
+
+    let existing = self
+      .entry_repo
+      .find_by_source(company_id, source, source_id)
+      .await?;
+    let today = Utc::now().date_naive();
+    for entry in unreversed_entries(&existing) {
+      let amount: Decimal = entry.lines.iter().map(|line| line.debit).sum();
+      match missing
+        .iter()
+        .position(|payment| *payment == (entry.entry_date, amount))
+      {
+        Some(index) => {
+          missing.remove(index);
+        }
+        None => {
+          self.entry_repo.create(entry.reversal(today, None)).await?;
+        }
+      }
+    }
+
+    for (entry_date, amount) in missing {
+      let entry = JournalEntry::new(
+        company_id,
+        entry_date,
+        description.clone(),
+        source,
+        Some(source_id),
+        None,
+        lines(amount),
+      )?;
+      self.entry_repo.create(entry).await?;
+    }
+
+    Ok(())
+  }
+
+  async fn post_once(
+    &self,
+    company_id: Uuid,
+    entry_date: NaiveDate,
+    description: String,
+    source: JournalSource,
+    source_id: Uuid,
+    lines: Vec<NewJournalLine>,
+  ) -> Result<Option<JournalEntry>, LedgerError> {
+    let existing = self
+      .entry_repo
+      .find_by_source(company_id, source, source_id)
+      .await?;
+    if !unreversed_entries(&existing).is_empty() {
+      return Ok(None);
+    }
+
+    let entry = JournalEntry::new(
+      company_id,
+      entry_date,
+      description,
+      source,
+      Some(source_id),
+      None,
+      lines,
+    )?;
+    self.entry_repo.create(entry).await.map(Some)
+  }
+}
+
+fn received_invoice_description(prefix: &str, invoice: &ReceivedInvoice) -> String {
+  match &invoice.invoice_number {
+    Some(number) => format!("{} {} from {}", prefix, number, invoice.vendor_name),
+    None => format!("{} from {}", prefix, invoice.vendor_name),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use async_trait::async_trait;
+  use rust_decimal_macros::dec;
+  use std::sync::Mutex;
+
+  #[derive(Default)]
+  struct InMemoryAccounts {
+    accounts: Mutex<Vec<Account>>,
+  }
+
+  #[async_trait]
+  impl AccountRepository for InMemoryAccounts {
+    async fn create(&self, account: Account) -> Result<Account, LedgerError> {
+      self.accounts.lock().unwrap().push(account.clone());
+      Ok(account)
+    }
+
+    async fn create_many(&self, accounts: Vec<Account>) -> Result<(), LedgerError> {
+      self.accounts.lock().unwrap().extend(accounts);
+      Ok(())
+    }
+
+    async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<Account>, LedgerError> {
+      let accounts = self.accounts.lock().unwrap();
+      Ok(
+        accounts
+          .iter()
+          .filter(|account| account.company_id == company_id)
+          .cloned()
+          .collect(),
+      )
+    }
+  }
+
+  #[derive(Default)]
+  struct InMemoryEntries {
+    entries: Mutex<Vec<JournalEntry>>,
+  }
+
+  #[async_trait]
+  impl JournalEntryRepository for InMemoryEntries {
+    async fn create(&self, entry: JournalEntry) -> Result<JournalEntry, LedgerError> {
+      self.entries.lock().unwrap().push(entry.clone());
+      Ok(entry)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<JournalEntry>, LedgerError> {
+      let entries = self.entries.lock().unwrap();
+      Ok(entries.iter().find(|entry| entry.id == id).cloned())
+    }
+
+    async fn is_reversed(&self, id: Uuid) -> Result<bool, LedgerError> {
+      let entries = self.entries.lock().unwrap();
+      Ok(entries.iter().any(|entry| entry.reversal_of == Some(id)))
+    }
+
+    async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<JournalEntry>, LedgerError> {
+      let entries = self.entries.lock().unwrap();
+      Ok(
+        entries
+          .iter()
+          .filter(|entry| entry.company_id == company_id)
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn find_by_source(
+      &self,
+      company_id: Uuid,
+      source: JournalSource,
+      source_id: Uuid,
+    ) -> Result<Vec<JournalEntry>, LedgerError> {
+      let entries = self.entries.lock().unwrap();
+      Ok(
+        entries
+          .iter()
+          .filter(|entry| {
+            entry.company_id == company_id
+              && entry.source == source
+              && entry.source_id == Some(source_id)
+          })
+          .cloned()
+          .collect(),
+      )
+    }
+  }
+
+  #[tokio::test]
+  async fn test_entry_can_only_be_reversed_once() {
+    let entries = Arc::new(InMemoryEntries::default());
+    let service = LedgerService::new(Arc::new(InMemoryAccounts::default()), entries.clone());
+    let company_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let date = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+
+    let accounts = service.chart_of_accounts(company_id).await.unwrap();
+    let entry = service
+      .post_manual_entry(
+        company_id,
+        user_id,
+        date,
+        "Office rent".to_string(),
+        vec![
+          NewJournalLine::debit(accounts[0].id, dec!(100.00)),
+          NewJournalLine::credit(accounts[1].id, dec!(100.00)),
+        ],
+      )
+      .await
+      .unwrap();
+
+    service
+      .reverse_entry(company_id, user_id, entry.id, date)
+      .await
+      .unwrap();
+    let again = service
+      .reverse_entry(company_id, user_id, entry.id, date)
+      .await;
+
+    assert!(matches!(again, Err(LedgerError::AlreadyReversed)));
+    assert_eq!(entries.entries.lock().unwrap().len(), 2);
+  }
+}
@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+
+use super::errors::LedgerError;
+
+/// Classification of an account in the chart of accounts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountType {
+  Asset,
+  Liability,
+  Equity,
+  Revenue,
+  Expense,
+}
+
+impl AccountType {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      AccountType::Asset => "asset",
+      AccountType::Liability => "liability",
+      AccountType::Equity => "equity",
+      AccountType::Revenue => "revenue",
+      AccountType::Expense => "expense",
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      AccountType::Asset => "Asset",
+      AccountType::Liability => "Liability",
+      AccountType::Equity => "Equity",
+      AccountType::Revenue => "Revenue",
+      AccountType::Expense => "Expense",
+    }
+  }
+
+  /// Assets and expenses increase on the debit side, everything else on the credit side
+  pub fn is_debit_normal(&self) -> bool {
+    matches!(self, AccountType::Asset | AccountType::Expense)
+  }
+
+  pub fn all() -> [AccountType; 5] {
+    [
+      AccountType::Asset,
+      AccountType::Liability,
+      AccountType::Equity,
+      AccountType::Revenue,
+      AccountType::Expense,
+    ]
+  }
+}
+
+impl TryFrom<&str> for AccountType {
+  type Error = LedgerError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s.to_lowercase().as_str() {
+      "asset" => Ok(AccountType::Asset),
+      "liability" => Ok(AccountType::Liability),
+      "equity" => Ok(AccountType::Equity),
+      "revenue" => Ok(AccountType::Revenue),
+      "expense" => Ok(AccountType::Expense),
+      _ => Err(LedgerError::Validation(format!(
+        "Invalid account type: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+/// What caused a journal entry to be posted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalSource {
+  /// Entered by hand by a user
+  Manual,
+  /// Issued invoice was sent (source id: invoice)
+  InvoiceIssued,
+  /// Issued invoice was paid (source id: invoice)
+  InvoicePayment,
+  /// Vendor bill was recorded (source id: received invoice)
+  ReceivedInvoice,
+  /// Vendor bill was paid from the bank (source id: received invoice)
+  ReceivedInvoicePayment,
+}
+
+impl JournalSource {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      JournalSource::Manual => "manual",
+      JournalSource::InvoiceIssued => "invoice_issued",
+      JournalSource::InvoicePayment => "invoice_payment",
+      JournalSource::ReceivedInvoice => "received_invoice",
+      JournalSource::ReceivedInvoicePayment => "received_invoice_payment",
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      JournalSource::Manual => "Manual entry",
+      JournalSource::InvoiceIssued => "Invoice issued",
+      JournalSource::InvoicePayment => "Invoice payment",
+      JournalSource::ReceivedInvoice => "Received invoice",
+      JournalSource::ReceivedInvoicePayment => "Received invoice payment",
+    }
+  }
+}
+
+impl TryFrom<&str> for JournalSource {
+  type Error = LedgerError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "manual" => Ok(JournalSource::Manual),
+      "invoice_issued" => Ok(JournalSource::InvoiceIssued),
+      "invoice_payment" => Ok(JournalSource::InvoicePayment),
+      "received_invoice" => Ok(JournalSource::ReceivedInvoice),
+      "received_invoice_payment" => Ok(JournalSource::ReceivedInvoicePayment),
+      _ => Err(LedgerError::Validation(format!(
+        "Invalid journal source: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_account_type_round_trip() {
+    for account_type in AccountType::all() {
+      assert_eq!(
+        AccountType::try_from(account_type.as_str()).unwrap(),
+        account_type
+      );
+    }
+    assert!(AccountType::try_from("income").is_err());
+  }
+
+  #[test]
+  fn test_journal_source_round_trip() {
+    for source in [
+      JournalSource::Manual,
+      JournalSource::InvoiceIssued,
+      JournalSource::InvoicePayment,
+      JournalSource::ReceivedInvoice,
+      JournalSource::ReceivedInvoicePayment,
+    ] {
+      assert_eq!(JournalSource::try_from(source.as_str()).unwrap(), source);
+    }
+  }
+}
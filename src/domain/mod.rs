@@ -1,7 +1,13 @@
 pub mod auth;
 pub mod company;
+pub mod document;
+pub mod financials;
 pub mod invoice;
+pub mod ledger;
 pub mod report;
+pub mod retention;
+pub mod saft;
+pub mod storage;
 
 // Re-export auth module for easier access
 pub use auth::*;
@@ -1,11 +1,17 @@
 use chrono::{DateTime, NaiveDate, Utc};
+use regex::{Regex, RegexBuilder};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::value_objects::{ReportStatus, TransactionDirection};
+use super::errors::ReportError;
+use super::value_objects::{
+  CategoryMatchField, PayableStatus, ReportEventKind, ReportStatus, RuleAction,
+  TransactionDirection,
+};
+use crate::domain::company::{Iban, RegistryCode, VatNumber};
 
-/// Monthly report representing one imported bank statement
+/// Monthly report aggregating the statements of the company's bank accounts
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonthlyReport {
   pub id: Uuid,
@@ -13,18 +19,20 @@ pub struct MonthlyReport {
   pub month: u32,
   pub year: i32,
   pub status: ReportStatus,
-  pub bank_account_iban: Option<String>,
+  /// Sums over the report's statements
   pub total_incoming: Decimal,
   pub total_outgoing: Decimal,
   pub transaction_count: i32,
   pub matched_count: i32,
   pub drive_folder_id: Option<String>,
+  /// Number of times the report was generated; 0 until the first generation
+  pub version: i32,
   pub created_at: DateTime<Utc>,
   pub updated_at: DateTime<Utc>,
 }
 
 impl MonthlyReport {
-  pub fn new(company_id: Uuid, month: u32, year: i32, bank_account_iban: Option<String>) -> Self {
+  pub fn new(company_id: Uuid, month: u32, year: i32) -> Self {
     let now = Utc::now();
     Self {
       id: Uuid::new_v4(),
@@ -32,16 +40,202 @@ impl MonthlyReport {
       month,
       year,
       status: ReportStatus::Draft,
-      bank_account_iban,
       total_incoming: Decimal::ZERO,
       total_outgoing: Decimal::ZERO,
       transaction_count: 0,
       matched_count: 0,
       drive_folder_id: None,
+      version: 0,
       created_at: now,
       updated_at: now,
     }
   }
+
+  pub fn is_locked(&self) -> bool {
+    self.status == ReportStatus::Locked
+  }
+
+  /// Fails unless the report is a draft: a generated report is read-only
+  /// until it is reopened, a signed off one for good
+  pub fn ensure_editable(&self) -> Result<(), ReportError> {
+    match self.status {
+      ReportStatus::Draft => Ok(()),
+      ReportStatus::Generated => Err(ReportError::Generated),
+      ReportStatus::Locked => Err(ReportError::Locked),
+    }
+  }
+
+  /// Record a generation into the given Drive folder as a new version
+  pub fn mark_generated(&mut self, drive_folder_id: String) -> Result<(), ReportError> {
+    if self.status != ReportStatus::Draft {
+      return Err(ReportError::NotDraft);
+    }
+    self.status = ReportStatus::Generated;
+    self.drive_folder_id = Some(drive_folder_id);
+    self.version += 1;
+    self.updated_at = Utc::now();
+    Ok(())
+  }
+
+  /// Move a generated report back to draft so it can be changed and
+  /// regenerated
+  pub fn reopen(&mut self) -> Result<(), ReportError> {
+    match self.status {
+      ReportStatus::Generated => {
+        self.status = ReportStatus::Draft;
+        self.updated_at = Utc::now();
+        Ok(())
+      }
+      ReportStatus::Locked => Err(ReportError::Locked),
+      ReportStatus::Draft => Err(ReportError::NotGenerated),
+    }
+  }
+
+  /// Sign off a generated report, locking it permanently
+  pub fn lock(&mut self) -> Result<(), ReportError> {
+    match self.status {
+      ReportStatus::Generated => {
+        self.status = ReportStatus::Locked;
+        self.updated_at = Utc::now();
+        Ok(())
+      }
+      ReportStatus::Locked => Err(ReportError::Locked),
+      ReportStatus::Draft => Err(ReportError::NotGenerated),
+    }
+  }
+
+  /// Add an imported statement's subtotals to the report's totals
+  pub fn add_statement(&mut self, statement: &BankStatement) {
+    self.total_incoming += statement.total_incoming;
+    self.total_outgoing += statement.total_outgoing;
+    self.transaction_count += statement.transaction_count;
+    self.updated_at = Utc::now();
+  }
+}
+
+/// Statement of one bank account imported into a monthly report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BankStatement {
+  pub id: Uuid,
+  pub report_id: Uuid,
+  /// Company bank account with the statement's IBAN, if one is registered
+  pub bank_account_id: Option<Uuid>,
+  pub bank_account_iban: String,
+  /// Credits on the statement, including rows skipped as already imported
+  pub total_incoming: Decimal,
+  /// Debits on the statement, including rows skipped as already imported
+  pub total_outgoing: Decimal,
+  pub transaction_count: i32,
+  /// Balances from the statement's balance rows, when it has them
+  pub opening_balance: Option<Decimal>,
+  pub closing_balance: Option<Decimal>,
+  /// Days the statement covers
+  pub period_start: NaiveDate,
+  pub period_end: NaiveDate,
+  /// Rows left out on import because another statement already had them
+  pub duplicate_count: i32,
+  /// Where the uploaded statement file is kept, and its original name
+  pub file_path: Option<String>,
+  pub file_name: Option<String>,
+  pub created_at: DateTime<Utc>,
+}
+
+impl BankStatement {
+  pub fn new(
+    report_id: Uuid,
+    bank_account_id: Option<Uuid>,
+    bank_account_iban: String,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+  ) -> Self {
+    Self {
+      id: Uuid::new_v4(),
+      report_id,
+      bank_account_id,
+      bank_account_iban,
+      total_incoming: Decimal::ZERO,
+      total_outgoing: Decimal::ZERO,
+      transaction_count: 0,
+      opening_balance: None,
+      closing_balance: None,
+      period_start,
+      period_end,
+      duplicate_count: 0,
+      file_path: None,
+      file_name: None,
+      created_at: Utc::now(),
+    }
+  }
+
+  /// Opening balance plus credits minus debits, less the closing balance.
+  /// Zero when the statement adds up; `None` without both balances.
+  pub fn balance_difference(&self) -> Option<Decimal> {
+    let opening = self.opening_balance?;
+    let closing = self.closing_balance?;
+    Some(opening + self.total_incoming - self.total_outgoing - closing)
+  }
+
+  pub fn is_balanced(&self) -> bool {
+    self.balance_difference() == Some(Decimal::ZERO)
+  }
+}
+
+/// Days not covered by any statement between two consecutive statements of
+/// the same bank account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StatementGap {
+  pub bank_account_iban: String,
+  pub from: NaiveDate,
+  pub to: NaiveDate,
+  pub previous_report_id: Uuid,
+  pub next_report_id: Uuid,
+}
+
+impl StatementGap {
+  /// Gaps between the imported statements of each account, oldest first
+  pub fn find(statements: &[BankStatement]) -> Vec<StatementGap> {
+    let mut statements: Vec<&BankStatement> = statements.iter().collect();
+    statements.sort_by(|a, b| {
+      (&a.bank_account_iban, a.period_start, a.period_end).cmp(&(
+        &b.bank_account_iban,
+        b.period_start,
+        b.period_end,
+      ))
+    });
+
+    let mut gaps = Vec::new();
+    // Latest day covered so far per account, so a long statement overlapping
+    // several shorter ones does not produce false gaps
+    let mut covered: Option<&BankStatement> = None;
+    for statement in statements {
+      match covered {
+        Some(previous) if previous.bank_account_iban == statement.bank_account_iban => {
+          if let Some(next_day) = previous
+            .period_end
+            .succ_opt()
+            .filter(|d| *d < statement.period_start)
+          {
+            gaps.push(StatementGap {
+              bank_account_iban: statement.bank_account_iban.clone(),
+              from: next_day,
+              to: statement
+                .period_start
+                .pred_opt()
+                .unwrap_or(statement.period_start),
+              previous_report_id: previous.report_id,
+              next_report_id: statement.report_id,
+            });
+          }
+          if statement.period_end > previous.period_end {
+            covered = Some(statement);
+          }
+        }
+        _ => covered = Some(statement),
+      }
+    }
+    gaps.sort_by_key(|g| g.from);
+    gaps
+  }
 }
 
 /// One row from a bank statement CSV
@@ -59,9 +253,22 @@ pub struct BankTransaction {
   pub description: Option<String>,
   pub currency: String,
   pub registry_code: Option<String>,
-  pub matched_invoice_id: Option<Uuid>,
-  pub matched_received_invoice_id: Option<Uuid>,
+  /// Statement the row was imported from
+  pub statement_id: Option<Uuid>,
+  /// Bank's identifiers for the statement row, used to spot rows imported twice
+  pub document_number: Option<String>,
+  pub archive_id: Option<String>,
+  /// Parts of the transaction settling issued or received invoices
+  pub allocations: Vec<MatchAllocation>,
   pub receipt_path: Option<String>,
+  /// Set for transactions no invoice will be matched to, e.g. bank fees
+  pub category_id: Option<Uuid>,
+  /// Matching rule that fired on import
+  pub rule_id: Option<Uuid>,
+  pub ignored: bool,
+  pub needs_review: bool,
+  /// Vendor paid, for outgoing payments to a known vendor
+  pub vendor_id: Option<Uuid>,
 }
 
 impl BankTransaction {
@@ -92,25 +299,389 @@ impl BankTransaction {
       description,
       currency,
       registry_code,
-      matched_invoice_id: None,
-      matched_received_invoice_id: None,
+      statement_id: None,
+      document_number: None,
+      archive_id: None,
+      allocations: Vec::new(),
       receipt_path: None,
+      category_id: None,
+      rule_id: None,
+      ignored: false,
+      needs_review: false,
+      vendor_id: None,
     }
   }
 
+  /// Settled by documents: fully allocated to invoices, or a receipt is attached
   pub fn is_matched(&self) -> bool {
-    self.matched_invoice_id.is_some()
-      || self.matched_received_invoice_id.is_some()
-      || self.receipt_path.is_some()
+    self.is_fully_allocated() || self.receipt_path.is_some()
+  }
+
+  pub fn allocated_amount(&self) -> Decimal {
+    self.allocations.iter().map(|a| a.amount).sum()
+  }
+
+  /// Part of the absolute amount not yet allocated to an invoice
+  pub fn unallocated_amount(&self) -> Decimal {
+    (self.amount.abs() - self.allocated_amount()).max(Decimal::ZERO)
+  }
+
+  pub fn is_fully_allocated(&self) -> bool {
+    !self.allocations.is_empty() && self.unallocated_amount().is_zero()
+  }
+
+  /// Allocate part of the transaction to an invoice. The invoice's own open
+  /// amount is checked by the caller, which knows its other payments.
+  pub fn allocate(
+    &mut self,
+    invoice_id: Option<Uuid>,
+    received_invoice_id: Option<Uuid>,
+    amount: Decimal,
+  ) -> Result<MatchAllocation, ReportError> {
+    let allocation = MatchAllocation::new(self.id, invoice_id, received_invoice_id, amount)?;
+
+    if self
+      .allocations
+      .iter()
+      .any(|a| a.same_document(&allocation))
+    {
+      return Err(ReportError::Validation(
+        "The transaction is already allocated to this invoice".to_string(),
+      ));
+    }
+    if allocation.amount > self.unallocated_amount() {
+      return Err(ReportError::Validation(format!(
+        "Only {} of the transaction is left to allocate",
+        self.unallocated_amount()
+      )));
+    }
+
+    self.allocations.push(allocation.clone());
+    Ok(allocation)
+  }
+
+  /// Matched to a document, explained by a category or ignored; nothing left to do
+  pub fn is_resolved(&self) -> bool {
+    self.is_matched() || self.category_id.is_some() || self.ignored
+  }
+
+  /// Normalized values a category suggestion can be keyed on, most specific first
+  pub fn category_keys(&self) -> Vec<(CategoryMatchField, String)> {
+    [
+      (
+        CategoryMatchField::CounterpartyAccount,
+        &self.counterparty_account,
+      ),
+      (CategoryMatchField::RegistryCode, &self.registry_code),
+      (
+        CategoryMatchField::CounterpartyName,
+        &self.counterparty_name,
+      ),
+    ]
+    .into_iter()
+    .filter_map(|(field, value)| {
+      value
+        .as_deref()
+        .and_then(|value| field.normalize(value))
+        .map(|value| (field, value))
+    })
+    .collect()
+  }
+}
+
+/// Part of a bank transaction settling one issued or received invoice. A
+/// payment can settle several invoices and an invoice can be paid in several
+/// transfers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchAllocation {
+  pub id: Uuid,
+  pub transaction_id: Uuid,
+  pub invoice_id: Option<Uuid>,
+  pub received_invoice_id: Option<Uuid>,
+  /// Positive, at most the transaction's absolute amount
+  pub amount: Decimal,
+  pub created_at: DateTime<Utc>,
+}
+
+impl MatchAllocation {
+  pub fn new(
+    transaction_id: Uuid,
+    invoice_id: Option<Uuid>,
+    received_invoice_id: Option<Uuid>,
+    amount: Decimal,
+  ) -> Result<Self, ReportError> {
+    if invoice_id.is_some() == received_invoice_id.is_some() {
+      return Err(ReportError::Validation(
+        "Exactly one of invoice_id or received_invoice_id must be provided".to_string(),
+      ));
+    }
+
+    let amount = amount.round_dp(2);
+    if amount <= Decimal::ZERO {
+      return Err(ReportError::Validation(
+        "Allocated amount must be positive".to_string(),
+      ));
+    }
+
+    Ok(Self {
+      id: Uuid::new_v4(),
+      transaction_id,
+      invoice_id,
+      received_invoice_id,
+      amount,
+      created_at: Utc::now(),
+    })
+  }
+
+  fn same_document(&self, other: &MatchAllocation) -> bool {
+    self.invoice_id == other.invoice_id && self.received_invoice_id == other.received_invoice_id
+  }
+}
+
+/// Company-defined category for bank transactions that have no invoice,
+/// such as bank fees, salaries, tax payments or subscriptions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionCategory {
+  pub id: Uuid,
+  pub company_id: Uuid,
+  pub name: String,
+  pub created_at: DateTime<Utc>,
+}
+
+impl TransactionCategory {
+  pub fn new(company_id: Uuid, name: String) -> Self {
+    Self {
+      id: Uuid::new_v4(),
+      company_id,
+      name,
+      created_at: Utc::now(),
+    }
   }
 }
 
-/// Uploaded vendor bill PDF with minimal metadata
+/// Category learned from a manual categorisation. Imported transactions with
+/// the same direction and key value get the category automatically.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategorySuggestion {
+  pub company_id: Uuid,
+  pub direction: TransactionDirection,
+  pub field: CategoryMatchField,
+  /// Normalized value, see `CategoryMatchField::normalize`
+  pub value: String,
+  pub category_id: Uuid,
+  /// How many times in a row this category was chosen for the key
+  pub times_used: i32,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl CategorySuggestion {
+  /// One suggestion per key of a categorised transaction
+  pub fn learn(
+    company_id: Uuid,
+    transaction: &BankTransaction,
+    category_id: Uuid,
+  ) -> Vec<CategorySuggestion> {
+    let now = Utc::now();
+    transaction
+      .category_keys()
+      .into_iter()
+      .map(|(field, value)| CategorySuggestion {
+        company_id,
+        direction: transaction.direction,
+        field,
+        value,
+        category_id,
+        times_used: 1,
+        updated_at: now,
+      })
+      .collect()
+  }
+
+  /// Category for a transaction from the most specific matching suggestion
+  pub fn suggest(
+    suggestions: &[CategorySuggestion],
+    transaction: &BankTransaction,
+  ) -> Option<Uuid> {
+    transaction
+      .category_keys()
+      .into_iter()
+      .find_map(|(field, value)| {
+        suggestions
+          .iter()
+          .find(|s| s.direction == transaction.direction && s.field == field && s.value == value)
+          .map(|s| s.category_id)
+      })
+  }
+}
+
+/// Conditions of a matching rule. Every condition that is set must hold.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleConditions {
+  pub counterparty_iban: Option<String>,
+  /// Case-insensitive regex searched in the description
+  pub description_pattern: Option<String>,
+  /// Inclusive bounds on the absolute amount
+  pub min_amount: Option<Decimal>,
+  pub max_amount: Option<Decimal>,
+  pub direction: Option<TransactionDirection>,
+}
+
+impl RuleConditions {
+  fn description_regex(&self) -> Result<Option<Regex>, ReportError> {
+    self
+      .description_pattern
+      .as_deref()
+      .map(|pattern| {
+        RegexBuilder::new(pattern)
+          .case_insensitive(true)
+          .build()
+          .map_err(|e| ReportError::Validation(format!("Invalid description pattern: {}", e)))
+      })
+      .transpose()
+  }
+}
+
+/// User-defined rule applied to transactions when a bank statement is
+/// imported. Rules are evaluated by ascending position; the first one whose
+/// conditions hold fires.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchingRule {
+  pub id: Uuid,
+  pub company_id: Uuid,
+  pub name: String,
+  pub position: i32,
+  pub conditions: RuleConditions,
+  pub action: RuleAction,
+  pub created_at: DateTime<Utc>,
+}
+
+impl MatchingRule {
+  pub fn new(
+    company_id: Uuid,
+    name: String,
+    position: i32,
+    conditions: RuleConditions,
+    action: RuleAction,
+  ) -> Result<Self, ReportError> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+      return Err(ReportError::Validation("Rule name is required".to_string()));
+    }
+
+    let mut conditions = conditions;
+    conditions.counterparty_iban = conditions
+      .counterparty_iban
+      .as_deref()
+      .and_then(|iban| CategoryMatchField::CounterpartyAccount.normalize(iban));
+    conditions.description_pattern = conditions
+      .description_pattern
+      .map(|pattern| pattern.trim().to_string())
+      .filter(|pattern| !pattern.is_empty());
+
+    if conditions == RuleConditions::default() {
+      return Err(ReportError::Validation(
+        "A rule needs at least one condition".to_string(),
+      ));
+    }
+    if let (Some(min), Some(max)) = (conditions.min_amount, conditions.max_amount) {
+      if min > max {
+        return Err(ReportError::Validation(
+          "Minimum amount is larger than the maximum".to_string(),
+        ));
+      }
+    }
+    conditions.description_regex()?;
+
+    Ok(Self {
+      id: Uuid::new_v4(),
+      company_id,
+      name,
+      position,
+      conditions,
+      action,
+      created_at: Utc::now(),
+    })
+  }
+}
+
+/// Ordered matching rules with their description patterns compiled once
+pub struct RuleEvaluator<'a> {
+  rules: Vec<(&'a MatchingRule, Option<Regex>)>,
+}
+
+impl<'a> RuleEvaluator<'a> {
+  /// Rules with a pattern that no longer compiles are skipped
+  pub fn new(rules: &'a [MatchingRule]) -> Self {
+    let mut rules: Vec<(&MatchingRule, Option<Regex>)> = rules
+      .iter()
+      .filter_map(|rule| {
+        rule
+          .conditions
+          .description_regex()
+          .ok()
+          .map(|regex| (rule, regex))
+      })
+      .collect();
+    rules.sort_by_key(|(rule, _)| rule.position);
+    Self { rules }
+  }
+
+  /// The rule that fires on a transaction
+  pub fn first_match(&self, transaction: &BankTransaction) -> Option<&'a MatchingRule> {
+    self
+      .rules
+      .iter()
+      .find(|(rule, regex)| conditions_hold(&rule.conditions, regex.as_ref(), transaction))
+      .map(|(rule, _)| *rule)
+  }
+
+  /// Whether a rule's conditions hold, regardless of the rules before it
+  pub fn matches(&self, rule_id: Uuid, transaction: &BankTransaction) -> bool {
+    self
+      .rules
+      .iter()
+      .find(|(rule, _)| rule.id == rule_id)
+      .is_some_and(|(rule, regex)| conditions_hold(&rule.conditions, regex.as_ref(), transaction))
+  }
+}
+
+fn conditions_hold(
+  conditions: &RuleConditions,
+  description_regex: Option<&Regex>,
+  transaction: &BankTransaction,
+) -> bool {
+  let amount = transaction.amount.abs();
+
+  conditions
+    .direction
+    .map_or(true, |direction| direction == transaction.direction)
+    && conditions
+      .counterparty_iban
+      .as_deref()
+      .map_or(true, |iban| {
+        transaction
+          .counterparty_account
+          .as_deref()
+          .and_then(|account| CategoryMatchField::CounterpartyAccount.normalize(account))
+          .is_some_and(|account| account == iban)
+      })
+    && description_regex.map_or(true, |regex| {
+      transaction
+        .description
+        .as_deref()
+        .is_some_and(|description| regex.is_match(description))
+    })
+    && conditions.min_amount.map_or(true, |min| amount >= min)
+    && conditions.max_amount.map_or(true, |max| amount <= max)
+}
+
+/// Uploaded vendor bill PDF, tracked as a payable until it is paid
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReceivedInvoice {
   pub id: Uuid,
   pub company_id: Uuid,
   pub vendor_name: String,
+  /// Gross amount, VAT included
   pub amount: Decimal,
   pub currency: String,
   pub invoice_date: Option<NaiveDate>,
@@ -118,10 +689,284 @@ pub struct ReceivedInvoice {
   pub pdf_path: String,
   pub pdf_drive_file_id: Option<String>,
   pub notes: Option<String>,
+  /// Input VAT included in `amount`
+  pub vat_amount: Decimal,
+  pub due_date: Option<NaiveDate>,
+  /// Account to pay to, normalised
+  pub vendor_iban: Option<String>,
+  /// Reference number or message to pay with
+  pub payment_reference: Option<String>,
+  pub status: PayableStatus,
+  /// Date of the bank transaction that settled the invoice; `None` when it
+  /// was marked paid by hand
+  pub paid_on: Option<NaiveDate>,
+  pub vendor_id: Option<Uuid>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
+/// Supplier the company buys from. Received invoices and the payments to it
+/// link here, so differently spelled names on them are still one vendor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Vendor {
+  pub id: Uuid,
+  pub company_id: Uuid,
+  pub name: String,
+  /// Business registry code, unique within the company
+  pub registry_code: Option<String>,
+  pub vat_number: Option<String>,
+  /// Accounts the vendor is paid to, normalised
+  pub ibans: Vec<String>,
+  /// Category for payments to the vendor that no invoice is matched to
+  pub default_category_id: Option<Uuid>,
+  /// VAT rate of the vendor's bills, to split VAT out of their amount
+  pub default_vat_rate: Option<Decimal>,
   pub created_at: DateTime<Utc>,
   pub updated_at: DateTime<Utc>,
 }
 
+/// Editable details of a vendor
+#[derive(Debug, Clone, Default)]
+pub struct VendorProfile {
+  pub name: String,
+  pub registry_code: Option<String>,
+  pub vat_number: Option<String>,
+  pub ibans: Vec<String>,
+  pub default_category_id: Option<Uuid>,
+  pub default_vat_rate: Option<Decimal>,
+}
+
+/// How a counterparty names itself on a bill or bank transaction
+#[derive(Debug, Clone, Default)]
+pub struct VendorIdentity {
+  pub name: String,
+  pub registry_code: Option<String>,
+  pub iban: Option<String>,
+}
+
+impl VendorIdentity {
+  /// Identity of the payee of an outgoing transaction
+  pub fn of_transaction(tx: &BankTransaction) -> Option<Self> {
+    let name = tx.counterparty_name.as_deref()?.trim();
+    if tx.direction != TransactionDirection::Debit || name.is_empty() {
+      return None;
+    }
+    Some(Self {
+      name: name.to_string(),
+      registry_code: tx.registry_code.clone(),
+      iban: tx.counterparty_account.clone(),
+    })
+  }
+}
+
+impl Vendor {
+  pub fn new(company_id: Uuid, profile: VendorProfile) -> Result<Self, ReportError> {
+    let now = Utc::now();
+    let mut vendor = Self {
+      id: Uuid::new_v4(),
+      company_id,
+      name: String::new(),
+      registry_code: None,
+      vat_number: None,
+      ibans: Vec::new(),
+      default_category_id: None,
+      default_vat_rate: None,
+      created_at: now,
+      updated_at: now,
+    };
+    vendor.update(profile)?;
+    Ok(vendor)
+  }
+
+  /// Vendor for a counterparty seen on a bill or bank transaction; invalid
+  /// details are left out rather than rejected
+  pub fn from_identity(company_id: Uuid, identity: &VendorIdentity) -> Result<Self, ReportError> {
+    let mut vendor = Self::new(
+      company_id,
+      VendorProfile {
+        name: identity.name.clone(),
+        ..Default::default()
+      },
+    )?;
+    vendor.learn(identity);
+    Ok(vendor)
+  }
+
+  pub fn update(&mut self, profile: VendorProfile) -> Result<(), ReportError> {
+    let name = profile
+      .name
+      .split_whitespace()
+      .collect::<Vec<_>>()
+      .join(" ");
+    if name.is_empty() {
+      return Err(ReportError::Validation(
+        "Vendor name is required".to_string(),
+      ));
+    }
+    let registry_code = filled(profile.registry_code)
+      .map(RegistryCode::new)
+      .transpose()
+      .map_err(|e| ReportError::Validation(e.to_string()))?
+      .map(RegistryCode::into_inner);
+    let vat_number = filled(profile.vat_number)
+      .map(VatNumber::new)
+      .transpose()
+      .map_err(|e| ReportError::Validation(e.to_string()))?
+      .map(VatNumber::into_inner);
+    let mut ibans: Vec<String> = Vec::new();
+    for iban in profile.ibans.into_iter().filter_map(|i| filled(Some(i))) {
+      let iban = Iban::new(iban)
+        .map_err(|e| ReportError::Validation(format!("Vendor IBAN: {}", e)))?
+        .into_inner();
+      if !ibans.contains(&iban) {
+        ibans.push(iban);
+      }
+    }
+    if let Some(rate) = profile.default_vat_rate {
+      if rate < Decimal::ZERO || rate > Decimal::ONE_HUNDRED {
+        return Err(ReportError::Validation(
+          "VAT rate must be between 0 and 100".to_string(),
+        ));
+      }
+    }
+
+    self.name = name;
+    self.registry_code = registry_code;
+    self.vat_number = vat_number;
+    self.ibans = ibans;
+    self.default_category_id = profile.default_category_id;
+    self.default_vat_rate = profile.default_vat_rate;
+    self.updated_at = Utc::now();
+    Ok(())
+  }
+
+  /// Name compared regardless of case and spacing
+  pub fn name_key(name: &str) -> String {
+    name
+      .split_whitespace()
+      .collect::<Vec<_>>()
+      .join(" ")
+      .to_lowercase()
+  }
+
+  /// Vendors with the same name regardless of case and spacing, as
+  /// `(duplicate, vendor to merge it into)`: each goes into the oldest vendor
+  /// of its name. Vendors with different registry codes are kept apart.
+  pub fn same_name_duplicates(vendors: &[Vendor]) -> Vec<(Uuid, Uuid)> {
+    let mut by_age: Vec<&Vendor> = vendors.iter().collect();
+    by_age.sort_by_key(|v| v.created_at);
+
+    // Name key, registry code once merged, ID of each vendor kept
+    let mut kept: Vec<(String, Option<String>, Uuid)> = Vec::new();
+    let mut duplicates = Vec::new();
+    for vendor in by_age {
+      let key = Self::name_key(&vendor.name);
+      let into = kept.iter_mut().find(|(name, registry_code, _)| {
+        *name == key
+          && (registry_code.is_none()
+            || vendor.registry_code.is_none()
+            || *registry_code == vendor.registry_code)
+      });
+      match into {
+        Some((_, registry_code, id)) => {
+          if registry_code.is_none() {
+            registry_code.clone_from(&vendor.registry_code);
+          }
+          duplicates.push((vendor.id, *id));
+        }
+        None => kept.push((key, vendor.registry_code.clone(), vendor.id)),
+      }
+    }
+    duplicates
+  }
+
+  /// The vendor a counterparty is: the one with its registry code, or else
+  /// one paid to the same IBAN or with the same name. Vendors with another
+  /// registry code never match.
+  pub fn find<'a>(vendors: &'a [Vendor], identity: &VendorIdentity) -> Option<&'a Vendor> {
+    let registry_code = identity
+      .registry_code
+      .as_deref()
+      .map(str::trim)
+      .filter(|code| !code.is_empty());
+    if let Some(code) = registry_code {
+      if let Some(vendor) = vendors
+        .iter()
+        .find(|v| v.registry_code.as_deref() == Some(code))
+      {
+        return Some(vendor);
+      }
+    }
+
+    let candidates = || {
+      vendors
+        .iter()
+        .filter(move |v| registry_code.is_none() || v.registry_code.is_none())
+    };
+    let iban = identity
+      .iban
+      .as_deref()
+      .and_then(|iban| Iban::new(iban).ok())
+      .map(Iban::into_inner);
+    if let Some(iban) = iban {
+      if let Some(vendor) = candidates().find(|v| v.ibans.contains(&iban)) {
+        return Some(vendor);
+      }
+    }
+    let name = Self::name_key(&identity.name);
+    candidates().find(|v| Self::name_key(&v.name) == name)
+  }
+
+  /// Take the registry code and IBAN of a counterparty the vendor was found
+  /// by, where it lacks them. Returns whether the vendor changed.
+  pub fn learn(&mut self, identity: &VendorIdentity) -> bool {
+    let mut changed = false;
+    if self.registry_code.is_none() {
+      self.registry_code = filled(identity.registry_code.clone())
+        .and_then(|code| RegistryCode::new(code).ok())
+        .map(RegistryCode::into_inner);
+      changed |= self.registry_code.is_some();
+    }
+    if let Some(iban) = identity
+      .iban
+      .as_deref()
+      .and_then(|iban| Iban::new(iban).ok())
+      .map(Iban::into_inner)
+    {
+      if !self.ibans.contains(&iban) {
+        self.ibans.push(iban);
+        changed = true;
+      }
+    }
+    if changed {
+      self.updated_at = Utc::now();
+    }
+    changed
+  }
+
+  /// VAT included in a gross amount at the vendor's default rate
+  pub fn vat_included_in(&self, gross: Decimal) -> Option<Decimal> {
+    self
+      .default_vat_rate
+      .map(|rate| (gross * rate / (Decimal::ONE_HUNDRED + rate)).round_dp(2))
+  }
+}
+
+fn filled(value: Option<String>) -> Option<String> {
+  value
+    .map(|value| value.trim().to_string())
+    .filter(|value| !value.is_empty())
+}
+
+/// What a vendor bill says about paying it
+#[derive(Debug, Clone, Default)]
+pub struct PaymentTerms {
+  pub vat_amount: Decimal,
+  pub due_date: Option<NaiveDate>,
+  pub vendor_iban: Option<String>,
+  pub payment_reference: Option<String>,
+}
+
 impl ReceivedInvoice {
   #[allow(clippy::too_many_arguments)]
   pub fn new(
@@ -146,10 +991,166 @@ impl ReceivedInvoice {
       pdf_path,
       pdf_drive_file_id: None,
       notes,
+      vat_amount: Decimal::ZERO,
+      due_date: None,
+      vendor_iban: None,
+      payment_reference: None,
+      status: PayableStatus::Unpaid,
+      paid_on: None,
+      vendor_id: None,
       created_at: now,
       updated_at: now,
     }
   }
+
+  /// Amount before VAT
+  pub fn net_amount(&self) -> Decimal {
+    self.amount - self.vat_amount
+  }
+
+  /// Still to be paid: neither paid nor settled by a bank transaction
+  pub fn is_open(&self) -> bool {
+    self.status != PayableStatus::Paid
+  }
+
+  pub fn is_overdue(&self, today: NaiveDate) -> bool {
+    self.is_open() && self.due_date.is_some_and(|due| due < today)
+  }
+
+  /// Set the VAT, due date and payment details; blank details are cleared
+  pub fn set_payment_terms(&mut self, terms: PaymentTerms) -> Result<(), ReportError> {
+    if terms.vat_amount.abs() > self.amount.abs()
+      || (!terms.vat_amount.is_zero()
+        && terms.vat_amount.is_sign_negative() != self.amount.is_sign_negative())
+    {
+      return Err(ReportError::Validation(
+        "VAT must have the sign of the amount and can't exceed it".to_string(),
+      ));
+    }
+    if let (Some(due), Some(issued)) = (terms.due_date, self.invoice_date) {
+      if due < issued {
+        return Err(ReportError::Validation(
+          "Due date can't be before the invoice date".to_string(),
+        ));
+      }
+    }
+
+    let vendor_iban = filled(terms.vendor_iban)
+      .map(|iban| {
+        Iban::new(iban)
+          .map(|iban| iban.as_str().to_string())
+          .map_err(|e| ReportError::Validation(format!("Vendor IBAN: {}", e)))
+      })
+      .transpose()?;
+
+    self.vat_amount = terms.vat_amount;
+    self.due_date = terms.due_date;
+    self.vendor_iban = vendor_iban;
+    self.payment_reference = filled(terms.payment_reference);
+    self.updated_at = Utc::now();
+    Ok(())
+  }
+
+  /// Set the status by hand
+  pub fn set_status(&mut self, status: PayableStatus) {
+    if status != self.status {
+      self.status = status;
+      self.paid_on = None;
+      self.updated_at = Utc::now();
+    }
+  }
+
+  /// Follow the debit transactions allocated to the invoice: paid once they
+  /// cover the gross amount, back to unpaid when an allocation that did is
+  /// removed. Statuses set by hand are otherwise kept. Returns whether the
+  /// invoice changed.
+  pub fn apply_payments(&mut self, paid: Decimal, last_paid_on: Option<NaiveDate>) -> bool {
+    let settled = !self.amount.is_zero() && paid >= self.amount.abs();
+    let (status, paid_on) = match (settled, self.status) {
+      (true, _) => (PayableStatus::Paid, last_paid_on),
+      (false, PayableStatus::Paid) if self.paid_on.is_some() => (PayableStatus::Unpaid, None),
+      (false, status) => (status, self.paid_on),
+    };
+    if (status, paid_on) == (self.status, self.paid_on) {
+      return false;
+    }
+
+    self.status = status;
+    self.paid_on = paid_on;
+    self.updated_at = Utc::now();
+    true
+  }
+}
+
+// Report Event - Append-only audit log entry of a report's lifecycle; kept
+// after the report is deleted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportEvent {
+  pub id: Uuid,
+  pub report_id: Uuid,
+  pub company_id: Uuid,
+  pub user_id: Option<Uuid>,
+  pub kind: ReportEventKind,
+  /// Report version the transition produced or left
+  pub version: i32,
+  /// Why the report was reopened
+  pub reason: Option<String>,
+  pub created_at: DateTime<Utc>,
+}
+
+impl ReportEvent {
+  pub fn new(
+    report: &MonthlyReport,
+    user_id: Uuid,
+    kind: ReportEventKind,
+    reason: Option<String>,
+  ) -> Self {
+    Self {
+      id: Uuid::new_v4(),
+      report_id: report.id,
+      company_id: report.company_id,
+      user_id: (!user_id.is_nil()).then_some(user_id),
+      kind,
+      version: report.version,
+      reason,
+      created_at: Utc::now(),
+    }
+  }
+}
+
+/// Folder or file uploaded to cloud storage for a report, so regenerating it
+/// reuses the folders and only uploads files whose content changed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportUpload {
+  pub report_id: Uuid,
+  /// Path below the report's month folder, e.g. "EE12 (Main)/incoming/x.pdf"
+  pub path: String,
+  /// ID of the folder or file in cloud storage
+  pub cloud_id: String,
+  /// SHA-256 of an uploaded file's content; None for folders
+  pub content_hash: Option<String>,
+  /// Report version that last uploaded it
+  pub version: i32,
+  pub uploaded_at: DateTime<Utc>,
+}
+
+impl ReportUpload {
+  pub fn new(
+    report_id: Uuid,
+    path: String,
+    cloud_id: String,
+    content_hash: Option<String>,
+    version: i32,
+  ) -> Self {
+    Self {
+      report_id,
+      path,
+      cloud_id,
+      content_hash,
+      version,
+      uploaded_at: Utc::now(),
+    }
+  }
 }
 
 #[cfg(test)]
@@ -160,18 +1161,178 @@ mod tests {
   #[test]
   fn test_monthly_report_new_defaults() {
     let company_id = Uuid::new_v4();
-    let report = MonthlyReport::new(company_id, 3, 2026, Some("EE123456789".to_string()));
+    let report = MonthlyReport::new(company_id, 3, 2026);
 
     assert_eq!(report.company_id, company_id);
     assert_eq!(report.month, 3);
     assert_eq!(report.year, 2026);
     assert_eq!(report.status, ReportStatus::Draft);
-    assert_eq!(report.bank_account_iban, Some("EE123456789".to_string()));
     assert_eq!(report.total_incoming, Decimal::ZERO);
     assert_eq!(report.total_outgoing, Decimal::ZERO);
     assert_eq!(report.transaction_count, 0);
     assert_eq!(report.matched_count, 0);
     assert!(report.drive_folder_id.is_none());
+    assert_eq!(report.version, 0);
+  }
+
+  #[test]
+  fn test_monthly_report_lifecycle() {
+    let mut report = MonthlyReport::new(Uuid::new_v4(), 3, 2026);
+    assert!(matches!(report.reopen(), Err(ReportError::NotGenerated)));
+    assert!(matches!(report.lock(), Err(ReportError::NotGenerated)));
+
+    report.ensure_editable().unwrap();
+    report.mark_generated("folder".to_string()).unwrap();
+    assert_eq!(report.status, ReportStatus::Generated);
+    assert_eq!(report.version, 1);
+    assert!(matches!(
+      report.mark_generated("folder".to_string()),
+      Err(ReportError::NotDraft)
+    ));
+    assert!(matches!(
+      report.ensure_editable(),
+      Err(ReportError::Generated)
+    ));
+
+    report.reopen().unwrap();
+    assert_eq!(report.status, ReportStatus::Draft);
+    report.ensure_editable().unwrap();
+    report.mark_generated("folder".to_string()).unwrap();
+    assert_eq!(report.version, 2);
+
+    report.lock().unwrap();
+    assert!(report.is_locked());
+    assert!(matches!(report.ensure_editable(), Err(ReportError::Locked)));
+    assert!(matches!(report.reopen(), Err(ReportError::Locked)));
+    assert!(matches!(report.lock(), Err(ReportError::Locked)));
+  }
+
+  #[test]
+  fn test_monthly_report_adds_statement_subtotals() {
+    let mut report = MonthlyReport::new(Uuid::new_v4(), 3, 2026);
+    for (incoming, outgoing, count) in
+      [(dec!(5000.50), dec!(1300.00), 4), (dec!(20.00), dec!(0), 1)]
+    {
+      let mut statement = BankStatement::new(
+        report.id,
+        None,
+        "EE123456789".to_string(),
+        NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+      );
+      statement.total_incoming = incoming;
+      statement.total_outgoing = outgoing;
+      statement.transaction_count = count;
+      report.add_statement(&statement);
+    }
+
+    assert_eq!(report.total_incoming, dec!(5020.50));
+    assert_eq!(report.total_outgoing, dec!(1300.00));
+    assert_eq!(report.transaction_count, 5);
+  }
+
+  #[test]
+  fn test_bank_statement_balance_difference() {
+    let mut statement = BankStatement::new(
+      Uuid::new_v4(),
+      None,
+      "EE123456789".to_string(),
+      NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+      NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+    );
+    statement.total_incoming = dec!(5000.50);
+    statement.total_outgoing = dec!(1300.00);
+    assert_eq!(statement.balance_difference(), None);
+    assert!(!statement.is_balanced());
+
+    statement.opening_balance = Some(dec!(1000.00));
+    statement.closing_balance = Some(dec!(4700.50));
+    assert_eq!(statement.balance_difference(), Some(Decimal::ZERO));
+    assert!(statement.is_balanced());
+
+    statement.closing_balance = Some(dec!(4600.50));
+    assert_eq!(statement.balance_difference(), Some(dec!(100.00)));
+    assert!(!statement.is_balanced());
+  }
+
+  #[test]
+  fn test_statement_gap_find() {
+    let statement = |start: (u32, u32), end: (u32, u32), iban: &str| {
+      BankStatement::new(
+        Uuid::new_v4(),
+        None,
+        iban.to_string(),
+        NaiveDate::from_ymd_opt(2026, start.0, start.1).unwrap(),
+        NaiveDate::from_ymd_opt(2026, end.0, end.1).unwrap(),
+      )
+    };
+    let january = statement((1, 1), (1, 31), "EE1");
+    // Overlaps January by a day
+    let february = statement((1, 31), (2, 20), "EE1");
+    let april = statement((4, 1), (4, 30), "EE1");
+    let other_account = statement((3, 1), (3, 31), "EE2");
+
+    let gaps = StatementGap::find(&[april.clone(), other_account, february.clone(), january]);
+
+    assert_eq!(
+      gaps,
+      vec![StatementGap {
+        bank_account_iban: "EE1".to_string(),
+        from: NaiveDate::from_ymd_opt(2026, 2, 21).unwrap(),
+        to: NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+        previous_report_id: february.report_id,
+        next_report_id: april.report_id,
+      }]
+    );
+  }
+
+  #[test]
+  fn test_parsed_transaction_is_duplicate_of() {
+    let date = NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+    let parsed = ParsedTransaction {
+      row_number: 2,
+      client_account: "EE123456789".to_string(),
+      date,
+      counterparty_name: None,
+      counterparty_account: None,
+      direction: TransactionDirection::Debit,
+      amount: dec!(-100.00),
+      reference_number: None,
+      description: None,
+      currency: "EUR".to_string(),
+      registry_code: None,
+      document_number: Some("17".to_string()),
+      archive_id: Some("2026033100123".to_string()),
+    };
+    let mut existing = BankTransaction::new(
+      Uuid::new_v4(),
+      40,
+      date,
+      None,
+      None,
+      TransactionDirection::Debit,
+      dec!(-100.00),
+      None,
+      None,
+      "EUR".to_string(),
+      None,
+    );
+    existing.document_number = Some("17".to_string());
+    existing.archive_id = Some("2026033100123".to_string());
+    assert!(parsed.is_duplicate_of(&existing));
+
+    // Archive IDs win over document numbers when both sides have one
+    existing.archive_id = Some("2026033100124".to_string());
+    assert!(!parsed.is_duplicate_of(&existing));
+
+    existing.archive_id = None;
+    assert!(parsed.is_duplicate_of(&existing));
+    existing.amount = dec!(-200.00);
+    assert!(!parsed.is_duplicate_of(&existing));
+
+    existing.document_number = None;
+    existing.amount = dec!(-100.00);
+    assert!(!parsed.is_duplicate_of(&existing));
   }
 
   #[test]
@@ -197,8 +1358,8 @@ mod tests {
     assert_eq!(tx.date, date);
     assert_eq!(tx.direction, TransactionDirection::Debit);
     assert_eq!(tx.amount, dec!(1300.00));
-    assert!(tx.matched_invoice_id.is_none());
-    assert!(tx.matched_received_invoice_id.is_none());
+    assert!(tx.allocations.is_empty());
+    assert_eq!(tx.unallocated_amount(), dec!(1300.00));
   }
 
   #[test]
@@ -234,7 +1395,8 @@ mod tests {
       "EUR".to_string(),
       None,
     );
-    tx.matched_invoice_id = Some(Uuid::new_v4());
+    tx.allocate(Some(Uuid::new_v4()), None, dec!(500.00))
+      .unwrap();
     assert!(tx.is_matched());
   }
 
@@ -253,8 +1415,227 @@ mod tests {
       "EUR".to_string(),
       None,
     );
-    tx.matched_received_invoice_id = Some(Uuid::new_v4());
+    tx.allocate(None, Some(Uuid::new_v4()), dec!(500.00))
+      .unwrap();
+    assert!(tx.is_matched());
+  }
+
+  #[test]
+  fn test_bank_transaction_split_allocation() {
+    let mut tx = BankTransaction::new(
+      Uuid::new_v4(),
+      1,
+      NaiveDate::from_ymd_opt(2026, 3, 15).unwrap(),
+      None,
+      None,
+      TransactionDirection::Credit,
+      dec!(900.00),
+      None,
+      None,
+      "EUR".to_string(),
+      None,
+    );
+    let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+
+    tx.allocate(Some(first), None, dec!(400.00)).unwrap();
+    assert!(!tx.is_matched());
+    assert_eq!(tx.unallocated_amount(), dec!(500.00));
+
+    assert!(tx.allocate(Some(first), None, dec!(100.00)).is_err());
+    assert!(tx.allocate(Some(second), None, dec!(500.01)).is_err());
+    assert!(tx.allocate(Some(second), None, dec!(0)).is_err());
+    assert!(tx.allocate(Some(second), Some(first), dec!(1)).is_err());
+
+    tx.allocate(Some(second), None, dec!(500.00)).unwrap();
     assert!(tx.is_matched());
+    assert_eq!(tx.allocated_amount(), dec!(900.00));
+    assert_eq!(tx.unallocated_amount(), Decimal::ZERO);
+  }
+
+  #[test]
+  fn test_bank_transaction_is_resolved_with_category() {
+    let mut tx = BankTransaction::new(
+      Uuid::new_v4(),
+      1,
+      NaiveDate::from_ymd_opt(2026, 3, 15).unwrap(),
+      Some("Swedbank AS".to_string()),
+      None,
+      TransactionDirection::Debit,
+      dec!(2.50),
+      None,
+      Some("Monthly fee".to_string()),
+      "EUR".to_string(),
+      None,
+    );
+    assert!(!tx.is_resolved());
+
+    tx.category_id = Some(Uuid::new_v4());
+    assert!(tx.is_resolved());
+    assert!(!tx.is_matched());
+  }
+
+  #[test]
+  fn test_category_suggestion_prefers_most_specific_key() {
+    let company_id = Uuid::new_v4();
+    let (fees, salaries) = (Uuid::new_v4(), Uuid::new_v4());
+    let tx = |name: &str, account: Option<&str>, direction| {
+      BankTransaction::new(
+        Uuid::new_v4(),
+        1,
+        NaiveDate::from_ymd_opt(2026, 3, 15).unwrap(),
+        Some(name.to_string()),
+        account.map(str::to_string),
+        direction,
+        dec!(10.00),
+        None,
+        None,
+        "EUR".to_string(),
+        None,
+      )
+    };
+
+    let mut suggestions = CategorySuggestion::learn(
+      company_id,
+      &tx("Swedbank AS", None, TransactionDirection::Debit),
+      fees,
+    );
+    suggestions.extend(CategorySuggestion::learn(
+      company_id,
+      &tx("Jane Doe", Some("EE12 3456"), TransactionDirection::Debit),
+      salaries,
+    ));
+
+    // Name matches after normalization
+    let fee = tx("SWEDBANK  AS", None, TransactionDirection::Debit);
+    assert_eq!(CategorySuggestion::suggest(&suggestions, &fee), Some(fees));
+
+    // The account wins over a name learned for another category
+    let salary = tx("Swedbank AS", Some("ee123456"), TransactionDirection::Debit);
+    assert_eq!(
+      CategorySuggestion::suggest(&suggestions, &salary),
+      Some(salaries)
+    );
+
+    // Suggestions never cross directions
+    let refund = tx("Swedbank AS", None, TransactionDirection::Credit);
+    assert_eq!(CategorySuggestion::suggest(&suggestions, &refund), None);
+  }
+
+  fn rule(position: i32, conditions: RuleConditions, action: RuleAction) -> MatchingRule {
+    MatchingRule::new(
+      Uuid::new_v4(),
+      format!("Rule {}", position),
+      position,
+      conditions,
+      action,
+    )
+    .unwrap()
+  }
+
+  #[test]
+  fn test_matching_rule_validation() {
+    let company_id = Uuid::new_v4();
+    let new = |conditions| {
+      MatchingRule::new(
+        company_id,
+        "Rule".to_string(),
+        1,
+        conditions,
+        RuleAction::Ignore,
+      )
+    };
+
+    assert!(new(RuleConditions::default()).is_err());
+    assert!(
+      new(RuleConditions {
+        description_pattern: Some("   ".to_string()),
+        ..Default::default()
+      })
+      .is_err()
+    );
+    assert!(
+      new(RuleConditions {
+        description_pattern: Some("fee(".to_string()),
+        ..Default::default()
+      })
+      .is_err()
+    );
+    assert!(
+      new(RuleConditions {
+        min_amount: Some(dec!(10)),
+        max_amount: Some(dec!(5)),
+        ..Default::default()
+      })
+      .is_err()
+    );
+
+    let rule = new(RuleConditions {
+      counterparty_iban: Some("ee12 3456".to_string()),
+      ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(
+      rule.conditions.counterparty_iban.as_deref(),
+      Some("EE123456")
+    );
+  }
+
+  #[test]
+  fn test_rule_evaluator_fires_first_matching_rule_in_order() {
+    let tx = BankTransaction::new(
+      Uuid::new_v4(),
+      1,
+      NaiveDate::from_ymd_opt(2026, 3, 15).unwrap(),
+      Some("Swedbank AS".to_string()),
+      Some("EE12 3456".to_string()),
+      TransactionDirection::Debit,
+      dec!(-2.50),
+      None,
+      Some("Monthly service FEE".to_string()),
+      "EUR".to_string(),
+      None,
+    );
+
+    let fee = rule(
+      2,
+      RuleConditions {
+        description_pattern: Some("service fee".to_string()),
+        max_amount: Some(dec!(10)),
+        ..Default::default()
+      },
+      RuleAction::FlagForReview,
+    );
+    let incoming = rule(
+      1,
+      RuleConditions {
+        counterparty_iban: Some("EE123456".to_string()),
+        direction: Some(TransactionDirection::Credit),
+        ..Default::default()
+      },
+      RuleAction::Ignore,
+    );
+    let large = rule(
+      0,
+      RuleConditions {
+        min_amount: Some(dec!(100)),
+        ..Default::default()
+      },
+      RuleAction::Ignore,
+    );
+    let account = rule(
+      3,
+      RuleConditions {
+        counterparty_iban: Some("EE123456".to_string()),
+        ..Default::default()
+      },
+      RuleAction::Ignore,
+    );
+
+    let rules = vec![account.clone(), fee.clone(), incoming, large];
+    let evaluator = RuleEvaluator::new(&rules);
+
+    assert_eq!(evaluator.first_match(&tx).map(|r| r.id), Some(fee.id));
+    assert!(evaluator.matches(account.id, &tx));
   }
 
   #[test]
@@ -275,6 +1656,252 @@ mod tests {
     assert_eq!(inv.vendor_name, "Vendor Co");
     assert_eq!(inv.amount, dec!(2500.00));
     assert!(inv.pdf_drive_file_id.is_none());
+    assert_eq!(inv.status, PayableStatus::Unpaid);
+    assert_eq!(inv.net_amount(), dec!(2500.00));
+  }
+
+  fn received_invoice(amount: Decimal) -> ReceivedInvoice {
+    ReceivedInvoice::new(
+      Uuid::new_v4(),
+      "Vendor Co".to_string(),
+      amount,
+      "EUR".to_string(),
+      Some(NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()),
+      None,
+      "/uploads/invoice.pdf".to_string(),
+      None,
+    )
+  }
+
+  #[test]
+  fn test_received_invoice_payment_terms() {
+    let mut inv = received_invoice(dec!(122.00));
+    inv
+      .set_payment_terms(PaymentTerms {
+        vat_amount: dec!(22.00),
+        due_date: NaiveDate::from_ymd_opt(2026, 3, 15),
+        vendor_iban: Some(" ee38 2200 2210 2014 5685 ".to_string()),
+        payment_reference: Some("  ".to_string()),
+      })
+      .unwrap();
+
+    assert_eq!(inv.net_amount(), dec!(100.00));
+    assert_eq!(inv.vendor_iban.as_deref(), Some("EE382200221020145685"));
+    assert!(inv.payment_reference.is_none());
+    assert!(!inv.is_overdue(NaiveDate::from_ymd_opt(2026, 3, 15).unwrap()));
+    assert!(inv.is_overdue(NaiveDate::from_ymd_opt(2026, 3, 16).unwrap()));
+
+    let too_much_vat = PaymentTerms {
+      vat_amount: dec!(130.00),
+      ..Default::default()
+    };
+    assert!(inv.set_payment_terms(too_much_vat).is_err());
+    let due_before_issue = PaymentTerms {
+      due_date: NaiveDate::from_ymd_opt(2026, 2, 1),
+      ..Default::default()
+    };
+    assert!(inv.set_payment_terms(due_before_issue).is_err());
+    let bad_iban = PaymentTerms {
+      vendor_iban: Some("EE00 1234".to_string()),
+      ..Default::default()
+    };
+    assert!(inv.set_payment_terms(bad_iban).is_err());
+    assert_eq!(inv.vat_amount, dec!(22.00));
+  }
+
+  #[test]
+  fn test_received_invoice_follows_payments() {
+    let mut inv = received_invoice(dec!(122.00));
+    let paid_on = NaiveDate::from_ymd_opt(2026, 3, 10);
+    inv.set_status(PayableStatus::Scheduled);
+
+    assert!(!inv.apply_payments(dec!(50.00), paid_on));
+    assert_eq!(inv.status, PayableStatus::Scheduled);
+
+    assert!(inv.apply_payments(dec!(122.00), paid_on));
+    assert_eq!(inv.status, PayableStatus::Paid);
+    assert_eq!(inv.paid_on, paid_on);
+    assert!(!inv.is_overdue(NaiveDate::from_ymd_opt(2027, 1, 1).unwrap()));
+
+    // Unmatching the payment reopens it
+    assert!(inv.apply_payments(Decimal::ZERO, None));
+    assert_eq!(inv.status, PayableStatus::Unpaid);
+    assert!(inv.paid_on.is_none());
+
+    // A bill marked paid by hand stays paid
+    inv.set_status(PayableStatus::Paid);
+    assert!(!inv.apply_payments(Decimal::ZERO, None));
+    assert_eq!(inv.status, PayableStatus::Paid);
+  }
+
+  fn vendor(name: &str, registry_code: Option<&str>) -> Vendor {
+    Vendor::new(
+      Uuid::new_v4(),
+      VendorProfile {
+        name: name.to_string(),
+        registry_code: registry_code.map(str::to_string),
+        ..Default::default()
+      },
+    )
+    .unwrap()
+  }
+
+  fn identity(name: &str, registry_code: Option<&str>, iban: Option<&str>) -> VendorIdentity {
+    VendorIdentity {
+      name: name.to_string(),
+      registry_code: registry_code.map(str::to_string),
+      iban: iban.map(str::to_string),
+    }
+  }
+
+  #[test]
+  fn test_vendor_profile_validation() {
+    let mut v = vendor("  Telia   Eesti AS ", None);
+    assert_eq!(v.name, "Telia Eesti AS");
+
+    let profile = VendorProfile {
+      name: "Telia Eesti AS".to_string(),
+      registry_code: Some(" 10234957 ".to_string()),
+      vat_number: Some(String::new()),
+      ibans: vec![
+        "ee38 2200 2210 2014 5685".to_string(),
+        "EE382200221020145685".to_string(),
+        " ".to_string(),
+      ],
+      default_category_id: None,
+      default_vat_rate: Some(dec!(24)),
+    };
+    v.update(profile.clone()).unwrap();
+    assert_eq!(v.registry_code.as_deref(), Some("10234957"));
+    assert!(v.vat_number.is_none());
+    assert_eq!(v.ibans, vec!["EE382200221020145685".to_string()]);
+
+    let mut bad_iban = profile.clone();
+    bad_iban.ibans = vec!["EE00123".to_string()];
+    assert!(matches!(
+      v.update(bad_iban),
+      Err(ReportError::Validation(_))
+    ));
+    let mut bad_rate = profile.clone();
+    bad_rate.default_vat_rate = Some(dec!(120));
+    assert!(matches!(
+      v.update(bad_rate),
+      Err(ReportError::Validation(_))
+    ));
+    let mut no_name = profile;
+    no_name.name = "   ".to_string();
+    assert!(matches!(v.update(no_name), Err(ReportError::Validation(_))));
+  }
+
+  #[test]
+  fn test_vendor_find_prefers_registry_code() {
+    let mut by_iban = vendor("Telia", None);
+    by_iban.ibans = vec!["EE382200221020145685".to_string()];
+    let vendors = vec![
+      vendor("Telia Eesti AS", Some("10234957")),
+      by_iban,
+      vendor("Elisa Eesti AS", Some("10178070")),
+      vendor("Bolt", None),
+    ];
+
+    // Same registry code wins over name and IBAN
+    let found = Vendor::find(&vendors, &identity("TELIA", Some("10234957"), None));
+    assert_eq!(found.unwrap().id, vendors[0].id);
+
+    // Then the IBAN, then the name regardless of case and spacing
+    let found = Vendor::find(
+      &vendors,
+      &identity("Whoever", None, Some("ee38 2200 2210 2014 5685")),
+    );
+    assert_eq!(found.unwrap().id, vendors[1].id);
+    let found = Vendor::find(&vendors, &identity("TELIA  EESTI AS", None, None));
+    assert_eq!(found.unwrap().id, vendors[0].id);
+
+    // A vendor with another registry code is a different vendor
+    assert!(
+      Vendor::find(
+        &vendors,
+        &identity("Elisa Eesti AS", Some("99999999"), None)
+      )
+      .is_none()
+    );
+    let found = Vendor::find(&vendors, &identity("bolt", Some("12417834"), None));
+    assert_eq!(found.unwrap().id, vendors[3].id);
+  }
+
+  #[test]
+  fn test_vendor_same_name_duplicates() {
+    let mut vendors = vec![
+      vendor("Tallinna Küte AS", None),
+      vendor("Elisa", Some("10178070")),
+      vendor("Telia", Some("10234957")),
+      vendor("Bolt", None),
+    ];
+    // Names as the SQL backfill kept them: case and inner spacing intact
+    let mut same = vendor("x", None);
+    same.name = "TALLINNA  KÜTE AS".to_string();
+    vendors.push(same);
+    let mut other_code = vendor("x", Some("99999999"));
+    other_code.name = "ELISA".to_string();
+    vendors.push(other_code);
+    for (i, vendor) in vendors.iter_mut().enumerate() {
+      vendor.created_at += chrono::Duration::seconds(i as i64);
+    }
+
+    assert_eq!(
+      Vendor::same_name_duplicates(&vendors),
+      vec![(vendors[4].id, vendors[0].id)]
+    );
+  }
+
+  #[test]
+  fn test_vendor_learns_from_counterparty() {
+    let mut v = vendor("Bolt", None);
+    assert!(v.learn(&identity(
+      "Bolt",
+      Some("12417834"),
+      Some("EE382200221020145685")
+    )));
+    assert_eq!(v.registry_code.as_deref(), Some("12417834"));
+    assert_eq!(v.ibans.len(), 1);
+
+    // Known details are kept, invalid IBANs ignored
+    assert!(!v.learn(&identity("Bolt", Some("1"), Some("EE382200221020145685"))));
+    assert!(!v.learn(&identity("Bolt", None, Some("not an iban"))));
+    assert_eq!(v.registry_code.as_deref(), Some("12417834"));
+  }
+
+  #[test]
+  fn test_vendor_identity_of_transaction() {
+    let mut tx = BankTransaction::new(
+      Uuid::new_v4(),
+      1,
+      NaiveDate::from_ymd_opt(2026, 3, 5).unwrap(),
+      Some(" TELIA EESTI AS ".to_string()),
+      Some("EE382200221020145685".to_string()),
+      TransactionDirection::Debit,
+      dec!(-24.40),
+      None,
+      None,
+      "EUR".to_string(),
+      Some("10234957".to_string()),
+    );
+    let id = VendorIdentity::of_transaction(&tx).unwrap();
+    assert_eq!(id.name, "TELIA EESTI AS");
+    assert_eq!(id.registry_code.as_deref(), Some("10234957"));
+
+    // Incoming payments are from customers, not vendors
+    tx.direction = TransactionDirection::Credit;
+    assert!(VendorIdentity::of_transaction(&tx).is_none());
+  }
+
+  #[test]
+  fn test_vendor_vat_included_in() {
+    let mut v = vendor("Telia", None);
+    assert!(v.vat_included_in(dec!(124.00)).is_none());
+    v.default_vat_rate = Some(dec!(24));
+    assert_eq!(v.vat_included_in(dec!(124.00)), Some(dec!(24.00)));
+    assert_eq!(v.vat_included_in(dec!(10.00)), Some(dec!(1.94)));
   }
 }
 
@@ -292,4 +1919,40 @@ pub struct ParsedTransaction {
   pub description: Option<String>,
   pub currency: String,
   pub registry_code: Option<String>,
+  pub document_number: Option<String>,
+  pub archive_id: Option<String>,
+}
+
+impl ParsedTransaction {
+  /// Whether `existing` was imported from the same bank row: same archive ID,
+  /// or when either lacks one, same document number, date and amount
+  pub fn is_duplicate_of(&self, existing: &BankTransaction) -> bool {
+    if let (Some(archive_id), Some(other)) = (&self.archive_id, &existing.archive_id) {
+      return archive_id == other;
+    }
+    match (&self.document_number, &existing.document_number) {
+      (Some(number), Some(other)) => {
+        number == other && self.date == existing.date && self.amount == existing.amount
+      }
+      _ => false,
+    }
+  }
+}
+
+/// Bank statement file as parsed, before being persisted
+#[derive(Debug, Clone, Default)]
+pub struct ParsedStatement {
+  pub transactions: Vec<ParsedTransaction>,
+  pub opening_balance: Option<Decimal>,
+  pub closing_balance: Option<Decimal>,
+  /// Dates of the opening and closing balance rows
+  pub period_start: Option<NaiveDate>,
+  pub period_end: Option<NaiveDate>,
+}
+
+impl ParsedStatement {
+  /// IBAN of the account the statement belongs to
+  pub fn account_iban(&self) -> Option<&str> {
+    self.transactions.first().map(|t| t.client_account.as_str())
+  }
 }
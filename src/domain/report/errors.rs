@@ -1,6 +1,9 @@
 use thiserror::Error;
 
 use crate::domain::auth::errors::RepositoryError;
+use crate::domain::document::DocumentError;
+use crate::domain::retention::RetentionError;
+use crate::domain::storage::StorageError;
 
 #[derive(Debug, Error)]
 pub enum ReportError {
@@ -13,21 +16,57 @@ pub enum ReportError {
   #[error("Received invoice not found")]
   ReceivedInvoiceNotFound,
 
+  #[error("Vendor not found")]
+  VendorNotFound,
+
+  #[error("A vendor with this registry code already exists")]
+  DuplicateVendor,
+
+  #[error("Category not found")]
+  CategoryNotFound,
+
+  #[error("A category with this name already exists")]
+  DuplicateCategory,
+
+  #[error("Category is used by transactions")]
+  CategoryInUse,
+
+  #[error("Matching rule not found")]
+  RuleNotFound,
+
   #[error("A report for this month already exists")]
   DuplicateReport,
 
+  #[error("A statement of this bank account is already imported for this month")]
+  DuplicateStatement,
+
   #[error("Transaction is already matched")]
   AlreadyMatched,
 
   #[error("Transaction is not matched")]
   NotMatched,
 
+  #[error("Received invoice is matched to bank transactions")]
+  ReceivedInvoiceMatched,
+
   #[error("Cannot match: direction mismatch")]
   DirectionMismatch,
 
   #[error("Report must be in draft status")]
   NotDraft,
 
+  #[error("Report has not been generated yet")]
+  NotGenerated,
+
+  #[error("Report is generated; reopen it to make changes")]
+  Generated,
+
+  #[error("Report is signed off and locked")]
+  Locked,
+
+  #[error("Insufficient permissions")]
+  InsufficientPermissions,
+
   #[error("No matched transactions to generate")]
   NoMatchedTransactions,
 
@@ -43,6 +82,9 @@ pub enum ReportError {
   #[error("File error: {0}")]
   FileError(String),
 
+  #[error("Ledger error: {0}")]
+  Ledger(String),
+
   #[error("Repository error: {0}")]
   Repository(#[from] RepositoryError),
 }
@@ -52,3 +94,28 @@ impl From<sqlx::Error> for ReportError {
     ReportError::Repository(RepositoryError::from(error))
   }
 }
+
+impl From<StorageError> for ReportError {
+  fn from(error: StorageError) -> Self {
+    ReportError::CloudStorage(error.to_string())
+  }
+}
+
+impl From<DocumentError> for ReportError {
+  fn from(error: DocumentError) -> Self {
+    match error {
+      DocumentError::Validation(message) => ReportError::Validation(message),
+      DocumentError::Repository(e) => ReportError::Repository(e),
+      other => ReportError::FileError(other.to_string()),
+    }
+  }
+}
+
+impl From<RetentionError> for ReportError {
+  fn from(error: RetentionError) -> Self {
+    match error {
+      RetentionError::Repository(e) => ReportError::Repository(e),
+      other => ReportError::Validation(other.to_string()),
+    }
+  }
+}
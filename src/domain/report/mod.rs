@@ -4,11 +4,22 @@ pub mod ports;
 pub mod services;
 pub mod value_objects;
 
-pub use entities::{BankTransaction, MonthlyReport, ReceivedInvoice};
+pub use entities::{
+  BankStatement, BankTransaction, CategorySuggestion, MatchAllocation, MatchingRule, MonthlyReport,
+  PaymentTerms, ReceivedInvoice, ReportEvent, ReportUpload, RuleConditions, RuleEvaluator,
+  StatementGap, TransactionCategory, Vendor, VendorIdentity, VendorProfile,
+};
 pub use errors::ReportError;
 pub use ports::{
-  BankStatementParser, BankTransactionRepository, ExtractedInvoiceData, InvoiceDataExtractor,
-  MonthlyReportRepository, ReceivedInvoiceRepository, ReportCloudStorage,
+  ArchiveEntry, BankStatementParser, BankStatementRepository, BankTransactionRepository,
+  CategorySuggestionRepository, ExtractedInvoiceData, InvoiceDataExtractor,
+  MatchAllocationRepository, MatchingRuleRepository, MonthlyReportRepository,
+  ReceivedInvoiceRepository, ReportArchiveWriter, ReportCloudStorage, ReportEventRepository,
+  ReportIndexRow, ReportIndexWriter, ReportStorageResolver, ReportUploadRepository,
+  TransactionCategoryRepository, VendorRepository,
+};
+pub use services::{ReportService, ReportServiceDependencies, RuleTestMatch};
+pub use value_objects::{
+  CategoryMatchField, MatchDocument, PayableStatus, ReportEventKind, ReportMonth, ReportStatus,
+  RuleAction, TransactionDirection,
 };
-pub use services::ReportService;
-pub use value_objects::{ReportMonth, ReportStatus, TransactionDirection};
@@ -1,9 +1,15 @@
 use async_trait::async_trait;
 use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use super::{
-  entities::{BankTransaction, MonthlyReport, ParsedTransaction, ReceivedInvoice},
+  entities::{
+    BankStatement, BankTransaction, CategorySuggestion, MatchAllocation, MatchingRule,
+    MonthlyReport, ParsedStatement, ReceivedInvoice, ReportEvent, ReportUpload,
+    TransactionCategory, Vendor,
+  },
   errors::ReportError,
 };
 
@@ -22,29 +28,128 @@ pub trait MonthlyReportRepository: Send + Sync {
   async fn delete(&self, id: Uuid) -> Result<(), ReportError>;
 }
 
+/// Append-only store of report lifecycle events; there is no update or delete
+#[async_trait]
+pub trait ReportEventRepository: Send + Sync {
+  async fn append(&self, event: ReportEvent) -> Result<ReportEvent, ReportError>;
+  /// Newest first
+  async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<ReportEvent>, ReportError>;
+}
+
+/// Folders and files uploaded for reports, one entry per path
+#[async_trait]
+pub trait ReportUploadRepository: Send + Sync {
+  async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<ReportUpload>, ReportError>;
+  /// Insert the upload or replace the one with the same report and path
+  async fn upsert(&self, upload: ReportUpload) -> Result<ReportUpload, ReportError>;
+  async fn delete(&self, report_id: Uuid, path: &str) -> Result<(), ReportError>;
+}
+
+/// Statements of the bank accounts imported into monthly reports
+#[async_trait]
+pub trait BankStatementRepository: Send + Sync {
+  async fn create(&self, statement: BankStatement) -> Result<BankStatement, ReportError>;
+  /// Ordered by IBAN
+  async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<BankStatement>, ReportError>;
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<BankStatement>, ReportError>;
+}
+
+/// Transactions are returned with their match allocations
 #[async_trait]
 pub trait BankTransactionRepository: Send + Sync {
+  /// Inserts the transactions only; their allocations are stored separately
   async fn create_many(
     &self,
     transactions: Vec<BankTransaction>,
   ) -> Result<Vec<BankTransaction>, ReportError>;
   async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<BankTransaction>, ReportError>;
+  /// Transactions of all the company's statements dated within the range
+  /// (inclusive), ordered by date
+  async fn find_by_company_and_date_range(
+    &self,
+    company_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+  ) -> Result<Vec<BankTransaction>, ReportError>;
   async fn find_by_id(&self, id: Uuid) -> Result<Option<BankTransaction>, ReportError>;
-  async fn update_match(
+  async fn update_receipt_path(
     &self,
     transaction_id: Uuid,
-    invoice_id: Option<Uuid>,
-    received_invoice_id: Option<Uuid>,
+    receipt_path: Option<String>,
   ) -> Result<(), ReportError>;
-  async fn clear_match(&self, transaction_id: Uuid) -> Result<(), ReportError>;
-  async fn update_receipt_path(
+  async fn update_category(
     &self,
     transaction_id: Uuid,
-    receipt_path: Option<String>,
+    category_id: Option<Uuid>,
+  ) -> Result<(), ReportError>;
+  async fn count_by_category_id(&self, category_id: Uuid) -> Result<i64, ReportError>;
+  async fn update_rule_outcome(
+    &self,
+    transaction_id: Uuid,
+    rule_id: Option<Uuid>,
+    ignored: bool,
+    needs_review: bool,
+  ) -> Result<(), ReportError>;
+  async fn update_vendor(
+    &self,
+    transaction_id: Uuid,
+    vendor_id: Option<Uuid>,
   ) -> Result<(), ReportError>;
+  /// Payments to a vendor, newest first
+  async fn find_by_vendor_id(&self, vendor_id: Uuid) -> Result<Vec<BankTransaction>, ReportError>;
+  /// Deletes the transactions and their allocations
   async fn delete_by_report_id(&self, report_id: Uuid) -> Result<(), ReportError>;
 }
 
+#[async_trait]
+pub trait MatchAllocationRepository: Send + Sync {
+  async fn create(&self, allocation: MatchAllocation) -> Result<MatchAllocation, ReportError>;
+  async fn find_by_invoice_id(&self, invoice_id: Uuid)
+  -> Result<Vec<MatchAllocation>, ReportError>;
+  async fn find_by_received_invoice_id(
+    &self,
+    received_invoice_id: Uuid,
+  ) -> Result<Vec<MatchAllocation>, ReportError>;
+  /// Allocations of all the company's bank transactions
+  async fn find_by_company_id(&self, company_id: Uuid)
+  -> Result<Vec<MatchAllocation>, ReportError>;
+  async fn delete_by_transaction_id(&self, transaction_id: Uuid) -> Result<(), ReportError>;
+}
+
+#[async_trait]
+pub trait TransactionCategoryRepository: Send + Sync {
+  async fn create(&self, category: TransactionCategory)
+  -> Result<TransactionCategory, ReportError>;
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<TransactionCategory>, ReportError>;
+  /// Categories ordered by name
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<TransactionCategory>, ReportError>;
+  async fn delete(&self, id: Uuid) -> Result<(), ReportError>;
+}
+
+#[async_trait]
+pub trait MatchingRuleRepository: Send + Sync {
+  async fn create(&self, rule: MatchingRule) -> Result<MatchingRule, ReportError>;
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<MatchingRule>, ReportError>;
+  /// Rules in evaluation order
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<MatchingRule>, ReportError>;
+  async fn update_position(&self, id: Uuid, position: i32) -> Result<(), ReportError>;
+  async fn delete(&self, id: Uuid) -> Result<(), ReportError>;
+}
+
+#[async_trait]
+pub trait CategorySuggestionRepository: Send + Sync {
+  /// Insert or replace the suggestion for its company, direction and key.
+  /// Choosing the same category again increments `times_used`.
+  async fn record(&self, suggestion: CategorySuggestion) -> Result<(), ReportError>;
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<CategorySuggestion>, ReportError>;
+}
+
 #[async_trait]
 pub trait ReceivedInvoiceRepository: Send + Sync {
   async fn create(&self, invoice: ReceivedInvoice) -> Result<ReceivedInvoice, ReportError>;
@@ -61,12 +166,33 @@ pub trait ReceivedInvoiceRepository: Send + Sync {
     &self,
     company_id: Uuid,
   ) -> Result<Vec<ReceivedInvoice>, ReportError>;
+  /// Invoices not yet paid, soonest due first and those without a due
+  /// date last
+  async fn find_open_by_company(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<ReceivedInvoice>, ReportError>;
+  async fn find_by_vendor_id(&self, vendor_id: Uuid) -> Result<Vec<ReceivedInvoice>, ReportError>;
+  /// Save the payment terms, status and vendor
+  async fn update(&self, invoice: ReceivedInvoice) -> Result<ReceivedInvoice, ReportError>;
   async fn delete(&self, id: Uuid) -> Result<(), ReportError>;
 }
 
+#[async_trait]
+pub trait VendorRepository: Send + Sync {
+  async fn create(&self, vendor: Vendor) -> Result<Vendor, ReportError>;
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<Vendor>, ReportError>;
+  /// A company's vendors by name
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<Vendor>, ReportError>;
+  async fn update(&self, vendor: Vendor) -> Result<Vendor, ReportError>;
+  /// Move the received invoices and transactions of vendor `from_id` to
+  /// `into`, save `into` and delete the other vendor
+  async fn merge(&self, from_id: Uuid, into: Vendor) -> Result<Vendor, ReportError>;
+}
+
 /// Port for parsing bank statement CSV files
 pub trait BankStatementParser: Send + Sync {
-  fn parse(&self, csv_content: &[u8]) -> Result<Vec<ParsedTransaction>, ReportError>;
+  fn parse(&self, csv_content: &[u8]) -> Result<ParsedStatement, ReportError>;
 }
 
 /// Extracted invoice data from a PDF file — all fields optional (best-effort)
@@ -95,4 +221,58 @@ pub trait ReportCloudStorage: Send + Sync {
     local_path: &str,
     mime_type: &str,
   ) -> Result<String, ReportError>;
+  /// Replace the content of an uploaded file, keeping its ID
+  async fn update_file(
+    &self,
+    file_id: &str,
+    local_path: &str,
+    mime_type: &str,
+  ) -> Result<String, ReportError>;
+  /// Remove an uploaded file; one that is already gone counts as removed
+  async fn delete_file(&self, file_id: &str) -> Result<(), ReportError>;
+}
+
+/// Port for looking up the storage a company's reports go to, as configured
+/// in its settings
+#[async_trait]
+pub trait ReportStorageResolver: Send + Sync {
+  async fn for_company(&self, company_id: Uuid)
+  -> Result<Arc<dyn ReportCloudStorage>, ReportError>;
+}
+
+/// One line of a report archive's transaction index: a transaction with one
+/// of the documents it was matched to, or on its own when nothing was
+#[derive(Debug, Clone)]
+pub struct ReportIndexRow {
+  pub date: NaiveDate,
+  pub account: String,
+  pub counterparty: Option<String>,
+  pub description: Option<String>,
+  /// Signed, negative for money going out
+  pub amount: Decimal,
+  pub currency: String,
+  pub status: String,
+  pub category: Option<String>,
+  pub document: Option<String>,
+  /// Part of the amount allocated to the document
+  pub allocated: Option<Decimal>,
+  /// Path of the document's file inside the archive
+  pub file: Option<String>,
+}
+
+/// Port for writing the transaction index of a report archive
+pub trait ReportIndexWriter: Send + Sync {
+  fn write(&self, rows: &[ReportIndexRow]) -> Result<Vec<u8>, ReportError>;
+}
+
+/// File to put in a report archive, at a path relative to its root
+#[derive(Debug, Clone)]
+pub struct ArchiveEntry {
+  pub path: String,
+  pub content: Vec<u8>,
+}
+
+/// Port for packing a report's files into a single downloadable archive
+pub trait ReportArchiveWriter: Send + Sync {
+  fn write(&self, entries: &[ArchiveEntry]) -> Result<Vec<u8>, ReportError>;
 }
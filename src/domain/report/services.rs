@@ -1,32 +1,81 @@
-use std::sync::Arc;
+use std::{
+  collections::{HashMap, HashSet},
+  sync::Arc,
+};
 
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
 use super::{
-  entities::{BankTransaction, MonthlyReport, ParsedTransaction, ReceivedInvoice},
+  entities::{
+    BankStatement, BankTransaction, CategorySuggestion, MatchAllocation, MatchingRule,
+    MonthlyReport, ParsedStatement, ParsedTransaction, ReceivedInvoice, ReportEvent, ReportUpload,
+    RuleConditions, RuleEvaluator, TransactionCategory, Vendor, VendorIdentity, VendorProfile,
+  },
   errors::ReportError,
-  ports::{BankTransactionRepository, MonthlyReportRepository, ReceivedInvoiceRepository},
-  value_objects::{ReportMonth, ReportStatus, TransactionDirection},
+  ports::{
+    BankStatementRepository, BankTransactionRepository, CategorySuggestionRepository,
+    MatchAllocationRepository, MatchingRuleRepository, MonthlyReportRepository,
+    ReceivedInvoiceRepository, ReportEventRepository, ReportUploadRepository,
+    TransactionCategoryRepository, VendorRepository,
+  },
+  value_objects::{
+    MatchDocument, ReportEventKind, ReportMonth, ReportStatus, RuleAction, TransactionDirection,
+  },
 };
 
+/// Dependencies for ReportService
+pub struct ReportServiceDependencies {
+  pub report_repo: Arc<dyn MonthlyReportRepository>,
+  pub statement_repo: Arc<dyn BankStatementRepository>,
+  pub transaction_repo: Arc<dyn BankTransactionRepository>,
+  pub received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
+  pub category_repo: Arc<dyn TransactionCategoryRepository>,
+  pub suggestion_repo: Arc<dyn CategorySuggestionRepository>,
+  pub rule_repo: Arc<dyn MatchingRuleRepository>,
+  pub allocation_repo: Arc<dyn MatchAllocationRepository>,
+  pub event_repo: Arc<dyn ReportEventRepository>,
+  pub upload_repo: Arc<dyn ReportUploadRepository>,
+  pub vendor_repo: Arc<dyn VendorRepository>,
+}
+
+/// A transaction of a past report that a rule being tested matches
+#[derive(Debug, Clone)]
+pub struct RuleTestMatch {
+  pub transaction: BankTransaction,
+  /// Earlier rule that would fire on the transaction instead
+  pub preempted_by: Option<String>,
+}
+
 pub struct ReportService {
   report_repo: Arc<dyn MonthlyReportRepository>,
+  statement_repo: Arc<dyn BankStatementRepository>,
   transaction_repo: Arc<dyn BankTransactionRepository>,
   received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
+  category_repo: Arc<dyn TransactionCategoryRepository>,
+  suggestion_repo: Arc<dyn CategorySuggestionRepository>,
+  rule_repo: Arc<dyn MatchingRuleRepository>,
+  allocation_repo: Arc<dyn MatchAllocationRepository>,
+  event_repo: Arc<dyn ReportEventRepository>,
+  upload_repo: Arc<dyn ReportUploadRepository>,
+  vendor_repo: Arc<dyn VendorRepository>,
 }
 
 impl ReportService {
-  pub fn new(
-    report_repo: Arc<dyn MonthlyReportRepository>,
-    transaction_repo: Arc<dyn BankTransactionRepository>,
-    received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
-  ) -> Self {
+  pub fn new(deps: ReportServiceDependencies) -> Self {
     Self {
-      report_repo,
-      transaction_repo,
-      received_invoice_repo,
+      report_repo: deps.report_repo,
+      statement_repo: deps.statement_repo,
+      transaction_repo: deps.transaction_repo,
+      received_invoice_repo: deps.received_invoice_repo,
+      category_repo: deps.category_repo,
+      suggestion_repo: deps.suggestion_repo,
+      rule_repo: deps.rule_repo,
+      allocation_repo: deps.allocation_repo,
+      event_repo: deps.event_repo,
+      upload_repo: deps.upload_repo,
+      vendor_repo: deps.vendor_repo,
     }
   }
 
@@ -46,66 +95,97 @@ impl ReportService {
       return Err(ReportError::DuplicateReport);
     }
 
-    let report = MonthlyReport::new(company_id, period.month, period.year, None);
+    let report = MonthlyReport::new(company_id, period.month, period.year);
     self.report_repo.create(report).await
   }
 
-  /// Import a bank statement: create report + transactions (or populate existing empty report)
+  /// Import one account's bank statement into the month's report, creating
+  /// the report if needed. Each account can be imported once per month.
   pub async fn import_bank_statement(
     &self,
     company_id: Uuid,
     period: ReportMonth,
-    transactions: Vec<ParsedTransaction>,
+    statement: ParsedStatement,
+    bank_account_id: Option<Uuid>,
+    file_path: Option<String>,
+    file_name: Option<String>,
   ) -> Result<MonthlyReport, ReportError> {
-    // Extract IBAN from first transaction
-    let iban = transactions
-      .first()
-      .map(|t| t.client_account.clone())
-      .unwrap_or_default();
-
-    // Calculate totals
-    let mut total_incoming = Decimal::ZERO;
-    let mut total_outgoing = Decimal::ZERO;
-    for t in &transactions {
-      match t.direction {
-        TransactionDirection::Credit => total_incoming += t.amount,
-        TransactionDirection::Debit => total_outgoing += t.amount.abs(),
-      }
-    }
+    let iban = statement.account_iban().unwrap_or_default().to_string();
+    let ParsedStatement {
+      transactions,
+      opening_balance,
+      closing_balance,
+      period_start,
+      period_end,
+    } = statement;
 
-    let tx_count = transactions.len() as i32;
-
-    // Check for existing report
-    let report = if let Some(existing) = self
+    let report = match self
       .report_repo
       .find_by_company_and_period(company_id, period.month, period.year)
       .await?
     {
-      // Only allow populating empty draft reports
-      if existing.status != ReportStatus::Draft || existing.transaction_count != 0 {
-        return Err(ReportError::DuplicateReport);
+      Some(existing) => {
+        if existing.status != ReportStatus::Draft {
+          return Err(ReportError::NotDraft);
+        }
+        let statements = self.statement_repo.find_by_report_id(existing.id).await?;
+        if statements.iter().any(|s| s.bank_account_iban == iban) {
+          return Err(ReportError::DuplicateStatement);
+        }
+        existing
+      }
+      None => {
+        let report = MonthlyReport::new(company_id, period.month, period.year);
+        self.report_repo.create(report).await?
       }
-
-      let mut report = existing;
-      report.bank_account_iban = Some(iban);
-      report.total_incoming = total_incoming;
-      report.total_outgoing = total_outgoing;
-      report.transaction_count = tx_count;
-      report.updated_at = Utc::now();
-      self.report_repo.update(report).await?
-    } else {
-      let mut report = MonthlyReport::new(company_id, period.month, period.year, Some(iban));
-      report.total_incoming = total_incoming;
-      report.total_outgoing = total_outgoing;
-      report.transaction_count = tx_count;
-      self.report_repo.create(report).await?
     };
 
-    // Create bank transactions
-    let bank_transactions: Vec<BankTransaction> = transactions
+    let mut bank_statement = BankStatement::new(
+      report.id,
+      bank_account_id,
+      iban,
+      period_start.unwrap_or_else(|| period.first_day()),
+      period_end.unwrap_or_else(|| period.last_day()),
+    );
+    bank_statement.opening_balance = opening_balance;
+    bank_statement.closing_balance = closing_balance;
+    bank_statement.file_path = file_path;
+    bank_statement.file_name = file_name;
+
+    // Calculate totals over the whole statement so they can be reconciled
+    // with its balances
+    for t in &transactions {
+      match t.direction {
+        TransactionDirection::Credit => bank_statement.total_incoming += t.amount,
+        TransactionDirection::Debit => bank_statement.total_outgoing += t.amount.abs(),
+      }
+    }
+
+    // Rows already imported from an overlapping statement of the same account
+    let (transactions, duplicates) = self
+      .split_duplicates(company_id, &bank_statement.bank_account_iban, transactions)
+      .await?;
+    bank_statement.transaction_count = transactions.len() as i32;
+    bank_statement.duplicate_count = duplicates;
+
+    let bank_statement = self.statement_repo.create(bank_statement).await?;
+    let mut report = report;
+    report.add_statement(&bank_statement);
+    let report = self.report_repo.update(report).await?;
+
+    // Create bank transactions. The first matching rule decides what happens to
+    // a transaction; without one, what was learned on earlier statements applies.
+    let rules = self.rule_repo.find_by_company_id(company_id).await?;
+    let evaluator = RuleEvaluator::new(&rules);
+    let suggestions = self.suggestion_repo.find_by_company_id(company_id).await?;
+    let mut unmatched_invoices = self
+      .received_invoice_repo
+      .find_unmatched_by_company(company_id)
+      .await?;
+    let mut bank_transactions: Vec<BankTransaction> = transactions
       .into_iter()
       .map(|t| {
-        BankTransaction::new(
+        let mut tx = BankTransaction::new(
           report.id,
           t.row_number,
           t.date,
@@ -117,32 +197,119 @@ impl ReportService {
           t.description,
           t.currency,
           t.registry_code,
-        )
+        );
+        tx.statement_id = Some(bank_statement.id);
+        tx.document_number = t.document_number;
+        tx.archive_id = t.archive_id;
+        match evaluator.first_match(&tx) {
+          Some(rule) => apply_rule(&mut tx, rule, &mut unmatched_invoices),
+          None => tx.category_id = CategorySuggestion::suggest(&suggestions, &tx),
+        }
+        tx
       })
       .collect();
 
-    let bank_transactions = self.transaction_repo.create_many(bank_transactions).await?;
+    // Link outgoing payments to the vendors they pay. Payees are added to the
+    // directory only when the bank supplies their registry code.
+    let mut vendors = self.vendor_repo.find_by_company_id(company_id).await?;
+    for tx in &mut bank_transactions {
+      let Some(identity) = VendorIdentity::of_transaction(tx) else {
+        continue;
+      };
+      let create = identity
+        .registry_code
+        .as_deref()
+        .is_some_and(|code| !code.trim().is_empty());
+      let Some(vendor) = self
+        .resolve_vendor(company_id, &mut vendors, &identity, create)
+        .await?
+      else {
+        continue;
+      };
+      tx.vendor_id = Some(vendor.id);
+      if tx.rule_id.is_none() && tx.category_id.is_none() && tx.allocations.is_empty() {
+        tx.category_id = vendor.default_category_id;
+      }
+    }
+
+    let mut bank_transactions = self.transaction_repo.create_many(bank_transactions).await?;
+    for tx in &bank_transactions {
+      for allocation in &tx.allocations {
+        self.allocation_repo.create(allocation.clone()).await?;
+      }
+    }
 
     // Auto-match received invoices to debit transactions
     let auto_matched = self
-      .auto_match_received_invoices(report.id, company_id, &bank_transactions)
+      .auto_match_received_invoices(company_id, &mut bank_transactions)
       .await?;
 
-    // Update matched count if any auto-matches were made
-    if auto_matched > 0 {
-      let mut report = self
-        .report_repo
-        .find_by_id(report.id)
-        .await?
-        .ok_or(ReportError::NotFound)?;
-      report.matched_count = auto_matched;
-      report.updated_at = Utc::now();
-      return self.report_repo.update(report).await;
+    // Update matched count if any transactions were resolved on import
+    if auto_matched > 0 || bank_transactions.iter().any(|t| t.is_resolved()) {
+      self.update_matched_count(report.id).await?;
+      return self.get_report(report.id).await;
     }
 
     Ok(report)
   }
 
+  /// Separate rows already imported with another statement of the same
+  /// account from new ones, returning the new rows and how many were left out
+  async fn split_duplicates(
+    &self,
+    company_id: Uuid,
+    iban: &str,
+    transactions: Vec<ParsedTransaction>,
+  ) -> Result<(Vec<ParsedTransaction>, i32), ReportError> {
+    let (Some(first), Some(last)) = (
+      transactions.iter().map(|t| t.date).min(),
+      transactions.iter().map(|t| t.date).max(),
+    ) else {
+      return Ok((transactions, 0));
+    };
+
+    let same_account: HashSet<Uuid> = self
+      .statement_repo
+      .find_by_company_id(company_id)
+      .await?
+      .into_iter()
+      .filter(|s| s.bank_account_iban == iban)
+      .map(|s| s.id)
+      .collect();
+    if same_account.is_empty() {
+      return Ok((transactions, 0));
+    }
+
+    let existing: Vec<BankTransaction> = self
+      .transaction_repo
+      .find_by_company_and_date_range(company_id, first, last)
+      .await?
+      .into_iter()
+      .filter(|tx| tx.statement_id.is_some_and(|id| same_account.contains(&id)))
+      .collect();
+
+    let before = transactions.len();
+    let transactions: Vec<_> = transactions
+      .into_iter()
+      .filter(|t| !existing.iter().any(|tx| t.is_duplicate_of(tx)))
+      .collect();
+    let duplicates = (before - transactions.len()) as i32;
+    Ok((transactions, duplicates))
+  }
+
+  /// Statements imported into a report, one per bank account
+  pub async fn get_statements(&self, report_id: Uuid) -> Result<Vec<BankStatement>, ReportError> {
+    self.statement_repo.find_by_report_id(report_id).await
+  }
+
+  /// Statements of all the company's reports
+  pub async fn get_company_statements(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<BankStatement>, ReportError> {
+    self.statement_repo.find_by_company_id(company_id).await
+  }
+
   /// Get all reports for a company
   pub async fn get_company_reports(
     &self,
@@ -151,6 +318,15 @@ impl ReportService {
     self.report_repo.find_by_company_id(company_id).await
   }
 
+  /// Get a report without its transactions
+  pub async fn get_report(&self, report_id: Uuid) -> Result<MonthlyReport, ReportError> {
+    self
+      .report_repo
+      .find_by_id(report_id)
+      .await?
+      .ok_or(ReportError::NotFound)
+  }
+
   /// Get report with transactions
   pub async fn get_report_details(
     &self,
@@ -167,62 +343,159 @@ impl ReportService {
     Ok((report, transactions))
   }
 
-  /// Match a transaction to an invoice (issued or received)
+  /// Allocate a transaction, or part of it, to an issued or received invoice.
+  /// Both have to belong to the company and be in the same currency.
+  /// Without an amount, as much as both the transaction and the invoice still
+  /// have open is allocated. Returns the transaction and the new allocation.
   pub async fn match_transaction(
     &self,
+    company_id: Uuid,
     transaction_id: Uuid,
-    invoice_id: Option<Uuid>,
-    received_invoice_id: Option<Uuid>,
-  ) -> Result<(), ReportError> {
-    let tx = self
+    document: MatchDocument,
+    amount: Option<Decimal>,
+  ) -> Result<(BankTransaction, MatchAllocation), ReportError> {
+    let mut tx = self
       .transaction_repo
       .find_by_id(transaction_id)
       .await?
       .ok_or(ReportError::TransactionNotFound)?;
+    let report = self.get_report(tx.report_id).await?;
+    if report.company_id != company_id {
+      return Err(ReportError::TransactionNotFound);
+    }
+    report.ensure_editable()?;
 
-    if tx.is_matched() {
+    if tx.is_fully_allocated() {
       return Err(ReportError::AlreadyMatched);
     }
 
-    // Validate that exactly one is provided
-    match (&invoice_id, &received_invoice_id) {
-      (Some(_), None) | (None, Some(_)) => {}
-      _ => {
-        return Err(ReportError::Validation(
-          "Exactly one of invoice_id or received_invoice_id must be provided".to_string(),
-        ));
+    let (invoice_id, total, currency, received_invoice, payments) = match document {
+      MatchDocument::Invoice {
+        id,
+        company_id: invoice_company_id,
+        total,
+        currency,
+      } => {
+        if invoice_company_id != company_id {
+          return Err(ReportError::Validation("Invoice not found".to_string()));
+        }
+        let payments = self.allocation_repo.find_by_invoice_id(id).await?;
+        (Some(id), total, currency, None, payments)
+      }
+      MatchDocument::ReceivedInvoice(id) => {
+        let invoice = self.get_received_invoice(id).await?;
+        if invoice.company_id != company_id {
+          return Err(ReportError::ReceivedInvoiceNotFound);
+        }
+        let payments = self.allocation_repo.find_by_received_invoice_id(id).await?;
+        (
+          None,
+          invoice.amount,
+          invoice.currency.clone(),
+          Some(invoice),
+          payments,
+        )
       }
+    };
+
+    // A payment in another currency cannot settle the invoice one to one
+    if !tx.currency.eq_ignore_ascii_case(&currency) {
+      return Err(ReportError::Validation(format!(
+        "The transaction is in {} but the invoice is in {}",
+        tx.currency, currency
+      )));
     }
 
-    self
-      .transaction_repo
-      .update_match(transaction_id, invoice_id, received_invoice_id)
-      .await?;
+    // What other transactions have not paid of the invoice yet
+    let invoice_open = total.abs() - payments.iter().map(|a| a.amount).sum::<Decimal>();
+    if invoice_open <= Decimal::ZERO {
+      return Err(ReportError::Validation(
+        "The invoice is already fully allocated".to_string(),
+      ));
+    }
+
+    let amount = amount.unwrap_or_else(|| tx.unallocated_amount().min(invoice_open));
+    if amount > invoice_open {
+      return Err(ReportError::Validation(format!(
+        "Only {} of the invoice is left to allocate",
+        invoice_open
+      )));
+    }
+
+    let received_invoice_id = received_invoice.as_ref().map(|inv| inv.id);
+    let allocation = tx.allocate(invoice_id, received_invoice_id, amount)?;
+    let allocation = self.allocation_repo.create(allocation).await?;
+    if let Some(invoice) = received_invoice {
+      self.link_vendor(&mut tx, invoice).await?;
+    }
 
     // Update matched count on report
     self.update_matched_count(tx.report_id).await?;
 
-    Ok(())
+    Ok((tx, allocation))
   }
 
-  /// Clear a match from a transaction
-  pub async fn unmatch_transaction(&self, transaction_id: Uuid) -> Result<(), ReportError> {
+  /// Clear all allocations of a transaction.
+  /// Returns the transaction as it was before its allocations were cleared.
+  pub async fn unmatch_transaction(
+    &self,
+    transaction_id: Uuid,
+  ) -> Result<BankTransaction, ReportError> {
     let tx = self
       .transaction_repo
       .find_by_id(transaction_id)
       .await?
       .ok_or(ReportError::TransactionNotFound)?;
+    self.editable_report(tx.report_id).await?;
 
-    if !tx.is_matched() {
+    if tx.allocations.is_empty() {
       return Err(ReportError::NotMatched);
     }
 
-    self.transaction_repo.clear_match(transaction_id).await?;
+    self
+      .allocation_repo
+      .delete_by_transaction_id(transaction_id)
+      .await?;
 
     // Update matched count on report
     self.update_matched_count(tx.report_id).await?;
 
-    Ok(())
+    Ok(tx)
+  }
+
+  /// Dated payments of an issued invoice, one per allocated transaction
+  pub async fn invoice_payments(
+    &self,
+    invoice_id: Uuid,
+  ) -> Result<Vec<(NaiveDate, Decimal)>, ReportError> {
+    let allocations = self.allocation_repo.find_by_invoice_id(invoice_id).await?;
+    self.dated_payments(allocations).await
+  }
+
+  /// Dated payments of a received invoice, one per allocated transaction
+  pub async fn received_invoice_payments(
+    &self,
+    received_invoice_id: Uuid,
+  ) -> Result<Vec<(NaiveDate, Decimal)>, ReportError> {
+    let allocations = self
+      .allocation_repo
+      .find_by_received_invoice_id(received_invoice_id)
+      .await?;
+    self.dated_payments(allocations).await
+  }
+
+  /// Amount allocated so far to each of the company's issued and received invoices
+  pub async fn allocated_by_document(
+    &self,
+    company_id: Uuid,
+  ) -> Result<HashMap<Uuid, Decimal>, ReportError> {
+    let mut allocated = HashMap::new();
+    for allocation in self.allocation_repo.find_by_company_id(company_id).await? {
+      if let Some(id) = allocation.invoice_id.or(allocation.received_invoice_id) {
+        *allocated.entry(id).or_insert(Decimal::ZERO) += allocation.amount;
+      }
+    }
+    Ok(allocated)
   }
 
   /// Delete a report and its transactions
@@ -232,6 +505,7 @@ impl ReportService {
       .find_by_id(report_id)
       .await?
       .ok_or(ReportError::NotFound)?;
+    report.ensure_editable()?;
 
     self.transaction_repo.delete_by_report_id(report.id).await?;
     self.report_repo.delete(report.id).await?;
@@ -239,25 +513,130 @@ impl ReportService {
     Ok(())
   }
 
-  /// Mark report as generated
+  /// Delete a report whose retention period has ended with its
+  /// transactions, locked or not
+  pub async fn purge_report(&self, report_id: Uuid) -> Result<(), ReportError> {
+    self.transaction_repo.delete_by_report_id(report_id).await?;
+    self.report_repo.delete(report_id).await
+  }
+
+  /// Mark a draft report as generated into the given Drive folder, as its
+  /// next version
   pub async fn mark_generated(
     &self,
     report_id: Uuid,
     drive_folder_id: String,
+    user_id: Uuid,
   ) -> Result<MonthlyReport, ReportError> {
-    let mut report = self
-      .report_repo
-      .find_by_id(report_id)
-      .await?
-      .ok_or(ReportError::NotFound)?;
+    let mut report = self.get_report(report_id).await?;
+    report.mark_generated(drive_folder_id)?;
+    let report = self.report_repo.update(report).await?;
 
-    report.status = ReportStatus::Generated;
-    report.drive_folder_id = Some(drive_folder_id);
-    report.updated_at = Utc::now();
+    let kind = if report.version > 1 {
+      ReportEventKind::Regenerated
+    } else {
+      ReportEventKind::Generated
+    };
+    self
+      .event_repo
+      .append(ReportEvent::new(&report, user_id, kind, None))
+      .await?;
+
+    Ok(report)
+  }
 
+  /// Remember the Drive folder of a draft report while its files are being
+  /// uploaded, so retries and later versions reuse it
+  pub async fn set_drive_folder(
+    &self,
+    report_id: Uuid,
+    drive_folder_id: String,
+  ) -> Result<MonthlyReport, ReportError> {
+    let mut report = self.get_report(report_id).await?;
+    if report.status != ReportStatus::Draft {
+      return Err(ReportError::NotDraft);
+    }
+    report.drive_folder_id = Some(drive_folder_id);
     self.report_repo.update(report).await
   }
 
+  /// Move a generated report back to draft, e.g. when a late receipt arrives
+  pub async fn reopen_report(
+    &self,
+    company_id: Uuid,
+    report_id: Uuid,
+    user_id: Uuid,
+    reason: &str,
+  ) -> Result<MonthlyReport, ReportError> {
+    let reason = reason.trim();
+    if reason.is_empty() {
+      return Err(ReportError::Validation(
+        "A reason is required to reopen a report".to_string(),
+      ));
+    }
+
+    let mut report = self.get_company_report(company_id, report_id).await?;
+    report.reopen()?;
+    let report = self.report_repo.update(report).await?;
+
+    self
+      .event_repo
+      .append(ReportEvent::new(
+        &report,
+        user_id,
+        ReportEventKind::Reopened,
+        Some(reason.to_string()),
+      ))
+      .await?;
+
+    Ok(report)
+  }
+
+  /// Sign off a generated report; it can't be reopened, changed or deleted
+  /// afterwards
+  pub async fn lock_report(
+    &self,
+    company_id: Uuid,
+    report_id: Uuid,
+    user_id: Uuid,
+  ) -> Result<MonthlyReport, ReportError> {
+    let mut report = self.get_company_report(company_id, report_id).await?;
+    report.lock()?;
+    let report = self.report_repo.update(report).await?;
+
+    self
+      .event_repo
+      .append(ReportEvent::new(
+        &report,
+        user_id,
+        ReportEventKind::Locked,
+        None,
+      ))
+      .await?;
+
+    Ok(report)
+  }
+
+  /// Lifecycle transitions of a report, newest first
+  pub async fn get_report_events(&self, report_id: Uuid) -> Result<Vec<ReportEvent>, ReportError> {
+    self.event_repo.find_by_report_id(report_id).await
+  }
+
+  /// Folders and files uploaded for a report's earlier versions
+  pub async fn get_uploads(&self, report_id: Uuid) -> Result<Vec<ReportUpload>, ReportError> {
+    self.upload_repo.find_by_report_id(report_id).await
+  }
+
+  /// Remember a folder or file uploaded for a report
+  pub async fn record_upload(&self, upload: ReportUpload) -> Result<ReportUpload, ReportError> {
+    self.upload_repo.upsert(upload).await
+  }
+
+  /// Forget a file removed from cloud storage
+  pub async fn forget_upload(&self, report_id: Uuid, path: &str) -> Result<(), ReportError> {
+    self.upload_repo.delete(report_id, path).await
+  }
+
   // -- Received Invoices --
 
   /// Create a received invoice
@@ -279,13 +658,70 @@ impl ReportService {
       .await
   }
 
-  /// Delete a received invoice
+  /// Save a received invoice's payment terms and status
+  pub async fn update_received_invoice(
+    &self,
+    invoice: ReceivedInvoice,
+  ) -> Result<ReceivedInvoice, ReportError> {
+    self.received_invoice_repo.update(invoice).await
+  }
+
+  /// Received invoices still to be paid, soonest due first
+  pub async fn open_payables(&self, company_id: Uuid) -> Result<Vec<ReceivedInvoice>, ReportError> {
+    self
+      .received_invoice_repo
+      .find_open_by_company(company_id)
+      .await
+  }
+
+  /// Mark a received invoice paid once the debit transactions allocated to
+  /// it cover its amount, or unpaid again when they no longer do
+  pub async fn sync_payable_status(
+    &self,
+    received_invoice_id: Uuid,
+  ) -> Result<ReceivedInvoice, ReportError> {
+    let mut invoice = self.get_received_invoice(received_invoice_id).await?;
+    let allocations = self
+      .allocation_repo
+      .find_by_received_invoice_id(received_invoice_id)
+      .await?;
+
+    let mut paid = Decimal::ZERO;
+    let mut last_paid_on = None;
+    for allocation in allocations {
+      let tx = self
+        .transaction_repo
+        .find_by_id(allocation.transaction_id)
+        .await?
+        .ok_or(ReportError::TransactionNotFound)?;
+      if tx.direction == TransactionDirection::Debit {
+        paid += allocation.amount;
+        last_paid_on = last_paid_on.max(Some(tx.date));
+      }
+    }
+
+    if invoice.apply_payments(paid, last_paid_on) {
+      invoice = self.received_invoice_repo.update(invoice).await?;
+    }
+    Ok(invoice)
+  }
+
+  /// Delete a received invoice. One matched to bank transactions has to be
+  /// unmatched first, so the reports and its payments stay in step.
   pub async fn delete_received_invoice(&self, id: Uuid) -> Result<String, ReportError> {
     let invoice = self
       .received_invoice_repo
       .find_by_id(id)
       .await?
       .ok_or(ReportError::ReceivedInvoiceNotFound)?;
+    if !self
+      .allocation_repo
+      .find_by_received_invoice_id(id)
+      .await?
+      .is_empty()
+    {
+      return Err(ReportError::ReceivedInvoiceMatched);
+    }
 
     let pdf_path = invoice.pdf_path.clone();
     self.received_invoice_repo.delete(id).await?;
@@ -293,18 +729,24 @@ impl ReportService {
     Ok(pdf_path)
   }
 
+  /// Report of a transaction whose receipt may change, i.e. one in a draft
+  /// report
+  pub async fn receipt_report(&self, transaction_id: Uuid) -> Result<MonthlyReport, ReportError> {
+    let tx = self
+      .transaction_repo
+      .find_by_id(transaction_id)
+      .await?
+      .ok_or(ReportError::TransactionNotFound)?;
+    self.editable_report(tx.report_id).await
+  }
+
   /// Update receipt path on a transaction
   pub async fn update_receipt_path(
     &self,
     transaction_id: Uuid,
     receipt_path: Option<String>,
   ) -> Result<(), ReportError> {
-    // Verify transaction exists
-    let tx = self
-      .transaction_repo
-      .find_by_id(transaction_id)
-      .await?
-      .ok_or(ReportError::TransactionNotFound)?;
+    let report = self.receipt_report(transaction_id).await?;
 
     self
       .transaction_repo
@@ -312,7 +754,7 @@ impl ReportService {
       .await?;
 
     // Recalculate matched count since receipt affects is_matched()
-    self.update_matched_count(tx.report_id).await?;
+    self.update_matched_count(report.id).await?;
 
     Ok(())
   }
@@ -326,65 +768,1182 @@ impl ReportService {
       .ok_or(ReportError::ReceivedInvoiceNotFound)
   }
 
-  /// Auto-match received invoices to debit transactions by exact amount.
-  /// Returns the number of auto-matched transactions.
-  async fn auto_match_received_invoices(
+  // -- Vendors --
+
+  /// List a company's vendors by name
+  pub async fn list_vendors(&self, company_id: Uuid) -> Result<Vec<Vendor>, ReportError> {
+    self.vendor_repo.find_by_company_id(company_id).await
+  }
+
+  /// Get a vendor of a company
+  pub async fn get_vendor(&self, company_id: Uuid, id: Uuid) -> Result<Vendor, ReportError> {
+    self
+      .vendor_repo
+      .find_by_id(id)
+      .await?
+      .filter(|v| v.company_id == company_id)
+      .ok_or(ReportError::VendorNotFound)
+  }
+
+  /// Add a vendor; registry codes are unique per company
+  pub async fn create_vendor(
     &self,
-    _report_id: Uuid,
     company_id: Uuid,
-    transactions: &[BankTransaction],
-  ) -> Result<i32, ReportError> {
-    let unmatched_invoices = self
-      .received_invoice_repo
-      .find_unmatched_by_company(company_id)
-      .await?;
+    profile: VendorProfile,
+  ) -> Result<Vendor, ReportError> {
+    let vendor = Vendor::new(company_id, profile)?;
+    self.check_vendor(&vendor).await?;
+    self.vendor_repo.create(vendor).await
+  }
 
-    if unmatched_invoices.is_empty() {
-      return Ok(0);
-    }
+  /// Change a vendor's details
+  pub async fn update_vendor(
+    &self,
+    company_id: Uuid,
+    id: Uuid,
+    profile: VendorProfile,
+  ) -> Result<Vendor, ReportError> {
+    let mut vendor = self.get_vendor(company_id, id).await?;
+    vendor.update(profile)?;
+    self.check_vendor(&vendor).await?;
+    self.vendor_repo.update(vendor).await
+  }
 
-    let mut matched_count = 0i32;
+  /// Merge vendor `from_id` into `into_id`: its bills and payments move over,
+  /// and details `into_id` lacks are taken from it
+  pub async fn merge_vendors(
+    &self,
+    company_id: Uuid,
+    from_id: Uuid,
+    into_id: Uuid,
+  ) -> Result<Vendor, ReportError> {
+    if from_id == into_id {
+      return Err(ReportError::Validation(
+        "A vendor cannot be merged into itself".to_string(),
+      ));
+    }
+    let from = self.get_vendor(company_id, from_id).await?;
+    let mut into = self.get_vendor(company_id, into_id).await?;
 
-    for tx in transactions {
-      // Only auto-match debit (outgoing) transactions
-      if tx.direction != TransactionDirection::Debit || tx.is_matched() {
-        continue;
+    if let (Some(a), Some(b)) = (&from.registry_code, &into.registry_code) {
+      if a != b {
+        return Err(ReportError::Validation(
+          "Vendors with different registry codes cannot be merged".to_string(),
+        ));
       }
-
-      // Find received invoices with exact same amount
-      let candidates: Vec<&ReceivedInvoice> = unmatched_invoices
-        .iter()
-        .filter(|inv| inv.amount == tx.amount && inv.currency == tx.currency)
-        .collect();
-
-      // Only auto-match if exactly one candidate (unambiguous)
-      if candidates.len() == 1 {
-        self
-          .transaction_repo
-          .update_match(tx.id, None, Some(candidates[0].id))
-          .await?;
-        matched_count += 1;
+    }
+    into.registry_code = into.registry_code.or(from.registry_code);
+    into.vat_number = into.vat_number.or(from.vat_number);
+    for iban in from.ibans {
+      if !into.ibans.contains(&iban) {
+        into.ibans.push(iban);
       }
     }
+    into.default_category_id = into.default_category_id.or(from.default_category_id);
+    into.default_vat_rate = into.default_vat_rate.or(from.default_vat_rate);
+    into.updated_at = Utc::now();
 
-    Ok(matched_count)
+    self.vendor_repo.merge(from.id, into).await
   }
 
-  /// Helper: recalculate matched count for a report
-  async fn update_matched_count(&self, report_id: Uuid) -> Result<(), ReportError> {
-    let transactions = self.transaction_repo.find_by_report_id(report_id).await?;
-    let matched_count = transactions.iter().filter(|t| t.is_matched()).count() as i32;
+  /// Merge the company's vendors whose names only differ in case or spacing,
+  /// e.g. those the vendor directory's SQL backfill kept apart. Returns the
+  /// number merged.
+  pub async fn merge_same_name_vendors(&self, company_id: Uuid) -> Result<usize, ReportError> {
+    let vendors = self.vendor_repo.find_by_company_id(company_id).await?;
+    let duplicates = Vendor::same_name_duplicates(&vendors);
+    for (from_id, into_id) in &duplicates {
+      self.merge_vendors(company_id, *from_id, *into_id).await?;
+    }
+    Ok(duplicates.len())
+  }
 
-    let mut report = self
-      .report_repo
-      .find_by_id(report_id)
-      .await?
-      .ok_or(ReportError::NotFound)?;
+  /// The vendor a counterparty on a bill is, learning its registry code and
+  /// IBAN. Without a match a vendor is added when `create` is set.
+  pub async fn identify_vendor(
+    &self,
+    company_id: Uuid,
+    identity: &VendorIdentity,
+    create: bool,
+  ) -> Result<Option<Vendor>, ReportError> {
+    let mut vendors = self.vendor_repo.find_by_company_id(company_id).await?;
+    self
+      .resolve_vendor(company_id, &mut vendors, identity, create)
+      .await
+  }
 
-    report.matched_count = matched_count;
-    report.updated_at = Utc::now();
+  /// Received invoices of a vendor, newest first
+  pub async fn vendor_invoices(
+    &self,
+    vendor_id: Uuid,
+  ) -> Result<Vec<ReceivedInvoice>, ReportError> {
+    self
+      .received_invoice_repo
+      .find_by_vendor_id(vendor_id)
+      .await
+  }
+
+  /// Payments to a vendor, newest first
+  pub async fn vendor_transactions(
+    &self,
+    vendor_id: Uuid,
+  ) -> Result<Vec<BankTransaction>, ReportError> {
+    self.transaction_repo.find_by_vendor_id(vendor_id).await
+  }
+
+  // -- Transaction categories --
+
+  /// List a company's transaction categories
+  pub async fn list_categories(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<TransactionCategory>, ReportError> {
+    self.category_repo.find_by_company_id(company_id).await
+  }
+
+  /// Create a transaction category; names are unique per company
+  pub async fn create_category(
+    &self,
+    company_id: Uuid,
+    name: &str,
+  ) -> Result<TransactionCategory, ReportError> {
+    let name = name.trim();
+    if name.is_empty() {
+      return Err(ReportError::Validation(
+        "Category name is required".to_string(),
+      ));
+    }
+
+    let existing = self.category_repo.find_by_company_id(company_id).await?;
+    if existing.iter().any(|c| c.name.eq_ignore_ascii_case(name)) {
+      return Err(ReportError::DuplicateCategory);
+    }
+
+    self
+      .category_repo
+      .create(TransactionCategory::new(company_id, name.to_string()))
+      .await
+  }
+
+  /// Delete a category that no transaction uses. Its learned suggestions go with it.
+  pub async fn delete_category(&self, company_id: Uuid, id: Uuid) -> Result<(), ReportError> {
+    let category = self.get_category(company_id, id).await?;
+
+    if self
+      .transaction_repo
+      .count_by_category_id(category.id)
+      .await?
+      > 0
+    {
+      return Err(ReportError::CategoryInUse);
+    }
+
+    self.category_repo.delete(category.id).await
+  }
+
+  /// Set or clear a transaction's category. Setting one also remembers it for
+  /// the transaction's counterparty so future imports are categorised the same.
+  pub async fn categorise_transaction(
+    &self,
+    company_id: Uuid,
+    transaction_id: Uuid,
+    category_id: Option<Uuid>,
+  ) -> Result<BankTransaction, ReportError> {
+    let mut tx = self
+      .transaction_repo
+      .find_by_id(transaction_id)
+      .await?
+      .ok_or(ReportError::TransactionNotFound)?;
+
+    let report = self.get_report(tx.report_id).await?;
+    if report.company_id != company_id {
+      return Err(ReportError::TransactionNotFound);
+    }
+    report.ensure_editable()?;
+
+    if let Some(category_id) = category_id {
+      self.get_category(company_id, category_id).await?;
+    }
+
+    self
+      .transaction_repo
+      .update_category(transaction_id, category_id)
+      .await?;
+
+    if let Some(category_id) = category_id {
+      for suggestion in CategorySuggestion::learn(company_id, &tx, category_id) {
+        self.suggestion_repo.record(suggestion).await?;
+      }
+    }
+
+    // Categorised transactions count as resolved
+    self.update_matched_count(tx.report_id).await?;
+
+    tx.category_id = category_id;
+    Ok(tx)
+  }
+
+  // -- Matching rules --
+
+  /// List a company's matching rules in evaluation order
+  pub async fn list_rules(&self, company_id: Uuid) -> Result<Vec<MatchingRule>, ReportError> {
+    self.rule_repo.find_by_company_id(company_id).await
+  }
+
+  /// Validate a rule without saving it; it goes after the existing rules
+  pub async fn build_rule(
+    &self,
+    company_id: Uuid,
+    name: String,
+    conditions: RuleConditions,
+    action: RuleAction,
+  ) -> Result<MatchingRule, ReportError> {
+    if let Some(category_id) = action.category_id() {
+      self.get_category(company_id, category_id).await?;
+    }
+
+    let position = self
+      .rule_repo
+      .find_by_company_id(company_id)
+      .await?
+      .iter()
+      .map(|rule| rule.position + 1)
+      .max()
+      .unwrap_or(0);
+
+    MatchingRule::new(company_id, name, position, conditions, action)
+  }
+
+  /// Save a rule as the last one evaluated
+  pub async fn create_rule(
+    &self,
+    company_id: Uuid,
+    name: String,
+    conditions: RuleConditions,
+    action: RuleAction,
+  ) -> Result<MatchingRule, ReportError> {
+    let rule = self
+      .build_rule(company_id, name, conditions, action)
+      .await?;
+    self.rule_repo.create(rule).await
+  }
+
+  /// Run an unsaved rule against a past report. Returns the transactions its
+  /// conditions hold for, noting where an existing rule would fire first.
+  pub async fn test_rule(
+    &self,
+    company_id: Uuid,
+    rule: MatchingRule,
+    report_id: Uuid,
+  ) -> Result<Vec<RuleTestMatch>, ReportError> {
+    let report = self.get_report(report_id).await?;
+    if report.company_id != company_id {
+      return Err(ReportError::NotFound);
+    }
+
+    let mut rules = self.rule_repo.find_by_company_id(company_id).await?;
+    let rule_id = rule.id;
+    rules.push(rule);
+    let evaluator = RuleEvaluator::new(&rules);
+
+    let transactions = self.transaction_repo.find_by_report_id(report_id).await?;
+    Ok(
+      transactions
+        .into_iter()
+        .filter(|tx| evaluator.matches(rule_id, tx))
+        .map(|tx| {
+          let preempted_by = evaluator
+            .first_match(&tx)
+            .filter(|fired| fired.id != rule_id)
+            .map(|fired| fired.name.clone());
+          RuleTestMatch {
+            transaction: tx,
+            preempted_by,
+          }
+        })
+        .collect(),
+    )
+  }
+
+  /// Swap a rule with its neighbour so it is evaluated earlier or later
+  pub async fn move_rule(
+    &self,
+    company_id: Uuid,
+    rule_id: Uuid,
+    earlier: bool,
+  ) -> Result<(), ReportError> {
+    let rules = self.rule_repo.find_by_company_id(company_id).await?;
+    let index = rules
+      .iter()
+      .position(|rule| rule.id == rule_id)
+      .ok_or(ReportError::RuleNotFound)?;
+    let neighbour = if earlier {
+      index.checked_sub(1)
+    } else {
+      Some(index + 1).filter(|i| *i < rules.len())
+    };
+
+    if let Some(neighbour) = neighbour {
+      let (rule, other) = (&rules[index], &rules[neighbour]);
+      self
+        .rule_repo
+        .update_position(rule.id, other.position)
+        .await?;
+      self
+        .rule_repo
+        .update_position(other.id, rule.position)
+        .await?;
+    }
+
+    Ok(())
+  }
+
+  /// Delete a rule. Transactions it fired on keep their outcome.
+  pub async fn delete_rule(&self, company_id: Uuid, rule_id: Uuid) -> Result<(), ReportError> {
+    let rule = self
+      .rule_repo
+      .find_by_id(rule_id)
+      .await?
+      .filter(|rule| rule.company_id == company_id)
+      .ok_or(ReportError::RuleNotFound)?;
+
+    self.rule_repo.delete(rule.id).await
+  }
+
+  /// Clear the ignored and review flags a rule set on a transaction
+  pub async fn clear_rule_outcome(
+    &self,
+    company_id: Uuid,
+    transaction_id: Uuid,
+  ) -> Result<(), ReportError> {
+    let tx = self
+      .transaction_repo
+      .find_by_id(transaction_id)
+      .await?
+      .ok_or(ReportError::TransactionNotFound)?;
+
+    let report = self.get_report(tx.report_id).await?;
+    if report.company_id != company_id {
+      return Err(ReportError::TransactionNotFound);
+    }
+    report.ensure_editable()?;
+
+    self
+      .transaction_repo
+      .update_rule_outcome(transaction_id, None, false, false)
+      .await?;
+
+    self.update_matched_count(tx.report_id).await
+  }
+
+  async fn get_company_report(
+    &self,
+    company_id: Uuid,
+    report_id: Uuid,
+  ) -> Result<MonthlyReport, ReportError> {
+    Some(self.get_report(report_id).await?)
+      .filter(|r| r.company_id == company_id)
+      .ok_or(ReportError::NotFound)
+  }
+
+  /// The report, if it is a draft whose transactions may still change
+  async fn editable_report(&self, report_id: Uuid) -> Result<MonthlyReport, ReportError> {
+    let report = self.get_report(report_id).await?;
+    report.ensure_editable()?;
+    Ok(report)
+  }
+
+  async fn get_category(
+    &self,
+    company_id: Uuid,
+    id: Uuid,
+  ) -> Result<TransactionCategory, ReportError> {
+    self
+      .category_repo
+      .find_by_id(id)
+      .await?
+      .filter(|c| c.company_id == company_id)
+      .ok_or(ReportError::CategoryNotFound)
+  }
+
+  /// Reject a vendor whose registry code another vendor of the company has,
+  /// or whose default category is not the company's
+  async fn check_vendor(&self, vendor: &Vendor) -> Result<(), ReportError> {
+    if let Some(code) = &vendor.registry_code {
+      let vendors = self
+        .vendor_repo
+        .find_by_company_id(vendor.company_id)
+        .await?;
+      if vendors
+        .iter()
+        .any(|v| v.id != vendor.id && v.registry_code.as_ref() == Some(code))
+      {
+        return Err(ReportError::DuplicateVendor);
+      }
+    }
+    if let Some(category_id) = vendor.default_category_id {
+      self.get_category(vendor.company_id, category_id).await?;
+    }
+    Ok(())
+  }
+
+  /// Find the vendor of a counterparty among `vendors`, saving what it
+  /// learned from the counterparty, or add one when `create` is set
+  async fn resolve_vendor(
+    &self,
+    company_id: Uuid,
+    vendors: &mut Vec<Vendor>,
+    identity: &VendorIdentity,
+    create: bool,
+  ) -> Result<Option<Vendor>, ReportError> {
+    if let Some(index) = Vendor::find(vendors, identity)
+      .and_then(|found| vendors.iter().position(|v| v.id == found.id))
+    {
+      if vendors[index].learn(identity) {
+        vendors[index] = self.vendor_repo.update(vendors[index].clone()).await?;
+      }
+      return Ok(Some(vendors[index].clone()));
+    }
+    if !create {
+      return Ok(None);
+    }
+
+    let vendor = self
+      .vendor_repo
+      .create(Vendor::from_identity(company_id, identity)?)
+      .await?;
+    vendors.push(vendor.clone());
+    Ok(Some(vendor))
+  }
+
+  /// Link a payment and the received invoice it settles to the same vendor
+  /// when only one of them has it. The vendor learns the payee's registry
+  /// code and IBAN unless another vendor already has that registry code.
+  async fn link_vendor(
+    &self,
+    tx: &mut BankTransaction,
+    mut invoice: ReceivedInvoice,
+  ) -> Result<(), ReportError> {
+    match (tx.vendor_id, invoice.vendor_id) {
+      (Some(vendor_id), None) => {
+        invoice.vendor_id = Some(vendor_id);
+        self.received_invoice_repo.update(invoice).await?;
+      }
+      (None, Some(vendor_id)) => {
+        self
+          .transaction_repo
+          .update_vendor(tx.id, Some(vendor_id))
+          .await?;
+        tx.vendor_id = Some(vendor_id);
+
+        let Some(mut identity) = VendorIdentity::of_transaction(tx) else {
+          return Ok(());
+        };
+        let vendors = self
+          .vendor_repo
+          .find_by_company_id(invoice.company_id)
+          .await?;
+        if vendors.iter().any(|v| {
+          v.id != vendor_id
+            && v.registry_code.is_some()
+            && v.registry_code == identity.registry_code
+        }) {
+          identity.registry_code = None;
+        }
+        if let Some(mut vendor) = vendors.into_iter().find(|v| v.id == vendor_id) {
+          if vendor.learn(&identity) {
+            self.vendor_repo.update(vendor).await?;
+          }
+        }
+      }
+      _ => {}
+    }
+    Ok(())
+  }
+
+  /// Auto-match received invoices to debit transactions by exact amount.
+  /// Returns the number of auto-matched transactions.
+  async fn auto_match_received_invoices(
+    &self,
+    company_id: Uuid,
+    transactions: &mut [BankTransaction],
+  ) -> Result<i32, ReportError> {
+    let unmatched_invoices = self
+      .received_invoice_repo
+      .find_unmatched_by_company(company_id)
+      .await?;
+
+    if unmatched_invoices.is_empty() {
+      return Ok(0);
+    }
+
+    let mut matched_count = 0i32;
+
+    for tx in transactions.iter_mut() {
+      // Only auto-match debit (outgoing) transactions no rule or receipt has handled
+      if tx.direction != TransactionDirection::Debit
+        || tx.is_matched()
+        || !tx.allocations.is_empty()
+        || tx.rule_id.is_some()
+      {
+        continue;
+      }
+
+      // Find received invoices with exact same amount
+      let mut candidates: Vec<&ReceivedInvoice> = unmatched_invoices
+        .iter()
+        .filter(|inv| inv.amount == tx.amount && inv.currency == tx.currency)
+        .collect();
+
+      // Several bills of the same amount: the one from the payee may still be clear
+      if candidates.len() > 1 && tx.vendor_id.is_some() {
+        candidates.retain(|inv| inv.vendor_id == tx.vendor_id);
+      }
+
+      // Only auto-match if exactly one candidate (unambiguous)
+      if candidates.len() == 1 {
+        let amount = tx.amount.abs();
+        let allocation = tx.allocate(None, Some(candidates[0].id), amount)?;
+        self.allocation_repo.create(allocation).await?;
+        matched_count += 1;
+      }
+    }
+
+    Ok(matched_count)
+  }
+
+  /// Transaction dates of allocations, for posting them as payments
+  async fn dated_payments(
+    &self,
+    allocations: Vec<MatchAllocation>,
+  ) -> Result<Vec<(NaiveDate, Decimal)>, ReportError> {
+    let mut payments = Vec::with_capacity(allocations.len());
+    for allocation in allocations {
+      let tx = self
+        .transaction_repo
+        .find_by_id(allocation.transaction_id)
+        .await?
+        .ok_or(ReportError::TransactionNotFound)?;
+      payments.push((tx.date, allocation.amount));
+    }
+    Ok(payments)
+  }
+
+  /// Helper: recalculate matched count for a report. Categorised transactions
+  /// need no document, so they count as matched too.
+  async fn update_matched_count(&self, report_id: Uuid) -> Result<(), ReportError> {
+    let transactions = self.transaction_repo.find_by_report_id(report_id).await?;
+    let matched_count = transactions.iter().filter(|t| t.is_resolved()).count() as i32;
+
+    let mut report = self
+      .report_repo
+      .find_by_id(report_id)
+      .await?
+      .ok_or(ReportError::NotFound)?;
+
+    report.matched_count = matched_count;
+    report.updated_at = Utc::now();
     self.report_repo.update(report).await?;
 
     Ok(())
   }
 }
+
+/// Apply a rule's action to a transaction being imported. A vendor rule takes
+/// the vendor's unpaid invoice with the same amount, or its only unpaid
+/// invoice, and allocates as much of the transaction as the invoice is for;
+/// when neither exists the transaction is flagged for review.
+fn apply_rule(
+  tx: &mut BankTransaction,
+  rule: &MatchingRule,
+  unmatched_invoices: &mut Vec<ReceivedInvoice>,
+) {
+  tx.rule_id = Some(rule.id);
+
+  match &rule.action {
+    RuleAction::AssignCategory(category_id) => tx.category_id = Some(*category_id),
+    RuleAction::Ignore => tx.ignored = true,
+    RuleAction::FlagForReview => tx.needs_review = true,
+    RuleAction::AttachVendor(vendor_name) => {
+      let candidates: Vec<usize> = unmatched_invoices
+        .iter()
+        .enumerate()
+        .filter(|(_, inv)| {
+          Vendor::name_key(&inv.vendor_name) == Vendor::name_key(vendor_name)
+            && inv.currency == tx.currency
+        })
+        .map(|(i, _)| i)
+        .collect();
+      let chosen = candidates
+        .iter()
+        .copied()
+        .find(|&i| unmatched_invoices[i].amount.abs() == tx.amount.abs())
+        .or_else(|| (candidates.len() == 1).then(|| candidates[0]));
+
+      match chosen {
+        Some(i) => {
+          let invoice = unmatched_invoices.remove(i);
+          let amount = tx.amount.abs().min(invoice.amount.abs());
+          if tx.allocate(None, Some(invoice.id), amount).is_err() {
+            tx.needs_review = true;
+          }
+        }
+        None => tx.needs_review = true,
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use async_trait::async_trait;
+  use rust_decimal_macros::dec;
+  use std::sync::Mutex;
+
+  /// Reports, transactions, allocations and received invoices; what
+  /// matching reads and writes
+  #[derive(Default)]
+  struct InMemoryReports {
+    reports: Mutex<Vec<MonthlyReport>>,
+    transactions: Mutex<Vec<BankTransaction>>,
+    allocations: Mutex<Vec<MatchAllocation>>,
+    received_invoices: Mutex<Vec<ReceivedInvoice>>,
+  }
+
+  impl InMemoryReports {
+    fn with_allocations(&self, mut tx: BankTransaction) -> BankTransaction {
+      let allocations = self.allocations.lock().unwrap();
+      tx.allocations = allocations
+        .iter()
+        .filter(|a| a.transaction_id == tx.id)
+        .cloned()
+        .collect();
+      tx
+    }
+  }
+
+  #[async_trait]
+  impl MonthlyReportRepository for InMemoryReports {
+    async fn create(&self, report: MonthlyReport) -> Result<MonthlyReport, ReportError> {
+      self.reports.lock().unwrap().push(report.clone());
+      Ok(report)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<MonthlyReport>, ReportError> {
+      let reports = self.reports.lock().unwrap();
+      Ok(reports.iter().find(|r| r.id == id).cloned())
+    }
+
+    async fn find_by_company_and_period(
+      &self,
+      _company_id: Uuid,
+      _month: u32,
+      _year: i32,
+    ) -> Result<Option<MonthlyReport>, ReportError> {
+      unimplemented!()
+    }
+
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<MonthlyReport>, ReportError> {
+      unimplemented!()
+    }
+
+    async fn update(&self, report: MonthlyReport) -> Result<MonthlyReport, ReportError> {
+      let mut reports = self.reports.lock().unwrap();
+      if let Some(stored) = reports.iter_mut().find(|r| r.id == report.id) {
+        *stored = report.clone();
+      }
+      Ok(report)
+    }
+
+    async fn delete(&self, _id: Uuid) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl BankTransactionRepository for InMemoryReports {
+    async fn create_many(
+      &self,
+      transactions: Vec<BankTransaction>,
+    ) -> Result<Vec<BankTransaction>, ReportError> {
+      self
+        .transactions
+        .lock()
+        .unwrap()
+        .extend(transactions.clone());
+      Ok(transactions)
+    }
+
+    async fn find_by_report_id(
+      &self,
+      report_id: Uuid,
+    ) -> Result<Vec<BankTransaction>, ReportError> {
+      let transactions = self.transactions.lock().unwrap().clone();
+      Ok(
+        transactions
+          .into_iter()
+          .filter(|tx| tx.report_id == report_id)
+          .map(|tx| self.with_allocations(tx))
+          .collect(),
+      )
+    }
+
+    async fn find_by_company_and_date_range(
+      &self,
+      _company_id: Uuid,
+      _start_date: NaiveDate,
+      _end_date: NaiveDate,
+    ) -> Result<Vec<BankTransaction>, ReportError> {
+      unimplemented!()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<BankTransaction>, ReportError> {
+      let tx = self
+        .transactions
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|tx| tx.id == id)
+        .cloned();
+      Ok(tx.map(|tx| self.with_allocations(tx)))
+    }
+
+    async fn update_receipt_path(
+      &self,
+      _transaction_id: Uuid,
+      _receipt_path: Option<String>,
+    ) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+
+    async fn update_category(
+      &self,
+      _transaction_id: Uuid,
+      _category_id: Option<Uuid>,
+    ) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+
+    async fn count_by_category_id(&self, _category_id: Uuid) -> Result<i64, ReportError> {
+      unimplemented!()
+    }
+
+    async fn update_rule_outcome(
+      &self,
+      _transaction_id: Uuid,
+      _rule_id: Option<Uuid>,
+      _ignored: bool,
+      _needs_review: bool,
+    ) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+
+    async fn update_vendor(
+      &self,
+      _transaction_id: Uuid,
+      _vendor_id: Option<Uuid>,
+    ) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+
+    async fn find_by_vendor_id(
+      &self,
+      _vendor_id: Uuid,
+    ) -> Result<Vec<BankTransaction>, ReportError> {
+      unimplemented!()
+    }
+
+    async fn delete_by_report_id(&self, _report_id: Uuid) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl MatchAllocationRepository for InMemoryReports {
+    async fn create(&self, allocation: MatchAllocation) -> Result<MatchAllocation, ReportError> {
+      self.allocations.lock().unwrap().push(allocation.clone());
+      Ok(allocation)
+    }
+
+    async fn find_by_invoice_id(
+      &self,
+      invoice_id: Uuid,
+    ) -> Result<Vec<MatchAllocation>, ReportError> {
+      let allocations = self.allocations.lock().unwrap();
+      Ok(
+        allocations
+          .iter()
+          .filter(|a| a.invoice_id == Some(invoice_id))
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn find_by_received_invoice_id(
+      &self,
+      received_invoice_id: Uuid,
+    ) -> Result<Vec<MatchAllocation>, ReportError> {
+      let allocations = self.allocations.lock().unwrap();
+      Ok(
+        allocations
+          .iter()
+          .filter(|a| a.received_invoice_id == Some(received_invoice_id))
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<MatchAllocation>, ReportError> {
+      unimplemented!()
+    }
+
+    async fn delete_by_transaction_id(&self, transaction_id: Uuid) -> Result<(), ReportError> {
+      let mut allocations = self.allocations.lock().unwrap();
+      allocations.retain(|a| a.transaction_id != transaction_id);
+      Ok(())
+    }
+  }
+
+  #[async_trait]
+  impl ReceivedInvoiceRepository for InMemoryReports {
+    async fn create(&self, invoice: ReceivedInvoice) -> Result<ReceivedInvoice, ReportError> {
+      self.received_invoices.lock().unwrap().push(invoice.clone());
+      Ok(invoice)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ReceivedInvoice>, ReportError> {
+      let invoices = self.received_invoices.lock().unwrap();
+      Ok(invoices.iter().find(|inv| inv.id == id).cloned())
+    }
+
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<ReceivedInvoice>, ReportError> {
+      unimplemented!()
+    }
+
+    async fn find_by_company_and_date_range(
+      &self,
+      _company_id: Uuid,
+      _start_date: NaiveDate,
+      _end_date: NaiveDate,
+    ) -> Result<Vec<ReceivedInvoice>, ReportError> {
+      unimplemented!()
+    }
+
+    async fn find_unmatched_by_company(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<ReceivedInvoice>, ReportError> {
+      unimplemented!()
+    }
+
+    async fn find_open_by_company(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<ReceivedInvoice>, ReportError> {
+      unimplemented!()
+    }
+
+    async fn find_by_vendor_id(
+      &self,
+      _vendor_id: Uuid,
+    ) -> Result<Vec<ReceivedInvoice>, ReportError> {
+      unimplemented!()
+    }
+
+    async fn update(&self, _invoice: ReceivedInvoice) -> Result<ReceivedInvoice, ReportError> {
+      unimplemented!()
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), ReportError> {
+      let mut invoices = self.received_invoices.lock().unwrap();
+      invoices.retain(|inv| inv.id != id);
+      Ok(())
+    }
+  }
+
+  /// Stands in for the repositories matching doesn't touch
+  struct Unused;
+
+  #[async_trait]
+  impl BankStatementRepository for Unused {
+    async fn create(&self, _statement: BankStatement) -> Result<BankStatement, ReportError> {
+      unimplemented!()
+    }
+    async fn find_by_report_id(&self, _report_id: Uuid) -> Result<Vec<BankStatement>, ReportError> {
+      unimplemented!()
+    }
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<BankStatement>, ReportError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl TransactionCategoryRepository for Unused {
+    async fn create(
+      &self,
+      _category: TransactionCategory,
+    ) -> Result<TransactionCategory, ReportError> {
+      unimplemented!()
+    }
+    async fn find_by_id(&self, _id: Uuid) -> Result<Option<TransactionCategory>, ReportError> {
+      unimplemented!()
+    }
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<TransactionCategory>, ReportError> {
+      unimplemented!()
+    }
+    async fn delete(&self, _id: Uuid) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl CategorySuggestionRepository for Unused {
+    async fn record(&self, _suggestion: CategorySuggestion) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<CategorySuggestion>, ReportError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl MatchingRuleRepository for Unused {
+    async fn create(&self, _rule: MatchingRule) -> Result<MatchingRule, ReportError> {
+      unimplemented!()
+    }
+    async fn find_by_id(&self, _id: Uuid) -> Result<Option<MatchingRule>, ReportError> {
+      unimplemented!()
+    }
+    async fn find_by_company_id(
+      &self,
+      _company_id: Uuid,
+    ) -> Result<Vec<MatchingRule>, ReportError> {
+      unimplemented!()
+    }
+    async fn update_position(&self, _id: Uuid, _position: i32) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+    async fn delete(&self, _id: Uuid) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl ReportEventRepository for Unused {
+    async fn append(&self, _event: ReportEvent) -> Result<ReportEvent, ReportError> {
+      unimplemented!()
+    }
+    async fn find_by_report_id(&self, _report_id: Uuid) -> Result<Vec<ReportEvent>, ReportError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl ReportUploadRepository for Unused {
+    async fn find_by_report_id(&self, _report_id: Uuid) -> Result<Vec<ReportUpload>, ReportError> {
+      unimplemented!()
+    }
+    async fn upsert(&self, _upload: ReportUpload) -> Result<ReportUpload, ReportError> {
+      unimplemented!()
+    }
+    async fn delete(&self, _report_id: Uuid, _path: &str) -> Result<(), ReportError> {
+      unimplemented!()
+    }
+  }
+
+  #[async_trait]
+  impl VendorRepository for Unused {
+    async fn create(&self, _vendor: Vendor) -> Result<Vendor, ReportError> {
+      unimplemented!()
+    }
+    async fn find_by_id(&self, _id: Uuid) -> Result<Option<Vendor>, ReportError> {
+      unimplemented!()
+    }
+    async fn find_by_company_id(&self, _company_id: Uuid) -> Result<Vec<Vendor>, ReportError> {
+      unimplemented!()
+    }
+    async fn update(&self, _vendor: Vendor) -> Result<Vendor, ReportError> {
+      unimplemented!()
+    }
+    async fn merge(&self, _from_id: Uuid, _into: Vendor) -> Result<Vendor, ReportError> {
+      unimplemented!()
+    }
+  }
+
+  fn service(store: &Arc<InMemoryReports>) -> ReportService {
+    let unused = Arc::new(Unused);
+    ReportService::new(ReportServiceDependencies {
+      report_repo: store.clone(),
+      statement_repo: unused.clone(),
+      transaction_repo: store.clone(),
+      received_invoice_repo: store.clone(),
+      category_repo: unused.clone(),
+      suggestion_repo: unused.clone(),
+      rule_repo: unused.clone(),
+      allocation_repo: store.clone(),
+      event_repo: unused.clone(),
+      upload_repo: unused.clone(),
+      vendor_repo: unused,
+    })
+  }
+
+  /// A draft report of the company with one incoming EUR payment
+  fn payment(store: &InMemoryReports, company_id: Uuid, amount: Decimal) -> BankTransaction {
+    let report = MonthlyReport::new(company_id, 3, 2026);
+    let tx = BankTransaction::new(
+      report.id,
+      1,
+      NaiveDate::from_ymd_opt(2026, 3, 10).unwrap(),
+      Some("Customer".to_string()),
+      None,
+      TransactionDirection::Credit,
+      amount,
+      None,
+      None,
+      "EUR".to_string(),
+      None,
+    );
+    store.reports.lock().unwrap().push(report);
+    store.transactions.lock().unwrap().push(tx.clone());
+    tx
+  }
+
+  fn invoice(id: Uuid, company_id: Uuid, total: Decimal, currency: &str) -> MatchDocument {
+    MatchDocument::Invoice {
+      id,
+      company_id,
+      total,
+      currency: currency.to_string(),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_match_rejects_invoice_of_another_company() {
+    let store = Arc::new(InMemoryReports::default());
+    let company_id = Uuid::new_v4();
+    let tx = payment(&store, company_id, dec!(100));
+
+    let result = service(&store)
+      .match_transaction(
+        company_id,
+        tx.id,
+        invoice(Uuid::new_v4(), Uuid::new_v4(), dec!(100), "EUR"),
+        None,
+      )
+      .await;
+
+    assert!(matches!(result, Err(ReportError::Validation(_))));
+    assert!(store.allocations.lock().unwrap().is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_match_rejects_invoice_in_another_currency() {
+    let store = Arc::new(InMemoryReports::default());
+    let company_id = Uuid::new_v4();
+    let tx = payment(&store, company_id, dec!(100));
+
+    let result = service(&store)
+      .match_transaction(
+        company_id,
+        tx.id,
+        invoice(Uuid::new_v4(), company_id, dec!(100), "USD"),
+        None,
+      )
+      .await;
+
+    assert!(matches!(result, Err(ReportError::Validation(message)) if message.contains("USD")));
+    assert!(store.allocations.lock().unwrap().is_empty());
+  }
+
+  #[tokio::test]
+  async fn test_match_rejects_allocating_more_than_the_invoice_has_open() {
+    let store = Arc::new(InMemoryReports::default());
+    let service = service(&store);
+    let company_id = Uuid::new_v4();
+    let invoice_id = Uuid::new_v4();
+    let first = payment(&store, company_id, dec!(80));
+    let second = payment(&store, company_id, dec!(100));
+
+    service
+      .match_transaction(
+        company_id,
+        first.id,
+        invoice(invoice_id, company_id, dec!(120), "EUR"),
+        None,
+      )
+      .await
+      .unwrap();
+    let result = service
+      .match_transaction(
+        company_id,
+        second.id,
+        invoice(invoice_id, company_id, dec!(120), "EUR"),
+        Some(dec!(50)),
+      )
+      .await;
+
+    assert!(matches!(result, Err(ReportError::Validation(message)) if message.contains("Only 40")));
+
+    // Without an amount only what is open is allocated
+    let (_, allocation) = service
+      .match_transaction(
+        company_id,
+        second.id,
+        invoice(invoice_id, company_id, dec!(120), "EUR"),
+        None,
+      )
+      .await
+      .unwrap();
+    assert_eq!(allocation.amount, dec!(40));
+  }
+
+  #[tokio::test]
+  async fn test_matched_received_invoice_cannot_be_deleted() {
+    let store = Arc::new(InMemoryReports::default());
+    let service = service(&store);
+    let company_id = Uuid::new_v4();
+    let bill = ReceivedInvoice::new(
+      company_id,
+      "Vendor".to_string(),
+      dec!(100),
+      "EUR".to_string(),
+      None,
+      None,
+      "bill.pdf".to_string(),
+      None,
+    );
+    store.received_invoices.lock().unwrap().push(bill.clone());
+    let tx = payment(&store, company_id, dec!(100));
+    let mut allocated = tx.clone();
+    let allocation = allocated.allocate(None, Some(bill.id), dec!(100)).unwrap();
+    store.allocations.lock().unwrap().push(allocation);
+
+    let result = service.delete_received_invoice(bill.id).await;
+    assert!(matches!(result, Err(ReportError::ReceivedInvoiceMatched)));
+
+    service.unmatch_transaction(tx.id).await.unwrap();
+    assert_eq!(
+      service.delete_received_invoice(bill.id).await.unwrap(),
+      "bill.pdf"
+    );
+  }
+}
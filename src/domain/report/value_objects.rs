@@ -1,4 +1,7 @@
+use chrono::{Months, NaiveDate};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 use super::errors::ReportError;
 
@@ -30,6 +33,18 @@ impl ReportMonth {
   pub fn folder_name(&self) -> String {
     format!("{:02}.{}", self.month, self.year)
   }
+
+  pub fn first_day(&self) -> NaiveDate {
+    NaiveDate::from_ymd_opt(self.year, self.month, 1).expect("validated month")
+  }
+
+  pub fn last_day(&self) -> NaiveDate {
+    self
+      .first_day()
+      .checked_add_months(Months::new(1))
+      .and_then(|d| d.pred_opt())
+      .expect("validated month")
+  }
 }
 
 /// Direction of a bank transaction
@@ -63,11 +78,13 @@ impl TryFrom<&str> for TransactionDirection {
   }
 }
 
-/// Status of a monthly report
+/// Status of a monthly report. A generated report can be reopened to draft
+/// until it is signed off, which locks it for good.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReportStatus {
   Draft,
   Generated,
+  Locked,
 }
 
 impl ReportStatus {
@@ -75,6 +92,7 @@ impl ReportStatus {
     match self {
       ReportStatus::Draft => "draft",
       ReportStatus::Generated => "generated",
+      ReportStatus::Locked => "locked",
     }
   }
 }
@@ -86,6 +104,7 @@ impl TryFrom<&str> for ReportStatus {
     match s.to_lowercase().as_str() {
       "draft" => Ok(ReportStatus::Draft),
       "generated" => Ok(ReportStatus::Generated),
+      "locked" => Ok(ReportStatus::Locked),
       _ => Err(ReportError::Validation(format!(
         "Invalid report status: '{}'",
         s
@@ -94,6 +113,244 @@ impl TryFrom<&str> for ReportStatus {
   }
 }
 
+/// Payment status of a received invoice. It becomes paid once debit bank
+/// transactions allocated to it cover the gross amount; scheduled and
+/// disputed are set by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PayableStatus {
+  Unpaid,
+  /// Payment is set up in the bank, but not on a statement yet
+  Scheduled,
+  Paid,
+  /// Not to be paid until a disagreement with the vendor is settled
+  Disputed,
+}
+
+impl PayableStatus {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      PayableStatus::Unpaid => "unpaid",
+      PayableStatus::Scheduled => "scheduled",
+      PayableStatus::Paid => "paid",
+      PayableStatus::Disputed => "disputed",
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      PayableStatus::Unpaid => "Unpaid",
+      PayableStatus::Scheduled => "Scheduled",
+      PayableStatus::Paid => "Paid",
+      PayableStatus::Disputed => "Disputed",
+    }
+  }
+
+  pub fn all() -> [PayableStatus; 4] {
+    [
+      PayableStatus::Unpaid,
+      PayableStatus::Scheduled,
+      PayableStatus::Paid,
+      PayableStatus::Disputed,
+    ]
+  }
+}
+
+impl TryFrom<&str> for PayableStatus {
+  type Error = ReportError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "unpaid" => Ok(PayableStatus::Unpaid),
+      "scheduled" => Ok(PayableStatus::Scheduled),
+      "paid" => Ok(PayableStatus::Paid),
+      "disputed" => Ok(PayableStatus::Disputed),
+      _ => Err(ReportError::Validation(format!(
+        "Invalid payable status: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+/// Lifecycle transition recorded in a report's audit trail
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReportEventKind {
+  Generated,
+  Reopened,
+  Regenerated,
+  Locked,
+}
+
+impl ReportEventKind {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      ReportEventKind::Generated => "generated",
+      ReportEventKind::Reopened => "reopened",
+      ReportEventKind::Regenerated => "regenerated",
+      ReportEventKind::Locked => "locked",
+    }
+  }
+
+  /// Human-readable label for the report history
+  pub fn label(&self) -> &'static str {
+    match self {
+      ReportEventKind::Generated => "Generated",
+      ReportEventKind::Reopened => "Reopened",
+      ReportEventKind::Regenerated => "Regenerated",
+      ReportEventKind::Locked => "Signed off and locked",
+    }
+  }
+}
+
+impl TryFrom<&str> for ReportEventKind {
+  type Error = ReportError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "generated" => Ok(ReportEventKind::Generated),
+      "reopened" => Ok(ReportEventKind::Reopened),
+      "regenerated" => Ok(ReportEventKind::Regenerated),
+      "locked" => Ok(ReportEventKind::Locked),
+      _ => Err(ReportError::Validation(format!(
+        "Invalid report event kind: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+/// Transaction field a learned category suggestion is keyed on, in order of
+/// precedence: an account number identifies a counterparty better than a
+/// registry code, which is better than a free-text name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CategoryMatchField {
+  CounterpartyAccount,
+  RegistryCode,
+  CounterpartyName,
+}
+
+impl CategoryMatchField {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      CategoryMatchField::CounterpartyAccount => "counterparty_account",
+      CategoryMatchField::RegistryCode => "registry_code",
+      CategoryMatchField::CounterpartyName => "counterparty_name",
+    }
+  }
+
+  /// Normalized form of a field value so formatting differences between
+  /// statements don't prevent a match. None for blank values.
+  pub fn normalize(&self, value: &str) -> Option<String> {
+    let normalized = match self {
+      CategoryMatchField::CounterpartyAccount => value
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase(),
+      CategoryMatchField::RegistryCode => value.trim().to_string(),
+      CategoryMatchField::CounterpartyName => value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase(),
+    };
+    (!normalized.is_empty()).then_some(normalized)
+  }
+}
+
+impl TryFrom<&str> for CategoryMatchField {
+  type Error = ReportError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "counterparty_account" => Ok(CategoryMatchField::CounterpartyAccount),
+      "registry_code" => Ok(CategoryMatchField::RegistryCode),
+      "counterparty_name" => Ok(CategoryMatchField::CounterpartyName),
+      _ => Err(ReportError::Validation(format!(
+        "Invalid category match field: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+/// What a matching rule does to the transactions it fires on
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum RuleAction {
+  AssignCategory(Uuid),
+  /// Match to an unpaid received invoice of this vendor
+  AttachVendor(String),
+  /// Resolved without a document, e.g. transfers between own accounts
+  Ignore,
+  FlagForReview,
+}
+
+impl RuleAction {
+  pub fn kind(&self) -> &'static str {
+    match self {
+      RuleAction::AssignCategory(_) => "assign_category",
+      RuleAction::AttachVendor(_) => "attach_vendor",
+      RuleAction::Ignore => "ignore",
+      RuleAction::FlagForReview => "flag_for_review",
+    }
+  }
+
+  /// Build an action from its kind and the column or form field it needs
+  pub fn from_parts(
+    kind: &str,
+    category_id: Option<Uuid>,
+    vendor_name: Option<&str>,
+  ) -> Result<Self, ReportError> {
+    match kind {
+      "assign_category" => category_id
+        .map(RuleAction::AssignCategory)
+        .ok_or_else(|| ReportError::Validation("Choose the category the rule assigns".to_string())),
+      "attach_vendor" => vendor_name
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| RuleAction::AttachVendor(name.to_string()))
+        .ok_or_else(|| {
+          ReportError::Validation("Enter the vendor the rule attaches to".to_string())
+        }),
+      "ignore" => Ok(RuleAction::Ignore),
+      "flag_for_review" => Ok(RuleAction::FlagForReview),
+      _ => Err(ReportError::Validation(format!(
+        "Invalid rule action: '{}'",
+        kind
+      ))),
+    }
+  }
+
+  pub fn category_id(&self) -> Option<Uuid> {
+    match self {
+      RuleAction::AssignCategory(id) => Some(*id),
+      _ => None,
+    }
+  }
+
+  pub fn vendor_name(&self) -> Option<&str> {
+    match self {
+      RuleAction::AttachVendor(name) => Some(name),
+      _ => None,
+    }
+  }
+}
+
+/// Invoice a bank transaction is allocated to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchDocument {
+  /// Issued invoice with its gross total, which is computed from its lines,
+  /// and the company it was issued by
+  Invoice {
+    id: Uuid,
+    company_id: Uuid,
+    total: Decimal,
+    currency: String,
+  },
+  ReceivedInvoice(Uuid),
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -147,6 +404,18 @@ mod tests {
     assert_eq!(rm.folder_name(), "12.2026");
   }
 
+  #[test]
+  fn test_report_month_first_and_last_day() {
+    let rm = ReportMonth::new(2, 2028).unwrap();
+    assert_eq!(rm.first_day(), NaiveDate::from_ymd_opt(2028, 2, 1).unwrap());
+    assert_eq!(rm.last_day(), NaiveDate::from_ymd_opt(2028, 2, 29).unwrap());
+    let rm = ReportMonth::new(12, 2026).unwrap();
+    assert_eq!(
+      rm.last_day(),
+      NaiveDate::from_ymd_opt(2026, 12, 31).unwrap()
+    );
+  }
+
   // TransactionDirection tests
 
   #[test]
@@ -203,6 +472,7 @@ mod tests {
   fn test_report_status_as_str() {
     assert_eq!(ReportStatus::Draft.as_str(), "draft");
     assert_eq!(ReportStatus::Generated.as_str(), "generated");
+    assert_eq!(ReportStatus::Locked.as_str(), "locked");
   }
 
   #[test]
@@ -219,6 +489,10 @@ mod tests {
       ReportStatus::try_from("DRAFT").unwrap(),
       ReportStatus::Draft
     );
+    assert_eq!(
+      ReportStatus::try_from("locked").unwrap(),
+      ReportStatus::Locked
+    );
   }
 
   #[test]
@@ -226,4 +500,50 @@ mod tests {
     assert!(ReportStatus::try_from("pending").is_err());
     assert!(ReportStatus::try_from("").is_err());
   }
+
+  #[test]
+  fn test_report_event_kind_round_trip() {
+    for kind in [
+      ReportEventKind::Generated,
+      ReportEventKind::Reopened,
+      ReportEventKind::Regenerated,
+      ReportEventKind::Locked,
+    ] {
+      assert_eq!(ReportEventKind::try_from(kind.as_str()).unwrap(), kind);
+    }
+    assert!(ReportEventKind::try_from("deleted").is_err());
+  }
+
+  // CategoryMatchField tests
+
+  #[test]
+  fn test_category_match_field_normalize() {
+    assert_eq!(
+      CategoryMatchField::CounterpartyAccount.normalize(" ee38 2200 2210 2014 5685 "),
+      Some("EE382200221020145685".to_string())
+    );
+    assert_eq!(
+      CategoryMatchField::CounterpartyName.normalize("  Swedbank   AS "),
+      Some("swedbank as".to_string())
+    );
+    assert_eq!(CategoryMatchField::RegistryCode.normalize("   "), None);
+  }
+
+  // RuleAction tests
+
+  #[test]
+  fn test_rule_action_from_parts() {
+    let category_id = Uuid::new_v4();
+    assert_eq!(
+      RuleAction::from_parts("assign_category", Some(category_id), None).unwrap(),
+      RuleAction::AssignCategory(category_id)
+    );
+    assert_eq!(
+      RuleAction::from_parts("attach_vendor", None, Some(" Zone Media ")).unwrap(),
+      RuleAction::AttachVendor("Zone Media".to_string())
+    );
+    assert!(RuleAction::from_parts("attach_vendor", None, Some("  ")).is_err());
+    assert!(RuleAction::from_parts("assign_category", None, None).is_err());
+    assert!(RuleAction::from_parts("delete", None, None).is_err());
+  }
 }
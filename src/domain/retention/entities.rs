@@ -0,0 +1,126 @@
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::errors::RetentionError;
+use super::value_objects::RetainedRecord;
+
+/// Source documents are kept for 7 years under the Estonian Accounting Act
+pub const DEFAULT_RETENTION_YEARS: u32 = 7;
+/// Shortest retention period a company can set: the legal minimum, a
+/// company may only keep its documents longer
+pub const MIN_RETENTION_YEARS: u32 = DEFAULT_RETENTION_YEARS;
+/// Longest retention period a company can set
+pub const MAX_RETENTION_YEARS: u32 = 50;
+
+/// How long a company keeps its source documents. Issued invoices, received
+/// invoices and reports cannot be deleted before the period ends.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+  pub company_id: Uuid,
+  pub retention_years: u32,
+  pub updated_by: Option<Uuid>,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl RetentionPolicy {
+  pub fn new(
+    company_id: Uuid,
+    retention_years: u32,
+    updated_by: Option<Uuid>,
+  ) -> Result<Self, RetentionError> {
+    if !(MIN_RETENTION_YEARS..=MAX_RETENTION_YEARS).contains(&retention_years) {
+      return Err(RetentionError::Validation(format!(
+        "Retention period must be between {} and {} years",
+        MIN_RETENTION_YEARS, MAX_RETENTION_YEARS
+      )));
+    }
+
+    Ok(Self {
+      company_id,
+      retention_years,
+      updated_by,
+      updated_at: Utc::now(),
+    })
+  }
+
+  /// Policy of a company that has not set one
+  pub fn default_for(company_id: Uuid) -> Self {
+    Self {
+      company_id,
+      retention_years: DEFAULT_RETENTION_YEARS,
+      updated_by: None,
+      updated_at: Utc::now(),
+    }
+  }
+
+  /// Last day a document dated `document_date` is kept: the period runs
+  /// from the end of the document's (calendar) financial year
+  pub fn retained_until(&self, document_date: NaiveDate) -> NaiveDate {
+    let year = document_date.year() + self.retention_years as i32;
+    NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or(NaiveDate::MAX)
+  }
+
+  pub fn is_expired(&self, document_date: NaiveDate, today: NaiveDate) -> bool {
+    today > self.retained_until(document_date)
+  }
+}
+
+/// A deletion refused because the record was still retained
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlockedDeletion {
+  pub id: Uuid,
+  pub company_id: Uuid,
+  pub record: RetainedRecord,
+  pub record_id: Uuid,
+  /// What the user tried to delete, e.g. "Invoice INV-0001"
+  pub label: String,
+  pub user_id: Option<Uuid>,
+  pub retained_until: NaiveDate,
+  pub attempted_at: DateTime<Utc>,
+}
+
+/// A record about to be deleted, checked against its company's policy
+#[derive(Debug, Clone)]
+pub struct DeletionAttempt {
+  pub company_id: Uuid,
+  pub record: RetainedRecord,
+  pub record_id: Uuid,
+  pub label: String,
+  /// Date the retention period counts from, e.g. the invoice date
+  pub document_date: NaiveDate,
+  pub user_id: Option<Uuid>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+  }
+
+  #[test]
+  fn test_retention_runs_from_the_end_of_the_year() {
+    let policy = RetentionPolicy::default_for(Uuid::new_v4());
+
+    assert_eq!(policy.retained_until(date(2025, 1, 2)), date(2032, 12, 31));
+    assert_eq!(
+      policy.retained_until(date(2025, 12, 31)),
+      date(2032, 12, 31)
+    );
+    assert!(!policy.is_expired(date(2025, 3, 1), date(2032, 12, 31)));
+    assert!(policy.is_expired(date(2025, 3, 1), date(2033, 1, 1)));
+  }
+
+  #[test]
+  fn test_retention_period_is_validated() {
+    let company_id = Uuid::new_v4();
+
+    assert!(RetentionPolicy::new(company_id, 10, None).is_ok());
+    assert!(RetentionPolicy::new(company_id, MIN_RETENTION_YEARS, None).is_ok());
+    assert!(RetentionPolicy::new(company_id, MIN_RETENTION_YEARS - 1, None).is_err());
+    assert!(RetentionPolicy::new(company_id, 0, None).is_err());
+    assert!(RetentionPolicy::new(company_id, MAX_RETENTION_YEARS + 1, None).is_err());
+  }
+}
@@ -0,0 +1,39 @@
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::CompanyError;
+
+#[derive(Debug, Error)]
+pub enum RetentionError {
+  #[error("{label} must be kept until {until} under the company's retention policy")]
+  Retained { label: String, until: NaiveDate },
+
+  #[error("User is not a member of this company")]
+  NotMember,
+
+  #[error("Insufficient permissions")]
+  InsufficientPermissions,
+
+  #[error("Validation error: {0}")]
+  Validation(String),
+
+  #[error("Repository error: {0}")]
+  Repository(#[from] RepositoryError),
+}
+
+impl From<sqlx::Error> for RetentionError {
+  fn from(error: sqlx::Error) -> Self {
+    RetentionError::Repository(RepositoryError::from(error))
+  }
+}
+
+impl From<CompanyError> for RetentionError {
+  fn from(error: CompanyError) -> Self {
+    match error {
+      CompanyError::Repository(e) => RetentionError::Repository(e),
+      CompanyError::NotMember => RetentionError::NotMember,
+      other => RetentionError::Validation(other.to_string()),
+    }
+  }
+}
@@ -0,0 +1,14 @@
+pub mod entities;
+pub mod errors;
+pub mod ports;
+pub mod services;
+pub mod value_objects;
+
+pub use entities::{
+  BlockedDeletion, DEFAULT_RETENTION_YEARS, DeletionAttempt, MAX_RETENTION_YEARS,
+  MIN_RETENTION_YEARS, RetentionPolicy,
+};
+pub use errors::RetentionError;
+pub use ports::{BlockedDeletionRepository, RetentionPolicyRepository};
+pub use services::RetentionService;
+pub use value_objects::RetainedRecord;
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{
+  entities::{BlockedDeletion, RetentionPolicy},
+  errors::RetentionError,
+};
+
+#[async_trait]
+pub trait RetentionPolicyRepository: Send + Sync {
+  async fn find(&self, company_id: Uuid) -> Result<Option<RetentionPolicy>, RetentionError>;
+  /// Insert or replace the company's policy
+  async fn save(&self, policy: RetentionPolicy) -> Result<RetentionPolicy, RetentionError>;
+}
+
+/// Append-only log of deletions refused by a retention policy
+#[async_trait]
+pub trait BlockedDeletionRepository: Send + Sync {
+  async fn create(&self, attempt: BlockedDeletion) -> Result<BlockedDeletion, RetentionError>;
+  /// Latest attempts first
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+    limit: i64,
+  ) -> Result<Vec<BlockedDeletion>, RetentionError>;
+}
@@ -0,0 +1,356 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use uuid::Uuid;
+
+use super::{
+  entities::{BlockedDeletion, DeletionAttempt, RetentionPolicy},
+  errors::RetentionError,
+  ports::{BlockedDeletionRepository, RetentionPolicyRepository},
+};
+use crate::domain::company::ports::CompanyMemberRepository;
+
+/// Keeps source documents for their company's retention period: deleting
+/// them earlier is refused, and every refused attempt is logged
+pub struct RetentionService {
+  policy_repo: Arc<dyn RetentionPolicyRepository>,
+  blocked_repo: Arc<dyn BlockedDeletionRepository>,
+  member_repo: Arc<dyn CompanyMemberRepository>,
+}
+
+impl RetentionService {
+  pub fn new(
+    policy_repo: Arc<dyn RetentionPolicyRepository>,
+    blocked_repo: Arc<dyn BlockedDeletionRepository>,
+    member_repo: Arc<dyn CompanyMemberRepository>,
+  ) -> Self {
+    Self {
+      policy_repo,
+      blocked_repo,
+      member_repo,
+    }
+  }
+
+  /// The company's policy, the default one unless it set its own
+  pub async fn policy(&self, company_id: Uuid) -> Result<RetentionPolicy, RetentionError> {
+    Ok(
+      self
+        .policy_repo
+        .find(company_id)
+        .await?
+        .unwrap_or_else(|| RetentionPolicy::default_for(company_id)),
+    )
+  }
+
+  /// The company's policy, for one of its members
+  pub async fn get_policy(
+    &self,
+    user_id: Uuid,
+    company_id: Uuid,
+  ) -> Result<RetentionPolicy, RetentionError> {
+    self.verify_membership(user_id, company_id).await?;
+    self.policy(company_id).await
+  }
+
+  /// Change the retention period (requires owner/admin permission)
+  pub async fn update_policy(
+    &self,
+    user_id: Uuid,
+    company_id: Uuid,
+    retention_years: u32,
+  ) -> Result<RetentionPolicy, RetentionError> {
+    let member = self
+      .member_repo
+      .find_member(company_id, user_id)
+      .await?
+      .ok_or(RetentionError::NotMember)?;
+    if !member.can_manage_members() {
+      return Err(RetentionError::InsufficientPermissions);
+    }
+
+    let policy = RetentionPolicy::new(company_id, retention_years, Some(user_id))?;
+    self.policy_repo.save(policy).await
+  }
+
+  /// Refuse deleting a record its company still has to keep, logging the
+  /// attempt
+  pub async fn guard_deletion(&self, attempt: DeletionAttempt) -> Result<(), RetentionError> {
+    let policy = self.policy(attempt.company_id).await?;
+    if policy.is_expired(attempt.document_date, Utc::now().date_naive()) {
+      return Ok(());
+    }
+
+    let retained_until = policy.retained_until(attempt.document_date);
+    tracing::warn!(
+      "Blocked deletion of {} ({}) of company {} by user {:?}: retained until {}",
+      attempt.label,
+      attempt.record_id,
+      attempt.company_id,
+      attempt.user_id,
+      retained_until
+    );
+    self
+      .blocked_repo
+      .create(BlockedDeletion {
+        id: Uuid::new_v4(),
+        company_id: attempt.company_id,
+        record: attempt.record,
+        record_id: attempt.record_id,
+        label: attempt.label.clone(),
+        user_id: attempt.user_id,
+        retained_until,
+        attempted_at: Utc::now(),
+      })
+      .await?;
+
+    Err(RetentionError::Retained {
+      label: attempt.label,
+      until: retained_until,
+    })
+  }
+
+  /// Last day a record dated `document_date` is kept, `None` once that day
+  /// has passed
+  pub async fn retained_until(
+    &self,
+    company_id: Uuid,
+    document_date: NaiveDate,
+  ) -> Result<Option<NaiveDate>, RetentionError> {
+    let policy = self.policy(company_id).await?;
+    let today = Utc::now().date_naive();
+    Ok((!policy.is_expired(document_date, today)).then(|| policy.retained_until(document_date)))
+  }
+
+  /// Whether a record dated `document_date` may be purged today
+  pub async fn is_expired(
+    &self,
+    company_id: Uuid,
+    document_date: NaiveDate,
+  ) -> Result<bool, RetentionError> {
+    let policy = self.policy(company_id).await?;
+    Ok(policy.is_expired(document_date, Utc::now().date_naive()))
+  }
+
+  /// Latest refused deletions of the company, for one of its members
+  pub async fn blocked_deletions(
+    &self,
+    user_id: Uuid,
+    company_id: Uuid,
+    limit: i64,
+  ) -> Result<Vec<BlockedDeletion>, RetentionError> {
+    self.verify_membership(user_id, company_id).await?;
+    self
+      .blocked_repo
+      .find_by_company_id(company_id, limit)
+      .await
+  }
+
+  async fn verify_membership(&self, user_id: Uuid, company_id: Uuid) -> Result<(), RetentionError> {
+    self
+      .member_repo
+      .find_member(company_id, user_id)
+      .await?
+      .map(|_| ())
+      .ok_or(RetentionError::NotMember)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::company::{CompanyError, CompanyMember, CompanyRole};
+  use crate::domain::retention::value_objects::RetainedRecord;
+  use async_trait::async_trait;
+  use chrono::Datelike;
+  use std::sync::Mutex;
+
+  #[derive(Default)]
+  struct InMemoryPolicies {
+    policies: Mutex<Vec<RetentionPolicy>>,
+  }
+
+  #[async_trait]
+  impl RetentionPolicyRepository for InMemoryPolicies {
+    async fn find(&self, company_id: Uuid) -> Result<Option<RetentionPolicy>, RetentionError> {
+      let policies = self.policies.lock().unwrap();
+      Ok(
+        policies
+          .iter()
+          .find(|p| p.company_id == company_id)
+          .cloned(),
+      )
+    }
+
+    async fn save(&self, policy: RetentionPolicy) -> Result<RetentionPolicy, RetentionError> {
+      let mut policies = self.policies.lock().unwrap();
+      policies.retain(|p| p.company_id != policy.company_id);
+      policies.push(policy.clone());
+      Ok(policy)
+    }
+  }
+
+  #[derive(Default)]
+  struct InMemoryBlocked {
+    attempts: Mutex<Vec<BlockedDeletion>>,
+  }
+
+  #[async_trait]
+  impl BlockedDeletionRepository for InMemoryBlocked {
+    async fn create(&self, attempt: BlockedDeletion) -> Result<BlockedDeletion, RetentionError> {
+      self.attempts.lock().unwrap().push(attempt.clone());
+      Ok(attempt)
+    }
+
+    async fn find_by_company_id(
+      &self,
+      company_id: Uuid,
+      limit: i64,
+    ) -> Result<Vec<BlockedDeletion>, RetentionError> {
+      let attempts = self.attempts.lock().unwrap();
+      Ok(
+        attempts
+          .iter()
+          .rev()
+          .filter(|a| a.company_id == company_id)
+          .take(limit as usize)
+          .cloned()
+          .collect(),
+      )
+    }
+  }
+
+  struct Members(Vec<CompanyMember>);
+
+  #[async_trait]
+  impl CompanyMemberRepository for Members {
+    async fn add_member(&self, member: CompanyMember) -> Result<CompanyMember, CompanyError> {
+      Ok(member)
+    }
+
+    async fn find_by_company_id(
+      &self,
+      company_id: Uuid,
+    ) -> Result<Vec<CompanyMember>, CompanyError> {
+      Ok(
+        self
+          .0
+          .iter()
+          .filter(|m| m.company_id == company_id)
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<CompanyMember>, CompanyError> {
+      Ok(
+        self
+          .0
+          .iter()
+          .filter(|m| m.user_id == user_id)
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn find_member(
+      &self,
+      company_id: Uuid,
+      user_id: Uuid,
+    ) -> Result<Option<CompanyMember>, CompanyError> {
+      Ok(
+        self
+          .0
+          .iter()
+          .find(|m| m.company_id == company_id && m.user_id == user_id)
+          .cloned(),
+      )
+    }
+
+    async fn remove_member(&self, _company_id: Uuid, _user_id: Uuid) -> Result<(), CompanyError> {
+      Ok(())
+    }
+  }
+
+  fn service(members: Vec<CompanyMember>) -> (RetentionService, Arc<InMemoryBlocked>) {
+    let blocked = Arc::new(InMemoryBlocked::default());
+    (
+      RetentionService::new(
+        Arc::new(InMemoryPolicies::default()),
+        blocked.clone(),
+        Arc::new(Members(members)),
+      ),
+      blocked,
+    )
+  }
+
+  fn attempt(company_id: Uuid, document_date: NaiveDate) -> DeletionAttempt {
+    DeletionAttempt {
+      company_id,
+      record: RetainedRecord::IssuedInvoice,
+      record_id: Uuid::new_v4(),
+      label: "Invoice INV-0001".to_string(),
+      document_date,
+      user_id: None,
+    }
+  }
+
+  #[tokio::test]
+  async fn test_retained_records_cannot_be_deleted() {
+    let (service, blocked) = service(vec![]);
+    let company_id = Uuid::new_v4();
+    let today = Utc::now().date_naive();
+
+    let result = service.guard_deletion(attempt(company_id, today)).await;
+    match result {
+      Err(RetentionError::Retained { label, until }) => {
+        assert_eq!(label, "Invoice INV-0001");
+        assert_eq!(until.year(), today.year() + 7);
+      }
+      other => panic!("expected a retained record, got {:?}", other),
+    }
+    assert_eq!(blocked.attempts.lock().unwrap().len(), 1);
+
+    // Past the default period
+    let old = NaiveDate::from_ymd_opt(today.year() - 8, 6, 30).unwrap();
+    service
+      .guard_deletion(attempt(company_id, old))
+      .await
+      .unwrap();
+    assert!(service.is_expired(company_id, old).await.unwrap());
+    assert_eq!(blocked.attempts.lock().unwrap().len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_policy_is_changed_by_owners_and_admins() {
+    let company_id = Uuid::new_v4();
+    let (owner, member) = (Uuid::new_v4(), Uuid::new_v4());
+    let (service, _) = service(vec![
+      CompanyMember::new(company_id, owner, CompanyRole::Owner),
+      CompanyMember::new(company_id, member, CompanyRole::Member),
+    ]);
+
+    assert!(matches!(
+      service.update_policy(member, company_id, 10).await,
+      Err(RetentionError::InsufficientPermissions)
+    ));
+    assert!(matches!(
+      service.get_policy(Uuid::new_v4(), company_id).await,
+      Err(RetentionError::NotMember)
+    ));
+
+    service.update_policy(owner, company_id, 10).await.unwrap();
+    let policy = service.get_policy(member, company_id).await.unwrap();
+    assert_eq!(policy.retention_years, 10);
+    assert_eq!(policy.updated_by, Some(owner));
+
+    // A longer period keeps a document the default one would let go
+    let today = Utc::now().date_naive();
+    let old = NaiveDate::from_ymd_opt(today.year() - 8, 6, 30).unwrap();
+    assert!(
+      service
+        .guard_deletion(attempt(company_id, old))
+        .await
+        .is_err()
+    );
+  }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use super::errors::RetentionError;
+
+/// Kind of source document kept under a retention policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RetainedRecord {
+  /// Invoice that left draft status, with its PDF
+  IssuedInvoice,
+  /// Vendor bill, with its PDF
+  ReceivedInvoice,
+  /// Monthly report with its bank transactions and receipts
+  Report,
+}
+
+impl RetainedRecord {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      RetainedRecord::IssuedInvoice => "issued_invoice",
+      RetainedRecord::ReceivedInvoice => "received_invoice",
+      RetainedRecord::Report => "report",
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      RetainedRecord::IssuedInvoice => "Invoice",
+      RetainedRecord::ReceivedInvoice => "Received invoice",
+      RetainedRecord::Report => "Report",
+    }
+  }
+}
+
+impl TryFrom<&str> for RetainedRecord {
+  type Error = RetentionError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "issued_invoice" => Ok(RetainedRecord::IssuedInvoice),
+      "received_invoice" => Ok(RetainedRecord::ReceivedInvoice),
+      "report" => Ok(RetainedRecord::Report),
+      _ => Err(RetentionError::Validation(format!(
+        "Invalid retained record: '{}'",
+        s
+      ))),
+    }
+  }
+}
@@ -0,0 +1,333 @@
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+
+use super::errors::SaftError;
+
+/// Inclusive date range an audit file covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SaftPeriod {
+  pub start: NaiveDate,
+  pub end: NaiveDate,
+}
+
+impl SaftPeriod {
+  pub fn new(start: NaiveDate, end: NaiveDate) -> Result<Self, SaftError> {
+    if start > end {
+      return Err(SaftError::InvalidPeriod(
+        "Start date must not be after end date".to_string(),
+      ));
+    }
+    Ok(Self { start, end })
+  }
+
+  pub fn contains(&self, date: NaiveDate) -> bool {
+    self.start <= date && date <= self.end
+  }
+}
+
+/// Debit/credit side of a source document line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebitCredit {
+  Debit,
+  Credit,
+}
+
+impl DebitCredit {
+  /// SAF-T `DebitCreditIndicator` value
+  pub fn indicator(&self) -> &'static str {
+    match self {
+      DebitCredit::Debit => "D",
+      DebitCredit::Credit => "C",
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartyAddress {
+  pub street: Option<String>,
+  pub city: Option<String>,
+  pub postal_code: Option<String>,
+  pub region: Option<String>,
+  /// ISO 3166-1 alpha-2 code, if known
+  pub country: Option<String>,
+}
+
+/// The reporting company, a customer or a supplier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Party {
+  /// CustomerID / SupplierID; empty for the reporting company
+  pub id: String,
+  pub registration_number: Option<String>,
+  pub name: String,
+  pub address: PartyAddress,
+  pub vat_number: Option<String>,
+  pub ibans: Vec<String>,
+  /// Control account in the general ledger
+  pub account_code: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditFileHeader {
+  pub created_on: NaiveDate,
+  /// ISO 3166-1 alpha-2 code
+  pub country: String,
+  /// Currency the books are kept in
+  pub currency: String,
+  pub period: SaftPeriod,
+  pub company: Party,
+}
+
+/// Account with its balances at the start and end of the period. Balances
+/// are signed: positive on the debit side, negative on the credit side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneralLedgerAccount {
+  pub code: String,
+  pub name: String,
+  pub account_type: String,
+  /// Set when the account comes from the standard chart of accounts
+  pub standard_code: Option<String>,
+  pub opening_balance: Decimal,
+  pub closing_balance: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxCode {
+  pub code: String,
+  pub description: String,
+  pub rate: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalTransactionLine {
+  pub record_id: String,
+  pub account_code: String,
+  pub description: String,
+  pub debit: Decimal,
+  pub credit: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalTransaction {
+  pub id: String,
+  pub date: NaiveDate,
+  pub description: String,
+  pub source_id: String,
+  pub system_entry_date: NaiveDate,
+  pub lines: Vec<JournalTransactionLine>,
+}
+
+impl JournalTransaction {
+  pub fn period(&self) -> u32 {
+    self.date.month()
+  }
+
+  pub fn period_year(&self) -> i32 {
+    self.date.year()
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Journal {
+  pub id: String,
+  pub description: String,
+  pub kind: String,
+  pub transactions: Vec<JournalTransaction>,
+}
+
+/// Tax charged on an invoice line, or summed per code on the invoice
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaxAmount {
+  pub code: String,
+  pub rate: Decimal,
+  pub base: Decimal,
+  pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceInvoiceLine {
+  pub number: u32,
+  pub account_code: Option<String>,
+  pub description: String,
+  pub quantity: Option<Decimal>,
+  pub unit_price: Option<Decimal>,
+  /// Net amount
+  pub amount: Decimal,
+  pub side: DebitCredit,
+  pub tax: Option<TaxAmount>,
+}
+
+/// Sales invoice (customer) or purchase invoice (supplier)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceInvoice {
+  pub number: String,
+  pub party_id: String,
+  pub billing_address: PartyAddress,
+  pub date: NaiveDate,
+  pub currency: String,
+  pub lines: Vec<SourceInvoiceLine>,
+}
+
+impl SourceInvoice {
+  pub fn net_total(&self) -> Decimal {
+    self.lines.iter().map(|line| line.amount).sum()
+  }
+
+  pub fn gross_total(&self) -> Decimal {
+    self.net_total() + self.tax_totals().iter().map(|t| t.amount).sum::<Decimal>()
+  }
+
+  /// Line taxes summed per tax code, in order of first appearance
+  pub fn tax_totals(&self) -> Vec<TaxAmount> {
+    let mut totals: Vec<TaxAmount> = Vec::new();
+    for tax in self.lines.iter().filter_map(|line| line.tax.as_ref()) {
+      match totals.iter_mut().find(|total| total.code == tax.code) {
+        Some(total) => {
+          total.base += tax.base;
+          total.amount += tax.amount;
+        }
+        None => totals.push(tax.clone()),
+      }
+    }
+    totals
+  }
+}
+
+/// Bank transaction, reported as a single line on the bank account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Payment {
+  pub reference: String,
+  pub date: NaiveDate,
+  pub description: String,
+  pub currency: String,
+  pub account_code: String,
+  /// Debit for money received, credit for money paid out
+  pub side: DebitCredit,
+  pub amount: Decimal,
+  /// One line per invoice the payment settles, plus one for any unallocated
+  /// remainder; together they add up to the amount
+  pub lines: Vec<PaymentLine>,
+}
+
+/// Part of a payment settling one sales or purchase invoice
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentLine {
+  /// Invoice number of the sales or purchase invoice; None for the remainder
+  pub source_document: Option<String>,
+  pub customer_id: Option<String>,
+  pub supplier_id: Option<String>,
+  pub amount: Decimal,
+}
+
+/// Everything that goes into one SAF-T file, mapped to SAF-T concepts but
+/// independent of the XML layout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditFile {
+  pub header: AuditFileHeader,
+  pub accounts: Vec<GeneralLedgerAccount>,
+  pub customers: Vec<Party>,
+  pub suppliers: Vec<Party>,
+  pub tax_codes: Vec<TaxCode>,
+  pub journals: Vec<Journal>,
+  pub sales_invoices: Vec<SourceInvoice>,
+  pub purchase_invoices: Vec<SourceInvoice>,
+  pub payments: Vec<Payment>,
+}
+
+impl AuditFile {
+  /// Number of transactions and total debits and credits over all journals
+  pub fn ledger_totals(&self) -> (usize, Decimal, Decimal) {
+    let transactions = self.journals.iter().flat_map(|j| j.transactions.iter());
+    let lines = transactions.clone().flat_map(|t| t.lines.iter());
+    (
+      transactions.count(),
+      lines.clone().map(|line| line.debit).sum(),
+      lines.map(|line| line.credit).sum(),
+    )
+  }
+}
+
+/// Sum of the net line amounts on each side
+pub fn invoice_side_totals(invoices: &[SourceInvoice]) -> (Decimal, Decimal) {
+  let lines = invoices.iter().flat_map(|invoice| invoice.lines.iter());
+  lines.fold(
+    (Decimal::ZERO, Decimal::ZERO),
+    |(debit, credit), line| match line.side {
+      DebitCredit::Debit => (debit + line.amount, credit),
+      DebitCredit::Credit => (debit, credit + line.amount),
+    },
+  )
+}
+
+/// Sum of payment amounts on each side
+pub fn payment_side_totals(payments: &[Payment]) -> (Decimal, Decimal) {
+  payments.iter().fold(
+    (Decimal::ZERO, Decimal::ZERO),
+    |(debit, credit), payment| match payment.side {
+      DebitCredit::Debit => (debit + payment.amount, credit),
+      DebitCredit::Credit => (debit, credit + payment.amount),
+    },
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rust_decimal_macros::dec;
+
+  fn line(amount: Decimal, tax: Option<(&str, Decimal)>) -> SourceInvoiceLine {
+    SourceInvoiceLine {
+      number: 1,
+      account_code: None,
+      description: "Consulting".to_string(),
+      quantity: None,
+      unit_price: None,
+      amount,
+      side: DebitCredit::Credit,
+      tax: tax.map(|(code, rate)| TaxAmount {
+        code: code.to_string(),
+        rate,
+        base: amount,
+        amount: (amount * rate / dec!(100)).round_dp(2),
+      }),
+    }
+  }
+
+  #[test]
+  fn test_period_rejects_reversed_range() {
+    let start = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2026, 2, 28).unwrap();
+    assert!(SaftPeriod::new(start, end).is_err());
+
+    let period = SaftPeriod::new(end, start).unwrap();
+    assert!(period.contains(start));
+    assert!(!period.contains(NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()));
+  }
+
+  #[test]
+  fn test_source_invoice_tax_totals_group_by_code() {
+    let invoice = SourceInvoice {
+      number: "INV-1".to_string(),
+      party_id: "C1".to_string(),
+      billing_address: PartyAddress::default(),
+      date: NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+      currency: "EUR".to_string(),
+      lines: vec![
+        line(dec!(100.00), Some(("VAT24", dec!(24)))),
+        line(dec!(50.00), Some(("VAT24", dec!(24)))),
+        line(dec!(10.00), Some(("VAT9", dec!(9)))),
+        line(dec!(5.00), None),
+      ],
+    };
+
+    let totals = invoice.tax_totals();
+    assert_eq!(totals.len(), 2);
+    assert_eq!(totals[0].base, dec!(150.00));
+    assert_eq!(totals[0].amount, dec!(36.00));
+    assert_eq!(invoice.net_total(), dec!(165.00));
+    assert_eq!(invoice.gross_total(), dec!(201.90));
+    assert_eq!(
+      invoice_side_totals(&[invoice]),
+      (Decimal::ZERO, dec!(165.00))
+    );
+  }
+}
@@ -0,0 +1,45 @@
+use thiserror::Error;
+
+use crate::domain::company::errors::CompanyError;
+use crate::domain::invoice::errors::InvoiceError;
+use crate::domain::ledger::errors::LedgerError;
+use crate::domain::report::errors::ReportError;
+
+#[derive(Debug, Error)]
+pub enum SaftError {
+  #[error("Invalid period: {0}")]
+  InvalidPeriod(String),
+
+  #[error("Company not found")]
+  CompanyNotFound,
+
+  #[error("Failed to load export data: {0}")]
+  DataAccess(String),
+
+  #[error("Failed to write audit file: {0}")]
+  Serialization(String),
+}
+
+impl From<CompanyError> for SaftError {
+  fn from(error: CompanyError) -> Self {
+    SaftError::DataAccess(error.to_string())
+  }
+}
+
+impl From<InvoiceError> for SaftError {
+  fn from(error: InvoiceError) -> Self {
+    SaftError::DataAccess(error.to_string())
+  }
+}
+
+impl From<ReportError> for SaftError {
+  fn from(error: ReportError) -> Self {
+    SaftError::DataAccess(error.to_string())
+  }
+}
+
+impl From<LedgerError> for SaftError {
+  fn from(error: LedgerError) -> Self {
+    SaftError::DataAccess(error.to_string())
+  }
+}
@@ -0,0 +1,13 @@
+pub mod entities;
+pub mod errors;
+pub mod ports;
+pub mod services;
+
+pub use entities::{
+  AuditFile, AuditFileHeader, DebitCredit, GeneralLedgerAccount, Journal, JournalTransaction,
+  JournalTransactionLine, Party, PartyAddress, Payment, PaymentLine, SaftPeriod, SourceInvoice,
+  SourceInvoiceLine, TaxAmount, TaxCode,
+};
+pub use errors::SaftError;
+pub use ports::AuditFileWriter;
+pub use services::{SaftService, SaftServiceDependencies};
@@ -0,0 +1,7 @@
+use super::{entities::AuditFile, errors::SaftError};
+
+/// Port for serializing an audit file into the format handed to the tax
+/// authority
+pub trait AuditFileWriter: Send + Sync {
+  fn write(&self, audit_file: &AuditFile) -> Result<Vec<u8>, SaftError>;
+}
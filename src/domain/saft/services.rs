@@ -0,0 +1,720 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::{
+  entities::{
+    AuditFile, AuditFileHeader, DebitCredit, GeneralLedgerAccount, Journal, JournalTransaction,
+    JournalTransactionLine, Party, PartyAddress, Payment, PaymentLine, SaftPeriod, SourceInvoice,
+    SourceInvoiceLine, TaxAmount, TaxCode,
+  },
+  errors::SaftError,
+};
+use crate::domain::company::entities::Company;
+use crate::domain::company::ports::{BankAccountRepository, CompanyRepository};
+use crate::domain::invoice::entities::{Customer, Invoice, InvoiceLineItem};
+use crate::domain::invoice::ports::{
+  CustomerRepository, InvoiceLineItemRepository, InvoiceRepository,
+};
+use crate::domain::invoice::value_objects::InvoiceStatus;
+use crate::domain::ledger::chart::{STANDARD_CHART, SystemAccount};
+use crate::domain::ledger::entities::{Account, JournalEntry};
+use crate::domain::ledger::services::{LEDGER_CURRENCY, LedgerService};
+use crate::domain::ledger::value_objects::JournalSource;
+use crate::domain::report::entities::{BankTransaction, ReceivedInvoice};
+use crate::domain::report::ports::{BankTransactionRepository, ReceivedInvoiceRepository};
+use crate::domain::report::value_objects::TransactionDirection;
+
+/// Used when the company address has no ISO country code; TaxByte targets
+/// Estonian companies
+const DEFAULT_COUNTRY: &str = "EE";
+
+/// Dependencies for SaftService
+pub struct SaftServiceDependencies {
+  pub company_repo: Arc<dyn CompanyRepository>,
+  pub bank_account_repo: Arc<dyn BankAccountRepository>,
+  pub customer_repo: Arc<dyn CustomerRepository>,
+  pub invoice_repo: Arc<dyn InvoiceRepository>,
+  pub line_item_repo: Arc<dyn InvoiceLineItemRepository>,
+  pub received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
+  pub bank_transaction_repo: Arc<dyn BankTransactionRepository>,
+  pub ledger_service: Arc<LedgerService>,
+}
+
+/// Collects a company's books for a period into an [`AuditFile`]
+pub struct SaftService {
+  company_repo: Arc<dyn CompanyRepository>,
+  bank_account_repo: Arc<dyn BankAccountRepository>,
+  customer_repo: Arc<dyn CustomerRepository>,
+  invoice_repo: Arc<dyn InvoiceRepository>,
+  line_item_repo: Arc<dyn InvoiceLineItemRepository>,
+  received_invoice_repo: Arc<dyn ReceivedInvoiceRepository>,
+  bank_transaction_repo: Arc<dyn BankTransactionRepository>,
+  ledger_service: Arc<LedgerService>,
+}
+
+impl SaftService {
+  pub fn new(deps: SaftServiceDependencies) -> Self {
+    Self {
+      company_repo: deps.company_repo,
+      bank_account_repo: deps.bank_account_repo,
+      customer_repo: deps.customer_repo,
+      invoice_repo: deps.invoice_repo,
+      line_item_repo: deps.line_item_repo,
+      received_invoice_repo: deps.received_invoice_repo,
+      bank_transaction_repo: deps.bank_transaction_repo,
+      ledger_service: deps.ledger_service,
+    }
+  }
+
+  pub async fn build_audit_file(
+    &self,
+    company_id: Uuid,
+    period: SaftPeriod,
+  ) -> Result<AuditFile, SaftError> {
+    let company = self
+      .company_repo
+      .find_by_id(company_id)
+      .await?
+      .ok_or(SaftError::CompanyNotFound)?;
+    let ibans = self
+      .bank_account_repo
+      .find_by_company_id(company_id, true)
+      .await?
+      .into_iter()
+      .map(|account| account.iban.as_str().to_string())
+      .collect();
+
+    // Ledger
+    let ledger_accounts = self.ledger_service.chart_of_accounts(company_id).await?;
+    let entries = self.ledger_service.list_entries(company_id).await?;
+
+    // Sales: every issued invoice, archived ones included
+    let mut invoices = self.invoice_repo.find_by_company_id(company_id).await?;
+    invoices.extend(
+      self
+        .invoice_repo
+        .find_archived_by_company_id(company_id)
+        .await?,
+    );
+    let customers = self.customer_repo.find_by_company_id(company_id).await?;
+
+    let mut issued: Vec<&Invoice> = invoices
+      .iter()
+      .filter(|invoice| is_issued(invoice.status) && period.contains(invoice.invoice_date))
+      .collect();
+    issued.sort_by(|a, b| {
+      (a.invoice_date, a.invoice_number.value()).cmp(&(b.invoice_date, b.invoice_number.value()))
+    });
+
+    let mut sales_invoices = Vec::with_capacity(issued.len());
+    for invoice in &issued {
+      let line_items = self.line_item_repo.find_by_invoice_id(invoice.id).await?;
+      let customer = customers.iter().find(|c| c.id == invoice.customer_id);
+      sales_invoices.push(sales_invoice(invoice, &line_items, customer));
+    }
+
+    // Purchases
+    let received_invoices = self
+      .received_invoice_repo
+      .find_by_company_id(company_id)
+      .await?;
+    let mut purchase_invoices: Vec<SourceInvoice> = received_invoices
+      .iter()
+      .filter(|invoice| {
+        invoice
+          .invoice_date
+          .is_some_and(|date| period.contains(date))
+      })
+      .map(purchase_invoice)
+      .collect();
+    purchase_invoices.sort_by(|a, b| (a.date, &a.number).cmp(&(b.date, &b.number)));
+
+    // Payments
+    let transactions = self
+      .bank_transaction_repo
+      .find_by_company_and_date_range(company_id, period.start, period.end)
+      .await?;
+    let payments: Vec<Payment> = transactions
+      .iter()
+      .map(|tx| payment(tx, &invoices, &received_invoices))
+      .collect();
+
+    // Master files: only the parties the documents refer to
+    let mut customer_ids: HashSet<Uuid> = issued.iter().map(|i| i.customer_id).collect();
+    customer_ids.extend(
+      transactions
+        .iter()
+        .flat_map(|tx| tx.allocations.iter())
+        .filter_map(|allocation| allocation.invoice_id)
+        .filter_map(|id| invoices.iter().find(|i| i.id == id))
+        .map(|invoice| invoice.customer_id),
+    );
+    let customer_parties = customers
+      .iter()
+      .filter(|customer| customer_ids.contains(&customer.id))
+      .map(customer_party)
+      .collect();
+
+    let mut vendor_names: Vec<&str> = received_invoices
+      .iter()
+      .filter(|invoice| {
+        invoice
+          .invoice_date
+          .is_some_and(|date| period.contains(date))
+      })
+      .chain(
+        transactions
+          .iter()
+          .flat_map(|tx| tx.allocations.iter())
+          .filter_map(|allocation| allocation.received_invoice_id)
+          .filter_map(|id| received_invoices.iter().find(|i| i.id == id)),
+      )
+      .map(|invoice| invoice.vendor_name.as_str())
+      .collect();
+    vendor_names.sort_by_key(|name| supplier_id(name));
+    vendor_names.dedup_by_key(|name| supplier_id(name));
+    let suppliers = vendor_names.into_iter().map(supplier_party).collect();
+
+    let header = AuditFileHeader {
+      created_on: Utc::now().date_naive(),
+      country: company
+        .address
+        .as_ref()
+        .and_then(|address| iso_country(address.country.as_deref()))
+        .unwrap_or_else(|| DEFAULT_COUNTRY.to_string()),
+      currency: LEDGER_CURRENCY.to_string(),
+      period,
+      company: company_party(&company, ibans),
+    };
+
+    Ok(AuditFile {
+      header,
+      accounts: account_balances(&ledger_accounts, &entries, period),
+      customers: customer_parties,
+      suppliers,
+      tax_codes: tax_table(&sales_invoices),
+      journals: journals(&ledger_accounts, &entries, period),
+      sales_invoices,
+      purchase_invoices,
+      payments,
+    })
+  }
+}
+
+/// Drafts were never issued and cancelled invoices are void
+fn is_issued(status: InvoiceStatus) -> bool {
+  !matches!(status, InvoiceStatus::Draft | InvoiceStatus::Cancelled)
+}
+
+/// Two-letter upper-case country code, if `country` is one
+fn iso_country(country: Option<&str>) -> Option<String> {
+  let country = country?.trim();
+  (country.len() == 2 && country.chars().all(|c| c.is_ascii_alphabetic()))
+    .then(|| country.to_ascii_uppercase())
+}
+
+/// Stable SupplierID derived from the vendor name, so the same vendor keeps
+/// its ID across exports
+fn supplier_id(vendor_name: &str) -> String {
+  let normalized = vendor_name.trim().to_lowercase();
+  let digest = Sha256::digest(normalized.as_bytes());
+  format!("V{}", &hex::encode(digest)[..16])
+}
+
+fn tax_code(rate: Decimal) -> String {
+  format!("VAT{}", rate.normalize())
+}
+
+fn company_party(company: &Company, ibans: Vec<String>) -> Party {
+  let address = company
+    .address
+    .as_ref()
+    .map(|address| PartyAddress {
+      street: address.street.clone(),
+      city: address.city.clone(),
+      postal_code: address.postal_code.clone(),
+      region: address.state.clone(),
+      country: iso_country(address.country.as_deref()),
+    })
+    .unwrap_or_default();
+
+  Party {
+    id: String::new(),
+    registration_number: company
+      .registry_code
+      .as_ref()
+      .map(|code| code.as_str().to_string()),
+    name: company.name.clone(),
+    address,
+    vat_number: company
+      .vat_number
+      .as_ref()
+      .map(|vat| vat.as_str().to_string()),
+    ibans,
+    account_code: None,
+  }
+}
+
+fn customer_address(customer: Option<&Customer>) -> PartyAddress {
+  customer
+    .and_then(|customer| customer.address.as_ref())
+    .map(|address| PartyAddress {
+      street: address.street.clone(),
+      city: address.city.clone(),
+      postal_code: address.postal_code.clone(),
+      region: address.state.clone(),
+      country: iso_country(address.country.as_deref()),
+    })
+    .unwrap_or_default()
+}
+
+fn customer_party(customer: &Customer) -> Party {
+  Party {
+    id: customer.id.simple().to_string(),
+    registration_number: None,
+    name: customer.name.value().to_string(),
+    address: customer_address(Some(customer)),
+    vat_number: None,
+    ibans: Vec::new(),
+    account_code: Some(SystemAccount::Receivables.code().to_string()),
+  }
+}
+
+fn supplier_party(vendor_name: &str) -> Party {
+  Party {
+    id: supplier_id(vendor_name),
+    registration_number: None,
+    name: vendor_name.trim().to_string(),
+    address: PartyAddress::default(),
+    vat_number: None,
+    ibans: Vec::new(),
+    account_code: Some(SystemAccount::Payables.code().to_string()),
+  }
+}
+
+fn sales_invoice(
+  invoice: &Invoice,
+  line_items: &[InvoiceLineItem],
+  customer: Option<&Customer>,
+) -> SourceInvoice {
+  let lines = line_items
+    .iter()
+    .enumerate()
+    .map(|(index, item)| {
+      let net = item.subtotal().amount.round_dp(2);
+      let rate = item.vat_rate.value();
+      SourceInvoiceLine {
+        number: index as u32 + 1,
+        account_code: Some(SystemAccount::SalesRevenue.code().to_string()),
+        description: item.description.value().to_string(),
+        quantity: Some(item.quantity.value()),
+        unit_price: Some(item.unit_price.amount),
+        amount: net,
+        side: DebitCredit::Credit,
+        tax: Some(TaxAmount {
+          code: tax_code(rate),
+          rate,
+          base: net,
+          amount: item.vat_amount().amount.round_dp(2),
+        }),
+      }
+    })
+    .collect();
+
+  SourceInvoice {
+    number: invoice.invoice_number.value().to_string(),
+    party_id: invoice.customer_id.simple().to_string(),
+    billing_address: customer_address(customer),
+    date: invoice.invoice_date,
+    currency: invoice.currency.as_str().to_string(),
+    lines,
+  }
+}
+
+/// Received invoices carry totals only, so they are reported as a single net
+/// line; the VAT rate is derived from the VAT and net amounts
+fn purchase_invoice(invoice: &ReceivedInvoice) -> SourceInvoice {
+  let description = invoice
+    .notes
+    .clone()
+    .filter(|notes| !notes.trim().is_empty())
+    .unwrap_or_else(|| format!("Invoice from {}", invoice.vendor_name));
+  let net = invoice.net_amount().abs().round_dp(2);
+  let vat = invoice.vat_amount.abs().round_dp(2);
+  let tax = (!vat.is_zero() && !net.is_zero()).then(|| {
+    let rate = (vat / net * Decimal::ONE_HUNDRED).round_dp(1).normalize();
+    TaxAmount {
+      code: tax_code(rate),
+      rate,
+      base: net,
+      amount: vat,
+    }
+  });
+
+  SourceInvoice {
+    number: invoice
+      .invoice_number
+      .clone()
+      .unwrap_or_else(|| invoice.id.simple().to_string()),
+    party_id: supplier_id(&invoice.vendor_name),
+    billing_address: PartyAddress::default(),
+    date: invoice
+      .invoice_date
+      .unwrap_or_else(|| invoice.created_at.date_naive()),
+    currency: invoice.currency.clone(),
+    lines: vec![SourceInvoiceLine {
+      number: 1,
+      account_code: Some(SystemAccount::PurchasedServices.code().to_string()),
+      description,
+      quantity: None,
+      unit_price: None,
+      amount: net,
+      side: DebitCredit::Debit,
+      tax,
+    }],
+  }
+}
+
+fn payment(
+  transaction: &BankTransaction,
+  invoices: &[Invoice],
+  received_invoices: &[ReceivedInvoice],
+) -> Payment {
+  let mut lines: Vec<PaymentLine> = transaction
+    .allocations
+    .iter()
+    .map(|allocation| {
+      let invoice = allocation
+        .invoice_id
+        .and_then(|id| invoices.iter().find(|invoice| invoice.id == id));
+      let received_invoice = allocation
+        .received_invoice_id
+        .and_then(|id| received_invoices.iter().find(|invoice| invoice.id == id));
+      PaymentLine {
+        source_document: invoice
+          .map(|invoice| invoice.invoice_number.value().to_string())
+          .or_else(|| received_invoice.map(|invoice| purchase_invoice(invoice).number)),
+        customer_id: invoice.map(|invoice| invoice.customer_id.simple().to_string()),
+        supplier_id: received_invoice.map(|invoice| supplier_id(&invoice.vendor_name)),
+        amount: allocation.amount,
+      }
+    })
+    .collect();
+  let unallocated = transaction.unallocated_amount().round_dp(2);
+  if lines.is_empty() || !unallocated.is_zero() {
+    lines.push(PaymentLine {
+      source_document: None,
+      customer_id: None,
+      supplier_id: None,
+      amount: unallocated,
+    });
+  }
+
+  let description = [
+    transaction.counterparty_name.as_deref(),
+    transaction.description.as_deref(),
+  ]
+  .into_iter()
+  .flatten()
+  .map(str::trim)
+  .filter(|part| !part.is_empty())
+  .collect::<Vec<_>>()
+  .join(" - ");
+
+  Payment {
+    reference: transaction.id.simple().to_string(),
+    date: transaction.date,
+    description: if description.is_empty() {
+      "Bank transaction".to_string()
+    } else {
+      description
+    },
+    currency: transaction.currency.clone(),
+    account_code: SystemAccount::Bank.code().to_string(),
+    side: match transaction.direction {
+      TransactionDirection::Credit => DebitCredit::Debit,
+      TransactionDirection::Debit => DebitCredit::Credit,
+    },
+    amount: transaction.amount.abs().round_dp(2),
+    lines,
+  }
+}
+
+/// VAT codes used on the exported sales invoices
+fn tax_table(sales_invoices: &[SourceInvoice]) -> Vec<TaxCode> {
+  let rates: BTreeMap<String, Decimal> = sales_invoices
+    .iter()
+    .flat_map(|invoice| invoice.lines.iter())
+    .filter_map(|line| line.tax.as_ref())
+    .map(|tax| (tax.code.clone(), tax.rate))
+    .collect();
+
+  rates
+    .into_iter()
+    .map(|(code, rate)| TaxCode {
+      code,
+      description: format!("VAT {}%", rate.normalize()),
+      rate,
+    })
+    .collect()
+}
+
+/// Every account of the chart with its balance before and at the end of
+/// the period
+fn account_balances(
+  accounts: &[Account],
+  entries: &[JournalEntry],
+  period: SaftPeriod,
+) -> Vec<GeneralLedgerAccount> {
+  let mut opening: HashMap<Uuid, Decimal> = HashMap::new();
+  let mut closing: HashMap<Uuid, Decimal> = HashMap::new();
+  for entry in entries.iter().filter(|e| e.entry_date <= period.end) {
+    for line in &entry.lines {
+      let net = line.debit - line.credit;
+      *closing.entry(line.account_id).or_default() += net;
+      if entry.entry_date < period.start {
+        *opening.entry(line.account_id).or_default() += net;
+      }
+    }
+  }
+
+  accounts
+    .iter()
+    .map(|account| GeneralLedgerAccount {
+      code: account.code.clone(),
+      name: account.name.clone(),
+      account_type: account.account_type.label().to_string(),
+      standard_code: STANDARD_CHART
+        .iter()
+        .any(|(code, _, _)| *code == account.code)
+        .then(|| account.code.clone()),
+      opening_balance: opening.get(&account.id).copied().unwrap_or_default(),
+      closing_balance: closing.get(&account.id).copied().unwrap_or_default(),
+    })
+    .collect()
+}
+
+/// SAF-T journal an entry is reported under
+fn journal_for(source: JournalSource) -> (&'static str, &'static str) {
+  match source {
+    JournalSource::Manual => ("GL", "General journal"),
+    JournalSource::InvoiceIssued => ("SALES", "Sales journal"),
+    JournalSource::ReceivedInvoice => ("PURCH", "Purchase journal"),
+    JournalSource::InvoicePayment | JournalSource::ReceivedInvoicePayment => {
+      ("BANK", "Bank journal")
+    }
+  }
+}
+
+/// Entries posted within the period, grouped into journals by source
+fn journals(accounts: &[Account], entries: &[JournalEntry], period: SaftPeriod) -> Vec<Journal> {
+  let codes: HashMap<Uuid, &str> = accounts
+    .iter()
+    .map(|account| (account.id, account.code.as_str()))
+    .collect();
+
+  let mut journals: Vec<Journal> = Vec::new();
+  for entry in entries.iter().filter(|e| period.contains(e.entry_date)) {
+    let (id, description) = journal_for(entry.source);
+    let transaction = JournalTransaction {
+      id: entry.id.simple().to_string(),
+      date: entry.entry_date,
+      description: entry.description.clone(),
+      source_id: entry
+        .created_by
+        .map(|user_id| user_id.simple().to_string())
+        .unwrap_or_else(|| "system".to_string()),
+      system_entry_date: entry.created_at.date_naive(),
+      lines: entry
+        .lines
+        .iter()
+        .map(|line| JournalTransactionLine {
+          record_id: (line.line_order + 1).to_string(),
+          account_code: codes
+            .get(&line.account_id)
+            .map(|code| code.to_string())
+            .unwrap_or_default(),
+          description: line
+            .description
+            .clone()
+            .unwrap_or_else(|| entry.description.clone()),
+          debit: line.debit,
+          credit: line.credit,
+        })
+        .collect(),
+    };
+
+    match journals.iter_mut().find(|journal| journal.id == id) {
+      Some(journal) => journal.transactions.push(transaction),
+      None => journals.push(Journal {
+        id: id.to_string(),
+        description: description.to_string(),
+        kind: id.to_string(),
+        transactions: vec![transaction],
+      }),
+    }
+  }
+
+  journals.sort_by(|a, b| a.id.cmp(&b.id));
+  journals
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::ledger::entities::NewJournalLine;
+  use crate::domain::ledger::value_objects::AccountType;
+  use chrono::NaiveDate;
+  use rust_decimal_macros::dec;
+
+  fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+  }
+
+  fn entry(
+    company_id: Uuid,
+    on: NaiveDate,
+    source: JournalSource,
+    debit: &Account,
+    credit: &Account,
+    amount: Decimal,
+  ) -> JournalEntry {
+    JournalEntry::new(
+      company_id,
+      on,
+      "Posting".to_string(),
+      source,
+      None,
+      None,
+      vec![
+        NewJournalLine::debit(debit.id, amount),
+        NewJournalLine::credit(credit.id, amount),
+      ],
+    )
+    .unwrap()
+  }
+
+  #[test]
+  fn test_account_balances_split_opening_and_closing() {
+    let company_id = Uuid::new_v4();
+    let bank = Account::new(
+      company_id,
+      "1020".to_string(),
+      "Bank".to_string(),
+      AccountType::Asset,
+    )
+    .unwrap();
+    let sales = Account::new(
+      company_id,
+      "3050".to_string(),
+      "Web shop".to_string(),
+      AccountType::Revenue,
+    )
+    .unwrap();
+    let entries = [
+      entry(
+        company_id,
+        date(1, 15),
+        JournalSource::Manual,
+        &bank,
+        &sales,
+        dec!(100.00),
+      ),
+      entry(
+        company_id,
+        date(2, 10),
+        JournalSource::Manual,
+        &bank,
+        &sales,
+        dec!(40.00),
+      ),
+      entry(
+        company_id,
+        date(3, 1),
+        JournalSource::Manual,
+        &bank,
+        &sales,
+        dec!(5.00),
+      ),
+    ];
+    let period = SaftPeriod::new(date(2, 1), date(2, 28)).unwrap();
+
+    let balances = account_balances(&[bank, sales], &entries, period);
+    assert_eq!(balances[0].opening_balance, dec!(100.00));
+    assert_eq!(balances[0].closing_balance, dec!(140.00));
+    assert_eq!(balances[0].standard_code.as_deref(), Some("1020"));
+    assert_eq!(balances[1].closing_balance, dec!(-140.00));
+    assert_eq!(balances[1].standard_code, None);
+  }
+
+  #[test]
+  fn test_journals_group_entries_in_period_by_source() {
+    let company_id = Uuid::new_v4();
+    let bank = Account::new(
+      company_id,
+      "1020".to_string(),
+      "Bank".to_string(),
+      AccountType::Asset,
+    )
+    .unwrap();
+    let receivables = Account::new(
+      company_id,
+      "1200".to_string(),
+      "Receivables".to_string(),
+      AccountType::Asset,
+    )
+    .unwrap();
+    let entries = [
+      entry(
+        company_id,
+        date(2, 1),
+        JournalSource::InvoicePayment,
+        &bank,
+        &receivables,
+        dec!(10.00),
+      ),
+      entry(
+        company_id,
+        date(2, 2),
+        JournalSource::ReceivedInvoicePayment,
+        &receivables,
+        &bank,
+        dec!(3.00),
+      ),
+      entry(
+        company_id,
+        date(4, 1),
+        JournalSource::Manual,
+        &bank,
+        &receivables,
+        dec!(1.00),
+      ),
+    ];
+    let period = SaftPeriod::new(date(2, 1), date(2, 28)).unwrap();
+
+    let journals = journals(&[bank, receivables], &entries, period);
+    assert_eq!(journals.len(), 1);
+    assert_eq!(journals[0].id, "BANK");
+    assert_eq!(journals[0].transactions.len(), 2);
+    assert_eq!(journals[0].transactions[0].lines[0].account_code, "1020");
+    assert_eq!(journals[0].transactions[0].source_id, "system");
+  }
+
+  #[test]
+  fn test_supplier_id_is_stable_across_spelling_variants() {
+    assert_eq!(
+      supplier_id("Telia Eesti AS"),
+      supplier_id(" telia eesti as ")
+    );
+    assert_ne!(supplier_id("Telia Eesti AS"), supplier_id("Elisa Eesti AS"));
+    assert_eq!(supplier_id("Telia Eesti AS").len(), 17);
+    assert_eq!(iso_country(Some("ee")), Some("EE".to_string()));
+    assert_eq!(iso_country(Some("Estonia")), None);
+    assert_eq!(tax_code(dec!(22.00)), "VAT22");
+  }
+}
@@ -0,0 +1,406 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::errors::StorageError;
+use super::value_objects::{SyncStatus, UploadAction, UploadStatus, UploadTarget};
+use crate::domain::company::StorageProvider;
+
+/// File waiting in the outbox to be pushed to the company's storage
+/// provider. Failed attempts are retried with exponential backoff until
+/// `MAX_ATTEMPTS`, after which the job stays failed until retried by hand.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UploadJob {
+  pub id: Uuid,
+  pub company_id: Uuid,
+  pub target: UploadTarget,
+  /// Storage the company had when the file was queued
+  pub provider: StorageProvider,
+  /// Report or invoice the file belongs to
+  pub owner_id: Uuid,
+  pub action: UploadAction,
+  /// Identity of the file within its report or invoice, e.g. the allocation
+  /// it documents. Queueing the same key again replaces the job, whatever
+  /// the file is named.
+  pub file_key: String,
+  /// Path below the report's month folder, or the invoice number
+  pub path: String,
+  /// Cloud ID of the folder to upload into
+  pub folder_id: String,
+  /// Cloud ID of an earlier upload whose content the file replaces, or
+  /// which a delete job removes
+  pub replace_file_id: Option<String>,
+  pub local_path: String,
+  pub mime_type: String,
+  /// SHA-256 of the file when it was queued
+  pub content_hash: Option<String>,
+  /// User whose action queued the upload
+  pub requested_by: Option<Uuid>,
+  pub status: UploadStatus,
+  pub attempts: i32,
+  pub next_attempt_at: DateTime<Utc>,
+  pub last_error: Option<String>,
+  /// Cloud ID of the uploaded file once it succeeded
+  pub cloud_id: Option<String>,
+  /// How the uploaded file compared to Drive when last checked
+  pub sync_status: Option<SyncStatus>,
+  pub synced_at: Option<DateTime<Utc>>,
+  pub created_at: DateTime<Utc>,
+  pub updated_at: DateTime<Utc>,
+}
+
+impl UploadJob {
+  pub const MAX_ATTEMPTS: i32 = 8;
+
+  /// How long a claimed job is left alone before another push may take it
+  /// over, in case the one that claimed it died mid-upload
+  pub const CLAIM_LEASE_MINUTES: i64 = 10;
+
+  pub fn new(
+    company_id: Uuid,
+    target: UploadTarget,
+    owner_id: Uuid,
+    path: String,
+    folder_id: String,
+    local_path: String,
+    requested_by: Uuid,
+  ) -> Self {
+    let now = Utc::now();
+    Self {
+      id: Uuid::new_v4(),
+      company_id,
+      target,
+      provider: StorageProvider::None,
+      owner_id,
+      action: UploadAction::Upload,
+      file_key: path.clone(),
+      path,
+      folder_id,
+      replace_file_id: None,
+      local_path,
+      mime_type: "application/pdf".to_string(),
+      content_hash: None,
+      requested_by: (!requested_by.is_nil()).then_some(requested_by),
+      status: UploadStatus::Pending,
+      attempts: 0,
+      next_attempt_at: now,
+      last_error: None,
+      cloud_id: None,
+      sync_status: None,
+      synced_at: None,
+      created_at: now,
+      updated_at: now,
+    }
+  }
+
+  /// Job removing the file uploaded at `path` under `file_id`, keyed apart
+  /// from the upload of whatever file takes its place
+  pub fn deletion(
+    company_id: Uuid,
+    target: UploadTarget,
+    owner_id: Uuid,
+    path: String,
+    file_id: String,
+    requested_by: Uuid,
+  ) -> Self {
+    let mut job = Self::new(
+      company_id,
+      target,
+      owner_id,
+      path.clone(),
+      String::new(),
+      String::new(),
+      requested_by,
+    );
+    job.action = UploadAction::Delete;
+    job.file_key = format!("delete/{}", path);
+    job.replace_file_id = Some(file_id);
+    job
+  }
+
+  /// Name of the uploaded file, the last part of its path
+  pub fn file_name(&self) -> &str {
+    self.path.rsplit('/').next().unwrap_or(&self.path)
+  }
+
+  /// Name the file was uploaded under; invoice PDFs are named after the
+  /// invoice number
+  pub fn cloud_file_name(&self) -> String {
+    match self.target {
+      UploadTarget::InvoicePdf => format!("{}.pdf", self.path),
+      UploadTarget::ReportFile => self.file_name().to_string(),
+    }
+  }
+
+  /// Status as shown to users
+  pub fn status_label(&self) -> &'static str {
+    match (self.action, self.status) {
+      (UploadAction::Delete, UploadStatus::Pending) => "Waiting to delete",
+      (UploadAction::Delete, UploadStatus::InProgress) => "Deleting",
+      (_, status) => status.label(),
+    }
+  }
+
+  /// Not uploaded yet, whether still retrying or given up
+  pub fn is_open(&self) -> bool {
+    self.status != UploadStatus::Succeeded
+  }
+
+  /// Pending and due, or claimed by a push whose lease ran out
+  pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+    matches!(
+      self.status,
+      UploadStatus::Pending | UploadStatus::InProgress
+    ) && self.next_attempt_at <= now
+  }
+
+  /// Take the job for one attempt; other pushes skip it until it succeeds,
+  /// fails or the lease runs out
+  pub fn claim(&mut self, now: DateTime<Utc>) {
+    self.status = UploadStatus::InProgress;
+    self.next_attempt_at = now + Duration::minutes(Self::CLAIM_LEASE_MINUTES);
+    self.updated_at = now;
+  }
+
+  /// Wait before the attempt after `attempts` failed ones: one minute,
+  /// doubling each time, at most an hour
+  pub fn backoff(attempts: i32) -> Duration {
+    let minutes = 1i64 << (attempts - 1).clamp(0, 6);
+    Duration::minutes(minutes.min(60))
+  }
+
+  pub fn succeed(&mut self, cloud_id: String) {
+    self.attempts += 1;
+    self.status = UploadStatus::Succeeded;
+    self.cloud_id = Some(cloud_id);
+    self.last_error = None;
+    self.sync_status = None;
+    self.synced_at = None;
+    self.updated_at = Utc::now();
+  }
+
+  /// Compare the uploaded file with what Drive has under its ID, `None`
+  /// when Drive doesn't know it
+  pub fn sync_status_of(&self, remote: Option<&RemoteFile>) -> SyncStatus {
+    match remote {
+      None => SyncStatus::Missing,
+      Some(remote) if remote.trashed => SyncStatus::Missing,
+      // Invoices uploaded without a folder land in the Drive root
+      Some(remote) if !self.folder_id.is_empty() && !remote.parents.contains(&self.folder_id) => {
+        SyncStatus::Moved
+      }
+      Some(remote) if remote.name != self.cloud_file_name() => SyncStatus::Renamed,
+      Some(_) => SyncStatus::InSync,
+    }
+  }
+
+  pub fn record_sync(&mut self, status: SyncStatus, now: DateTime<Utc>) {
+    self.sync_status = Some(status);
+    self.synced_at = Some(now);
+  }
+
+  /// Queue an uploaded file again after it went missing from Drive, as a
+  /// new file rather than a new version of the lost one
+  pub fn reupload(&mut self, now: DateTime<Utc>) {
+    self.record_sync(SyncStatus::Missing, now);
+    self.status = UploadStatus::Pending;
+    self.attempts = 0;
+    self.next_attempt_at = now;
+    self.replace_file_id = None;
+    self.cloud_id = None;
+    self.last_error = None;
+    self.updated_at = now;
+  }
+
+  pub fn fail(&mut self, error: String, now: DateTime<Utc>) {
+    self.attempts += 1;
+    self.last_error = Some(error);
+    if self.attempts >= Self::MAX_ATTEMPTS {
+      self.status = UploadStatus::Failed;
+    } else {
+      self.status = UploadStatus::Pending;
+      self.next_attempt_at = now + Self::backoff(self.attempts);
+    }
+    self.updated_at = now;
+  }
+
+  /// Start over with a failed job, attempting it right away
+  pub fn retry(&mut self, now: DateTime<Utc>) -> Result<(), StorageError> {
+    if self.status != UploadStatus::Failed {
+      return Err(StorageError::Validation(
+        "Only failed uploads can be retried".to_string(),
+      ));
+    }
+    self.status = UploadStatus::Pending;
+    self.attempts = 0;
+    self.next_attempt_at = now;
+    self.updated_at = now;
+    Ok(())
+  }
+
+  /// Take over a newer version of the same file queued while this job was
+  /// still open, keeping its ID
+  pub fn replace_with(&mut self, newer: UploadJob) {
+    *self = UploadJob {
+      id: self.id,
+      created_at: self.created_at,
+      ..newer
+    };
+  }
+}
+
+/// A file as Drive has it
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteFile {
+  pub name: String,
+  /// IDs of the folders it is in
+  pub parents: Vec<String>,
+  pub trashed: bool,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn job() -> UploadJob {
+    UploadJob::new(
+      Uuid::new_v4(),
+      UploadTarget::ReportFile,
+      Uuid::new_v4(),
+      "EE12 (Main)/incoming/2026-03-10 - Acme.pdf".to_string(),
+      "folder-1".to_string(),
+      "data/invoices/1.pdf".to_string(),
+      Uuid::nil(),
+    )
+  }
+
+  #[test]
+  fn test_new_job_is_due_right_away() {
+    let job = job();
+
+    assert_eq!(job.status, UploadStatus::Pending);
+    assert_eq!(job.requested_by, None);
+    assert_eq!(job.file_name(), "2026-03-10 - Acme.pdf");
+    assert!(job.is_due(Utc::now()));
+  }
+
+  #[test]
+  fn test_deletion_is_keyed_apart_from_the_upload_it_removes() {
+    let upload = job();
+    let deletion = UploadJob::deletion(
+      upload.company_id,
+      UploadTarget::ReportFile,
+      upload.owner_id,
+      upload.path.clone(),
+      "file-1".to_string(),
+      Uuid::nil(),
+    );
+
+    assert_eq!(deletion.action, UploadAction::Delete);
+    assert_ne!(deletion.file_key, upload.file_key);
+    assert_eq!(deletion.replace_file_id.as_deref(), Some("file-1"));
+    assert_eq!(deletion.status_label(), "Waiting to delete");
+    assert!(deletion.is_due(Utc::now()));
+  }
+
+  #[test]
+  fn test_backoff_doubles_up_to_an_hour() {
+    assert_eq!(UploadJob::backoff(1), Duration::minutes(1));
+    assert_eq!(UploadJob::backoff(2), Duration::minutes(2));
+    assert_eq!(UploadJob::backoff(4), Duration::minutes(8));
+    assert_eq!(UploadJob::backoff(7), Duration::minutes(60));
+    assert_eq!(UploadJob::backoff(20), Duration::minutes(60));
+  }
+
+  #[test]
+  fn test_failed_attempts_back_off_then_give_up() {
+    let mut job = job();
+    let now = Utc::now();
+
+    job.fail("timeout".to_string(), now);
+    assert_eq!(job.status, UploadStatus::Pending);
+    assert_eq!(job.next_attempt_at, now + Duration::minutes(1));
+    assert!(!job.is_due(now));
+    assert!(job.is_due(now + Duration::minutes(1)));
+
+    for _ in 1..UploadJob::MAX_ATTEMPTS {
+      job.fail("timeout".to_string(), now);
+    }
+    assert_eq!(job.status, UploadStatus::Failed);
+    assert_eq!(job.attempts, UploadJob::MAX_ATTEMPTS);
+    assert!(!job.is_due(now + Duration::days(1)));
+    assert!(job.is_open());
+
+    job.retry(now).unwrap();
+    assert_eq!(job.attempts, 0);
+    assert!(job.is_due(now));
+    assert!(job.retry(now).is_err());
+
+    job.succeed("file-1".to_string());
+    assert_eq!(job.status, UploadStatus::Succeeded);
+    assert_eq!(job.cloud_id.as_deref(), Some("file-1"));
+    assert_eq!(job.last_error, None);
+    assert!(!job.is_open());
+  }
+
+  #[test]
+  fn test_claimed_job_is_left_alone_until_its_lease_runs_out() {
+    let mut job = job();
+    let now = Utc::now();
+
+    job.claim(now);
+    assert_eq!(job.status, UploadStatus::InProgress);
+    assert!(job.is_open());
+    assert!(!job.is_due(now));
+    assert!(job.is_due(now + Duration::minutes(UploadJob::CLAIM_LEASE_MINUTES)));
+
+    job.fail("timeout".to_string(), now);
+    assert_eq!(job.status, UploadStatus::Pending);
+    assert!(job.is_due(now + Duration::minutes(1)));
+  }
+
+  #[test]
+  fn test_sync_status_compares_folder_and_name() {
+    let job = job();
+    let remote = |name: &str, parent: &str, trashed: bool| RemoteFile {
+      name: name.to_string(),
+      parents: vec![parent.to_string()],
+      trashed,
+    };
+
+    assert_eq!(
+      job.sync_status_of(Some(&remote("2026-03-10 - Acme.pdf", "folder-1", false))),
+      SyncStatus::InSync
+    );
+    assert_eq!(
+      job.sync_status_of(Some(&remote("2026-03-10 - Acme.pdf", "folder-2", false))),
+      SyncStatus::Moved
+    );
+    assert_eq!(
+      job.sync_status_of(Some(&remote("acme.pdf", "folder-1", false))),
+      SyncStatus::Renamed
+    );
+    assert_eq!(
+      job.sync_status_of(Some(&remote("2026-03-10 - Acme.pdf", "folder-1", true))),
+      SyncStatus::Missing
+    );
+    assert_eq!(job.sync_status_of(None), SyncStatus::Missing);
+  }
+
+  #[test]
+  fn test_missing_file_is_uploaded_again_as_a_new_file() {
+    let mut job = job();
+    job.replace_file_id = Some("file-0".to_string());
+    job.succeed("file-1".to_string());
+    let now = Utc::now();
+
+    job.reupload(now);
+    assert!(job.is_due(now));
+    assert_eq!(job.sync_status, Some(SyncStatus::Missing));
+    assert_eq!(job.cloud_id, None);
+    assert_eq!(job.replace_file_id, None);
+
+    job.succeed("file-2".to_string());
+    assert_eq!(job.sync_status, None);
+  }
+}
@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+use crate::domain::auth::errors::RepositoryError;
+
+#[derive(Debug, Error)]
+pub enum StorageError {
+  #[error("Upload job not found")]
+  JobNotFound,
+
+  #[error("Validation error: {0}")]
+  Validation(String),
+
+  #[error("Upload failed: {0}")]
+  UploadFailed(String),
+
+  #[error("Repository error: {0}")]
+  Repository(#[from] RepositoryError),
+}
+
+impl From<sqlx::Error> for StorageError {
+  fn from(error: sqlx::Error) -> Self {
+    StorageError::Repository(RepositoryError::from(error))
+  }
+}
@@ -0,0 +1,11 @@
+pub mod entities;
+pub mod errors;
+pub mod ports;
+pub mod services;
+pub mod value_objects;
+
+pub use entities::{RemoteFile, UploadJob};
+pub use errors::StorageError;
+pub use ports::{DriveFileInspector, DriveInspectorResolver, UploadJobRepository};
+pub use services::UploadOutboxService;
+pub use value_objects::{SyncStatus, UploadAction, UploadStatus, UploadTarget};
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::{
+  entities::{RemoteFile, UploadJob},
+  errors::StorageError,
+  value_objects::UploadTarget,
+};
+
+#[async_trait]
+pub trait UploadJobRepository: Send + Sync {
+  async fn create(&self, job: UploadJob) -> Result<UploadJob, StorageError>;
+  async fn update(&self, job: UploadJob) -> Result<UploadJob, StorageError>;
+  async fn delete(&self, id: Uuid) -> Result<(), StorageError>;
+  /// Store the claim of a job only if it was still due at `now`, in one
+  /// statement so two pushes never both win. `None` when another push got
+  /// there first.
+  async fn claim(
+    &self,
+    job: UploadJob,
+    now: DateTime<Utc>,
+  ) -> Result<Option<UploadJob>, StorageError>;
+  /// Pending jobs whose next attempt is due, oldest first
+  async fn find_due(
+    &self,
+    target: UploadTarget,
+    now: DateTime<Utc>,
+    limit: i64,
+  ) -> Result<Vec<UploadJob>, StorageError>;
+  /// Jobs of a report or invoice, ordered by path
+  async fn find_by_owner(
+    &self,
+    target: UploadTarget,
+    owner_id: Uuid,
+  ) -> Result<Vec<UploadJob>, StorageError>;
+  /// Uploaded files of a company, oldest first
+  async fn find_uploaded(&self, company_id: Uuid) -> Result<Vec<UploadJob>, StorageError>;
+}
+
+/// Port for looking up uploaded files in Google Drive
+#[async_trait]
+pub trait DriveFileInspector: Send + Sync {
+  /// The file with the given ID, `None` when Drive doesn't know it
+  async fn inspect(&self, file_id: &str) -> Result<Option<RemoteFile>, StorageError>;
+}
+
+/// Port for the Drive a company's files are uploaded to
+#[async_trait]
+pub trait DriveInspectorResolver: Send + Sync {
+  /// `None` unless the company stores its files in a connected Google Drive
+  async fn for_company(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Option<Arc<dyn DriveFileInspector>>, StorageError>;
+}
@@ -0,0 +1,318 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::{
+  entities::UploadJob,
+  errors::StorageError,
+  ports::UploadJobRepository,
+  value_objects::{SyncStatus, UploadTarget},
+};
+
+/// Persistent outbox of files to push to storage providers, so a failed
+/// upload is retried instead of lost
+pub struct UploadOutboxService {
+  job_repo: Arc<dyn UploadJobRepository>,
+}
+
+impl UploadOutboxService {
+  pub fn new(job_repo: Arc<dyn UploadJobRepository>) -> Self {
+    Self { job_repo }
+  }
+
+  /// Queue a file; a job already queued for the same file key takes over
+  /// the new name and content and starts over
+  pub async fn enqueue(&self, job: UploadJob) -> Result<UploadJob, StorageError> {
+    let existing = self
+      .job_repo
+      .find_by_owner(job.target, job.owner_id)
+      .await?
+      .into_iter()
+      .find(|j| j.file_key == job.file_key);
+
+    match existing {
+      Some(mut existing) => {
+        existing.replace_with(job);
+        self.job_repo.update(existing).await
+      }
+      None => self.job_repo.create(job).await,
+    }
+  }
+
+  /// Jobs of a report or invoice, uploaded ones included
+  pub async fn jobs(
+    &self,
+    target: UploadTarget,
+    owner_id: Uuid,
+  ) -> Result<Vec<UploadJob>, StorageError> {
+    self.job_repo.find_by_owner(target, owner_id).await
+  }
+
+  /// Jobs of a report or invoice not uploaded yet
+  pub async fn open_jobs(
+    &self,
+    target: UploadTarget,
+    owner_id: Uuid,
+  ) -> Result<Vec<UploadJob>, StorageError> {
+    let jobs = self.jobs(target, owner_id).await?;
+    Ok(jobs.into_iter().filter(|j| j.is_open()).collect())
+  }
+
+  /// Pending jobs of all companies whose next attempt is due
+  pub async fn due_jobs(
+    &self,
+    target: UploadTarget,
+    limit: i64,
+  ) -> Result<Vec<UploadJob>, StorageError> {
+    self.job_repo.find_due(target, Utc::now(), limit).await
+  }
+
+  /// Take a due job for one attempt. `None` when it is no longer due, e.g.
+  /// the background worker and a request pushing the same job at once: only
+  /// one of them gets it.
+  pub async fn claim(&self, mut job: UploadJob) -> Result<Option<UploadJob>, StorageError> {
+    let now = Utc::now();
+    if !job.is_due(now) {
+      return Ok(None);
+    }
+    job.claim(now);
+    self.job_repo.claim(job, now).await
+  }
+
+  pub async fn record_success(
+    &self,
+    mut job: UploadJob,
+    cloud_id: String,
+  ) -> Result<UploadJob, StorageError> {
+    job.succeed(cloud_id);
+    self.job_repo.update(job).await
+  }
+
+  /// Schedule the next attempt, or give up after the last one
+  pub async fn record_failure(
+    &self,
+    mut job: UploadJob,
+    error: String,
+  ) -> Result<UploadJob, StorageError> {
+    tracing::warn!(
+      "Upload of {} ({} {}) failed on attempt {}: {}",
+      job.path,
+      job.target.as_str(),
+      job.owner_id,
+      job.attempts + 1,
+      error
+    );
+    job.fail(error, Utc::now());
+    self.job_repo.update(job).await
+  }
+
+  /// Drop a job whose file is no longer needed, e.g. an invoice unmatched
+  /// from a report before its upload went through
+  pub async fn discard(&self, job: UploadJob) -> Result<(), StorageError> {
+    self.job_repo.delete(job.id).await
+  }
+
+  /// Put the failed jobs of a report or invoice back in the queue, due now
+  pub async fn retry_failed(
+    &self,
+    target: UploadTarget,
+    owner_id: Uuid,
+  ) -> Result<Vec<UploadJob>, StorageError> {
+    let now = Utc::now();
+    let mut retried = Vec::new();
+    for mut job in self.job_repo.find_by_owner(target, owner_id).await? {
+      if job.retry(now).is_ok() {
+        retried.push(self.job_repo.update(job).await?);
+      }
+    }
+    Ok(retried)
+  }
+
+  /// Uploaded files of a company, to compare with its storage
+  pub async fn uploaded_jobs(&self, company_id: Uuid) -> Result<Vec<UploadJob>, StorageError> {
+    self.job_repo.find_uploaded(company_id).await
+  }
+
+  /// Remember how an uploaded file compared to Drive; a missing one is
+  /// queued again
+  pub async fn record_sync(
+    &self,
+    mut job: UploadJob,
+    status: SyncStatus,
+  ) -> Result<UploadJob, StorageError> {
+    let now = Utc::now();
+    if status == SyncStatus::Missing {
+      tracing::warn!(
+        "{} ({} {}) is missing from Drive, uploading it again",
+        job.path,
+        job.target.as_str(),
+        job.owner_id
+      );
+      job.reupload(now);
+    } else {
+      if status.is_drifted() && job.sync_status != Some(status) {
+        tracing::info!(
+          "{} ({} {}): {}",
+          job.path,
+          job.target.as_str(),
+          job.owner_id,
+          status.label()
+        );
+      }
+      job.record_sync(status, now);
+    }
+    self.job_repo.update(job).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::storage::UploadStatus;
+  use async_trait::async_trait;
+  use chrono::DateTime;
+  use std::sync::Mutex;
+
+  #[derive(Default)]
+  struct InMemoryJobs(Mutex<Vec<UploadJob>>);
+
+  #[async_trait]
+  impl UploadJobRepository for InMemoryJobs {
+    async fn create(&self, job: UploadJob) -> Result<UploadJob, StorageError> {
+      self.0.lock().unwrap().push(job.clone());
+      Ok(job)
+    }
+
+    async fn update(&self, job: UploadJob) -> Result<UploadJob, StorageError> {
+      let mut jobs = self.0.lock().unwrap();
+      let stored = jobs
+        .iter_mut()
+        .find(|j| j.id == job.id)
+        .ok_or(StorageError::JobNotFound)?;
+      *stored = job.clone();
+      Ok(job)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), StorageError> {
+      self.0.lock().unwrap().retain(|j| j.id != id);
+      Ok(())
+    }
+
+    async fn claim(
+      &self,
+      job: UploadJob,
+      now: DateTime<Utc>,
+    ) -> Result<Option<UploadJob>, StorageError> {
+      let mut jobs = self.0.lock().unwrap();
+      match jobs.iter_mut().find(|j| j.id == job.id && j.is_due(now)) {
+        Some(stored) => {
+          *stored = job.clone();
+          Ok(Some(job))
+        }
+        None => Ok(None),
+      }
+    }
+
+    async fn find_due(
+      &self,
+      target: UploadTarget,
+      now: DateTime<Utc>,
+      _limit: i64,
+    ) -> Result<Vec<UploadJob>, StorageError> {
+      let jobs = self.0.lock().unwrap();
+      Ok(
+        jobs
+          .iter()
+          .filter(|j| j.target == target && j.is_due(now))
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn find_by_owner(
+      &self,
+      target: UploadTarget,
+      owner_id: Uuid,
+    ) -> Result<Vec<UploadJob>, StorageError> {
+      let jobs = self.0.lock().unwrap();
+      Ok(
+        jobs
+          .iter()
+          .filter(|j| j.target == target && j.owner_id == owner_id)
+          .cloned()
+          .collect(),
+      )
+    }
+
+    async fn find_uploaded(&self, _company_id: Uuid) -> Result<Vec<UploadJob>, StorageError> {
+      Ok(Vec::new())
+    }
+  }
+
+  fn job(report_id: Uuid, file_key: &str, path: &str) -> UploadJob {
+    let mut job = UploadJob::new(
+      Uuid::new_v4(),
+      UploadTarget::ReportFile,
+      report_id,
+      path.to_string(),
+      "folder-1".to_string(),
+      format!("data/{}.pdf", file_key),
+      Uuid::nil(),
+    );
+    job.file_key = file_key.to_string();
+    job
+  }
+
+  #[tokio::test]
+  async fn test_jobs_are_replaced_by_file_key_not_by_name() {
+    let outbox = UploadOutboxService::new(Arc::new(InMemoryJobs::default()));
+    let report_id = Uuid::new_v4();
+
+    let first = outbox
+      .enqueue(job(report_id, "a", "incoming/2026-03-05 - Telia.pdf"))
+      .await
+      .unwrap();
+    outbox
+      .enqueue(job(report_id, "b", "incoming/2026-03-05 - Telia (2).pdf"))
+      .await
+      .unwrap();
+    // Regenerated with the files named the other way round
+    let renamed = outbox
+      .enqueue(job(report_id, "a", "incoming/2026-03-05 - Telia (2).pdf"))
+      .await
+      .unwrap();
+
+    let jobs = outbox
+      .jobs(UploadTarget::ReportFile, report_id)
+      .await
+      .unwrap();
+    assert_eq!(jobs.len(), 2);
+    assert_eq!(renamed.id, first.id);
+    assert_eq!(renamed.path, "incoming/2026-03-05 - Telia (2).pdf");
+  }
+
+  #[tokio::test]
+  async fn test_only_one_push_claims_a_job() {
+    let outbox = UploadOutboxService::new(Arc::new(InMemoryJobs::default()));
+    let queued = outbox
+      .enqueue(job(Uuid::new_v4(), "a", "incoming/a.pdf"))
+      .await
+      .unwrap();
+
+    // The request and the background worker both picked up the due job
+    let claimed = outbox.claim(queued.clone()).await.unwrap();
+    assert_eq!(
+      claimed.map(|job| job.status),
+      Some(UploadStatus::InProgress)
+    );
+    assert_eq!(outbox.claim(queued).await.unwrap(), None);
+    assert!(
+      outbox
+        .due_jobs(UploadTarget::ReportFile, 10)
+        .await
+        .unwrap()
+        .is_empty()
+    );
+  }
+}
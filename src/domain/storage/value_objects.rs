@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+
+use super::errors::StorageError;
+
+/// Where an upload job's file goes once it is pushed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UploadTarget {
+  /// PDF in a monthly report's incoming/ or outcoming/ folder
+  ReportFile,
+  /// PDF of an issued invoice in the company's invoices folder
+  InvoicePdf,
+}
+
+impl UploadTarget {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      UploadTarget::ReportFile => "report_file",
+      UploadTarget::InvoicePdf => "invoice_pdf",
+    }
+  }
+}
+
+impl TryFrom<&str> for UploadTarget {
+  type Error = StorageError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "report_file" => Ok(UploadTarget::ReportFile),
+      "invoice_pdf" => Ok(UploadTarget::InvoicePdf),
+      _ => Err(StorageError::Validation(format!(
+        "Invalid upload target: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+/// What pushing an upload job does with its file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UploadAction {
+  /// Upload the local file, or a new version of an earlier upload
+  Upload,
+  /// Remove an earlier upload that is no longer wanted
+  Delete,
+}
+
+impl UploadAction {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      UploadAction::Upload => "upload",
+      UploadAction::Delete => "delete",
+    }
+  }
+}
+
+impl TryFrom<&str> for UploadAction {
+  type Error = StorageError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "upload" => Ok(UploadAction::Upload),
+      "delete" => Ok(UploadAction::Delete),
+      _ => Err(StorageError::Validation(format!(
+        "Invalid upload action: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UploadStatus {
+  /// Waiting for its first or next attempt
+  Pending,
+  /// Claimed by a push that is uploading it right now
+  InProgress,
+  Succeeded,
+  /// Gave up after the last attempt; only retried on request
+  Failed,
+}
+
+impl UploadStatus {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      UploadStatus::Pending => "pending",
+      UploadStatus::InProgress => "in_progress",
+      UploadStatus::Succeeded => "succeeded",
+      UploadStatus::Failed => "failed",
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      UploadStatus::Pending => "Waiting to upload",
+      UploadStatus::InProgress => "Uploading",
+      UploadStatus::Succeeded => "Uploaded",
+      UploadStatus::Failed => "Failed",
+    }
+  }
+}
+
+impl TryFrom<&str> for UploadStatus {
+  type Error = StorageError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "pending" => Ok(UploadStatus::Pending),
+      "in_progress" => Ok(UploadStatus::InProgress),
+      "succeeded" => Ok(UploadStatus::Succeeded),
+      "failed" => Ok(UploadStatus::Failed),
+      _ => Err(StorageError::Validation(format!(
+        "Invalid upload status: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+/// How an uploaded file compares to what is in Drive, as last checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SyncStatus {
+  InSync,
+  /// Still there, but no longer in the folder it was uploaded to
+  Moved,
+  /// Still in its folder, under another name
+  Renamed,
+  /// Deleted or trashed; queued again for upload
+  Missing,
+}
+
+impl SyncStatus {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      SyncStatus::InSync => "in_sync",
+      SyncStatus::Moved => "moved",
+      SyncStatus::Renamed => "renamed",
+      SyncStatus::Missing => "missing",
+    }
+  }
+
+  pub fn label(&self) -> &'static str {
+    match self {
+      SyncStatus::InSync => "In sync",
+      SyncStatus::Moved => "Moved in Drive",
+      SyncStatus::Renamed => "Renamed in Drive",
+      SyncStatus::Missing => "Missing from Drive",
+    }
+  }
+
+  /// Moved or renamed by someone in Drive, but not lost
+  pub fn is_drifted(&self) -> bool {
+    matches!(self, SyncStatus::Moved | SyncStatus::Renamed)
+  }
+}
+
+impl TryFrom<&str> for SyncStatus {
+  type Error = StorageError;
+
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    match s {
+      "in_sync" => Ok(SyncStatus::InSync),
+      "moved" => Ok(SyncStatus::Moved),
+      "renamed" => Ok(SyncStatus::Renamed),
+      "missing" => Ok(SyncStatus::Missing),
+      _ => Err(StorageError::Validation(format!(
+        "Invalid sync status: '{}'",
+        s
+      ))),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_upload_target_round_trip() {
+    for target in [UploadTarget::ReportFile, UploadTarget::InvoicePdf] {
+      assert_eq!(UploadTarget::try_from(target.as_str()).unwrap(), target);
+    }
+    assert!(UploadTarget::try_from("s3").is_err());
+  }
+
+  #[test]
+  fn test_upload_status_round_trip() {
+    for status in [
+      UploadStatus::Pending,
+      UploadStatus::InProgress,
+      UploadStatus::Succeeded,
+      UploadStatus::Failed,
+    ] {
+      assert_eq!(UploadStatus::try_from(status.as_str()).unwrap(), status);
+    }
+    assert!(UploadStatus::try_from("done").is_err());
+  }
+
+  #[test]
+  fn test_sync_status_round_trip() {
+    for status in [
+      SyncStatus::InSync,
+      SyncStatus::Moved,
+      SyncStatus::Renamed,
+      SyncStatus::Missing,
+    ] {
+      assert_eq!(SyncStatus::try_from(status.as_str()).unwrap(), status);
+    }
+    assert!(SyncStatus::try_from("trashed").is_err());
+  }
+}
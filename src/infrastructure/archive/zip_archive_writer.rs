@@ -0,0 +1,71 @@
+use std::io::{Cursor, Write};
+
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::domain::report::{ArchiveEntry, ReportArchiveWriter, ReportError};
+
+/// Packs report files into a deflate-compressed ZIP archive, keeping the
+/// folders in their paths
+#[derive(Default)]
+pub struct ZipArchiveWriter;
+
+impl ZipArchiveWriter {
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl ReportArchiveWriter for ZipArchiveWriter {
+  fn write(&self, entries: &[ArchiveEntry]) -> Result<Vec<u8>, ReportError> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    for entry in entries {
+      zip
+        .start_file(entry.path.as_str(), options)
+        .map_err(|e| ReportError::FileError(format!("Failed to add {}: {}", entry.path, e)))?;
+      zip
+        .write_all(&entry.content)
+        .map_err(|e| ReportError::FileError(format!("Failed to add {}: {}", entry.path, e)))?;
+    }
+
+    zip
+      .finish()
+      .map(|cursor| cursor.into_inner())
+      .map_err(|e| ReportError::FileError(format!("Failed to write archive: {}", e)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::io::Read;
+  use zip::ZipArchive;
+
+  #[test]
+  fn test_writes_entries_with_their_folders() {
+    let entries = vec![
+      ArchiveEntry {
+        path: "index.csv".to_string(),
+        content: b"Date,Account".to_vec(),
+      },
+      ArchiveEntry {
+        path: "EE12 (Main)/incoming/2026-03-10 - Acme.pdf".to_string(),
+        content: b"%PDF-1.4".to_vec(),
+      },
+    ];
+
+    let bytes = ZipArchiveWriter::new().write(&entries).unwrap();
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+
+    assert_eq!(archive.len(), 2);
+    let mut content = String::new();
+    archive
+      .by_name("EE12 (Main)/incoming/2026-03-10 - Acme.pdf")
+      .unwrap()
+      .read_to_string(&mut content)
+      .unwrap();
+    assert_eq!(content, "%PDF-1.4");
+  }
+}
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::CloudStorageFactory;
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::CompanyRepository;
+use crate::domain::storage::{DriveFileInspector, DriveInspectorResolver, StorageError};
+use crate::infrastructure::config::Config;
+use crate::infrastructure::security::AesTokenEncryption;
+
+/// Signs in to the Drive of each company storing its files in Google Drive,
+/// with its OAuth connection
+pub struct CompanyDriveInspectorResolver {
+  company_repo: Arc<dyn CompanyRepository>,
+  token_encryption: Arc<AesTokenEncryption>,
+  config: Arc<Config>,
+}
+
+impl CompanyDriveInspectorResolver {
+  pub fn new(
+    company_repo: Arc<dyn CompanyRepository>,
+    token_encryption: Arc<AesTokenEncryption>,
+    config: Arc<Config>,
+  ) -> Self {
+    Self {
+      company_repo,
+      token_encryption,
+      config,
+    }
+  }
+}
+
+#[async_trait]
+impl DriveInspectorResolver for CompanyDriveInspectorResolver {
+  async fn for_company(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Option<Arc<dyn DriveFileInspector>>, StorageError> {
+    let Some(company) = self
+      .company_repo
+      .find_by_id(company_id)
+      .await
+      .map_err(|e| StorageError::Repository(RepositoryError::QueryFailed(e.to_string())))?
+    else {
+      return Ok(None);
+    };
+
+    let drive_config = self.config.google_drive.as_ref();
+    Ok(
+      CloudStorageFactory::create_drive_api(
+        &company,
+        &self.token_encryption,
+        drive_config.and_then(|c| c.oauth_client_id.as_deref()),
+        drive_config.and_then(|c| c.oauth_client_secret.as_deref()),
+      )
+      .map(|api| Arc::new(api) as Arc<dyn DriveFileInspector>),
+    )
+  }
+}
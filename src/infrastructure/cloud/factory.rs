@@ -1,9 +1,16 @@
-use super::{GoogleDriveAdapter, GoogleDriveOAuthAdapter, NoOpCloudStorage};
+use super::report_drive_adapter::NoOpReportCloudStorage;
+use super::{
+  GoogleDriveAdapter, GoogleDriveApi, GoogleDriveOAuthAdapter, LocalStorageAdapter,
+  NoOpCloudStorage, ReportDriveAdapter, S3Settings, S3StorageAdapter, WebDavSettings,
+  WebDavStorageAdapter,
+};
 use crate::application::company::ConnectGoogleDriveUseCase;
-use crate::domain::company::{Company, StorageConfig, StorageProvider};
+use crate::domain::company::{Company, LocalConfig, StorageConfig, StorageProvider};
 use crate::domain::invoice::errors::InvoiceError;
 use crate::domain::invoice::ports::CloudStorage;
+use crate::domain::report::ports::ReportCloudStorage;
 use crate::infrastructure::security::AesTokenEncryption;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 pub struct CloudStorageFactory;
@@ -40,6 +47,18 @@ impl CloudStorageFactory {
 
         Arc::new(NoOpCloudStorage::new())
       }
+
+      StorageProvider::S3 | StorageProvider::WebDav | StorageProvider::Local => {
+        // Credentials are encrypted and the local root is configured; this
+        // method has neither
+        tracing::warn!(
+          "{} selected but the adapter must be created via create_with_oauth(). \
+           Using NoOpCloudStorage.",
+          provider_type.as_str()
+        );
+
+        Arc::new(NoOpCloudStorage::new())
+      }
     }
   }
 
@@ -53,6 +72,8 @@ impl CloudStorageFactory {
   /// * `connect_use_case` - Optional use case for refreshing tokens
   /// * `oauth_client_id` - OAuth client ID from Google Cloud Console (for token refresh)
   /// * `oauth_client_secret` - OAuth client secret from Google Cloud Console (for token refresh)
+  /// * `local_storage_root` - Directory the local storage provider stores companies' files in
+  #[allow(clippy::too_many_arguments)]
   pub async fn create_with_oauth(
     provider: Option<&String>,
     config_json: Option<&String>,
@@ -61,6 +82,7 @@ impl CloudStorageFactory {
     connect_use_case: Option<&ConnectGoogleDriveUseCase>,
     oauth_client_id: Option<&str>,
     oauth_client_secret: Option<&str>,
+    local_storage_root: &str,
   ) -> Arc<dyn CloudStorage> {
     // Parse provider type
     let provider_type = provider
@@ -126,7 +148,251 @@ impl CloudStorageFactory {
 
         Arc::new(NoOpCloudStorage::new())
       }
+
+      StorageProvider::S3 => match Self::s3_settings(config_json, token_encryption) {
+        Some(settings) => Arc::new(S3StorageAdapter::new(settings)),
+        None => Arc::new(NoOpCloudStorage::new()),
+      },
+
+      StorageProvider::WebDav => match Self::webdav_settings(config_json, token_encryption) {
+        Some(settings) => Arc::new(WebDavStorageAdapter::new(settings)),
+        None => Arc::new(NoOpCloudStorage::new()),
+      },
+
+      StorageProvider::Local => match Self::local_directory(company, local_storage_root) {
+        Some(directory) => Arc::new(LocalStorageAdapter::new(directory)),
+        None => Arc::new(NoOpCloudStorage::new()),
+      },
+    }
+  }
+
+  /// Create the storage a company's monthly reports are uploaded to
+  ///
+  /// Falls back to a storage that rejects every upload, so a report is not
+  /// marked generated while its provider is not configured.
+  pub fn create_report_storage(
+    company: &Company,
+    token_encryption: &AesTokenEncryption,
+    oauth_client_id: Option<&str>,
+    oauth_client_secret: Option<&str>,
+    local_storage_root: &str,
+  ) -> Arc<dyn ReportCloudStorage> {
+    let provider_type = company
+      .storage_provider
+      .as_ref()
+      .and_then(|p| p.parse::<StorageProvider>().ok())
+      .unwrap_or_default();
+
+    match provider_type {
+      StorageProvider::None => Arc::new(NoOpReportCloudStorage),
+
+      StorageProvider::GoogleDrive => {
+        match Self::drive_credentials(
+          company,
+          token_encryption,
+          oauth_client_id,
+          oauth_client_secret,
+        ) {
+          Some((client_id, client_secret, refresh_token)) => Arc::new(ReportDriveAdapter::new(
+            client_id,
+            client_secret,
+            refresh_token,
+          )),
+          None => {
+            tracing::warn!(
+              "Google Drive selected for company {} but not connected. Reports can't be uploaded.",
+              company.id
+            );
+            Arc::new(NoOpReportCloudStorage)
+          }
+        }
+      }
+
+      StorageProvider::S3 => {
+        match Self::s3_settings(company.storage_config.as_ref(), token_encryption) {
+          Some(settings) => Arc::new(S3StorageAdapter::new(settings)),
+          None => Arc::new(NoOpReportCloudStorage),
+        }
+      }
+
+      StorageProvider::WebDav => {
+        match Self::webdav_settings(company.storage_config.as_ref(), token_encryption) {
+          Some(settings) => Arc::new(WebDavStorageAdapter::new(settings)),
+          None => Arc::new(NoOpReportCloudStorage),
+        }
+      }
+
+      StorageProvider::Local => match Self::local_directory(company, local_storage_root) {
+        Some(directory) => Arc::new(LocalStorageAdapter::new(directory)),
+        None => Arc::new(NoOpReportCloudStorage),
+      },
+    }
+  }
+
+  /// Drive API client signed in with the company's OAuth connection, if it
+  /// stores its files in Google Drive
+  pub fn create_drive_api(
+    company: &Company,
+    token_encryption: &AesTokenEncryption,
+    oauth_client_id: Option<&str>,
+    oauth_client_secret: Option<&str>,
+  ) -> Option<GoogleDriveApi> {
+    let provider_type = company
+      .storage_provider
+      .as_ref()
+      .and_then(|p| p.parse::<StorageProvider>().ok())
+      .unwrap_or_default();
+    if provider_type != StorageProvider::GoogleDrive {
+      return None;
+    }
+
+    Self::connected_drive_api(
+      company,
+      token_encryption,
+      oauth_client_id,
+      oauth_client_secret,
+    )
+  }
+
+  /// Drive API client signed in with the company's OAuth connection,
+  /// whichever provider it currently stores its files with
+  pub fn connected_drive_api(
+    company: &Company,
+    token_encryption: &AesTokenEncryption,
+    oauth_client_id: Option<&str>,
+    oauth_client_secret: Option<&str>,
+  ) -> Option<GoogleDriveApi> {
+    let (client_id, client_secret, refresh_token) = Self::drive_credentials(
+      company,
+      token_encryption,
+      oauth_client_id,
+      oauth_client_secret,
+    )?;
+    Some(GoogleDriveApi::new(client_id, client_secret, refresh_token))
+  }
+
+  /// OAuth client and the company's decrypted refresh token
+  fn drive_credentials(
+    company: &Company,
+    token_encryption: &AesTokenEncryption,
+    oauth_client_id: Option<&str>,
+    oauth_client_secret: Option<&str>,
+  ) -> Option<(String, String, String)> {
+    let refresh_token = match token_encryption.decrypt(company.oauth_refresh_token.as_ref()?) {
+      Ok(token) => token,
+      Err(e) => {
+        tracing::error!("Failed to decrypt OAuth refresh token: {}", e);
+        return None;
+      }
+    };
+
+    Some((
+      oauth_client_id?.to_string(),
+      oauth_client_secret?.to_string(),
+      refresh_token,
+    ))
+  }
+
+  /// S3 settings from the company's storage configuration, with the secret
+  /// key decrypted
+  fn s3_settings(
+    config_json: Option<&String>,
+    token_encryption: &AesTokenEncryption,
+  ) -> Option<S3Settings> {
+    let config = match serde_json::from_str::<StorageConfig>(config_json?) {
+      Ok(StorageConfig::S3(config)) => config,
+      Ok(_) => {
+        tracing::warn!("S3 provider selected but config doesn't match. Using NoOp storage.");
+        return None;
+      }
+      Err(e) => {
+        tracing::warn!("Failed to parse S3 config: {}. Using NoOp storage.", e);
+        return None;
+      }
+    };
+
+    let secret_access_key = match token_encryption.decrypt(&config.secret_access_key) {
+      Ok(secret) => secret,
+      Err(e) => {
+        tracing::error!("Failed to decrypt S3 secret access key: {}", e);
+        return None;
+      }
+    };
+
+    Some(S3Settings {
+      bucket: config.bucket,
+      region: config.region,
+      endpoint: config.endpoint,
+      prefix: config.prefix,
+      access_key_id: config.access_key_id,
+      secret_access_key,
+    })
+  }
+
+  /// WebDAV settings from the company's storage configuration, with the app
+  /// password decrypted
+  pub fn webdav_settings(
+    config_json: Option<&String>,
+    token_encryption: &AesTokenEncryption,
+  ) -> Option<WebDavSettings> {
+    let config = match serde_json::from_str::<StorageConfig>(config_json?) {
+      Ok(StorageConfig::WebDav(config)) => config,
+      Ok(_) => {
+        tracing::warn!("WebDAV provider selected but config doesn't match. Using NoOp storage.");
+        return None;
+      }
+      Err(e) => {
+        tracing::warn!("Failed to parse WebDAV config: {}. Using NoOp storage.", e);
+        return None;
+      }
+    };
+
+    let app_password = match token_encryption.decrypt(&config.app_password) {
+      Ok(password) => password,
+      Err(e) => {
+        tracing::error!("Failed to decrypt WebDAV app password: {}", e);
+        return None;
+      }
+    };
+
+    Some(WebDavSettings {
+      url: config.url,
+      username: config.username,
+      app_password,
+      root_folder: config.root_folder,
+    })
+  }
+
+  /// Directory of the company below the local storage root, always inside
+  /// the company's own ID directory
+  fn local_directory(company: &Company, local_storage_root: &str) -> Option<PathBuf> {
+    let config = match company
+      .storage_config
+      .as_deref()
+      .map(serde_json::from_str::<StorageConfig>)
+    {
+      Some(Ok(StorageConfig::Local(config))) => config,
+      None => LocalConfig::default(),
+      Some(Ok(_)) => {
+        tracing::warn!("Local provider selected but config doesn't match. Using NoOp storage.");
+        return None;
+      }
+      Some(Err(e)) => {
+        tracing::warn!(
+          "Failed to parse local storage config: {}. Using NoOp storage.",
+          e
+        );
+        return None;
+      }
+    };
+    let directory = config.company_path(Path::new(local_storage_root), company.id);
+    if directory.is_none() {
+      tracing::error!(
+        "Local storage directory of company {} is not a plain relative path",
+        company.id
+      );
     }
+    directory
   }
 
   /// Try to create OAuth adapter if company has OAuth tokens
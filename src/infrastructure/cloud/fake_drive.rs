@@ -0,0 +1,302 @@
+//! In-memory Google Drive server for tests, answering the parts of the
+//! OAuth and Drive v3 APIs the Drive adapters use
+
+use actix_multipart::Multipart;
+use actix_web::{App, HttpRequest, HttpResponse, HttpServer, web};
+use futures_util::StreamExt;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::{DriveEndpoints, GoogleDriveApi};
+
+pub const REFRESH_TOKEN: &str = "fake-refresh-token";
+const ACCESS_TOKEN: &str = "fake-access-token";
+const FOLDER_MIME_TYPE: &str = "application/vnd.google-apps.folder";
+
+#[derive(Debug, Clone)]
+pub struct FakeFile {
+  pub name: String,
+  pub parents: Vec<String>,
+  pub mime_type: String,
+  pub trashed: bool,
+  pub content: Vec<u8>,
+  /// Shared drive the file is in, if any
+  pub drive_id: Option<String>,
+}
+
+#[derive(Default)]
+pub struct FakeDrive {
+  pub files: HashMap<String, FakeFile>,
+  pub shared_drives: Vec<(String, String)>,
+  next_id: usize,
+}
+
+impl FakeDrive {
+  fn new_id(&mut self) -> String {
+    self.next_id += 1;
+    format!("file-{}", self.next_id)
+  }
+
+  /// Shared drive the folder or shared drive `id` belongs to
+  fn drive_of(&self, id: &str) -> Option<String> {
+    if self.shared_drives.iter().any(|(drive, _)| drive == id) {
+      return Some(id.to_string());
+    }
+    self.files.get(id).and_then(|f| f.drive_id.clone())
+  }
+
+  fn insert(&mut self, name: &str, parent: &str, mime_type: &str, content: &[u8]) -> String {
+    let id = self.new_id();
+    let file = FakeFile {
+      name: name.to_string(),
+      parents: vec![parent.to_string()],
+      mime_type: mime_type.to_string(),
+      trashed: false,
+      content: content.to_vec(),
+      drive_id: self.drive_of(parent),
+    };
+    self.files.insert(id.clone(), file);
+    id
+  }
+
+  pub fn add_folder(&mut self, name: &str, parent: &str) -> String {
+    self.insert(name, parent, FOLDER_MIME_TYPE, b"")
+  }
+
+  pub fn add_file(&mut self, name: &str, parent: &str, content: &[u8]) -> String {
+    self.insert(name, parent, "application/pdf", content)
+  }
+
+  pub fn add_shared_drive(&mut self, name: &str) -> String {
+    self.next_id += 1;
+    let id = format!("drive-{}", self.next_id);
+    self.shared_drives.push((id.clone(), name.to_string()));
+    id
+  }
+
+  pub fn trash(&mut self, id: &str) {
+    self.files.get_mut(id).expect("file exists").trashed = true;
+  }
+
+  pub fn delete(&mut self, id: &str) {
+    self.files.remove(id);
+  }
+
+  pub fn rename(&mut self, id: &str, name: &str) {
+    self.files.get_mut(id).expect("file exists").name = name.to_string();
+  }
+
+  pub fn move_to(&mut self, id: &str, parent: &str) {
+    self.files.get_mut(id).expect("file exists").parents = vec![parent.to_string()];
+  }
+
+  /// Whether the request may see the file: files in shared drives are
+  /// hidden unless the request supports them
+  fn visible(&self, file: &FakeFile, req: &HttpRequest) -> bool {
+    file.drive_id.is_none() || req.query_string().contains("supportsAllDrives=true")
+  }
+}
+
+type State = web::Data<Mutex<FakeDrive>>;
+
+fn file_json(id: &str, file: &FakeFile) -> Value {
+  json!({
+    "id": id,
+    "name": file.name,
+    "mimeType": file.mime_type,
+    "parents": file.parents,
+    "trashed": file.trashed,
+  })
+}
+
+fn not_found() -> HttpResponse {
+  HttpResponse::NotFound().json(json!({"error": {"code": 404, "message": "File not found"}}))
+}
+
+fn authorized(req: &HttpRequest) -> bool {
+  req
+    .headers()
+    .get("authorization")
+    .and_then(|value| value.to_str().ok())
+    == Some(&format!("Bearer {}", ACCESS_TOKEN))
+}
+
+async fn token(form: web::Form<HashMap<String, String>>) -> HttpResponse {
+  if form.get("refresh_token").map(String::as_str) != Some(REFRESH_TOKEN) {
+    return HttpResponse::BadRequest().json(json!({"error": "invalid_grant"}));
+  }
+  HttpResponse::Ok().json(json!({
+    "access_token": ACCESS_TOKEN,
+    "expires_in": 3600,
+    "token_type": "Bearer",
+  }))
+}
+
+async fn get_file(req: HttpRequest, id: web::Path<String>, drive: State) -> HttpResponse {
+  if !authorized(&req) {
+    return HttpResponse::Unauthorized().finish();
+  }
+  let drive = drive.lock().unwrap();
+  match drive.files.get(id.as_str()) {
+    Some(file) if drive.visible(file, &req) => HttpResponse::Ok().json(file_json(&id, file)),
+    _ => not_found(),
+  }
+}
+
+/// Folder listing, for queries of the form "'<parent>' in parents and ..."
+async fn list_files(
+  req: HttpRequest,
+  query: web::Query<HashMap<String, String>>,
+  drive: State,
+) -> HttpResponse {
+  if !authorized(&req) {
+    return HttpResponse::Unauthorized().finish();
+  }
+  let q = query.get("q").cloned().unwrap_or_default();
+  let parent = q.split('\'').nth(1).unwrap_or("root").to_string();
+  let folders_only = q.contains(FOLDER_MIME_TYPE);
+  let drive = drive.lock().unwrap();
+
+  let mut files: Vec<(&String, &FakeFile)> = drive
+    .files
+    .iter()
+    .filter(|(_, f)| f.parents.contains(&parent) && !f.trashed && drive.visible(f, &req))
+    .filter(|(_, f)| !folders_only || f.mime_type == FOLDER_MIME_TYPE)
+    .collect();
+  files.sort_by(|a, b| a.1.name.cmp(&b.1.name));
+
+  HttpResponse::Ok().json(json!({
+    "files": files.iter().map(|(id, f)| file_json(id, f)).collect::<Vec<_>>(),
+  }))
+}
+
+async fn list_drives(req: HttpRequest, drive: State) -> HttpResponse {
+  if !authorized(&req) {
+    return HttpResponse::Unauthorized().finish();
+  }
+  let drive = drive.lock().unwrap();
+  HttpResponse::Ok().json(json!({
+    "drives": drive
+      .shared_drives
+      .iter()
+      .map(|(id, name)| json!({"id": id, "name": name}))
+      .collect::<Vec<_>>(),
+  }))
+}
+
+/// Folder creation, with JSON metadata only
+async fn create_file(req: HttpRequest, metadata: web::Json<Value>, drive: State) -> HttpResponse {
+  if !authorized(&req) {
+    return HttpResponse::Unauthorized().finish();
+  }
+  let mut drive = drive.lock().unwrap();
+  let parent = metadata["parents"][0]
+    .as_str()
+    .unwrap_or("root")
+    .to_string();
+  if drive.drive_of(&parent).is_some() && !req.query_string().contains("supportsAllDrives=true") {
+    return not_found();
+  }
+  let name = metadata["name"].as_str().unwrap_or_default();
+  let mime_type = metadata["mimeType"]
+    .as_str()
+    .unwrap_or("application/octet-stream");
+  let id = drive.insert(name, &parent, mime_type, b"");
+  HttpResponse::Ok().json(json!({"id": id, "name": name}))
+}
+
+/// Multipart upload of a new file: a metadata part and a file part
+async fn upload_file(req: HttpRequest, mut payload: Multipart, drive: State) -> HttpResponse {
+  if !authorized(&req) {
+    return HttpResponse::Unauthorized().finish();
+  }
+  let mut metadata = Value::Null;
+  let mut content = Vec::new();
+  while let Some(Ok(mut field)) = payload.next().await {
+    let name = field.name().unwrap_or_default().to_string();
+    let mut bytes = Vec::new();
+    while let Some(Ok(chunk)) = field.next().await {
+      bytes.extend_from_slice(&chunk);
+    }
+    match name.as_str() {
+      "metadata" => metadata = serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+      _ => content = bytes,
+    }
+  }
+
+  let mut drive = drive.lock().unwrap();
+  let parent = metadata["parents"][0]
+    .as_str()
+    .unwrap_or("root")
+    .to_string();
+  if drive.drive_of(&parent).is_some() && !req.query_string().contains("supportsAllDrives=true") {
+    return not_found();
+  }
+  let name = metadata["name"].as_str().unwrap_or_default().to_string();
+  let id = drive.insert(&name, &parent, "application/pdf", &content);
+  HttpResponse::Ok().json(json!({"id": id, "name": name}))
+}
+
+/// Media upload replacing the content of an existing file
+async fn update_file(
+  req: HttpRequest,
+  id: web::Path<String>,
+  body: web::Bytes,
+  drive: State,
+) -> HttpResponse {
+  if !authorized(&req) {
+    return HttpResponse::Unauthorized().finish();
+  }
+  let mut drive = drive.lock().unwrap();
+  let visible = drive
+    .files
+    .get(id.as_str())
+    .is_some_and(|file| drive.visible(file, &req));
+  if !visible {
+    return not_found();
+  }
+  let file = drive.files.get_mut(id.as_str()).expect("file exists");
+  file.content = body.to_vec();
+  HttpResponse::Ok().json(json!({"id": id.as_str(), "name": file.name}))
+}
+
+/// Start the fake server; returns the endpoints to point adapters at and
+/// its state
+pub fn start() -> (DriveEndpoints, State) {
+  let drive = web::Data::new(Mutex::new(FakeDrive::default()));
+  let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+  let port = listener.local_addr().unwrap().port();
+  let state = drive.clone();
+  let server = HttpServer::new(move || {
+    App::new()
+      .app_data(state.clone())
+      .route("/token", web::post().to(token))
+      .route("/drive/v3/files", web::get().to(list_files))
+      .route("/drive/v3/files", web::post().to(create_file))
+      .route("/drive/v3/files/{id}", web::get().to(get_file))
+      .route("/drive/v3/drives", web::get().to(list_drives))
+      .route("/upload/drive/v3/files", web::post().to(upload_file))
+      .route("/upload/drive/v3/files/{id}", web::patch().to(update_file))
+  })
+  .workers(1)
+  .listen(listener)
+  .unwrap()
+  .run();
+  actix_web::rt::spawn(server);
+
+  (
+    DriveEndpoints::at(&format!("http://127.0.0.1:{}", port)),
+    drive,
+  )
+}
+
+/// Drive API client signed in to the fake server
+pub fn api(endpoints: DriveEndpoints) -> GoogleDriveApi {
+  GoogleDriveApi::new(
+    "client-id".to_string(),
+    "client-secret".to_string(),
+    REFRESH_TOKEN.to_string(),
+  )
+  .with_endpoints(endpoints)
+}
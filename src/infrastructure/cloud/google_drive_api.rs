@@ -0,0 +1,328 @@
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::storage::{DriveFileInspector, RemoteFile, StorageError};
+
+const FOLDER_MIME_TYPE: &str = "application/vnd.google-apps.folder";
+
+/// Base URLs of the Google OAuth and Drive APIs; a local fake Drive server
+/// in tests
+#[derive(Debug, Clone)]
+pub struct DriveEndpoints {
+  pub token_url: String,
+  pub api_url: String,
+  pub upload_url: String,
+}
+
+impl Default for DriveEndpoints {
+  fn default() -> Self {
+    Self {
+      token_url: "https://oauth2.googleapis.com/token".to_string(),
+      api_url: "https://www.googleapis.com/drive/v3".to_string(),
+      upload_url: "https://www.googleapis.com/upload/drive/v3".to_string(),
+    }
+  }
+}
+
+impl DriveEndpoints {
+  /// Endpoints of a server mimicking Google's paths below `base_url`
+  pub fn at(base_url: &str) -> Self {
+    let base_url = base_url.trim_end_matches('/');
+    Self {
+      token_url: format!("{}/token", base_url),
+      api_url: format!("{}/drive/v3", base_url),
+      upload_url: format!("{}/upload/drive/v3", base_url),
+    }
+  }
+}
+
+/// Drive file as returned by the API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DriveFileInfo {
+  pub id: String,
+  #[serde(default)]
+  pub name: String,
+  #[serde(default)]
+  pub parents: Vec<String>,
+  #[serde(default)]
+  pub trashed: bool,
+}
+
+/// Folder, or shared drive, to pick as an upload destination
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DriveFolder {
+  pub id: String,
+  pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+  access_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileList {
+  #[serde(default)]
+  files: Vec<DriveFolder>,
+  next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DriveList {
+  #[serde(default)]
+  drives: Vec<DriveFolder>,
+  next_page_token: Option<String>,
+}
+
+/// Google Drive API client signed in with a company's OAuth refresh token.
+///
+/// Every request includes shared drives, so folders in a shared drive can
+/// be uploaded to like folders in My Drive.
+pub struct GoogleDriveApi {
+  client: Client,
+  endpoints: DriveEndpoints,
+  client_id: String,
+  client_secret: String,
+  refresh_token: String,
+}
+
+impl GoogleDriveApi {
+  pub fn new(client_id: String, client_secret: String, refresh_token: String) -> Self {
+    Self {
+      client: Client::new(),
+      endpoints: DriveEndpoints::default(),
+      client_id,
+      client_secret,
+      refresh_token,
+    }
+  }
+
+  pub fn with_endpoints(mut self, endpoints: DriveEndpoints) -> Self {
+    self.endpoints = endpoints;
+    self
+  }
+
+  pub fn endpoints(&self) -> &DriveEndpoints {
+    &self.endpoints
+  }
+
+  pub fn client(&self) -> &Client {
+    &self.client
+  }
+
+  /// Get a fresh access token using the refresh token
+  pub async fn access_token(&self) -> Result<String, String> {
+    let response = self
+      .client
+      .post(&self.endpoints.token_url)
+      .form(&[
+        ("client_id", self.client_id.as_str()),
+        ("client_secret", self.client_secret.as_str()),
+        ("refresh_token", self.refresh_token.as_str()),
+        ("grant_type", "refresh_token"),
+      ])
+      .send()
+      .await
+      .map_err(|e| format!("Token refresh failed: {}", e))?;
+
+    if !response.status().is_success() {
+      let status = response.status();
+      let body = response.text().await.unwrap_or_default();
+      return Err(format!(
+        "Token refresh failed with status {}: {}",
+        status, body
+      ));
+    }
+
+    let token: TokenResponse = response
+      .json()
+      .await
+      .map_err(|e| format!("Failed to parse token response: {}", e))?;
+    Ok(token.access_token)
+  }
+
+  /// The file with the given ID, `None` when Drive doesn't know it or it
+  /// is no longer shared with the connected account
+  pub async fn file(&self, file_id: &str) -> Result<Option<DriveFileInfo>, String> {
+    let token = self.access_token().await?;
+    let response = self
+      .get(
+        &format!("{}/files/{}", self.endpoints.api_url, file_id),
+        &token,
+      )
+      .query(&[("fields", "id,name,parents,trashed")])
+      .send()
+      .await
+      .map_err(|e| format!("Drive request failed: {}", e))?;
+
+    match response.status() {
+      StatusCode::NOT_FOUND => Ok(None),
+      status if status.is_success() => response
+        .json()
+        .await
+        .map(Some)
+        .map_err(|e| format!("Failed to parse Drive file: {}", e)),
+      status => {
+        let body = response.text().await.unwrap_or_default();
+        Err(format!("Drive answered {}: {}", status, body))
+      }
+    }
+  }
+
+  /// Folders directly inside `parent_id`, a folder or shared drive ID;
+  /// those of My Drive when `None`
+  pub async fn folders(&self, parent_id: Option<&str>) -> Result<Vec<DriveFolder>, String> {
+    let token = self.access_token().await?;
+    let parent = parent_id.unwrap_or("root").replace('\'', "\\'");
+    let query = format!(
+      "'{}' in parents and mimeType = '{}' and trashed = false",
+      parent, FOLDER_MIME_TYPE
+    );
+
+    let mut folders = Vec::new();
+    let mut page_token: Option<String> = None;
+    loop {
+      let mut request = self
+        .get(&format!("{}/files", self.endpoints.api_url), &token)
+        .query(&[
+          ("q", query.as_str()),
+          ("corpora", "allDrives"),
+          ("includeItemsFromAllDrives", "true"),
+          ("orderBy", "name"),
+          ("pageSize", "100"),
+          ("fields", "nextPageToken,files(id,name)"),
+        ]);
+      if let Some(page_token) = &page_token {
+        request = request.query(&[("pageToken", page_token)]);
+      }
+      let list: FileList = Self::parse(request.send().await).await?;
+      folders.extend(list.files);
+      match list.next_page_token {
+        Some(next) => page_token = Some(next),
+        None => return Ok(folders),
+      }
+    }
+  }
+
+  /// Shared drives the connected account is a member of
+  pub async fn shared_drives(&self) -> Result<Vec<DriveFolder>, String> {
+    let token = self.access_token().await?;
+
+    let mut drives = Vec::new();
+    let mut page_token: Option<String> = None;
+    loop {
+      let mut request = self
+        .client
+        .get(format!("{}/drives", self.endpoints.api_url))
+        .bearer_auth(&token)
+        .query(&[
+          ("pageSize", "100"),
+          ("fields", "nextPageToken,drives(id,name)"),
+        ]);
+      if let Some(page_token) = &page_token {
+        request = request.query(&[("pageToken", page_token)]);
+      }
+      let list: DriveList = Self::parse(request.send().await).await?;
+      drives.extend(list.drives);
+      match list.next_page_token {
+        Some(next) => page_token = Some(next),
+        None => return Ok(drives),
+      }
+    }
+  }
+
+  /// GET request that sees files in shared drives too
+  fn get(&self, url: &str, token: &str) -> RequestBuilder {
+    self
+      .client
+      .get(url)
+      .bearer_auth(token)
+      .query(&[("supportsAllDrives", "true")])
+  }
+
+  async fn parse<T: serde::de::DeserializeOwned>(
+    response: Result<reqwest::Response, reqwest::Error>,
+  ) -> Result<T, String> {
+    let response = response.map_err(|e| format!("Drive request failed: {}", e))?;
+    if !response.status().is_success() {
+      let status = response.status();
+      let body = response.text().await.unwrap_or_default();
+      return Err(format!("Drive answered {}: {}", status, body));
+    }
+    response
+      .json()
+      .await
+      .map_err(|e| format!("Failed to parse Drive response: {}", e))
+  }
+}
+
+#[async_trait]
+impl DriveFileInspector for GoogleDriveApi {
+  async fn inspect(&self, file_id: &str) -> Result<Option<RemoteFile>, StorageError> {
+    let file = self
+      .file(file_id)
+      .await
+      .map_err(StorageError::UploadFailed)?;
+    Ok(file.map(|file| RemoteFile {
+      name: file.name,
+      parents: file.parents,
+      trashed: file.trashed,
+    }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::infrastructure::cloud::fake_drive;
+
+  #[actix_web::test]
+  async fn test_looks_up_files_in_my_drive_and_shared_drives() {
+    let (endpoints, drive) = fake_drive::start();
+    let api = fake_drive::api(endpoints);
+    let (folder, shared, shared_folder, file) = {
+      let mut drive = drive.lock().unwrap();
+      let folder = drive.add_folder("Invoices", "root");
+      let shared = drive.add_shared_drive("Accounting");
+      let shared_folder = drive.add_folder("Reports", &shared);
+      let file = drive.add_file("INV-001.pdf", &shared_folder, b"%PDF");
+      (folder, shared, shared_folder, file)
+    };
+
+    let found = api.file(&file).await.unwrap().unwrap();
+    assert_eq!(found.name, "INV-001.pdf");
+    assert_eq!(found.parents, vec![shared_folder.clone()]);
+    assert!(!found.trashed);
+    assert!(api.file("unknown").await.unwrap().is_none());
+
+    let folders = api.folders(None).await.unwrap();
+    assert_eq!(folders.len(), 1);
+    assert_eq!(folders[0].id, folder);
+    let drives = api.shared_drives().await.unwrap();
+    assert_eq!(drives[0].id, shared);
+    assert_eq!(drives[0].name, "Accounting");
+    let folders = api.folders(Some(&shared)).await.unwrap();
+    assert_eq!(folders[0].name, "Reports");
+
+    drive.lock().unwrap().trash(&file);
+    let inspected = api.inspect(&file).await.unwrap().unwrap();
+    assert!(inspected.trashed);
+  }
+
+  #[actix_web::test]
+  async fn test_rejected_refresh_token_is_an_error() {
+    let (endpoints, _drive) = fake_drive::start();
+    let api = GoogleDriveApi::new(
+      "client".to_string(),
+      "secret".to_string(),
+      "revoked".to_string(),
+    )
+    .with_endpoints(endpoints);
+
+    let error = api.file("any").await.unwrap_err();
+    assert!(error.contains("Token refresh failed"), "{}", error);
+  }
+}
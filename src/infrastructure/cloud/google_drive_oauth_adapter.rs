@@ -1,26 +1,15 @@
 use async_trait::async_trait;
-use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
+use super::{DriveEndpoints, GoogleDriveApi};
 use crate::domain::invoice::errors::InvoiceError;
 use crate::domain::invoice::ports::CloudStorage;
 
-/// Google Drive adapter using OAuth 2.0 user tokens
+/// Google Drive adapter using OAuth 2.0 user tokens. The invoices folder
+/// may be in a shared drive.
 pub struct GoogleDriveOAuthAdapter {
-  client: Client,
-  client_id: String,
-  client_secret: String,
-  refresh_token: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct TokenResponse {
-  access_token: String,
-  #[allow(dead_code)]
-  expires_in: u64,
-  #[allow(dead_code)]
-  token_type: String,
+  api: GoogleDriveApi,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -38,44 +27,26 @@ impl GoogleDriveOAuthAdapter {
     refresh_token: &str,
   ) -> Result<Self, InvoiceError> {
     Ok(Self {
-      client: Client::new(),
-      client_id: client_id.to_string(),
-      client_secret: client_secret.to_string(),
-      refresh_token: refresh_token.to_string(),
+      api: GoogleDriveApi::new(
+        client_id.to_string(),
+        client_secret.to_string(),
+        refresh_token.to_string(),
+      ),
     })
   }
 
+  pub fn with_endpoints(mut self, endpoints: DriveEndpoints) -> Self {
+    self.api = self.api.with_endpoints(endpoints);
+    self
+  }
+
   /// Get a fresh access token using the refresh token
   async fn get_access_token(&self) -> Result<String, InvoiceError> {
-    let response = self
-      .client
-      .post("https://oauth2.googleapis.com/token")
-      .form(&[
-        ("client_id", self.client_id.as_str()),
-        ("client_secret", self.client_secret.as_str()),
-        ("refresh_token", self.refresh_token.as_str()),
-        ("grant_type", "refresh_token"),
-      ])
-      .send()
+    self
+      .api
+      .access_token()
       .await
-      .map_err(|e| {
-        InvoiceError::CloudStorageUploadFailed(format!("Token refresh failed: {}", e))
-      })?;
-
-    if !response.status().is_success() {
-      let status = response.status();
-      let body = response.text().await.unwrap_or_default();
-      return Err(InvoiceError::CloudStorageUploadFailed(format!(
-        "Token refresh failed with status {}: {}",
-        status, body
-      )));
-    }
-
-    let token_response: TokenResponse = response.json().await.map_err(|e| {
-      InvoiceError::CloudStorageUploadFailed(format!("Failed to parse token response: {}", e))
-    })?;
-
-    Ok(token_response.access_token)
+      .map_err(InvoiceError::CloudStorageUploadFailed)
   }
 }
 
@@ -118,8 +89,14 @@ impl CloudStorage for GoogleDriveOAuthAdapter {
       );
 
     let response = self
-      .client
-      .post("https://www.googleapis.com/upload/drive/v3/files?uploadType=multipart&fields=id")
+      .api
+      .client()
+      .post(format!("{}/files", self.api.endpoints().upload_url))
+      .query(&[
+        ("uploadType", "multipart"),
+        ("fields", "id"),
+        ("supportsAllDrives", "true"),
+      ])
       .bearer_auth(&access_token)
       .multipart(form)
       .send()
@@ -148,3 +125,37 @@ impl CloudStorage for GoogleDriveOAuthAdapter {
     Ok(file.id)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::infrastructure::cloud::fake_drive;
+
+  #[actix_web::test]
+  async fn test_uploads_invoice_into_a_shared_drive_folder() {
+    let (endpoints, drive) = fake_drive::start();
+    let folder = {
+      let mut drive = drive.lock().unwrap();
+      let shared = drive.add_shared_drive("Accounting");
+      drive.add_folder("Invoices", &shared)
+    };
+    let adapter =
+      GoogleDriveOAuthAdapter::new("client-id", "client-secret", fake_drive::REFRESH_TOKEN)
+        .await
+        .unwrap()
+        .with_endpoints(endpoints);
+    let dir = std::env::temp_dir().join(format!("drive_test_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let pdf = dir.join("invoice.pdf");
+    std::fs::write(&pdf, b"%PDF-1.4 invoice").unwrap();
+
+    let file_id = adapter
+      .upload_invoice_pdf(&folder, "INV-001", &pdf.to_string_lossy())
+      .await
+      .unwrap();
+
+    let drive = drive.lock().unwrap();
+    assert_eq!(drive.files[&file_id].name, "INV-001.pdf");
+    assert_eq!(drive.files[&file_id].parents, vec![folder]);
+  }
+}
@@ -0,0 +1,550 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use uuid::Uuid;
+
+use super::s3_storage_adapter::join_key;
+use crate::domain::invoice::errors::InvoiceError;
+use crate::domain::invoice::ports::CloudStorage;
+use crate::domain::report::{errors::ReportError, ports::ReportCloudStorage};
+
+/// Manifest of stored files, one JSON entry appended per write
+const MANIFEST_FILE: &str = "manifest.jsonl";
+
+/// A file written by the local storage, as recorded in its manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+  /// Path relative to the storage root
+  pub path: String,
+  pub size: u64,
+  pub sha256: String,
+  pub stored_at: DateTime<Utc>,
+  /// Set on the entry recording that the file was deleted
+  #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+  pub deleted: bool,
+}
+
+/// Storage in a directory of the server, for self-hosted setups without a
+/// cloud account. Reports get the same `MM.YYYY/<account>/incoming` layout
+/// as on Drive.
+///
+/// Files are written to a temporary file and renamed into place, so a crash
+/// never leaves a half-written file. A new file never replaces another one
+/// of the same name: it is stored as `name (2).pdf` instead, unless the
+/// content is the same. Paths relative to the root are returned as file and
+/// folder IDs.
+pub struct LocalStorageAdapter {
+  root: PathBuf,
+}
+
+impl LocalStorageAdapter {
+  pub fn new(root: impl Into<PathBuf>) -> Self {
+    Self { root: root.into() }
+  }
+
+  /// Files currently stored, the latest entry of each path
+  pub async fn manifest(&self) -> Result<Vec<ManifestEntry>, String> {
+    let content = match tokio::fs::read_to_string(self.root.join(MANIFEST_FILE)).await {
+      Ok(content) => content,
+      Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+      Err(e) => return Err(format!("Failed to read manifest: {}", e)),
+    };
+
+    let mut entries = BTreeMap::new();
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+      let entry: ManifestEntry =
+        serde_json::from_str(line).map_err(|e| format!("Invalid manifest entry: {}", e))?;
+      entries.insert(entry.path.clone(), entry);
+    }
+    Ok(entries.into_values().filter(|e| !e.deleted).collect())
+  }
+
+  /// Write a local file as `file_name` into `folder`. With `replace` an
+  /// existing file of that name is overwritten, otherwise a free name is
+  /// picked. Returns the path of the stored file.
+  async fn store(
+    &self,
+    folder: &str,
+    file_name: &str,
+    local_path: &str,
+    replace: bool,
+  ) -> Result<String, String> {
+    let content = tokio::fs::read(local_path)
+      .await
+      .map_err(|e| format!("Failed to read {}: {}", local_path, e))?;
+    let sha256 = hex::encode(Sha256::digest(&content));
+
+    let dir = self.resolve(folder)?;
+    tokio::fs::create_dir_all(&dir)
+      .await
+      .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let file_name = sanitize(file_name);
+    let temp = dir.join(format!(".{}.{}.tmp", file_name, Uuid::new_v4()));
+    if let Err(e) = write_synced(&temp, &content).await {
+      let _ = tokio::fs::remove_file(&temp).await;
+      return Err(e);
+    }
+
+    let (name, reserved) = if replace {
+      (file_name, false)
+    } else {
+      match self.reserve_name(&dir, &file_name, &sha256).await {
+        Ok(Reserved::Free(name)) => (name, true),
+        Ok(Reserved::SameContent(name)) => {
+          let _ = tokio::fs::remove_file(&temp).await;
+          return Ok(join_key(folder, &name));
+        }
+        Err(e) => {
+          let _ = tokio::fs::remove_file(&temp).await;
+          return Err(e);
+        }
+      }
+    };
+
+    move_into_place(&temp, &dir.join(&name), reserved).await?;
+
+    let path = join_key(folder, &name);
+    self
+      .append_manifest(&ManifestEntry {
+        path: path.clone(),
+        size: content.len() as u64,
+        sha256,
+        stored_at: Utc::now(),
+        deleted: false,
+      })
+      .await?;
+    Ok(path)
+  }
+
+  /// Claim the first of `name`, `name (2)`, ... not taken in `dir` by
+  /// creating it empty, or find one that already holds this content. Report
+  /// files already come with distinct names; this keeps a file left over
+  /// from an earlier layout from being overwritten.
+  async fn reserve_name(&self, dir: &Path, name: &str, sha256: &str) -> Result<Reserved, String> {
+    for n in 1.. {
+      let candidate = numbered(name, n);
+      let target = dir.join(&candidate);
+      match tokio::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&target)
+        .await
+      {
+        Ok(_) => return Ok(Reserved::Free(candidate)),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+          let existing = tokio::fs::read(&target)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", target.display(), e))?;
+          if hex::encode(Sha256::digest(&existing)) == sha256 {
+            return Ok(Reserved::SameContent(candidate));
+          }
+        }
+        Err(e) => return Err(format!("Failed to create {}: {}", target.display(), e)),
+      }
+    }
+    unreachable!("file name candidates are unbounded")
+  }
+
+  async fn append_manifest(&self, entry: &ManifestEntry) -> Result<(), String> {
+    let mut line = serde_json::to_string(entry).map_err(|e| e.to_string())?;
+    line.push('\n');
+
+    let mut manifest = tokio::fs::OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(self.root.join(MANIFEST_FILE))
+      .await
+      .map_err(|e| format!("Failed to open manifest: {}", e))?;
+    manifest
+      .write_all(line.as_bytes())
+      .await
+      .map_err(|e| format!("Failed to write manifest: {}", e))?;
+    manifest
+      .sync_data()
+      .await
+      .map_err(|e| format!("Failed to write manifest: {}", e))
+  }
+
+  /// Directory of a path relative to the root, which must stay below it
+  fn resolve(&self, relative: &str) -> Result<PathBuf, String> {
+    let mut path = self.root.clone();
+    for part in relative.split('/').filter(|part| !part.is_empty()) {
+      if part == "." || part == ".." || part.contains('\\') {
+        return Err(format!("Invalid storage path: {}", relative));
+      }
+      path.push(part);
+    }
+    Ok(path)
+  }
+}
+
+enum Reserved {
+  Free(String),
+  SameContent(String),
+}
+
+async fn write_synced(path: &Path, content: &[u8]) -> Result<(), String> {
+  let mut file = tokio::fs::File::create(path)
+    .await
+    .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+  file
+    .write_all(content)
+    .await
+    .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+  file
+    .sync_all()
+    .await
+    .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Rename the written temporary file to `target`. When that fails neither
+/// the temporary file nor the empty placeholder `reserve_name` created for
+/// `target` is left behind.
+async fn move_into_place(temp: &Path, target: &Path, reserved: bool) -> Result<(), String> {
+  if let Err(e) = tokio::fs::rename(temp, target).await {
+    let _ = tokio::fs::remove_file(temp).await;
+    if reserved {
+      let _ = tokio::fs::remove_file(target).await;
+    }
+    return Err(format!("Failed to store {}: {}", target.display(), e));
+  }
+  Ok(())
+}
+
+/// A single path segment made of a file or folder name
+pub(super) fn sanitize(name: &str) -> String {
+  let name: String = name
+    .chars()
+    .map(|c| match c {
+      '/' | '\\' | '\0' => '_',
+      c => c,
+    })
+    .collect();
+  match name.trim() {
+    "" | "." | ".." => "_".to_string(),
+    _ => name,
+  }
+}
+
+/// `name` for the first candidate, then `stem (n).ext`
+fn numbered(name: &str, n: u32) -> String {
+  if n == 1 {
+    return name.to_string();
+  }
+  match name.rsplit_once('.') {
+    Some((stem, ext)) if !stem.is_empty() => format!("{} ({}).{}", stem, n, ext),
+    _ => format!("{} ({})", name, n),
+  }
+}
+
+#[async_trait]
+impl CloudStorage for LocalStorageAdapter {
+  async fn upload_invoice_pdf(
+    &self,
+    folder_id: &str,
+    invoice_number: &str,
+    local_pdf_path: &str,
+  ) -> Result<String, InvoiceError> {
+    // An invoice number is unique, so a new PDF replaces the earlier one
+    let path = self
+      .store(
+        folder_id,
+        &format!("{}.pdf", invoice_number),
+        local_pdf_path,
+        true,
+      )
+      .await
+      .map_err(InvoiceError::CloudStorageUploadFailed)?;
+
+    tracing::info!("Stored invoice PDF locally: {}", path);
+    Ok(path)
+  }
+}
+
+#[async_trait]
+impl ReportCloudStorage for LocalStorageAdapter {
+  async fn create_folder(&self, parent_id: &str, name: &str) -> Result<String, ReportError> {
+    let path = join_key(parent_id, &sanitize(name));
+    let dir = self.resolve(&path).map_err(ReportError::CloudStorage)?;
+    tokio::fs::create_dir_all(&dir).await.map_err(|e| {
+      ReportError::CloudStorage(format!("Failed to create {}: {}", dir.display(), e))
+    })?;
+    Ok(path)
+  }
+
+  async fn upload_file(
+    &self,
+    folder_id: &str,
+    file_name: &str,
+    local_path: &str,
+    _mime_type: &str,
+  ) -> Result<String, ReportError> {
+    self
+      .store(folder_id, file_name, local_path, false)
+      .await
+      .map_err(ReportError::CloudStorage)
+  }
+
+  async fn update_file(
+    &self,
+    file_id: &str,
+    local_path: &str,
+    _mime_type: &str,
+  ) -> Result<String, ReportError> {
+    let (folder, name) = file_id.rsplit_once('/').unwrap_or(("", file_id));
+    self
+      .store(folder, name, local_path, true)
+      .await
+      .map_err(ReportError::CloudStorage)
+  }
+
+  async fn delete_file(&self, file_id: &str) -> Result<(), ReportError> {
+    let path = self.resolve(file_id).map_err(ReportError::CloudStorage)?;
+    match tokio::fs::remove_file(&path).await {
+      Ok(()) => {}
+      Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+      Err(e) => {
+        return Err(ReportError::CloudStorage(format!(
+          "Failed to delete {}: {}",
+          path.display(),
+          e
+        )));
+      }
+    }
+
+    self
+      .append_manifest(&ManifestEntry {
+        path: file_id.to_string(),
+        size: 0,
+        sha256: String::new(),
+        stored_at: Utc::now(),
+        deleted: true,
+      })
+      .await
+      .map_err(ReportError::CloudStorage)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn temp_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("local_storage_test_{}", Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+  }
+
+  fn source_file(dir: &Path, content: &[u8]) -> String {
+    let path = dir.join(format!("{}.pdf", Uuid::new_v4()));
+    std::fs::write(&path, content).unwrap();
+    path.to_string_lossy().to_string()
+  }
+
+  #[test]
+  fn test_numbered_names() {
+    assert_eq!(
+      numbered("2026-03-10 - Vendor.pdf", 1),
+      "2026-03-10 - Vendor.pdf"
+    );
+    assert_eq!(
+      numbered("2026-03-10 - Vendor.pdf", 2),
+      "2026-03-10 - Vendor (2).pdf"
+    );
+    assert_eq!(numbered("README", 3), "README (3)");
+    assert_eq!(numbered(".hidden", 2), ".hidden (2)");
+    assert_eq!(sanitize("Vendor A/S"), "Vendor A_S");
+    assert_eq!(sanitize(".."), "_");
+  }
+
+  #[tokio::test]
+  async fn test_failed_store_removes_the_reserved_name() {
+    let dir = temp_dir();
+    let storage = LocalStorageAdapter::new(&dir);
+    std::fs::write(dir.join("Vendor.pdf"), b"other content").unwrap();
+
+    let name = match storage
+      .reserve_name(&dir, "Vendor.pdf", "sha256")
+      .await
+      .unwrap()
+    {
+      Reserved::Free(name) => name,
+      Reserved::SameContent(_) => panic!("content differs"),
+    };
+    assert_eq!(name, "Vendor (2).pdf");
+    assert!(dir.join(&name).is_file());
+
+    // The temporary file went missing before the rename
+    let temp = dir.join(".Vendor.pdf.tmp");
+    let result = move_into_place(&temp, &dir.join(&name), true).await;
+    assert!(result.is_err());
+    assert!(!dir.join(&name).exists());
+    assert!(dir.join("Vendor.pdf").is_file());
+
+    // The name is free again for the next upload
+    let source = source_file(&dir, b"payment");
+    let stored = storage
+      .upload_file("", "Vendor.pdf", &source, "application/pdf")
+      .await
+      .unwrap();
+    assert_eq!(stored, "Vendor (2).pdf");
+  }
+
+  #[tokio::test]
+  async fn test_builds_the_report_layout_without_overwriting_files() {
+    let sources = temp_dir();
+    let root = temp_dir().join("company");
+    let storage = LocalStorageAdapter::new(&root);
+    let first = source_file(&sources, b"payment 1");
+    let second = source_file(&sources, b"payment 2");
+
+    let month = storage.create_folder("reports", "03.2026").await.unwrap();
+    let account = storage.create_folder(&month, "Main (EE12)").await.unwrap();
+    let incoming = storage.create_folder(&account, "incoming").await.unwrap();
+    assert!(root.join("reports/03.2026/Main (EE12)/incoming").is_dir());
+
+    // Two same-day payments to the same counterparty
+    let a = storage
+      .upload_file(
+        &incoming,
+        "2026-03-10 - Vendor.pdf",
+        &first,
+        "application/pdf",
+      )
+      .await
+      .unwrap();
+    let b = storage
+      .upload_file(
+        &incoming,
+        "2026-03-10 - Vendor.pdf",
+        &second,
+        "application/pdf",
+      )
+      .await
+      .unwrap();
+    assert_eq!(
+      a,
+      "reports/03.2026/Main (EE12)/incoming/2026-03-10 - Vendor.pdf"
+    );
+    assert_eq!(
+      b,
+      "reports/03.2026/Main (EE12)/incoming/2026-03-10 - Vendor (2).pdf"
+    );
+    assert_eq!(std::fs::read(root.join(&a)).unwrap(), b"payment 1");
+    assert_eq!(std::fs::read(root.join(&b)).unwrap(), b"payment 2");
+
+    // Uploading the same content again reuses the stored file
+    let again = storage
+      .upload_file(
+        &incoming,
+        "2026-03-10 - Vendor.pdf",
+        &second,
+        "application/pdf",
+      )
+      .await
+      .unwrap();
+    assert_eq!(again, b);
+
+    // An update replaces the file in place
+    std::fs::write(&first, b"payment 1 corrected").unwrap();
+    let updated = storage
+      .update_file(&a, &first, "application/pdf")
+      .await
+      .unwrap();
+    assert_eq!(updated, a);
+    assert_eq!(
+      std::fs::read(root.join(&a)).unwrap(),
+      b"payment 1 corrected"
+    );
+
+    // No temporary files are left behind
+    let leftovers = std::fs::read_dir(root.join(&incoming))
+      .unwrap()
+      .filter(|entry| {
+        entry
+          .as_ref()
+          .unwrap()
+          .file_name()
+          .to_string_lossy()
+          .ends_with(".tmp")
+      })
+      .count();
+    assert_eq!(leftovers, 0);
+
+    let manifest = storage.manifest().await.unwrap();
+    assert_eq!(manifest.len(), 2);
+    let entry = manifest.iter().find(|entry| entry.path == a).unwrap();
+    assert_eq!(entry.size, 19);
+    assert_eq!(
+      entry.sha256,
+      hex::encode(Sha256::digest(b"payment 1 corrected"))
+    );
+  }
+
+  #[tokio::test]
+  async fn test_invoice_pdfs_replace_their_earlier_version() {
+    let sources = temp_dir();
+    let root = temp_dir();
+    let storage = LocalStorageAdapter::new(&root);
+    let pdf = source_file(&sources, b"%PDF-1.4 draft");
+
+    storage
+      .upload_invoice_pdf("invoices", "INV-001", &pdf)
+      .await
+      .unwrap();
+    std::fs::write(&pdf, b"%PDF-1.4 sent").unwrap();
+    let path = storage
+      .upload_invoice_pdf("invoices", "INV-001", &pdf)
+      .await
+      .unwrap();
+
+    assert_eq!(path, "invoices/INV-001.pdf");
+    assert_eq!(std::fs::read(root.join(&path)).unwrap(), b"%PDF-1.4 sent");
+    assert_eq!(storage.manifest().await.unwrap().len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_deleted_files_leave_the_manifest() {
+    let sources = temp_dir();
+    let root = temp_dir();
+    let storage = LocalStorageAdapter::new(&root);
+    let receipt = source_file(&sources, b"receipt");
+    let kept = source_file(&sources, b"kept");
+    let path = storage
+      .upload_file("03.2026", "receipt.pdf", &receipt, "application/pdf")
+      .await
+      .unwrap();
+    storage
+      .upload_file("03.2026", "kept.pdf", &kept, "application/pdf")
+      .await
+      .unwrap();
+
+    storage.delete_file(&path).await.unwrap();
+    // Gone already: nothing left to do
+    storage.delete_file(&path).await.unwrap();
+
+    assert!(!root.join(&path).exists());
+    let manifest = storage.manifest().await.unwrap();
+    assert_eq!(manifest.len(), 1);
+    assert_eq!(manifest[0].path, "03.2026/kept.pdf");
+  }
+
+  #[tokio::test]
+  async fn test_rejects_paths_outside_the_root() {
+    let sources = temp_dir();
+    let storage = LocalStorageAdapter::new(temp_dir());
+    let pdf = source_file(&sources, b"%PDF-1.4");
+
+    let result = storage
+      .upload_file("../elsewhere", "a.pdf", &pdf, "application/pdf")
+      .await;
+
+    assert!(matches!(result, Err(ReportError::CloudStorage(_))));
+  }
+}
@@ -1,15 +1,29 @@
+mod drive_inspector_resolver;
 mod factory;
+#[cfg(test)]
+pub(crate) mod fake_drive;
 mod google_drive_adapter;
+mod google_drive_api;
 mod google_drive_oauth_adapter;
+mod local_storage_adapter;
 mod mock_oauth_manager;
 mod noop_storage;
 mod oauth_manager;
 pub mod report_drive_adapter;
+mod report_storage_resolver;
+mod s3_storage_adapter;
+mod webdav_storage_adapter;
 
+pub use drive_inspector_resolver::CompanyDriveInspectorResolver;
 pub use factory::CloudStorageFactory;
 pub use google_drive_adapter::GoogleDriveAdapter;
+pub use google_drive_api::{DriveEndpoints, DriveFileInfo, DriveFolder, GoogleDriveApi};
 pub use google_drive_oauth_adapter::GoogleDriveOAuthAdapter;
+pub use local_storage_adapter::{LocalStorageAdapter, ManifestEntry};
 pub use mock_oauth_manager::MockOAuthManager;
 pub use noop_storage::NoOpCloudStorage;
 pub use oauth_manager::{GoogleOAuthManager, OAuthManager, OAuthTokens};
 pub use report_drive_adapter::ReportDriveAdapter;
+pub use report_storage_resolver::CompanyReportStorageResolver;
+pub use s3_storage_adapter::{S3Settings, S3StorageAdapter};
+pub use webdav_storage_adapter::{WebDavSettings, WebDavStorageAdapter};
@@ -1,8 +1,8 @@
 use async_trait::async_trait;
-use reqwest::Client;
 use serde::Deserialize;
 use serde_json::json;
 
+use super::{DriveEndpoints, GoogleDriveApi};
 use crate::domain::report::{errors::ReportError, ports::ReportCloudStorage};
 
 /// No-op implementation for when Drive is not configured
@@ -27,19 +27,29 @@ impl ReportCloudStorage for NoOpReportCloudStorage {
       "Cloud storage not configured".to_string(),
     ))
   }
-}
 
-/// Google Drive adapter for report folder/file operations
-pub struct ReportDriveAdapter {
-  client: Client,
-  client_id: String,
-  client_secret: String,
-  refresh_token: String,
+  async fn update_file(
+    &self,
+    _file_id: &str,
+    _local_path: &str,
+    _mime_type: &str,
+  ) -> Result<String, ReportError> {
+    Err(ReportError::CloudStorage(
+      "Cloud storage not configured".to_string(),
+    ))
+  }
+
+  async fn delete_file(&self, _file_id: &str) -> Result<(), ReportError> {
+    Err(ReportError::CloudStorage(
+      "Cloud storage not configured".to_string(),
+    ))
+  }
 }
 
-#[derive(Debug, Deserialize)]
-struct TokenResponse {
-  access_token: String,
+/// Google Drive adapter for report folder/file operations. Folders in
+/// shared drives work like folders in My Drive.
+pub struct ReportDriveAdapter {
+  api: GoogleDriveApi,
 }
 
 #[derive(Debug, Deserialize)]
@@ -50,41 +60,21 @@ struct DriveFile {
 impl ReportDriveAdapter {
   pub fn new(client_id: String, client_secret: String, refresh_token: String) -> Self {
     Self {
-      client: Client::new(),
-      client_id,
-      client_secret,
-      refresh_token,
+      api: GoogleDriveApi::new(client_id, client_secret, refresh_token),
     }
   }
 
-  async fn get_access_token(&self) -> Result<String, ReportError> {
-    let response = self
-      .client
-      .post("https://oauth2.googleapis.com/token")
-      .form(&[
-        ("client_id", self.client_id.as_str()),
-        ("client_secret", self.client_secret.as_str()),
-        ("refresh_token", self.refresh_token.as_str()),
-        ("grant_type", "refresh_token"),
-      ])
-      .send()
-      .await
-      .map_err(|e| ReportError::CloudStorage(format!("Token refresh failed: {}", e)))?;
-
-    if !response.status().is_success() {
-      let body = response.text().await.unwrap_or_default();
-      return Err(ReportError::CloudStorage(format!(
-        "Token refresh failed: {}",
-        body
-      )));
-    }
+  pub fn with_endpoints(mut self, endpoints: DriveEndpoints) -> Self {
+    self.api = self.api.with_endpoints(endpoints);
+    self
+  }
 
-    let token: TokenResponse = response
-      .json()
+  async fn get_access_token(&self) -> Result<String, ReportError> {
+    self
+      .api
+      .access_token()
       .await
-      .map_err(|e| ReportError::CloudStorage(format!("Token parse failed: {}", e)))?;
-
-    Ok(token.access_token)
+      .map_err(ReportError::CloudStorage)
   }
 }
 
@@ -100,8 +90,10 @@ impl ReportCloudStorage for ReportDriveAdapter {
     });
 
     let response = self
-      .client
-      .post("https://www.googleapis.com/drive/v3/files")
+      .api
+      .client()
+      .post(format!("{}/files", self.api.endpoints().api_url))
+      .query(&[("supportsAllDrives", "true")])
       .bearer_auth(&token)
       .json(&metadata)
       .send()
@@ -155,8 +147,10 @@ impl ReportCloudStorage for ReportDriveAdapter {
       .part("file", file_part);
 
     let response = self
-      .client
-      .post("https://www.googleapis.com/upload/drive/v3/files?uploadType=multipart")
+      .api
+      .client()
+      .post(format!("{}/files", self.api.endpoints().upload_url))
+      .query(&[("uploadType", "multipart"), ("supportsAllDrives", "true")])
       .bearer_auth(&token)
       .multipart(form)
       .send()
@@ -178,4 +172,135 @@ impl ReportCloudStorage for ReportDriveAdapter {
 
     Ok(file.id)
   }
+
+  async fn update_file(
+    &self,
+    file_id: &str,
+    local_path: &str,
+    mime_type: &str,
+  ) -> Result<String, ReportError> {
+    let token = self.get_access_token().await?;
+
+    let file_bytes = tokio::fs::read(local_path)
+      .await
+      .map_err(|e| ReportError::FileError(format!("Failed to read {}: {}", local_path, e)))?;
+
+    // Drive keeps the previous content as a revision of the file
+    let response = self
+      .api
+      .client()
+      .patch(format!(
+        "{}/files/{}",
+        self.api.endpoints().upload_url,
+        file_id
+      ))
+      .query(&[("uploadType", "media"), ("supportsAllDrives", "true")])
+      .bearer_auth(&token)
+      .header(reqwest::header::CONTENT_TYPE, mime_type)
+      .body(file_bytes)
+      .send()
+      .await
+      .map_err(|e| ReportError::CloudStorage(format!("Update failed: {}", e)))?;
+
+    if !response.status().is_success() {
+      let body = response.text().await.unwrap_or_default();
+      return Err(ReportError::CloudStorage(format!(
+        "Update failed: {}",
+        body
+      )));
+    }
+
+    let file: DriveFile = response
+      .json()
+      .await
+      .map_err(|e| ReportError::CloudStorage(format!("Parse response failed: {}", e)))?;
+
+    Ok(file.id)
+  }
+
+  /// Moves the file to the trash, where it can still be restored
+  async fn delete_file(&self, file_id: &str) -> Result<(), ReportError> {
+    let token = self.get_access_token().await?;
+
+    let response = self
+      .api
+      .client()
+      .patch(format!(
+        "{}/files/{}",
+        self.api.endpoints().api_url,
+        file_id
+      ))
+      .query(&[("supportsAllDrives", "true")])
+      .bearer_auth(&token)
+      .json(&json!({ "trashed": true }))
+      .send()
+      .await
+      .map_err(|e| ReportError::CloudStorage(format!("Delete failed: {}", e)))?;
+
+    if !response.status().is_success() && response.status() != reqwest::StatusCode::NOT_FOUND {
+      let body = response.text().await.unwrap_or_default();
+      return Err(ReportError::CloudStorage(format!(
+        "Delete failed: {}",
+        body
+      )));
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::infrastructure::cloud::fake_drive;
+
+  fn adapter(endpoints: DriveEndpoints) -> ReportDriveAdapter {
+    ReportDriveAdapter::new(
+      "client-id".to_string(),
+      "client-secret".to_string(),
+      fake_drive::REFRESH_TOKEN.to_string(),
+    )
+    .with_endpoints(endpoints)
+  }
+
+  fn write_file(content: &[u8]) -> String {
+    let dir = std::env::temp_dir().join(format!("drive_test_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("invoice.pdf");
+    std::fs::write(&path, content).unwrap();
+    path.to_string_lossy().to_string()
+  }
+
+  #[actix_web::test]
+  async fn test_uploads_reports_into_a_shared_drive() {
+    let (endpoints, drive) = fake_drive::start();
+    let shared = drive.lock().unwrap().add_shared_drive("Accounting");
+    let adapter = adapter(endpoints);
+
+    let month = adapter.create_folder(&shared, "03.2026").await.unwrap();
+    let file = write_file(b"%PDF-1.4 v1");
+    let file_id = adapter
+      .upload_file(&month, "2026-03-10 - Acme.pdf", &file, "application/pdf")
+      .await
+      .unwrap();
+    {
+      let drive = drive.lock().unwrap();
+      let uploaded = &drive.files[&file_id];
+      assert_eq!(uploaded.parents, vec![month.clone()]);
+      assert_eq!(uploaded.drive_id.as_deref(), Some(shared.as_str()));
+      assert_eq!(uploaded.content, b"%PDF-1.4 v1");
+    }
+
+    // A new version keeps the file ID
+    let file = write_file(b"%PDF-1.4 v2");
+    let updated = adapter
+      .update_file(&file_id, &file, "application/pdf")
+      .await
+      .unwrap();
+    assert_eq!(updated, file_id);
+    assert_eq!(
+      drive.lock().unwrap().files[&file_id].content,
+      b"%PDF-1.4 v2"
+    );
+  }
 }
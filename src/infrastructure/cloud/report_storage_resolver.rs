@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::CloudStorageFactory;
+use crate::domain::company::CompanyRepository;
+use crate::domain::report::{
+  errors::ReportError,
+  ports::{ReportCloudStorage, ReportStorageResolver},
+};
+use crate::infrastructure::config::Config;
+use crate::infrastructure::security::AesTokenEncryption;
+
+/// Builds each company's report storage from its settings whenever reports
+/// are uploaded, so a change of provider applies to the next upload
+pub struct CompanyReportStorageResolver {
+  company_repo: Arc<dyn CompanyRepository>,
+  token_encryption: Arc<AesTokenEncryption>,
+  config: Arc<Config>,
+}
+
+impl CompanyReportStorageResolver {
+  pub fn new(
+    company_repo: Arc<dyn CompanyRepository>,
+    token_encryption: Arc<AesTokenEncryption>,
+    config: Arc<Config>,
+  ) -> Self {
+    Self {
+      company_repo,
+      token_encryption,
+      config,
+    }
+  }
+}
+
+#[async_trait]
+impl ReportStorageResolver for CompanyReportStorageResolver {
+  async fn for_company(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Arc<dyn ReportCloudStorage>, ReportError> {
+    let company = self
+      .company_repo
+      .find_by_id(company_id)
+      .await
+      .map_err(|e| ReportError::CloudStorage(e.to_string()))?
+      .ok_or_else(|| ReportError::CloudStorage("Company not found".to_string()))?;
+
+    let drive_config = self.config.google_drive.as_ref();
+    Ok(CloudStorageFactory::create_report_storage(
+      &company,
+      &self.token_encryption,
+      drive_config.and_then(|c| c.oauth_client_id.as_deref()),
+      drive_config.and_then(|c| c.oauth_client_secret.as_deref()),
+      &self.config.local_storage.root_dir,
+    ))
+  }
+}
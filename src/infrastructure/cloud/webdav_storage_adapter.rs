@@ -0,0 +1,441 @@
+use async_trait::async_trait;
+use reqwest::{Client, Method, StatusCode};
+
+use super::local_storage_adapter::sanitize;
+use super::s3_storage_adapter::{join_key, uri_encode};
+use crate::domain::invoice::errors::InvoiceError;
+use crate::domain::invoice::ports::CloudStorage;
+use crate::domain::report::{errors::ReportError, ports::ReportCloudStorage};
+
+/// Connection settings of a WebDAV server, with the app password decrypted
+#[derive(Debug, Clone)]
+pub struct WebDavSettings {
+  /// WebDAV URL of the user's files
+  pub url: String,
+  pub username: String,
+  pub app_password: String,
+  pub root_folder: String,
+}
+
+/// WebDAV storage (Nextcloud, ownCloud) for invoice PDFs and report files.
+///
+/// Folders are created with MKCOL and files uploaded with PUT, both
+/// authenticated with an app password. Paths relative to the root folder
+/// are returned as file and folder IDs; the names added to them are single
+/// path segments, so they can't reach outside their folder.
+pub struct WebDavStorageAdapter {
+  client: Client,
+  settings: WebDavSettings,
+}
+
+impl WebDavStorageAdapter {
+  pub fn new(settings: WebDavSettings) -> Self {
+    Self {
+      client: Client::new(),
+      settings,
+    }
+  }
+
+  /// Check that the server accepts the credentials
+  pub async fn test_connection(&self) -> Result<(), String> {
+    let response = self
+      .request(Method::from_bytes(b"PROPFIND").expect("valid method"), "")
+      .header("Depth", "0")
+      .send()
+      .await
+      .map_err(|e| format!("WebDAV request failed: {}", e))?;
+
+    match response.status() {
+      StatusCode::MULTI_STATUS | StatusCode::OK => Ok(()),
+      StatusCode::UNAUTHORIZED => Err("WebDAV server rejected the credentials".to_string()),
+      status => Err(format!("WebDAV server answered {}", status)),
+    }
+  }
+
+  /// Content of the file at `path` below the root folder
+  pub async fn get_file(&self, path: &str) -> Result<Vec<u8>, String> {
+    let response = self
+      .request(Method::GET, &self.full_path(path))
+      .send()
+      .await
+      .map_err(|e| format!("WebDAV request failed: {}", e))?;
+    if !response.status().is_success() {
+      return Err(format!(
+        "WebDAV GET {} failed ({})",
+        path,
+        response.status()
+      ));
+    }
+    response
+      .bytes()
+      .await
+      .map(|bytes| bytes.to_vec())
+      .map_err(|e| format!("Failed to read {}: {}", path, e))
+  }
+
+  /// Create the collection at `path` below the root folder, and its
+  /// missing parents. An existing collection is left as it is.
+  async fn ensure_collection(&self, path: &str) -> Result<(), String> {
+    let path = self.full_path(path);
+    if path.is_empty() || self.make_collection(&path).await? {
+      return Ok(());
+    }
+
+    // Parent missing: create the chain from the top
+    let mut current = String::new();
+    for segment in path.split('/') {
+      current = join_key(&current, segment);
+      if !self.make_collection(&current).await? {
+        return Err(format!("WebDAV MKCOL {} failed (409 Conflict)", current));
+      }
+    }
+    Ok(())
+  }
+
+  /// MKCOL of a path from the WebDAV URL; false when its parent doesn't
+  /// exist
+  async fn make_collection(&self, path: &str) -> Result<bool, String> {
+    let response = self
+      .request(Method::from_bytes(b"MKCOL").expect("valid method"), path)
+      .send()
+      .await
+      .map_err(|e| format!("WebDAV request failed: {}", e))?;
+
+    match response.status() {
+      // 405: the collection already exists
+      status if status.is_success() || status == StatusCode::METHOD_NOT_ALLOWED => Ok(true),
+      StatusCode::CONFLICT => Ok(false),
+      status => Err(format!("WebDAV MKCOL {} failed ({})", path, status)),
+    }
+  }
+
+  async fn put_file(&self, path: &str, local_path: &str, content_type: &str) -> Result<(), String> {
+    let content = tokio::fs::read(local_path)
+      .await
+      .map_err(|e| format!("Failed to read {}: {}", local_path, e))?;
+
+    let response = self
+      .request(Method::PUT, &self.full_path(path))
+      .header("content-type", content_type)
+      .body(content)
+      .send()
+      .await
+      .map_err(|e| format!("WebDAV request failed: {}", e))?;
+    if !response.status().is_success() {
+      let status = response.status();
+      let body = response.text().await.unwrap_or_default();
+      return Err(format!("WebDAV PUT {} failed ({}): {}", path, status, body));
+    }
+    Ok(())
+  }
+
+  /// Path from the WebDAV URL of a path below the root folder
+  fn full_path(&self, path: &str) -> String {
+    join_key(&self.settings.root_folder, path)
+  }
+
+  /// Authenticated request for a path from the WebDAV URL
+  fn request(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
+    self
+      .client
+      .request(method, self.url(path))
+      .basic_auth(&self.settings.username, Some(&self.settings.app_password))
+  }
+
+  fn url(&self, path: &str) -> String {
+    format!(
+      "{}/{}",
+      self.settings.url.trim_end_matches('/'),
+      uri_encode(path)
+    )
+  }
+}
+
+#[async_trait]
+impl CloudStorage for WebDavStorageAdapter {
+  async fn upload_invoice_pdf(
+    &self,
+    folder_id: &str,
+    invoice_number: &str,
+    local_pdf_path: &str,
+  ) -> Result<String, InvoiceError> {
+    self
+      .ensure_collection(folder_id)
+      .await
+      .map_err(InvoiceError::CloudStorageUploadFailed)?;
+
+    let path = join_key(folder_id, &sanitize(&format!("{}.pdf", invoice_number)));
+    self
+      .put_file(&path, local_pdf_path, "application/pdf")
+      .await
+      .map_err(InvoiceError::CloudStorageUploadFailed)?;
+
+    tracing::info!("Uploaded invoice PDF to WebDAV: {}", path);
+    Ok(path)
+  }
+}
+
+#[async_trait]
+impl ReportCloudStorage for WebDavStorageAdapter {
+  async fn create_folder(&self, parent_id: &str, name: &str) -> Result<String, ReportError> {
+    let path = join_key(parent_id, &sanitize(name));
+    self
+      .ensure_collection(&path)
+      .await
+      .map_err(ReportError::CloudStorage)?;
+    Ok(path)
+  }
+
+  async fn upload_file(
+    &self,
+    folder_id: &str,
+    file_name: &str,
+    local_path: &str,
+    mime_type: &str,
+  ) -> Result<String, ReportError> {
+    let path = join_key(folder_id, &sanitize(file_name));
+    self
+      .put_file(&path, local_path, mime_type)
+      .await
+      .map_err(ReportError::CloudStorage)?;
+    Ok(path)
+  }
+
+  async fn update_file(
+    &self,
+    file_id: &str,
+    local_path: &str,
+    mime_type: &str,
+  ) -> Result<String, ReportError> {
+    self
+      .put_file(file_id, local_path, mime_type)
+      .await
+      .map_err(ReportError::CloudStorage)?;
+    Ok(file_id.to_string())
+  }
+
+  async fn delete_file(&self, file_id: &str) -> Result<(), ReportError> {
+    let response = self
+      .request(Method::DELETE, &self.full_path(file_id))
+      .send()
+      .await
+      .map_err(|e| ReportError::CloudStorage(format!("WebDAV request failed: {}", e)))?;
+
+    match response.status() {
+      status if status.is_success() || status == StatusCode::NOT_FOUND => Ok(()),
+      status => Err(ReportError::CloudStorage(format!(
+        "WebDAV DELETE {} failed ({})",
+        file_id, status
+      ))),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use actix_web::{App, HttpRequest, HttpResponse, HttpServer, web};
+  use std::collections::{HashMap, HashSet};
+  use std::sync::Mutex;
+
+  /// Collections and files of an in-memory WebDAV server
+  #[derive(Default)]
+  struct FakeDav {
+    collections: HashSet<String>,
+    files: HashMap<String, Vec<u8>>,
+  }
+
+  async fn fake_dav(
+    req: HttpRequest,
+    body: web::Bytes,
+    dav: web::Data<Mutex<FakeDav>>,
+  ) -> HttpResponse {
+    if req.headers().get("authorization").is_none() {
+      return HttpResponse::Unauthorized().finish();
+    }
+    let path = req.path().trim_end_matches('/').to_string();
+    let parent = path
+      .rsplit_once('/')
+      .map(|(parent, _)| parent)
+      .unwrap_or("");
+    let mut dav = dav.lock().unwrap();
+    let parent_exists = parent.is_empty() || dav.collections.contains(parent);
+
+    match req.method().as_str() {
+      "PROPFIND" => HttpResponse::build(actix_web::http::StatusCode::MULTI_STATUS).finish(),
+      "MKCOL" if dav.collections.contains(&path) => HttpResponse::MethodNotAllowed().finish(),
+      "MKCOL" if !parent_exists => HttpResponse::Conflict().finish(),
+      "MKCOL" => {
+        dav.collections.insert(path);
+        HttpResponse::Created().finish()
+      }
+      "PUT" if !parent_exists => HttpResponse::Conflict().finish(),
+      "PUT" => {
+        dav.files.insert(path, body.to_vec());
+        HttpResponse::Created().finish()
+      }
+      "GET" => match dav.files.get(&path) {
+        Some(content) => HttpResponse::Ok().body(content.clone()),
+        None => HttpResponse::NotFound().finish(),
+      },
+      "DELETE" => match dav.files.remove(&path) {
+        Some(_) => HttpResponse::NoContent().finish(),
+        None => HttpResponse::NotFound().finish(),
+      },
+      _ => HttpResponse::MethodNotAllowed().finish(),
+    }
+  }
+
+  /// Start the fake server; returns its WebDAV URL and state
+  fn start_fake_dav() -> (String, web::Data<Mutex<FakeDav>>) {
+    let dav = web::Data::new(Mutex::new(FakeDav::default()));
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let base = "/remote.php/dav/files/alice";
+    dav.lock().unwrap().collections.insert(base.to_string());
+    let state = dav.clone();
+    let server = HttpServer::new(move || {
+      App::new()
+        .app_data(state.clone())
+        .default_service(web::to(fake_dav))
+    })
+    .workers(1)
+    .listen(listener)
+    .unwrap()
+    .run();
+    actix_web::rt::spawn(server);
+
+    (format!("http://127.0.0.1:{}{}", port, base), dav)
+  }
+
+  fn adapter(url: String) -> WebDavStorageAdapter {
+    WebDavStorageAdapter::new(WebDavSettings {
+      url,
+      username: "alice".to_string(),
+      app_password: "app-password".to_string(),
+      root_folder: "TaxByte".to_string(),
+    })
+  }
+
+  fn write_pdf(content: &[u8]) -> String {
+    let dir = std::env::temp_dir().join(format!("webdav_test_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let pdf = dir.join("invoice.pdf");
+    std::fs::write(&pdf, content).unwrap();
+    pdf.to_string_lossy().to_string()
+  }
+
+  #[test]
+  fn test_urls_are_encoded_below_the_webdav_url() {
+    let adapter = adapter("https://cloud.example.com/remote.php/dav/files/alice/".to_string());
+
+    assert_eq!(
+      adapter.url("TaxByte/03.2026/Main (EE12)/Vendor OÜ.pdf"),
+      "https://cloud.example.com/remote.php/dav/files/alice/TaxByte/03.2026/Main%20%28EE12%29/Vendor%20O%C3%9C.pdf"
+    );
+  }
+
+  #[actix_web::test]
+  async fn test_uploads_invoices_and_reports_into_collections() {
+    let (url, dav) = start_fake_dav();
+    let adapter = adapter(url);
+    let pdf = write_pdf(b"%PDF-1.4 invoice");
+
+    adapter.test_connection().await.unwrap();
+
+    // Missing root and invoice folders are created on the way
+    let path = adapter
+      .upload_invoice_pdf("Invoices", "INV-001", &pdf)
+      .await
+      .unwrap();
+    assert_eq!(path, "Invoices/INV-001.pdf");
+    assert_eq!(adapter.get_file(&path).await.unwrap(), b"%PDF-1.4 invoice");
+
+    // Report folders are collections; existing ones are reused
+    let month = adapter.create_folder("", "03.2026").await.unwrap();
+    let account = adapter.create_folder(&month, "Main (EE12)").await.unwrap();
+    assert_eq!(adapter.create_folder("", "03.2026").await.unwrap(), month);
+    let file_id = adapter
+      .upload_file(&account, "Vendor OÜ.pdf", &pdf, "application/pdf")
+      .await
+      .unwrap();
+    assert_eq!(file_id, "03.2026/Main (EE12)/Vendor OÜ.pdf");
+
+    std::fs::write(&pdf, b"%PDF-1.4 corrected").unwrap();
+    let updated = adapter
+      .update_file(&file_id, &pdf, "application/pdf")
+      .await
+      .unwrap();
+    assert_eq!(updated, file_id);
+    assert_eq!(
+      adapter.get_file(&file_id).await.unwrap(),
+      b"%PDF-1.4 corrected"
+    );
+
+    let dav = dav.lock().unwrap();
+    assert!(
+      dav
+        .collections
+        .contains("/remote.php/dav/files/alice/TaxByte/03.2026/Main%20%28EE12%29")
+    );
+    assert_eq!(dav.files.len(), 2);
+  }
+
+  #[actix_web::test]
+  async fn test_names_cannot_leave_their_folder() {
+    let (url, dav) = start_fake_dav();
+    let adapter = adapter(url);
+    let pdf = write_pdf(b"%PDF-1.4 invoice");
+
+    let month = adapter.create_folder("", "..").await.unwrap();
+    assert_eq!(month, "_");
+    let account = adapter.create_folder(&month, "../Main/EE12").await.unwrap();
+    assert_eq!(account, "_/.._Main_EE12");
+
+    let file_id = adapter
+      .upload_file(&account, "..\\secrets/Vendor.pdf", &pdf, "application/pdf")
+      .await
+      .unwrap();
+    assert_eq!(file_id, "_/.._Main_EE12/.._secrets_Vendor.pdf");
+    let path = adapter
+      .upload_invoice_pdf("Invoices", "2026/INV-001", &pdf)
+      .await
+      .unwrap();
+    assert_eq!(path, "Invoices/2026_INV-001.pdf");
+
+    let dav = dav.lock().unwrap();
+    let mut files: Vec<_> = dav.files.keys().cloned().collect();
+    files.sort();
+    assert_eq!(
+      files,
+      [
+        "/remote.php/dav/files/alice/TaxByte/Invoices/2026_INV-001.pdf",
+        "/remote.php/dav/files/alice/TaxByte/_/.._Main_EE12/.._secrets_Vendor.pdf",
+      ]
+    );
+  }
+
+  #[actix_web::test]
+  async fn test_failed_puts_and_deletes_of_missing_files() {
+    let (url, _dav) = start_fake_dav();
+    let adapter = adapter(url);
+    let pdf = write_pdf(b"%PDF-1.4 invoice");
+
+    // PUT doesn't create folders; the server answers 409 Conflict
+    let error = adapter
+      .upload_file("Missing", "Vendor.pdf", &pdf, "application/pdf")
+      .await
+      .unwrap_err();
+    assert!(error.to_string().contains("409"), "{}", error);
+
+    let folder = adapter.create_folder("", "03.2026").await.unwrap();
+    let file_id = adapter
+      .upload_file(&folder, "Vendor.pdf", &pdf, "application/pdf")
+      .await
+      .unwrap();
+    adapter.delete_file(&file_id).await.unwrap();
+    assert!(adapter.get_file(&file_id).await.is_err());
+    // Already gone counts as deleted
+    adapter.delete_file(&file_id).await.unwrap();
+  }
+}
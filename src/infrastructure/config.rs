@@ -86,6 +86,34 @@ fn default_pdf_output_dir() -> String {
   "./data/invoices/pdfs".to_string()
 }
 
+fn default_pdf_logo_dir() -> String {
+  "./data/branding".to_string()
+}
+
+fn default_pdf_assets_dir() -> String {
+  "./static".to_string()
+}
+
+fn default_local_storage_root() -> String {
+  "./data/storage".to_string()
+}
+
+fn default_documents_root() -> String {
+  "./data/documents".to_string()
+}
+
+fn default_integrity_check_interval_seconds() -> u64 {
+  21600
+}
+
+fn default_purge_interval_seconds() -> u64 {
+  86400
+}
+
+fn default_reconcile_interval_seconds() -> u64 {
+  21600
+}
+
 /// Main application configuration
 #[derive(Debug, Clone, Deserialize)]
 pub struct Config {
@@ -103,6 +131,14 @@ pub struct Config {
   pub google_drive: Option<GoogleDriveConfig>,
   #[serde(default)]
   pub pdf: PdfConfig,
+  #[serde(default)]
+  pub local_storage: LocalStorageConfig,
+  #[serde(default)]
+  pub documents: DocumentsConfig,
+  #[serde(default)]
+  pub retention: RetentionConfig,
+  #[serde(default)]
+  pub drive_sync: DriveSyncConfig,
 }
 
 /// Server configuration
@@ -234,6 +270,12 @@ pub struct PdfConfig {
   #[serde(default = "default_pdf_output_dir")]
   pub output_dir: String,
   pub wkhtmltopdf_path: Option<String>,
+  /// Directory the companies' invoice logos are stored in
+  #[serde(default = "default_pdf_logo_dir")]
+  pub logo_dir: String,
+  /// The only local files wkhtmltopdf may read while rendering
+  #[serde(default = "default_pdf_assets_dir")]
+  pub assets_dir: String,
 }
 
 impl Default for PdfConfig {
@@ -241,6 +283,86 @@ impl Default for PdfConfig {
     Self {
       output_dir: default_pdf_output_dir(),
       wkhtmltopdf_path: None,
+      logo_dir: default_pdf_logo_dir(),
+      assets_dir: default_pdf_assets_dir(),
+    }
+  }
+}
+
+/// Local filesystem storage provider configuration
+#[derive(Debug, Clone, Deserialize)]
+pub struct LocalStorageConfig {
+  /// Directory the companies' storage directories are created in
+  #[serde(default = "default_local_storage_root")]
+  pub root_dir: String,
+}
+
+impl Default for LocalStorageConfig {
+  fn default() -> Self {
+    Self {
+      root_dir: default_local_storage_root(),
+    }
+  }
+}
+
+/// Document store configuration
+#[derive(Debug, Clone, Deserialize)]
+pub struct DocumentsConfig {
+  /// Directory uploaded and generated files are stored in
+  #[serde(default = "default_documents_root")]
+  pub root_dir: String,
+  /// How often stored files are checked against their SHA-256
+  #[serde(default = "default_integrity_check_interval_seconds")]
+  pub integrity_check_interval_seconds: u64,
+  /// Encrypt stored files with per-company data keys, wrapped by the
+  /// security encryption key
+  #[serde(default)]
+  pub encrypt_at_rest: bool,
+}
+
+impl Default for DocumentsConfig {
+  fn default() -> Self {
+    Self {
+      root_dir: default_documents_root(),
+      integrity_check_interval_seconds: default_integrity_check_interval_seconds(),
+      encrypt_at_rest: false,
+    }
+  }
+}
+
+/// Retention of source documents
+#[derive(Debug, Clone, Deserialize)]
+pub struct RetentionConfig {
+  /// Periodically remove records past their company's retention period
+  #[serde(default)]
+  pub purge_enabled: bool,
+  /// How often the purge runs when enabled
+  #[serde(default = "default_purge_interval_seconds")]
+  pub purge_interval_seconds: u64,
+}
+
+impl Default for RetentionConfig {
+  fn default() -> Self {
+    Self {
+      purge_enabled: false,
+      purge_interval_seconds: default_purge_interval_seconds(),
+    }
+  }
+}
+
+/// Reconciliation of uploaded files with Google Drive
+#[derive(Debug, Clone, Deserialize)]
+pub struct DriveSyncConfig {
+  /// How often uploaded files are checked for being moved, renamed or
+  /// deleted in Drive
+  #[serde(default = "default_reconcile_interval_seconds")]
+  pub reconcile_interval_seconds: u64,
+}
+
+impl Default for DriveSyncConfig {
+  fn default() -> Self {
+    Self {
+      reconcile_interval_seconds: default_reconcile_interval_seconds(),
     }
   }
 }
@@ -281,6 +403,7 @@ impl Config {
   /// - `TAXBYTE_GOOGLE_DRIVE__OAUTH_CLIENT_ID=your-client-id.apps.googleusercontent.com`
   /// - `TAXBYTE_GOOGLE_DRIVE__OAUTH_CLIENT_SECRET=your-client-secret`
   /// - `TAXBYTE_GOOGLE_DRIVE__OAUTH_REDIRECT_URL=http://localhost:8080/oauth/google/callback`
+  /// - `TAXBYTE_LOCAL_STORAGE__ROOT_DIR=/srv/taxbyte/storage`
   ///
   /// Note: Use double underscores (__) to separate the section name from the field name.
   /// For nested config like `google_drive.oauth_client_id`, use `GOOGLE_DRIVE__OAUTH_CLIENT_ID`.
@@ -399,12 +522,29 @@ mod tests {
     assert_eq!(config.rate_limit.login_max_attempts, 5);
     assert_eq!(config.rate_limit.login_window_seconds, 300);
 
+    // Local storage defaults
+    assert_eq!(config.local_storage.root_dir, "./data/storage");
+
+    // Document store defaults
+    assert_eq!(config.documents.root_dir, "./data/documents");
+    assert_eq!(config.documents.integrity_check_interval_seconds, 21600);
+    assert!(!config.documents.encrypt_at_rest);
+
+    // Retention defaults
+    assert!(!config.retention.purge_enabled);
+    assert_eq!(config.retention.purge_interval_seconds, 86400);
+
+    // Drive sync defaults
+    assert_eq!(config.drive_sync.reconcile_interval_seconds, 21600);
+
     // Google Drive defaults to None
     assert!(config.google_drive.is_none());
 
     // PDF defaults
     assert_eq!(config.pdf.output_dir, "./data/invoices/pdfs");
     assert!(config.pdf.wkhtmltopdf_path.is_none());
+    assert_eq!(config.pdf.logo_dir, "./data/branding");
+    assert_eq!(config.pdf.assets_dir, "./static");
   }
 
   #[test]
@@ -1,3 +1,7 @@
+mod report_index_writer;
+mod statement_csv_writer;
 mod swedbank_parser;
 
+pub use report_index_writer::ReportIndexCsvWriter;
+pub use statement_csv_writer::StatementCsvExporter;
 pub use swedbank_parser::SwedbankCsvParser;
@@ -0,0 +1,124 @@
+use rust_decimal::Decimal;
+
+use crate::domain::report::{ReportError, ReportIndexRow, ReportIndexWriter};
+
+/// Writes a report archive's transaction index as a comma-separated table
+///
+/// One row per transaction and matched document, so a payment settling
+/// several invoices appears once per invoice.
+#[derive(Default)]
+pub struct ReportIndexCsvWriter;
+
+impl ReportIndexCsvWriter {
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl ReportIndexWriter for ReportIndexCsvWriter {
+  fn write(&self, rows: &[ReportIndexRow]) -> Result<Vec<u8>, ReportError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+
+    write_record(
+      &mut writer,
+      &[
+        "Date",
+        "Account",
+        "Counterparty",
+        "Description",
+        "Amount",
+        "Currency",
+        "Status",
+        "Category",
+        "Document",
+        "Allocated",
+        "File",
+      ],
+    )?;
+
+    for row in rows {
+      write_record(
+        &mut writer,
+        &[
+          &row.date.format("%Y-%m-%d").to_string(),
+          &row.account,
+          row.counterparty.as_deref().unwrap_or_default(),
+          row.description.as_deref().unwrap_or_default(),
+          &amount(row.amount),
+          &row.currency,
+          &row.status,
+          row.category.as_deref().unwrap_or_default(),
+          row.document.as_deref().unwrap_or_default(),
+          &row.allocated.map(amount).unwrap_or_default(),
+          row.file.as_deref().unwrap_or_default(),
+        ],
+      )?;
+    }
+
+    writer
+      .into_inner()
+      .map_err(|e| ReportError::FileError(e.to_string()))
+  }
+}
+
+fn write_record(writer: &mut csv::Writer<Vec<u8>>, record: &[&str]) -> Result<(), ReportError> {
+  writer
+    .write_record(record)
+    .map_err(|e| ReportError::FileError(e.to_string()))
+}
+
+fn amount(value: Decimal) -> String {
+  format!("{:.2}", value)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::NaiveDate;
+  use rust_decimal_macros::dec;
+
+  #[test]
+  fn test_writes_one_row_per_document() {
+    let row = ReportIndexRow {
+      date: NaiveDate::from_ymd_opt(2026, 3, 10).unwrap(),
+      account: "Main (EE382200221020145685)".to_string(),
+      counterparty: Some("Acme, Inc".to_string()),
+      description: Some("Invoice 12".to_string()),
+      amount: dec!(-150),
+      currency: "EUR".to_string(),
+      status: "Matched".to_string(),
+      category: None,
+      document: Some("INV-12".to_string()),
+      allocated: Some(dec!(150)),
+      file: Some("Main (EE382200221020145685)/outcoming/2026-03-10 - Acme.pdf".to_string()),
+    };
+    let unmatched = ReportIndexRow {
+      status: "Unmatched".to_string(),
+      document: None,
+      allocated: None,
+      file: None,
+      ..row.clone()
+    };
+
+    let csv = String::from_utf8(
+      ReportIndexCsvWriter::new()
+        .write(&[row, unmatched])
+        .unwrap(),
+    )
+    .unwrap();
+    let rows: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(
+      rows[0],
+      "Date,Account,Counterparty,Description,Amount,Currency,Status,Category,Document,Allocated,File"
+    );
+    assert_eq!(
+      rows[1],
+      "2026-03-10,Main (EE382200221020145685),\"Acme, Inc\",Invoice 12,-150.00,EUR,Matched,,INV-12,150.00,Main (EE382200221020145685)/outcoming/2026-03-10 - Acme.pdf"
+    );
+    assert_eq!(
+      rows[2],
+      "2026-03-10,Main (EE382200221020145685),\"Acme, Inc\",Invoice 12,-150.00,EUR,Unmatched,,,,"
+    );
+  }
+}
@@ -0,0 +1,139 @@
+use rust_decimal::Decimal;
+
+use crate::domain::financials::{
+  Comparison, FinancialStatement, FinancialsError, PeriodKind, StatementCsvWriter,
+};
+
+/// Writes a financial statement as a comma-separated table
+///
+/// One row per line, a total row per section and the bottom line last.
+/// Monthly statements carry previous-month and year-ago columns; yearly ones
+/// only the previous year.
+#[derive(Default)]
+pub struct StatementCsvExporter;
+
+impl StatementCsvExporter {
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl StatementCsvWriter for StatementCsvExporter {
+  fn write(&self, statement: &FinancialStatement) -> Result<Vec<u8>, FinancialsError> {
+    let monthly = statement.period.kind == PeriodKind::Month;
+    let mut writer = csv::Writer::from_writer(Vec::new());
+
+    let mut header = vec![
+      "Section".to_string(),
+      "Line".to_string(),
+      statement.period_label.clone(),
+      statement.previous_label.clone(),
+      "Change %".to_string(),
+    ];
+    if monthly {
+      header.push(statement.year_ago_label.clone());
+      header.push("Change %".to_string());
+    }
+    write_record(&mut writer, header)?;
+
+    for section in &statement.sections {
+      for line in &section.lines {
+        write_record(
+          &mut writer,
+          row(&section.title, &line.label, &line.amounts, monthly),
+        )?;
+      }
+      write_record(
+        &mut writer,
+        row(&section.title, "Total", &section.total, monthly),
+      )?;
+    }
+    write_record(
+      &mut writer,
+      row(&statement.result_label, "", &statement.result, monthly),
+    )?;
+
+    writer
+      .into_inner()
+      .map_err(|e| FinancialsError::Export(e.to_string()))
+  }
+}
+
+fn row(section: &str, label: &str, amounts: &Comparison, monthly: bool) -> Vec<String> {
+  let mut row = vec![
+    section.to_string(),
+    label.to_string(),
+    amount(amounts.current),
+    amount(amounts.previous),
+    change(amounts.previous_change),
+  ];
+  if monthly {
+    row.push(amount(amounts.year_ago));
+    row.push(change(amounts.year_ago_change));
+  }
+  row
+}
+
+fn write_record(
+  writer: &mut csv::Writer<Vec<u8>>,
+  record: Vec<String>,
+) -> Result<(), FinancialsError> {
+  writer
+    .write_record(record)
+    .map_err(|e| FinancialsError::Export(e.to_string()))
+}
+
+fn amount(value: Decimal) -> String {
+  format!("{:.2}", value)
+}
+
+fn change(value: Option<Decimal>) -> String {
+  value.map(|v| format!("{:.1}", v)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::financials::{Posting, StatementKind, StatementPeriod, StatementSectionKind};
+  use chrono::NaiveDate;
+  use rust_decimal_macros::dec;
+
+  #[test]
+  fn test_writes_lines_totals_and_result() {
+    let postings = vec![
+      Posting {
+        date: NaiveDate::from_ymd_opt(2026, 3, 10).unwrap(),
+        section: StatementSectionKind::Revenue,
+        label: "Acme, Inc".to_string(),
+        amount: dec!(1000),
+      },
+      Posting {
+        date: NaiveDate::from_ymd_opt(2026, 2, 10).unwrap(),
+        section: StatementSectionKind::Revenue,
+        label: "Acme, Inc".to_string(),
+        amount: dec!(500),
+      },
+    ];
+    let statement = FinancialStatement::build(
+      StatementKind::ProfitAndLoss,
+      StatementPeriod::month(2026, 3).unwrap(),
+      "EUR",
+      &postings,
+      0,
+    );
+
+    let csv = String::from_utf8(StatementCsvExporter::new().write(&statement).unwrap()).unwrap();
+    let rows: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(
+      rows[0],
+      "Section,Line,03.2026,02.2026,Change %,03.2025,Change %"
+    );
+    assert_eq!(rows[1], "Revenue,\"Acme, Inc\",1000.00,500.00,100.0,0.00,");
+    assert_eq!(rows[2], "Revenue,Total,1000.00,500.00,100.0,0.00,");
+    assert_eq!(
+      *rows.last().unwrap(),
+      "Net profit,,1000.00,500.00,100.0,0.00,"
+    );
+  }
+}
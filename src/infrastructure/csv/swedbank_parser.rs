@@ -3,7 +3,9 @@ use rust_decimal::Decimal;
 use std::str::FromStr;
 
 use crate::domain::report::{
-  entities::ParsedTransaction, errors::ReportError, ports::BankStatementParser,
+  entities::{ParsedStatement, ParsedTransaction},
+  errors::ReportError,
+  ports::BankStatementParser,
   value_objects::TransactionDirection,
 };
 
@@ -18,7 +20,8 @@ use crate::domain::report::{
 ///   4: Counterparty name
 ///   5: Counterparty bank
 ///   6: Empty
-///   7: Direction (D/C)
+///   7: Direction (D/C), or the row type of a balance row: AS for the
+///      opening balance, LS for the closing balance, K2 for turnover
 ///   8: Amount (comma decimal, e.g. "-1300,00")
 ///   9: Reference number
 ///  10: Archive ID
@@ -26,6 +29,9 @@ use crate::domain::report::{
 ///  12: Service fee
 ///  13: Currency
 ///  14: Registry code
+///
+/// Balance rows carry the balance in the amount column and are dated on the
+/// first and last day of the statement.
 #[derive(Default)]
 pub struct SwedbankCsvParser;
 
@@ -36,7 +42,7 @@ impl SwedbankCsvParser {
 }
 
 impl BankStatementParser for SwedbankCsvParser {
-  fn parse(&self, csv_content: &[u8]) -> Result<Vec<ParsedTransaction>, ReportError> {
+  fn parse(&self, csv_content: &[u8]) -> Result<ParsedStatement, ReportError> {
     // Try to decode as Windows-1252 first, fall back to UTF-8
     let content = {
       let (decoded, _, had_errors) = encoding_rs::WINDOWS_1252.decode(csv_content);
@@ -53,7 +59,7 @@ impl BankStatementParser for SwedbankCsvParser {
       .flexible(true)
       .from_reader(content.as_bytes());
 
-    let mut transactions = Vec::new();
+    let mut statement = ParsedStatement::default();
 
     for (idx, result) in reader.records().enumerate() {
       let record = result.map_err(|e| ReportError::CsvParse(format!("Row {}: {}", idx + 2, e)))?;
@@ -78,15 +84,6 @@ impl BankStatementParser for SwedbankCsvParser {
         ))
       })?;
 
-      let direction_str = unquote(record.get(7).unwrap_or(""));
-      let direction = TransactionDirection::try_from(direction_str.as_str()).map_err(|_| {
-        ReportError::CsvParse(format!(
-          "Row {}: invalid direction '{}'",
-          idx + 2,
-          direction_str
-        ))
-      })?;
-
       let amount_str = unquote(record.get(8).unwrap_or("0")).replace(',', ".");
       let amount = Decimal::from_str(&amount_str).map_err(|e| {
         ReportError::CsvParse(format!(
@@ -97,14 +94,39 @@ impl BankStatementParser for SwedbankCsvParser {
         ))
       })?;
 
+      let direction_str = unquote(record.get(7).unwrap_or(""));
+      match direction_str.to_uppercase().as_str() {
+        "AS" => {
+          statement.opening_balance = Some(amount);
+          statement.period_start = Some(date);
+          continue;
+        }
+        "LS" => {
+          statement.closing_balance = Some(amount);
+          statement.period_end = Some(date);
+          continue;
+        }
+        "K2" => continue,
+        _ => {}
+      }
+      let direction = TransactionDirection::try_from(direction_str.as_str()).map_err(|_| {
+        ReportError::CsvParse(format!(
+          "Row {}: invalid direction '{}'",
+          idx + 2,
+          direction_str
+        ))
+      })?;
+
       let counterparty_name = non_empty(record.get(4));
       let counterparty_account = non_empty(record.get(3));
       let reference_number = non_empty(record.get(9));
       let description = non_empty(record.get(11));
       let currency = unquote(record.get(13).unwrap_or("EUR"));
       let registry_code = non_empty(record.get(14));
+      let document_number = non_empty(record.get(1));
+      let archive_id = non_empty(record.get(10));
 
-      transactions.push(ParsedTransaction {
+      statement.transactions.push(ParsedTransaction {
         row_number: (idx + 2) as i32,
         client_account,
         date,
@@ -120,16 +142,18 @@ impl BankStatementParser for SwedbankCsvParser {
           currency
         },
         registry_code,
+        document_number,
+        archive_id,
       });
     }
 
-    if transactions.is_empty() {
+    if statement.transactions.is_empty() {
       return Err(ReportError::CsvParse(
         "No transactions found in CSV file".to_string(),
       ));
     }
 
-    Ok(transactions)
+    Ok(statement)
   }
 }
 
@@ -162,7 +186,7 @@ mod tests {
       "\"EE123456789\";\"001\";\"15-03-2026\";\"EE987654321\";\"Acme Corp\";\"HABAEE2X\";\"\";\"D\";\"-1300,00\";\"12345\";\"\";\"Office rent\";\"\";\"EUR\";\"12345678\"",
     ]);
     let parser = SwedbankCsvParser::new();
-    let txs = parser.parse(&csv).unwrap();
+    let txs = parser.parse(&csv).unwrap().transactions;
 
     assert_eq!(txs.len(), 1);
     let tx = &txs[0];
@@ -183,7 +207,7 @@ mod tests {
       "\"EE123456789\";\"002\";\"16-03-2026\";\"EE111222333\";\"Client Ltd\";\"HABAEE2X\";\"\";\"C\";\"5000,50\";\"\";\"\";\"\";\"Payment received\";\"\";\"EUR\";\"\"",
     ]);
     let parser = SwedbankCsvParser::new();
-    let txs = parser.parse(&csv).unwrap();
+    let txs = parser.parse(&csv).unwrap().transactions;
 
     assert_eq!(txs.len(), 1);
     assert_eq!(txs[0].direction, TransactionDirection::Credit);
@@ -197,7 +221,7 @@ mod tests {
       "\"EE123456789\";\"002\";\"16-03-2026\";\"EE111222333\";\"Client Ltd\";\"HABAEE2X\";\"\";\"C\";\"5000,50\";\"\";\"\";\"Income\";\"\";\"EUR\";\"\"",
     ]);
     let parser = SwedbankCsvParser::new();
-    let txs = parser.parse(&csv).unwrap();
+    let txs = parser.parse(&csv).unwrap().transactions;
     assert_eq!(txs.len(), 2);
   }
 
@@ -207,7 +231,7 @@ mod tests {
       "\"EE123456789\";\"003\";\"17-03-2026\";\"US999888777\";\"US Vendor\";\"\";\"\";\"D\";\"-250,00\";\"\";\"\";\"USD payment\";\"\";\"USD\";\"\"",
     ]);
     let parser = SwedbankCsvParser::new();
-    let txs = parser.parse(&csv).unwrap();
+    let txs = parser.parse(&csv).unwrap().transactions;
 
     assert_eq!(txs.len(), 1);
     assert_eq!(txs[0].currency, "USD");
@@ -243,7 +267,7 @@ mod tests {
     let csv = format!("{}\n{}\n{}", header, short_row, valid_row).into_bytes();
 
     let parser = SwedbankCsvParser::new();
-    let txs = parser.parse(&csv).unwrap();
+    let txs = parser.parse(&csv).unwrap().transactions;
     assert_eq!(txs.len(), 1);
   }
 
@@ -253,7 +277,29 @@ mod tests {
       "\"EE123456789\";\"001\";\"15-03-2026\";\"\";\"\";\"\";\"\";\"D\";\"-1300,50\";\"\";\"\";\"\";\"\";\"EUR\";\"\"",
     ]);
     let parser = SwedbankCsvParser::new();
-    let txs = parser.parse(&csv).unwrap();
+    let txs = parser.parse(&csv).unwrap().transactions;
     assert_eq!(txs[0].amount, dec!(-1300.50));
   }
+
+  #[test]
+  fn test_parse_balance_rows() {
+    let csv = make_csv(&[
+      "\"EE123456789\";\"\";\"01-03-2026\";\"\";\"\";\"\";\"\";\"AS\";\"1000,00\";\"\";\"\";\"Opening balance\";\"\";\"EUR\";\"\"",
+      "\"EE123456789\";\"001\";\"15-03-2026\";\"EE987654321\";\"Acme Corp\";\"\";\"\";\"D\";\"-1300,00\";\"\";\"2026031500017\";\"Rent\";\"\";\"EUR\";\"\"",
+      "\"EE123456789\";\"\";\"31-03-2026\";\"\";\"\";\"\";\"\";\"K2\";\"1300,00\";\"\";\"\";\"Turnover\";\"\";\"EUR\";\"\"",
+      "\"EE123456789\";\"\";\"31-03-2026\";\"\";\"\";\"\";\"\";\"LS\";\"-300,00\";\"\";\"\";\"Closing balance\";\"\";\"EUR\";\"\"",
+    ]);
+    let parser = SwedbankCsvParser::new();
+    let statement = parser.parse(&csv).unwrap();
+
+    assert_eq!(statement.transactions.len(), 1);
+    assert_eq!(statement.opening_balance, Some(dec!(1000.00)));
+    assert_eq!(statement.closing_balance, Some(dec!(-300.00)));
+    assert_eq!(statement.period_start, NaiveDate::from_ymd_opt(2026, 3, 1));
+    assert_eq!(statement.period_end, NaiveDate::from_ymd_opt(2026, 3, 31));
+
+    let tx = &statement.transactions[0];
+    assert_eq!(tx.document_number.as_deref(), Some("001"));
+    assert_eq!(tx.archive_id.as_deref(), Some("2026031500017"));
+  }
 }
@@ -0,0 +1,238 @@
+use aes_gcm::{
+  Aes256Gcm, KeyInit, Nonce,
+  aead::{Aead, Payload},
+};
+use async_trait::async_trait;
+use base64::{Engine as _, engine::general_purpose};
+use rand::RngCore;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+use crate::domain::document::{DataKeyRepository, DocumentCipher, DocumentError};
+use crate::infrastructure::security::AesTokenEncryption;
+
+/// Marks sealed content, followed by a format version
+const MAGIC: &[u8] = b"TBENC\x01";
+const COMPANY_ID_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+/// Magic and company ID, authenticated along with the content
+const HEADER_LEN: usize = MAGIC.len() + COMPANY_ID_LEN;
+
+/// AES-256-GCM envelope encryption of documents
+///
+/// Every company has a random 256-bit data key, stored wrapped by the
+/// instance's master key (the token encryption key). Sealed content is laid
+/// out as `magic | company ID | nonce | ciphertext`, so it can be opened
+/// without knowing whose file it is. Unwrapped keys are cached in memory.
+pub struct EnvelopeCipher {
+  master: Arc<AesTokenEncryption>,
+  keys: Arc<dyn DataKeyRepository>,
+  cache: Mutex<HashMap<Uuid, Aes256Gcm>>,
+}
+
+impl EnvelopeCipher {
+  pub fn new(master: Arc<AesTokenEncryption>, keys: Arc<dyn DataKeyRepository>) -> Self {
+    Self {
+      master,
+      keys,
+      cache: Mutex::new(HashMap::new()),
+    }
+  }
+
+  /// The company's data key; a company without one gets one when `create`
+  async fn data_key(&self, company_id: Uuid, create: bool) -> Result<Aes256Gcm, DocumentError> {
+    if let Some(key) = self.cache.lock().unwrap().get(&company_id) {
+      return Ok(key.clone());
+    }
+
+    let wrapped = match self.keys.find(company_id).await? {
+      Some(wrapped) => wrapped,
+      None if create => {
+        let mut key = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut key);
+        let wrapped = self
+          .master
+          .encrypt(&general_purpose::STANDARD.encode(key))
+          .map_err(|e| DocumentError::Encryption(e.to_string()))?;
+        // Another request may have created the key meanwhile; its key wins
+        self.keys.create_if_absent(company_id, wrapped).await?
+      }
+      None => {
+        return Err(DocumentError::Encryption(format!(
+          "No data key for company {}",
+          company_id
+        )));
+      }
+    };
+
+    let key_bytes = self
+      .master
+      .decrypt(&wrapped)
+      .ok()
+      .and_then(|encoded| general_purpose::STANDARD.decode(encoded).ok())
+      .filter(|bytes| bytes.len() == 32)
+      .ok_or_else(|| {
+        DocumentError::Encryption(format!(
+          "Data key of company {} cannot be unwrapped with the master key",
+          company_id
+        ))
+      })?;
+    let key = Aes256Gcm::new_from_slice(&key_bytes)
+      .map_err(|e| DocumentError::Encryption(e.to_string()))?;
+
+    self.cache.lock().unwrap().insert(company_id, key.clone());
+    Ok(key)
+  }
+}
+
+#[async_trait]
+impl DocumentCipher for EnvelopeCipher {
+  async fn seal(&self, company_id: Uuid, content: &[u8]) -> Result<Vec<u8>, DocumentError> {
+    let key = self.data_key(company_id, true).await?;
+
+    let mut sealed = Vec::with_capacity(HEADER_LEN + NONCE_LEN + content.len() + 16);
+    sealed.extend_from_slice(MAGIC);
+    sealed.extend_from_slice(company_id.as_bytes());
+
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let ciphertext = key
+      .encrypt(
+        Nonce::from_slice(&nonce),
+        Payload {
+          msg: content,
+          aad: &sealed[..HEADER_LEN],
+        },
+      )
+      .map_err(|e| DocumentError::Encryption(format!("Encryption failed: {}", e)))?;
+
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+  }
+
+  async fn open(&self, stored: Vec<u8>) -> Result<Vec<u8>, DocumentError> {
+    if !self.is_sealed(&stored) {
+      return Ok(stored);
+    }
+    if stored.len() < HEADER_LEN + NONCE_LEN {
+      return Err(DocumentError::Encryption(
+        "Encrypted file is truncated".to_string(),
+      ));
+    }
+
+    let (header, rest) = stored.split_at(HEADER_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let company_id = Uuid::from_slice(&header[MAGIC.len()..])
+      .map_err(|e| DocumentError::Encryption(e.to_string()))?;
+
+    let key = self.data_key(company_id, false).await?;
+    key
+      .decrypt(
+        Nonce::from_slice(nonce),
+        Payload {
+          msg: ciphertext,
+          aad: header,
+        },
+      )
+      .map_err(|_| DocumentError::Encryption("Decryption failed".to_string()))
+  }
+
+  fn is_sealed(&self, stored: &[u8]) -> bool {
+    stored.starts_with(MAGIC)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[derive(Default)]
+  struct InMemoryKeys {
+    keys: Mutex<HashMap<Uuid, String>>,
+  }
+
+  #[async_trait]
+  impl DataKeyRepository for InMemoryKeys {
+    async fn find(&self, company_id: Uuid) -> Result<Option<String>, DocumentError> {
+      Ok(self.keys.lock().unwrap().get(&company_id).cloned())
+    }
+
+    async fn create_if_absent(
+      &self,
+      company_id: Uuid,
+      wrapped_key: String,
+    ) -> Result<String, DocumentError> {
+      let mut keys = self.keys.lock().unwrap();
+      Ok(keys.entry(company_id).or_insert(wrapped_key).clone())
+    }
+  }
+
+  fn master(byte: u8) -> Arc<AesTokenEncryption> {
+    let key = general_purpose::STANDARD.encode([byte; 32]);
+    Arc::new(AesTokenEncryption::new(&key).unwrap())
+  }
+
+  #[tokio::test]
+  async fn test_seal_and_open() {
+    let keys = Arc::new(InMemoryKeys::default());
+    let cipher = EnvelopeCipher::new(master(7), keys.clone());
+    let company_id = Uuid::new_v4();
+
+    let sealed = cipher.seal(company_id, b"%PDF-1.4 bill").await.unwrap();
+    assert!(cipher.is_sealed(&sealed));
+    assert!(!sealed.windows(4).any(|w| w == b"%PDF"));
+    assert_eq!(
+      cipher.open(sealed.clone()).await.unwrap(),
+      b"%PDF-1.4 bill".to_vec()
+    );
+
+    // The wrapped key is stored once and opens the file in a new process
+    assert_eq!(keys.keys.lock().unwrap().len(), 1);
+    cipher.seal(company_id, b"second").await.unwrap();
+    assert_eq!(keys.keys.lock().unwrap().len(), 1);
+    let restarted = EnvelopeCipher::new(master(7), keys.clone());
+    assert_eq!(
+      restarted.open(sealed.clone()).await.unwrap(),
+      b"%PDF-1.4 bill".to_vec()
+    );
+
+    // Other master key
+    let other = EnvelopeCipher::new(master(8), keys);
+    assert!(matches!(
+      other.open(sealed).await,
+      Err(DocumentError::Encryption(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_plain_content_is_passed_through() {
+    let cipher = EnvelopeCipher::new(master(7), Arc::new(InMemoryKeys::default()));
+
+    assert!(!cipher.is_sealed(b"%PDF-1.4"));
+    assert_eq!(
+      cipher.open(b"%PDF-1.4".to_vec()).await.unwrap(),
+      b"%PDF-1.4".to_vec()
+    );
+  }
+
+  #[tokio::test]
+  async fn test_tampered_content_is_rejected() {
+    let cipher = EnvelopeCipher::new(master(7), Arc::new(InMemoryKeys::default()));
+    let (company_a, company_b) = (Uuid::new_v4(), Uuid::new_v4());
+    cipher.seal(company_b, b"other").await.unwrap();
+
+    let mut sealed = cipher.seal(company_a, b"receipt").await.unwrap();
+    let last = sealed.len() - 1;
+    sealed[last] ^= 1;
+    assert!(cipher.open(sealed).await.is_err());
+
+    // Moving a file to another company's header fails authentication
+    let mut sealed = cipher.seal(company_a, b"receipt").await.unwrap();
+    sealed[MAGIC.len()..HEADER_LEN].copy_from_slice(company_b.as_bytes());
+    assert!(cipher.open(sealed).await.is_err());
+
+    assert!(cipher.open(MAGIC.to_vec()).await.is_err());
+  }
+}
@@ -0,0 +1,214 @@
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use uuid::Uuid;
+
+use crate::domain::document::{DocumentBlobStore, DocumentError};
+
+/// Subdirectory of the root holding decrypted copies while they are read
+const PLAIN_DIR: &str = ".plain";
+
+/// Document content kept as files below a root directory of the server
+///
+/// Files are written to a temporary file and renamed into place, so a crash
+/// never leaves a half-written document behind. Decrypted copies go to a
+/// directory below the root that only the server's user can open.
+pub struct FsDocumentBlobStore {
+  root: PathBuf,
+}
+
+impl FsDocumentBlobStore {
+  pub fn new(root: impl Into<PathBuf>) -> Self {
+    Self { root: root.into() }
+  }
+
+  /// Path of `key`; keys are generated by the document service, but a key
+  /// leaving the root is refused all the same
+  fn resolve(&self, key: &str) -> Result<PathBuf, DocumentError> {
+    let relative = Path::new(key);
+    let safe = relative
+      .components()
+      .all(|c| matches!(c, std::path::Component::Normal(_)));
+    if key.is_empty() || !safe {
+      return Err(DocumentError::Validation(format!(
+        "Invalid document key: {}",
+        key
+      )));
+    }
+    Ok(self.root.join(relative))
+  }
+}
+
+#[async_trait]
+impl DocumentBlobStore for FsDocumentBlobStore {
+  async fn write(&self, key: &str, content: &[u8]) -> Result<(), DocumentError> {
+    let path = self.resolve(key)?;
+    let dir = path.parent().unwrap_or(&self.root);
+    tokio::fs::create_dir_all(dir)
+      .await
+      .map_err(|e| file_error("create", dir, e))?;
+
+    let temp = dir.join(format!(".{}.tmp", Uuid::new_v4()));
+    let written = async {
+      let mut file = tokio::fs::File::create(&temp).await?;
+      file.write_all(content).await?;
+      file.sync_all().await?;
+      tokio::fs::rename(&temp, &path).await
+    }
+    .await;
+
+    if let Err(e) = written {
+      let _ = tokio::fs::remove_file(&temp).await;
+      return Err(file_error("write", &path, e));
+    }
+    Ok(())
+  }
+
+  async fn read(&self, key: &str) -> Result<Option<Vec<u8>>, DocumentError> {
+    let path = self.resolve(key)?;
+    match tokio::fs::read(&path).await {
+      Ok(content) => Ok(Some(content)),
+      Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+      Err(e) => Err(file_error("read", &path, e)),
+    }
+  }
+
+  async fn delete(&self, key: &str) -> Result<(), DocumentError> {
+    let path = self.resolve(key)?;
+    match tokio::fs::remove_file(&path).await {
+      Ok(()) => Ok(()),
+      Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+      Err(e) => Err(file_error("delete", &path, e)),
+    }
+  }
+
+  fn path(&self, key: &str) -> String {
+    self.root.join(key).to_string_lossy().to_string()
+  }
+
+  async fn write_plain_copy(&self, name: &str, content: &[u8]) -> Result<String, DocumentError> {
+    let dir = self.root.join(PLAIN_DIR);
+    tokio::fs::create_dir_all(&dir)
+      .await
+      .map_err(|e| file_error("create", &dir, e))?;
+    #[cfg(unix)]
+    {
+      use std::os::unix::fs::PermissionsExt;
+      tokio::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700))
+        .await
+        .map_err(|e| file_error("protect", &dir, e))?;
+    }
+
+    let name: String = name
+      .chars()
+      .map(|c| if matches!(c, '/' | '\\') { '_' } else { c })
+      .collect();
+    let path = dir.join(format!("{}-{}", Uuid::new_v4(), name));
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    let written = async {
+      let mut file = options.open(&path).await?;
+      file.write_all(content).await?;
+      file.flush().await
+    }
+    .await;
+    if let Err(e) = written {
+      let _ = tokio::fs::remove_file(&path).await;
+      return Err(file_error("write", &path, e));
+    }
+    Ok(path.to_string_lossy().to_string())
+  }
+}
+
+fn file_error(action: &str, path: &Path, error: std::io::Error) -> DocumentError {
+  DocumentError::FileError(format!(
+    "Failed to {} {}: {}",
+    action,
+    path.display(),
+    error
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn store() -> (FsDocumentBlobStore, PathBuf) {
+    let root = std::env::temp_dir().join(format!("document_store_test_{}", Uuid::new_v4()));
+    (FsDocumentBlobStore::new(&root), root)
+  }
+
+  #[tokio::test]
+  async fn test_write_read_and_delete() {
+    let (store, root) = store();
+    let key = "company/ab/abcdef.pdf";
+
+    assert_eq!(store.read(key).await.unwrap(), None);
+    store.write(key, b"%PDF first").await.unwrap();
+    store.write(key, b"%PDF second").await.unwrap();
+    assert_eq!(
+      store.read(key).await.unwrap(),
+      Some(b"%PDF second".to_vec())
+    );
+    assert_eq!(
+      std::fs::read(store.path(key)).unwrap(),
+      b"%PDF second".to_vec()
+    );
+
+    let leftovers = std::fs::read_dir(root.join("company/ab"))
+      .unwrap()
+      .filter(|entry| {
+        entry
+          .as_ref()
+          .unwrap()
+          .file_name()
+          .to_string_lossy()
+          .ends_with(".tmp")
+      })
+      .count();
+    assert_eq!(leftovers, 0);
+
+    store.delete(key).await.unwrap();
+    store.delete(key).await.unwrap();
+    assert_eq!(store.read(key).await.unwrap(), None);
+
+    std::fs::remove_dir_all(root).unwrap();
+  }
+
+  #[cfg(unix)]
+  #[tokio::test]
+  async fn test_plain_copies_are_private_to_the_server() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let (store, root) = store();
+    let copy = store
+      .write_plain_copy("INV-1.pdf", b"%PDF plain")
+      .await
+      .unwrap();
+
+    assert!(copy.starts_with(&*root.join(PLAIN_DIR).to_string_lossy()));
+    assert!(copy.ends_with("INV-1.pdf"));
+    assert_eq!(std::fs::read(&copy).unwrap(), b"%PDF plain".to_vec());
+    let mode = |path: &Path| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+    assert_eq!(mode(Path::new(&copy)), 0o600);
+    assert_eq!(mode(&root.join(PLAIN_DIR)), 0o700);
+
+    std::fs::remove_dir_all(root).unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_keys_outside_the_root_are_rejected() {
+    let (store, _) = store();
+
+    for key in ["../escape.pdf", "/etc/passwd", "a/../../b", ""] {
+      assert!(matches!(
+        store.read(key).await,
+        Err(DocumentError::Validation(_))
+      ));
+    }
+  }
+}
@@ -0,0 +1,5 @@
+mod envelope_cipher;
+mod fs_blob_store;
+
+pub use envelope_cipher::EnvelopeCipher;
+pub use fs_blob_store::FsDocumentBlobStore;
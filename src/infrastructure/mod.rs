@@ -1,6 +1,9 @@
+pub mod archive;
 pub mod cloud;
 pub mod config;
 pub mod csv;
+pub mod documents;
 pub mod pdf;
 pub mod persistence;
+pub mod saft;
 pub mod security;
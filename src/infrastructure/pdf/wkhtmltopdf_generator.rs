@@ -1,16 +1,25 @@
 use async_trait::async_trait;
 use std::path::PathBuf;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use uuid::Uuid;
 
 use crate::application::invoice::get_invoice_details::InvoiceDetailsResponse;
+use crate::domain::financials::{FinancialsError, HtmlPdfConverter};
 use crate::domain::invoice::errors::InvoiceError;
 use crate::domain::invoice::ports::PdfGenerator;
 
+/// Renders pages to PDF with wkhtmltopdf
+///
+/// Invoice pages may come from company-authored templates, so pages are
+/// rendered with JavaScript disabled and no local file access beyond the
+/// assets directory.
 pub struct WkHtmlToPdfGenerator {
   pdf_output_dir: PathBuf,
   wkhtmltopdf_path: String,
   server_base_url: String,
+  assets_dir: PathBuf,
 }
 
 impl WkHtmlToPdfGenerator {
@@ -18,6 +27,7 @@ impl WkHtmlToPdfGenerator {
     pdf_output_dir: PathBuf,
     wkhtmltopdf_path: Option<String>,
     server_base_url: String,
+    assets_dir: PathBuf,
   ) -> Self {
     // Create output directory if doesn't exist
     std::fs::create_dir_all(&pdf_output_dir).ok();
@@ -28,9 +38,35 @@ impl WkHtmlToPdfGenerator {
       pdf_output_dir,
       wkhtmltopdf_path,
       server_base_url,
+      assets_dir,
     }
   }
 
+  /// Page layout and sandboxing options shared by all conversions
+  fn page_args(&self) -> Vec<String> {
+    let mut args: Vec<String> = [
+      "--page-size",
+      "A4",
+      "--margin-top",
+      "10mm",
+      "--margin-bottom",
+      "10mm",
+      "--margin-left",
+      "10mm",
+      "--margin-right",
+      "10mm",
+      "--disable-javascript",
+      "--disable-local-file-access",
+      "--quiet", // Suppress verbose output
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
+    args.push("--allow".to_string());
+    args.push(self.assets_dir.to_string_lossy().to_string());
+    args
+  }
+
   async fn verify_wkhtmltopdf_installed(&self) -> Result<(), InvoiceError> {
     let output = Command::new(&self.wkhtmltopdf_path)
       .arg("--version")
@@ -72,21 +108,8 @@ impl PdfGenerator for WkHtmlToPdfGenerator {
     let output_path = self.pdf_output_dir.join(&pdf_filename);
 
     let output = Command::new(&self.wkhtmltopdf_path)
-      .args([
-        "--page-size",
-        "A4",
-        "--margin-top",
-        "10mm",
-        "--margin-bottom",
-        "10mm",
-        "--margin-left",
-        "10mm",
-        "--margin-right",
-        "10mm",
-        "--quiet", // Suppress verbose output
-        &invoice_url,
-        output_path.to_str().unwrap(),
-      ])
+      .args(self.page_args())
+      .args([invoice_url.as_str(), output_path.to_str().unwrap()])
       .output()
       .await
       .map_err(|e| {
@@ -112,3 +135,44 @@ impl PdfGenerator for WkHtmlToPdfGenerator {
     Ok(output_path.to_string_lossy().to_string())
   }
 }
+
+#[async_trait]
+impl HtmlPdfConverter for WkHtmlToPdfGenerator {
+  async fn convert(&self, html: &str) -> Result<Vec<u8>, FinancialsError> {
+    self
+      .verify_wkhtmltopdf_installed()
+      .await
+      .map_err(|e| FinancialsError::Export(e.to_string()))?;
+
+    // Read the page from stdin and write the PDF to stdout
+    let mut child = Command::new(&self.wkhtmltopdf_path)
+      .args(self.page_args())
+      .args(["-", "-"])
+      .stdin(Stdio::piped())
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped())
+      .spawn()
+      .map_err(|e| FinancialsError::Export(format!("wkhtmltopdf execution failed: {}", e)))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+      stdin.write_all(html.as_bytes()).await.map_err(|e| {
+        FinancialsError::Export(format!("Failed to pass page to wkhtmltopdf: {}", e))
+      })?;
+    }
+
+    let output = child
+      .wait_with_output()
+      .await
+      .map_err(|e| FinancialsError::Export(format!("wkhtmltopdf execution failed: {}", e)))?;
+
+    if !output.status.success() || output.stdout.is_empty() {
+      let stderr = String::from_utf8_lossy(&output.stderr);
+      return Err(FinancialsError::Export(format!(
+        "wkhtmltopdf failed: {}",
+        stderr
+      )));
+    }
+
+    Ok(output.stdout)
+  }
+}
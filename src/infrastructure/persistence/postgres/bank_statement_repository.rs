@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::report::{BankStatement, BankStatementRepository, ReportError};
+
+#[derive(Debug, FromRow)]
+struct BankStatementRow {
+  id: Uuid,
+  report_id: Uuid,
+  bank_account_id: Option<Uuid>,
+  bank_account_iban: String,
+  total_incoming: Decimal,
+  total_outgoing: Decimal,
+  transaction_count: i32,
+  opening_balance: Option<Decimal>,
+  closing_balance: Option<Decimal>,
+  period_start: NaiveDate,
+  period_end: NaiveDate,
+  duplicate_count: i32,
+  file_path: Option<String>,
+  file_name: Option<String>,
+  created_at: DateTime<Utc>,
+}
+
+impl From<BankStatementRow> for BankStatement {
+  fn from(row: BankStatementRow) -> Self {
+    BankStatement {
+      id: row.id,
+      report_id: row.report_id,
+      bank_account_id: row.bank_account_id,
+      bank_account_iban: row.bank_account_iban,
+      total_incoming: row.total_incoming,
+      total_outgoing: row.total_outgoing,
+      transaction_count: row.transaction_count,
+      opening_balance: row.opening_balance,
+      closing_balance: row.closing_balance,
+      period_start: row.period_start,
+      period_end: row.period_end,
+      duplicate_count: row.duplicate_count,
+      file_path: row.file_path,
+      file_name: row.file_name,
+      created_at: row.created_at,
+    }
+  }
+}
+
+pub struct PostgresBankStatementRepository {
+  pool: PgPool,
+}
+
+impl PostgresBankStatementRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl BankStatementRepository for PostgresBankStatementRepository {
+  async fn create(&self, statement: BankStatement) -> Result<BankStatement, ReportError> {
+    let row = sqlx::query_as::<_, BankStatementRow>(
+      r#"
+            INSERT INTO bank_statements (id, report_id, bank_account_id, bank_account_iban, total_incoming, total_outgoing, transaction_count, opening_balance, closing_balance, period_start, period_end, duplicate_count, file_path, file_name, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            RETURNING id, report_id, bank_account_id, bank_account_iban, total_incoming, total_outgoing, transaction_count, opening_balance, closing_balance, period_start, period_end, duplicate_count, file_path, file_name, created_at
+            "#,
+    )
+    .bind(statement.id)
+    .bind(statement.report_id)
+    .bind(statement.bank_account_id)
+    .bind(&statement.bank_account_iban)
+    .bind(statement.total_incoming)
+    .bind(statement.total_outgoing)
+    .bind(statement.transaction_count)
+    .bind(statement.opening_balance)
+    .bind(statement.closing_balance)
+    .bind(statement.period_start)
+    .bind(statement.period_end)
+    .bind(statement.duplicate_count)
+    .bind(&statement.file_path)
+    .bind(&statement.file_name)
+    .bind(statement.created_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    Ok(row.into())
+  }
+
+  async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<BankStatement>, ReportError> {
+    let rows = sqlx::query_as::<_, BankStatementRow>(
+      r#"
+            SELECT id, report_id, bank_account_id, bank_account_iban, total_incoming, total_outgoing, transaction_count, opening_balance, closing_balance, period_start, period_end, duplicate_count, file_path, file_name, created_at
+            FROM bank_statements WHERE report_id = $1 ORDER BY bank_account_iban
+            "#,
+    )
+    .bind(report_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into()).collect())
+  }
+
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<BankStatement>, ReportError> {
+    let rows = sqlx::query_as::<_, BankStatementRow>(
+      r#"
+            SELECT s.id, s.report_id, s.bank_account_id, s.bank_account_iban, s.total_incoming, s.total_outgoing, s.transaction_count, s.opening_balance, s.closing_balance, s.period_start, s.period_end, s.duplicate_count, s.file_path, s.file_name, s.created_at
+            FROM bank_statements s
+            JOIN monthly_reports r ON r.id = s.report_id
+            WHERE r.company_id = $1
+            ORDER BY s.bank_account_iban, s.period_start
+            "#,
+    )
+    .bind(company_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into()).collect())
+  }
+}
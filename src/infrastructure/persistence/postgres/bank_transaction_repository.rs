@@ -2,10 +2,12 @@ use async_trait::async_trait;
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use sqlx::{FromRow, PgPool};
+use std::collections::HashMap;
 use uuid::Uuid;
 
+use super::match_allocation_repository::MatchAllocationRow;
 use crate::domain::report::{
-  BankTransaction, BankTransactionRepository, ReportError, TransactionDirection,
+  BankTransaction, BankTransactionRepository, MatchAllocation, ReportError, TransactionDirection,
 };
 
 #[derive(Debug, FromRow)]
@@ -22,9 +24,15 @@ struct BankTransactionRow {
   description: Option<String>,
   currency: String,
   registry_code: Option<String>,
-  matched_invoice_id: Option<Uuid>,
-  matched_received_invoice_id: Option<Uuid>,
+  statement_id: Option<Uuid>,
+  document_number: Option<String>,
+  archive_id: Option<String>,
   receipt_path: Option<String>,
+  category_id: Option<Uuid>,
+  rule_id: Option<Uuid>,
+  ignored: bool,
+  needs_review: bool,
+  vendor_id: Option<Uuid>,
 }
 
 impl TryFrom<BankTransactionRow> for BankTransaction {
@@ -44,13 +52,44 @@ impl TryFrom<BankTransactionRow> for BankTransaction {
       description: row.description,
       currency: row.currency,
       registry_code: row.registry_code,
-      matched_invoice_id: row.matched_invoice_id,
-      matched_received_invoice_id: row.matched_received_invoice_id,
+      statement_id: row.statement_id,
+      document_number: row.document_number,
+      archive_id: row.archive_id,
+      allocations: Vec::new(),
       receipt_path: row.receipt_path,
+      category_id: row.category_id,
+      rule_id: row.rule_id,
+      ignored: row.ignored,
+      needs_review: row.needs_review,
+      vendor_id: row.vendor_id,
     })
   }
 }
 
+/// Attach allocations to the transactions they belong to
+fn build_transactions(
+  rows: Vec<BankTransactionRow>,
+  allocation_rows: Vec<MatchAllocationRow>,
+) -> Result<Vec<BankTransaction>, ReportError> {
+  let mut allocations_by_tx: HashMap<Uuid, Vec<MatchAllocation>> = HashMap::new();
+  for row in allocation_rows {
+    let allocation = MatchAllocation::from(row);
+    allocations_by_tx
+      .entry(allocation.transaction_id)
+      .or_default()
+      .push(allocation);
+  }
+
+  rows
+    .into_iter()
+    .map(|row| {
+      let mut tx = BankTransaction::try_from(row)?;
+      tx.allocations = allocations_by_tx.remove(&tx.id).unwrap_or_default();
+      Ok(tx)
+    })
+    .collect()
+}
+
 pub struct PostgresBankTransactionRepository {
   pool: PgPool,
 }
@@ -71,8 +110,8 @@ impl BankTransactionRepository for PostgresBankTransactionRepository {
     for tx in &transactions {
       sqlx::query(
                 r#"
-                INSERT INTO bank_transactions (id, report_id, row_number, date, counterparty_name, counterparty_account, direction, amount, reference_number, description, currency, registry_code, created_at)
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+                INSERT INTO bank_transactions (id, report_id, row_number, date, counterparty_name, counterparty_account, direction, amount, reference_number, description, currency, registry_code, statement_id, document_number, archive_id, category_id, rule_id, ignored, needs_review, vendor_id, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
                 "#,
             )
             .bind(tx.id)
@@ -87,6 +126,14 @@ impl BankTransactionRepository for PostgresBankTransactionRepository {
             .bind(tx.description.as_deref())
             .bind(&tx.currency)
             .bind(tx.registry_code.as_deref())
+            .bind(tx.statement_id)
+            .bind(tx.document_number.as_deref())
+            .bind(tx.archive_id.as_deref())
+            .bind(tx.category_id)
+            .bind(tx.rule_id)
+            .bind(tx.ignored)
+            .bind(tx.needs_review)
+            .bind(tx.vendor_id)
             .bind(now)
             .execute(&self.pool)
             .await?;
@@ -97,7 +144,7 @@ impl BankTransactionRepository for PostgresBankTransactionRepository {
   async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<BankTransaction>, ReportError> {
     let rows = sqlx::query_as::<_, BankTransactionRow>(
             r#"
-            SELECT id, report_id, row_number, date, counterparty_name, counterparty_account, direction, amount, reference_number, description, currency, registry_code, matched_invoice_id, matched_received_invoice_id, receipt_path
+            SELECT id, report_id, row_number, date, counterparty_name, counterparty_account, direction, amount, reference_number, description, currency, registry_code, statement_id, document_number, archive_id, receipt_path, category_id, rule_id, ignored, needs_review, vendor_id
             FROM bank_transactions WHERE report_id = $1 ORDER BY row_number
             "#,
         )
@@ -105,13 +152,66 @@ impl BankTransactionRepository for PostgresBankTransactionRepository {
         .fetch_all(&self.pool)
         .await?;
 
-    rows.into_iter().map(|r| r.try_into()).collect()
+    let allocation_rows = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            SELECT a.id, a.transaction_id, a.invoice_id, a.received_invoice_id, a.amount, a.created_at
+            FROM match_allocations a
+            JOIN bank_transactions t ON t.id = a.transaction_id
+            WHERE t.report_id = $1
+            ORDER BY a.created_at
+            "#,
+    )
+    .bind(report_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    build_transactions(rows, allocation_rows)
+  }
+
+  async fn find_by_company_and_date_range(
+    &self,
+    company_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+  ) -> Result<Vec<BankTransaction>, ReportError> {
+    let rows = sqlx::query_as::<_, BankTransactionRow>(
+            r#"
+            SELECT t.id, t.report_id, t.row_number, t.date, t.counterparty_name, t.counterparty_account, t.direction, t.amount, t.reference_number, t.description, t.currency, t.registry_code, t.statement_id, t.document_number, t.archive_id, t.receipt_path, t.category_id, t.rule_id, t.ignored, t.needs_review, t.vendor_id
+            FROM bank_transactions t
+            JOIN monthly_reports r ON r.id = t.report_id
+            WHERE r.company_id = $1 AND t.date >= $2 AND t.date <= $3
+            ORDER BY t.date, t.row_number
+            "#,
+        )
+        .bind(company_id)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(&self.pool)
+        .await?;
+
+    let allocation_rows = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            SELECT a.id, a.transaction_id, a.invoice_id, a.received_invoice_id, a.amount, a.created_at
+            FROM match_allocations a
+            JOIN bank_transactions t ON t.id = a.transaction_id
+            JOIN monthly_reports r ON r.id = t.report_id
+            WHERE r.company_id = $1 AND t.date >= $2 AND t.date <= $3
+            ORDER BY a.created_at
+            "#,
+    )
+    .bind(company_id)
+    .bind(start_date)
+    .bind(end_date)
+    .fetch_all(&self.pool)
+    .await?;
+
+    build_transactions(rows, allocation_rows)
   }
 
   async fn find_by_id(&self, id: Uuid) -> Result<Option<BankTransaction>, ReportError> {
     let row = sqlx::query_as::<_, BankTransactionRow>(
             r#"
-            SELECT id, report_id, row_number, date, counterparty_name, counterparty_account, direction, amount, reference_number, description, currency, registry_code, matched_invoice_id, matched_received_invoice_id, receipt_path
+            SELECT id, report_id, row_number, date, counterparty_name, counterparty_account, direction, amount, reference_number, description, currency, registry_code, statement_id, document_number, archive_id, receipt_path, category_id, rule_id, ignored, needs_review, vendor_id
             FROM bank_transactions WHERE id = $1
             "#,
         )
@@ -119,64 +219,147 @@ impl BankTransactionRepository for PostgresBankTransactionRepository {
         .fetch_optional(&self.pool)
         .await?;
 
-    row.map(|r| r.try_into()).transpose()
+    let Some(row) = row else {
+      return Ok(None);
+    };
+
+    let allocation_rows = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            SELECT id, transaction_id, invoice_id, received_invoice_id, amount, created_at
+            FROM match_allocations WHERE transaction_id = $1 ORDER BY created_at
+            "#,
+    )
+    .bind(id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(build_transactions(vec![row], allocation_rows)?.pop())
   }
 
-  async fn update_match(
+  async fn update_receipt_path(
     &self,
     transaction_id: Uuid,
-    invoice_id: Option<Uuid>,
-    received_invoice_id: Option<Uuid>,
+    receipt_path: Option<String>,
   ) -> Result<(), ReportError> {
     sqlx::query(
       r#"
             UPDATE bank_transactions
-            SET matched_invoice_id = $2, matched_received_invoice_id = $3
+            SET receipt_path = $2
             WHERE id = $1
             "#,
     )
     .bind(transaction_id)
-    .bind(invoice_id)
-    .bind(received_invoice_id)
+    .bind(receipt_path)
     .execute(&self.pool)
     .await?;
     Ok(())
   }
 
-  async fn clear_match(&self, transaction_id: Uuid) -> Result<(), ReportError> {
+  async fn update_category(
+    &self,
+    transaction_id: Uuid,
+    category_id: Option<Uuid>,
+  ) -> Result<(), ReportError> {
     sqlx::query(
       r#"
             UPDATE bank_transactions
-            SET matched_invoice_id = NULL, matched_received_invoice_id = NULL
+            SET category_id = $2
             WHERE id = $1
             "#,
     )
     .bind(transaction_id)
+    .bind(category_id)
     .execute(&self.pool)
     .await?;
     Ok(())
   }
 
-  async fn update_receipt_path(
+  async fn count_by_category_id(&self, category_id: Uuid) -> Result<i64, ReportError> {
+    let count: i64 =
+      sqlx::query_scalar("SELECT COUNT(*) FROM bank_transactions WHERE category_id = $1")
+        .bind(category_id)
+        .fetch_one(&self.pool)
+        .await?;
+    Ok(count)
+  }
+
+  async fn update_rule_outcome(
     &self,
     transaction_id: Uuid,
-    receipt_path: Option<String>,
+    rule_id: Option<Uuid>,
+    ignored: bool,
+    needs_review: bool,
   ) -> Result<(), ReportError> {
     sqlx::query(
       r#"
             UPDATE bank_transactions
-            SET receipt_path = $2
+            SET rule_id = $2, ignored = $3, needs_review = $4
             WHERE id = $1
             "#,
     )
     .bind(transaction_id)
-    .bind(receipt_path)
+    .bind(rule_id)
+    .bind(ignored)
+    .bind(needs_review)
+    .execute(&self.pool)
+    .await?;
+    Ok(())
+  }
+
+  async fn update_vendor(
+    &self,
+    transaction_id: Uuid,
+    vendor_id: Option<Uuid>,
+  ) -> Result<(), ReportError> {
+    sqlx::query(
+      r#"
+            UPDATE bank_transactions
+            SET vendor_id = $2
+            WHERE id = $1
+            "#,
+    )
+    .bind(transaction_id)
+    .bind(vendor_id)
     .execute(&self.pool)
     .await?;
     Ok(())
   }
 
+  async fn find_by_vendor_id(&self, vendor_id: Uuid) -> Result<Vec<BankTransaction>, ReportError> {
+    let rows = sqlx::query_as::<_, BankTransactionRow>(
+            r#"
+            SELECT id, report_id, row_number, date, counterparty_name, counterparty_account, direction, amount, reference_number, description, currency, registry_code, statement_id, document_number, archive_id, receipt_path, category_id, rule_id, ignored, needs_review, vendor_id
+            FROM bank_transactions WHERE vendor_id = $1 ORDER BY date DESC, row_number
+            "#,
+        )
+        .bind(vendor_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+    let allocation_rows = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            SELECT a.id, a.transaction_id, a.invoice_id, a.received_invoice_id, a.amount, a.created_at
+            FROM match_allocations a
+            JOIN bank_transactions t ON t.id = a.transaction_id
+            WHERE t.vendor_id = $1
+            ORDER BY a.created_at
+            "#,
+    )
+    .bind(vendor_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    build_transactions(rows, allocation_rows)
+  }
+
   async fn delete_by_report_id(&self, report_id: Uuid) -> Result<(), ReportError> {
+    sqlx::query(
+      "DELETE FROM match_allocations WHERE transaction_id IN (SELECT id FROM bank_transactions WHERE report_id = $1)",
+    )
+    .bind(report_id)
+    .execute(&self.pool)
+    .await?;
+
     sqlx::query("DELETE FROM bank_transactions WHERE report_id = $1")
       .bind(report_id)
       .execute(&self.pool)
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::report::{
+  CategoryMatchField, CategorySuggestion, CategorySuggestionRepository, ReportError,
+  TransactionDirection,
+};
+
+#[derive(Debug, FromRow)]
+struct CategorySuggestionRow {
+  company_id: Uuid,
+  direction: String,
+  match_field: String,
+  match_value: String,
+  category_id: Uuid,
+  times_used: i32,
+  updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<CategorySuggestionRow> for CategorySuggestion {
+  type Error = ReportError;
+
+  fn try_from(row: CategorySuggestionRow) -> Result<Self, Self::Error> {
+    Ok(CategorySuggestion {
+      company_id: row.company_id,
+      direction: TransactionDirection::try_from(row.direction.as_str())?,
+      field: CategoryMatchField::try_from(row.match_field.as_str())?,
+      value: row.match_value,
+      category_id: row.category_id,
+      times_used: row.times_used,
+      updated_at: row.updated_at,
+    })
+  }
+}
+
+pub struct PostgresCategorySuggestionRepository {
+  pool: PgPool,
+}
+
+impl PostgresCategorySuggestionRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl CategorySuggestionRepository for PostgresCategorySuggestionRepository {
+  async fn record(&self, suggestion: CategorySuggestion) -> Result<(), ReportError> {
+    sqlx::query(
+      r#"
+            INSERT INTO transaction_category_suggestions (company_id, direction, match_field, match_value, category_id, times_used, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (company_id, direction, match_field, match_value) DO UPDATE
+            SET times_used = CASE
+                    WHEN transaction_category_suggestions.category_id = EXCLUDED.category_id
+                    THEN transaction_category_suggestions.times_used + 1
+                    ELSE EXCLUDED.times_used
+                END,
+                category_id = EXCLUDED.category_id,
+                updated_at = EXCLUDED.updated_at
+            "#,
+    )
+    .bind(suggestion.company_id)
+    .bind(suggestion.direction.as_str())
+    .bind(suggestion.field.as_str())
+    .bind(&suggestion.value)
+    .bind(suggestion.category_id)
+    .bind(suggestion.times_used)
+    .bind(suggestion.updated_at)
+    .execute(&self.pool)
+    .await?;
+    Ok(())
+  }
+
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<CategorySuggestion>, ReportError> {
+    let rows = sqlx::query_as::<_, CategorySuggestionRow>(
+      r#"
+            SELECT company_id, direction, match_field, match_value, category_id, times_used, updated_at
+            FROM transaction_category_suggestions WHERE company_id = $1
+            "#,
+    )
+    .bind(company_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+}
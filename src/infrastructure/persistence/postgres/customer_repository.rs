@@ -2,10 +2,11 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde_json::Value as JsonValue;
 use sqlx::{FromRow, PgPool};
+use std::str::FromStr;
 use uuid::Uuid;
 
 use crate::domain::invoice::{
-  Customer, CustomerName, errors::InvoiceError, ports::CustomerRepository,
+  Customer, CustomerName, InvoiceLanguage, errors::InvoiceError, ports::CustomerRepository,
   value_objects::CustomerAddress,
 };
 
@@ -15,6 +16,7 @@ struct CustomerRow {
   company_id: Uuid,
   name: String,
   address: Option<JsonValue>,
+  default_language: String,
   created_at: DateTime<Utc>,
   updated_at: DateTime<Utc>,
   archived_at: Option<DateTime<Utc>>,
@@ -40,6 +42,7 @@ impl TryFrom<CustomerRow> for Customer {
       company_id: row.company_id,
       name,
       address,
+      default_language: InvoiceLanguage::from_str(&row.default_language)?,
       created_at: row.created_at,
       updated_at: row.updated_at,
       archived_at: row.archived_at,
@@ -69,15 +72,16 @@ impl CustomerRepository for PostgresCustomerRepository {
 
     let row = sqlx::query_as::<_, CustomerRow>(
             r#"
-            INSERT INTO customers (id, company_id, name, address, created_at, updated_at, archived_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING id, company_id, name, address, created_at, updated_at, archived_at
+            INSERT INTO customers (id, company_id, name, address, default_language, created_at, updated_at, archived_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id, company_id, name, address, default_language, created_at, updated_at, archived_at
             "#,
         )
         .bind(customer.id)
         .bind(customer.company_id)
         .bind(customer.name.value())
         .bind(address_json)
+        .bind(customer.default_language.as_str())
         .bind(customer.created_at)
         .bind(customer.updated_at)
         .bind(customer.archived_at)
@@ -98,14 +102,15 @@ impl CustomerRepository for PostgresCustomerRepository {
     let row = sqlx::query_as::<_, CustomerRow>(
       r#"
             UPDATE customers
-            SET name = $2, address = $3, updated_at = $4, archived_at = $5
+            SET name = $2, address = $3, default_language = $4, updated_at = $5, archived_at = $6
             WHERE id = $1
-            RETURNING id, company_id, name, address, created_at, updated_at, archived_at
+            RETURNING id, company_id, name, address, default_language, created_at, updated_at, archived_at
             "#,
     )
     .bind(customer.id)
     .bind(customer.name.value())
     .bind(address_json)
+    .bind(customer.default_language.as_str())
     .bind(customer.updated_at)
     .bind(customer.archived_at)
     .fetch_one(&self.pool)
@@ -117,7 +122,7 @@ impl CustomerRepository for PostgresCustomerRepository {
   async fn find_by_id(&self, id: Uuid) -> Result<Option<Customer>, InvoiceError> {
     let row = sqlx::query_as::<_, CustomerRow>(
       r#"
-            SELECT id, company_id, name, address, created_at, updated_at, archived_at
+            SELECT id, company_id, name, address, default_language, created_at, updated_at, archived_at
             FROM customers
             WHERE id = $1
             "#,
@@ -132,7 +137,7 @@ impl CustomerRepository for PostgresCustomerRepository {
   async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<Customer>, InvoiceError> {
     let rows = sqlx::query_as::<_, CustomerRow>(
       r#"
-            SELECT id, company_id, name, address, created_at, updated_at, archived_at
+            SELECT id, company_id, name, address, default_language, created_at, updated_at, archived_at
             FROM customers
             WHERE company_id = $1
             ORDER BY name ASC
@@ -151,7 +156,7 @@ impl CustomerRepository for PostgresCustomerRepository {
   ) -> Result<Vec<Customer>, InvoiceError> {
     let rows = sqlx::query_as::<_, CustomerRow>(
       r#"
-            SELECT id, company_id, name, address, created_at, updated_at, archived_at
+            SELECT id, company_id, name, address, default_language, created_at, updated_at, archived_at
             FROM customers
             WHERE company_id = $1 AND archived_at IS NULL
             ORDER BY name ASC
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::domain::document::{DataKeyRepository, DocumentError};
+
+pub struct PostgresDataKeyRepository {
+  pool: PgPool,
+}
+
+impl PostgresDataKeyRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl DataKeyRepository for PostgresDataKeyRepository {
+  async fn find(&self, company_id: Uuid) -> Result<Option<String>, DocumentError> {
+    let wrapped_key = sqlx::query_scalar::<_, String>(
+      r#"
+      SELECT wrapped_key
+      FROM company_data_keys
+      WHERE company_id = $1
+      "#,
+    )
+    .bind(company_id)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    Ok(wrapped_key)
+  }
+
+  async fn create_if_absent(
+    &self,
+    company_id: Uuid,
+    wrapped_key: String,
+  ) -> Result<String, DocumentError> {
+    sqlx::query(
+      r#"
+      INSERT INTO company_data_keys (company_id, wrapped_key, created_at)
+      VALUES ($1, $2, $3)
+      ON CONFLICT (company_id) DO NOTHING
+      "#,
+    )
+    .bind(company_id)
+    .bind(&wrapped_key)
+    .bind(Utc::now())
+    .execute(&self.pool)
+    .await?;
+
+    let stored = sqlx::query_scalar::<_, String>(
+      r#"
+      SELECT wrapped_key
+      FROM company_data_keys
+      WHERE company_id = $1
+      "#,
+    )
+    .bind(company_id)
+    .fetch_one(&self.pool)
+    .await?;
+
+    Ok(stored)
+  }
+}
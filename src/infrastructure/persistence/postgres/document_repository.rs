@@ -0,0 +1,371 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::domain::document::{
+  Document, DocumentError, DocumentLink, DocumentOwner, DocumentRepository, IntegrityStatus,
+  Unlinked,
+};
+
+#[derive(Debug, FromRow)]
+struct DocumentRow {
+  id: Uuid,
+  company_id: Uuid,
+  sha256: String,
+  size_bytes: i64,
+  mime_type: String,
+  original_name: String,
+  storage_key: String,
+  uploaded_by: Option<Uuid>,
+  integrity_status: String,
+  checked_at: Option<DateTime<Utc>>,
+  created_at: DateTime<Utc>,
+  retained_until: Option<NaiveDate>,
+}
+
+#[derive(Debug, FromRow)]
+struct DocumentLinkRow {
+  owner_type: String,
+  owner_id: Uuid,
+  document_id: Uuid,
+  created_at: DateTime<Utc>,
+}
+
+impl TryFrom<DocumentRow> for Document {
+  type Error = DocumentError;
+
+  fn try_from(row: DocumentRow) -> Result<Self, Self::Error> {
+    Ok(Document {
+      id: row.id,
+      company_id: row.company_id,
+      sha256: row.sha256,
+      size_bytes: row.size_bytes,
+      mime_type: row.mime_type,
+      original_name: row.original_name,
+      storage_key: row.storage_key,
+      uploaded_by: row.uploaded_by,
+      integrity_status: IntegrityStatus::try_from(row.integrity_status.as_str())?,
+      checked_at: row.checked_at,
+      created_at: row.created_at,
+      retained_until: row.retained_until,
+    })
+  }
+}
+
+impl TryFrom<DocumentLinkRow> for DocumentLink {
+  type Error = DocumentError;
+
+  fn try_from(row: DocumentLinkRow) -> Result<Self, Self::Error> {
+    Ok(DocumentLink {
+      document_id: row.document_id,
+      owner: DocumentOwner::try_from(row.owner_type.as_str())?,
+      owner_id: row.owner_id,
+      created_at: row.created_at,
+    })
+  }
+}
+
+/// Delete the document unless something links to it or it is retained
+/// beyond `today`
+async fn delete_unlinked(
+  conn: &mut PgConnection,
+  id: Uuid,
+  today: NaiveDate,
+) -> Result<Option<Document>, DocumentError> {
+  let row = sqlx::query_as::<_, DocumentRow>(
+    r#"
+    DELETE FROM documents
+    WHERE id = $1
+      AND (retained_until IS NULL OR retained_until < $2)
+      AND NOT EXISTS (SELECT 1 FROM document_links WHERE document_links.document_id = documents.id)
+    RETURNING id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+    "#,
+  )
+  .bind(id)
+  .bind(today)
+  .fetch_optional(conn)
+  .await?;
+
+  row.map(|r| r.try_into()).transpose()
+}
+
+pub struct PostgresDocumentRepository {
+  pool: PgPool,
+}
+
+impl PostgresDocumentRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl DocumentRepository for PostgresDocumentRepository {
+  async fn create(&self, document: Document) -> Result<Document, DocumentError> {
+    sqlx::query(
+      r#"
+      INSERT INTO documents (id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until)
+      VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+      ON CONFLICT (company_id, sha256) DO NOTHING
+      "#,
+    )
+    .bind(document.id)
+    .bind(document.company_id)
+    .bind(&document.sha256)
+    .bind(document.size_bytes)
+    .bind(&document.mime_type)
+    .bind(&document.original_name)
+    .bind(&document.storage_key)
+    .bind(document.uploaded_by)
+    .bind(document.integrity_status.as_str())
+    .bind(document.checked_at)
+    .bind(document.created_at)
+    .bind(document.retained_until)
+    .execute(&self.pool)
+    .await?;
+
+    // Ours, or the one stored by a concurrent upload of the same content
+    self
+      .find_by_hash(document.company_id, &document.sha256)
+      .await?
+      .ok_or(DocumentError::NotFound)
+  }
+
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<Document>, DocumentError> {
+    let row = sqlx::query_as::<_, DocumentRow>(
+      r#"
+      SELECT id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+      FROM documents
+      WHERE id = $1
+      "#,
+    )
+    .bind(id)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_by_hash(
+    &self,
+    company_id: Uuid,
+    sha256: &str,
+  ) -> Result<Option<Document>, DocumentError> {
+    let row = sqlx::query_as::<_, DocumentRow>(
+      r#"
+      SELECT id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+      FROM documents
+      WHERE company_id = $1 AND sha256 = $2
+      "#,
+    )
+    .bind(company_id)
+    .bind(sha256)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_by_company(&self, company_id: Uuid) -> Result<Vec<Document>, DocumentError> {
+    let rows = sqlx::query_as::<_, DocumentRow>(
+      r#"
+      SELECT id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+      FROM documents
+      WHERE company_id = $1
+      ORDER BY created_at DESC
+      "#,
+    )
+    .bind(company_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn find_page(
+    &self,
+    after: Option<Uuid>,
+    limit: i64,
+  ) -> Result<Vec<Document>, DocumentError> {
+    let rows = sqlx::query_as::<_, DocumentRow>(
+      r#"
+      SELECT id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+      FROM documents
+      WHERE $1::uuid IS NULL OR id > $1
+      ORDER BY id
+      LIMIT $2
+      "#,
+    )
+    .bind(after)
+    .bind(limit)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn update_integrity(
+    &self,
+    id: Uuid,
+    status: IntegrityStatus,
+    checked_at: DateTime<Utc>,
+  ) -> Result<(), DocumentError> {
+    sqlx::query("UPDATE documents SET integrity_status = $2, checked_at = $3 WHERE id = $1")
+      .bind(id)
+      .bind(status.as_str())
+      .bind(checked_at)
+      .execute(&self.pool)
+      .await?;
+
+    Ok(())
+  }
+
+  async fn delete_if_unlinked(
+    &self,
+    id: Uuid,
+    today: NaiveDate,
+  ) -> Result<Option<Document>, DocumentError> {
+    let mut conn = self.pool.acquire().await?;
+    delete_unlinked(&mut conn, id, today).await
+  }
+
+  async fn find_released(&self, today: NaiveDate) -> Result<Vec<Document>, DocumentError> {
+    let rows = sqlx::query_as::<_, DocumentRow>(
+      r#"
+      SELECT id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+      FROM documents
+      WHERE retained_until < $1
+        AND NOT EXISTS (SELECT 1 FROM document_links WHERE document_links.document_id = documents.id)
+      ORDER BY retained_until
+      "#,
+    )
+    .bind(today)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn link(
+    &self,
+    link: DocumentLink,
+    keep_replaced_until: Option<NaiveDate>,
+    today: NaiveDate,
+  ) -> Result<Option<Document>, DocumentError> {
+    let mut tx = self.pool.begin().await?;
+
+    // Held until commit, so the document can't be deleted under the link
+    let document: Option<Uuid> =
+      sqlx::query_scalar("SELECT id FROM documents WHERE id = $1 FOR SHARE")
+        .bind(link.document_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+    if document.is_none() {
+      return Err(DocumentError::NotFound);
+    }
+
+    let previous: Option<Uuid> = sqlx::query_scalar(
+      "SELECT document_id FROM document_links WHERE owner_type = $1 AND owner_id = $2 FOR UPDATE",
+    )
+    .bind(link.owner.as_str())
+    .bind(link.owner_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    sqlx::query(
+      r#"
+      INSERT INTO document_links (owner_type, owner_id, document_id, created_at)
+      VALUES ($1, $2, $3, $4)
+      ON CONFLICT (owner_type, owner_id)
+      DO UPDATE SET document_id = EXCLUDED.document_id, created_at = EXCLUDED.created_at
+      "#,
+    )
+    .bind(link.owner.as_str())
+    .bind(link.owner_id)
+    .bind(link.document_id)
+    .bind(link.created_at)
+    .execute(&mut *tx)
+    .await?;
+
+    let mut removed = None;
+    if let Some(previous) = previous.filter(|id| *id != link.document_id) {
+      if let Some(until) = keep_replaced_until {
+        sqlx::query(
+          "UPDATE documents SET retained_until = GREATEST(COALESCE(retained_until, $2), $2) WHERE id = $1",
+        )
+        .bind(previous)
+        .bind(until)
+        .execute(&mut *tx)
+        .await?;
+      }
+      removed = delete_unlinked(&mut tx, previous, today).await?;
+    }
+
+    tx.commit().await?;
+    Ok(removed)
+  }
+
+  async fn unlink(
+    &self,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+    today: NaiveDate,
+  ) -> Result<Option<Unlinked>, DocumentError> {
+    let mut tx = self.pool.begin().await?;
+
+    let document_id: Option<Uuid> = sqlx::query_scalar(
+      "DELETE FROM document_links WHERE owner_type = $1 AND owner_id = $2 RETURNING document_id",
+    )
+    .bind(owner.as_str())
+    .bind(owner_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    let unlinked = match document_id {
+      Some(document_id) => Some(Unlinked {
+        document_id,
+        removed: delete_unlinked(&mut tx, document_id, today).await?,
+      }),
+      None => None,
+    };
+
+    tx.commit().await?;
+    Ok(unlinked)
+  }
+
+  async fn find_link(
+    &self,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+  ) -> Result<Option<DocumentLink>, DocumentError> {
+    let row = sqlx::query_as::<_, DocumentLinkRow>(
+      r#"
+      SELECT owner_type, owner_id, document_id, created_at
+      FROM document_links
+      WHERE owner_type = $1 AND owner_id = $2
+      "#,
+    )
+    .bind(owner.as_str())
+    .bind(owner_id)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_links(&self, document_id: Uuid) -> Result<Vec<DocumentLink>, DocumentError> {
+    let rows = sqlx::query_as::<_, DocumentLinkRow>(
+      r#"
+      SELECT owner_type, owner_id, document_id, created_at
+      FROM document_links
+      WHERE document_id = $1
+      ORDER BY created_at
+      "#,
+    )
+    .bind(document_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+}
@@ -0,0 +1,130 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::domain::invoice::{
+  entities::InvoiceBranding,
+  errors::InvoiceError,
+  ports::InvoiceBrandingRepository,
+  value_objects::{AccentColor, BrandingText, CustomInvoiceTemplate, InvoiceFont},
+};
+
+#[derive(Debug, FromRow)]
+struct BrandingRow {
+  company_id: Uuid,
+  logo_path: Option<String>,
+  accent_color: String,
+  font: String,
+  default_notes: Option<String>,
+  footer_text: Option<String>,
+  signature_text: Option<String>,
+  custom_template: Option<String>,
+  created_at: DateTime<Utc>,
+  updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<BrandingRow> for InvoiceBranding {
+  type Error = InvoiceError;
+
+  fn try_from(row: BrandingRow) -> Result<Self, Self::Error> {
+    Ok(InvoiceBranding {
+      company_id: row.company_id,
+      logo_path: row.logo_path,
+      accent_color: AccentColor::new(row.accent_color)?,
+      font: InvoiceFont::from_str(&row.font)?,
+      default_notes: row
+        .default_notes
+        .map(BrandingText::new)
+        .transpose()?
+        .flatten(),
+      footer_text: row
+        .footer_text
+        .map(BrandingText::new)
+        .transpose()?
+        .flatten(),
+      signature_text: row
+        .signature_text
+        .map(BrandingText::new)
+        .transpose()?
+        .flatten(),
+      custom_template: row
+        .custom_template
+        .map(CustomInvoiceTemplate::new)
+        .transpose()?
+        .flatten(),
+      created_at: row.created_at,
+      updated_at: row.updated_at,
+    })
+  }
+}
+
+pub struct PostgresInvoiceBrandingRepository {
+  pool: PgPool,
+}
+
+impl PostgresInvoiceBrandingRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl InvoiceBrandingRepository for PostgresInvoiceBrandingRepository {
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Option<InvoiceBranding>, InvoiceError> {
+    let row = sqlx::query_as::<_, BrandingRow>(
+      r#"
+      SELECT company_id, logo_path, accent_color, font, default_notes, footer_text,
+             signature_text, custom_template, created_at, updated_at
+      FROM invoice_brandings
+      WHERE company_id = $1
+      "#,
+    )
+    .bind(company_id)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(InvoiceBranding::try_from).transpose()
+  }
+
+  async fn save(&self, branding: InvoiceBranding) -> Result<InvoiceBranding, InvoiceError> {
+    let row = sqlx::query_as::<_, BrandingRow>(
+      r#"
+      INSERT INTO invoice_brandings (
+        company_id, logo_path, accent_color, font, default_notes, footer_text,
+        signature_text, custom_template, created_at, updated_at
+      )
+      VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+      ON CONFLICT (company_id) DO UPDATE
+      SET logo_path = EXCLUDED.logo_path,
+          accent_color = EXCLUDED.accent_color,
+          font = EXCLUDED.font,
+          default_notes = EXCLUDED.default_notes,
+          footer_text = EXCLUDED.footer_text,
+          signature_text = EXCLUDED.signature_text,
+          custom_template = EXCLUDED.custom_template,
+          updated_at = EXCLUDED.updated_at
+      RETURNING company_id, logo_path, accent_color, font, default_notes, footer_text,
+                signature_text, custom_template, created_at, updated_at
+      "#,
+    )
+    .bind(branding.company_id)
+    .bind(&branding.logo_path)
+    .bind(branding.accent_color.value())
+    .bind(branding.font.as_str())
+    .bind(branding.default_notes.as_ref().map(|t| t.value()))
+    .bind(branding.footer_text.as_ref().map(|t| t.value()))
+    .bind(branding.signature_text.as_ref().map(|t| t.value()))
+    .bind(branding.custom_template.as_ref().map(|t| t.value()))
+    .bind(branding.created_at)
+    .bind(branding.updated_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    InvoiceBranding::try_from(row)
+  }
+}
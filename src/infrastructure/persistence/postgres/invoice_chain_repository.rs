@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use super::invoice_unit_of_work::postgres_transaction;
+use crate::domain::invoice::{
+  chain::InvoiceChainLink,
+  errors::InvoiceError,
+  ports::{InvoiceChainRepository, InvoiceUnitOfWork},
+};
+
+#[derive(Debug, FromRow)]
+struct ChainLinkRow {
+  id: Uuid,
+  company_id: Uuid,
+  invoice_id: Uuid,
+  sequence: i64,
+  previous_hash: Option<String>,
+  hash: String,
+  created_at: DateTime<Utc>,
+}
+
+impl From<ChainLinkRow> for InvoiceChainLink {
+  fn from(row: ChainLinkRow) -> Self {
+    InvoiceChainLink {
+      id: row.id,
+      company_id: row.company_id,
+      invoice_id: row.invoice_id,
+      sequence: row.sequence,
+      previous_hash: row.previous_hash,
+      hash: row.hash,
+      created_at: row.created_at,
+    }
+  }
+}
+
+pub struct PostgresInvoiceChainRepository {
+  pool: PgPool,
+}
+
+impl PostgresInvoiceChainRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl InvoiceChainRepository for PostgresInvoiceChainRepository {
+  async fn append(
+    &self,
+    unit_of_work: &mut dyn InvoiceUnitOfWork,
+    link: InvoiceChainLink,
+  ) -> Result<InvoiceChainLink, InvoiceError> {
+    let tx = postgres_transaction(unit_of_work)?;
+
+    let row = sqlx::query_as::<_, ChainLinkRow>(
+      r#"
+      INSERT INTO invoice_chain_links (
+        id, company_id, invoice_id, sequence, previous_hash, hash, created_at
+      )
+      VALUES ($1, $2, $3, $4, $5, $6, $7)
+      RETURNING id, company_id, invoice_id, sequence, previous_hash, hash, created_at
+      "#,
+    )
+    .bind(link.id)
+    .bind(link.company_id)
+    .bind(link.invoice_id)
+    .bind(link.sequence)
+    .bind(&link.previous_hash)
+    .bind(&link.hash)
+    .bind(link.created_at)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| {
+      if let sqlx::Error::Database(db_err) = &e {
+        if db_err.is_unique_violation() {
+          return InvoiceError::ChainConflict;
+        }
+      }
+      InvoiceError::Database(e)
+    })?;
+
+    Ok(row.into())
+  }
+
+  async fn find_latest(&self, company_id: Uuid) -> Result<Option<InvoiceChainLink>, InvoiceError> {
+    let row = sqlx::query_as::<_, ChainLinkRow>(
+      r#"
+      SELECT id, company_id, invoice_id, sequence, previous_hash, hash, created_at
+      FROM invoice_chain_links
+      WHERE company_id = $1
+      ORDER BY sequence DESC
+      LIMIT 1
+      "#,
+    )
+    .bind(company_id)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    Ok(row.map(InvoiceChainLink::from))
+  }
+
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<InvoiceChainLink>, InvoiceError> {
+    let rows = sqlx::query_as::<_, ChainLinkRow>(
+      r#"
+      SELECT id, company_id, invoice_id, sequence, previous_hash, hash, created_at
+      FROM invoice_chain_links
+      WHERE company_id = $1
+      ORDER BY sequence ASC
+      "#,
+    )
+    .bind(company_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(rows.into_iter().map(InvoiceChainLink::from).collect())
+  }
+}
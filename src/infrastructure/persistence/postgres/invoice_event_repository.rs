@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::domain::invoice::{
+  entities::InvoiceEvent, errors::InvoiceError, ports::InvoiceEventRepository,
+  value_objects::InvoiceEventKind,
+};
+
+#[derive(Debug, FromRow)]
+struct InvoiceEventRow {
+  id: Uuid,
+  invoice_id: Uuid,
+  company_id: Uuid,
+  invoice_number: String,
+  user_id: Option<Uuid>,
+  kind: String,
+  changes: String,
+  created_at: DateTime<Utc>,
+}
+
+impl TryFrom<InvoiceEventRow> for InvoiceEvent {
+  type Error = InvoiceError;
+
+  fn try_from(row: InvoiceEventRow) -> Result<Self, Self::Error> {
+    Ok(InvoiceEvent {
+      id: row.id,
+      invoice_id: row.invoice_id,
+      company_id: row.company_id,
+      invoice_number: row.invoice_number,
+      user_id: row.user_id,
+      kind: InvoiceEventKind::from_str(&row.kind)?,
+      changes: serde_json::from_str(&row.changes)
+        .map_err(|e| InvoiceError::Internal(format!("Failed to parse event changes: {}", e)))?,
+      created_at: row.created_at,
+    })
+  }
+}
+
+pub struct PostgresInvoiceEventRepository {
+  pool: PgPool,
+}
+
+impl PostgresInvoiceEventRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl InvoiceEventRepository for PostgresInvoiceEventRepository {
+  async fn append(&self, event: InvoiceEvent) -> Result<InvoiceEvent, InvoiceError> {
+    let changes = serde_json::to_string(&event.changes)
+      .map_err(|e| InvoiceError::Internal(format!("Failed to serialize event changes: {}", e)))?;
+
+    let row = sqlx::query_as::<_, InvoiceEventRow>(
+      r#"
+      INSERT INTO invoice_events (
+        id, invoice_id, company_id, invoice_number, user_id, kind, changes, created_at
+      )
+      VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+      RETURNING id, invoice_id, company_id, invoice_number, user_id, kind, changes, created_at
+      "#,
+    )
+    .bind(event.id)
+    .bind(event.invoice_id)
+    .bind(event.company_id)
+    .bind(&event.invoice_number)
+    .bind(event.user_id)
+    .bind(event.kind.as_str())
+    .bind(changes)
+    .bind(event.created_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_invoice_id(&self, invoice_id: Uuid) -> Result<Vec<InvoiceEvent>, InvoiceError> {
+    let rows = sqlx::query_as::<_, InvoiceEventRow>(
+      r#"
+      SELECT id, invoice_id, company_id, invoice_number, user_id, kind, changes, created_at
+      FROM invoice_events
+      WHERE invoice_id = $1
+      ORDER BY created_at DESC
+      "#,
+    )
+    .bind(invoice_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(InvoiceEvent::try_from).collect()
+  }
+}
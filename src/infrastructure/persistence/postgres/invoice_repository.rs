@@ -4,9 +4,11 @@ use sqlx::{FromRow, PgPool};
 use std::str::FromStr;
 use uuid::Uuid;
 
+use super::invoice_unit_of_work::postgres_transaction;
 use crate::domain::invoice::{
-  Currency, Invoice, InvoiceNumber, InvoiceStatus, PaymentTerms, errors::InvoiceError,
-  ports::InvoiceRepository,
+  Currency, Invoice, InvoiceLanguage, InvoiceNumber, InvoiceStatus, PaymentTerms,
+  errors::InvoiceError,
+  ports::{InvoiceRepository, InvoiceUnitOfWork},
 };
 
 #[derive(Debug, FromRow)]
@@ -20,6 +22,7 @@ struct InvoiceRow {
   due_date: NaiveDate,
   payment_terms: String,
   currency: String,
+  language: String,
   status: String,
   pdf_path: Option<String>,
   pdf_drive_file_id: Option<String>,
@@ -35,6 +38,7 @@ impl TryFrom<InvoiceRow> for Invoice {
     let invoice_number = InvoiceNumber::new(row.invoice_number)?;
     let payment_terms = PaymentTerms::from_str(&row.payment_terms)?;
     let currency = Currency::from_str(&row.currency)?;
+    let language = InvoiceLanguage::from_str(&row.language)?;
     let status = InvoiceStatus::from_str(&row.status)?;
 
     Ok(Invoice {
@@ -47,6 +51,7 @@ impl TryFrom<InvoiceRow> for Invoice {
       due_date: row.due_date,
       payment_terms,
       currency,
+      language,
       status,
       pdf_path: row.pdf_path,
       pdf_drive_file_id: row.pdf_drive_file_id,
@@ -76,12 +81,12 @@ impl InvoiceRepository for PostgresInvoiceRepository {
       r#"
             INSERT INTO invoices (
                 id, company_id, customer_id, bank_account_id, invoice_number,
-                invoice_date, due_date, payment_terms, currency, status,
+                invoice_date, due_date, payment_terms, currency, language, status,
                 pdf_path, pdf_drive_file_id, created_at, updated_at, archived_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
             RETURNING id, company_id, customer_id, bank_account_id, invoice_number,
-                      invoice_date, due_date, payment_terms, currency, status,
+                      invoice_date, due_date, payment_terms, currency, language, status,
                       pdf_path, pdf_drive_file_id, created_at, updated_at, archived_at
             "#,
     )
@@ -94,6 +99,7 @@ impl InvoiceRepository for PostgresInvoiceRepository {
     .bind(invoice.due_date)
     .bind(invoice.payment_terms.as_str())
     .bind(invoice.currency.as_str())
+    .bind(invoice.language.as_str())
     .bind(invoice.status.as_str())
     .bind(invoice.pdf_path)
     .bind(invoice.pdf_drive_file_id)
@@ -124,10 +130,11 @@ impl InvoiceRepository for PostgresInvoiceRepository {
             UPDATE invoices
             SET customer_id = $2, bank_account_id = $3, invoice_date = $4,
                 due_date = $5, payment_terms = $6, status = $7,
-                pdf_path = $8, pdf_drive_file_id = $9, updated_at = $10, archived_at = $11
+                pdf_path = $8, pdf_drive_file_id = $9, updated_at = $10, archived_at = $11,
+                language = $12
             WHERE id = $1
             RETURNING id, company_id, customer_id, bank_account_id, invoice_number,
-                      invoice_date, due_date, payment_terms, currency, status,
+                      invoice_date, due_date, payment_terms, currency, language, status,
                       pdf_path, pdf_drive_file_id, created_at, updated_at, archived_at
             "#,
     )
@@ -142,6 +149,7 @@ impl InvoiceRepository for PostgresInvoiceRepository {
     .bind(invoice.pdf_drive_file_id)
     .bind(invoice.updated_at)
     .bind(invoice.archived_at)
+    .bind(invoice.language.as_str())
     .fetch_one(&self.pool)
     .await?;
 
@@ -152,7 +160,7 @@ impl InvoiceRepository for PostgresInvoiceRepository {
     let row = sqlx::query_as::<_, InvoiceRow>(
       r#"
             SELECT id, company_id, customer_id, bank_account_id, invoice_number,
-                   invoice_date, due_date, payment_terms, currency, status,
+                   invoice_date, due_date, payment_terms, currency, language, status,
                    pdf_path, pdf_drive_file_id, created_at, updated_at, archived_at
             FROM invoices
             WHERE id = $1
@@ -169,7 +177,7 @@ impl InvoiceRepository for PostgresInvoiceRepository {
     let rows = sqlx::query_as::<_, InvoiceRow>(
       r#"
             SELECT id, company_id, customer_id, bank_account_id, invoice_number,
-                   invoice_date, due_date, payment_terms, currency, status,
+                   invoice_date, due_date, payment_terms, currency, language, status,
                    pdf_path, pdf_drive_file_id, created_at, updated_at, archived_at
             FROM invoices
             WHERE company_id = $1 AND archived_at IS NULL
@@ -191,7 +199,7 @@ impl InvoiceRepository for PostgresInvoiceRepository {
     let rows = sqlx::query_as::<_, InvoiceRow>(
       r#"
             SELECT id, company_id, customer_id, bank_account_id, invoice_number,
-                   invoice_date, due_date, payment_terms, currency, status,
+                   invoice_date, due_date, payment_terms, currency, language, status,
                    pdf_path, pdf_drive_file_id, created_at, updated_at, archived_at
             FROM invoices
             WHERE company_id = $1 AND status = $2 AND archived_at IS NULL
@@ -214,7 +222,7 @@ impl InvoiceRepository for PostgresInvoiceRepository {
     let rows = sqlx::query_as::<_, InvoiceRow>(
       r#"
             SELECT id, company_id, customer_id, bank_account_id, invoice_number,
-                   invoice_date, due_date, payment_terms, currency, status,
+                   invoice_date, due_date, payment_terms, currency, language, status,
                    pdf_path, pdf_drive_file_id, created_at, updated_at, archived_at
             FROM invoices
             WHERE company_id = $1 AND customer_id = $2 AND archived_at IS NULL
@@ -237,7 +245,7 @@ impl InvoiceRepository for PostgresInvoiceRepository {
     let rows = sqlx::query_as::<_, InvoiceRow>(
       r#"
             SELECT id, company_id, customer_id, bank_account_id, invoice_number,
-                   invoice_date, due_date, payment_terms, currency, status,
+                   invoice_date, due_date, payment_terms, currency, language, status,
                    pdf_path, pdf_drive_file_id, created_at, updated_at, archived_at
             FROM invoices
             WHERE company_id = $1 AND status = 'sent' AND due_date < $2 AND archived_at IS NULL
@@ -259,7 +267,7 @@ impl InvoiceRepository for PostgresInvoiceRepository {
     let rows = sqlx::query_as::<_, InvoiceRow>(
       r#"
             SELECT id, company_id, customer_id, bank_account_id, invoice_number,
-                   invoice_date, due_date, payment_terms, currency, status,
+                   invoice_date, due_date, payment_terms, currency, language, status,
                    pdf_path, pdf_drive_file_id, created_at, updated_at, archived_at
             FROM invoices
             WHERE company_id = $1 AND archived_at IS NOT NULL
@@ -298,4 +306,20 @@ impl InvoiceRepository for PostgresInvoiceRepository {
 
     Ok(())
   }
+
+  async fn update_status(
+    &self,
+    unit_of_work: &mut dyn InvoiceUnitOfWork,
+    invoice: &Invoice,
+  ) -> Result<(), InvoiceError> {
+    let tx = postgres_transaction(unit_of_work)?;
+    sqlx::query("UPDATE invoices SET status = $2, updated_at = $3 WHERE id = $1")
+      .bind(invoice.id)
+      .bind(invoice.status.as_str())
+      .bind(invoice.updated_at)
+      .execute(&mut **tx)
+      .await?;
+
+    Ok(())
+  }
 }
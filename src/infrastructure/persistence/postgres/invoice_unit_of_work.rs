@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres, Transaction};
+use std::any::Any;
+
+use crate::domain::invoice::{
+  errors::InvoiceError,
+  ports::{InvoiceUnitOfWork, InvoiceUnitOfWorkFactory},
+};
+
+pub struct PostgresInvoiceUnitOfWork {
+  tx: Transaction<'static, Postgres>,
+}
+
+#[async_trait]
+impl InvoiceUnitOfWork for PostgresInvoiceUnitOfWork {
+  fn transaction(&mut self) -> &mut (dyn Any + Send) {
+    &mut self.tx
+  }
+
+  async fn commit(self: Box<Self>) -> Result<(), InvoiceError> {
+    self.tx.commit().await?;
+    Ok(())
+  }
+}
+
+/// The postgres transaction of a unit of work begun by `PostgresInvoiceUnitOfWorkFactory`
+pub(crate) fn postgres_transaction(
+  unit_of_work: &mut dyn InvoiceUnitOfWork,
+) -> Result<&mut Transaction<'static, Postgres>, InvoiceError> {
+  unit_of_work
+    .transaction()
+    .downcast_mut()
+    .ok_or_else(|| InvoiceError::Internal("Unit of work is not a postgres transaction".to_string()))
+}
+
+pub struct PostgresInvoiceUnitOfWorkFactory {
+  pool: PgPool,
+}
+
+impl PostgresInvoiceUnitOfWorkFactory {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl InvoiceUnitOfWorkFactory for PostgresInvoiceUnitOfWorkFactory {
+  async fn begin(&self) -> Result<Box<dyn InvoiceUnitOfWork>, InvoiceError> {
+    let tx = self.pool.begin().await?;
+    Ok(Box::new(PostgresInvoiceUnitOfWork { tx }))
+  }
+}
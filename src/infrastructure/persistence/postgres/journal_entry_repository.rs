@@ -0,0 +1,239 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::ledger::{
+  JournalEntry, JournalEntryRepository, JournalLine, JournalSource, LedgerError,
+};
+
+#[derive(Debug, FromRow)]
+struct JournalEntryRow {
+  id: Uuid,
+  company_id: Uuid,
+  entry_date: NaiveDate,
+  description: String,
+  source: String,
+  source_id: Option<Uuid>,
+  reversal_of: Option<Uuid>,
+  created_by: Option<Uuid>,
+  created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct JournalLineRow {
+  id: Uuid,
+  entry_id: Uuid,
+  account_id: Uuid,
+  debit: Decimal,
+  credit: Decimal,
+  description: Option<String>,
+  line_order: i32,
+}
+
+impl From<JournalLineRow> for JournalLine {
+  fn from(row: JournalLineRow) -> Self {
+    JournalLine {
+      id: row.id,
+      entry_id: row.entry_id,
+      account_id: row.account_id,
+      debit: row.debit,
+      credit: row.credit,
+      description: row.description,
+      line_order: row.line_order,
+    }
+  }
+}
+
+fn build_entries(
+  entry_rows: Vec<JournalEntryRow>,
+  line_rows: Vec<JournalLineRow>,
+) -> Result<Vec<JournalEntry>, LedgerError> {
+  let mut lines_by_entry: HashMap<Uuid, Vec<JournalLine>> = HashMap::new();
+  for row in line_rows {
+    lines_by_entry
+      .entry(row.entry_id)
+      .or_default()
+      .push(row.into());
+  }
+
+  entry_rows
+    .into_iter()
+    .map(|row| {
+      Ok(JournalEntry {
+        id: row.id,
+        company_id: row.company_id,
+        entry_date: row.entry_date,
+        description: row.description,
+        source: JournalSource::try_from(row.source.as_str())?,
+        source_id: row.source_id,
+        reversal_of: row.reversal_of,
+        created_by: row.created_by,
+        created_at: row.created_at,
+        lines: lines_by_entry.remove(&row.id).unwrap_or_default(),
+      })
+    })
+    .collect()
+}
+
+pub struct PostgresJournalEntryRepository {
+  pool: PgPool,
+}
+
+impl PostgresJournalEntryRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+
+  async fn find_lines(&self, entry_ids: &[Uuid]) -> Result<Vec<JournalLineRow>, LedgerError> {
+    let rows = sqlx::query_as::<_, JournalLineRow>(
+      r#"
+      SELECT id, entry_id, account_id, debit, credit, description, line_order
+      FROM journal_lines
+      WHERE entry_id = ANY($1)
+      ORDER BY line_order ASC
+      "#,
+    )
+    .bind(entry_ids)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(rows)
+  }
+
+  async fn load(&self, entry_rows: Vec<JournalEntryRow>) -> Result<Vec<JournalEntry>, LedgerError> {
+    let entry_ids: Vec<Uuid> = entry_rows.iter().map(|row| row.id).collect();
+    let line_rows = self.find_lines(&entry_ids).await?;
+    build_entries(entry_rows, line_rows)
+  }
+}
+
+#[async_trait]
+impl JournalEntryRepository for PostgresJournalEntryRepository {
+  async fn create(&self, entry: JournalEntry) -> Result<JournalEntry, LedgerError> {
+    let mut tx = self.pool.begin().await?;
+
+    sqlx::query(
+      r#"
+      INSERT INTO journal_entries (
+        id, company_id, entry_date, description, source, source_id, reversal_of,
+        created_by, created_at
+      )
+      VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+      "#,
+    )
+    .bind(entry.id)
+    .bind(entry.company_id)
+    .bind(entry.entry_date)
+    .bind(&entry.description)
+    .bind(entry.source.as_str())
+    .bind(entry.source_id)
+    .bind(entry.reversal_of)
+    .bind(entry.created_by)
+    .bind(entry.created_at)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+      if let sqlx::Error::Database(db_err) = &e {
+        if db_err.is_unique_violation() {
+          return LedgerError::AlreadyReversed;
+        }
+      }
+      LedgerError::from(e)
+    })?;
+
+    for line in &entry.lines {
+      sqlx::query(
+        r#"
+        INSERT INTO journal_lines (
+          id, entry_id, account_id, debit, credit, description, line_order
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        "#,
+      )
+      .bind(line.id)
+      .bind(line.entry_id)
+      .bind(line.account_id)
+      .bind(line.debit)
+      .bind(line.credit)
+      .bind(line.description.as_deref())
+      .bind(line.line_order)
+      .execute(&mut *tx)
+      .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(entry)
+  }
+
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<JournalEntry>, LedgerError> {
+    let rows = sqlx::query_as::<_, JournalEntryRow>(
+      r#"
+      SELECT id, company_id, entry_date, description, source, source_id, reversal_of,
+             created_by, created_at
+      FROM journal_entries
+      WHERE id = $1
+      "#,
+    )
+    .bind(id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(self.load(rows).await?.into_iter().next())
+  }
+
+  async fn is_reversed(&self, id: Uuid) -> Result<bool, LedgerError> {
+    let reversed = sqlx::query_scalar::<_, bool>(
+      "SELECT EXISTS(SELECT 1 FROM journal_entries WHERE reversal_of = $1)",
+    )
+    .bind(id)
+    .fetch_one(&self.pool)
+    .await?;
+
+    Ok(reversed)
+  }
+
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<JournalEntry>, LedgerError> {
+    let rows = sqlx::query_as::<_, JournalEntryRow>(
+      r#"
+      SELECT id, company_id, entry_date, description, source, source_id, reversal_of,
+             created_by, created_at
+      FROM journal_entries
+      WHERE company_id = $1
+      ORDER BY entry_date ASC, created_at ASC
+      "#,
+    )
+    .bind(company_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    self.load(rows).await
+  }
+
+  async fn find_by_source(
+    &self,
+    company_id: Uuid,
+    source: JournalSource,
+    source_id: Uuid,
+  ) -> Result<Vec<JournalEntry>, LedgerError> {
+    let rows = sqlx::query_as::<_, JournalEntryRow>(
+      r#"
+      SELECT id, company_id, entry_date, description, source, source_id, reversal_of,
+             created_by, created_at
+      FROM journal_entries
+      WHERE company_id = $1 AND source = $2 AND source_id = $3
+      ORDER BY created_at ASC
+      "#,
+    )
+    .bind(company_id)
+    .bind(source.as_str())
+    .bind(source_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    self.load(rows).await
+  }
+}
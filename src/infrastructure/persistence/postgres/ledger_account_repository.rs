@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::ledger::{Account, AccountRepository, AccountType, LedgerError};
+
+#[derive(Debug, FromRow)]
+struct AccountRow {
+  id: Uuid,
+  company_id: Uuid,
+  code: String,
+  name: String,
+  account_type: String,
+  created_at: DateTime<Utc>,
+}
+
+impl TryFrom<AccountRow> for Account {
+  type Error = LedgerError;
+
+  fn try_from(row: AccountRow) -> Result<Self, Self::Error> {
+    Ok(Account {
+      id: row.id,
+      company_id: row.company_id,
+      code: row.code,
+      name: row.name,
+      account_type: AccountType::try_from(row.account_type.as_str())?,
+      created_at: row.created_at,
+    })
+  }
+}
+
+pub struct PostgresAccountRepository {
+  pool: PgPool,
+}
+
+impl PostgresAccountRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl AccountRepository for PostgresAccountRepository {
+  async fn create(&self, account: Account) -> Result<Account, LedgerError> {
+    let row = sqlx::query_as::<_, AccountRow>(
+      r#"
+      INSERT INTO ledger_accounts (id, company_id, code, name, account_type, created_at)
+      VALUES ($1, $2, $3, $4, $5, $6)
+      RETURNING id, company_id, code, name, account_type, created_at
+      "#,
+    )
+    .bind(account.id)
+    .bind(account.company_id)
+    .bind(&account.code)
+    .bind(&account.name)
+    .bind(account.account_type.as_str())
+    .bind(account.created_at)
+    .fetch_one(&self.pool)
+    .await
+    .map_err(|e| {
+      if let sqlx::Error::Database(db_err) = &e {
+        if db_err.is_unique_violation() {
+          return LedgerError::DuplicateAccountCode(account.code.clone());
+        }
+      }
+      LedgerError::from(e)
+    })?;
+
+    row.try_into()
+  }
+
+  async fn create_many(&self, accounts: Vec<Account>) -> Result<(), LedgerError> {
+    for account in accounts {
+      sqlx::query(
+        r#"
+        INSERT INTO ledger_accounts (id, company_id, code, name, account_type, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (company_id, code) DO NOTHING
+        "#,
+      )
+      .bind(account.id)
+      .bind(account.company_id)
+      .bind(&account.code)
+      .bind(&account.name)
+      .bind(account.account_type.as_str())
+      .bind(account.created_at)
+      .execute(&self.pool)
+      .await?;
+    }
+
+    Ok(())
+  }
+
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<Account>, LedgerError> {
+    let rows = sqlx::query_as::<_, AccountRow>(
+      r#"
+      SELECT id, company_id, code, name, account_type, created_at
+      FROM ledger_accounts
+      WHERE company_id = $1
+      ORDER BY code ASC
+      "#,
+    )
+    .bind(company_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(Account::try_from).collect()
+  }
+}
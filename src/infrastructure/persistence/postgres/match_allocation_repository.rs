@@ -0,0 +1,127 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::report::{MatchAllocation, MatchAllocationRepository, ReportError};
+
+/// Also loaded by the bank transaction repository to fill in allocations
+#[derive(Debug, FromRow)]
+pub(super) struct MatchAllocationRow {
+  id: Uuid,
+  transaction_id: Uuid,
+  invoice_id: Option<Uuid>,
+  received_invoice_id: Option<Uuid>,
+  amount: Decimal,
+  created_at: DateTime<Utc>,
+}
+
+impl From<MatchAllocationRow> for MatchAllocation {
+  fn from(row: MatchAllocationRow) -> Self {
+    MatchAllocation {
+      id: row.id,
+      transaction_id: row.transaction_id,
+      invoice_id: row.invoice_id,
+      received_invoice_id: row.received_invoice_id,
+      amount: row.amount,
+      created_at: row.created_at,
+    }
+  }
+}
+
+pub struct PostgresMatchAllocationRepository {
+  pool: PgPool,
+}
+
+impl PostgresMatchAllocationRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl MatchAllocationRepository for PostgresMatchAllocationRepository {
+  async fn create(&self, allocation: MatchAllocation) -> Result<MatchAllocation, ReportError> {
+    let row = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            INSERT INTO match_allocations (id, transaction_id, invoice_id, received_invoice_id, amount, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, transaction_id, invoice_id, received_invoice_id, amount, created_at
+            "#,
+    )
+    .bind(allocation.id)
+    .bind(allocation.transaction_id)
+    .bind(allocation.invoice_id)
+    .bind(allocation.received_invoice_id)
+    .bind(allocation.amount)
+    .bind(allocation.created_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    Ok(row.into())
+  }
+
+  async fn find_by_invoice_id(
+    &self,
+    invoice_id: Uuid,
+  ) -> Result<Vec<MatchAllocation>, ReportError> {
+    let rows = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            SELECT id, transaction_id, invoice_id, received_invoice_id, amount, created_at
+            FROM match_allocations WHERE invoice_id = $1 ORDER BY created_at
+            "#,
+    )
+    .bind(invoice_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into()).collect())
+  }
+
+  async fn find_by_received_invoice_id(
+    &self,
+    received_invoice_id: Uuid,
+  ) -> Result<Vec<MatchAllocation>, ReportError> {
+    let rows = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            SELECT id, transaction_id, invoice_id, received_invoice_id, amount, created_at
+            FROM match_allocations WHERE received_invoice_id = $1 ORDER BY created_at
+            "#,
+    )
+    .bind(received_invoice_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into()).collect())
+  }
+
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<MatchAllocation>, ReportError> {
+    let rows = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            SELECT a.id, a.transaction_id, a.invoice_id, a.received_invoice_id, a.amount, a.created_at
+            FROM match_allocations a
+            JOIN bank_transactions t ON t.id = a.transaction_id
+            JOIN monthly_reports r ON r.id = t.report_id
+            WHERE r.company_id = $1
+            ORDER BY a.created_at
+            "#,
+    )
+    .bind(company_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into()).collect())
+  }
+
+  async fn delete_by_transaction_id(&self, transaction_id: Uuid) -> Result<(), ReportError> {
+    sqlx::query("DELETE FROM match_allocations WHERE transaction_id = $1")
+      .bind(transaction_id)
+      .execute(&self.pool)
+      .await?;
+    Ok(())
+  }
+}
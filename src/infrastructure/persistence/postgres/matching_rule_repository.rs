@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::report::{
+  MatchingRule, MatchingRuleRepository, ReportError, RuleAction, RuleConditions,
+  TransactionDirection,
+};
+
+#[derive(Debug, FromRow)]
+struct MatchingRuleRow {
+  id: Uuid,
+  company_id: Uuid,
+  name: String,
+  position: i32,
+  counterparty_iban: Option<String>,
+  description_pattern: Option<String>,
+  min_amount: Option<Decimal>,
+  max_amount: Option<Decimal>,
+  direction: Option<String>,
+  action: String,
+  category_id: Option<Uuid>,
+  vendor_name: Option<String>,
+  created_at: DateTime<Utc>,
+}
+
+impl TryFrom<MatchingRuleRow> for MatchingRule {
+  type Error = ReportError;
+
+  fn try_from(row: MatchingRuleRow) -> Result<Self, Self::Error> {
+    Ok(MatchingRule {
+      id: row.id,
+      company_id: row.company_id,
+      name: row.name,
+      position: row.position,
+      conditions: RuleConditions {
+        counterparty_iban: row.counterparty_iban,
+        description_pattern: row.description_pattern,
+        min_amount: row.min_amount,
+        max_amount: row.max_amount,
+        direction: row
+          .direction
+          .as_deref()
+          .map(TransactionDirection::try_from)
+          .transpose()?,
+      },
+      action: RuleAction::from_parts(&row.action, row.category_id, row.vendor_name.as_deref())?,
+      created_at: row.created_at,
+    })
+  }
+}
+
+pub struct PostgresMatchingRuleRepository {
+  pool: PgPool,
+}
+
+impl PostgresMatchingRuleRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl MatchingRuleRepository for PostgresMatchingRuleRepository {
+  async fn create(&self, rule: MatchingRule) -> Result<MatchingRule, ReportError> {
+    let row = sqlx::query_as::<_, MatchingRuleRow>(
+      r#"
+            INSERT INTO matching_rules (id, company_id, name, position, counterparty_iban, description_pattern, min_amount, max_amount, direction, action, category_id, vendor_name, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            RETURNING id, company_id, name, position, counterparty_iban, description_pattern, min_amount, max_amount, direction, action, category_id, vendor_name, created_at
+            "#,
+    )
+    .bind(rule.id)
+    .bind(rule.company_id)
+    .bind(&rule.name)
+    .bind(rule.position)
+    .bind(rule.conditions.counterparty_iban.as_deref())
+    .bind(rule.conditions.description_pattern.as_deref())
+    .bind(rule.conditions.min_amount)
+    .bind(rule.conditions.max_amount)
+    .bind(rule.conditions.direction.map(|d| d.as_str()))
+    .bind(rule.action.kind())
+    .bind(rule.action.category_id())
+    .bind(rule.action.vendor_name())
+    .bind(rule.created_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<MatchingRule>, ReportError> {
+    let row = sqlx::query_as::<_, MatchingRuleRow>(
+      r#"
+            SELECT id, company_id, name, position, counterparty_iban, description_pattern, min_amount, max_amount, direction, action, category_id, vendor_name, created_at
+            FROM matching_rules WHERE id = $1
+            "#,
+    )
+    .bind(id)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<MatchingRule>, ReportError> {
+    let rows = sqlx::query_as::<_, MatchingRuleRow>(
+      r#"
+            SELECT id, company_id, name, position, counterparty_iban, description_pattern, min_amount, max_amount, direction, action, category_id, vendor_name, created_at
+            FROM matching_rules WHERE company_id = $1 ORDER BY position, created_at
+            "#,
+    )
+    .bind(company_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn update_position(&self, id: Uuid, position: i32) -> Result<(), ReportError> {
+    sqlx::query("UPDATE matching_rules SET position = $2 WHERE id = $1")
+      .bind(id)
+      .bind(position)
+      .execute(&self.pool)
+      .await?;
+    Ok(())
+  }
+
+  async fn delete(&self, id: Uuid) -> Result<(), ReportError> {
+    sqlx::query("DELETE FROM matching_rules WHERE id = $1")
+      .bind(id)
+      .execute(&self.pool)
+      .await?;
+    Ok(())
+  }
+}
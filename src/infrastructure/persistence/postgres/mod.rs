@@ -1,33 +1,71 @@
 pub mod active_bank_account_repository;
 pub mod active_company_repository;
 pub mod bank_account_repository;
+pub mod bank_statement_repository;
 pub mod bank_transaction_repository;
+pub mod category_suggestion_repository;
 pub mod company_member_repository;
 pub mod company_repository;
 pub mod customer_repository;
+pub mod data_key_repository;
+pub mod document_repository;
+pub mod invoice_branding_repository;
+pub mod invoice_chain_repository;
+pub mod invoice_event_repository;
 pub mod invoice_line_item_repository;
 pub mod invoice_repository;
 pub mod invoice_template_line_item_repository;
 pub mod invoice_template_repository;
+pub mod invoice_unit_of_work;
+pub mod journal_entry_repository;
+pub mod ledger_account_repository;
 pub mod login_attempt_repository;
+pub mod match_allocation_repository;
+pub mod matching_rule_repository;
 pub mod monthly_report_repository;
 pub mod received_invoice_repository;
+pub mod report_event_repository;
+pub mod report_upload_repository;
+pub mod retention_repository;
 pub mod session_repository;
+pub mod transaction_category_repository;
+pub mod upload_job_repository;
 pub mod user_repository;
+pub mod vendor_repository;
 
 pub use active_bank_account_repository::PostgresActiveBankAccountRepository;
 pub use active_company_repository::PostgresActiveCompanyRepository;
 pub use bank_account_repository::PostgresBankAccountRepository;
+pub use bank_statement_repository::PostgresBankStatementRepository;
 pub use bank_transaction_repository::PostgresBankTransactionRepository;
+pub use category_suggestion_repository::PostgresCategorySuggestionRepository;
 pub use company_member_repository::PostgresCompanyMemberRepository;
 pub use company_repository::PostgresCompanyRepository;
 pub use customer_repository::PostgresCustomerRepository;
+pub use data_key_repository::PostgresDataKeyRepository;
+pub use document_repository::PostgresDocumentRepository;
+pub use invoice_branding_repository::PostgresInvoiceBrandingRepository;
+pub use invoice_chain_repository::PostgresInvoiceChainRepository;
+pub use invoice_event_repository::PostgresInvoiceEventRepository;
 pub use invoice_line_item_repository::PostgresInvoiceLineItemRepository;
 pub use invoice_repository::PostgresInvoiceRepository;
 pub use invoice_template_line_item_repository::PostgresInvoiceTemplateLineItemRepository;
 pub use invoice_template_repository::PostgresInvoiceTemplateRepository;
+pub use invoice_unit_of_work::PostgresInvoiceUnitOfWorkFactory;
+pub use journal_entry_repository::PostgresJournalEntryRepository;
+pub use ledger_account_repository::PostgresAccountRepository;
 pub use login_attempt_repository::PostgresLoginAttemptRepository;
+pub use match_allocation_repository::PostgresMatchAllocationRepository;
+pub use matching_rule_repository::PostgresMatchingRuleRepository;
 pub use monthly_report_repository::PostgresMonthlyReportRepository;
 pub use received_invoice_repository::PostgresReceivedInvoiceRepository;
+pub use report_event_repository::PostgresReportEventRepository;
+pub use report_upload_repository::PostgresReportUploadRepository;
+pub use retention_repository::{
+  PostgresBlockedDeletionRepository, PostgresRetentionPolicyRepository,
+};
 pub use session_repository::PostgresSessionRepository;
+pub use transaction_category_repository::PostgresTransactionCategoryRepository;
+pub use upload_job_repository::PostgresUploadJobRepository;
 pub use user_repository::PostgresUserRepository;
+pub use vendor_repository::PostgresVendorRepository;
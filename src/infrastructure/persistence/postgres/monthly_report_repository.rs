@@ -13,12 +13,12 @@ struct MonthlyReportRow {
   month: i32,
   year: i32,
   status: String,
-  bank_account_iban: Option<String>,
   total_incoming: Decimal,
   total_outgoing: Decimal,
   transaction_count: i32,
   matched_count: i32,
   drive_folder_id: Option<String>,
+  version: i32,
   created_at: DateTime<Utc>,
   updated_at: DateTime<Utc>,
 }
@@ -33,12 +33,12 @@ impl TryFrom<MonthlyReportRow> for MonthlyReport {
       month: row.month as u32,
       year: row.year,
       status: ReportStatus::try_from(row.status.as_str())?,
-      bank_account_iban: row.bank_account_iban,
       total_incoming: row.total_incoming,
       total_outgoing: row.total_outgoing,
       transaction_count: row.transaction_count,
       matched_count: row.matched_count,
       drive_folder_id: row.drive_folder_id,
+      version: row.version,
       created_at: row.created_at,
       updated_at: row.updated_at,
     })
@@ -60,9 +60,9 @@ impl MonthlyReportRepository for PostgresMonthlyReportRepository {
   async fn create(&self, report: MonthlyReport) -> Result<MonthlyReport, ReportError> {
     let row = sqlx::query_as::<_, MonthlyReportRow>(
             r#"
-            INSERT INTO monthly_reports (id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at)
+            INSERT INTO monthly_reports (id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at)
             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
-            RETURNING id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at
+            RETURNING id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at
             "#,
         )
         .bind(report.id)
@@ -70,12 +70,12 @@ impl MonthlyReportRepository for PostgresMonthlyReportRepository {
         .bind(report.month as i32)
         .bind(report.year)
         .bind(report.status.as_str())
-        .bind(report.bank_account_iban.as_deref())
         .bind(report.total_incoming)
         .bind(report.total_outgoing)
         .bind(report.transaction_count)
         .bind(report.matched_count)
         .bind(report.drive_folder_id.as_deref())
+        .bind(report.version)
         .bind(report.created_at)
         .bind(report.updated_at)
         .fetch_one(&self.pool)
@@ -87,7 +87,7 @@ impl MonthlyReportRepository for PostgresMonthlyReportRepository {
   async fn find_by_id(&self, id: Uuid) -> Result<Option<MonthlyReport>, ReportError> {
     let row = sqlx::query_as::<_, MonthlyReportRow>(
             r#"
-            SELECT id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at
+            SELECT id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at
             FROM monthly_reports WHERE id = $1
             "#,
         )
@@ -106,7 +106,7 @@ impl MonthlyReportRepository for PostgresMonthlyReportRepository {
   ) -> Result<Option<MonthlyReport>, ReportError> {
     let row = sqlx::query_as::<_, MonthlyReportRow>(
             r#"
-            SELECT id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at
+            SELECT id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at
             FROM monthly_reports WHERE company_id = $1 AND month = $2 AND year = $3
             "#,
         )
@@ -122,7 +122,7 @@ impl MonthlyReportRepository for PostgresMonthlyReportRepository {
   async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<MonthlyReport>, ReportError> {
     let rows = sqlx::query_as::<_, MonthlyReportRow>(
             r#"
-            SELECT id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at
+            SELECT id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at
             FROM monthly_reports WHERE company_id = $1 ORDER BY year DESC, month DESC
             "#,
         )
@@ -137,19 +137,19 @@ impl MonthlyReportRepository for PostgresMonthlyReportRepository {
     let row = sqlx::query_as::<_, MonthlyReportRow>(
             r#"
             UPDATE monthly_reports
-            SET status = $2, bank_account_iban = $3, total_incoming = $4, total_outgoing = $5, transaction_count = $6, matched_count = $7, drive_folder_id = $8, updated_at = $9
+            SET status = $2, total_incoming = $3, total_outgoing = $4, transaction_count = $5, matched_count = $6, drive_folder_id = $7, version = $8, updated_at = $9
             WHERE id = $1
-            RETURNING id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at
+            RETURNING id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at
             "#,
         )
         .bind(report.id)
         .bind(report.status.as_str())
-        .bind(report.bank_account_iban.as_deref())
         .bind(report.total_incoming)
         .bind(report.total_outgoing)
         .bind(report.transaction_count)
         .bind(report.matched_count)
         .bind(report.drive_folder_id.as_deref())
+        .bind(report.version)
         .bind(report.updated_at)
         .fetch_one(&self.pool)
         .await?;
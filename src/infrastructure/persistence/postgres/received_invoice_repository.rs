@@ -4,7 +4,9 @@ use rust_decimal::Decimal;
 use sqlx::{FromRow, PgPool};
 use uuid::Uuid;
 
-use crate::domain::report::{ReceivedInvoice, ReceivedInvoiceRepository, ReportError};
+use crate::domain::report::{
+  PayableStatus, ReceivedInvoice, ReceivedInvoiceRepository, ReportError,
+};
 
 #[derive(Debug, FromRow)]
 struct ReceivedInvoiceRow {
@@ -18,13 +20,22 @@ struct ReceivedInvoiceRow {
   pdf_path: String,
   pdf_drive_file_id: Option<String>,
   notes: Option<String>,
+  vat_amount: Decimal,
+  due_date: Option<NaiveDate>,
+  vendor_iban: Option<String>,
+  payment_reference: Option<String>,
+  status: String,
+  paid_on: Option<NaiveDate>,
+  vendor_id: Option<Uuid>,
   created_at: DateTime<Utc>,
   updated_at: DateTime<Utc>,
 }
 
-impl From<ReceivedInvoiceRow> for ReceivedInvoice {
-  fn from(row: ReceivedInvoiceRow) -> Self {
-    ReceivedInvoice {
+impl TryFrom<ReceivedInvoiceRow> for ReceivedInvoice {
+  type Error = ReportError;
+
+  fn try_from(row: ReceivedInvoiceRow) -> Result<Self, Self::Error> {
+    Ok(ReceivedInvoice {
       id: row.id,
       company_id: row.company_id,
       vendor_name: row.vendor_name,
@@ -35,9 +46,16 @@ impl From<ReceivedInvoiceRow> for ReceivedInvoice {
       pdf_path: row.pdf_path,
       pdf_drive_file_id: row.pdf_drive_file_id,
       notes: row.notes,
+      vat_amount: row.vat_amount,
+      due_date: row.due_date,
+      vendor_iban: row.vendor_iban,
+      payment_reference: row.payment_reference,
+      status: PayableStatus::try_from(row.status.as_str())?,
+      paid_on: row.paid_on,
+      vendor_id: row.vendor_id,
       created_at: row.created_at,
       updated_at: row.updated_at,
-    }
+    })
   }
 }
 
@@ -56,9 +74,9 @@ impl ReceivedInvoiceRepository for PostgresReceivedInvoiceRepository {
   async fn create(&self, invoice: ReceivedInvoice) -> Result<ReceivedInvoice, ReportError> {
     let row = sqlx::query_as::<_, ReceivedInvoiceRow>(
             r#"
-            INSERT INTO received_invoices (id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
-            RETURNING id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at
+            INSERT INTO received_invoices (id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+            RETURNING id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
             "#,
         )
         .bind(invoice.id)
@@ -71,18 +89,25 @@ impl ReceivedInvoiceRepository for PostgresReceivedInvoiceRepository {
         .bind(&invoice.pdf_path)
         .bind(invoice.pdf_drive_file_id.as_deref())
         .bind(invoice.notes.as_deref())
+        .bind(invoice.vat_amount)
+        .bind(invoice.due_date)
+        .bind(invoice.vendor_iban.as_deref())
+        .bind(invoice.payment_reference.as_deref())
+        .bind(invoice.status.as_str())
+        .bind(invoice.paid_on)
+        .bind(invoice.vendor_id)
         .bind(invoice.created_at)
         .bind(invoice.updated_at)
         .fetch_one(&self.pool)
         .await?;
 
-    Ok(row.into())
+    row.try_into()
   }
 
   async fn find_by_id(&self, id: Uuid) -> Result<Option<ReceivedInvoice>, ReportError> {
     let row = sqlx::query_as::<_, ReceivedInvoiceRow>(
             r#"
-            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
             FROM received_invoices WHERE id = $1
             "#,
         )
@@ -90,7 +115,7 @@ impl ReceivedInvoiceRepository for PostgresReceivedInvoiceRepository {
         .fetch_optional(&self.pool)
         .await?;
 
-    Ok(row.map(|r| r.into()))
+    row.map(|r| r.try_into()).transpose()
   }
 
   async fn find_by_company_id(
@@ -99,7 +124,7 @@ impl ReceivedInvoiceRepository for PostgresReceivedInvoiceRepository {
   ) -> Result<Vec<ReceivedInvoice>, ReportError> {
     let rows = sqlx::query_as::<_, ReceivedInvoiceRow>(
             r#"
-            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
             FROM received_invoices WHERE company_id = $1 ORDER BY created_at DESC
             "#,
         )
@@ -107,7 +132,7 @@ impl ReceivedInvoiceRepository for PostgresReceivedInvoiceRepository {
         .fetch_all(&self.pool)
         .await?;
 
-    Ok(rows.into_iter().map(|r| r.into()).collect())
+    rows.into_iter().map(|r| r.try_into()).collect()
   }
 
   async fn find_by_company_and_date_range(
@@ -118,7 +143,7 @@ impl ReceivedInvoiceRepository for PostgresReceivedInvoiceRepository {
   ) -> Result<Vec<ReceivedInvoice>, ReportError> {
     let rows = sqlx::query_as::<_, ReceivedInvoiceRow>(
             r#"
-            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
             FROM received_invoices
             WHERE company_id = $1 AND invoice_date >= $2 AND invoice_date <= $3
             ORDER BY invoice_date
@@ -130,7 +155,7 @@ impl ReceivedInvoiceRepository for PostgresReceivedInvoiceRepository {
         .fetch_all(&self.pool)
         .await?;
 
-    Ok(rows.into_iter().map(|r| r.into()).collect())
+    rows.into_iter().map(|r| r.try_into()).collect()
   }
 
   async fn find_unmatched_by_company(
@@ -139,10 +164,10 @@ impl ReceivedInvoiceRepository for PostgresReceivedInvoiceRepository {
   ) -> Result<Vec<ReceivedInvoice>, ReportError> {
     let rows = sqlx::query_as::<_, ReceivedInvoiceRow>(
             r#"
-            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
             FROM received_invoices
             WHERE company_id = $1
-              AND id NOT IN (SELECT matched_received_invoice_id FROM bank_transactions WHERE matched_received_invoice_id IS NOT NULL)
+              AND NOT EXISTS (SELECT 1 FROM match_allocations a WHERE a.received_invoice_id = received_invoices.id)
             ORDER BY created_at DESC
             "#,
         )
@@ -150,7 +175,64 @@ impl ReceivedInvoiceRepository for PostgresReceivedInvoiceRepository {
         .fetch_all(&self.pool)
         .await?;
 
-    Ok(rows.into_iter().map(|r| r.into()).collect())
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn find_open_by_company(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<ReceivedInvoice>, ReportError> {
+    let rows = sqlx::query_as::<_, ReceivedInvoiceRow>(
+            r#"
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
+            FROM received_invoices
+            WHERE company_id = $1 AND status <> 'paid'
+            ORDER BY due_date NULLS LAST, created_at
+            "#,
+        )
+        .bind(company_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn find_by_vendor_id(&self, vendor_id: Uuid) -> Result<Vec<ReceivedInvoice>, ReportError> {
+    let rows = sqlx::query_as::<_, ReceivedInvoiceRow>(
+            r#"
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
+            FROM received_invoices WHERE vendor_id = $1 ORDER BY created_at DESC
+            "#,
+        )
+        .bind(vendor_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn update(&self, invoice: ReceivedInvoice) -> Result<ReceivedInvoice, ReportError> {
+    let row = sqlx::query_as::<_, ReceivedInvoiceRow>(
+            r#"
+            UPDATE received_invoices
+            SET vat_amount = $2, due_date = $3, vendor_iban = $4, payment_reference = $5, status = $6, paid_on = $7, vendor_id = $8, updated_at = $9
+            WHERE id = $1
+            RETURNING id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
+            "#,
+        )
+        .bind(invoice.id)
+        .bind(invoice.vat_amount)
+        .bind(invoice.due_date)
+        .bind(invoice.vendor_iban.as_deref())
+        .bind(invoice.payment_reference.as_deref())
+        .bind(invoice.status.as_str())
+        .bind(invoice.paid_on)
+        .bind(invoice.vendor_id)
+        .bind(invoice.updated_at)
+        .fetch_optional(&self.pool)
+        .await?;
+
+    row.ok_or(ReportError::ReceivedInvoiceNotFound)?.try_into()
   }
 
   async fn delete(&self, id: Uuid) -> Result<(), ReportError> {
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::report::{ReportError, ReportEvent, ReportEventKind, ReportEventRepository};
+
+#[derive(Debug, FromRow)]
+struct ReportEventRow {
+  id: Uuid,
+  report_id: Uuid,
+  company_id: Uuid,
+  user_id: Option<Uuid>,
+  kind: String,
+  version: i32,
+  reason: Option<String>,
+  created_at: DateTime<Utc>,
+}
+
+impl TryFrom<ReportEventRow> for ReportEvent {
+  type Error = ReportError;
+
+  fn try_from(row: ReportEventRow) -> Result<Self, Self::Error> {
+    Ok(ReportEvent {
+      id: row.id,
+      report_id: row.report_id,
+      company_id: row.company_id,
+      user_id: row.user_id,
+      kind: ReportEventKind::try_from(row.kind.as_str())?,
+      version: row.version,
+      reason: row.reason,
+      created_at: row.created_at,
+    })
+  }
+}
+
+pub struct PostgresReportEventRepository {
+  pool: PgPool,
+}
+
+impl PostgresReportEventRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl ReportEventRepository for PostgresReportEventRepository {
+  async fn append(&self, event: ReportEvent) -> Result<ReportEvent, ReportError> {
+    let row = sqlx::query_as::<_, ReportEventRow>(
+      r#"
+      INSERT INTO report_events (id, report_id, company_id, user_id, kind, version, reason, created_at)
+      VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+      RETURNING id, report_id, company_id, user_id, kind, version, reason, created_at
+      "#,
+    )
+    .bind(event.id)
+    .bind(event.report_id)
+    .bind(event.company_id)
+    .bind(event.user_id)
+    .bind(event.kind.as_str())
+    .bind(event.version)
+    .bind(event.reason.as_deref())
+    .bind(event.created_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<ReportEvent>, ReportError> {
+    let rows = sqlx::query_as::<_, ReportEventRow>(
+      r#"
+      SELECT id, report_id, company_id, user_id, kind, version, reason, created_at
+      FROM report_events
+      WHERE report_id = $1
+      ORDER BY created_at DESC
+      "#,
+    )
+    .bind(report_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+}
@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::report::{ReportError, ReportUpload, ReportUploadRepository};
+
+#[derive(Debug, FromRow)]
+struct ReportUploadRow {
+  report_id: Uuid,
+  path: String,
+  cloud_id: String,
+  content_hash: Option<String>,
+  version: i32,
+  uploaded_at: DateTime<Utc>,
+}
+
+impl From<ReportUploadRow> for ReportUpload {
+  fn from(row: ReportUploadRow) -> Self {
+    ReportUpload {
+      report_id: row.report_id,
+      path: row.path,
+      cloud_id: row.cloud_id,
+      content_hash: row.content_hash,
+      version: row.version,
+      uploaded_at: row.uploaded_at,
+    }
+  }
+}
+
+pub struct PostgresReportUploadRepository {
+  pool: PgPool,
+}
+
+impl PostgresReportUploadRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl ReportUploadRepository for PostgresReportUploadRepository {
+  async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<ReportUpload>, ReportError> {
+    let rows = sqlx::query_as::<_, ReportUploadRow>(
+      r#"
+      SELECT report_id, path, cloud_id, content_hash, version, uploaded_at
+      FROM report_uploads
+      WHERE report_id = $1
+      ORDER BY path
+      "#,
+    )
+    .bind(report_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(rows.into_iter().map(Into::into).collect())
+  }
+
+  async fn upsert(&self, upload: ReportUpload) -> Result<ReportUpload, ReportError> {
+    let row = sqlx::query_as::<_, ReportUploadRow>(
+      r#"
+      INSERT INTO report_uploads (report_id, path, cloud_id, content_hash, version, uploaded_at)
+      VALUES ($1, $2, $3, $4, $5, $6)
+      ON CONFLICT (report_id, path) DO UPDATE
+      SET cloud_id = EXCLUDED.cloud_id, content_hash = EXCLUDED.content_hash,
+          version = EXCLUDED.version, uploaded_at = EXCLUDED.uploaded_at
+      RETURNING report_id, path, cloud_id, content_hash, version, uploaded_at
+      "#,
+    )
+    .bind(upload.report_id)
+    .bind(&upload.path)
+    .bind(&upload.cloud_id)
+    .bind(upload.content_hash.as_deref())
+    .bind(upload.version)
+    .bind(upload.uploaded_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    Ok(row.into())
+  }
+
+  async fn delete(&self, report_id: Uuid, path: &str) -> Result<(), ReportError> {
+    sqlx::query("DELETE FROM report_uploads WHERE report_id = $1 AND path = $2")
+      .bind(report_id)
+      .bind(path)
+      .execute(&self.pool)
+      .await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,168 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::retention::{
+  BlockedDeletion, BlockedDeletionRepository, RetainedRecord, RetentionError, RetentionPolicy,
+  RetentionPolicyRepository,
+};
+
+#[derive(Debug, FromRow)]
+struct RetentionPolicyRow {
+  company_id: Uuid,
+  retention_years: i32,
+  updated_by: Option<Uuid>,
+  updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow)]
+struct BlockedDeletionRow {
+  id: Uuid,
+  company_id: Uuid,
+  record_type: String,
+  record_id: Uuid,
+  label: String,
+  user_id: Option<Uuid>,
+  retained_until: NaiveDate,
+  attempted_at: DateTime<Utc>,
+}
+
+impl TryFrom<RetentionPolicyRow> for RetentionPolicy {
+  type Error = RetentionError;
+
+  fn try_from(row: RetentionPolicyRow) -> Result<Self, Self::Error> {
+    Ok(RetentionPolicy {
+      company_id: row.company_id,
+      retention_years: u32::try_from(row.retention_years)
+        .map_err(|e| RetentionError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      updated_by: row.updated_by,
+      updated_at: row.updated_at,
+    })
+  }
+}
+
+impl TryFrom<BlockedDeletionRow> for BlockedDeletion {
+  type Error = RetentionError;
+
+  fn try_from(row: BlockedDeletionRow) -> Result<Self, Self::Error> {
+    Ok(BlockedDeletion {
+      id: row.id,
+      company_id: row.company_id,
+      record: RetainedRecord::try_from(row.record_type.as_str())?,
+      record_id: row.record_id,
+      label: row.label,
+      user_id: row.user_id,
+      retained_until: row.retained_until,
+      attempted_at: row.attempted_at,
+    })
+  }
+}
+
+pub struct PostgresRetentionPolicyRepository {
+  pool: PgPool,
+}
+
+impl PostgresRetentionPolicyRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl RetentionPolicyRepository for PostgresRetentionPolicyRepository {
+  async fn find(&self, company_id: Uuid) -> Result<Option<RetentionPolicy>, RetentionError> {
+    let row = sqlx::query_as::<_, RetentionPolicyRow>(
+      r#"
+      SELECT company_id, retention_years, updated_by, updated_at
+      FROM retention_policies
+      WHERE company_id = $1
+      "#,
+    )
+    .bind(company_id)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn save(&self, policy: RetentionPolicy) -> Result<RetentionPolicy, RetentionError> {
+    let row = sqlx::query_as::<_, RetentionPolicyRow>(
+      r#"
+      INSERT INTO retention_policies (company_id, retention_years, updated_by, updated_at)
+      VALUES ($1, $2, $3, $4)
+      ON CONFLICT (company_id) DO UPDATE SET
+        retention_years = EXCLUDED.retention_years,
+        updated_by = EXCLUDED.updated_by,
+        updated_at = EXCLUDED.updated_at
+      RETURNING company_id, retention_years, updated_by, updated_at
+      "#,
+    )
+    .bind(policy.company_id)
+    .bind(policy.retention_years as i32)
+    .bind(policy.updated_by)
+    .bind(policy.updated_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+}
+
+pub struct PostgresBlockedDeletionRepository {
+  pool: PgPool,
+}
+
+impl PostgresBlockedDeletionRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl BlockedDeletionRepository for PostgresBlockedDeletionRepository {
+  async fn create(&self, attempt: BlockedDeletion) -> Result<BlockedDeletion, RetentionError> {
+    let row = sqlx::query_as::<_, BlockedDeletionRow>(
+      r#"
+      INSERT INTO retention_blocked_deletions (id, company_id, record_type, record_id, label, user_id, retained_until, attempted_at)
+      VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+      RETURNING id, company_id, record_type, record_id, label, user_id, retained_until, attempted_at
+      "#,
+    )
+    .bind(attempt.id)
+    .bind(attempt.company_id)
+    .bind(attempt.record.as_str())
+    .bind(attempt.record_id)
+    .bind(&attempt.label)
+    .bind(attempt.user_id)
+    .bind(attempt.retained_until)
+    .bind(attempt.attempted_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+    limit: i64,
+  ) -> Result<Vec<BlockedDeletion>, RetentionError> {
+    let rows = sqlx::query_as::<_, BlockedDeletionRow>(
+      r#"
+      SELECT id, company_id, record_type, record_id, label, user_id, retained_until, attempted_at
+      FROM retention_blocked_deletions
+      WHERE company_id = $1
+      ORDER BY attempted_at DESC
+      LIMIT $2
+      "#,
+    )
+    .bind(company_id)
+    .bind(limit)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+}
@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::report::{ReportError, TransactionCategory, TransactionCategoryRepository};
+
+#[derive(Debug, FromRow)]
+struct TransactionCategoryRow {
+  id: Uuid,
+  company_id: Uuid,
+  name: String,
+  created_at: DateTime<Utc>,
+}
+
+impl From<TransactionCategoryRow> for TransactionCategory {
+  fn from(row: TransactionCategoryRow) -> Self {
+    TransactionCategory {
+      id: row.id,
+      company_id: row.company_id,
+      name: row.name,
+      created_at: row.created_at,
+    }
+  }
+}
+
+pub struct PostgresTransactionCategoryRepository {
+  pool: PgPool,
+}
+
+impl PostgresTransactionCategoryRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl TransactionCategoryRepository for PostgresTransactionCategoryRepository {
+  async fn create(
+    &self,
+    category: TransactionCategory,
+  ) -> Result<TransactionCategory, ReportError> {
+    let row = sqlx::query_as::<_, TransactionCategoryRow>(
+      r#"
+            INSERT INTO transaction_categories (id, company_id, name, created_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, company_id, name, created_at
+            "#,
+    )
+    .bind(category.id)
+    .bind(category.company_id)
+    .bind(&category.name)
+    .bind(category.created_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    Ok(row.into())
+  }
+
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<TransactionCategory>, ReportError> {
+    let row = sqlx::query_as::<_, TransactionCategoryRow>(
+      "SELECT id, company_id, name, created_at FROM transaction_categories WHERE id = $1",
+    )
+    .bind(id)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    Ok(row.map(Into::into))
+  }
+
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<TransactionCategory>, ReportError> {
+    let rows = sqlx::query_as::<_, TransactionCategoryRow>(
+      r#"
+            SELECT id, company_id, name, created_at
+            FROM transaction_categories WHERE company_id = $1 ORDER BY name
+            "#,
+    )
+    .bind(company_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(rows.into_iter().map(Into::into).collect())
+  }
+
+  async fn delete(&self, id: Uuid) -> Result<(), ReportError> {
+    sqlx::query("DELETE FROM transaction_categories WHERE id = $1")
+      .bind(id)
+      .execute(&self.pool)
+      .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,175 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde_json::Value as JsonValue;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::report::{ReportError, Vendor, VendorRepository};
+
+#[derive(Debug, FromRow)]
+struct VendorRow {
+  id: Uuid,
+  company_id: Uuid,
+  name: String,
+  registry_code: Option<String>,
+  vat_number: Option<String>,
+  ibans: JsonValue,
+  default_category_id: Option<Uuid>,
+  default_vat_rate: Option<Decimal>,
+  created_at: DateTime<Utc>,
+  updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<VendorRow> for Vendor {
+  type Error = ReportError;
+
+  fn try_from(row: VendorRow) -> Result<Self, Self::Error> {
+    Ok(Vendor {
+      id: row.id,
+      company_id: row.company_id,
+      name: row.name,
+      registry_code: row.registry_code,
+      vat_number: row.vat_number,
+      ibans: serde_json::from_value(row.ibans)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      default_category_id: row.default_category_id,
+      default_vat_rate: row.default_vat_rate,
+      created_at: row.created_at,
+      updated_at: row.updated_at,
+    })
+  }
+}
+
+pub struct PostgresVendorRepository {
+  pool: PgPool,
+}
+
+impl PostgresVendorRepository {
+  pub fn new(pool: PgPool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl VendorRepository for PostgresVendorRepository {
+  async fn create(&self, vendor: Vendor) -> Result<Vendor, ReportError> {
+    let row = sqlx::query_as::<_, VendorRow>(
+      r#"
+            INSERT INTO vendors (id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at
+            "#,
+    )
+    .bind(vendor.id)
+    .bind(vendor.company_id)
+    .bind(&vendor.name)
+    .bind(&vendor.registry_code)
+    .bind(&vendor.vat_number)
+    .bind(serde_json::json!(vendor.ibans))
+    .bind(vendor.default_category_id)
+    .bind(vendor.default_vat_rate)
+    .bind(vendor.created_at)
+    .bind(vendor.updated_at)
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<Vendor>, ReportError> {
+    let row = sqlx::query_as::<_, VendorRow>(
+      r#"
+            SELECT id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at
+            FROM vendors WHERE id = $1
+            "#,
+    )
+    .bind(id)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<Vendor>, ReportError> {
+    let rows = sqlx::query_as::<_, VendorRow>(
+      r#"
+            SELECT id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at
+            FROM vendors WHERE company_id = $1 ORDER BY LOWER(name)
+            "#,
+    )
+    .bind(company_id)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn update(&self, vendor: Vendor) -> Result<Vendor, ReportError> {
+    let row = sqlx::query_as::<_, VendorRow>(
+      r#"
+            UPDATE vendors
+            SET name = $2, registry_code = $3, vat_number = $4, ibans = $5, default_category_id = $6, default_vat_rate = $7, updated_at = $8
+            WHERE id = $1
+            RETURNING id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at
+            "#,
+    )
+    .bind(vendor.id)
+    .bind(&vendor.name)
+    .bind(&vendor.registry_code)
+    .bind(&vendor.vat_number)
+    .bind(serde_json::json!(vendor.ibans))
+    .bind(vendor.default_category_id)
+    .bind(vendor.default_vat_rate)
+    .bind(vendor.updated_at)
+    .fetch_optional(&self.pool)
+    .await?
+    .ok_or(ReportError::VendorNotFound)?;
+
+    row.try_into()
+  }
+
+  async fn merge(&self, from_id: Uuid, into: Vendor) -> Result<Vendor, ReportError> {
+    let mut tx = self.pool.begin().await?;
+
+    sqlx::query("UPDATE received_invoices SET vendor_id = $2 WHERE vendor_id = $1")
+      .bind(from_id)
+      .bind(into.id)
+      .execute(&mut *tx)
+      .await?;
+    sqlx::query("UPDATE bank_transactions SET vendor_id = $2 WHERE vendor_id = $1")
+      .bind(from_id)
+      .bind(into.id)
+      .execute(&mut *tx)
+      .await?;
+    // Delete first, so `into` can take over the registry code
+    sqlx::query("DELETE FROM vendors WHERE id = $1")
+      .bind(from_id)
+      .execute(&mut *tx)
+      .await?;
+
+    let row = sqlx::query_as::<_, VendorRow>(
+      r#"
+            UPDATE vendors
+            SET name = $2, registry_code = $3, vat_number = $4, ibans = $5, default_category_id = $6, default_vat_rate = $7, updated_at = $8
+            WHERE id = $1
+            RETURNING id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at
+            "#,
+    )
+    .bind(into.id)
+    .bind(&into.name)
+    .bind(&into.registry_code)
+    .bind(&into.vat_number)
+    .bind(serde_json::json!(into.ibans))
+    .bind(into.default_category_id)
+    .bind(into.default_vat_rate)
+    .bind(into.updated_at)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ReportError::VendorNotFound)?;
+
+    tx.commit().await?;
+    row.try_into()
+  }
+}
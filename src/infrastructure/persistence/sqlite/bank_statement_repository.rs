@@ -0,0 +1,147 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, SqlitePool};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::report::{BankStatement, BankStatementRepository, ReportError};
+
+#[derive(Debug, FromRow)]
+struct BankStatementRow {
+  id: String,
+  report_id: String,
+  bank_account_id: Option<String>,
+  bank_account_iban: String,
+  total_incoming: String,
+  total_outgoing: String,
+  transaction_count: i32,
+  opening_balance: Option<String>,
+  closing_balance: Option<String>,
+  period_start: String,
+  period_end: String,
+  duplicate_count: i32,
+  file_path: Option<String>,
+  file_name: Option<String>,
+  created_at: String,
+}
+
+impl TryFrom<BankStatementRow> for BankStatement {
+  type Error = ReportError;
+
+  fn try_from(row: BankStatementRow) -> Result<Self, Self::Error> {
+    Ok(BankStatement {
+      id: Uuid::parse_str(&row.id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      report_id: Uuid::parse_str(&row.report_id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      bank_account_id: row
+        .bank_account_id
+        .map(|s| Uuid::parse_str(&s))
+        .transpose()
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      bank_account_iban: row.bank_account_iban,
+      total_incoming: Decimal::from_str(&row.total_incoming)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      total_outgoing: Decimal::from_str(&row.total_outgoing)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      transaction_count: row.transaction_count,
+      opening_balance: row
+        .opening_balance
+        .as_deref()
+        .map(Decimal::from_str)
+        .transpose()
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      closing_balance: row
+        .closing_balance
+        .as_deref()
+        .map(Decimal::from_str)
+        .transpose()
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      period_start: NaiveDate::parse_from_str(&row.period_start, "%Y-%m-%d")
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      period_end: NaiveDate::parse_from_str(&row.period_end, "%Y-%m-%d")
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      duplicate_count: row.duplicate_count,
+      file_path: row.file_path,
+      file_name: row.file_name,
+      created_at: DateTime::parse_from_rfc3339(&row.created_at)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+    })
+  }
+}
+
+pub struct SqliteBankStatementRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteBankStatementRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl BankStatementRepository for SqliteBankStatementRepository {
+  async fn create(&self, statement: BankStatement) -> Result<BankStatement, ReportError> {
+    let row = sqlx::query_as::<_, BankStatementRow>(
+      r#"
+            INSERT INTO bank_statements (id, report_id, bank_account_id, bank_account_iban, total_incoming, total_outgoing, transaction_count, opening_balance, closing_balance, period_start, period_end, duplicate_count, file_path, file_name, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+            RETURNING id, report_id, bank_account_id, bank_account_iban, total_incoming, total_outgoing, transaction_count, opening_balance, closing_balance, period_start, period_end, duplicate_count, file_path, file_name, created_at
+            "#,
+    )
+    .bind(statement.id.to_string())
+    .bind(statement.report_id.to_string())
+    .bind(statement.bank_account_id.map(|id| id.to_string()))
+    .bind(&statement.bank_account_iban)
+    .bind(statement.total_incoming.to_string())
+    .bind(statement.total_outgoing.to_string())
+    .bind(statement.transaction_count)
+    .bind(statement.opening_balance.map(|b| b.to_string()))
+    .bind(statement.closing_balance.map(|b| b.to_string()))
+    .bind(statement.period_start.format("%Y-%m-%d").to_string())
+    .bind(statement.period_end.format("%Y-%m-%d").to_string())
+    .bind(statement.duplicate_count)
+    .bind(&statement.file_path)
+    .bind(&statement.file_name)
+    .bind(statement.created_at.to_rfc3339())
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<BankStatement>, ReportError> {
+    let rows = sqlx::query_as::<_, BankStatementRow>(
+      r#"
+            SELECT id, report_id, bank_account_id, bank_account_iban, total_incoming, total_outgoing, transaction_count, opening_balance, closing_balance, period_start, period_end, duplicate_count, file_path, file_name, created_at
+            FROM bank_statements WHERE report_id = ?1 ORDER BY bank_account_iban
+            "#,
+    )
+    .bind(report_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<BankStatement>, ReportError> {
+    let rows = sqlx::query_as::<_, BankStatementRow>(
+      r#"
+            SELECT s.id, s.report_id, s.bank_account_id, s.bank_account_iban, s.total_incoming, s.total_outgoing, s.transaction_count, s.opening_balance, s.closing_balance, s.period_start, s.period_end, s.duplicate_count, s.file_path, s.file_name, s.created_at
+            FROM bank_statements s
+            JOIN monthly_reports r ON r.id = s.report_id
+            WHERE r.company_id = ?1
+            ORDER BY s.bank_account_iban, s.period_start
+            "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+}
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::report::{
+  CategoryMatchField, CategorySuggestion, CategorySuggestionRepository, ReportError,
+  TransactionDirection,
+};
+
+#[derive(Debug, FromRow)]
+struct CategorySuggestionRow {
+  company_id: String,
+  direction: String,
+  match_field: String,
+  match_value: String,
+  category_id: String,
+  times_used: i32,
+  updated_at: String,
+}
+
+impl TryFrom<CategorySuggestionRow> for CategorySuggestion {
+  type Error = ReportError;
+
+  fn try_from(row: CategorySuggestionRow) -> Result<Self, Self::Error> {
+    Ok(CategorySuggestion {
+      company_id: Uuid::parse_str(&row.company_id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      direction: TransactionDirection::try_from(row.direction.as_str())?,
+      field: CategoryMatchField::try_from(row.match_field.as_str())?,
+      value: row.match_value,
+      category_id: Uuid::parse_str(&row.category_id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      times_used: row.times_used,
+      updated_at: DateTime::parse_from_rfc3339(&row.updated_at)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+    })
+  }
+}
+
+pub struct SqliteCategorySuggestionRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteCategorySuggestionRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl CategorySuggestionRepository for SqliteCategorySuggestionRepository {
+  async fn record(&self, suggestion: CategorySuggestion) -> Result<(), ReportError> {
+    sqlx::query(
+      r#"
+            INSERT INTO transaction_category_suggestions (company_id, direction, match_field, match_value, category_id, times_used, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            ON CONFLICT (company_id, direction, match_field, match_value) DO UPDATE
+            SET times_used = CASE
+                    WHEN transaction_category_suggestions.category_id = excluded.category_id
+                    THEN transaction_category_suggestions.times_used + 1
+                    ELSE excluded.times_used
+                END,
+                category_id = excluded.category_id,
+                updated_at = excluded.updated_at
+            "#,
+    )
+    .bind(suggestion.company_id.to_string())
+    .bind(suggestion.direction.as_str())
+    .bind(suggestion.field.as_str())
+    .bind(&suggestion.value)
+    .bind(suggestion.category_id.to_string())
+    .bind(suggestion.times_used)
+    .bind(suggestion.updated_at.to_rfc3339())
+    .execute(&self.pool)
+    .await?;
+    Ok(())
+  }
+
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<CategorySuggestion>, ReportError> {
+    let rows = sqlx::query_as::<_, CategorySuggestionRow>(
+      r#"
+            SELECT company_id, direction, match_field, match_value, category_id, times_used, updated_at
+            FROM transaction_category_suggestions WHERE company_id = ?1
+            "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+}
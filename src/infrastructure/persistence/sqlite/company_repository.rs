@@ -192,6 +192,20 @@ impl CompanyRepository for SqliteCompanyRepository {
     row.map(|r| r.try_into()).transpose()
   }
 
+  async fn find_all(&self) -> Result<Vec<Company>, CompanyError> {
+    let rows = sqlx::query_as::<_, CompanyRow>(
+      r#"
+      SELECT id, name, email, phone, address, tax_id, vat_number, google_drive_folder_id, storage_provider, storage_config, oauth_access_token, oauth_refresh_token, oauth_token_expires_at, oauth_connected_by, oauth_connected_at, reports_folder_id, created_at, updated_at
+      FROM companies
+      ORDER BY created_at
+      "#,
+    )
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
   async fn update(&self, company: Company) -> Result<Company, CompanyError> {
     let address_json = company
       .address
@@ -232,11 +246,29 @@ impl CompanyRepository for SqliteCompanyRepository {
   }
 
   async fn delete(&self, id: Uuid) -> Result<(), CompanyError> {
+    let mut tx = self.pool.begin().await?;
+
+    // Matched invoices can't be deleted, so their allocations go first
+    sqlx::query(
+      r#"
+      DELETE FROM match_allocations
+      WHERE transaction_id IN (
+        SELECT t.id FROM bank_transactions t
+        JOIN monthly_reports r ON r.id = t.report_id
+        WHERE r.company_id = ?1
+      )
+      "#,
+    )
+    .bind(id.to_string())
+    .execute(&mut *tx)
+    .await?;
+
     sqlx::query("DELETE FROM companies WHERE id = ?1")
       .bind(id.to_string())
-      .execute(&self.pool)
+      .execute(&mut *tx)
       .await?;
 
+    tx.commit().await?;
     Ok(())
   }
 
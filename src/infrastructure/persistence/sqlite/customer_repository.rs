@@ -1,10 +1,11 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use sqlx::{FromRow, SqlitePool};
+use std::str::FromStr;
 use uuid::Uuid;
 
 use crate::domain::invoice::{
-  Customer, CustomerName, errors::InvoiceError, ports::CustomerRepository,
+  Customer, CustomerName, InvoiceLanguage, errors::InvoiceError, ports::CustomerRepository,
   value_objects::CustomerAddress,
 };
 
@@ -14,6 +15,7 @@ struct CustomerRow {
   company_id: String,
   name: String,
   address: Option<String>,
+  default_language: String,
   created_at: String,
   updated_at: String,
   archived_at: Option<String>,
@@ -33,6 +35,7 @@ fn parse_customer_row(row: CustomerRow) -> Result<Customer, InvoiceError> {
   } else {
     None
   };
+  let default_language = InvoiceLanguage::from_str(&row.default_language)?;
   let created_at = DateTime::parse_from_rfc3339(&row.created_at)
     .map(|dt| dt.with_timezone(&Utc))
     .map_err(|e| InvoiceError::Internal(format!("Failed to parse datetime: {}", e)))?;
@@ -53,6 +56,7 @@ fn parse_customer_row(row: CustomerRow) -> Result<Customer, InvoiceError> {
     company_id,
     name,
     address,
+    default_language,
     created_at,
     updated_at,
     archived_at,
@@ -81,15 +85,16 @@ impl CustomerRepository for SqliteCustomerRepository {
 
     let row = sqlx::query_as::<_, CustomerRow>(
       r#"
-      INSERT INTO customers (id, company_id, name, address, created_at, updated_at, archived_at)
-      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
-      RETURNING id, company_id, name, address, created_at, updated_at, archived_at
+      INSERT INTO customers (id, company_id, name, address, default_language, created_at, updated_at, archived_at)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+      RETURNING id, company_id, name, address, default_language, created_at, updated_at, archived_at
       "#,
     )
     .bind(customer.id.to_string())
     .bind(customer.company_id.to_string())
     .bind(customer.name.value())
     .bind(address_json.as_deref())
+    .bind(customer.default_language.as_str())
     .bind(customer.created_at.to_rfc3339())
     .bind(customer.updated_at.to_rfc3339())
     .bind(customer.archived_at.map(|dt| dt.to_rfc3339()))
@@ -110,14 +115,15 @@ impl CustomerRepository for SqliteCustomerRepository {
     let row = sqlx::query_as::<_, CustomerRow>(
       r#"
       UPDATE customers
-      SET name = ?2, address = ?3, updated_at = ?4, archived_at = ?5
+      SET name = ?2, address = ?3, default_language = ?4, updated_at = ?5, archived_at = ?6
       WHERE id = ?1
-      RETURNING id, company_id, name, address, created_at, updated_at, archived_at
+      RETURNING id, company_id, name, address, default_language, created_at, updated_at, archived_at
       "#,
     )
     .bind(customer.id.to_string())
     .bind(customer.name.value())
     .bind(address_json.as_deref())
+    .bind(customer.default_language.as_str())
     .bind(customer.updated_at.to_rfc3339())
     .bind(customer.archived_at.map(|dt| dt.to_rfc3339()))
     .fetch_one(&self.pool)
@@ -129,7 +135,7 @@ impl CustomerRepository for SqliteCustomerRepository {
   async fn find_by_id(&self, id: Uuid) -> Result<Option<Customer>, InvoiceError> {
     let row = sqlx::query_as::<_, CustomerRow>(
       r#"
-      SELECT id, company_id, name, address, created_at, updated_at, archived_at
+      SELECT id, company_id, name, address, default_language, created_at, updated_at, archived_at
       FROM customers
       WHERE id = ?1
       "#,
@@ -144,7 +150,7 @@ impl CustomerRepository for SqliteCustomerRepository {
   async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<Customer>, InvoiceError> {
     let rows = sqlx::query_as::<_, CustomerRow>(
       r#"
-      SELECT id, company_id, name, address, created_at, updated_at, archived_at
+      SELECT id, company_id, name, address, default_language, created_at, updated_at, archived_at
       FROM customers
       WHERE company_id = ?1
       ORDER BY name ASC
@@ -163,7 +169,7 @@ impl CustomerRepository for SqliteCustomerRepository {
   ) -> Result<Vec<Customer>, InvoiceError> {
     let rows = sqlx::query_as::<_, CustomerRow>(
       r#"
-      SELECT id, company_id, name, address, created_at, updated_at, archived_at
+      SELECT id, company_id, name, address, default_language, created_at, updated_at, archived_at
       FROM customers
       WHERE company_id = ?1 AND archived_at IS NULL
       ORDER BY name ASC
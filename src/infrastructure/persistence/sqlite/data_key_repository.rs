@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use chrono::{SecondsFormat, Utc};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::domain::document::{DataKeyRepository, DocumentError};
+
+pub struct SqliteDataKeyRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteDataKeyRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl DataKeyRepository for SqliteDataKeyRepository {
+  async fn find(&self, company_id: Uuid) -> Result<Option<String>, DocumentError> {
+    let wrapped_key = sqlx::query_scalar::<_, String>(
+      r#"
+      SELECT wrapped_key
+      FROM company_data_keys
+      WHERE company_id = ?1
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_optional(&self.pool)
+    .await?;
+
+    Ok(wrapped_key)
+  }
+
+  async fn create_if_absent(
+    &self,
+    company_id: Uuid,
+    wrapped_key: String,
+  ) -> Result<String, DocumentError> {
+    sqlx::query(
+      r#"
+      INSERT INTO company_data_keys (company_id, wrapped_key, created_at)
+      VALUES (?1, ?2, ?3)
+      ON CONFLICT (company_id) DO NOTHING
+      "#,
+    )
+    .bind(company_id.to_string())
+    .bind(&wrapped_key)
+    .bind(Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true))
+    .execute(&self.pool)
+    .await?;
+
+    let stored = sqlx::query_scalar::<_, String>(
+      r#"
+      SELECT wrapped_key
+      FROM company_data_keys
+      WHERE company_id = ?1
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_one(&self.pool)
+    .await?;
+
+    Ok(stored)
+  }
+}
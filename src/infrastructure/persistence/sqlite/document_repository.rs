@@ -0,0 +1,395 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use sqlx::{FromRow, SqliteConnection, SqlitePool};
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::document::{
+  Document, DocumentError, DocumentLink, DocumentOwner, DocumentRepository, IntegrityStatus,
+  Unlinked,
+};
+
+#[derive(Debug, FromRow)]
+struct DocumentRow {
+  id: String,
+  company_id: String,
+  sha256: String,
+  size_bytes: i64,
+  mime_type: String,
+  original_name: String,
+  storage_key: String,
+  uploaded_by: Option<String>,
+  integrity_status: String,
+  checked_at: Option<String>,
+  created_at: String,
+  retained_until: Option<String>,
+}
+
+#[derive(Debug, FromRow)]
+struct DocumentLinkRow {
+  owner_type: String,
+  owner_id: String,
+  document_id: String,
+  created_at: String,
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, DocumentError> {
+  Uuid::parse_str(value)
+    .map_err(|e| DocumentError::Repository(RepositoryError::QueryFailed(e.to_string())))
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, DocumentError> {
+  DateTime::parse_from_rfc3339(value)
+    .map(|dt| dt.with_timezone(&Utc))
+    .map_err(|e| DocumentError::Repository(RepositoryError::QueryFailed(e.to_string())))
+}
+
+fn timestamp(value: DateTime<Utc>) -> String {
+  value.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+impl TryFrom<DocumentRow> for Document {
+  type Error = DocumentError;
+
+  fn try_from(row: DocumentRow) -> Result<Self, Self::Error> {
+    Ok(Document {
+      id: parse_uuid(&row.id)?,
+      company_id: parse_uuid(&row.company_id)?,
+      sha256: row.sha256,
+      size_bytes: row.size_bytes,
+      mime_type: row.mime_type,
+      original_name: row.original_name,
+      storage_key: row.storage_key,
+      uploaded_by: row.uploaded_by.as_deref().map(parse_uuid).transpose()?,
+      integrity_status: IntegrityStatus::try_from(row.integrity_status.as_str())?,
+      checked_at: row.checked_at.as_deref().map(parse_timestamp).transpose()?,
+      created_at: parse_timestamp(&row.created_at)?,
+      retained_until: row
+        .retained_until
+        .as_deref()
+        .map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d"))
+        .transpose()
+        .map_err(|e| DocumentError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+    })
+  }
+}
+
+impl TryFrom<DocumentLinkRow> for DocumentLink {
+  type Error = DocumentError;
+
+  fn try_from(row: DocumentLinkRow) -> Result<Self, Self::Error> {
+    Ok(DocumentLink {
+      document_id: parse_uuid(&row.document_id)?,
+      owner: DocumentOwner::try_from(row.owner_type.as_str())?,
+      owner_id: parse_uuid(&row.owner_id)?,
+      created_at: parse_timestamp(&row.created_at)?,
+    })
+  }
+}
+
+/// Delete the document unless something links to it or it is retained
+/// beyond `today`
+async fn delete_unlinked(
+  conn: &mut SqliteConnection,
+  id: Uuid,
+  today: NaiveDate,
+) -> Result<Option<Document>, DocumentError> {
+  let row = sqlx::query_as::<_, DocumentRow>(
+    r#"
+    DELETE FROM documents
+    WHERE id = ?1
+      AND (retained_until IS NULL OR retained_until < ?2)
+      AND NOT EXISTS (SELECT 1 FROM document_links WHERE document_links.document_id = documents.id)
+    RETURNING id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+    "#,
+  )
+  .bind(id.to_string())
+  .bind(today.to_string())
+  .fetch_optional(conn)
+  .await?;
+
+  row.map(|r| r.try_into()).transpose()
+}
+
+pub struct SqliteDocumentRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteDocumentRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl DocumentRepository for SqliteDocumentRepository {
+  async fn create(&self, document: Document) -> Result<Document, DocumentError> {
+    sqlx::query(
+      r#"
+      INSERT INTO documents (id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+      ON CONFLICT (company_id, sha256) DO NOTHING
+      "#,
+    )
+    .bind(document.id.to_string())
+    .bind(document.company_id.to_string())
+    .bind(&document.sha256)
+    .bind(document.size_bytes)
+    .bind(&document.mime_type)
+    .bind(&document.original_name)
+    .bind(&document.storage_key)
+    .bind(document.uploaded_by.map(|id| id.to_string()))
+    .bind(document.integrity_status.as_str())
+    .bind(document.checked_at.map(timestamp))
+    .bind(timestamp(document.created_at))
+    .bind(document.retained_until.map(|date| date.to_string()))
+    .execute(&self.pool)
+    .await?;
+
+    // Ours, or the one stored by a concurrent upload of the same content
+    self
+      .find_by_hash(document.company_id, &document.sha256)
+      .await?
+      .ok_or(DocumentError::NotFound)
+  }
+
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<Document>, DocumentError> {
+    let row = sqlx::query_as::<_, DocumentRow>(
+      r#"
+      SELECT id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+      FROM documents
+      WHERE id = ?1
+      "#,
+    )
+    .bind(id.to_string())
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_by_hash(
+    &self,
+    company_id: Uuid,
+    sha256: &str,
+  ) -> Result<Option<Document>, DocumentError> {
+    let row = sqlx::query_as::<_, DocumentRow>(
+      r#"
+      SELECT id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+      FROM documents
+      WHERE company_id = ?1 AND sha256 = ?2
+      "#,
+    )
+    .bind(company_id.to_string())
+    .bind(sha256)
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_by_company(&self, company_id: Uuid) -> Result<Vec<Document>, DocumentError> {
+    let rows = sqlx::query_as::<_, DocumentRow>(
+      r#"
+      SELECT id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+      FROM documents
+      WHERE company_id = ?1
+      ORDER BY created_at DESC
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn find_page(
+    &self,
+    after: Option<Uuid>,
+    limit: i64,
+  ) -> Result<Vec<Document>, DocumentError> {
+    let rows = sqlx::query_as::<_, DocumentRow>(
+      r#"
+      SELECT id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+      FROM documents
+      WHERE ?1 IS NULL OR id > ?1
+      ORDER BY id
+      LIMIT ?2
+      "#,
+    )
+    .bind(after.map(|id| id.to_string()))
+    .bind(limit)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn update_integrity(
+    &self,
+    id: Uuid,
+    status: IntegrityStatus,
+    checked_at: DateTime<Utc>,
+  ) -> Result<(), DocumentError> {
+    sqlx::query("UPDATE documents SET integrity_status = ?2, checked_at = ?3 WHERE id = ?1")
+      .bind(id.to_string())
+      .bind(status.as_str())
+      .bind(timestamp(checked_at))
+      .execute(&self.pool)
+      .await?;
+
+    Ok(())
+  }
+
+  async fn delete_if_unlinked(
+    &self,
+    id: Uuid,
+    today: NaiveDate,
+  ) -> Result<Option<Document>, DocumentError> {
+    let mut conn = self.pool.acquire().await?;
+    delete_unlinked(&mut conn, id, today).await
+  }
+
+  async fn find_released(&self, today: NaiveDate) -> Result<Vec<Document>, DocumentError> {
+    let rows = sqlx::query_as::<_, DocumentRow>(
+      r#"
+      SELECT id, company_id, sha256, size_bytes, mime_type, original_name, storage_key, uploaded_by, integrity_status, checked_at, created_at, retained_until
+      FROM documents
+      WHERE retained_until < ?1
+        AND NOT EXISTS (SELECT 1 FROM document_links WHERE document_links.document_id = documents.id)
+      ORDER BY retained_until
+      "#,
+    )
+    .bind(today.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn link(
+    &self,
+    link: DocumentLink,
+    keep_replaced_until: Option<NaiveDate>,
+    today: NaiveDate,
+  ) -> Result<Option<Document>, DocumentError> {
+    let mut tx = self.pool.begin().await?;
+
+    let document: Option<String> = sqlx::query_scalar("SELECT id FROM documents WHERE id = ?1")
+      .bind(link.document_id.to_string())
+      .fetch_optional(&mut *tx)
+      .await?;
+    if document.is_none() {
+      return Err(DocumentError::NotFound);
+    }
+
+    let previous: Option<String> = sqlx::query_scalar(
+      "SELECT document_id FROM document_links WHERE owner_type = ?1 AND owner_id = ?2",
+    )
+    .bind(link.owner.as_str())
+    .bind(link.owner_id.to_string())
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    sqlx::query(
+      r#"
+      INSERT INTO document_links (owner_type, owner_id, document_id, created_at)
+      VALUES (?1, ?2, ?3, ?4)
+      ON CONFLICT (owner_type, owner_id)
+      DO UPDATE SET document_id = excluded.document_id, created_at = excluded.created_at
+      "#,
+    )
+    .bind(link.owner.as_str())
+    .bind(link.owner_id.to_string())
+    .bind(link.document_id.to_string())
+    .bind(timestamp(link.created_at))
+    .execute(&mut *tx)
+    .await?;
+
+    let mut removed = None;
+    if let Some(previous) = previous
+      .as_deref()
+      .map(parse_uuid)
+      .transpose()?
+      .filter(|id| *id != link.document_id)
+    {
+      if let Some(until) = keep_replaced_until {
+        sqlx::query(
+          "UPDATE documents SET retained_until = MAX(COALESCE(retained_until, ?2), ?2) WHERE id = ?1",
+        )
+        .bind(previous.to_string())
+        .bind(until.to_string())
+        .execute(&mut *tx)
+        .await?;
+      }
+      removed = delete_unlinked(&mut tx, previous, today).await?;
+    }
+
+    tx.commit().await?;
+    Ok(removed)
+  }
+
+  async fn unlink(
+    &self,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+    today: NaiveDate,
+  ) -> Result<Option<Unlinked>, DocumentError> {
+    let mut tx = self.pool.begin().await?;
+
+    let document_id: Option<String> = sqlx::query_scalar(
+      "DELETE FROM document_links WHERE owner_type = ?1 AND owner_id = ?2 RETURNING document_id",
+    )
+    .bind(owner.as_str())
+    .bind(owner_id.to_string())
+    .fetch_optional(&mut *tx)
+    .await?;
+    let unlinked = match document_id.as_deref().map(parse_uuid).transpose()? {
+      Some(document_id) => Some(Unlinked {
+        document_id,
+        removed: delete_unlinked(&mut tx, document_id, today).await?,
+      }),
+      None => None,
+    };
+
+    tx.commit().await?;
+    Ok(unlinked)
+  }
+
+  async fn find_link(
+    &self,
+    owner: DocumentOwner,
+    owner_id: Uuid,
+  ) -> Result<Option<DocumentLink>, DocumentError> {
+    let row = sqlx::query_as::<_, DocumentLinkRow>(
+      r#"
+      SELECT owner_type, owner_id, document_id, created_at
+      FROM document_links
+      WHERE owner_type = ?1 AND owner_id = ?2
+      "#,
+    )
+    .bind(owner.as_str())
+    .bind(owner_id.to_string())
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_links(&self, document_id: Uuid) -> Result<Vec<DocumentLink>, DocumentError> {
+    let rows = sqlx::query_as::<_, DocumentLinkRow>(
+      r#"
+      SELECT owner_type, owner_id, document_id, created_at
+      FROM document_links
+      WHERE document_id = ?1
+      ORDER BY created_at
+      "#,
+    )
+    .bind(document_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+}
@@ -0,0 +1,136 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::domain::invoice::{
+  entities::InvoiceBranding,
+  errors::InvoiceError,
+  ports::InvoiceBrandingRepository,
+  value_objects::{AccentColor, BrandingText, CustomInvoiceTemplate, InvoiceFont},
+};
+
+#[derive(Debug, FromRow)]
+struct BrandingRow {
+  company_id: String,
+  logo_path: Option<String>,
+  accent_color: String,
+  font: String,
+  default_notes: Option<String>,
+  footer_text: Option<String>,
+  signature_text: Option<String>,
+  custom_template: Option<String>,
+  created_at: String,
+  updated_at: String,
+}
+
+fn parse_branding_row(row: BrandingRow) -> Result<InvoiceBranding, InvoiceError> {
+  let company_id = Uuid::parse_str(&row.company_id)
+    .map_err(|e| InvoiceError::Internal(format!("Failed to parse UUID: {}", e)))?;
+
+  let created_at = DateTime::parse_from_rfc3339(&row.created_at)
+    .map(|dt| dt.with_timezone(&Utc))
+    .map_err(|e| InvoiceError::Internal(format!("Failed to parse datetime: {}", e)))?;
+  let updated_at = DateTime::parse_from_rfc3339(&row.updated_at)
+    .map(|dt| dt.with_timezone(&Utc))
+    .map_err(|e| InvoiceError::Internal(format!("Failed to parse datetime: {}", e)))?;
+
+  Ok(InvoiceBranding {
+    company_id,
+    logo_path: row.logo_path,
+    accent_color: AccentColor::new(row.accent_color)?,
+    font: InvoiceFont::from_str(&row.font)?,
+    default_notes: row
+      .default_notes
+      .map(BrandingText::new)
+      .transpose()?
+      .flatten(),
+    footer_text: row
+      .footer_text
+      .map(BrandingText::new)
+      .transpose()?
+      .flatten(),
+    signature_text: row
+      .signature_text
+      .map(BrandingText::new)
+      .transpose()?
+      .flatten(),
+    custom_template: row
+      .custom_template
+      .map(CustomInvoiceTemplate::new)
+      .transpose()?
+      .flatten(),
+    created_at,
+    updated_at,
+  })
+}
+
+pub struct SqliteInvoiceBrandingRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteInvoiceBrandingRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl InvoiceBrandingRepository for SqliteInvoiceBrandingRepository {
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Option<InvoiceBranding>, InvoiceError> {
+    let row = sqlx::query_as::<_, BrandingRow>(
+      r#"
+      SELECT company_id, logo_path, accent_color, font, default_notes, footer_text,
+             signature_text, custom_template, created_at, updated_at
+      FROM invoice_brandings
+      WHERE company_id = ?1
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(parse_branding_row).transpose()
+  }
+
+  async fn save(&self, branding: InvoiceBranding) -> Result<InvoiceBranding, InvoiceError> {
+    let row = sqlx::query_as::<_, BrandingRow>(
+      r#"
+      INSERT INTO invoice_brandings (
+        company_id, logo_path, accent_color, font, default_notes, footer_text,
+        signature_text, custom_template, created_at, updated_at
+      )
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+      ON CONFLICT (company_id) DO UPDATE
+      SET logo_path = excluded.logo_path,
+          accent_color = excluded.accent_color,
+          font = excluded.font,
+          default_notes = excluded.default_notes,
+          footer_text = excluded.footer_text,
+          signature_text = excluded.signature_text,
+          custom_template = excluded.custom_template,
+          updated_at = excluded.updated_at
+      RETURNING company_id, logo_path, accent_color, font, default_notes, footer_text,
+                signature_text, custom_template, created_at, updated_at
+      "#,
+    )
+    .bind(branding.company_id.to_string())
+    .bind(&branding.logo_path)
+    .bind(branding.accent_color.value())
+    .bind(branding.font.as_str())
+    .bind(branding.default_notes.as_ref().map(|t| t.value()))
+    .bind(branding.footer_text.as_ref().map(|t| t.value()))
+    .bind(branding.signature_text.as_ref().map(|t| t.value()))
+    .bind(branding.custom_template.as_ref().map(|t| t.value()))
+    .bind(branding.created_at.to_rfc3339())
+    .bind(branding.updated_at.to_rfc3339())
+    .fetch_one(&self.pool)
+    .await?;
+
+    parse_branding_row(row)
+  }
+}
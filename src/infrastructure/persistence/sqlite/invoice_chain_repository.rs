@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+use super::invoice_unit_of_work::sqlite_transaction;
+use crate::domain::invoice::{
+  chain::InvoiceChainLink,
+  errors::InvoiceError,
+  ports::{InvoiceChainRepository, InvoiceUnitOfWork},
+};
+
+#[derive(Debug, FromRow)]
+struct ChainLinkRow {
+  id: String,
+  company_id: String,
+  invoice_id: String,
+  sequence: i64,
+  previous_hash: Option<String>,
+  hash: String,
+  created_at: String,
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, InvoiceError> {
+  Uuid::parse_str(value).map_err(|e| InvoiceError::Internal(format!("Failed to parse UUID: {}", e)))
+}
+
+fn parse_chain_link_row(row: ChainLinkRow) -> Result<InvoiceChainLink, InvoiceError> {
+  let created_at = DateTime::parse_from_rfc3339(&row.created_at)
+    .map(|dt| dt.with_timezone(&Utc))
+    .map_err(|e| InvoiceError::Internal(format!("Failed to parse datetime: {}", e)))?;
+
+  Ok(InvoiceChainLink {
+    id: parse_uuid(&row.id)?,
+    company_id: parse_uuid(&row.company_id)?,
+    invoice_id: parse_uuid(&row.invoice_id)?,
+    sequence: row.sequence,
+    previous_hash: row.previous_hash,
+    hash: row.hash,
+    created_at,
+  })
+}
+
+pub struct SqliteInvoiceChainRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteInvoiceChainRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl InvoiceChainRepository for SqliteInvoiceChainRepository {
+  async fn append(
+    &self,
+    unit_of_work: &mut dyn InvoiceUnitOfWork,
+    link: InvoiceChainLink,
+  ) -> Result<InvoiceChainLink, InvoiceError> {
+    let tx = sqlite_transaction(unit_of_work)?;
+
+    let row = sqlx::query_as::<_, ChainLinkRow>(
+      r#"
+      INSERT INTO invoice_chain_links (
+        id, company_id, invoice_id, sequence, previous_hash, hash, created_at
+      )
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+      RETURNING id, company_id, invoice_id, sequence, previous_hash, hash, created_at
+      "#,
+    )
+    .bind(link.id.to_string())
+    .bind(link.company_id.to_string())
+    .bind(link.invoice_id.to_string())
+    .bind(link.sequence)
+    .bind(&link.previous_hash)
+    .bind(&link.hash)
+    .bind(link.created_at.to_rfc3339())
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| {
+      if let sqlx::Error::Database(db_err) = &e {
+        if db_err.is_unique_violation() {
+          return InvoiceError::ChainConflict;
+        }
+      }
+      InvoiceError::Database(e)
+    })?;
+
+    parse_chain_link_row(row)
+  }
+
+  async fn find_latest(&self, company_id: Uuid) -> Result<Option<InvoiceChainLink>, InvoiceError> {
+    let row = sqlx::query_as::<_, ChainLinkRow>(
+      r#"
+      SELECT id, company_id, invoice_id, sequence, previous_hash, hash, created_at
+      FROM invoice_chain_links
+      WHERE company_id = ?1
+      ORDER BY sequence DESC
+      LIMIT 1
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(parse_chain_link_row).transpose()
+  }
+
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<InvoiceChainLink>, InvoiceError> {
+    let rows = sqlx::query_as::<_, ChainLinkRow>(
+      r#"
+      SELECT id, company_id, invoice_id, sequence, previous_hash, hash, created_at
+      FROM invoice_chain_links
+      WHERE company_id = ?1
+      ORDER BY sequence ASC
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(parse_chain_link_row).collect()
+  }
+}
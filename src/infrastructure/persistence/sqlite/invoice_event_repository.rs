@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::domain::invoice::{
+  entities::InvoiceEvent, errors::InvoiceError, ports::InvoiceEventRepository,
+  value_objects::InvoiceEventKind,
+};
+
+#[derive(Debug, FromRow)]
+struct InvoiceEventRow {
+  id: String,
+  invoice_id: String,
+  company_id: String,
+  invoice_number: String,
+  user_id: Option<String>,
+  kind: String,
+  changes: String,
+  created_at: String,
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, InvoiceError> {
+  Uuid::parse_str(value).map_err(|e| InvoiceError::Internal(format!("Failed to parse UUID: {}", e)))
+}
+
+fn parse_invoice_event_row(row: InvoiceEventRow) -> Result<InvoiceEvent, InvoiceError> {
+  let created_at = DateTime::parse_from_rfc3339(&row.created_at)
+    .map(|dt| dt.with_timezone(&Utc))
+    .map_err(|e| InvoiceError::Internal(format!("Failed to parse datetime: {}", e)))?;
+
+  Ok(InvoiceEvent {
+    id: parse_uuid(&row.id)?,
+    invoice_id: parse_uuid(&row.invoice_id)?,
+    company_id: parse_uuid(&row.company_id)?,
+    invoice_number: row.invoice_number,
+    user_id: row.user_id.as_deref().map(parse_uuid).transpose()?,
+    kind: InvoiceEventKind::from_str(&row.kind)?,
+    changes: serde_json::from_str(&row.changes)
+      .map_err(|e| InvoiceError::Internal(format!("Failed to parse event changes: {}", e)))?,
+    created_at,
+  })
+}
+
+pub struct SqliteInvoiceEventRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteInvoiceEventRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl InvoiceEventRepository for SqliteInvoiceEventRepository {
+  async fn append(&self, event: InvoiceEvent) -> Result<InvoiceEvent, InvoiceError> {
+    let changes = serde_json::to_string(&event.changes)
+      .map_err(|e| InvoiceError::Internal(format!("Failed to serialize event changes: {}", e)))?;
+
+    let row = sqlx::query_as::<_, InvoiceEventRow>(
+      r#"
+      INSERT INTO invoice_events (
+        id, invoice_id, company_id, invoice_number, user_id, kind, changes, created_at
+      )
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+      RETURNING id, invoice_id, company_id, invoice_number, user_id, kind, changes, created_at
+      "#,
+    )
+    .bind(event.id.to_string())
+    .bind(event.invoice_id.to_string())
+    .bind(event.company_id.to_string())
+    .bind(&event.invoice_number)
+    .bind(event.user_id.map(|id| id.to_string()))
+    .bind(event.kind.as_str())
+    .bind(changes)
+    .bind(event.created_at.to_rfc3339())
+    .fetch_one(&self.pool)
+    .await?;
+
+    parse_invoice_event_row(row)
+  }
+
+  async fn find_by_invoice_id(&self, invoice_id: Uuid) -> Result<Vec<InvoiceEvent>, InvoiceError> {
+    let rows = sqlx::query_as::<_, InvoiceEventRow>(
+      r#"
+      SELECT id, invoice_id, company_id, invoice_number, user_id, kind, changes, created_at
+      FROM invoice_events
+      WHERE invoice_id = ?1
+      ORDER BY created_at DESC
+      "#,
+    )
+    .bind(invoice_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(parse_invoice_event_row).collect()
+  }
+}
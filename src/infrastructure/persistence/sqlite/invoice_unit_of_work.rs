@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::any::Any;
+
+use crate::domain::invoice::{
+  errors::InvoiceError,
+  ports::{InvoiceUnitOfWork, InvoiceUnitOfWorkFactory},
+};
+
+pub struct SqliteInvoiceUnitOfWork {
+  tx: Transaction<'static, Sqlite>,
+}
+
+#[async_trait]
+impl InvoiceUnitOfWork for SqliteInvoiceUnitOfWork {
+  fn transaction(&mut self) -> &mut (dyn Any + Send) {
+    &mut self.tx
+  }
+
+  async fn commit(self: Box<Self>) -> Result<(), InvoiceError> {
+    self.tx.commit().await?;
+    Ok(())
+  }
+}
+
+/// The sqlite transaction of a unit of work begun by `SqliteInvoiceUnitOfWorkFactory`
+pub(crate) fn sqlite_transaction(
+  unit_of_work: &mut dyn InvoiceUnitOfWork,
+) -> Result<&mut Transaction<'static, Sqlite>, InvoiceError> {
+  unit_of_work
+    .transaction()
+    .downcast_mut()
+    .ok_or_else(|| InvoiceError::Internal("Unit of work is not a sqlite transaction".to_string()))
+}
+
+pub struct SqliteInvoiceUnitOfWorkFactory {
+  pool: SqlitePool,
+}
+
+impl SqliteInvoiceUnitOfWorkFactory {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl InvoiceUnitOfWorkFactory for SqliteInvoiceUnitOfWorkFactory {
+  async fn begin(&self) -> Result<Box<dyn InvoiceUnitOfWork>, InvoiceError> {
+    let tx = self.pool.begin().await?;
+    Ok(Box::new(SqliteInvoiceUnitOfWork { tx }))
+  }
+}
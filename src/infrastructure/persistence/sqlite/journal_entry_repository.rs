@@ -0,0 +1,266 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, SqlitePool};
+use std::collections::HashMap;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::ledger::{
+  JournalEntry, JournalEntryRepository, JournalLine, JournalSource, LedgerError,
+};
+
+#[derive(Debug, FromRow)]
+struct JournalEntryRow {
+  id: String,
+  company_id: String,
+  entry_date: String,
+  description: String,
+  source: String,
+  source_id: Option<String>,
+  reversal_of: Option<String>,
+  created_by: Option<String>,
+  created_at: String,
+}
+
+#[derive(Debug, FromRow)]
+struct JournalLineRow {
+  id: String,
+  entry_id: String,
+  account_id: String,
+  debit: String,
+  credit: String,
+  description: Option<String>,
+  line_order: i32,
+}
+
+fn query_failed(e: impl std::fmt::Display) -> LedgerError {
+  LedgerError::Repository(RepositoryError::QueryFailed(e.to_string()))
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, LedgerError> {
+  Uuid::parse_str(value).map_err(query_failed)
+}
+
+fn parse_line_row(row: JournalLineRow) -> Result<JournalLine, LedgerError> {
+  Ok(JournalLine {
+    id: parse_uuid(&row.id)?,
+    entry_id: parse_uuid(&row.entry_id)?,
+    account_id: parse_uuid(&row.account_id)?,
+    debit: Decimal::from_str(&row.debit).map_err(query_failed)?,
+    credit: Decimal::from_str(&row.credit).map_err(query_failed)?,
+    description: row.description,
+    line_order: row.line_order,
+  })
+}
+
+fn build_entries(
+  entry_rows: Vec<JournalEntryRow>,
+  line_rows: Vec<JournalLineRow>,
+) -> Result<Vec<JournalEntry>, LedgerError> {
+  let mut lines_by_entry: HashMap<Uuid, Vec<JournalLine>> = HashMap::new();
+  for row in line_rows {
+    let line = parse_line_row(row)?;
+    lines_by_entry.entry(line.entry_id).or_default().push(line);
+  }
+
+  entry_rows
+    .into_iter()
+    .map(|row| {
+      let id = parse_uuid(&row.id)?;
+      Ok(JournalEntry {
+        id,
+        company_id: parse_uuid(&row.company_id)?,
+        entry_date: NaiveDate::parse_from_str(&row.entry_date, "%Y-%m-%d").map_err(query_failed)?,
+        description: row.description,
+        source: JournalSource::try_from(row.source.as_str())?,
+        source_id: row.source_id.as_deref().map(parse_uuid).transpose()?,
+        reversal_of: row.reversal_of.as_deref().map(parse_uuid).transpose()?,
+        created_by: row.created_by.as_deref().map(parse_uuid).transpose()?,
+        created_at: DateTime::parse_from_rfc3339(&row.created_at)
+          .map(|dt| dt.with_timezone(&Utc))
+          .map_err(query_failed)?,
+        lines: lines_by_entry.remove(&id).unwrap_or_default(),
+      })
+    })
+    .collect()
+}
+
+pub struct SqliteJournalEntryRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteJournalEntryRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl JournalEntryRepository for SqliteJournalEntryRepository {
+  async fn create(&self, entry: JournalEntry) -> Result<JournalEntry, LedgerError> {
+    let mut tx = self.pool.begin().await?;
+
+    sqlx::query(
+      r#"
+      INSERT INTO journal_entries (
+        id, company_id, entry_date, description, source, source_id, reversal_of,
+        created_by, created_at
+      )
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+      "#,
+    )
+    .bind(entry.id.to_string())
+    .bind(entry.company_id.to_string())
+    .bind(entry.entry_date.format("%Y-%m-%d").to_string())
+    .bind(&entry.description)
+    .bind(entry.source.as_str())
+    .bind(entry.source_id.map(|id| id.to_string()))
+    .bind(entry.reversal_of.map(|id| id.to_string()))
+    .bind(entry.created_by.map(|id| id.to_string()))
+    .bind(entry.created_at.to_rfc3339())
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+      if let sqlx::Error::Database(db_err) = &e {
+        if db_err.is_unique_violation() {
+          return LedgerError::AlreadyReversed;
+        }
+      }
+      LedgerError::from(e)
+    })?;
+
+    for line in &entry.lines {
+      sqlx::query(
+        r#"
+        INSERT INTO journal_lines (
+          id, entry_id, account_id, debit, credit, description, line_order
+        )
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        "#,
+      )
+      .bind(line.id.to_string())
+      .bind(line.entry_id.to_string())
+      .bind(line.account_id.to_string())
+      .bind(line.debit.to_string())
+      .bind(line.credit.to_string())
+      .bind(line.description.as_deref())
+      .bind(line.line_order)
+      .execute(&mut *tx)
+      .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(entry)
+  }
+
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<JournalEntry>, LedgerError> {
+    let entry_rows = sqlx::query_as::<_, JournalEntryRow>(
+      r#"
+      SELECT id, company_id, entry_date, description, source, source_id, reversal_of,
+             created_by, created_at
+      FROM journal_entries
+      WHERE id = ?1
+      "#,
+    )
+    .bind(id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    let line_rows = sqlx::query_as::<_, JournalLineRow>(
+      r#"
+      SELECT id, entry_id, account_id, debit, credit, description, line_order
+      FROM journal_lines
+      WHERE entry_id = ?1
+      ORDER BY line_order ASC
+      "#,
+    )
+    .bind(id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    Ok(build_entries(entry_rows, line_rows)?.into_iter().next())
+  }
+
+  async fn is_reversed(&self, id: Uuid) -> Result<bool, LedgerError> {
+    let reversed = sqlx::query_scalar::<_, bool>(
+      "SELECT EXISTS(SELECT 1 FROM journal_entries WHERE reversal_of = ?1)",
+    )
+    .bind(id.to_string())
+    .fetch_one(&self.pool)
+    .await?;
+
+    Ok(reversed)
+  }
+
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<JournalEntry>, LedgerError> {
+    let entry_rows = sqlx::query_as::<_, JournalEntryRow>(
+      r#"
+      SELECT id, company_id, entry_date, description, source, source_id, reversal_of,
+             created_by, created_at
+      FROM journal_entries
+      WHERE company_id = ?1
+      ORDER BY entry_date ASC, created_at ASC
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    let line_rows = sqlx::query_as::<_, JournalLineRow>(
+      r#"
+      SELECT l.id, l.entry_id, l.account_id, l.debit, l.credit, l.description, l.line_order
+      FROM journal_lines l
+      JOIN journal_entries e ON e.id = l.entry_id
+      WHERE e.company_id = ?1
+      ORDER BY l.line_order ASC
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    build_entries(entry_rows, line_rows)
+  }
+
+  async fn find_by_source(
+    &self,
+    company_id: Uuid,
+    source: JournalSource,
+    source_id: Uuid,
+  ) -> Result<Vec<JournalEntry>, LedgerError> {
+    let entry_rows = sqlx::query_as::<_, JournalEntryRow>(
+      r#"
+      SELECT id, company_id, entry_date, description, source, source_id, reversal_of,
+             created_by, created_at
+      FROM journal_entries
+      WHERE company_id = ?1 AND source = ?2 AND source_id = ?3
+      ORDER BY created_at ASC
+      "#,
+    )
+    .bind(company_id.to_string())
+    .bind(source.as_str())
+    .bind(source_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    let line_rows = sqlx::query_as::<_, JournalLineRow>(
+      r#"
+      SELECT l.id, l.entry_id, l.account_id, l.debit, l.credit, l.description, l.line_order
+      FROM journal_lines l
+      JOIN journal_entries e ON e.id = l.entry_id
+      WHERE e.company_id = ?1 AND e.source = ?2 AND e.source_id = ?3
+      ORDER BY l.line_order ASC
+      "#,
+    )
+    .bind(company_id.to_string())
+    .bind(source.as_str())
+    .bind(source_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    build_entries(entry_rows, line_rows)
+  }
+}
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::ledger::{Account, AccountRepository, AccountType, LedgerError};
+
+#[derive(Debug, FromRow)]
+struct AccountRow {
+  id: String,
+  company_id: String,
+  code: String,
+  name: String,
+  account_type: String,
+  created_at: String,
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, LedgerError> {
+  Uuid::parse_str(value)
+    .map_err(|e| LedgerError::Repository(RepositoryError::QueryFailed(e.to_string())))
+}
+
+fn parse_account_row(row: AccountRow) -> Result<Account, LedgerError> {
+  Ok(Account {
+    id: parse_uuid(&row.id)?,
+    company_id: parse_uuid(&row.company_id)?,
+    code: row.code,
+    name: row.name,
+    account_type: AccountType::try_from(row.account_type.as_str())?,
+    created_at: DateTime::parse_from_rfc3339(&row.created_at)
+      .map(|dt| dt.with_timezone(&Utc))
+      .map_err(|e| LedgerError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+  })
+}
+
+pub struct SqliteAccountRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteAccountRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl AccountRepository for SqliteAccountRepository {
+  async fn create(&self, account: Account) -> Result<Account, LedgerError> {
+    let row = sqlx::query_as::<_, AccountRow>(
+      r#"
+      INSERT INTO ledger_accounts (id, company_id, code, name, account_type, created_at)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+      RETURNING id, company_id, code, name, account_type, created_at
+      "#,
+    )
+    .bind(account.id.to_string())
+    .bind(account.company_id.to_string())
+    .bind(&account.code)
+    .bind(&account.name)
+    .bind(account.account_type.as_str())
+    .bind(account.created_at.to_rfc3339())
+    .fetch_one(&self.pool)
+    .await
+    .map_err(|e| {
+      if let sqlx::Error::Database(db_err) = &e {
+        if db_err.is_unique_violation() {
+          return LedgerError::DuplicateAccountCode(account.code.clone());
+        }
+      }
+      LedgerError::from(e)
+    })?;
+
+    parse_account_row(row)
+  }
+
+  async fn create_many(&self, accounts: Vec<Account>) -> Result<(), LedgerError> {
+    for account in accounts {
+      sqlx::query(
+        r#"
+        INSERT INTO ledger_accounts (id, company_id, code, name, account_type, created_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        ON CONFLICT (company_id, code) DO NOTHING
+        "#,
+      )
+      .bind(account.id.to_string())
+      .bind(account.company_id.to_string())
+      .bind(&account.code)
+      .bind(&account.name)
+      .bind(account.account_type.as_str())
+      .bind(account.created_at.to_rfc3339())
+      .execute(&self.pool)
+      .await?;
+    }
+
+    Ok(())
+  }
+
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<Account>, LedgerError> {
+    let rows = sqlx::query_as::<_, AccountRow>(
+      r#"
+      SELECT id, company_id, code, name, account_type, created_at
+      FROM ledger_accounts
+      WHERE company_id = ?1
+      ORDER BY code ASC
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(parse_account_row).collect()
+  }
+}
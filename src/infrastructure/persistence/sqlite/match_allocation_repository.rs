@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, SqlitePool};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::report::{MatchAllocation, MatchAllocationRepository, ReportError};
+
+/// Also loaded by the bank transaction repository to fill in allocations
+#[derive(Debug, FromRow)]
+pub(super) struct MatchAllocationRow {
+  id: String,
+  transaction_id: String,
+  invoice_id: Option<String>,
+  received_invoice_id: Option<String>,
+  amount: String,
+  created_at: String,
+}
+
+impl TryFrom<MatchAllocationRow> for MatchAllocation {
+  type Error = ReportError;
+
+  fn try_from(row: MatchAllocationRow) -> Result<Self, Self::Error> {
+    Ok(MatchAllocation {
+      id: Uuid::parse_str(&row.id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      transaction_id: Uuid::parse_str(&row.transaction_id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      invoice_id: row
+        .invoice_id
+        .map(|s| Uuid::parse_str(&s))
+        .transpose()
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      received_invoice_id: row
+        .received_invoice_id
+        .map(|s| Uuid::parse_str(&s))
+        .transpose()
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      amount: Decimal::from_str(&row.amount)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      created_at: DateTime::parse_from_rfc3339(&row.created_at)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+    })
+  }
+}
+
+pub struct SqliteMatchAllocationRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteMatchAllocationRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl MatchAllocationRepository for SqliteMatchAllocationRepository {
+  async fn create(&self, allocation: MatchAllocation) -> Result<MatchAllocation, ReportError> {
+    let row = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            INSERT INTO match_allocations (id, transaction_id, invoice_id, received_invoice_id, amount, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            RETURNING id, transaction_id, invoice_id, received_invoice_id, amount, created_at
+            "#,
+    )
+    .bind(allocation.id.to_string())
+    .bind(allocation.transaction_id.to_string())
+    .bind(allocation.invoice_id.map(|id| id.to_string()))
+    .bind(allocation.received_invoice_id.map(|id| id.to_string()))
+    .bind(allocation.amount.to_string())
+    .bind(allocation.created_at.to_rfc3339())
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_invoice_id(
+    &self,
+    invoice_id: Uuid,
+  ) -> Result<Vec<MatchAllocation>, ReportError> {
+    let rows = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            SELECT id, transaction_id, invoice_id, received_invoice_id, amount, created_at
+            FROM match_allocations WHERE invoice_id = ?1 ORDER BY created_at
+            "#,
+    )
+    .bind(invoice_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn find_by_received_invoice_id(
+    &self,
+    received_invoice_id: Uuid,
+  ) -> Result<Vec<MatchAllocation>, ReportError> {
+    let rows = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            SELECT id, transaction_id, invoice_id, received_invoice_id, amount, created_at
+            FROM match_allocations WHERE received_invoice_id = ?1 ORDER BY created_at
+            "#,
+    )
+    .bind(received_invoice_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<MatchAllocation>, ReportError> {
+    let rows = sqlx::query_as::<_, MatchAllocationRow>(
+      r#"
+            SELECT a.id, a.transaction_id, a.invoice_id, a.received_invoice_id, a.amount, a.created_at
+            FROM match_allocations a
+            JOIN bank_transactions t ON t.id = a.transaction_id
+            JOIN monthly_reports r ON r.id = t.report_id
+            WHERE r.company_id = ?1
+            ORDER BY a.created_at
+            "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn delete_by_transaction_id(&self, transaction_id: Uuid) -> Result<(), ReportError> {
+    sqlx::query("DELETE FROM match_allocations WHERE transaction_id = ?1")
+      .bind(transaction_id.to_string())
+      .execute(&self.pool)
+      .await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, SqlitePool};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::report::{
+  MatchingRule, MatchingRuleRepository, ReportError, RuleAction, RuleConditions,
+  TransactionDirection,
+};
+
+#[derive(Debug, FromRow)]
+struct MatchingRuleRow {
+  id: String,
+  company_id: String,
+  name: String,
+  position: i32,
+  counterparty_iban: Option<String>,
+  description_pattern: Option<String>,
+  min_amount: Option<String>,
+  max_amount: Option<String>,
+  direction: Option<String>,
+  action: String,
+  category_id: Option<String>,
+  vendor_name: Option<String>,
+  created_at: String,
+}
+
+impl TryFrom<MatchingRuleRow> for MatchingRule {
+  type Error = ReportError;
+
+  fn try_from(row: MatchingRuleRow) -> Result<Self, Self::Error> {
+    let category_id = row
+      .category_id
+      .map(|s| Uuid::parse_str(&s))
+      .transpose()
+      .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?;
+
+    Ok(MatchingRule {
+      id: Uuid::parse_str(&row.id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      company_id: Uuid::parse_str(&row.company_id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      name: row.name,
+      position: row.position,
+      conditions: RuleConditions {
+        counterparty_iban: row.counterparty_iban,
+        description_pattern: row.description_pattern,
+        min_amount: row
+          .min_amount
+          .map(|s| Decimal::from_str(&s))
+          .transpose()
+          .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+        max_amount: row
+          .max_amount
+          .map(|s| Decimal::from_str(&s))
+          .transpose()
+          .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+        direction: row
+          .direction
+          .as_deref()
+          .map(TransactionDirection::try_from)
+          .transpose()?,
+      },
+      action: RuleAction::from_parts(&row.action, category_id, row.vendor_name.as_deref())?,
+      created_at: DateTime::parse_from_rfc3339(&row.created_at)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+    })
+  }
+}
+
+pub struct SqliteMatchingRuleRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteMatchingRuleRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl MatchingRuleRepository for SqliteMatchingRuleRepository {
+  async fn create(&self, rule: MatchingRule) -> Result<MatchingRule, ReportError> {
+    let row = sqlx::query_as::<_, MatchingRuleRow>(
+      r#"
+            INSERT INTO matching_rules (id, company_id, name, position, counterparty_iban, description_pattern, min_amount, max_amount, direction, action, category_id, vendor_name, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+            RETURNING id, company_id, name, position, counterparty_iban, description_pattern, min_amount, max_amount, direction, action, category_id, vendor_name, created_at
+            "#,
+    )
+    .bind(rule.id.to_string())
+    .bind(rule.company_id.to_string())
+    .bind(&rule.name)
+    .bind(rule.position)
+    .bind(rule.conditions.counterparty_iban.as_deref())
+    .bind(rule.conditions.description_pattern.as_deref())
+    .bind(rule.conditions.min_amount.map(|a| a.to_string()))
+    .bind(rule.conditions.max_amount.map(|a| a.to_string()))
+    .bind(rule.conditions.direction.map(|d| d.as_str()))
+    .bind(rule.action.kind())
+    .bind(rule.action.category_id().map(|id| id.to_string()))
+    .bind(rule.action.vendor_name())
+    .bind(rule.created_at.to_rfc3339())
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<MatchingRule>, ReportError> {
+    let row = sqlx::query_as::<_, MatchingRuleRow>(
+      r#"
+            SELECT id, company_id, name, position, counterparty_iban, description_pattern, min_amount, max_amount, direction, action, category_id, vendor_name, created_at
+            FROM matching_rules WHERE id = ?1
+            "#,
+    )
+    .bind(id.to_string())
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<MatchingRule>, ReportError> {
+    let rows = sqlx::query_as::<_, MatchingRuleRow>(
+      r#"
+            SELECT id, company_id, name, position, counterparty_iban, description_pattern, min_amount, max_amount, direction, action, category_id, vendor_name, created_at
+            FROM matching_rules WHERE company_id = ?1 ORDER BY position, created_at
+            "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn update_position(&self, id: Uuid, position: i32) -> Result<(), ReportError> {
+    sqlx::query("UPDATE matching_rules SET position = ?2 WHERE id = ?1")
+      .bind(id.to_string())
+      .bind(position)
+      .execute(&self.pool)
+      .await?;
+    Ok(())
+  }
+
+  async fn delete(&self, id: Uuid) -> Result<(), ReportError> {
+    sqlx::query("DELETE FROM matching_rules WHERE id = ?1")
+      .bind(id.to_string())
+      .execute(&self.pool)
+      .await?;
+    Ok(())
+  }
+}
@@ -1,33 +1,69 @@
 pub mod active_bank_account_repository;
 pub mod active_company_repository;
 pub mod bank_account_repository;
+pub mod bank_statement_repository;
 pub mod bank_transaction_repository;
+pub mod category_suggestion_repository;
 pub mod company_member_repository;
 pub mod company_repository;
 pub mod customer_repository;
+pub mod data_key_repository;
+pub mod document_repository;
+pub mod invoice_branding_repository;
+pub mod invoice_chain_repository;
+pub mod invoice_event_repository;
 pub mod invoice_line_item_repository;
 pub mod invoice_repository;
 pub mod invoice_template_line_item_repository;
 pub mod invoice_template_repository;
+pub mod invoice_unit_of_work;
+pub mod journal_entry_repository;
+pub mod ledger_account_repository;
 pub mod login_attempt_repository;
+pub mod match_allocation_repository;
+pub mod matching_rule_repository;
 pub mod monthly_report_repository;
 pub mod received_invoice_repository;
+pub mod report_event_repository;
+pub mod report_upload_repository;
+pub mod retention_repository;
 pub mod session_repository;
+pub mod transaction_category_repository;
+pub mod upload_job_repository;
 pub mod user_repository;
+pub mod vendor_repository;
 
 pub use active_bank_account_repository::SqliteActiveBankAccountRepository;
 pub use active_company_repository::SqliteActiveCompanyRepository;
 pub use bank_account_repository::SqliteBankAccountRepository;
+pub use bank_statement_repository::SqliteBankStatementRepository;
 pub use bank_transaction_repository::SqliteBankTransactionRepository;
+pub use category_suggestion_repository::SqliteCategorySuggestionRepository;
 pub use company_member_repository::SqliteCompanyMemberRepository;
 pub use company_repository::SqliteCompanyRepository;
 pub use customer_repository::SqliteCustomerRepository;
+pub use data_key_repository::SqliteDataKeyRepository;
+pub use document_repository::SqliteDocumentRepository;
+pub use invoice_branding_repository::SqliteInvoiceBrandingRepository;
+pub use invoice_chain_repository::SqliteInvoiceChainRepository;
+pub use invoice_event_repository::SqliteInvoiceEventRepository;
 pub use invoice_line_item_repository::SqliteInvoiceLineItemRepository;
 pub use invoice_repository::SqliteInvoiceRepository;
 pub use invoice_template_line_item_repository::SqliteInvoiceTemplateLineItemRepository;
 pub use invoice_template_repository::SqliteInvoiceTemplateRepository;
+pub use invoice_unit_of_work::SqliteInvoiceUnitOfWorkFactory;
+pub use journal_entry_repository::SqliteJournalEntryRepository;
+pub use ledger_account_repository::SqliteAccountRepository;
 pub use login_attempt_repository::SqliteLoginAttemptRepository;
+pub use match_allocation_repository::SqliteMatchAllocationRepository;
+pub use matching_rule_repository::SqliteMatchingRuleRepository;
 pub use monthly_report_repository::SqliteMonthlyReportRepository;
 pub use received_invoice_repository::SqliteReceivedInvoiceRepository;
+pub use report_event_repository::SqliteReportEventRepository;
+pub use report_upload_repository::SqliteReportUploadRepository;
+pub use retention_repository::{SqliteBlockedDeletionRepository, SqliteRetentionPolicyRepository};
 pub use session_repository::SqliteSessionRepository;
+pub use transaction_category_repository::SqliteTransactionCategoryRepository;
+pub use upload_job_repository::SqliteUploadJobRepository;
 pub use user_repository::SqliteUserRepository;
+pub use vendor_repository::SqliteVendorRepository;
@@ -15,12 +15,12 @@ struct MonthlyReportRow {
   month: i32,
   year: i32,
   status: String,
-  bank_account_iban: Option<String>,
   total_incoming: String,
   total_outgoing: String,
   transaction_count: i32,
   matched_count: i32,
   drive_folder_id: Option<String>,
+  version: i32,
   created_at: String,
   updated_at: String,
 }
@@ -37,7 +37,6 @@ impl TryFrom<MonthlyReportRow> for MonthlyReport {
       month: row.month as u32,
       year: row.year,
       status: ReportStatus::try_from(row.status.as_str())?,
-      bank_account_iban: row.bank_account_iban,
       total_incoming: Decimal::from_str(&row.total_incoming)
         .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
       total_outgoing: Decimal::from_str(&row.total_outgoing)
@@ -45,6 +44,7 @@ impl TryFrom<MonthlyReportRow> for MonthlyReport {
       transaction_count: row.transaction_count,
       matched_count: row.matched_count,
       drive_folder_id: row.drive_folder_id,
+      version: row.version,
       created_at: DateTime::parse_from_rfc3339(&row.created_at)
         .map(|dt| dt.with_timezone(&Utc))
         .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
@@ -70,9 +70,9 @@ impl MonthlyReportRepository for SqliteMonthlyReportRepository {
   async fn create(&self, report: MonthlyReport) -> Result<MonthlyReport, ReportError> {
     let row = sqlx::query_as::<_, MonthlyReportRow>(
             r#"
-            INSERT INTO monthly_reports (id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at)
+            INSERT INTO monthly_reports (id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at)
             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
-            RETURNING id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at
+            RETURNING id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at
             "#,
         )
         .bind(report.id.to_string())
@@ -80,12 +80,12 @@ impl MonthlyReportRepository for SqliteMonthlyReportRepository {
         .bind(report.month as i32)
         .bind(report.year)
         .bind(report.status.as_str())
-        .bind(report.bank_account_iban.as_deref())
         .bind(report.total_incoming.to_string())
         .bind(report.total_outgoing.to_string())
         .bind(report.transaction_count)
         .bind(report.matched_count)
         .bind(report.drive_folder_id.as_deref())
+        .bind(report.version)
         .bind(report.created_at.to_rfc3339())
         .bind(report.updated_at.to_rfc3339())
         .fetch_one(&self.pool)
@@ -97,7 +97,7 @@ impl MonthlyReportRepository for SqliteMonthlyReportRepository {
   async fn find_by_id(&self, id: Uuid) -> Result<Option<MonthlyReport>, ReportError> {
     let row = sqlx::query_as::<_, MonthlyReportRow>(
             r#"
-            SELECT id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at
+            SELECT id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at
             FROM monthly_reports WHERE id = ?1
             "#,
         )
@@ -116,7 +116,7 @@ impl MonthlyReportRepository for SqliteMonthlyReportRepository {
   ) -> Result<Option<MonthlyReport>, ReportError> {
     let row = sqlx::query_as::<_, MonthlyReportRow>(
             r#"
-            SELECT id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at
+            SELECT id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at
             FROM monthly_reports WHERE company_id = ?1 AND month = ?2 AND year = ?3
             "#,
         )
@@ -132,7 +132,7 @@ impl MonthlyReportRepository for SqliteMonthlyReportRepository {
   async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<MonthlyReport>, ReportError> {
     let rows = sqlx::query_as::<_, MonthlyReportRow>(
             r#"
-            SELECT id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at
+            SELECT id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at
             FROM monthly_reports WHERE company_id = ?1 ORDER BY year DESC, month DESC
             "#,
         )
@@ -147,19 +147,19 @@ impl MonthlyReportRepository for SqliteMonthlyReportRepository {
     let row = sqlx::query_as::<_, MonthlyReportRow>(
             r#"
             UPDATE monthly_reports
-            SET status = ?2, bank_account_iban = ?3, total_incoming = ?4, total_outgoing = ?5, transaction_count = ?6, matched_count = ?7, drive_folder_id = ?8, updated_at = ?9
+            SET status = ?2, total_incoming = ?3, total_outgoing = ?4, transaction_count = ?5, matched_count = ?6, drive_folder_id = ?7, version = ?8, updated_at = ?9
             WHERE id = ?1
-            RETURNING id, company_id, month, year, status, bank_account_iban, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, created_at, updated_at
+            RETURNING id, company_id, month, year, status, total_incoming, total_outgoing, transaction_count, matched_count, drive_folder_id, version, created_at, updated_at
             "#,
         )
         .bind(report.id.to_string())
         .bind(report.status.as_str())
-        .bind(report.bank_account_iban.as_deref())
         .bind(report.total_incoming.to_string())
         .bind(report.total_outgoing.to_string())
         .bind(report.transaction_count)
         .bind(report.matched_count)
         .bind(report.drive_folder_id.as_deref())
+        .bind(report.version)
         .bind(report.updated_at.to_rfc3339())
         .fetch_one(&self.pool)
         .await?;
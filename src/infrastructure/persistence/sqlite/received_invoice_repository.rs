@@ -6,7 +6,9 @@ use std::str::FromStr;
 use uuid::Uuid;
 
 use crate::domain::auth::errors::RepositoryError;
-use crate::domain::report::{ReceivedInvoice, ReceivedInvoiceRepository, ReportError};
+use crate::domain::report::{
+  PayableStatus, ReceivedInvoice, ReceivedInvoiceRepository, ReportError,
+};
 
 #[derive(Debug, FromRow)]
 struct ReceivedInvoiceRow {
@@ -20,10 +22,24 @@ struct ReceivedInvoiceRow {
   pdf_path: String,
   pdf_drive_file_id: Option<String>,
   notes: Option<String>,
+  vat_amount: String,
+  due_date: Option<String>,
+  vendor_iban: Option<String>,
+  payment_reference: Option<String>,
+  status: String,
+  paid_on: Option<String>,
+  vendor_id: Option<String>,
   created_at: String,
   updated_at: String,
 }
 
+fn parse_date(value: Option<String>) -> Result<Option<NaiveDate>, ReportError> {
+  value
+    .map(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d"))
+    .transpose()
+    .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))
+}
+
 impl TryFrom<ReceivedInvoiceRow> for ReceivedInvoice {
   type Error = ReportError;
 
@@ -37,15 +53,23 @@ impl TryFrom<ReceivedInvoiceRow> for ReceivedInvoice {
       amount: Decimal::from_str(&row.amount)
         .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
       currency: row.currency,
-      invoice_date: row
-        .invoice_date
-        .map(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d"))
-        .transpose()
-        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      invoice_date: parse_date(row.invoice_date)?,
       invoice_number: row.invoice_number,
       pdf_path: row.pdf_path,
       pdf_drive_file_id: row.pdf_drive_file_id,
       notes: row.notes,
+      vat_amount: Decimal::from_str(&row.vat_amount)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      due_date: parse_date(row.due_date)?,
+      vendor_iban: row.vendor_iban,
+      payment_reference: row.payment_reference,
+      status: PayableStatus::try_from(row.status.as_str())?,
+      paid_on: parse_date(row.paid_on)?,
+      vendor_id: row
+        .vendor_id
+        .map(|s| Uuid::parse_str(&s))
+        .transpose()
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
       created_at: DateTime::parse_from_rfc3339(&row.created_at)
         .map(|dt| dt.with_timezone(&Utc))
         .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
@@ -71,9 +95,9 @@ impl ReceivedInvoiceRepository for SqliteReceivedInvoiceRepository {
   async fn create(&self, invoice: ReceivedInvoice) -> Result<ReceivedInvoice, ReportError> {
     let row = sqlx::query_as::<_, ReceivedInvoiceRow>(
             r#"
-            INSERT INTO received_invoices (id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
-            RETURNING id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at
+            INSERT INTO received_invoices (id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)
+            RETURNING id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
             "#,
         )
         .bind(invoice.id.to_string())
@@ -86,6 +110,13 @@ impl ReceivedInvoiceRepository for SqliteReceivedInvoiceRepository {
         .bind(&invoice.pdf_path)
         .bind(invoice.pdf_drive_file_id.as_deref())
         .bind(invoice.notes.as_deref())
+        .bind(invoice.vat_amount.to_string())
+        .bind(invoice.due_date.map(|d| d.format("%Y-%m-%d").to_string()))
+        .bind(invoice.vendor_iban.as_deref())
+        .bind(invoice.payment_reference.as_deref())
+        .bind(invoice.status.as_str())
+        .bind(invoice.paid_on.map(|d| d.format("%Y-%m-%d").to_string()))
+        .bind(invoice.vendor_id.map(|id| id.to_string()))
         .bind(invoice.created_at.to_rfc3339())
         .bind(invoice.updated_at.to_rfc3339())
         .fetch_one(&self.pool)
@@ -97,7 +128,7 @@ impl ReceivedInvoiceRepository for SqliteReceivedInvoiceRepository {
   async fn find_by_id(&self, id: Uuid) -> Result<Option<ReceivedInvoice>, ReportError> {
     let row = sqlx::query_as::<_, ReceivedInvoiceRow>(
             r#"
-            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
             FROM received_invoices WHERE id = ?1
             "#,
         )
@@ -114,7 +145,7 @@ impl ReceivedInvoiceRepository for SqliteReceivedInvoiceRepository {
   ) -> Result<Vec<ReceivedInvoice>, ReportError> {
     let rows = sqlx::query_as::<_, ReceivedInvoiceRow>(
             r#"
-            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
             FROM received_invoices WHERE company_id = ?1 ORDER BY created_at DESC
             "#,
         )
@@ -133,7 +164,7 @@ impl ReceivedInvoiceRepository for SqliteReceivedInvoiceRepository {
   ) -> Result<Vec<ReceivedInvoice>, ReportError> {
     let rows = sqlx::query_as::<_, ReceivedInvoiceRow>(
             r#"
-            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
             FROM received_invoices
             WHERE company_id = ?1 AND invoice_date >= ?2 AND invoice_date <= ?3
             ORDER BY invoice_date
@@ -154,10 +185,10 @@ impl ReceivedInvoiceRepository for SqliteReceivedInvoiceRepository {
   ) -> Result<Vec<ReceivedInvoice>, ReportError> {
     let rows = sqlx::query_as::<_, ReceivedInvoiceRow>(
             r#"
-            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, created_at, updated_at
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
             FROM received_invoices
             WHERE company_id = ?1
-              AND id NOT IN (SELECT matched_received_invoice_id FROM bank_transactions WHERE matched_received_invoice_id IS NOT NULL)
+              AND NOT EXISTS (SELECT 1 FROM match_allocations a WHERE a.received_invoice_id = received_invoices.id)
             ORDER BY created_at DESC
             "#,
         )
@@ -168,6 +199,63 @@ impl ReceivedInvoiceRepository for SqliteReceivedInvoiceRepository {
     rows.into_iter().map(|r| r.try_into()).collect()
   }
 
+  async fn find_open_by_company(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<ReceivedInvoice>, ReportError> {
+    let rows = sqlx::query_as::<_, ReceivedInvoiceRow>(
+            r#"
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
+            FROM received_invoices
+            WHERE company_id = ?1 AND status <> 'paid'
+            ORDER BY due_date IS NULL, due_date, created_at
+            "#,
+        )
+        .bind(company_id.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn find_by_vendor_id(&self, vendor_id: Uuid) -> Result<Vec<ReceivedInvoice>, ReportError> {
+    let rows = sqlx::query_as::<_, ReceivedInvoiceRow>(
+            r#"
+            SELECT id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
+            FROM received_invoices WHERE vendor_id = ?1 ORDER BY created_at DESC
+            "#,
+        )
+        .bind(vendor_id.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn update(&self, invoice: ReceivedInvoice) -> Result<ReceivedInvoice, ReportError> {
+    let row = sqlx::query_as::<_, ReceivedInvoiceRow>(
+            r#"
+            UPDATE received_invoices
+            SET vat_amount = ?2, due_date = ?3, vendor_iban = ?4, payment_reference = ?5, status = ?6, paid_on = ?7, vendor_id = ?8, updated_at = ?9
+            WHERE id = ?1
+            RETURNING id, company_id, vendor_name, amount, currency, invoice_date, invoice_number, pdf_path, pdf_drive_file_id, notes, vat_amount, due_date, vendor_iban, payment_reference, status, paid_on, vendor_id, created_at, updated_at
+            "#,
+        )
+        .bind(invoice.id.to_string())
+        .bind(invoice.vat_amount.to_string())
+        .bind(invoice.due_date.map(|d| d.format("%Y-%m-%d").to_string()))
+        .bind(invoice.vendor_iban.as_deref())
+        .bind(invoice.payment_reference.as_deref())
+        .bind(invoice.status.as_str())
+        .bind(invoice.paid_on.map(|d| d.format("%Y-%m-%d").to_string()))
+        .bind(invoice.vendor_id.map(|id| id.to_string()))
+        .bind(invoice.updated_at.to_rfc3339())
+        .fetch_optional(&self.pool)
+        .await?;
+
+    row.ok_or(ReportError::ReceivedInvoiceNotFound)?.try_into()
+  }
+
   async fn delete(&self, id: Uuid) -> Result<(), ReportError> {
     sqlx::query("DELETE FROM received_invoices WHERE id = ?1")
       .bind(id.to_string())
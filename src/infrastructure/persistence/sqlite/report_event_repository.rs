@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::report::{ReportError, ReportEvent, ReportEventKind, ReportEventRepository};
+
+#[derive(Debug, FromRow)]
+struct ReportEventRow {
+  id: String,
+  report_id: String,
+  company_id: String,
+  user_id: Option<String>,
+  kind: String,
+  version: i32,
+  reason: Option<String>,
+  created_at: String,
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, ReportError> {
+  Uuid::parse_str(value)
+    .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))
+}
+
+impl TryFrom<ReportEventRow> for ReportEvent {
+  type Error = ReportError;
+
+  fn try_from(row: ReportEventRow) -> Result<Self, Self::Error> {
+    Ok(ReportEvent {
+      id: parse_uuid(&row.id)?,
+      report_id: parse_uuid(&row.report_id)?,
+      company_id: parse_uuid(&row.company_id)?,
+      user_id: row.user_id.as_deref().map(parse_uuid).transpose()?,
+      kind: ReportEventKind::try_from(row.kind.as_str())?,
+      version: row.version,
+      reason: row.reason,
+      created_at: DateTime::parse_from_rfc3339(&row.created_at)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+    })
+  }
+}
+
+pub struct SqliteReportEventRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteReportEventRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl ReportEventRepository for SqliteReportEventRepository {
+  async fn append(&self, event: ReportEvent) -> Result<ReportEvent, ReportError> {
+    let row = sqlx::query_as::<_, ReportEventRow>(
+      r#"
+      INSERT INTO report_events (id, report_id, company_id, user_id, kind, version, reason, created_at)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+      RETURNING id, report_id, company_id, user_id, kind, version, reason, created_at
+      "#,
+    )
+    .bind(event.id.to_string())
+    .bind(event.report_id.to_string())
+    .bind(event.company_id.to_string())
+    .bind(event.user_id.map(|id| id.to_string()))
+    .bind(event.kind.as_str())
+    .bind(event.version)
+    .bind(event.reason.as_deref())
+    .bind(event.created_at.to_rfc3339())
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<ReportEvent>, ReportError> {
+    let rows = sqlx::query_as::<_, ReportEventRow>(
+      r#"
+      SELECT id, report_id, company_id, user_id, kind, version, reason, created_at
+      FROM report_events
+      WHERE report_id = ?1
+      ORDER BY created_at DESC
+      "#,
+    )
+    .bind(report_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+}
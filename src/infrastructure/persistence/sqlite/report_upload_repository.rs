@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::report::{ReportError, ReportUpload, ReportUploadRepository};
+
+#[derive(Debug, FromRow)]
+struct ReportUploadRow {
+  report_id: String,
+  path: String,
+  cloud_id: String,
+  content_hash: Option<String>,
+  version: i32,
+  uploaded_at: String,
+}
+
+impl TryFrom<ReportUploadRow> for ReportUpload {
+  type Error = ReportError;
+
+  fn try_from(row: ReportUploadRow) -> Result<Self, Self::Error> {
+    Ok(ReportUpload {
+      report_id: Uuid::parse_str(&row.report_id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      path: row.path,
+      cloud_id: row.cloud_id,
+      content_hash: row.content_hash,
+      version: row.version,
+      uploaded_at: DateTime::parse_from_rfc3339(&row.uploaded_at)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+    })
+  }
+}
+
+pub struct SqliteReportUploadRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteReportUploadRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl ReportUploadRepository for SqliteReportUploadRepository {
+  async fn find_by_report_id(&self, report_id: Uuid) -> Result<Vec<ReportUpload>, ReportError> {
+    let rows = sqlx::query_as::<_, ReportUploadRow>(
+      r#"
+      SELECT report_id, path, cloud_id, content_hash, version, uploaded_at
+      FROM report_uploads
+      WHERE report_id = ?1
+      ORDER BY path
+      "#,
+    )
+    .bind(report_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn upsert(&self, upload: ReportUpload) -> Result<ReportUpload, ReportError> {
+    let row = sqlx::query_as::<_, ReportUploadRow>(
+      r#"
+      INSERT INTO report_uploads (report_id, path, cloud_id, content_hash, version, uploaded_at)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+      ON CONFLICT (report_id, path) DO UPDATE
+      SET cloud_id = excluded.cloud_id, content_hash = excluded.content_hash,
+          version = excluded.version, uploaded_at = excluded.uploaded_at
+      RETURNING report_id, path, cloud_id, content_hash, version, uploaded_at
+      "#,
+    )
+    .bind(upload.report_id.to_string())
+    .bind(&upload.path)
+    .bind(&upload.cloud_id)
+    .bind(upload.content_hash.as_deref())
+    .bind(upload.version)
+    .bind(upload.uploaded_at.to_rfc3339())
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn delete(&self, report_id: Uuid, path: &str) -> Result<(), ReportError> {
+    sqlx::query("DELETE FROM report_uploads WHERE report_id = ?1 AND path = ?2")
+      .bind(report_id.to_string())
+      .bind(path)
+      .execute(&self.pool)
+      .await?;
+
+    Ok(())
+  }
+}
@@ -0,0 +1,186 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::retention::{
+  BlockedDeletion, BlockedDeletionRepository, RetainedRecord, RetentionError, RetentionPolicy,
+  RetentionPolicyRepository,
+};
+
+#[derive(Debug, FromRow)]
+struct RetentionPolicyRow {
+  company_id: String,
+  retention_years: i64,
+  updated_by: Option<String>,
+  updated_at: String,
+}
+
+#[derive(Debug, FromRow)]
+struct BlockedDeletionRow {
+  id: String,
+  company_id: String,
+  record_type: String,
+  record_id: String,
+  label: String,
+  user_id: Option<String>,
+  retained_until: String,
+  attempted_at: String,
+}
+
+fn query_failed(error: impl ToString) -> RetentionError {
+  RetentionError::Repository(RepositoryError::QueryFailed(error.to_string()))
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, RetentionError> {
+  Uuid::parse_str(value).map_err(query_failed)
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, RetentionError> {
+  DateTime::parse_from_rfc3339(value)
+    .map(|dt| dt.with_timezone(&Utc))
+    .map_err(query_failed)
+}
+
+fn timestamp(value: DateTime<Utc>) -> String {
+  value.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+impl TryFrom<RetentionPolicyRow> for RetentionPolicy {
+  type Error = RetentionError;
+
+  fn try_from(row: RetentionPolicyRow) -> Result<Self, Self::Error> {
+    Ok(RetentionPolicy {
+      company_id: parse_uuid(&row.company_id)?,
+      retention_years: u32::try_from(row.retention_years).map_err(query_failed)?,
+      updated_by: row.updated_by.as_deref().map(parse_uuid).transpose()?,
+      updated_at: parse_timestamp(&row.updated_at)?,
+    })
+  }
+}
+
+impl TryFrom<BlockedDeletionRow> for BlockedDeletion {
+  type Error = RetentionError;
+
+  fn try_from(row: BlockedDeletionRow) -> Result<Self, Self::Error> {
+    Ok(BlockedDeletion {
+      id: parse_uuid(&row.id)?,
+      company_id: parse_uuid(&row.company_id)?,
+      record: RetainedRecord::try_from(row.record_type.as_str())?,
+      record_id: parse_uuid(&row.record_id)?,
+      label: row.label,
+      user_id: row.user_id.as_deref().map(parse_uuid).transpose()?,
+      retained_until: NaiveDate::parse_from_str(&row.retained_until, "%Y-%m-%d")
+        .map_err(query_failed)?,
+      attempted_at: parse_timestamp(&row.attempted_at)?,
+    })
+  }
+}
+
+pub struct SqliteRetentionPolicyRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteRetentionPolicyRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl RetentionPolicyRepository for SqliteRetentionPolicyRepository {
+  async fn find(&self, company_id: Uuid) -> Result<Option<RetentionPolicy>, RetentionError> {
+    let row = sqlx::query_as::<_, RetentionPolicyRow>(
+      r#"
+      SELECT company_id, retention_years, updated_by, updated_at
+      FROM retention_policies
+      WHERE company_id = ?1
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn save(&self, policy: RetentionPolicy) -> Result<RetentionPolicy, RetentionError> {
+    let row = sqlx::query_as::<_, RetentionPolicyRow>(
+      r#"
+      INSERT INTO retention_policies (company_id, retention_years, updated_by, updated_at)
+      VALUES (?1, ?2, ?3, ?4)
+      ON CONFLICT (company_id) DO UPDATE SET
+        retention_years = excluded.retention_years,
+        updated_by = excluded.updated_by,
+        updated_at = excluded.updated_at
+      RETURNING company_id, retention_years, updated_by, updated_at
+      "#,
+    )
+    .bind(policy.company_id.to_string())
+    .bind(policy.retention_years as i64)
+    .bind(policy.updated_by.map(|id| id.to_string()))
+    .bind(timestamp(policy.updated_at))
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+}
+
+pub struct SqliteBlockedDeletionRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteBlockedDeletionRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl BlockedDeletionRepository for SqliteBlockedDeletionRepository {
+  async fn create(&self, attempt: BlockedDeletion) -> Result<BlockedDeletion, RetentionError> {
+    let row = sqlx::query_as::<_, BlockedDeletionRow>(
+      r#"
+      INSERT INTO retention_blocked_deletions (id, company_id, record_type, record_id, label, user_id, retained_until, attempted_at)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+      RETURNING id, company_id, record_type, record_id, label, user_id, retained_until, attempted_at
+      "#,
+    )
+    .bind(attempt.id.to_string())
+    .bind(attempt.company_id.to_string())
+    .bind(attempt.record.as_str())
+    .bind(attempt.record_id.to_string())
+    .bind(&attempt.label)
+    .bind(attempt.user_id.map(|id| id.to_string()))
+    .bind(attempt.retained_until.format("%Y-%m-%d").to_string())
+    .bind(timestamp(attempt.attempted_at))
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+    limit: i64,
+  ) -> Result<Vec<BlockedDeletion>, RetentionError> {
+    let rows = sqlx::query_as::<_, BlockedDeletionRow>(
+      r#"
+      SELECT id, company_id, record_type, record_id, label, user_id, retained_until, attempted_at
+      FROM retention_blocked_deletions
+      WHERE company_id = ?1
+      ORDER BY attempted_at DESC
+      LIMIT ?2
+      "#,
+    )
+    .bind(company_id.to_string())
+    .bind(limit)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+}
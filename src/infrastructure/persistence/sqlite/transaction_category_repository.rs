@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::report::{ReportError, TransactionCategory, TransactionCategoryRepository};
+
+#[derive(Debug, FromRow)]
+struct TransactionCategoryRow {
+  id: String,
+  company_id: String,
+  name: String,
+  created_at: String,
+}
+
+impl TryFrom<TransactionCategoryRow> for TransactionCategory {
+  type Error = ReportError;
+
+  fn try_from(row: TransactionCategoryRow) -> Result<Self, Self::Error> {
+    Ok(TransactionCategory {
+      id: Uuid::parse_str(&row.id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      company_id: Uuid::parse_str(&row.company_id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      name: row.name,
+      created_at: DateTime::parse_from_rfc3339(&row.created_at)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+    })
+  }
+}
+
+pub struct SqliteTransactionCategoryRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteTransactionCategoryRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl TransactionCategoryRepository for SqliteTransactionCategoryRepository {
+  async fn create(
+    &self,
+    category: TransactionCategory,
+  ) -> Result<TransactionCategory, ReportError> {
+    let row = sqlx::query_as::<_, TransactionCategoryRow>(
+      r#"
+            INSERT INTO transaction_categories (id, company_id, name, created_at)
+            VALUES (?1, ?2, ?3, ?4)
+            RETURNING id, company_id, name, created_at
+            "#,
+    )
+    .bind(category.id.to_string())
+    .bind(category.company_id.to_string())
+    .bind(&category.name)
+    .bind(category.created_at.to_rfc3339())
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<TransactionCategory>, ReportError> {
+    let row = sqlx::query_as::<_, TransactionCategoryRow>(
+      "SELECT id, company_id, name, created_at FROM transaction_categories WHERE id = ?1",
+    )
+    .bind(id.to_string())
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_by_company_id(
+    &self,
+    company_id: Uuid,
+  ) -> Result<Vec<TransactionCategory>, ReportError> {
+    let rows = sqlx::query_as::<_, TransactionCategoryRow>(
+      r#"
+            SELECT id, company_id, name, created_at
+            FROM transaction_categories WHERE company_id = ?1 ORDER BY name
+            "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn delete(&self, id: Uuid) -> Result<(), ReportError> {
+    sqlx::query("DELETE FROM transaction_categories WHERE id = ?1")
+      .bind(id.to_string())
+      .execute(&self.pool)
+      .await?;
+    Ok(())
+  }
+}
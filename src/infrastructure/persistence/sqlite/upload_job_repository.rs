@@ -0,0 +1,276 @@
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::company::StorageProvider;
+use crate::domain::storage::{
+  StorageError, SyncStatus, UploadAction, UploadJob, UploadJobRepository, UploadStatus,
+  UploadTarget,
+};
+
+#[derive(Debug, FromRow)]
+struct UploadJobRow {
+  id: String,
+  company_id: String,
+  target: String,
+  provider: String,
+  action: String,
+  owner_id: String,
+  file_key: String,
+  path: String,
+  folder_id: String,
+  replace_file_id: Option<String>,
+  local_path: String,
+  mime_type: String,
+  content_hash: Option<String>,
+  requested_by: Option<String>,
+  status: String,
+  attempts: i32,
+  next_attempt_at: String,
+  last_error: Option<String>,
+  cloud_id: Option<String>,
+  sync_status: Option<String>,
+  synced_at: Option<String>,
+  created_at: String,
+  updated_at: String,
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, StorageError> {
+  Uuid::parse_str(value)
+    .map_err(|e| StorageError::Repository(RepositoryError::QueryFailed(e.to_string())))
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, StorageError> {
+  DateTime::parse_from_rfc3339(value)
+    .map(|dt| dt.with_timezone(&Utc))
+    .map_err(|e| StorageError::Repository(RepositoryError::QueryFailed(e.to_string())))
+}
+
+/// Fixed-width UTC timestamps, so next_attempt_at compares as text
+fn timestamp(value: DateTime<Utc>) -> String {
+  value.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+impl TryFrom<UploadJobRow> for UploadJob {
+  type Error = StorageError;
+
+  fn try_from(row: UploadJobRow) -> Result<Self, Self::Error> {
+    Ok(UploadJob {
+      id: parse_uuid(&row.id)?,
+      company_id: parse_uuid(&row.company_id)?,
+      target: UploadTarget::try_from(row.target.as_str())?,
+      provider: row
+        .provider
+        .parse::<StorageProvider>()
+        .map_err(StorageError::Validation)?,
+      owner_id: parse_uuid(&row.owner_id)?,
+      action: UploadAction::try_from(row.action.as_str())?,
+      file_key: row.file_key,
+      path: row.path,
+      folder_id: row.folder_id,
+      replace_file_id: row.replace_file_id,
+      local_path: row.local_path,
+      mime_type: row.mime_type,
+      content_hash: row.content_hash,
+      requested_by: row.requested_by.as_deref().map(parse_uuid).transpose()?,
+      status: UploadStatus::try_from(row.status.as_str())?,
+      attempts: row.attempts,
+      next_attempt_at: parse_timestamp(&row.next_attempt_at)?,
+      last_error: row.last_error,
+      cloud_id: row.cloud_id,
+      sync_status: row
+        .sync_status
+        .as_deref()
+        .map(SyncStatus::try_from)
+        .transpose()?,
+      synced_at: row.synced_at.as_deref().map(parse_timestamp).transpose()?,
+      created_at: parse_timestamp(&row.created_at)?,
+      updated_at: parse_timestamp(&row.updated_at)?,
+    })
+  }
+}
+
+pub struct SqliteUploadJobRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteUploadJobRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl UploadJobRepository for SqliteUploadJobRepository {
+  async fn create(&self, job: UploadJob) -> Result<UploadJob, StorageError> {
+    let row = sqlx::query_as::<_, UploadJobRow>(
+      r#"
+      INSERT INTO upload_jobs (id, company_id, target, owner_id, path, folder_id, replace_file_id, local_path, mime_type, content_hash, requested_by, status, attempts, next_attempt_at, last_error, cloud_id, sync_status, synced_at, created_at, updated_at, file_key, provider, action)
+      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23)
+      RETURNING id, company_id, target, owner_id, path, folder_id, replace_file_id, local_path, mime_type, content_hash, requested_by, status, attempts, next_attempt_at, last_error, cloud_id, sync_status, synced_at, created_at, updated_at, file_key, provider, action
+      "#,
+    )
+    .bind(job.id.to_string())
+    .bind(job.company_id.to_string())
+    .bind(job.target.as_str())
+    .bind(job.owner_id.to_string())
+    .bind(&job.path)
+    .bind(&job.folder_id)
+    .bind(&job.replace_file_id)
+    .bind(&job.local_path)
+    .bind(&job.mime_type)
+    .bind(&job.content_hash)
+    .bind(job.requested_by.map(|id| id.to_string()))
+    .bind(job.status.as_str())
+    .bind(job.attempts)
+    .bind(timestamp(job.next_attempt_at))
+    .bind(&job.last_error)
+    .bind(&job.cloud_id)
+    .bind(job.sync_status.map(|s| s.as_str()))
+    .bind(job.synced_at.map(timestamp))
+    .bind(timestamp(job.created_at))
+    .bind(timestamp(job.updated_at))
+    .bind(&job.file_key)
+    .bind(job.provider.as_str())
+    .bind(job.action.as_str())
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn update(&self, job: UploadJob) -> Result<UploadJob, StorageError> {
+    let row = sqlx::query_as::<_, UploadJobRow>(
+      r#"
+      UPDATE upload_jobs
+      SET folder_id = ?2, replace_file_id = ?3, local_path = ?4, mime_type = ?5,
+          content_hash = ?6, requested_by = ?7, status = ?8, attempts = ?9,
+          next_attempt_at = ?10, last_error = ?11, cloud_id = ?12, sync_status = ?13,
+          synced_at = ?14, updated_at = ?15, path = ?16, file_key = ?17,
+          provider = ?18, action = ?19
+      WHERE id = ?1
+      RETURNING id, company_id, target, owner_id, path, folder_id, replace_file_id, local_path, mime_type, content_hash, requested_by, status, attempts, next_attempt_at, last_error, cloud_id, sync_status, synced_at, created_at, updated_at, file_key, provider, action
+      "#,
+    )
+    .bind(job.id.to_string())
+    .bind(&job.folder_id)
+    .bind(&job.replace_file_id)
+    .bind(&job.local_path)
+    .bind(&job.mime_type)
+    .bind(&job.content_hash)
+    .bind(job.requested_by.map(|id| id.to_string()))
+    .bind(job.status.as_str())
+    .bind(job.attempts)
+    .bind(timestamp(job.next_attempt_at))
+    .bind(&job.last_error)
+    .bind(&job.cloud_id)
+    .bind(job.sync_status.map(|s| s.as_str()))
+    .bind(job.synced_at.map(timestamp))
+    .bind(timestamp(job.updated_at))
+    .bind(&job.path)
+    .bind(&job.file_key)
+    .bind(job.provider.as_str())
+    .bind(job.action.as_str())
+    .fetch_optional(&self.pool)
+    .await?
+    .ok_or(StorageError::JobNotFound)?;
+
+    row.try_into()
+  }
+
+  async fn delete(&self, id: Uuid) -> Result<(), StorageError> {
+    sqlx::query("DELETE FROM upload_jobs WHERE id = ?1")
+      .bind(id.to_string())
+      .execute(&self.pool)
+      .await?;
+
+    Ok(())
+  }
+
+  async fn claim(
+    &self,
+    job: UploadJob,
+    now: DateTime<Utc>,
+  ) -> Result<Option<UploadJob>, StorageError> {
+    let row = sqlx::query_as::<_, UploadJobRow>(
+      r#"
+      UPDATE upload_jobs
+      SET status = ?2, next_attempt_at = ?3, updated_at = ?4
+      WHERE id = ?1 AND status IN ('pending', 'in_progress') AND next_attempt_at <= ?5
+      RETURNING id, company_id, target, owner_id, path, folder_id, replace_file_id, local_path, mime_type, content_hash, requested_by, status, attempts, next_attempt_at, last_error, cloud_id, sync_status, synced_at, created_at, updated_at, file_key, provider, action
+      "#,
+    )
+    .bind(job.id.to_string())
+    .bind(job.status.as_str())
+    .bind(timestamp(job.next_attempt_at))
+    .bind(timestamp(job.updated_at))
+    .bind(timestamp(now))
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_due(
+    &self,
+    target: UploadTarget,
+    now: DateTime<Utc>,
+    limit: i64,
+  ) -> Result<Vec<UploadJob>, StorageError> {
+    let rows = sqlx::query_as::<_, UploadJobRow>(
+      r#"
+      SELECT id, company_id, target, owner_id, path, folder_id, replace_file_id, local_path, mime_type, content_hash, requested_by, status, attempts, next_attempt_at, last_error, cloud_id, sync_status, synced_at, created_at, updated_at, file_key, provider, action
+      FROM upload_jobs
+      WHERE target = ?1 AND status IN ('pending', 'in_progress') AND next_attempt_at <= ?2
+      ORDER BY next_attempt_at
+      LIMIT ?3
+      "#,
+    )
+    .bind(target.as_str())
+    .bind(timestamp(now))
+    .bind(limit)
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn find_by_owner(
+    &self,
+    target: UploadTarget,
+    owner_id: Uuid,
+  ) -> Result<Vec<UploadJob>, StorageError> {
+    let rows = sqlx::query_as::<_, UploadJobRow>(
+      r#"
+      SELECT id, company_id, target, owner_id, path, folder_id, replace_file_id, local_path, mime_type, content_hash, requested_by, status, attempts, next_attempt_at, last_error, cloud_id, sync_status, synced_at, created_at, updated_at, file_key, provider, action
+      FROM upload_jobs
+      WHERE target = ?1 AND owner_id = ?2
+      ORDER BY path
+      "#,
+    )
+    .bind(target.as_str())
+    .bind(owner_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn find_uploaded(&self, company_id: Uuid) -> Result<Vec<UploadJob>, StorageError> {
+    let rows = sqlx::query_as::<_, UploadJobRow>(
+      r#"
+      SELECT id, company_id, target, owner_id, path, folder_id, replace_file_id, local_path, mime_type, content_hash, requested_by, status, attempts, next_attempt_at, last_error, cloud_id, sync_status, synced_at, created_at, updated_at, file_key, provider, action
+      FROM upload_jobs
+      WHERE company_id = ?1 AND status = 'succeeded' AND cloud_id IS NOT NULL
+      ORDER BY created_at
+      "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+}
@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, SqlitePool};
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::domain::auth::errors::RepositoryError;
+use crate::domain::report::{ReportError, Vendor, VendorRepository};
+
+#[derive(Debug, FromRow)]
+struct VendorRow {
+  id: String,
+  company_id: String,
+  name: String,
+  registry_code: Option<String>,
+  vat_number: Option<String>,
+  ibans: String,
+  default_category_id: Option<String>,
+  default_vat_rate: Option<String>,
+  created_at: String,
+  updated_at: String,
+}
+
+impl TryFrom<VendorRow> for Vendor {
+  type Error = ReportError;
+
+  fn try_from(row: VendorRow) -> Result<Self, Self::Error> {
+    Ok(Vendor {
+      id: Uuid::parse_str(&row.id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      company_id: Uuid::parse_str(&row.company_id)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      name: row.name,
+      registry_code: row.registry_code,
+      vat_number: row.vat_number,
+      ibans: serde_json::from_str(&row.ibans)
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      default_category_id: row
+        .default_category_id
+        .map(|s| Uuid::parse_str(&s))
+        .transpose()
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      default_vat_rate: row
+        .default_vat_rate
+        .map(|s| Decimal::from_str(&s))
+        .transpose()
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      created_at: DateTime::parse_from_rfc3339(&row.created_at)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+      updated_at: DateTime::parse_from_rfc3339(&row.updated_at)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))?,
+    })
+  }
+}
+
+fn ibans_json(vendor: &Vendor) -> Result<String, ReportError> {
+  serde_json::to_string(&vendor.ibans)
+    .map_err(|e| ReportError::Repository(RepositoryError::QueryFailed(e.to_string())))
+}
+
+pub struct SqliteVendorRepository {
+  pool: SqlitePool,
+}
+
+impl SqliteVendorRepository {
+  pub fn new(pool: SqlitePool) -> Self {
+    Self { pool }
+  }
+}
+
+#[async_trait]
+impl VendorRepository for SqliteVendorRepository {
+  async fn create(&self, vendor: Vendor) -> Result<Vendor, ReportError> {
+    let row = sqlx::query_as::<_, VendorRow>(
+      r#"
+            INSERT INTO vendors (id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            RETURNING id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at
+            "#,
+    )
+    .bind(vendor.id.to_string())
+    .bind(vendor.company_id.to_string())
+    .bind(&vendor.name)
+    .bind(&vendor.registry_code)
+    .bind(&vendor.vat_number)
+    .bind(ibans_json(&vendor)?)
+    .bind(vendor.default_category_id.map(|id| id.to_string()))
+    .bind(vendor.default_vat_rate.map(|r| r.to_string()))
+    .bind(vendor.created_at.to_rfc3339())
+    .bind(vendor.updated_at.to_rfc3339())
+    .fetch_one(&self.pool)
+    .await?;
+
+    row.try_into()
+  }
+
+  async fn find_by_id(&self, id: Uuid) -> Result<Option<Vendor>, ReportError> {
+    let row = sqlx::query_as::<_, VendorRow>(
+      r#"
+            SELECT id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at
+            FROM vendors WHERE id = ?1
+            "#,
+    )
+    .bind(id.to_string())
+    .fetch_optional(&self.pool)
+    .await?;
+
+    row.map(|r| r.try_into()).transpose()
+  }
+
+  async fn find_by_company_id(&self, company_id: Uuid) -> Result<Vec<Vendor>, ReportError> {
+    let rows = sqlx::query_as::<_, VendorRow>(
+      r#"
+            SELECT id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at
+            FROM vendors WHERE company_id = ?1 ORDER BY name COLLATE NOCASE
+            "#,
+    )
+    .bind(company_id.to_string())
+    .fetch_all(&self.pool)
+    .await?;
+
+    rows.into_iter().map(|r| r.try_into()).collect()
+  }
+
+  async fn update(&self, vendor: Vendor) -> Result<Vendor, ReportError> {
+    let row = sqlx::query_as::<_, VendorRow>(
+      r#"
+            UPDATE vendors
+            SET name = ?2, registry_code = ?3, vat_number = ?4, ibans = ?5, default_category_id = ?6, default_vat_rate = ?7, updated_at = ?8
+            WHERE id = ?1
+            RETURNING id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at
+            "#,
+    )
+    .bind(vendor.id.to_string())
+    .bind(&vendor.name)
+    .bind(&vendor.registry_code)
+    .bind(&vendor.vat_number)
+    .bind(ibans_json(&vendor)?)
+    .bind(vendor.default_category_id.map(|id| id.to_string()))
+    .bind(vendor.default_vat_rate.map(|r| r.to_string()))
+    .bind(vendor.updated_at.to_rfc3339())
+    .fetch_optional(&self.pool)
+    .await?
+    .ok_or(ReportError::VendorNotFound)?;
+
+    row.try_into()
+  }
+
+  async fn merge(&self, from_id: Uuid, into: Vendor) -> Result<Vendor, ReportError> {
+    let mut tx = self.pool.begin().await?;
+
+    sqlx::query("UPDATE received_invoices SET vendor_id = ?2 WHERE vendor_id = ?1")
+      .bind(from_id.to_string())
+      .bind(into.id.to_string())
+      .execute(&mut *tx)
+      .await?;
+    sqlx::query("UPDATE bank_transactions SET vendor_id = ?2 WHERE vendor_id = ?1")
+      .bind(from_id.to_string())
+      .bind(into.id.to_string())
+      .execute(&mut *tx)
+      .await?;
+    // Delete first, so `into` can take over the registry code
+    sqlx::query("DELETE FROM vendors WHERE id = ?1")
+      .bind(from_id.to_string())
+      .execute(&mut *tx)
+      .await?;
+
+    let row = sqlx::query_as::<_, VendorRow>(
+      r#"
+            UPDATE vendors
+            SET name = ?2, registry_code = ?3, vat_number = ?4, ibans = ?5, default_category_id = ?6, default_vat_rate = ?7, updated_at = ?8
+            WHERE id = ?1
+            RETURNING id, company_id, name, registry_code, vat_number, ibans, default_category_id, default_vat_rate, created_at, updated_at
+            "#,
+    )
+    .bind(into.id.to_string())
+    .bind(&into.name)
+    .bind(&into.registry_code)
+    .bind(&into.vat_number)
+    .bind(ibans_json(&into)?)
+    .bind(into.default_category_id.map(|id| id.to_string()))
+    .bind(into.default_vat_rate.map(|r| r.to_string()))
+    .bind(into.updated_at.to_rfc3339())
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or(ReportError::VendorNotFound)?;
+
+    tx.commit().await?;
+    row.try_into()
+  }
+}
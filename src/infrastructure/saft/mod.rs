@@ -0,0 +1,3 @@
+mod oecd_xml_writer;
+
+pub use oecd_xml_writer::OecdSaftXmlWriter;
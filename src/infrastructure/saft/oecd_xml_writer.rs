@@ -0,0 +1,792 @@
+use quick_xml::Writer;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use rust_decimal::Decimal;
+
+use crate::domain::saft::{
+  AuditFile, AuditFileWriter, Party, PartyAddress, Payment, SaftError, SourceInvoice, TaxAmount,
+  entities::{invoice_side_totals, payment_side_totals},
+};
+
+const NAMESPACE: &str = "urn:OECD:StandardAuditFile-Tax:2.00";
+const AUDIT_FILE_VERSION: &str = "2.00";
+const SOFTWARE_NAME: &str = "TaxByte";
+/// Accrual accounting
+const TAX_ACCOUNTING_BASIS: &str = "A";
+/// SourceID for documents and entries not entered by a user
+const SYSTEM_SOURCE_ID: &str = "system";
+
+// Maximum lengths of the SAF-T text types
+const SHORT_TEXT: usize = 18;
+const MIDDLE1_TEXT: usize = 35;
+const MIDDLE2_TEXT: usize = 70;
+const LONG_TEXT: usize = 256;
+
+/// Writes audit files as OECD SAF-T 2.00 XML
+///
+/// Sections without data are left out. Amounts are written as recorded:
+/// documents in a currency other than the default one carry their
+/// `CurrencyCode` but are not converted.
+#[derive(Default)]
+pub struct OecdSaftXmlWriter;
+
+impl OecdSaftXmlWriter {
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+impl AuditFileWriter for OecdSaftXmlWriter {
+  fn write(&self, audit_file: &AuditFile) -> Result<Vec<u8>, SaftError> {
+    let mut xml = XmlOut::new();
+    xml.declaration()?;
+    xml.open_with_namespace("AuditFile")?;
+
+    write_header(&mut xml, audit_file)?;
+    write_master_files(&mut xml, audit_file)?;
+    write_general_ledger_entries(&mut xml, audit_file)?;
+    write_source_documents(&mut xml, audit_file)?;
+
+    xml.close("AuditFile")?;
+    Ok(xml.finish())
+  }
+}
+
+fn write_header(xml: &mut XmlOut, audit_file: &AuditFile) -> Result<(), SaftError> {
+  let header = &audit_file.header;
+
+  xml.open("Header")?;
+  xml.leaf("AuditFileVersion", AUDIT_FILE_VERSION)?;
+  xml.leaf("AuditFileCountry", &header.country)?;
+  xml.leaf("AuditFileDateCreated", &header.created_on.to_string())?;
+  xml.text("SoftwareCompanyName", SOFTWARE_NAME, LONG_TEXT)?;
+  xml.text("SoftwareID", SOFTWARE_NAME, LONG_TEXT)?;
+  xml.text("SoftwareVersion", env!("CARGO_PKG_VERSION"), SHORT_TEXT)?;
+
+  xml.open("Company")?;
+  write_company_structure(xml, &header.company)?;
+  xml.close("Company")?;
+
+  xml.leaf("DefaultCurrencyCode", &header.currency)?;
+  xml.open("SelectionCriteria")?;
+  xml.leaf("SelectionStartDate", &header.period.start.to_string())?;
+  xml.leaf("SelectionEndDate", &header.period.end.to_string())?;
+  xml.close("SelectionCriteria")?;
+  xml.leaf("TaxAccountingBasis", TAX_ACCOUNTING_BASIS)?;
+  xml.close("Header")
+}
+
+fn write_company_structure(xml: &mut XmlOut, party: &Party) -> Result<(), SaftError> {
+  if let Some(registration_number) = &party.registration_number {
+    xml.text("RegistrationNumber", registration_number, MIDDLE1_TEXT)?;
+  }
+  xml.text("Name", &party.name, MIDDLE2_TEXT)?;
+  write_address(xml, "Address", &party.address)?;
+
+  if let Some(vat_number) = &party.vat_number {
+    xml.open("TaxRegistration")?;
+    xml.text("TaxRegistrationNumber", vat_number, MIDDLE1_TEXT)?;
+    xml.leaf("TaxType", "VAT")?;
+    xml.close("TaxRegistration")?;
+  }
+
+  for iban in &party.ibans {
+    xml.open("BankAccount")?;
+    xml.text("IBANNumber", iban, MIDDLE1_TEXT)?;
+    xml.close("BankAccount")?;
+  }
+
+  Ok(())
+}
+
+/// City and postal code are mandatory in SAF-T; unknown ones are left empty
+fn write_address(xml: &mut XmlOut, element: &str, address: &PartyAddress) -> Result<(), SaftError> {
+  xml.open(element)?;
+  if let Some(street) = &address.street {
+    xml.text("StreetName", street, MIDDLE2_TEXT)?;
+  }
+  xml.text(
+    "City",
+    address.city.as_deref().unwrap_or_default(),
+    MIDDLE1_TEXT,
+  )?;
+  xml.text(
+    "PostalCode",
+    address.postal_code.as_deref().unwrap_or_default(),
+    SHORT_TEXT,
+  )?;
+  if let Some(region) = &address.region {
+    xml.text("Region", region, MIDDLE1_TEXT)?;
+  }
+  if let Some(country) = &address.country {
+    xml.leaf("Country", country)?;
+  }
+  xml.close(element)
+}
+
+fn write_master_files(xml: &mut XmlOut, audit_file: &AuditFile) -> Result<(), SaftError> {
+  xml.open("MasterFiles")?;
+
+  if !audit_file.accounts.is_empty() {
+    xml.open("GeneralLedgerAccounts")?;
+    for account in &audit_file.accounts {
+      xml.open("Account")?;
+      xml.text("AccountID", &account.code, MIDDLE2_TEXT)?;
+      xml.text("AccountDescription", &account.name, LONG_TEXT)?;
+      if let Some(standard_code) = &account.standard_code {
+        xml.text("StandardAccountID", standard_code, MIDDLE1_TEXT)?;
+      }
+      xml.text("AccountType", &account.account_type, SHORT_TEXT)?;
+      write_balance(xml, "Opening", account.opening_balance)?;
+      write_balance(xml, "Closing", account.closing_balance)?;
+      xml.close("Account")?;
+    }
+    xml.close("GeneralLedgerAccounts")?;
+  }
+
+  write_parties(
+    xml,
+    "Customers",
+    "Customer",
+    "CustomerID",
+    &audit_file.customers,
+  )?;
+  write_parties(
+    xml,
+    "Suppliers",
+    "Supplier",
+    "SupplierID",
+    &audit_file.suppliers,
+  )?;
+
+  if !audit_file.tax_codes.is_empty() {
+    xml.open("TaxTable")?;
+    xml.open("TaxTableEntry")?;
+    xml.leaf("TaxType", "VAT")?;
+    xml.text("Description", "Value added tax", LONG_TEXT)?;
+    for tax_code in &audit_file.tax_codes {
+      xml.open("TaxCodeDetails")?;
+      xml.text("TaxCode", &tax_code.code, SHORT_TEXT)?;
+      xml.text("Description", &tax_code.description, LONG_TEXT)?;
+      xml.leaf("TaxPercentage", &tax_code.rate.normalize().to_string())?;
+      xml.leaf("Country", &audit_file.header.country)?;
+      xml.close("TaxCodeDetails")?;
+    }
+    xml.close("TaxTableEntry")?;
+    xml.close("TaxTable")?;
+  }
+
+  xml.close("MasterFiles")
+}
+
+/// Signed balance as a debit or credit balance element
+fn write_balance(xml: &mut XmlOut, prefix: &str, balance: Decimal) -> Result<(), SaftError> {
+  if balance.is_sign_negative() && !balance.is_zero() {
+    xml.leaf(&format!("{}CreditBalance", prefix), &amount(-balance))
+  } else {
+    xml.leaf(&format!("{}DebitBalance", prefix), &amount(balance))
+  }
+}
+
+fn write_parties(
+  xml: &mut XmlOut,
+  section: &str,
+  element: &str,
+  id_element: &str,
+  parties: &[Party],
+) -> Result<(), SaftError> {
+  if parties.is_empty() {
+    return Ok(());
+  }
+
+  xml.open(section)?;
+  for party in parties {
+    xml.open(element)?;
+    write_company_structure(xml, party)?;
+    xml.text(id_element, &party.id, MIDDLE1_TEXT)?;
+    if let Some(account_code) = &party.account_code {
+      xml.text("AccountID", account_code, MIDDLE2_TEXT)?;
+    }
+    xml.close(element)?;
+  }
+  xml.close(section)
+}
+
+fn write_general_ledger_entries(xml: &mut XmlOut, audit_file: &AuditFile) -> Result<(), SaftError> {
+  if audit_file.journals.is_empty() {
+    return Ok(());
+  }
+
+  let (count, total_debit, total_credit) = audit_file.ledger_totals();
+  xml.open("GeneralLedgerEntries")?;
+  xml.leaf("NumberOfEntries", &count.to_string())?;
+  xml.leaf("TotalDebit", &amount(total_debit))?;
+  xml.leaf("TotalCredit", &amount(total_credit))?;
+
+  for journal in &audit_file.journals {
+    xml.open("Journal")?;
+    xml.text("JournalID", &journal.id, SHORT_TEXT)?;
+    xml.text("Description", &journal.description, LONG_TEXT)?;
+    xml.leaf("Type", &journal.kind)?;
+
+    for transaction in &journal.transactions {
+      xml.open("Transaction")?;
+      xml.text("TransactionID", &transaction.id, MIDDLE2_TEXT)?;
+      xml.leaf("Period", &transaction.period().to_string())?;
+      xml.leaf("PeriodYear", &transaction.period_year().to_string())?;
+      xml.leaf("TransactionDate", &transaction.date.to_string())?;
+      xml.text("SourceID", &transaction.source_id, MIDDLE1_TEXT)?;
+      xml.text("Description", &transaction.description, LONG_TEXT)?;
+      xml.leaf(
+        "SystemEntryDate",
+        &transaction.system_entry_date.to_string(),
+      )?;
+      xml.leaf("GLPostingDate", &transaction.date.to_string())?;
+
+      for line in &transaction.lines {
+        xml.open("Line")?;
+        xml.text("RecordID", &line.record_id, SHORT_TEXT)?;
+        xml.text("AccountID", &line.account_code, MIDDLE2_TEXT)?;
+        xml.text("Description", &line.description, LONG_TEXT)?;
+        if line.debit.is_zero() && !line.credit.is_zero() {
+          write_amount(xml, "CreditAmount", line.credit, None)?;
+        } else {
+          write_amount(xml, "DebitAmount", line.debit, None)?;
+        }
+        xml.close("Line")?;
+      }
+
+      xml.close("Transaction")?;
+    }
+
+    xml.close("Journal")?;
+  }
+
+  xml.close("GeneralLedgerEntries")
+}
+
+fn write_source_documents(xml: &mut XmlOut, audit_file: &AuditFile) -> Result<(), SaftError> {
+  if audit_file.sales_invoices.is_empty()
+    && audit_file.purchase_invoices.is_empty()
+    && audit_file.payments.is_empty()
+  {
+    return Ok(());
+  }
+
+  let currency = audit_file.header.currency.as_str();
+  xml.open("SourceDocuments")?;
+  write_invoices(xml, "SalesInvoices", &audit_file.sales_invoices, currency)?;
+  write_invoices(
+    xml,
+    "PurchaseInvoices",
+    &audit_file.purchase_invoices,
+    currency,
+  )?;
+  write_payments(xml, &audit_file.payments, currency)?;
+  xml.close("SourceDocuments")
+}
+
+fn write_invoices(
+  xml: &mut XmlOut,
+  section: &str,
+  invoices: &[SourceInvoice],
+  default_currency: &str,
+) -> Result<(), SaftError> {
+  if invoices.is_empty() {
+    return Ok(());
+  }
+
+  let (party_element, id_element) = if section == "SalesInvoices" {
+    ("CustomerInfo", "CustomerID")
+  } else {
+    ("SupplierInfo", "SupplierID")
+  };
+
+  let (total_debit, total_credit) = invoice_side_totals(invoices);
+  xml.open(section)?;
+  xml.leaf("NumberOfEntries", &invoices.len().to_string())?;
+  xml.leaf("TotalDebit", &amount(total_debit))?;
+  xml.leaf("TotalCredit", &amount(total_credit))?;
+
+  for invoice in invoices {
+    let currency = foreign_currency(&invoice.currency, default_currency);
+
+    xml.open("Invoice")?;
+    xml.text("InvoiceNo", &invoice.number, MIDDLE1_TEXT)?;
+    xml.open(party_element)?;
+    xml.text(id_element, &invoice.party_id, MIDDLE1_TEXT)?;
+    write_address(xml, "BillingAddress", &invoice.billing_address)?;
+    xml.close(party_element)?;
+    xml.leaf("InvoiceDate", &invoice.date.to_string())?;
+    xml.leaf("InvoiceType", "Invoice")?;
+    xml.leaf("SourceID", SYSTEM_SOURCE_ID)?;
+
+    for line in &invoice.lines {
+      xml.open("Line")?;
+      xml.leaf("LineNumber", &line.number.to_string())?;
+      if let Some(account_code) = &line.account_code {
+        xml.text("AccountID", account_code, MIDDLE2_TEXT)?;
+      }
+      if let Some(quantity) = line.quantity {
+        xml.leaf("Quantity", &quantity.round_dp(6).normalize().to_string())?;
+      }
+      if let Some(unit_price) = line.unit_price {
+        xml.leaf("UnitPrice", &unit_price.round_dp(8).normalize().to_string())?;
+      }
+      xml.text("Description", &line.description, LONG_TEXT)?;
+      write_amount(xml, "InvoiceLineAmount", line.amount, currency)?;
+      xml.leaf("DebitCreditIndicator", line.side.indicator())?;
+      if let Some(tax) = &line.tax {
+        write_tax_information(xml, "TaxInformation", tax, currency)?;
+      }
+      xml.close("Line")?;
+    }
+
+    xml.open("InvoiceDocumentTotals")?;
+    for tax in invoice.tax_totals() {
+      write_tax_information(xml, "TaxInformationTotals", &tax, currency)?;
+    }
+    xml.leaf("NetTotal", &amount(invoice.net_total()))?;
+    xml.leaf("GrossTotal", &amount(invoice.gross_total()))?;
+    xml.close("InvoiceDocumentTotals")?;
+
+    xml.close("Invoice")?;
+  }
+
+  xml.close(section)
+}
+
+fn write_tax_information(
+  xml: &mut XmlOut,
+  element: &str,
+  tax: &TaxAmount,
+  currency: Option<&str>,
+) -> Result<(), SaftError> {
+  xml.open(element)?;
+  xml.leaf("TaxType", "VAT")?;
+  xml.text("TaxCode", &tax.code, SHORT_TEXT)?;
+  xml.leaf("TaxPercentage", &tax.rate.normalize().to_string())?;
+  xml.leaf("TaxBase", &amount(tax.base))?;
+  write_amount(xml, "TaxAmount", tax.amount, currency)?;
+  xml.close(element)
+}
+
+fn write_payments(
+  xml: &mut XmlOut,
+  payments: &[Payment],
+  default_currency: &str,
+) -> Result<(), SaftError> {
+  if payments.is_empty() {
+    return Ok(());
+  }
+
+  let (total_debit, total_credit) = payment_side_totals(payments);
+  xml.open("Payments")?;
+  xml.leaf("NumberOfEntries", &payments.len().to_string())?;
+  xml.leaf("TotalDebit", &amount(total_debit))?;
+  xml.leaf("TotalCredit", &amount(total_credit))?;
+
+  for payment in payments {
+    let currency = foreign_currency(&payment.currency, default_currency);
+
+    xml.open("Payment")?;
+    xml.text("PaymentRefNo", &payment.reference, MIDDLE1_TEXT)?;
+    xml.leaf("TransactionDate", &payment.date.to_string())?;
+    xml.text("Description", &payment.description, LONG_TEXT)?;
+    xml.leaf("SourceID", SYSTEM_SOURCE_ID)?;
+
+    for (index, line) in payment.lines.iter().enumerate() {
+      xml.open("Line")?;
+      xml.leaf("LineNumber", &(index + 1).to_string())?;
+      if let Some(source_document) = &line.source_document {
+        xml.text("SourceDocumentID", source_document, MIDDLE1_TEXT)?;
+      }
+      xml.text("AccountID", &payment.account_code, MIDDLE2_TEXT)?;
+      if let Some(customer_id) = &line.customer_id {
+        xml.text("CustomerID", customer_id, MIDDLE1_TEXT)?;
+      }
+      if let Some(supplier_id) = &line.supplier_id {
+        xml.text("SupplierID", supplier_id, MIDDLE1_TEXT)?;
+      }
+      xml.leaf("DebitCreditIndicator", payment.side.indicator())?;
+      write_amount(xml, "PaymentLineAmount", line.amount, currency)?;
+      xml.close("Line")?;
+    }
+
+    xml.open("DocumentTotals")?;
+    xml.leaf("NetTotal", &amount(payment.amount))?;
+    xml.leaf("GrossTotal", &amount(payment.amount))?;
+    xml.close("DocumentTotals")?;
+
+    xml.close("Payment")?;
+  }
+
+  xml.close("Payments")
+}
+
+/// SAF-T AmountStructure
+fn write_amount(
+  xml: &mut XmlOut,
+  element: &str,
+  value: Decimal,
+  currency: Option<&str>,
+) -> Result<(), SaftError> {
+  xml.open(element)?;
+  xml.leaf("Amount", &amount(value))?;
+  if let Some(currency) = currency {
+    xml.leaf("CurrencyCode", currency)?;
+  }
+  xml.close(element)
+}
+
+/// The document currency, if it differs from the file's default currency
+fn foreign_currency<'a>(currency: &'a str, default_currency: &str) -> Option<&'a str> {
+  (!currency.eq_ignore_ascii_case(default_currency)).then_some(currency)
+}
+
+fn amount(value: Decimal) -> String {
+  format!("{:.2}", value.round_dp(2))
+}
+
+/// Thin wrapper over the quick-xml writer that maps errors and keeps the
+/// section writers short
+struct XmlOut {
+  writer: Writer<Vec<u8>>,
+}
+
+impl XmlOut {
+  fn new() -> Self {
+    Self {
+      writer: Writer::new_with_indent(Vec::new(), b' ', 2),
+    }
+  }
+
+  fn declaration(&mut self) -> Result<(), SaftError> {
+    self.event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))
+  }
+
+  fn open_with_namespace(&mut self, name: &str) -> Result<(), SaftError> {
+    self.event(Event::Start(
+      BytesStart::new(name).with_attributes([("xmlns", NAMESPACE)]),
+    ))
+  }
+
+  fn open(&mut self, name: &str) -> Result<(), SaftError> {
+    self.event(Event::Start(BytesStart::new(name)))
+  }
+
+  fn close(&mut self, name: &str) -> Result<(), SaftError> {
+    self.event(Event::End(BytesEnd::new(name)))
+  }
+
+  fn leaf(&mut self, name: &str, value: &str) -> Result<(), SaftError> {
+    self.open(name)?;
+    self.event(Event::Text(BytesText::new(value)))?;
+    self.close(name)
+  }
+
+  /// Text element cut to the schema's maximum length for it
+  fn text(&mut self, name: &str, value: &str, max_chars: usize) -> Result<(), SaftError> {
+    let value = value.trim();
+    if value.chars().count() <= max_chars {
+      self.leaf(name, value)
+    } else {
+      let truncated: String = value.chars().take(max_chars).collect();
+      self.leaf(name, truncated.trim_end())
+    }
+  }
+
+  fn event(&mut self, event: Event<'_>) -> Result<(), SaftError> {
+    self
+      .writer
+      .write_event(event)
+      .map_err(|e| SaftError::Serialization(e.to_string()))
+  }
+
+  fn finish(self) -> Vec<u8> {
+    self.writer.into_inner()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::domain::saft::{
+    AuditFileHeader, DebitCredit, GeneralLedgerAccount, Journal, JournalTransaction,
+    JournalTransactionLine, PaymentLine, SaftPeriod, SourceInvoiceLine, TaxCode,
+  };
+  use chrono::NaiveDate;
+  use libxml::parser::Parser;
+  use libxml::schemas::{SchemaParserContext, SchemaValidationContext};
+  use rust_decimal_macros::dec;
+
+  fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+  }
+
+  fn address() -> PartyAddress {
+    PartyAddress {
+      street: Some("Narva mnt 5".to_string()),
+      city: Some("Tallinn".to_string()),
+      postal_code: Some("10117".to_string()),
+      region: None,
+      country: Some("EE".to_string()),
+    }
+  }
+
+  fn party(id: &str, name: &str) -> Party {
+    Party {
+      id: id.to_string(),
+      registration_number: Some("12345678".to_string()),
+      name: name.to_string(),
+      address: address(),
+      vat_number: Some("EE123456789".to_string()),
+      ibans: vec!["EE382200221020145685".to_string()],
+      account_code: Some("1200".to_string()),
+    }
+  }
+
+  fn sample_audit_file() -> AuditFile {
+    let tax = TaxAmount {
+      code: "VAT24".to_string(),
+      rate: dec!(24),
+      base: dec!(1000.00),
+      amount: dec!(240.00),
+    };
+
+    AuditFile {
+      header: AuditFileHeader {
+        created_on: date(4, 2),
+        country: "EE".to_string(),
+        currency: "EUR".to_string(),
+        period: SaftPeriod::new(date(3, 1), date(3, 31)).unwrap(),
+        company: party("", "Acme OÜ"),
+      },
+      accounts: vec![
+        GeneralLedgerAccount {
+          code: "1020".to_string(),
+          name: "Bank".to_string(),
+          account_type: "asset".to_string(),
+          standard_code: Some("1020".to_string()),
+          opening_balance: dec!(500.00),
+          closing_balance: dec!(1740.00),
+        },
+        GeneralLedgerAccount {
+          code: "3000".to_string(),
+          name: "Sales revenue".to_string(),
+          account_type: "revenue".to_string(),
+          standard_code: None,
+          opening_balance: Decimal::ZERO,
+          closing_balance: dec!(-1000.00),
+        },
+      ],
+      customers: vec![party("c0ffee", "Customer & Sons")],
+      suppliers: vec![Party {
+        vat_number: None,
+        ibans: vec![],
+        address: PartyAddress::default(),
+        ..party("V1", "Hosting Ltd")
+      }],
+      tax_codes: vec![TaxCode {
+        code: "VAT24".to_string(),
+        description: "VAT 24%".to_string(),
+        rate: dec!(24),
+      }],
+      journals: vec![Journal {
+        id: "SALES".to_string(),
+        description: "Sales".to_string(),
+        kind: "SALES".to_string(),
+        transactions: vec![JournalTransaction {
+          id: "e1".to_string(),
+          date: date(3, 5),
+          description: "Invoice INV-1 issued".to_string(),
+          source_id: "system".to_string(),
+          system_entry_date: date(3, 5),
+          lines: vec![
+            JournalTransactionLine {
+              record_id: "1".to_string(),
+              account_code: "1200".to_string(),
+              description: "Receivables".to_string(),
+              debit: dec!(1240.00),
+              credit: Decimal::ZERO,
+            },
+            JournalTransactionLine {
+              record_id: "2".to_string(),
+              account_code: "3000".to_string(),
+              description: "Sales revenue".to_string(),
+              debit: Decimal::ZERO,
+              credit: dec!(1240.00),
+            },
+          ],
+        }],
+      }],
+      sales_invoices: vec![SourceInvoice {
+        number: "INV-1".to_string(),
+        party_id: "c0ffee".to_string(),
+        billing_address: address(),
+        date: date(3, 5),
+        currency: "EUR".to_string(),
+        lines: vec![SourceInvoiceLine {
+          number: 1,
+          account_code: Some("3000".to_string()),
+          description: "Consulting ".repeat(40),
+          quantity: Some(dec!(10)),
+          unit_price: Some(dec!(100.00)),
+          amount: dec!(1000.00),
+          side: DebitCredit::Credit,
+          tax: Some(tax),
+        }],
+      }],
+      purchase_invoices: vec![SourceInvoice {
+        number: "H-77".to_string(),
+        party_id: "V1".to_string(),
+        billing_address: PartyAddress::default(),
+        date: date(3, 10),
+        currency: "USD".to_string(),
+        lines: vec![SourceInvoiceLine {
+          number: 1,
+          account_code: Some("4000".to_string()),
+          description: "Hosting".to_string(),
+          quantity: None,
+          unit_price: None,
+          amount: dec!(49.9),
+          side: DebitCredit::Debit,
+          tax: None,
+        }],
+      }],
+      payments: vec![Payment {
+        reference: "a1b2".to_string(),
+        date: date(3, 20),
+        description: "INV-1".to_string(),
+        currency: "EUR".to_string(),
+        account_code: "1020".to_string(),
+        side: DebitCredit::Debit,
+        amount: dec!(1240.00),
+        lines: vec![
+          PaymentLine {
+            source_document: Some("INV-1".to_string()),
+            customer_id: Some("c0ffee".to_string()),
+            supplier_id: None,
+            amount: dec!(1000.00),
+          },
+          PaymentLine {
+            source_document: None,
+            customer_id: None,
+            supplier_id: None,
+            amount: dec!(240.00),
+          },
+        ],
+      }],
+    }
+  }
+
+  /// Subset of the schema kept in the repository
+  const SUBSET_SCHEMA: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/schemas/saft/oecd-saf-t-2.00-subset.xsd"
+  );
+
+  /// The schema as published by the OECD, fetched next to the subset
+  /// (see schemas/saft/README.md)
+  const OFFICIAL_SCHEMA: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/schemas/saft/oecd-saf-t-2.00.xsd"
+  );
+
+  /// Validate against the subset, and against the OECD schema when it has been fetched.
+  /// With `SAFT_REQUIRE_OFFICIAL_SCHEMA` set (as in CI) a missing OECD schema is an error.
+  fn validate(xml: &[u8]) -> Result<(), Vec<String>> {
+    validate_with(SUBSET_SCHEMA, xml)?;
+
+    if std::path::Path::new(OFFICIAL_SCHEMA).exists() {
+      validate_with(OFFICIAL_SCHEMA, xml)
+    } else if std::env::var_os("SAFT_REQUIRE_OFFICIAL_SCHEMA").is_some() {
+      Err(vec![format!(
+        "{} is missing; run scripts/fetch-saft-schema.sh",
+        OFFICIAL_SCHEMA
+      )])
+    } else {
+      Ok(())
+    }
+  }
+
+  fn validate_with(schema_path: &str, xml: &[u8]) -> Result<(), Vec<String>> {
+    let mut schema_parser = SchemaParserContext::from_file(schema_path);
+    let mut validator =
+      SchemaValidationContext::from_parser(&mut schema_parser).map_err(|errors| {
+        errors
+          .iter()
+          .map(|e| e.message().to_string())
+          .collect::<Vec<_>>()
+      })?;
+
+    let document = Parser::default()
+      .parse_string(xml)
+      .map_err(|e| vec![format!("{:?}", e)])?;
+    validator
+      .validate_document(&document)
+      .map_err(|errors| errors.iter().map(|e| e.message().to_string()).collect())
+  }
+
+  #[test]
+  fn test_written_file_validates_against_schema() {
+    let xml = OecdSaftXmlWriter::new()
+      .write(&sample_audit_file())
+      .unwrap();
+
+    validate(&xml).unwrap();
+
+    let xml = String::from_utf8(xml).unwrap();
+    assert!(xml.contains("<AuditFile xmlns=\"urn:OECD:StandardAuditFile-Tax:2.00\">"));
+    assert!(xml.contains("<Name>Customer &amp; Sons</Name>"));
+    assert!(xml.contains("<ClosingCreditBalance>1000.00</ClosingCreditBalance>"));
+    assert!(xml.contains("<Amount>49.90</Amount>"));
+    assert!(xml.contains("<CurrencyCode>USD</CurrencyCode>"));
+  }
+
+  #[test]
+  fn test_empty_period_validates_against_schema() {
+    let mut audit_file = sample_audit_file();
+    audit_file.accounts.clear();
+    audit_file.customers.clear();
+    audit_file.suppliers.clear();
+    audit_file.tax_codes.clear();
+    audit_file.journals.clear();
+    audit_file.sales_invoices.clear();
+    audit_file.purchase_invoices.clear();
+    audit_file.payments.clear();
+
+    let xml = OecdSaftXmlWriter::new().write(&audit_file).unwrap();
+
+    validate(&xml).unwrap();
+    let xml = String::from_utf8(xml).unwrap();
+    assert!(!xml.contains("<SourceDocuments>"));
+    assert!(!xml.contains("<GeneralLedgerEntries>"));
+  }
+
+  #[test]
+  fn test_written_file_validates_against_official_schema() {
+    let mut empty = sample_audit_file();
+    empty.accounts.clear();
+    empty.journals.clear();
+    empty.sales_invoices.clear();
+    empty.purchase_invoices.clear();
+    empty.payments.clear();
+
+    for audit_file in [sample_audit_file(), empty] {
+      let xml = OecdSaftXmlWriter::new().write(&audit_file).unwrap();
+      validate(&xml).unwrap();
+    }
+  }
+
+  #[test]
+  fn test_schema_rejects_out_of_order_elements() {
+    let xml = OecdSaftXmlWriter::new()
+      .write(&sample_audit_file())
+      .unwrap();
+    let broken = String::from_utf8(xml)
+      .unwrap()
+      .replace("<AuditFileVersion>2.00</AuditFileVersion>", "");
+
+    assert!(validate_with(SUBSET_SCHEMA, broken.as_bytes()).is_err());
+  }
+}
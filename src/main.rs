@@ -18,16 +18,20 @@ use taxbyte::{
     AddCompanyMemberUseCase, ArchiveBankAccountUseCase, ConnectGoogleDriveUseCase,
     CreateBankAccountUseCase, CreateCompanyUseCase, DisconnectGoogleDriveUseCase,
     GetBankAccountsUseCase, GetCompanyDetailsUseCase, GetUserCompaniesUseCase,
-    RemoveCompanyMemberUseCase, SetActiveBankAccountUseCase, SetActiveCompanyUseCase,
-    TestDriveConnectionUseCase, UpdateBankAccountUseCase, UpdateCompanyProfileUseCase,
+    ListDriveFoldersUseCase, RemoveCompanyMemberUseCase, SetActiveBankAccountUseCase,
+    SetActiveCompanyUseCase, TestDriveConnectionUseCase, UpdateBankAccountUseCase,
+    UpdateCompanyProfileUseCase,
   },
   application::invoice::{
     ArchiveCustomerUseCase, ArchiveInvoiceUseCase, ArchiveTemplateUseCase,
     ChangeInvoiceStatusUseCase, CreateCustomerUseCase, CreateInvoiceFromTemplateUseCase,
     CreateInvoiceUseCase, CreateTemplateFromInvoiceUseCase, DeleteInvoiceUseCase,
-    GetInvoiceDetailsUseCase, ListArchivedInvoicesUseCase, ListCustomersUseCase,
-    ListInvoicesUseCase, ListTemplatesUseCase, PermanentlyDeleteInvoiceUseCase,
-    ReuploadInvoiceUseCase, UnarchiveInvoiceUseCase, UpdateCustomerUseCase,
+    GetInvoiceBrandingUseCase, GetInvoiceDetailsUseCase, ListArchivedInvoicesUseCase,
+    ListCustomersUseCase, ListInvoiceEventsUseCase, ListInvoicesUseCase, ListTemplatesUseCase,
+    PermanentlyDeleteInvoiceUseCase, PreviewInvoiceBrandingUseCase, ProcessInvoiceUploadsUseCase,
+    RetryInvoiceUploadsUseCase, ReuploadInvoiceUseCase, UnarchiveInvoiceUseCase,
+    UpdateCustomerUseCase, UpdateInvoiceBrandingUseCase, UpdateInvoiceUseCase,
+    VerifyInvoiceChainCommand, VerifyInvoiceChainUseCase,
   },
   domain::auth::{
     ports::{LoginAttemptRepository, SessionRepository, UserRepository},
@@ -40,20 +44,28 @@ use taxbyte::{
     },
     services::CompanyService,
   },
+  domain::document::{DataKeyRepository, DocumentRepository, DocumentService},
   domain::invoice::{
     InvoiceService, InvoiceServiceDependencies,
     ports::{
-      CustomerRepository, InvoiceLineItemRepository, InvoiceRepository,
-      InvoiceTemplateLineItemRepository, InvoiceTemplateRepository,
+      CustomerRepository, InvoiceBrandingRepository, InvoiceChainRepository,
+      InvoiceEventRepository, InvoiceLineItemRepository, InvoiceRepository,
+      InvoiceTemplateLineItemRepository, InvoiceTemplateRepository, InvoiceUnitOfWorkFactory,
     },
   },
+  domain::ledger::{AccountRepository, JournalEntryRepository, LedgerService},
   domain::report::ports::{
-    BankTransactionRepository as BankTxRepo, MonthlyReportRepository,
-    ReceivedInvoiceRepository as RecvInvRepo,
+    BankStatementRepository, BankTransactionRepository as BankTxRepo, CategorySuggestionRepository,
+    MatchAllocationRepository, MatchingRuleRepository, MonthlyReportRepository,
+    ReceivedInvoiceRepository as RecvInvRepo, ReportEventRepository, ReportUploadRepository,
+    TransactionCategoryRepository, VendorRepository,
   },
+  domain::retention::{BlockedDeletionRepository, RetentionPolicyRepository, RetentionService},
+  domain::storage::{UploadJobRepository, UploadOutboxService},
   infrastructure::{
     cloud::{GoogleOAuthManager, MockOAuthManager, OAuthManager},
     config::{Config, DatabaseBackend},
+    documents::{EnvelopeCipher, FsDocumentBlobStore},
     security::{AesTokenEncryption, Argon2PasswordHasher, SecureTokenGenerator},
   },
 };
@@ -95,9 +107,28 @@ async fn main() -> std::io::Result<()> {
   let invoice_line_item_repo: Arc<dyn InvoiceLineItemRepository>;
   let invoice_template_repo: Arc<dyn InvoiceTemplateRepository>;
   let invoice_template_line_item_repo: Arc<dyn InvoiceTemplateLineItemRepository>;
+  let invoice_branding_repo: Arc<dyn InvoiceBrandingRepository>;
+  let invoice_event_repo: Arc<dyn InvoiceEventRepository>;
+  let invoice_chain_repo: Arc<dyn InvoiceChainRepository>;
+  let invoice_unit_of_work: Arc<dyn InvoiceUnitOfWorkFactory>;
   let monthly_report_repo: Arc<dyn MonthlyReportRepository>;
   let bank_transaction_repo: Arc<dyn BankTxRepo>;
   let received_invoice_repo: Arc<dyn RecvInvRepo>;
+  let transaction_category_repo: Arc<dyn TransactionCategoryRepository>;
+  let category_suggestion_repo: Arc<dyn CategorySuggestionRepository>;
+  let matching_rule_repo: Arc<dyn MatchingRuleRepository>;
+  let match_allocation_repo: Arc<dyn MatchAllocationRepository>;
+  let bank_statement_repo: Arc<dyn BankStatementRepository>;
+  let report_event_repo: Arc<dyn ReportEventRepository>;
+  let report_upload_repo: Arc<dyn ReportUploadRepository>;
+  let vendor_repo: Arc<dyn VendorRepository>;
+  let upload_job_repo: Arc<dyn UploadJobRepository>;
+  let document_repo: Arc<dyn DocumentRepository>;
+  let data_key_repo: Arc<dyn DataKeyRepository>;
+  let retention_policy_repo: Arc<dyn RetentionPolicyRepository>;
+  let blocked_deletion_repo: Arc<dyn BlockedDeletionRepository>;
+  let ledger_account_repo: Arc<dyn AccountRepository>;
+  let journal_entry_repo: Arc<dyn JournalEntryRepository>;
 
   match config.database.backend {
     DatabaseBackend::Postgres => {
@@ -213,9 +244,30 @@ async fn main() -> std::io::Result<()> {
       invoice_template_line_item_repo = Arc::new(PostgresInvoiceTemplateLineItemRepository::new(
         db_pool.clone(),
       ));
+      invoice_branding_repo = Arc::new(PostgresInvoiceBrandingRepository::new(db_pool.clone()));
+      invoice_event_repo = Arc::new(PostgresInvoiceEventRepository::new(db_pool.clone()));
+      invoice_chain_repo = Arc::new(PostgresInvoiceChainRepository::new(db_pool.clone()));
+      invoice_unit_of_work = Arc::new(PostgresInvoiceUnitOfWorkFactory::new(db_pool.clone()));
       monthly_report_repo = Arc::new(PostgresMonthlyReportRepository::new(db_pool.clone()));
       bank_transaction_repo = Arc::new(PostgresBankTransactionRepository::new(db_pool.clone()));
       received_invoice_repo = Arc::new(PostgresReceivedInvoiceRepository::new(db_pool.clone()));
+      transaction_category_repo =
+        Arc::new(PostgresTransactionCategoryRepository::new(db_pool.clone()));
+      category_suggestion_repo =
+        Arc::new(PostgresCategorySuggestionRepository::new(db_pool.clone()));
+      matching_rule_repo = Arc::new(PostgresMatchingRuleRepository::new(db_pool.clone()));
+      match_allocation_repo = Arc::new(PostgresMatchAllocationRepository::new(db_pool.clone()));
+      bank_statement_repo = Arc::new(PostgresBankStatementRepository::new(db_pool.clone()));
+      report_event_repo = Arc::new(PostgresReportEventRepository::new(db_pool.clone()));
+      report_upload_repo = Arc::new(PostgresReportUploadRepository::new(db_pool.clone()));
+      vendor_repo = Arc::new(PostgresVendorRepository::new(db_pool.clone()));
+      upload_job_repo = Arc::new(PostgresUploadJobRepository::new(db_pool.clone()));
+      document_repo = Arc::new(PostgresDocumentRepository::new(db_pool.clone()));
+      data_key_repo = Arc::new(PostgresDataKeyRepository::new(db_pool.clone()));
+      retention_policy_repo = Arc::new(PostgresRetentionPolicyRepository::new(db_pool.clone()));
+      blocked_deletion_repo = Arc::new(PostgresBlockedDeletionRepository::new(db_pool.clone()));
+      ledger_account_repo = Arc::new(PostgresAccountRepository::new(db_pool.clone()));
+      journal_entry_repo = Arc::new(PostgresJournalEntryRepository::new(db_pool.clone()));
     }
 
     DatabaseBackend::Sqlite => {
@@ -282,9 +334,29 @@ async fn main() -> std::io::Result<()> {
       invoice_template_line_item_repo = Arc::new(SqliteInvoiceTemplateLineItemRepository::new(
         db_pool.clone(),
       ));
+      invoice_branding_repo = Arc::new(SqliteInvoiceBrandingRepository::new(db_pool.clone()));
+      invoice_event_repo = Arc::new(SqliteInvoiceEventRepository::new(db_pool.clone()));
+      invoice_chain_repo = Arc::new(SqliteInvoiceChainRepository::new(db_pool.clone()));
+      invoice_unit_of_work = Arc::new(SqliteInvoiceUnitOfWorkFactory::new(db_pool.clone()));
       monthly_report_repo = Arc::new(SqliteMonthlyReportRepository::new(db_pool.clone()));
       bank_transaction_repo = Arc::new(SqliteBankTransactionRepository::new(db_pool.clone()));
       received_invoice_repo = Arc::new(SqliteReceivedInvoiceRepository::new(db_pool.clone()));
+      transaction_category_repo =
+        Arc::new(SqliteTransactionCategoryRepository::new(db_pool.clone()));
+      category_suggestion_repo = Arc::new(SqliteCategorySuggestionRepository::new(db_pool.clone()));
+      matching_rule_repo = Arc::new(SqliteMatchingRuleRepository::new(db_pool.clone()));
+      match_allocation_repo = Arc::new(SqliteMatchAllocationRepository::new(db_pool.clone()));
+      bank_statement_repo = Arc::new(SqliteBankStatementRepository::new(db_pool.clone()));
+      report_event_repo = Arc::new(SqliteReportEventRepository::new(db_pool.clone()));
+      report_upload_repo = Arc::new(SqliteReportUploadRepository::new(db_pool.clone()));
+      vendor_repo = Arc::new(SqliteVendorRepository::new(db_pool.clone()));
+      upload_job_repo = Arc::new(SqliteUploadJobRepository::new(db_pool.clone()));
+      document_repo = Arc::new(SqliteDocumentRepository::new(db_pool.clone()));
+      data_key_repo = Arc::new(SqliteDataKeyRepository::new(db_pool.clone()));
+      retention_policy_repo = Arc::new(SqliteRetentionPolicyRepository::new(db_pool.clone()));
+      blocked_deletion_repo = Arc::new(SqliteBlockedDeletionRepository::new(db_pool.clone()));
+      ledger_account_repo = Arc::new(SqliteAccountRepository::new(db_pool.clone()));
+      journal_entry_repo = Arc::new(SqliteJournalEntryRepository::new(db_pool.clone()));
     }
   }
 
@@ -322,6 +394,31 @@ async fn main() -> std::io::Result<()> {
     active_bank_account_repo.clone(),
   ));
 
+  // The security encryption key encrypts OAuth tokens and wraps the data
+  // keys of encrypted documents
+  let token_encryption = Arc::new(
+    AesTokenEncryption::new(&config.security.encryption_key_base64)
+      .expect("Failed to create token encryption"),
+  );
+
+  // Initialize document store
+  let document_service = Arc::new(DocumentService::new(
+    document_repo,
+    Arc::new(FsDocumentBlobStore::new(&config.documents.root_dir)),
+    Arc::new(EnvelopeCipher::new(token_encryption.clone(), data_key_repo)),
+    config.documents.encrypt_at_rest,
+  ));
+  if config.documents.encrypt_at_rest {
+    tracing::info!("Encrypting stored documents at rest");
+  }
+
+  // Initialize retention policies
+  let retention_service = Arc::new(RetentionService::new(
+    retention_policy_repo,
+    blocked_deletion_repo,
+    company_member_repo.clone(),
+  ));
+
   // Initialize invoice service
   let invoice_service = Arc::new(InvoiceService::new(InvoiceServiceDependencies {
     invoice_repo: invoice_repo.clone(),
@@ -332,8 +429,69 @@ async fn main() -> std::io::Result<()> {
     bank_account_repo: bank_account_repo.clone(),
     template_repo: invoice_template_repo.clone(),
     template_line_item_repo: invoice_template_line_item_repo.clone(),
+    branding_repo: invoice_branding_repo.clone(),
+    event_repo: invoice_event_repo.clone(),
+    chain_repo: invoice_chain_repo.clone(),
+    unit_of_work: invoice_unit_of_work,
   }));
 
+  // Initialize report service
+  let report_service = Arc::new(taxbyte::domain::report::ReportService::new(
+    taxbyte::domain::report::ReportServiceDependencies {
+      report_repo: monthly_report_repo.clone(),
+      statement_repo: bank_statement_repo,
+      transaction_repo: bank_transaction_repo.clone(),
+      received_invoice_repo: received_invoice_repo.clone(),
+      category_repo: transaction_category_repo.clone(),
+      suggestion_repo: category_suggestion_repo,
+      rule_repo: matching_rule_repo,
+      allocation_repo: match_allocation_repo,
+      event_repo: report_event_repo,
+      upload_repo: report_upload_repo,
+      vendor_repo,
+    },
+  ));
+
+  // Maintenance commands (e.g. `taxbyte verify-invoice-chain <company_id>`)
+  // run against the configured database and exit instead of serving HTTP
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  if let Some(command) = args.first() {
+    let encrypt_documents = Arc::new(
+      taxbyte::application::document::EncryptDocumentsUseCase::new(
+        document_service.clone(),
+        company_repo.clone(),
+        invoice_repo.clone(),
+        received_invoice_repo.clone(),
+        monthly_report_repo.clone(),
+        bank_transaction_repo.clone(),
+      ),
+    );
+    let import_documents = Arc::new(
+      taxbyte::application::document::ImportLegacyFilesUseCase::new(
+        document_service.clone(),
+        company_repo.clone(),
+        invoice_repo.clone(),
+        received_invoice_repo.clone(),
+        monthly_report_repo.clone(),
+        bank_transaction_repo.clone(),
+      ),
+    );
+    return run_cli_command(
+      command,
+      &args[1..],
+      invoice_service.clone(),
+      encrypt_documents,
+      import_documents,
+      Arc::new(
+        taxbyte::application::report::MergeSameNameVendorsUseCase::new(
+          report_service.clone(),
+          company_repo.clone(),
+        ),
+      ),
+    )
+    .await;
+  }
+
   // Initialize use cases
   let register_use_case = Arc::new(RegisterUserUseCase::new(auth_service.clone()));
   let login_use_case = Arc::new(LoginUserUseCase::new(auth_service.clone()));
@@ -356,9 +514,6 @@ async fn main() -> std::io::Result<()> {
     company_member_repo.clone(),
   ));
   let update_profile_use_case = Arc::new(UpdateCompanyProfileUseCase::new(company_service.clone()));
-  let update_storage_config_use_case = Arc::new(
-    taxbyte::application::company::UpdateStorageConfigUseCase::new(company_service.clone()),
-  );
 
   // Initialize bank account use cases
   let create_bank_account_use_case =
@@ -372,9 +527,11 @@ async fn main() -> std::io::Result<()> {
     Arc::new(SetActiveBankAccountUseCase::new(company_service.clone()));
 
   // Initialize OAuth dependencies and use cases
-  let token_encryption = Arc::new(
-    AesTokenEncryption::new(&config.security.encryption_key_base64)
-      .expect("Failed to create token encryption"),
+  let update_storage_config_use_case = Arc::new(
+    taxbyte::application::company::UpdateStorageConfigUseCase::new(
+      company_service.clone(),
+      token_encryption.clone(),
+    ),
   );
 
   let oauth_manager: Arc<dyn OAuthManager> =
@@ -428,8 +585,26 @@ async fn main() -> std::io::Result<()> {
   let disconnect_google_drive_use_case =
     Arc::new(DisconnectGoogleDriveUseCase::new(company_repo.clone()));
 
-  let test_drive_connection_use_case =
-    Arc::new(TestDriveConnectionUseCase::new(company_repo.clone()));
+  let test_drive_connection_use_case = Arc::new(TestDriveConnectionUseCase::new(
+    company_repo.clone(),
+    token_encryption.clone(),
+  ));
+  let list_drive_folders_use_case = Arc::new(ListDriveFoldersUseCase::new(
+    company_repo.clone(),
+    company_member_repo.clone(),
+    token_encryption.clone(),
+    Arc::new(config.clone()),
+  ));
+
+  // Initialize invoice branding use cases
+  let get_invoice_branding_use_case =
+    Arc::new(GetInvoiceBrandingUseCase::new(invoice_service.clone()));
+  let update_invoice_branding_use_case = Arc::new(UpdateInvoiceBrandingUseCase::new(
+    invoice_service.clone(),
+    std::path::PathBuf::from(&config.pdf.logo_dir),
+  ));
+  let preview_invoice_branding_use_case =
+    Arc::new(PreviewInvoiceBrandingUseCase::new(invoice_service.clone()));
 
   // Initialize customer use cases
   let create_customer_use_case = Arc::new(CreateCustomerUseCase::new(invoice_service.clone()));
@@ -444,11 +619,18 @@ async fn main() -> std::io::Result<()> {
     Arc::new(GetInvoiceDetailsUseCase::new(invoice_service.clone()));
   let archive_invoice_use_case = Arc::new(ArchiveInvoiceUseCase::new(invoice_service.clone()));
   let delete_invoice_use_case = Arc::new(DeleteInvoiceUseCase::new(invoice_service.clone()));
+  let update_invoice_use_case = Arc::new(UpdateInvoiceUseCase::new(invoice_service.clone()));
+  let list_invoice_events_use_case = Arc::new(ListInvoiceEventsUseCase::new(
+    invoice_service.clone(),
+    user_repo.clone(),
+  ));
   let list_archived_invoices_use_case =
     Arc::new(ListArchivedInvoicesUseCase::new(invoice_service.clone()));
   let unarchive_invoice_use_case = Arc::new(UnarchiveInvoiceUseCase::new(invoice_service.clone()));
   let permanently_delete_invoice_use_case = Arc::new(PermanentlyDeleteInvoiceUseCase::new(
     invoice_service.clone(),
+    retention_service.clone(),
+    document_service.clone(),
   ));
 
   // Initialize template use cases
@@ -465,12 +647,47 @@ async fn main() -> std::io::Result<()> {
   ));
   let archive_template_use_case = Arc::new(ArchiveTemplateUseCase::new(invoice_service.clone()));
 
-  // Initialize report service and use cases
-  let report_service = Arc::new(taxbyte::domain::report::ReportService::new(
-    monthly_report_repo.clone(),
-    bank_transaction_repo.clone(),
-    received_invoice_repo.clone(),
+  // Initialize ledger service and use cases
+  let ledger_service = Arc::new(LedgerService::new(
+    ledger_account_repo.clone(),
+    journal_entry_repo.clone(),
+  ));
+  let list_accounts_use_case = Arc::new(taxbyte::application::ledger::ListAccountsUseCase::new(
+    ledger_service.clone(),
   ));
+  let create_account_use_case = Arc::new(taxbyte::application::ledger::CreateAccountUseCase::new(
+    ledger_service.clone(),
+  ));
+  let post_journal_entry_use_case = Arc::new(
+    taxbyte::application::ledger::PostJournalEntryUseCase::new(ledger_service.clone()),
+  );
+  let reverse_journal_entry_use_case =
+    Arc::new(taxbyte::application::ledger::ReverseJournalEntryUseCase::new(ledger_service.clone()));
+  let list_journal_entries_use_case =
+    Arc::new(taxbyte::application::ledger::ListJournalEntriesUseCase::new(ledger_service.clone()));
+  let get_trial_balance_use_case = Arc::new(
+    taxbyte::application::ledger::GetTrialBalanceUseCase::new(ledger_service.clone()),
+  );
+
+  // Initialize SAF-T export
+  let saft_service = Arc::new(taxbyte::domain::saft::SaftService::new(
+    taxbyte::domain::saft::SaftServiceDependencies {
+      company_repo: company_repo.clone(),
+      bank_account_repo: bank_account_repo.clone(),
+      customer_repo: customer_repo.clone(),
+      invoice_repo: invoice_repo.clone(),
+      line_item_repo: invoice_line_item_repo.clone(),
+      received_invoice_repo: received_invoice_repo.clone(),
+      bank_transaction_repo: bank_transaction_repo.clone(),
+      ledger_service: ledger_service.clone(),
+    },
+  ));
+  let export_saft_use_case = Arc::new(taxbyte::application::saft::ExportSaftUseCase::new(
+    saft_service,
+    Arc::new(taxbyte::infrastructure::saft::OecdSaftXmlWriter::new()),
+  ));
+
+  // Initialize report use cases
 
   let csv_parser: Arc<dyn taxbyte::domain::report::BankStatementParser> =
     Arc::new(taxbyte::infrastructure::csv::SwedbankCsvParser::new());
@@ -484,46 +701,188 @@ async fn main() -> std::io::Result<()> {
   let import_bank_statement_use_case = Arc::new(
     taxbyte::application::report::ImportBankStatementUseCase::new(
       report_service.clone(),
+      ledger_service.clone(),
+      invoice_repo.clone(),
+      bank_account_repo.clone(),
       csv_parser,
     ),
   );
   let list_monthly_reports_use_case =
     Arc::new(taxbyte::application::report::ListMonthlyReportsUseCase::new(report_service.clone()));
-  let get_report_details_use_case = Arc::new(
-    taxbyte::application::report::GetReportDetailsUseCase::new(report_service.clone()),
-  );
+  let get_report_details_use_case =
+    Arc::new(taxbyte::application::report::GetReportDetailsUseCase::new(
+      report_service.clone(),
+      bank_account_repo.clone(),
+    ));
   let upload_received_invoice_use_case = Arc::new(
-    taxbyte::application::report::UploadReceivedInvoiceUseCase::new(report_service.clone()),
+    taxbyte::application::report::UploadReceivedInvoiceUseCase::new(
+      report_service.clone(),
+      ledger_service.clone(),
+      document_service.clone(),
+    ),
   );
   let list_received_invoices_use_case = Arc::new(
     taxbyte::application::report::ListReceivedInvoicesUseCase::new(report_service.clone()),
   );
-  let match_transaction_use_case = Arc::new(
-    taxbyte::application::report::MatchTransactionUseCase::new(report_service.clone()),
+  let update_payable_use_case = Arc::new(taxbyte::application::report::UpdatePayableUseCase::new(
+    report_service.clone(),
+    ledger_service.clone(),
+  ));
+  let list_upcoming_bills_use_case = Arc::new(
+    taxbyte::application::report::ListUpcomingBillsUseCase::new(report_service.clone()),
+  );
+  let list_vendors_use_case = Arc::new(taxbyte::application::report::ListVendorsUseCase::new(
+    report_service.clone(),
+  ));
+  let get_vendor_details_use_case = Arc::new(
+    taxbyte::application::report::GetVendorDetailsUseCase::new(report_service.clone()),
+  );
+  let save_vendor_use_case = Arc::new(taxbyte::application::report::SaveVendorUseCase::new(
+    report_service.clone(),
+  ));
+  let merge_vendors_use_case = Arc::new(taxbyte::application::report::MergeVendorsUseCase::new(
+    report_service.clone(),
+  ));
+  let match_transaction_use_case =
+    Arc::new(taxbyte::application::report::MatchTransactionUseCase::new(
+      report_service.clone(),
+      ledger_service.clone(),
+      invoice_repo.clone(),
+      invoice_line_item_repo.clone(),
+    ));
+  let unmatch_transaction_use_case = Arc::new(
+    taxbyte::application::report::UnmatchTransactionUseCase::new(
+      report_service.clone(),
+      ledger_service.clone(),
+      invoice_repo.clone(),
+    ),
   );
-  let unmatch_transaction_use_case =
-    Arc::new(taxbyte::application::report::UnmatchTransactionUseCase::new(report_service.clone()));
   let delete_report_use_case = Arc::new(taxbyte::application::report::DeleteReportUseCase::new(
     report_service.clone(),
+    ledger_service.clone(),
+    invoice_repo.clone(),
+    document_service.clone(),
+    retention_service.clone(),
+  ));
+  let reopen_report_use_case = Arc::new(taxbyte::application::report::ReopenReportUseCase::new(
+    report_service.clone(),
+  ));
+  let lock_report_use_case = Arc::new(taxbyte::application::report::LockReportUseCase::new(
+    report_service.clone(),
+    company_member_repo.clone(),
   ));
+  let list_report_events_use_case =
+    Arc::new(taxbyte::application::report::ListReportEventsUseCase::new(
+      report_service.clone(),
+      user_repo.clone(),
+    ));
   let delete_received_invoice_use_case = Arc::new(
-    taxbyte::application::report::DeleteReceivedInvoiceUseCase::new(report_service.clone()),
+    taxbyte::application::report::DeleteReceivedInvoiceUseCase::new(
+      report_service.clone(),
+      ledger_service.clone(),
+      document_service.clone(),
+      retention_service.clone(),
+    ),
   );
   let upload_receipt_use_case = Arc::new(taxbyte::application::report::UploadReceiptUseCase::new(
     report_service.clone(),
+    document_service.clone(),
+    retention_service.clone(),
   ));
+  let list_transaction_categories_use_case = Arc::new(
+    taxbyte::application::report::ListTransactionCategoriesUseCase::new(report_service.clone()),
+  );
+  let create_transaction_category_use_case = Arc::new(
+    taxbyte::application::report::CreateTransactionCategoryUseCase::new(report_service.clone()),
+  );
+  let delete_transaction_category_use_case = Arc::new(
+    taxbyte::application::report::DeleteTransactionCategoryUseCase::new(report_service.clone()),
+  );
+  let categorise_transaction_use_case = Arc::new(
+    taxbyte::application::report::CategoriseTransactionUseCase::new(report_service.clone()),
+  );
+  let list_matching_rules_use_case = Arc::new(
+    taxbyte::application::report::ListMatchingRulesUseCase::new(report_service.clone()),
+  );
+  let create_matching_rule_use_case =
+    Arc::new(taxbyte::application::report::CreateMatchingRuleUseCase::new(report_service.clone()));
+  let test_matching_rule_use_case = Arc::new(
+    taxbyte::application::report::TestMatchingRuleUseCase::new(report_service.clone()),
+  );
+  let move_matching_rule_use_case = Arc::new(
+    taxbyte::application::report::MoveMatchingRuleUseCase::new(report_service.clone()),
+  );
+  let delete_matching_rule_use_case =
+    Arc::new(taxbyte::application::report::DeleteMatchingRuleUseCase::new(report_service.clone()));
+  let clear_rule_outcome_use_case = Arc::new(
+    taxbyte::application::report::ClearRuleOutcomeUseCase::new(report_service.clone()),
+  );
 
-  // Generate report use case needs cloud storage — use a no-op placeholder
-  // (actual Drive adapter is created per-company when generating)
-  let report_cloud_storage: Arc<dyn taxbyte::domain::report::ReportCloudStorage> =
-    Arc::new(taxbyte::infrastructure::cloud::report_drive_adapter::NoOpReportCloudStorage);
+  // Outbox of report files and invoice PDFs waiting to be uploaded
+  let upload_outbox = Arc::new(UploadOutboxService::new(upload_job_repo));
+  let list_pending_uploads_use_case =
+    Arc::new(taxbyte::application::storage::ListPendingUploadsUseCase::new(upload_outbox.clone()));
+  let get_sync_status_use_case = Arc::new(
+    taxbyte::application::storage::GetSyncStatusUseCase::new(upload_outbox.clone()),
+  );
+  let reconcile_drive_files_use_case = Arc::new(
+    taxbyte::application::storage::ReconcileDriveFilesUseCase::new(
+      company_repo.clone(),
+      upload_outbox.clone(),
+      Arc::new(
+        taxbyte::infrastructure::cloud::CompanyDriveInspectorResolver::new(
+          company_repo.clone(),
+          token_encryption.clone(),
+          Arc::new(config.clone()),
+        ),
+      ),
+    ),
+  );
+
+  // Report files go to the storage provider configured per company
+  let report_storage_resolver: Arc<dyn taxbyte::domain::report::ReportStorageResolver> = Arc::new(
+    taxbyte::infrastructure::cloud::CompanyReportStorageResolver::new(
+      company_repo.clone(),
+      token_encryption.clone(),
+      Arc::new(config.clone()),
+    ),
+  );
   let generate_report_use_case =
     Arc::new(taxbyte::application::report::GenerateReportUseCase::new(
       report_service.clone(),
       company_repo.clone(),
       invoice_repo.clone(),
-      report_cloud_storage,
+      bank_account_repo.clone(),
+      report_storage_resolver.clone(),
+      upload_outbox.clone(),
+      document_service.clone(),
     ));
+  let retry_report_uploads_use_case = Arc::new(
+    taxbyte::application::report::RetryReportUploadsUseCase::new(
+      report_service.clone(),
+      report_storage_resolver.clone(),
+      upload_outbox.clone(),
+      document_service.clone(),
+    ),
+  );
+  let process_report_uploads_use_case = Arc::new(
+    taxbyte::application::report::ProcessReportUploadsUseCase::new(
+      report_service.clone(),
+      report_storage_resolver,
+      upload_outbox.clone(),
+      document_service.clone(),
+    ),
+  );
+  let export_report_archive_use_case = Arc::new(
+    taxbyte::application::report::ExportReportArchiveUseCase::new(
+      report_service.clone(),
+      invoice_repo.clone(),
+      bank_account_repo.clone(),
+      Arc::new(taxbyte::infrastructure::csv::ReportIndexCsvWriter::new()),
+      Arc::new(taxbyte::infrastructure::archive::ZipArchiveWriter::new()),
+      document_service.clone(),
+    ),
+  );
 
   // Initialize template engine
   let templates = TemplateEngine::new().expect("Failed to initialize template engine");
@@ -531,33 +890,162 @@ async fn main() -> std::io::Result<()> {
 
   // Initialize PDF generator
   let pdf_output_dir = std::path::PathBuf::from(&config.pdf.output_dir);
-  let pdf_generator = Arc::new(taxbyte::infrastructure::pdf::WkHtmlToPdfGenerator::new(
+  let wkhtmltopdf = Arc::new(taxbyte::infrastructure::pdf::WkHtmlToPdfGenerator::new(
     pdf_output_dir,
     config.pdf.wkhtmltopdf_path.clone(),
     config.server.base_url.clone(),
-  )) as Arc<dyn taxbyte::domain::invoice::ports::PdfGenerator>;
+    std::path::PathBuf::from(&config.pdf.assets_dir),
+  ));
+  let pdf_generator = wkhtmltopdf.clone() as Arc<dyn taxbyte::domain::invoice::ports::PdfGenerator>;
+  let html_pdf_converter = wkhtmltopdf as Arc<dyn taxbyte::domain::financials::HtmlPdfConverter>;
   tracing::info!("PDF generator initialized");
 
-  // Initialize change invoice status use case (cloud storage configured per-company)
-  let change_invoice_status_use_case = Arc::new(ChangeInvoiceStatusUseCase::new(
+  // Initialize financial statements
+  let financial_statement_service =
+    Arc::new(taxbyte::domain::financials::FinancialStatementService::new(
+      taxbyte::domain::financials::FinancialStatementServiceDependencies {
+        customer_repo: customer_repo.clone(),
+        invoice_repo: invoice_repo.clone(),
+        line_item_repo: invoice_line_item_repo.clone(),
+        received_invoice_repo: received_invoice_repo.clone(),
+        bank_transaction_repo: bank_transaction_repo.clone(),
+        transaction_category_repo: transaction_category_repo.clone(),
+      },
+    ));
+  let get_financial_statement_use_case = Arc::new(
+    taxbyte::application::financials::GetFinancialStatementUseCase::new(
+      financial_statement_service.clone(),
+    ),
+  );
+  let export_statement_csv_use_case = Arc::new(
+    taxbyte::application::financials::ExportStatementCsvUseCase::new(
+      financial_statement_service,
+      Arc::new(taxbyte::infrastructure::csv::StatementCsvExporter::new()),
+    ),
+  );
+
+  // Invoice PDFs go through the upload outbox (cloud storage configured per-company)
+  let invoice_pdf_uploader = Arc::new(taxbyte::application::invoice::InvoicePdfUploader::new(
     invoice_service.clone(),
-    pdf_generator.clone(),
-    get_invoice_details_use_case.clone(),
     company_repo.clone(),
     token_encryption.clone(),
     connect_google_drive_use_case.clone(),
     Arc::new(config.clone()),
+    upload_outbox,
+    document_service.clone(),
+  ));
+  let change_invoice_status_use_case = Arc::new(ChangeInvoiceStatusUseCase::new(
+    invoice_service.clone(),
+    pdf_generator.clone(),
+    get_invoice_details_use_case.clone(),
+    invoice_pdf_uploader.clone(),
+    ledger_service.clone(),
+    document_service.clone(),
   ));
 
   let reupload_invoice_use_case = Arc::new(ReuploadInvoiceUseCase::new(
     invoice_service.clone(),
     pdf_generator.clone(),
     get_invoice_details_use_case.clone(),
-    company_repo.clone(),
-    token_encryption.clone(),
-    connect_google_drive_use_case.clone(),
-    Arc::new(config.clone()),
+    invoice_pdf_uploader.clone(),
+    document_service.clone(),
+    retention_service.clone(),
   ));
+  let retry_invoice_uploads_use_case = Arc::new(RetryInvoiceUploadsUseCase::new(
+    invoice_service.clone(),
+    invoice_pdf_uploader.clone(),
+  ));
+  let process_invoice_uploads_use_case =
+    Arc::new(ProcessInvoiceUploadsUseCase::new(invoice_pdf_uploader));
+
+  // Retry queued uploads in the background, each job once its backoff is over
+  tokio::spawn(async move {
+    let mut interval = tokio::time::interval(Duration::from_secs(60));
+    loop {
+      interval.tick().await;
+      if let Err(e) = process_report_uploads_use_case.execute().await {
+        tracing::error!("Processing report uploads failed: {}", e);
+      }
+      if let Err(e) = process_invoice_uploads_use_case.execute().await {
+        tracing::error!("Processing invoice uploads failed: {}", e);
+      }
+    }
+  });
+
+  // Check the files uploaded to Google Drive for drift; missing ones are
+  // queued again and uploaded by the loop above
+  let reconcile_interval = config.drive_sync.reconcile_interval_seconds;
+  tokio::spawn(async move {
+    let mut interval = tokio::time::interval(Duration::from_secs(reconcile_interval));
+    loop {
+      interval.tick().await;
+      match reconcile_drive_files_use_case.execute().await {
+        Ok(counts) => tracing::info!(
+          "Checked {} Drive files: {} in sync, {} moved or renamed, {} missing",
+          counts.checked,
+          counts.in_sync,
+          counts.drifted,
+          counts.missing
+        ),
+        Err(e) => tracing::error!("Drive reconciliation failed: {}", e),
+      }
+    }
+  });
+
+  let list_documents_use_case = Arc::new(
+    taxbyte::application::document::ListDocumentsUseCase::new(document_service.clone()),
+  );
+
+  // Check the stored documents for missing or corrupted files
+  let integrity_check_interval = config.documents.integrity_check_interval_seconds;
+  let check_integrity_use_case = Arc::new(
+    taxbyte::application::document::CheckDocumentIntegrityUseCase::new(document_service.clone()),
+  );
+  tokio::spawn(async move {
+    let mut interval = tokio::time::interval(Duration::from_secs(integrity_check_interval));
+    loop {
+      interval.tick().await;
+      if let Err(e) = check_integrity_use_case.execute().await {
+        tracing::error!("Document integrity check failed: {}", e);
+      }
+    }
+  });
+
+  // Retention settings and the purge of records past their retention period
+  let get_retention_settings_use_case = Arc::new(
+    taxbyte::application::retention::GetRetentionSettingsUseCase::new(retention_service.clone()),
+  );
+  let update_retention_policy_use_case = Arc::new(
+    taxbyte::application::retention::UpdateRetentionPolicyUseCase::new(retention_service.clone()),
+  );
+  if config.retention.purge_enabled {
+    let purge_interval = config.retention.purge_interval_seconds;
+    let purge_expired_records_use_case = Arc::new(
+      taxbyte::application::retention::PurgeExpiredRecordsUseCase::new(
+        retention_service.clone(),
+        company_repo.clone(),
+        invoice_repo.clone(),
+        invoice_service.clone(),
+        report_service.clone(),
+        document_service.clone(),
+      ),
+    );
+    tokio::spawn(async move {
+      let mut interval = tokio::time::interval(Duration::from_secs(purge_interval));
+      loop {
+        interval.tick().await;
+        match purge_expired_records_use_case.execute().await {
+          Ok(purged) => tracing::info!(
+            "Purged {} invoices, {} received invoices and {} reports past retention",
+            purged.invoices,
+            purged.received_invoices,
+            purged.reports
+          ),
+          Err(e) => tracing::error!("Retention purge failed: {}", e),
+        }
+      }
+    });
+  }
 
   let server_host = config.server.host.clone();
   let server_port = config.server.port;
@@ -608,8 +1096,11 @@ async fn main() -> std::io::Result<()> {
             get_invoice_details_use_case: get_invoice_details_use_case.clone(),
             change_invoice_status_use_case: change_invoice_status_use_case.clone(),
             reupload_invoice_use_case: reupload_invoice_use_case.clone(),
+            retry_invoice_uploads_use_case: retry_invoice_uploads_use_case.clone(),
             archive_invoice_use_case: archive_invoice_use_case.clone(),
             delete_invoice_use_case: delete_invoice_use_case.clone(),
+            update_invoice_use_case: update_invoice_use_case.clone(),
+            list_invoice_events_use_case: list_invoice_events_use_case.clone(),
             // Archived invoice use cases
             list_archived_invoices_use_case: list_archived_invoices_use_case.clone(),
             unarchive_invoice_use_case: unarchive_invoice_use_case.clone(),
@@ -623,6 +1114,13 @@ async fn main() -> std::io::Result<()> {
             connect_google_drive_use_case: connect_google_drive_use_case.clone(),
             disconnect_google_drive_use_case: disconnect_google_drive_use_case.clone(),
             test_drive_connection_use_case: test_drive_connection_use_case.clone(),
+            list_drive_folders_use_case: list_drive_folders_use_case.clone(),
+            // Invoice branding use cases
+            get_invoice_branding_use_case: get_invoice_branding_use_case.clone(),
+            update_invoice_branding_use_case: update_invoice_branding_use_case.clone(),
+            preview_invoice_branding_use_case: preview_invoice_branding_use_case.clone(),
+            get_retention_settings_use_case: get_retention_settings_use_case.clone(),
+            update_retention_policy_use_case: update_retention_policy_use_case.clone(),
             // Report use cases
             create_empty_report_use_case: create_empty_report_use_case.clone(),
             import_bank_statement_use_case: import_bank_statement_use_case.clone(),
@@ -630,13 +1128,50 @@ async fn main() -> std::io::Result<()> {
             get_report_details_use_case: get_report_details_use_case.clone(),
             upload_received_invoice_use_case: upload_received_invoice_use_case.clone(),
             list_received_invoices_use_case: list_received_invoices_use_case.clone(),
+            update_payable_use_case: update_payable_use_case.clone(),
+            list_upcoming_bills_use_case: list_upcoming_bills_use_case.clone(),
+            list_vendors_use_case: list_vendors_use_case.clone(),
+            get_vendor_details_use_case: get_vendor_details_use_case.clone(),
+            save_vendor_use_case: save_vendor_use_case.clone(),
+            merge_vendors_use_case: merge_vendors_use_case.clone(),
             match_transaction_use_case: match_transaction_use_case.clone(),
             unmatch_transaction_use_case: unmatch_transaction_use_case.clone(),
             generate_report_use_case: generate_report_use_case.clone(),
+            export_report_archive_use_case: export_report_archive_use_case.clone(),
+            retry_report_uploads_use_case: retry_report_uploads_use_case.clone(),
             delete_report_use_case: delete_report_use_case.clone(),
+            reopen_report_use_case: reopen_report_use_case.clone(),
+            lock_report_use_case: lock_report_use_case.clone(),
+            list_report_events_use_case: list_report_events_use_case.clone(),
             delete_received_invoice_use_case: delete_received_invoice_use_case.clone(),
             upload_receipt_use_case: upload_receipt_use_case.clone(),
+            list_transaction_categories_use_case: list_transaction_categories_use_case.clone(),
+            create_transaction_category_use_case: create_transaction_category_use_case.clone(),
+            delete_transaction_category_use_case: delete_transaction_category_use_case.clone(),
+            categorise_transaction_use_case: categorise_transaction_use_case.clone(),
+            list_matching_rules_use_case: list_matching_rules_use_case.clone(),
+            create_matching_rule_use_case: create_matching_rule_use_case.clone(),
+            test_matching_rule_use_case: test_matching_rule_use_case.clone(),
+            move_matching_rule_use_case: move_matching_rule_use_case.clone(),
+            delete_matching_rule_use_case: delete_matching_rule_use_case.clone(),
+            clear_rule_outcome_use_case: clear_rule_outcome_use_case.clone(),
             invoice_data_extractor: invoice_data_extractor.clone(),
+            list_documents_use_case: list_documents_use_case.clone(),
+            // Ledger use cases
+            list_accounts_use_case: list_accounts_use_case.clone(),
+            create_account_use_case: create_account_use_case.clone(),
+            post_journal_entry_use_case: post_journal_entry_use_case.clone(),
+            reverse_journal_entry_use_case: reverse_journal_entry_use_case.clone(),
+            list_journal_entries_use_case: list_journal_entries_use_case.clone(),
+            get_trial_balance_use_case: get_trial_balance_use_case.clone(),
+            export_saft_use_case: export_saft_use_case.clone(),
+            // Financial statement use cases
+            get_financial_statement_use_case: get_financial_statement_use_case.clone(),
+            export_statement_csv_use_case: export_statement_csv_use_case.clone(),
+            html_pdf_converter: html_pdf_converter.clone(),
+            // Upload outbox
+            list_pending_uploads_use_case: list_pending_uploads_use_case.clone(),
+            get_sync_status_use_case: get_sync_status_use_case.clone(),
           },
         )
       })
@@ -703,6 +1238,8 @@ async fn main() -> std::io::Result<()> {
               change_invoice_status_use_case.clone(),
               archive_invoice_use_case.clone(),
               list_customers_use_case.clone(),
+              update_invoice_use_case.clone(),
+              list_invoice_events_use_case.clone(),
             )
           }),
       )
@@ -720,3 +1257,96 @@ async fn main() -> std::io::Result<()> {
 async fn health_check() -> &'static str {
   "OK"
 }
+
+/// Run a one-off maintenance command; a non-zero exit means it found problems
+async fn run_cli_command(
+  command: &str,
+  args: &[String],
+  invoice_service: Arc<InvoiceService>,
+  encrypt_documents: Arc<taxbyte::application::document::EncryptDocumentsUseCase>,
+  import_documents: Arc<taxbyte::application::document::ImportLegacyFilesUseCase>,
+  merge_vendors: Arc<taxbyte::application::report::MergeSameNameVendorsUseCase>,
+) -> std::io::Result<()> {
+  match command {
+    "verify-invoice-chain" => {
+      let company_id = args
+        .first()
+        .and_then(|id| uuid::Uuid::parse_str(id).ok())
+        .ok_or_else(|| {
+          std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "usage: taxbyte verify-invoice-chain <company_id>",
+          )
+        })?;
+
+      let report = VerifyInvoiceChainUseCase::new(invoice_service)
+        .execute(VerifyInvoiceChainCommand {
+          user_id: uuid::Uuid::nil(),
+          company_id,
+        })
+        .await
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+
+      println!(
+        "Checked {} invoice chain link(s) for company {}",
+        report.links_checked, report.company_id
+      );
+      for broken in &report.broken_links {
+        println!(
+          "  #{} invoice {} ({}): {}",
+          broken.sequence,
+          broken.invoice_number.as_deref().unwrap_or("-"),
+          broken.invoice_id,
+          broken.problem.description()
+        );
+      }
+
+      if report.is_intact() {
+        println!("Invoice chain intact");
+        Ok(())
+      } else {
+        Err(std::io::Error::other(format!(
+          "{} broken link(s) in invoice chain",
+          report.broken_links.len()
+        )))
+      }
+    }
+    "encrypt-documents" => {
+      let report = encrypt_documents
+        .execute()
+        .await
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+
+      println!(
+        "Encrypted {} stored document(s) and {} other file(s); {} already encrypted",
+        report.documents, report.files, report.skipped
+      );
+      Ok(())
+    }
+    "import-documents" => {
+      let report = import_documents
+        .execute()
+        .await
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+
+      println!(
+        "Imported {} file(s) into the document store; {} missing",
+        report.imported, report.missing
+      );
+      Ok(())
+    }
+    "merge-vendors" => {
+      let merged = merge_vendors
+        .execute()
+        .await
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+
+      println!("Merged {} vendor(s) into others of the same name", merged);
+      Ok(())
+    }
+    other => Err(std::io::Error::new(
+      std::io::ErrorKind::InvalidInput,
+      format!("Unknown command '{}'", other),
+    )),
+  }
+}